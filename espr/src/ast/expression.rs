@@ -97,6 +97,11 @@ impl Expression {
         }
     }
 
+    /// Integer value literal
+    pub fn integer(value: i64) -> Self {
+        Expression::Literal(Literal::Integer(value))
+    }
+
     /// Real value literal
     pub fn real(value: f64) -> Self {
         Expression::Literal(Literal::Real(value))
@@ -307,6 +312,7 @@ pub enum Logical {
 
 #[derive(Debug, Clone, PartialEq, From)]
 pub enum Literal {
+    Integer(i64),
     Real(f64),
     String(String),
     Logial(Logical),
@@ -128,4 +128,38 @@ mod tests {
         dbg!(&st);
         assert_eq!(st.remarks.len(), 6);
     }
+
+    #[test]
+    fn remarks_discarded() {
+        let remarked = super::SyntaxTree::parse(
+            r#"
+            (* (* nested *) header *)
+            SCHEMA one; -- schema
+              ENTITY point (* 2D *);
+                x : REAL; -- abscissa
+                (* y, *) y : (* coordinate *) REAL;
+              END_ENTITY;
+              TYPE label = (* (* a *) b *) STRING; -- label
+              END_TYPE;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap();
+        let plain = super::SyntaxTree::parse(
+            r#"
+            SCHEMA one;
+              ENTITY point;
+                x : REAL;
+                y : REAL;
+              END_ENTITY;
+              TYPE label = STRING;
+              END_TYPE;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap();
+        assert_eq!(remarked.schemas, plain.schemas);
+        assert_eq!(remarked.remarks.len(), 8);
+        assert!(plain.remarks.is_empty());
+    }
 }
@@ -10,6 +10,42 @@ struct Arguments {
     num_lines: usize,
     #[structopt(long = "check", help = "Check input EXPRESS definitions can be parsed")]
     check: bool,
+    #[structopt(
+        long = "attribute-order",
+        default_value = "inherited-first",
+        help = "Order of fields in generated structs, 'inherited-first' or 'inherited-last'"
+    )]
+    attribute_order: AttributeOrder,
+    #[structopt(
+        long = "non-exhaustive",
+        help = "Add #[non_exhaustive] to generated enums"
+    )]
+    non_exhaustive: bool,
+    #[structopt(
+        long = "variant-naming",
+        default_value = "pascal-case",
+        help = "Naming of enum variants for enumeration items, 'pascal-case' or 'screaming-snake-case'"
+    )]
+    variant_naming: VariantNaming,
+    #[structopt(
+        long = "shared-references",
+        help = "Use Rc<T> for entity references, sharing the same instance"
+    )]
+    shared_references: bool,
+    #[structopt(
+        long = "deserialize-backend",
+        default_value = "serde",
+        help = "Deserialization of generated holders, 'serde' or 'from-parameter'"
+    )]
+    deserialize_backend: DeserializeBackend,
+    #[structopt(
+        long = "target-edition",
+        default_value = "2021",
+        help = "Rust edition of the crate including generated code, '2018', '2021' or '2024'"
+    )]
+    target_edition: TargetEdition,
+    #[structopt(long = "warnings", help = "Print semantic warnings to stderr")]
+    warnings: bool,
     #[structopt(parse(from_os_str))]
     source: PathBuf,
 }
@@ -38,9 +74,23 @@ fn main() {
         return;
     }
 
-    let ir = IR::from_syntax_tree(&st).expect("Failed in semantic analysis phase");
+    let (ir, warnings) =
+        IR::from_syntax_tree_with_warnings(&st).expect("Failed in semantic analysis phase");
+    if args.warnings {
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
+        }
+    }
+    let options = CodegenOptions {
+        attribute_order: args.attribute_order,
+        non_exhaustive: args.non_exhaustive,
+        variant_naming: args.variant_naming,
+        shared_references: args.shared_references,
+        deserialize_backend: args.deserialize_backend,
+        target_edition: args.target_edition,
+    };
     println!(
         "#![allow(dead_code)]\n{}",
-        ir.to_token_stream(CratePrefix::Internal)
+        ir.to_token_stream_with_options(CratePrefix::Internal, &options)
     );
 }
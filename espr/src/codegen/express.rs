@@ -0,0 +1,472 @@
+//! Print IR back as EXPRESS schemas
+//!
+//! This is used to save a pruned IR, e.g. [IR::subset], as a standalone EXPRESS file
+//! which can be compiled again by espr or other tools.
+//! The printed schemas are legalized into the same IR as the input,
+//! while the following parts are not kept in IR and thus not printed:
+//!
+//! - Declarations other than entities and types, i.e. `CONSTANT`, `FUNCTION`, `PROCEDURE` and `RULE`
+//! - `WHERE` clauses of `TYPE` declarations, and `SUPERTYPE OF` constraints
+//! - `DERIVE` attributes redeclaring inherited ones
+//! - Bounds of aggregates referring constants, which are printed as `0` or `?`
+//!
+//! Expressions are fully parenthesized since the original parentheses are not kept in AST.
+
+use crate::{
+    ast::{self, Expression},
+    ir::*,
+};
+use std::fmt::Write;
+
+impl IR {
+    /// EXPRESS schemas which are legalized into this IR
+    pub fn to_express(&self) -> String {
+        self.schemas
+            .iter()
+            .map(Schema::to_express)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Schema {
+    pub fn to_express(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "SCHEMA {};", self.name).unwrap();
+        for name in &self.imported_schemas {
+            writeln!(out, "  USE FROM {};", name).unwrap();
+        }
+        for alias in &self.aliases {
+            let (schema, name) = match &alias.ty {
+                TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => {
+                    match scope.schema_name() {
+                        Some(schema) => (schema, name),
+                        None => continue,
+                    }
+                }
+                _ => continue,
+            };
+            if name == &alias.name {
+                writeln!(out, "  USE FROM {} ({});", schema, name).unwrap();
+            } else {
+                writeln!(out, "  USE FROM {} ({} AS {});", schema, name, alias.name).unwrap();
+            }
+        }
+        for ty in &self.types {
+            self.write_type_decl(&mut out, ty);
+        }
+        for entity in &self.entities {
+            self.write_entity(&mut out, entity);
+        }
+        writeln!(out, "END_SCHEMA;").unwrap();
+        out
+    }
+
+    fn write_type_decl(&self, out: &mut String, ty: &TypeDecl) {
+        let underlying = match ty {
+            // Synthesized for `GENERIC_ENTITY` while legalization
+            TypeDecl::Select(Select { id, .. }) if id == GENERIC_ENTITY => return,
+            TypeDecl::Simple(Simple { ty, .. }) => simple_type(&ty.0),
+            TypeDecl::Rename(Rename { ty, .. }) => self.type_ref(ty),
+            TypeDecl::Enumeration(Enumeration { items, .. }) => {
+                format!("ENUMERATION OF ({})", items.join(", "))
+            }
+            TypeDecl::Select(Select { types, .. }) => format!(
+                "SELECT ({})",
+                types
+                    .iter()
+                    .map(|ty| self.type_ref(ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        writeln!(out, "  TYPE {} = {};", ty.id(), underlying).unwrap();
+        writeln!(out, "  END_TYPE;").unwrap();
+    }
+
+    fn write_entity(&self, out: &mut String, entity: &Entity) {
+        write!(out, "  ENTITY {}", entity.name).unwrap();
+        if entity.is_abstract {
+            let has_subtype = self.entities.iter().any(|sub| {
+                sub.supertypes
+                    .iter()
+                    .any(|ty| matches!(ty, TypeRef::Entity { name, .. } if name == &entity.name))
+            });
+            if has_subtype {
+                write!(out, " ABSTRACT SUPERTYPE").unwrap();
+            } else {
+                write!(out, " ABSTRACT").unwrap();
+            }
+        }
+        if !entity.supertypes.is_empty() {
+            let supertypes: Vec<String> = entity
+                .supertypes
+                .iter()
+                .map(|ty| self.type_ref(ty))
+                .collect();
+            write!(out, " SUBTYPE OF ({})", supertypes.join(", ")).unwrap();
+        }
+        writeln!(out, ";").unwrap();
+        for attr in &entity.attributes {
+            let optional = if attr.optional { "OPTIONAL " } else { "" };
+            writeln!(
+                out,
+                "    {} : {}{};",
+                attr.name,
+                optional,
+                self.type_ref(&attr.ty)
+            )
+            .unwrap();
+        }
+        if !entity.derived_attributes.is_empty() {
+            writeln!(out, "  DERIVE").unwrap();
+            for attr in &entity.derived_attributes {
+                writeln!(
+                    out,
+                    "    {} : {} := {};",
+                    attr.name,
+                    self.type_ref(&attr.ty),
+                    expression(&attr.expr)
+                )
+                .unwrap();
+            }
+        }
+        if !entity.inverse_attributes.is_empty() {
+            writeln!(out, "  INVERSE").unwrap();
+            for attr in &entity.inverse_attributes {
+                let name = match &attr.redeclares {
+                    Some(supertype) => format!("SELF\\{}.{}", supertype, attr.name),
+                    None => attr.name.clone(),
+                };
+                writeln!(
+                    out,
+                    "    {} : {} FOR {};",
+                    name,
+                    self.type_ref(&attr.dest),
+                    attr.attribute
+                )
+                .unwrap();
+            }
+        }
+        if !entity.unique_rules.is_empty() {
+            writeln!(out, "  UNIQUE").unwrap();
+            for rule in &entity.unique_rules {
+                let label = match &rule.label {
+                    Some(label) => format!("{}: ", label),
+                    None => String::new(),
+                };
+                writeln!(out, "    {}{};", label, rule.attributes.join(", ")).unwrap();
+            }
+        }
+        if !entity.domain_rules.is_empty() {
+            writeln!(out, "  WHERE").unwrap();
+            for rule in &entity.domain_rules {
+                let label = if rule.is_labeled {
+                    format!("{}: ", rule.label)
+                } else {
+                    String::new()
+                };
+                writeln!(out, "    {}{};", label, expression(&rule.expr)).unwrap();
+            }
+        }
+        writeln!(out, "  END_ENTITY;").unwrap();
+    }
+
+    /// Name of the declaration visible in this schema, i.e. the local name if imported with `AS`
+    fn local_name<'a>(&'a self, name: &'a str, scope: &Scope) -> &'a str {
+        if scope.schema_name() == Some(self.name.as_str()) {
+            return name;
+        }
+        self.aliases
+            .iter()
+            .find(|alias| match &alias.ty {
+                TypeRef::Named {
+                    name: n, scope: s, ..
+                }
+                | TypeRef::Entity {
+                    name: n, scope: s, ..
+                } => n == name && s == scope,
+                _ => false,
+            })
+            .map(|alias| alias.name.as_str())
+            .unwrap_or(name)
+    }
+
+    fn type_ref(&self, ty: &TypeRef) -> String {
+        match ty {
+            TypeRef::SimpleType(ty) => simple_type(&ty.0),
+            TypeRef::Named { name, .. } if name == GENERIC_ENTITY => "GENERIC_ENTITY".to_string(),
+            TypeRef::Named { name, scope, .. } | TypeRef::Entity { name, scope, .. } => {
+                self.local_name(name, scope).to_string()
+            }
+            TypeRef::Set { base, bound } => {
+                format!("SET{} OF {}", bound_spec(bound), self.type_ref(base))
+            }
+            TypeRef::Bag { base, bound } => {
+                format!("BAG{} OF {}", bound_spec(bound), self.type_ref(base))
+            }
+            TypeRef::List {
+                base,
+                bound,
+                unique,
+            } => format!(
+                "LIST{} OF {}{}",
+                bound_spec(bound),
+                if *unique { "UNIQUE " } else { "" },
+                self.type_ref(base)
+            ),
+            TypeRef::Array {
+                base,
+                bound,
+                unique,
+                optional,
+            } => format!(
+                // bound is required for ARRAY
+                "ARRAY{} OF {}{}{}",
+                bound_spec(&Some(bound.clone().unwrap_or(Bound {
+                    lower: None,
+                    upper: None
+                }))),
+                if *optional { "OPTIONAL " } else { "" },
+                if *unique { "UNIQUE " } else { "" },
+                self.type_ref(base)
+            ),
+            TypeRef::Aggregate { base, label } => match label {
+                Some(label) => format!("AGGREGATE:{} OF {}", label, self.type_ref(base)),
+                None => format!("AGGREGATE OF {}", self.type_ref(base)),
+            },
+        }
+    }
+}
+
+fn simple_type(ty: &ast::SimpleType) -> String {
+    let width = |name: &str, width_spec: &Option<ast::WidthSpec>| match width_spec {
+        Some(ast::WidthSpec { width, fixed: true }) => format!("{}({}) FIXED", name, width),
+        Some(ast::WidthSpec {
+            width,
+            fixed: false,
+        }) => format!("{}({})", name, width),
+        None => name.to_string(),
+    };
+    match ty {
+        ast::SimpleType::Number => "NUMBER".to_string(),
+        ast::SimpleType::Real => "REAL".to_string(),
+        ast::SimpleType::Integer => "INTEGER".to_string(),
+        ast::SimpleType::Logical => "LOGICAL".to_string(),
+        ast::SimpleType::Boolen => "BOOLEAN".to_string(),
+        ast::SimpleType::String_ { width_spec } => width("STRING", width_spec),
+        ast::SimpleType::Binary { width_spec } => width("BINARY", width_spec),
+    }
+}
+
+fn bound_spec(bound: &Option<Bound>) -> String {
+    match bound {
+        Some(Bound { lower, upper }) => format!(
+            " [{}:{}]",
+            lower.unwrap_or(0),
+            upper.map_or_else(|| "?".to_string(), |upper| upper.to_string())
+        ),
+        None => String::new(),
+    }
+}
+
+/// Operand of an operator, parenthesized unless it is a primary
+fn operand(expr: &Expression) -> String {
+    match expr {
+        Expression::Unary { .. } | Expression::Binary { .. } | Expression::Relation { .. } => {
+            format!("({})", expression(expr))
+        }
+        _ => expression(expr),
+    }
+}
+
+fn expression(expr: &Expression) -> String {
+    match expr {
+        Expression::Unary { op, arg } => {
+            let op = match op {
+                ast::UnaryOperator::Plus => "+",
+                ast::UnaryOperator::Minus => "-",
+                ast::UnaryOperator::Not => "NOT ",
+            };
+            format!("{}({})", op, expression(arg))
+        }
+        Expression::Binary { op, arg1, arg2 } => {
+            use ast::BinaryOperator::*;
+            let op = match op {
+                Mul => "*",
+                RealDiv => "/",
+                IntegerDiv => "DIV",
+                Mod => "MOD",
+                And => "AND",
+                ComplexEntityInstanceConstruction => "||",
+                Add => "+",
+                Sub => "-",
+                Or => "OR",
+                Xor => "XOR",
+                Power => "**",
+            };
+            format!("{} {} {}", operand(arg1), op, operand(arg2))
+        }
+        Expression::Relation { op, lhs, rhs } => {
+            use ast::RelationOperator::*;
+            let op = match op {
+                Equal => "=",
+                NotEqual => "<>",
+                Lt => "<",
+                Gt => ">",
+                Leq => "<=",
+                Geq => ">=",
+                InstanceEqual => ":=:",
+                InstanceNotEqual => ":<>:",
+                In => "IN",
+                Like => "LIKE",
+            };
+            format!("{} {} {}", operand(lhs), op, operand(rhs))
+        }
+        Expression::Literal(literal) => match literal {
+            ast::Literal::Integer(value) => value.to_string(),
+            ast::Literal::Real(value) => {
+                // EXPRESS requires the decimal point, e.g. `1.0E-5` instead of `1e-5`
+                let value = format!("{:?}", value).to_uppercase();
+                match value.split_once('E') {
+                    Some((mantissa, exponent)) if !mantissa.contains('.') => {
+                        format!("{}.0E{}", mantissa, exponent)
+                    }
+                    _ => value,
+                }
+            }
+            ast::Literal::String(value) => format!("'{}'", value.replace('\'', "''")),
+            ast::Literal::Logial(value) => match value {
+                ast::Logical::True => "TRUE",
+                ast::Logical::False => "FALSE",
+                ast::Logical::Unknown => "UNKNOWN",
+            }
+            .to_string(),
+        },
+        Expression::QualifiableFactor { factor, qualifiers } => {
+            let mut out = match factor {
+                ast::QualifiableFactor::Reference(name) => name.clone(),
+                ast::QualifiableFactor::BuiltInConstant(constant) => match constant {
+                    ast::BuiltInConstant::Napier => "CONST_E",
+                    ast::BuiltInConstant::Pi => "PI",
+                    ast::BuiltInConstant::Self_ => "SELF",
+                    ast::BuiltInConstant::Indeterminate => "?",
+                }
+                .to_string(),
+                ast::QualifiableFactor::FunctionCall { name, args } => {
+                    let name = match name {
+                        ast::FunctionCallName::BuiltInFunction(f) => format!("{:?}", f),
+                        ast::FunctionCallName::Reference(name) => name.clone(),
+                    };
+                    format!("{}({})", name, expressions(args))
+                }
+            };
+            for qualifier in qualifiers {
+                match qualifier {
+                    ast::Qualifier::Attribute(name) => write!(out, ".{}", name),
+                    ast::Qualifier::Group(name) => write!(out, "\\{}", name),
+                    ast::Qualifier::Index(index) => write!(out, "[{}]", expression(index)),
+                    ast::Qualifier::Range { begin, end } => {
+                        write!(out, "[{}:{}]", expression(begin), expression(end))
+                    }
+                }
+                .unwrap();
+            }
+            out
+        }
+        Expression::EntityConstructor { name, values } => {
+            format!("{}({})", name, expressions(values))
+        }
+        Expression::Interval {
+            op_low,
+            op_high,
+            high,
+            low,
+            item,
+        } => {
+            let op = |op: &ast::IntervalOperator| match op {
+                ast::IntervalOperator::LessThan => "<",
+                ast::IntervalOperator::LessThanEqual => "<=",
+            };
+            format!(
+                "{{{} {} {} {} {}}}",
+                operand(low),
+                op(op_low),
+                operand(item),
+                op(op_high),
+                operand(high)
+            )
+        }
+        Expression::EnumerationReference { ty, enum_ref } => match ty {
+            Some(ty) => format!("{}.{}", ty, enum_ref),
+            None => enum_ref.clone(),
+        },
+        Expression::AggregateInitializer { elements } => {
+            let elements: Vec<String> = elements
+                .iter()
+                .map(|element| match &element.repetition {
+                    Some(repetition) => {
+                        format!("{} : {}", expression(&element.expr), operand(repetition))
+                    }
+                    None => expression(&element.expr),
+                })
+                .collect();
+            format!("[{}]", elements.join(", "))
+        }
+        Expression::Query {
+            variable,
+            source,
+            expr,
+        } => format!(
+            "QUERY({} <* {} | {})",
+            variable,
+            operand(source),
+            expression(expr)
+        ),
+    }
+}
+
+fn expressions(exprs: &[Expression]) -> String {
+    exprs.iter().map(expression).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::SyntaxTree, ir::IR};
+
+    #[test]
+    fn round_trip() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE positive = INTEGER;
+              END_TYPE;
+              TYPE id_select = SELECT (positive, item);
+              END_TYPE;
+              ENTITY base ABSTRACT SUPERTYPE;
+                values: ARRAY [1:3] OF OPTIONAL REAL;
+              END_ENTITY;
+              ENTITY item SUBTYPE OF (base);
+                name: STRING;
+                parts: BAG [1:?] OF item;
+                owner: OPTIONAL GENERIC_ENTITY;
+              DERIVE
+                total: INTEGER := SIZEOF(QUERY(p <* parts | p.name LIKE 'a''b*')) * -2;
+              INVERSE
+                used_by: item FOR parts;
+              WHERE
+                {0 <= SIZEOF(parts) < 10};
+                wr2: (values[1] > 1.5E-3) OR NOT EXISTS(SELF\base.values[2]);
+                wr3: 'S.ITEM' IN TYPEOF(SELF) XOR (parts :<>: [SELF : 2]);
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let printed = ir.to_express();
+        let st = SyntaxTree::parse(&printed).unwrap();
+        assert_eq!(IR::from_syntax_tree(&st).unwrap(), ir);
+    }
+}
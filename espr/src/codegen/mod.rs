@@ -1,3 +1,4 @@
 //! Code generation
 
+pub mod express;
 pub mod rust;
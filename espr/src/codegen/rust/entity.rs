@@ -1,10 +1,10 @@
+use super::{AttributeOrder, CodegenOptions, TargetEdition};
 use crate::ir::*;
 
-use check_keyword::CheckKeyword;
 use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::*;
-use syn::parse_quote;
+use syn::{ext::IdentExt, parse_quote};
 
 // Each component of Rust struct corresponding to ENTITY in EXPRESS
 struct Field {
@@ -19,26 +19,59 @@ struct Field {
 ///
 /// - a simple type
 /// - an enumeration
-/// - a set or list whose base type use place holder
+/// - an aggregate whose base type does not use place holder
 ///
 fn use_place_holder(ty: &TypeRef) -> bool {
     match ty {
         TypeRef::SimpleType(..) => false,
         TypeRef::Named { is_enumerate, .. } => !*is_enumerate,
-        TypeRef::Set { base, .. } | TypeRef::List { base, .. } => use_place_holder(base),
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. }
+        | TypeRef::Aggregate { base, .. } => use_place_holder(base),
         _ => true,
     }
 }
 
-impl From<EntityAttribute> for Field {
-    fn from(attr: EntityAttribute) -> Self {
-        let EntityAttribute { name, ty, optional } = attr;
+/// Type `Rc<T>` for an entity reference `T`, or `Vec<Rc<T>>` for aggregates of them
+fn shared_type(ty: &TypeRef) -> Option<syn::Type> {
+    match ty {
+        TypeRef::Entity { .. } => Some(parse_quote! { ::std::rc::Rc<#ty> }),
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Aggregate { base, .. }
+            if matches!(**base, TypeRef::Entity { .. }) =>
+        {
+            Some(parse_quote! { Vec<::std::rc::Rc<#base>> })
+        }
+        _ => None,
+    }
+}
 
-        let name = format_ident!("{}", name.into_safe());
-        let attributes = if use_place_holder(&ty) {
-            vec![parse_quote! { #[holder(use_place_holder)] }]
-        } else {
-            Vec::new()
+impl Field {
+    fn new(attr: EntityAttribute, options: &CodegenOptions) -> Self {
+        let EntityAttribute {
+            name,
+            ty,
+            optional,
+            is_recursive,
+        } = attr;
+
+        let ident = options.target_edition.ident(&name);
+        let mut attributes: Vec<syn::Attribute> = Vec::new();
+        if use_place_holder(&ty) {
+            attributes.push(parse_quote! { #[holder(use_place_holder)] });
+        }
+        // Suffixed keywords, e.g. `crate_`, keep their EXPRESS names
+        if ident.unraw() != name {
+            attributes.push(parse_quote! { #[holder(rename = #name)] });
+        }
+        let ty: syn::Type = match shared_type(&ty) {
+            Some(shared) if options.shared_references => shared,
+            _ if is_recursive => parse_quote! { Box<#ty> },
+            _ => parse_quote! { #ty },
         };
         let ty = if optional {
             parse_quote! { Option<#ty> }
@@ -47,7 +80,7 @@ impl From<EntityAttribute> for Field {
         };
 
         Field {
-            name,
+            name: ident,
             ty,
             attributes,
         }
@@ -82,15 +115,18 @@ impl Entity {
     }
 
     /// Field identifier
-    fn field_ident(&self) -> syn::Ident {
-        format_ident!("{}", self.name.as_str().into_safe())
+    fn field_ident(&self, edition: TargetEdition) -> syn::Ident {
+        edition.ident(&self.name)
     }
 
     /// Generate declaration of `XxxAny` enum
-    fn generate_any_enum(&self, tokens: &mut TokenStream) {
+    fn generate_any_enum(&self, options: &CodegenOptions, tokens: &mut TokenStream) {
         let any = self.any_ident();
+        let non_exhaustive = super::type_decl::non_exhaustive(options);
+        let holder_attributes = options.holder_attributes();
+        let type_name = &self.name;
 
-        let mut fields = vec![format_ident!("{}", self.name.as_str().into_safe())];
+        let mut fields = vec![self.field_ident(options.target_edition)];
         let mut variants = vec![format_ident!("{}", self.name.to_pascal_case())];
         let mut constraints = vec![format_ident!("{}", self.name.to_pascal_case())];
 
@@ -99,7 +135,7 @@ impl Entity {
                 TypeRef::Entity {
                     name, is_supertype, ..
                 } => {
-                    fields.push(format_ident!("{}", name.as_str().into_safe()));
+                    fields.push(options.target_edition.ident(name));
                     variants.push(format_ident!("{}", name.to_pascal_case()));
                     if *is_supertype {
                         constraints.push(format_ident!("{}Any", name.to_pascal_case()));
@@ -114,7 +150,9 @@ impl Entity {
         tokens.append_all(quote! {
             #[derive(Debug, Clone, PartialEq, Holder)]
             #[holder(table = Tables)]
-            #[holder(generate_deserialize)]
+            #[holder(type_name = #type_name)]
+            #holder_attributes
+            #non_exhaustive
             pub enum #any {
                 #(
                 #[holder(use_place_holder)]
@@ -200,7 +238,7 @@ impl Entity {
         }
     }
 
-    fn supertype_fields(&self) -> Vec<Field> {
+    fn supertype_fields(&self, edition: TargetEdition) -> Vec<Field> {
         self.supertypes
             .iter()
             .map(|ty| {
@@ -213,13 +251,11 @@ impl Entity {
                     attributes.push(parse_quote! { #[deref_mut] });
                 }
                 attributes.push(parse_quote! { #[holder(use_place_holder)] });
+                attributes.push(parse_quote! { #[holder(supertype)] });
                 let (name, ty) = match ty {
                     TypeRef::Named { name, .. } | TypeRef::Entity { name, .. } => {
                         let ty = format_ident!("{}", name.to_pascal_case());
-                        (
-                            format_ident!("{}", name.as_str().into_safe()),
-                            parse_quote! { #ty },
-                        )
+                        (edition.ident(name), parse_quote! { #ty })
                     }
                     _ => unreachable!(),
                 };
@@ -233,6 +269,114 @@ impl Entity {
             .collect()
     }
 
+    /// Generate `{attr}_at` and `{attr}_at_mut` accessors of `ARRAY` attributes by EXPRESS index
+    ///
+    /// `ARRAY [1:3]` is stored in `Vec` indexed from 0,
+    /// and the accessors subtract the lower bound from the index.
+    fn generate_array_accessors(&self, edition: TargetEdition, tokens: &mut TokenStream) {
+        let accessors: Vec<_> = self
+            .attributes
+            .iter()
+            .filter_map(|attr| {
+                let TypeRef::Array {
+                    base,
+                    bound:
+                        Some(Bound {
+                            lower: Some(lower), ..
+                        }),
+                    optional,
+                    ..
+                } = &attr.ty
+                else {
+                    return None;
+                };
+                let field = edition.ident(&attr.name);
+                let at = format_ident!("{}_at", attr.name);
+                let at_mut = format_ident!("{}_at_mut", attr.name);
+                let element: syn::Type = if *optional {
+                    parse_quote! { Option<#base> }
+                } else {
+                    parse_quote! { #base }
+                };
+                let (elements, elements_mut) = if attr.optional {
+                    (
+                        quote! { self.#field.as_ref()? },
+                        quote! { self.#field.as_mut()? },
+                    )
+                } else {
+                    (quote! { self.#field }, quote! { self.#field })
+                };
+                let doc = format!(
+                    "Element of `{}` by EXPRESS index starting from {}",
+                    attr.name, lower
+                );
+                // `TryFrom` is not in the prelude of the 2018 edition
+                let try_from = if edition == TargetEdition::Edition2018 {
+                    quote! { <usize as ::std::convert::TryFrom<i64>>::try_from }
+                } else {
+                    quote! { usize::try_from }
+                };
+                Some(quote! {
+                    #[doc = #doc]
+                    pub fn #at(&self, index: i64) -> Option<&#element> {
+                        let index = #try_from(index - #lower).ok()?;
+                        #elements.get(index)
+                    }
+                    #[doc = #doc]
+                    pub fn #at_mut(&mut self, index: i64) -> Option<&mut #element> {
+                        let index = #try_from(index - #lower).ok()?;
+                        #elements_mut.get_mut(index)
+                    }
+                })
+            })
+            .collect();
+        if accessors.is_empty() {
+            return;
+        }
+        let name = self.name_ident();
+        tokens.append_all(quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        });
+    }
+
+    /// Generate accessors of `DERIVE` attributes folded into constants of simple types
+    ///
+    /// Attributes derived from other attributes are not generated since they have to be evaluated.
+    fn generate_derived_accessors(&self, edition: TargetEdition, tokens: &mut TokenStream) {
+        let accessors: Vec<_> = self
+            .derived_attributes
+            .iter()
+            .filter_map(|derived| {
+                let TypeRef::SimpleType(ty) = &derived.ty else {
+                    return None;
+                };
+                let literal = constant_literal(&ty.0, derived.value.as_ref()?)?;
+                let name = edition.ident(&derived.name);
+                let doc = format!(
+                    "Derived attribute `{}` folded into a constant",
+                    derived.name
+                );
+                Some(quote! {
+                    #[doc = #doc]
+                    pub fn #name(&self) -> #ty {
+                        #literal
+                    }
+                })
+            })
+            .collect();
+        if accessors.is_empty() {
+            return;
+        }
+        let name = self.name_ident();
+        tokens.append_all(quote! {
+            impl #name {
+                #(#accessors)*
+            }
+        });
+    }
+
     fn derives(&self) -> Vec<syn::Path> {
         let mut derives = vec![
             syn::parse_str("Debug").unwrap(),
@@ -253,10 +397,87 @@ impl Entity {
     }
 }
 
+/// Rust expression of a folded constant as a value of the simple type
+fn constant_literal(
+    ty: &crate::ast::SimpleType,
+    value: &crate::eval::Value,
+) -> Option<TokenStream> {
+    use crate::{ast::SimpleType, eval::Value};
+    use proc_macro2::Literal;
+    let integer = |value: f64| {
+        (value.fract() == 0.0 && value.abs() < i64::MAX as f64).then_some(value as i64)
+    };
+    Some(match (ty, value) {
+        (SimpleType::Real, Value::Real(value)) if value.is_finite() => real_literal(*value),
+        (SimpleType::Real, Value::Integer(value)) => {
+            Literal::f64_unsuffixed(*value as f64).into_token_stream()
+        }
+        (SimpleType::Integer, Value::Integer(value)) => {
+            Literal::i64_unsuffixed(*value).into_token_stream()
+        }
+        (SimpleType::Integer, Value::Real(value)) => {
+            Literal::i64_unsuffixed(integer(*value)?).into_token_stream()
+        }
+        (SimpleType::Number, Value::Integer(value)) => {
+            let value = Literal::i64_unsuffixed(*value);
+            quote! { Number::Int(#value) }
+        }
+        (SimpleType::Number, Value::Real(value)) if value.is_finite() => {
+            let value = real_literal(*value);
+            quote! { Number::Real(#value) }
+        }
+        (SimpleType::Boolen, Value::Logical(crate::ast::Logical::True)) => quote! { true },
+        (SimpleType::Boolen, Value::Logical(crate::ast::Logical::False)) => quote! { false },
+        (SimpleType::Logical, Value::Logical(logical)) => {
+            let variant = format_ident!("{}", format!("{:?}", logical));
+            quote! { Logical::#variant }
+        }
+        (SimpleType::String_ { .. }, Value::String(value)) => quote! { #value.to_string() },
+        _ => return None,
+    })
+}
+
+/// Real number literal, or a constant in `std::f64::consts` if equal to it, e.g. for `2 * PI`
+///
+/// A literal of these values is denied by `clippy::approx_constant`.
+fn real_literal(value: f64) -> TokenStream {
+    use std::f64::consts::*;
+    let constants = [
+        ("PI", PI),
+        ("TAU", TAU),
+        ("E", E),
+        ("FRAC_PI_2", FRAC_PI_2),
+        ("FRAC_PI_3", FRAC_PI_3),
+        ("FRAC_PI_4", FRAC_PI_4),
+        ("FRAC_PI_6", FRAC_PI_6),
+        ("FRAC_PI_8", FRAC_PI_8),
+        ("FRAC_1_PI", FRAC_1_PI),
+        ("FRAC_2_PI", FRAC_2_PI),
+        ("SQRT_2", SQRT_2),
+        ("LN_2", LN_2),
+        ("LN_10", LN_10),
+    ];
+    match constants.iter().find(|(_, c)| *c == value.abs()) {
+        Some((name, _)) => {
+            let name = format_ident!("{}", name);
+            let sign = (value < 0.0).then(|| quote! { - });
+            quote! { #sign ::std::f64::consts::#name }
+        }
+        None => proc_macro2::Literal::f64_unsuffixed(value).into_token_stream(),
+    }
+}
+
 impl ToTokens for Entity {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl Entity {
+    pub(super) fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
+        let mut tokens = TokenStream::new();
         let name = self.name_ident();
-        let field_name = self.field_ident();
+        let field_name = self.field_ident(options.target_edition);
 
         // Each component of struct is called "field" in Rust,
         // and "attribute" refers other items
@@ -265,29 +486,39 @@ impl ToTokens for Entity {
         let fields = self
             .attributes
             .iter()
-            .map(|attr| Field::from(attr.clone()))
+            .map(|attr| Field::new(attr.clone(), options))
             .collect::<Vec<Field>>();
-        let supertype_fields = self.supertype_fields();
+        let supertype_fields = self.supertype_fields(options.target_edition);
+        let fields: Vec<Field> = match options.attribute_order {
+            AttributeOrder::InheritedFirst => supertype_fields.into_iter().chain(fields).collect(),
+            AttributeOrder::InheritedLast => fields.into_iter().chain(supertype_fields).collect(),
+        };
 
         let derive = self.derives();
+        let holder_attributes = options.holder_attributes();
+        let type_name = &self.name;
 
         tokens.append_all(quote! {
             #( #[derive(#derive)] )*
             #[holder(table = Tables)]
             #[holder(field = #field_name)]
-            #[holder(generate_deserialize)]
+            #[holder(type_name = #type_name)]
+            #holder_attributes
             pub struct #name {
-                #(#supertype_fields,)*
                 #(#fields,)*
             }
         });
 
+        self.generate_array_accessors(options.target_edition, &mut tokens);
+        self.generate_derived_accessors(options.target_edition, &mut tokens);
+
         // Generate `Any` enum if this entity is a supertype of other entities
         if !self.constraints.is_empty() {
-            self.generate_any_enum(tokens);
+            self.generate_any_enum(options, &mut tokens);
             // Generate `impl Into<XxxAny> for Yyy` for self and all constraints
-            self.generate_into_any(tokens);
-            self.generate_asref_from_any(tokens);
+            self.generate_into_any(&mut tokens);
+            self.generate_asref_from_any(&mut tokens);
         }
+        tokens
     }
 }
@@ -1,5 +1,5 @@
 use std::{
-    io::Write,
+    io::{self, Write},
     process::{Command, Stdio},
 };
 
@@ -13,25 +13,30 @@ use std::{
 ///     and thus it must be a bug, i.e. not a runtime error.
 ///
 pub fn rustfmt(tt: String) -> String {
+    try_rustfmt(tt).expect("Failed to format by rustfmt")
+}
+
+/// Fallible version of [rustfmt] returning an error if `rustfmt` is not available or fails.
+pub fn try_rustfmt(tt: String) -> io::Result<String> {
     let mut child = Command::new("rustfmt")
         .stdin(Stdio::piped())
         .stdout(Stdio::piped())
-        .spawn()
-        .expect("Failed to spawn rustfmt process");
+        .stderr(Stdio::piped())
+        .spawn()?;
 
     // Write input from another thread for avoiding deadlock.
     // See https://doc.rust-lang.org/std/process/index.html#handling-io
     let mut stdin = child.stdin.take().expect("Failed to open stdin");
-    std::thread::spawn(move || {
-        stdin
-            .write_all(tt.as_bytes())
-            .expect("Failed to write to stdin");
-    });
-    let output = child
-        .wait_with_output()
-        .expect("Failed to wait output of rustfmt process");
+    let writer = std::thread::spawn(move || stdin.write_all(tt.as_bytes()));
+    let output = child.wait_with_output()?;
+    writer.join().expect("Writer thread panicked")?;
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ));
+    }
 
     // non-UTF8 comment should be handled in the tokenize phase,
     // and not be included in IR.
-    String::from_utf8(output.stdout).expect("rustfmt output contains non-UTF8 input")
+    String::from_utf8(output.stdout).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
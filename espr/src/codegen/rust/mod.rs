@@ -1,4 +1,15 @@
 //! Generate Rust code using proc-macro utility crates
+//!
+//! Each EXPRESS schema is generated as a Rust module, e.g. `pub mod geometry { ... }`,
+//! and items imported by `USE FROM` or `REFERENCE FROM` are re-exported
+//! from the module of the original schema, e.g. `pub use super::geometry::Point;`.
+//!
+//! Holders look up references `#N` in `Tables` of the schema declaring them,
+//! since each holder is bound to a single table type by `Holder::Table`.
+//! Thus an entity or a `SELECT` type referring entities declared in other schemas,
+//! e.g. `p: pt;` for `USE FROM geometry (point AS pt);`, cannot be resolved by `Tables` of this schema,
+//! and the generated module reports it by `compile_error!` instead of generating tables which cannot read them.
+//! Imported simple types, enumerations and other `TYPE`s without entities can be used freely.
 
 mod entity;
 mod format;
@@ -6,6 +17,7 @@ mod schema;
 mod simple_type;
 mod type_decl;
 mod type_ref;
+mod validate;
 
-pub use format::rustfmt;
+pub use format::{rustfmt, try_rustfmt};
 pub use schema::*;
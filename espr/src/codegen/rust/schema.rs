@@ -20,22 +20,284 @@ impl CratePrefix {
     }
 }
 
+/// Order of fields in generated entity struct
+///
+/// Since the fields are deserialized in this order,
+/// this also determines the order of parameters expected in exchange structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttributeOrder {
+    /// Fields for supertypes come first, followed by explicit attributes in declared order
+    ///
+    /// This is the order defined in ISO 10303-21,
+    /// and exchange structures of common APs, e.g. AP201, AP203 and AP214, use it.
+    #[default]
+    InheritedFirst,
+    /// Explicit attributes in declared order come first, followed by fields for supertypes
+    InheritedLast,
+}
+
+impl std::str::FromStr for AttributeOrder {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "inherited-first" => Ok(AttributeOrder::InheritedFirst),
+            "inherited-last" => Ok(AttributeOrder::InheritedLast),
+            _ => Err(format!(
+                "Unknown attribute order '{}', expected 'inherited-first' or 'inherited-last'",
+                s
+            )),
+        }
+    }
+}
+
+/// Naming of Rust enum variants for items of EXPRESS enumerations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariantNaming {
+    /// `some_multi_word_value` into `SomeMultiWordValue`
+    #[default]
+    PascalCase,
+    /// `some_multi_word_value` into `SOME_MULTI_WORD_VALUE` as written in exchange structure
+    ScreamingSnakeCase,
+}
+
+impl VariantNaming {
+    /// Rust variant name for the enumeration item
+    pub fn variant_name(&self, item: &str) -> String {
+        match self {
+            VariantNaming::PascalCase => item.to_pascal_case(),
+            VariantNaming::ScreamingSnakeCase => item.to_uppercase(),
+        }
+    }
+}
+
+/// Variant name passed by the deserializer of ruststep for the item, e.g. `SomeMultiWordValue` for `.SOME_MULTI_WORD_VALUE.`
+///
+/// Generated variants are renamed by `#[serde(rename = "...")]` if they differ from this.
+pub fn deserialized_variant_name(item: &str) -> String {
+    item.to_uppercase().to_pascal_case()
+}
+
+impl std::str::FromStr for VariantNaming {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pascal-case" => Ok(VariantNaming::PascalCase),
+            "screaming-snake-case" => Ok(VariantNaming::ScreamingSnakeCase),
+            _ => Err(format!(
+                "Unknown variant naming '{}', expected 'pascal-case' or 'screaming-snake-case'",
+                s
+            )),
+        }
+    }
+}
+
+/// Deserialization of generated holders from exchange structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeserializeBackend {
+    /// `serde::Deserialize` implementations by `#[holder(generate_deserialize)]`
+    #[default]
+    Serde,
+    /// Hand-rolled `ruststep::tables::FromParameter` implementations by `#[holder(generate_from_parameter)]`
+    ///
+    /// Generated code does not use serde, e.g. enumerations do not derive `serde::Deserialize`.
+    FromParameter,
+}
+
+impl DeserializeBackend {
+    /// `#[holder(...)]` attribute for generated structs and enums
+    pub(super) fn holder_attribute(&self) -> TokenStream {
+        match self {
+            DeserializeBackend::Serde => quote! { #[holder(generate_deserialize)] },
+            DeserializeBackend::FromParameter => quote! { #[holder(generate_from_parameter)] },
+        }
+    }
+}
+
+impl std::str::FromStr for DeserializeBackend {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "serde" => Ok(DeserializeBackend::Serde),
+            "from-parameter" => Ok(DeserializeBackend::FromParameter),
+            _ => Err(format!(
+                "Unknown deserialize backend '{}', expected 'serde' or 'from-parameter'",
+                s
+            )),
+        }
+    }
+}
+
+/// Rust edition of the crate including generated code
+///
+/// The generated code differs only in the following constructs:
+///
+/// - [TargetEdition::Edition2018] does not have `TryFrom` in the prelude,
+///   and the accessors of `ARRAY` attributes call it by the full path `::std::convert::TryFrom`.
+/// - [TargetEdition::Edition2024] reserves `gen` as a keyword,
+///   and identifiers from EXPRESS named `gen` are escaped into `r#gen`.
+///
+/// `#[non_exhaustive]` by [CodegenOptions::non_exhaustive] is available in all editions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum TargetEdition {
+    Edition2018,
+    #[default]
+    Edition2021,
+    Edition2024,
+}
+
+impl TargetEdition {
+    /// Identifier of an EXPRESS name, escaped as a raw identifier if it is a keyword in this edition
+    ///
+    /// Keywords which cannot be raw identifiers, e.g. `crate`, are suffixed as `crate_`.
+    pub(super) fn ident(&self, name: &str) -> syn::Ident {
+        if *self >= TargetEdition::Edition2024 && name == "gen" {
+            return format_ident!("r#gen");
+        }
+        if matches!(name, "crate" | "self" | "super" | "Self") {
+            return format_ident!("{}_", name);
+        }
+        format_ident!("{}", name.into_safe())
+    }
+}
+
+impl std::str::FromStr for TargetEdition {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2018" => Ok(TargetEdition::Edition2018),
+            "2021" => Ok(TargetEdition::Edition2021),
+            "2024" => Ok(TargetEdition::Edition2024),
+            _ => Err(format!(
+                "Unknown target edition '{}', expected '2018', '2021' or '2024'",
+                s
+            )),
+        }
+    }
+}
+
+/// Options for Rust code generation
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CodegenOptions {
+    pub attribute_order: AttributeOrder,
+
+    /// Add `#[non_exhaustive]` to generated enums, i.e. `SELECT` types, enumerations and `XxxAny` enums
+    ///
+    /// Adding variants in a revision of the schema is not a breaking change for crates using them,
+    /// but these crates must have a wildcard arm `_ => ...` in every `match` on the enums.
+    /// Matches in the crate containing the generated code are not affected.
+    pub non_exhaustive: bool,
+
+    /// Naming of Rust enum variants for items of EXPRESS enumerations, see [VariantNaming]
+    pub variant_naming: VariantNaming,
+
+    /// Use `Rc<T>` for entity references in generated structs
+    ///
+    /// References to the same instance are resolved into the same `Rc`
+    /// through the `SharedCache` of the tables, instead of a copy for each reference.
+    /// This applies to attributes of entity types and aggregates of them.
+    pub shared_references: bool,
+
+    /// Deserialization of generated holders from exchange structure, see [DeserializeBackend]
+    pub deserialize_backend: DeserializeBackend,
+
+    /// Rust edition of the crate containing the generated code, see [TargetEdition]
+    pub target_edition: TargetEdition,
+}
+
+impl CodegenOptions {
+    /// `#[holder(...)]` attributes for generated structs and enums
+    ///
+    /// `ruststep::tables::IntoParameter` and `ruststep::tables::FromOwned` are always generated
+    /// in addition to the deserialization backend.
+    pub(super) fn holder_attributes(&self) -> TokenStream {
+        let deserialize = self.deserialize_backend.holder_attribute();
+        quote! {
+            #deserialize
+            #[holder(generate_into_parameter)]
+            #[holder(generate_from_owned)]
+        }
+    }
+}
+
 impl IR {
     pub fn to_token_stream(&self, prefix: CratePrefix) -> TokenStream {
+        self.to_token_stream_with_options(prefix, &CodegenOptions::default())
+    }
+
+    pub fn to_token_stream_with_options(
+        &self,
+        prefix: CratePrefix,
+        options: &CodegenOptions,
+    ) -> TokenStream {
         let schemas: Vec<_> = self
             .schemas
             .iter()
-            .map(|schema| schema.to_token_stream(prefix))
+            .map(|schema| schema.to_token_stream_with_options(prefix, options))
             .collect();
-        quote! { #(#schemas)* }
+        let identifiers: Vec<_> = self
+            .schemas
+            .iter()
+            .map(|schema| schema.name.to_uppercase())
+            .collect();
+        quote! {
+            /// Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record
+            pub const SCHEMA_IDENTIFIERS: &[&str] = &[#(#identifiers),*];
+            #(#schemas)*
+        }
     }
 }
 
 impl Schema {
     pub fn to_token_stream(&self, prefix: CratePrefix) -> TokenStream {
+        self.to_token_stream_with_options(prefix, &CodegenOptions::default())
+    }
+
+    pub fn to_token_stream_with_options(
+        &self,
+        prefix: CratePrefix,
+        options: &CodegenOptions,
+    ) -> TokenStream {
         let name = format_ident!("{}", self.name);
-        let types = &self.types;
+        let types: Vec<_> = self
+            .types
+            .iter()
+            .map(|ty| ty.to_token_stream_with_options(options))
+            .collect();
         let entities = &self.entities;
+        let ruststep_path = prefix.as_path();
+        let entity_tokens: Vec<_> = entities
+            .iter()
+            .map(|entity| {
+                let mut tokens = entity.to_token_stream_with_options(options);
+                tokens.append_all(entity.generate_validate(&ruststep_path, options.target_edition));
+                tokens
+            })
+            .collect();
+        let enumeration_text: Vec<_> = self
+            .types
+            .iter()
+            .filter_map(|ty| match ty {
+                TypeDecl::Enumeration(e) => {
+                    let text_impls = e.generate_text_impls(&ruststep_path, options);
+                    let into_parameter = e.generate_into_parameter(&ruststep_path);
+                    Some(quote! { #text_impls #into_parameter })
+                }
+                _ => None,
+            })
+            .collect();
+        let from_parameter: Vec<_> = match options.deserialize_backend {
+            DeserializeBackend::Serde => Vec::new(),
+            DeserializeBackend::FromParameter => self
+                .types
+                .iter()
+                .filter_map(|ty| match ty {
+                    TypeDecl::Enumeration(e) => {
+                        Some(e.generate_from_parameter(&ruststep_path, options))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        };
         let type_decls = self
             .types
             .iter()
@@ -51,44 +313,356 @@ impl Schema {
             .collect();
         let holder_name: Vec<_> = entities
             .iter()
-            .map(|e| format_ident!("{}", e.name.as_str().into_safe()))
+            .map(|e| options.target_edition.ident(&e.name))
             .chain(
                 type_decls
                     .clone()
-                    .map(|e| format_ident!("{}", e.id().into_safe())),
+                    .map(|e| options.target_edition.ident(e.id())),
             )
             .collect();
+        // Only entities have domain rules checked by `Validate`
+        let table_attributes: Vec<_> = entities
+            .iter()
+            .map(|e| {
+                if self.has_domain_rules(e) {
+                    quote! { #[table(validate)] }
+                } else {
+                    quote! {}
+                }
+            })
+            .chain(type_decls.clone().map(|_| quote! {}))
+            .collect();
         let holders_name: Vec<_> = entities
             .iter()
             .map(|e| format_ident!("{}_holders", e.name))
             .chain(type_decls.map(|e| format_ident!("{}_holders", e.id())))
             .collect();
 
-        let ruststep_path = prefix.as_path();
+        let shared_cache = if options.shared_references {
+            quote! {
+                #[table(shared_cache)]
+                shared_cache: #ruststep_path::tables::SharedCache,
+            }
+        } else {
+            quote! {}
+        };
+
+        // `IntoParameter` is always generated for holders, see `CodegenOptions::holder_attributes`
+        let tables_attribute = match options.deserialize_backend {
+            DeserializeBackend::Serde => quote! { #[table(into_parameter)] },
+            DeserializeBackend::FromParameter => {
+                quote! { #[table(from_parameter)] #[table(into_parameter)] }
+            }
+        };
+
+        let identifier = self.name.to_uppercase();
+        let inverse_accessors = self.inverse_accessors(&ruststep_path, options.target_edition);
+        let aliases = self.aliases.iter().filter_map(|alias| {
+            let (name, scope, suffixes) = match &alias.ty {
+                TypeRef::Entity {
+                    name,
+                    scope,
+                    is_supertype,
+                } => {
+                    let suffixes: &[&str] = if *is_supertype {
+                        &["", "Holder", "Any", "AnyHolder"]
+                    } else {
+                        &["", "Holder"]
+                    };
+                    (name, scope, suffixes)
+                }
+                TypeRef::Named {
+                    name,
+                    scope,
+                    is_enumerate,
+                    ..
+                } => {
+                    let suffixes: &[&str] = if *is_enumerate {
+                        &[""]
+                    } else {
+                        &["", "Holder"]
+                    };
+                    (name, scope, suffixes)
+                }
+                // Built-in simple types, e.g. `USE FROM other (real);`, are not declared in schemas,
+                // and attributes of the local name are generated with the simple type
+                _ => return None,
+            };
+            let schema = format_ident!("{}", scope.schema_name()?);
+            let doc = if *name == alias.name {
+                format!("`{}.{}` imported", scope, name)
+            } else {
+                format!("`{}.{}` imported as `{}`", scope, name, alias.name)
+            };
+            let local: Vec<_> = suffixes
+                .iter()
+                .map(|suffix| format_ident!("{}{}", alias.name.to_pascal_case(), suffix))
+                .collect();
+            let original: Vec<_> = suffixes
+                .iter()
+                .map(|suffix| format_ident!("{}{}", name.to_pascal_case(), suffix))
+                .collect();
+            let items = local.iter().zip(&original).map(|(local, original)| {
+                if local == original {
+                    quote! { #original }
+                } else {
+                    quote! { #original as #local }
+                }
+            });
+            Some(quote! {
+                #[doc = #doc]
+                pub use super::#schema::{#(#items),*};
+            })
+        });
+        let imported_schemas = self
+            .imported_schemas
+            .iter()
+            .map(|schema| format_ident!("{}", schema));
+        let unresolvable = self.foreign_references().into_iter().map(|(item, ty)| {
+            let message = format!(
+                "`{}` refers `{}` declared in another schema, whose references `#N` cannot be resolved by `{}::Tables`",
+                item, ty, self.name
+            );
+            quote! { compile_error!(#message); }
+        });
 
         quote! {
             pub mod #name {
                 use #ruststep_path::{as_holder, Holder, TableInit, primitive::*, derive_more::*};
                 use std::collections::HashMap;
 
+                #(pub use super::#imported_schemas::*;)*
+                #(#aliases)*
+                #(#unresolvable)*
+
                 #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+                #tables_attribute
                 pub struct Tables {
                     #(
-                    #holder_name: HashMap<u64, as_holder!(#entity_types)>,
+                    #table_attributes
+                    #holder_name: HashMap<#ruststep_path::tables::InstanceId, as_holder!(#entity_types)>,
                     )*
+                    #shared_cache
                 }
 
                 impl Tables {
+                    /// Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record
+                    pub const SCHEMA_IDENTIFIER: &str = #identifier;
+
                     #(
-                    pub fn #holders_name(&self) -> &HashMap<u64, as_holder!(#entity_types)> {
+                    pub fn #holders_name(&self) -> &HashMap<#ruststep_path::tables::InstanceId, as_holder!(#entity_types)> {
                         &self.#holder_name
                     }
                     )*
+
+                    #(#inverse_accessors)*
                 }
 
                 #(#types)*
-                #(#entities)*
+                #(#enumeration_text)*
+                #(#from_parameter)*
+                #(#entity_tokens)*
+            }
+        }
+    }
+
+    /// Accessors `inverse_{attr}` computing inverse attributes by scanning the tables
+    ///
+    /// The accessor is named `inverse_{entity}_{attr}` if the attribute name is shared by several entities.
+    /// Inverse attributes through inherited attributes or `SELECT` types are not supported yet.
+    fn inverse_accessors(
+        &self,
+        ruststep_path: &syn::Path,
+        edition: TargetEdition,
+    ) -> Vec<TokenStream> {
+        let scope = Scope::root().schema(&self.name);
+        let inverses: Vec<_> = self
+            .entities
+            .iter()
+            .flat_map(|entity| {
+                entity
+                    .inverse_attributes
+                    .iter()
+                    .map(move |inv| (entity, inv))
+            })
+            .filter(|(_, inv)| !inv.is_inherited)
+            .filter_map(|(entity, inv)| {
+                let dest = match &inv.dest {
+                    TypeRef::Entity { name, scope: s, .. } if *s == scope => {
+                        self.entities.iter().find(|e| &e.name == name)?
+                    }
+                    _ => return None,
+                };
+                let attr = dest.attributes.iter().find(|a| a.name == inv.attribute)?;
+                if !refers_entity(&attr.ty) {
+                    return None;
+                }
+                Some((entity, inv, dest))
+            })
+            .collect();
+
+        inverses
+            .iter()
+            .map(|(entity, inv, dest)| {
+                let shared = inverses.iter().filter(|(_, i, _)| i.name == inv.name).count() > 1;
+                let accessor = if shared {
+                    format_ident!("inverse_{}_{}", entity.name, inv.name)
+                } else {
+                    format_ident!("inverse_{}", inv.name)
+                };
+                let attr_field = edition.ident(&inv.attribute);
+                let scans = self.subtype_paths(dest).into_iter().map(|(source, path)| {
+                    let table = edition.ident(&source.name);
+                    // Follow the supertype parts inlined in the holder of the subtype
+                    let contains = path.iter().rev().fold(
+                        quote! { holder.#attr_field.contains_ref(id) },
+                        |inner, supertype| {
+                            let field = edition.ident(supertype);
+                            quote! {
+                                match &holder.#field {
+                                    #ruststep_path::tables::PlaceHolder::Owned(holder) => #inner,
+                                    #ruststep_path::tables::PlaceHolder::Ref(_) => false,
+                                }
+                            }
+                        },
+                    );
+                    quote! {
+                        ids.extend(
+                            self.#table
+                                .iter()
+                                .filter(|(_, holder)| #contains)
+                                .map(|(id, _)| *id),
+                        );
+                    }
+                });
+                let doc = format!(
+                    "Instances of `{}` and its subtypes referring `{}` by `{}`, i.e. inverse attribute `{}.{}`",
+                    dest.name, entity.name, inv.attribute, entity.name, inv.name
+                );
+                quote! {
+                    #[doc = #doc]
+                    pub fn #accessor(&self, id: #ruststep_path::tables::InstanceId) -> Vec<#ruststep_path::tables::InstanceId> {
+                        use #ruststep_path::tables::ContainsRef;
+                        let mut ids = Vec::new();
+                        #(#scans)*
+                        ids.sort();
+                        ids
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl Schema {
+    /// Items of this schema referring entities, or `SELECT` types of them, declared in other schemas
+    ///
+    /// Holders of such entities are bound to `Tables` of the schemas declaring them by `Holder::Table`,
+    /// and thus cannot be looked up in `Tables` of this schema. Returns pairs of the item and the referred type,
+    /// e.g. `("drawing.line.p", "geometry.point")`.
+    fn foreign_references(&self) -> Vec<(String, String)> {
+        let scope = Scope::root().schema(&self.name);
+        let foreign = |ty: &TypeRef| -> Option<String> {
+            let mut ty = ty;
+            while let Some(base) = ty.aggregate_base() {
+                ty = base;
+            }
+            match ty {
+                TypeRef::Entity { name, scope: s, .. }
+                | TypeRef::Named {
+                    name,
+                    scope: s,
+                    is_simple: false,
+                    is_enumerate: false,
+                } if *s != scope && name != GENERIC_ENTITY => Some(format!("{}.{}", s, name)),
+                _ => None,
+            }
+        };
+        let mut references = Vec::new();
+        for entity in &self.entities {
+            let item = format!("{}.{}", scope, entity.name);
+            for ty in &entity.supertypes {
+                references.extend(foreign(ty).map(|ty| (item.clone(), ty)));
+            }
+            for attr in &entity.attributes {
+                let item = format!("{}.{}", item, attr.name);
+                references.extend(foreign(&attr.ty).map(|ty| (item, ty)));
+            }
+        }
+        for decl in &self.types {
+            let item = format!("{}.{}", scope, decl.id());
+            let types = match decl {
+                TypeDecl::Rename(Rename { ty, .. }) => std::slice::from_ref(ty),
+                TypeDecl::Select(Select { types, .. }) => types.as_slice(),
+                TypeDecl::Simple(_) | TypeDecl::Enumeration(_) => &[],
+            };
+            for ty in types {
+                references.extend(foreign(ty).map(|ty| (item.clone(), ty)));
             }
         }
+        references
+    }
+
+    /// True if `entity` or its supertypes in this schema have domain rules in `WHERE` clauses
+    fn has_domain_rules(&self, entity: &Entity) -> bool {
+        let scope = Scope::root().schema(&self.name);
+        let mut entities = vec![entity];
+        let mut i = 0;
+        while i < entities.len() {
+            if !entities[i].domain_rules.is_empty() {
+                return true;
+            }
+            for ty in &entities[i].supertypes {
+                let TypeRef::Entity { name, scope: s, .. } = ty else {
+                    continue;
+                };
+                if *s != scope || entities.iter().any(|e| e.name == *name) {
+                    continue;
+                }
+                if let Some(supertype) = self.entities.iter().find(|e| e.name == *name) {
+                    entities.push(supertype);
+                }
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// `entity` and its subtypes in this schema with the names of supertypes
+    /// from each subtype to `entity`, e.g. `(subsub, [sub, base])` for `base`
+    ///
+    /// A subtype inheriting `entity` in several ways appears only once.
+    fn subtype_paths<'a>(&'a self, entity: &'a Entity) -> Vec<(&'a Entity, Vec<&'a str>)> {
+        let scope = Scope::root().schema(&self.name);
+        let mut paths = vec![(entity, Vec::new())];
+        let mut i = 0;
+        while i < paths.len() {
+            let (supertype, path) = paths[i].clone();
+            for sub in &self.entities {
+                let inherits = sub.supertypes.iter().any(|ty| {
+                    matches!(ty, TypeRef::Entity { name, scope: s, .. } if *name == supertype.name && *s == scope)
+                });
+                if inherits && paths.iter().all(|(e, _)| e.name != sub.name) {
+                    let mut path = path.clone();
+                    path.insert(0, supertype.name.as_str());
+                    paths.push((sub, path));
+                }
+            }
+            i += 1;
+        }
+        paths
+    }
+}
+
+/// True if the type is an entity reference possibly wrapped by aggregates
+fn refers_entity(ty: &TypeRef) -> bool {
+    match ty {
+        TypeRef::Entity { .. } => true,
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. }
+        | TypeRef::Aggregate { base, .. } => refers_entity(base),
+        _ => false,
     }
 }
@@ -7,7 +7,7 @@ impl ToTokens for SimpleType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         use crate::ast::SimpleType::*;
         match self.0 {
-            Number => tokens.append(format_ident!("f64")),
+            Number => tokens.append_all(quote! { Number }),
             Real => tokens.append(format_ident!("f64")),
             Integer => tokens.append(format_ident!("i64")),
             Logical => tokens.append_all(quote! { Logical }),
@@ -2,76 +2,116 @@ use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::*;
 
+use super::{CodegenOptions, DeserializeBackend, VariantNaming};
 use crate::ir::*;
 
 impl ToTokens for TypeDecl {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl TypeDecl {
+    pub(super) fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
         match self {
-            TypeDecl::Simple(simple) => simple.to_tokens(tokens),
-            TypeDecl::Rename(rename) => rename.to_tokens(tokens),
-            TypeDecl::Enumeration(e) => e.to_tokens(tokens),
-            TypeDecl::Select(select) => select.to_tokens(tokens),
+            TypeDecl::Simple(simple) => simple.to_token_stream_with_options(options),
+            TypeDecl::Rename(rename) => rename.to_token_stream_with_options(options),
+            TypeDecl::Enumeration(e) => e.to_token_stream_with_options(options),
+            TypeDecl::Select(select) => select.to_token_stream_with_options(options),
         }
     }
 }
 
+/// `#[non_exhaustive]` attribute if enabled
+pub(super) fn non_exhaustive(options: &CodegenOptions) -> TokenStream {
+    if options.non_exhaustive {
+        quote! { #[non_exhaustive] }
+    } else {
+        quote! {}
+    }
+}
+
 impl ToTokens for Simple {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl Simple {
+    fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
         let field_name = format_ident!("{}", &self.id.to_snake_case());
         let id = format_ident!("{}", &self.id.to_pascal_case());
         let ty = &self.ty;
-        let (derive, _) = simple_meta(&field_name);
-        tokens.append_all(quote! {
+        let (derive, _) = simple_meta(&field_name, &self.id, options);
+        quote! {
             #derive
             pub struct #id(pub #ty);
-        });
+        }
     }
 }
 
 impl ToTokens for Rename {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl Rename {
+    fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
         let field_name = format_ident!("{}", &self.id.to_snake_case());
         let id = format_ident!("{}", &self.id.to_pascal_case());
         let ty = &self.ty;
         let (derive, use_place_holder) = match ty {
-            TypeRef::SimpleType(_) => simple_meta(&field_name),
+            TypeRef::SimpleType(_) => simple_meta(&field_name, &self.id, options),
             TypeRef::Named { is_enumerate, .. } => {
                 // Enumeration does not have Holder.
                 if *is_enumerate {
-                    simple_meta(&field_name)
+                    simple_meta(&field_name, &self.id, options)
                 } else {
-                    rename_meta(&field_name)
+                    rename_meta(&field_name, &self.id, options)
                 }
             }
-            _ => rename_meta(&field_name),
+            _ => rename_meta(&field_name, &self.id, options),
         };
 
-        tokens.append_all(quote! {
+        quote! {
             #derive
             pub struct #id(#use_place_holder pub #ty);
-        });
+        }
     }
 }
 
-fn simple_meta(field_name: &syn::Ident) -> (TokenStream, TokenStream) {
+fn simple_meta(
+    field_name: &syn::Ident,
+    type_name: &str,
+    options: &CodegenOptions,
+) -> (TokenStream, TokenStream) {
+    let holder_attributes = options.holder_attributes();
     (
         quote! {
             #[derive(Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, ::ruststep_derive::Holder)]
             #[holder(table = Tables)]
             #[holder(field = #field_name)]
-            #[holder(generate_deserialize)]
+            #[holder(type_name = #type_name)]
+            #holder_attributes
         },
         quote! {},
     )
 }
 
-fn rename_meta(field_name: &syn::Ident) -> (TokenStream, TokenStream) {
+fn rename_meta(
+    field_name: &syn::Ident,
+    type_name: &str,
+    options: &CodegenOptions,
+) -> (TokenStream, TokenStream) {
+    let holder_attributes = options.holder_attributes();
     (
         quote! {
             #[derive(Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, ::ruststep_derive::Holder)]
             #[holder(table = Tables)]
             #[holder(field = #field_name)]
-            #[holder(generate_deserialize)]
+            #[holder(type_name = #type_name)]
+            #holder_attributes
         },
         quote! {#[holder(use_place_holder)]},
     )
@@ -79,23 +119,152 @@ fn rename_meta(field_name: &syn::Ident) -> (TokenStream, TokenStream) {
 
 impl ToTokens for Enumeration {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl Enumeration {
+    fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
+        let non_exhaustive = non_exhaustive(options);
+        let id = format_ident!("{}", &self.id.to_pascal_case());
+        let items = self.items.iter().map(|item| {
+            let name = options.variant_naming.variant_name(item);
+            let variant = format_ident!("{}", name);
+            let deserialized = super::deserialized_variant_name(item);
+            if name == deserialized || options.deserialize_backend != DeserializeBackend::Serde {
+                quote! { #variant }
+            } else {
+                quote! {
+                    #[serde(rename = #deserialized)]
+                    #variant
+                }
+            }
+        });
+        let allow = match options.variant_naming {
+            VariantNaming::PascalCase => quote! {},
+            VariantNaming::ScreamingSnakeCase => quote! { #[allow(non_camel_case_types)] },
+        };
+        let derive = match options.deserialize_backend {
+            DeserializeBackend::Serde => {
+                quote! { #[derive(Debug, Clone, PartialEq, ::serde::Deserialize)] }
+            }
+            DeserializeBackend::FromParameter => quote! { #[derive(Debug, Clone, PartialEq)] },
+        };
+        quote! {
+            #derive
+            #non_exhaustive
+            #allow
+            pub enum #id {
+                #( #items ),*
+            }
+        }
+    }
+
+    /// Generate conversions from and into the text of items
+    ///
+    /// - `to_part21()` returns the item as written in exchange structure with dots, e.g. `.RED.`
+    /// - `Display` writes the bare uppercase name without dots, e.g. `RED`
+    /// - `FromStr` accepts both forms
+    pub(super) fn generate_text_impls(
+        &self,
+        ruststep_path: &syn::Path,
+        options: &CodegenOptions,
+    ) -> TokenStream {
         let id = format_ident!("{}", &self.id.to_pascal_case());
-        let items: Vec<_> = self
+        let items: Vec<_> = self.items.iter().map(|item| item.to_uppercase()).collect();
+        let part21: Vec<_> = items.iter().map(|item| format!(".{}.", item)).collect();
+        let variants: Vec<_> = self
             .items
             .iter()
-            .map(|i| format_ident!("{}", i.to_pascal_case()))
+            .map(|item| format_ident!("{}", options.variant_naming.variant_name(item)))
             .collect();
-        tokens.append_all(quote! {
-            #[derive(Debug, Clone, PartialEq, ::serde::Deserialize)]
-            pub enum #id {
-                #( #items ),*
+        quote! {
+            impl #id {
+                /// Item as written in exchange structure, e.g. `.RED.`
+                pub fn to_part21(&self) -> &'static str {
+                    match self {
+                        #( #id::#variants => #part21, )*
+                    }
+                }
             }
-        });
+
+            impl ::std::fmt::Display for #id {
+                /// Uppercase name of the item without dots, e.g. `RED`
+                fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                    f.write_str(match self {
+                        #( #id::#variants => #items, )*
+                    })
+                }
+            }
+
+            impl ::std::str::FromStr for #id {
+                type Err = #ruststep_path::error::Error;
+                /// Accepts both `RED` and `.RED.`
+                fn from_str(s: &str) -> #ruststep_path::error::Result<Self> {
+                    let item = s.strip_prefix('.').and_then(|s| s.strip_suffix('.')).unwrap_or(s);
+                    match item {
+                        #( #items => Ok(#id::#variants), )*
+                        _ => Err(#ruststep_path::tables::unexpected_parameter::<Self>(
+                            &#ruststep_path::ast::Parameter::Enumeration(item.to_string()),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate `impl FromParameter` matching items as written in exchange structure, e.g. `.RED.`
+    pub(super) fn generate_from_parameter(
+        &self,
+        ruststep_path: &syn::Path,
+        options: &CodegenOptions,
+    ) -> TokenStream {
+        let id = format_ident!("{}", &self.id.to_pascal_case());
+        let items: Vec<_> = self.items.iter().map(|item| item.to_uppercase()).collect();
+        let variants: Vec<_> = self
+            .items
+            .iter()
+            .map(|item| format_ident!("{}", options.variant_naming.variant_name(item)))
+            .collect();
+        quote! {
+            impl #ruststep_path::tables::FromParameter for #id {
+                fn from_parameter(parameter: &#ruststep_path::ast::Parameter) -> #ruststep_path::error::Result<Self> {
+                    match parameter {
+                        #ruststep_path::ast::Parameter::Enumeration(item) => match item.as_str() {
+                            #( #items => Ok(#id::#variants), )*
+                            _ => Err(#ruststep_path::tables::unexpected_parameter::<Self>(parameter)),
+                        },
+                        _ => Err(#ruststep_path::tables::unexpected_parameter::<Self>(parameter)),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generate `impl IntoParameter` into items as written in exchange structure, e.g. `.RED.`
+    pub(super) fn generate_into_parameter(&self, ruststep_path: &syn::Path) -> TokenStream {
+        let id = format_ident!("{}", &self.id.to_pascal_case());
+        quote! {
+            impl #ruststep_path::tables::IntoParameter for #id {
+                fn into_parameter(self) -> #ruststep_path::ast::Parameter {
+                    #ruststep_path::ast::Parameter::Enumeration(self.to_string())
+                }
+            }
+        }
     }
 }
 
 impl ToTokens for Select {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(self.to_token_stream_with_options(&CodegenOptions::default()));
+    }
+}
+
+impl Select {
+    fn to_token_stream_with_options(&self, options: &CodegenOptions) -> TokenStream {
+        let non_exhaustive = non_exhaustive(options);
+        let holder_attributes = options.holder_attributes();
+        let type_name = &self.id;
         let id = format_ident!("{}", &self.id.to_pascal_case());
         let mut entries = Vec::new();
         let mut entry_types = Vec::new();
@@ -128,16 +297,18 @@ impl ToTokens for Select {
                 _ => unimplemented!(),
             }
         }
-        tokens.append_all(quote! {
+        quote! {
             #[derive(Debug, Clone, PartialEq, Holder)]
             #[holder(table = Tables)]
-            #[holder(generate_deserialize)]
+            #[holder(type_name = #type_name)]
+            #holder_attributes
+            #non_exhaustive
             pub enum #id {
                 #(
                 #use_place_holder
                 #entries(#entry_types)
                 ),*
             }
-        });
+        }
     }
 }
@@ -23,9 +23,62 @@ impl ToTokens for TypeRef {
                 };
                 tokens.append_all(quote! { #name });
             }
-            Set { base, .. } | List { base, .. } => {
+            // `AGGREGATE` may be any of `ARRAY`, `BAG`, `LIST` or `SET`, and `Vec` can hold all of them.
+            // `SET` is not `HashSet` since `Hash` and `Eq` cannot be derived for types holding `f64`.
+            Set { base, .. } | Bag { base, .. } | List { base, .. } | Aggregate { base, .. } => {
                 tokens.append_all(quote! { Vec<#base> });
             }
+            Array { base, optional, .. } => {
+                if *optional {
+                    tokens.append_all(quote! { Vec<Option<#base>> });
+                } else {
+                    tokens.append_all(quote! { Vec<#base> });
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{ast::SyntaxTree, codegen::rust::CratePrefix, ir::IR};
+    use quote::quote;
+
+    #[test]
+    fn aggregate_tokens() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              ENTITY point;
+                x: REAL;
+              END_ENTITY;
+              ENTITY a;
+                points: LIST [1:?] OF point;
+                labels: SET [0:?] OF label;
+                tags: OPTIONAL BAG OF SET OF label;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let types: Vec<_> = ir.schemas[0].entities[1]
+            .attributes
+            .iter()
+            .map(|attr| {
+                let ty = &attr.ty;
+                quote! { #ty }.to_string()
+            })
+            .collect();
+        assert_eq!(
+            types,
+            ["Vec < Point >", "Vec < Label >", "Vec < Vec < Label > >"]
+        );
+
+        let tt = ir.to_token_stream(CratePrefix::External).to_string();
+        assert!(tt.contains("pub tags : Option < Vec < Vec < Label > > >"));
+    }
+}
@@ -0,0 +1,586 @@
+use super::TargetEdition;
+use crate::{ast, ir::*};
+
+use inflector::Inflector;
+use proc_macro2::{Literal, TokenStream};
+use quote::*;
+use std::cell::Cell;
+
+/// Rust expression translated from EXPRESS expression with its type
+enum Operand {
+    /// Number literal, which becomes `i64` or `f64` depending on the other operand
+    Number(f64),
+    Integer(TokenStream),
+    Real(TokenStream),
+    String(TokenStream),
+    Boolean(TokenStream),
+}
+
+impl Operand {
+    fn into_real(self) -> Option<TokenStream> {
+        match self {
+            Operand::Number(value) => Some(Literal::f64_unsuffixed(value).into_token_stream()),
+            Operand::Integer(tt) => Some(quote! { (#tt as f64) }),
+            Operand::Real(tt) => Some(tt),
+            _ => None,
+        }
+    }
+
+    fn into_boolean(self) -> Option<TokenStream> {
+        match self {
+            Operand::Boolean(tt) => Some(tt),
+            _ => None,
+        }
+    }
+}
+
+/// Pair of numeric operands converted into the same Rust type
+enum Numeric {
+    Integer(TokenStream, TokenStream),
+    Real(TokenStream, TokenStream),
+}
+
+fn numeric(lhs: Operand, rhs: Operand) -> Option<Numeric> {
+    let integer = |op: &Operand| match op {
+        Operand::Integer(tt) => Some(tt.clone()),
+        Operand::Number(value) if value.fract() == 0.0 => {
+            Some(Literal::i64_unsuffixed(*value as i64).into_token_stream())
+        }
+        _ => None,
+    };
+    match (&lhs, &rhs) {
+        (Operand::Number(_), Operand::Number(_)) => {}
+        (Operand::Integer(_) | Operand::Number(_), Operand::Integer(_) | Operand::Number(_)) => {
+            if let (Some(lhs), Some(rhs)) = (integer(&lhs), integer(&rhs)) {
+                return Some(Numeric::Integer(lhs, rhs));
+            }
+        }
+        _ => {}
+    }
+    Some(Numeric::Real(lhs.into_real()?, rhs.into_real()?))
+}
+
+/// Domain rule translated into Rust
+struct Rule {
+    /// `bool` expression on `self` of owned struct
+    expr: TokenStream,
+    /// The expression uses `?` on checked integer arithmetic,
+    /// and has to be evaluated in a function returning `Option`
+    fallible: bool,
+}
+
+/// Translate a domain rule of the entity into Rust `bool` expression on `self` of owned struct
+///
+/// Only a subset of EXPRESS is supported, i.e. arithmetic, comparisons and logical operators
+/// on explicit attributes of simple types, `EXISTS` and `SIZEOF` on attributes,
+/// and `SIZEOF(QUERY(v <* attr | cond))` on aggregate attributes of simple types.
+/// Returns `None` for other rules, which are not checked.
+fn translate(entity: &Entity, expr: &ast::Expression, edition: TargetEdition) -> Option<Rule> {
+    let ctx = Context {
+        entity,
+        edition,
+        variables: Vec::new(),
+        fallible: Cell::new(false),
+    };
+    let expr = translate_operand(&ctx, expr)?.into_boolean()?;
+    Some(Rule {
+        expr,
+        fallible: ctx.fallible.get(),
+    })
+}
+
+/// Entity of `self` and query variables in scope while translating a rule
+struct Context<'a> {
+    entity: &'a Entity,
+    edition: TargetEdition,
+    /// Query variables bound to elements of aggregates with their types, the innermost last
+    variables: Vec<(&'a str, &'a TypeRef)>,
+    /// Checked integer arithmetic is used, which is indeterminate on overflow
+    fallible: Cell<bool>,
+}
+
+impl<'a> Context<'a> {
+    fn with_variable(&self, name: &'a str, ty: &'a TypeRef) -> Self {
+        let mut variables = self.variables.clone();
+        variables.push((name, ty));
+        Context {
+            entity: self.entity,
+            edition: self.edition,
+            variables,
+            fallible: Cell::new(false),
+        }
+    }
+
+    /// `i64::checked_*` call propagating overflow by `?`
+    fn checked(&self, f: TokenStream, args: TokenStream) -> Operand {
+        self.fallible.set(true);
+        Operand::Integer(quote! { i64::#f(#args)? })
+    }
+
+    /// Query variable referred as `v`, which shadows attributes of the same name
+    fn variable(&self, expr: &ast::Expression) -> Option<Operand> {
+        let ast::Expression::QualifiableFactor {
+            factor: ast::QualifiableFactor::Reference(name),
+            qualifiers,
+        } = expr
+        else {
+            return None;
+        };
+        if !qualifiers.is_empty() {
+            return None;
+        }
+        let (name, ty) = self.variables.iter().rev().find(|(v, _)| v == name)?;
+        let var = self.edition.ident(name);
+        match ty {
+            TypeRef::SimpleType(ty) => match ty.0 {
+                ast::SimpleType::Integer => Some(Operand::Integer(quote! { (*#var) })),
+                ast::SimpleType::Real => Some(Operand::Real(quote! { (*#var) })),
+                ast::SimpleType::Boolen => Some(Operand::Boolean(quote! { (*#var) })),
+                ast::SimpleType::String_ { .. } => Some(Operand::String(quote! { #var.as_str() })),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn translate_operand(ctx: &Context, expr: &ast::Expression) -> Option<Operand> {
+    use ast::{BinaryOperator::*, Expression, RelationOperator::*, UnaryOperator::*};
+    match expr {
+        Expression::Literal(ast::Literal::Integer(value)) => Some(Operand::Number(*value as f64)),
+        Expression::Literal(ast::Literal::Real(value)) => Some(Operand::Number(*value)),
+        Expression::Literal(ast::Literal::String(value)) => {
+            Some(Operand::String(quote! { #value }))
+        }
+        Expression::Literal(ast::Literal::Logial(ast::Logical::True)) => {
+            Some(Operand::Boolean(quote! { true }))
+        }
+        Expression::Literal(ast::Literal::Logial(ast::Logical::False)) => {
+            Some(Operand::Boolean(quote! { false }))
+        }
+        Expression::Unary { op, arg } => {
+            let arg = translate_operand(ctx, arg)?;
+            match (op, arg) {
+                (Not, Operand::Boolean(tt)) => Some(Operand::Boolean(quote! { !(#tt) })),
+                (Plus, arg @ (Operand::Number(_) | Operand::Integer(_) | Operand::Real(_))) => {
+                    Some(arg)
+                }
+                (Minus, Operand::Number(value)) => Some(Operand::Number(-value)),
+                (Minus, Operand::Integer(tt)) => Some(ctx.checked(quote! { checked_neg }, tt)),
+                (Minus, Operand::Real(tt)) => Some(Operand::Real(quote! { (-#tt) })),
+                _ => None,
+            }
+        }
+        Expression::Binary { op, arg1, arg2 } => {
+            let lhs = translate_operand(ctx, arg1)?;
+            let rhs = translate_operand(ctx, arg2)?;
+            match op {
+                And | Or | Xor => {
+                    let lhs = lhs.into_boolean()?;
+                    let rhs = rhs.into_boolean()?;
+                    Some(Operand::Boolean(match op {
+                        And => quote! { (#lhs && #rhs) },
+                        Or => quote! { (#lhs || #rhs) },
+                        _ => quote! { (#lhs != #rhs) },
+                    }))
+                }
+                Add | Sub | Mul => {
+                    let (op, checked) = match op {
+                        Add => (quote! { + }, quote! { checked_add }),
+                        Sub => (quote! { - }, quote! { checked_sub }),
+                        _ => (quote! { * }, quote! { checked_mul }),
+                    };
+                    match numeric(lhs, rhs)? {
+                        Numeric::Integer(lhs, rhs) => {
+                            Some(ctx.checked(checked, quote! { #lhs, #rhs }))
+                        }
+                        Numeric::Real(lhs, rhs) => Some(Operand::Real(quote! { (#lhs #op #rhs) })),
+                    }
+                }
+                RealDiv => {
+                    let lhs = lhs.into_real()?;
+                    let rhs = rhs.into_real()?;
+                    Some(Operand::Real(quote! { (#lhs / #rhs) }))
+                }
+                _ => None,
+            }
+        }
+        Expression::Relation { op, lhs, rhs } => {
+            let is_equality = matches!(op, Equal | NotEqual);
+            let op = match op {
+                Equal => quote! { == },
+                NotEqual => quote! { != },
+                Lt => quote! { < },
+                Gt => quote! { > },
+                Leq => quote! { <= },
+                Geq => quote! { >= },
+                _ => return None,
+            };
+            let lhs = translate_operand(ctx, lhs)?;
+            let rhs = translate_operand(ctx, rhs)?;
+            match (lhs, rhs) {
+                (Operand::String(lhs), Operand::String(rhs)) => {
+                    Some(Operand::Boolean(quote! { (#lhs #op #rhs) }))
+                }
+                (Operand::Boolean(lhs), Operand::Boolean(rhs)) if is_equality => {
+                    Some(Operand::Boolean(quote! { (#lhs #op #rhs) }))
+                }
+                (lhs, rhs) => match numeric(lhs, rhs)? {
+                    Numeric::Integer(lhs, rhs) | Numeric::Real(lhs, rhs) => {
+                        Some(Operand::Boolean(quote! { (#lhs #op #rhs) }))
+                    }
+                },
+            }
+        }
+        Expression::QualifiableFactor { factor, qualifiers } => {
+            if let ast::QualifiableFactor::FunctionCall {
+                name: ast::FunctionCallName::BuiltInFunction(f),
+                args,
+            } = factor
+            {
+                if !qualifiers.is_empty() {
+                    return None;
+                }
+                let [arg] = args.as_slice() else {
+                    return None;
+                };
+                if let (
+                    ast::BuiltInFunction::SIZEOF,
+                    Expression::Query {
+                        variable,
+                        source,
+                        expr,
+                    },
+                ) = (f, arg)
+                {
+                    return translate_sizeof_query(ctx, variable, source, expr);
+                }
+                let attr = attribute(ctx.entity, arg)?;
+                let field = ctx.edition.ident(&attr.name);
+                return match (f, attr.optional, &attr.ty) {
+                    (ast::BuiltInFunction::EXISTS, true, _) => {
+                        Some(Operand::Boolean(quote! { self.#field.is_some() }))
+                    }
+                    (ast::BuiltInFunction::EXISTS, false, _) => {
+                        Some(Operand::Boolean(quote! { true }))
+                    }
+                    (ast::BuiltInFunction::SIZEOF, false, ty) if ty.aggregate_base().is_some() => {
+                        Some(Operand::Integer(quote! { (self.#field.len() as i64) }))
+                    }
+                    _ => None,
+                };
+            }
+            if let Some(variable) = ctx.variable(expr) {
+                return Some(variable);
+            }
+            let attr = attribute(ctx.entity, expr)?;
+            if attr.optional {
+                return None;
+            }
+            let field = ctx.edition.ident(&attr.name);
+            match &attr.ty {
+                TypeRef::SimpleType(ty) => match ty.0 {
+                    ast::SimpleType::Integer => Some(Operand::Integer(quote! { self.#field })),
+                    ast::SimpleType::Real => Some(Operand::Real(quote! { self.#field })),
+                    ast::SimpleType::Boolen => Some(Operand::Boolean(quote! { self.#field })),
+                    ast::SimpleType::String_ { .. } => {
+                        Some(Operand::String(quote! { self.#field.as_str() }))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `SIZEOF(QUERY(v <* attr | cond))`, i.e. the number of elements satisfying `cond`
+///
+/// Elements of the aggregate must be simple types and not indeterminate.
+/// If `cond` overflows for an element, the number is indeterminate.
+fn translate_sizeof_query<'a>(
+    ctx: &Context<'a>,
+    variable: &'a str,
+    source: &ast::Expression,
+    cond: &ast::Expression,
+) -> Option<Operand> {
+    let attr = attribute(ctx.entity, source)?;
+    if attr.optional || matches!(attr.ty, TypeRef::Array { optional: true, .. }) {
+        return None;
+    }
+    let base = attr.ty.aggregate_base()?;
+    let field = ctx.edition.ident(&attr.name);
+    let var = ctx.edition.ident(variable);
+    let cond_ctx = ctx.with_variable(variable, base);
+    let cond = translate_operand(&cond_ctx, cond)?.into_boolean()?;
+    if cond_ctx.fallible.get() {
+        ctx.fallible.set(true);
+        return Some(Operand::Integer(quote! {
+            self.#field.iter().map(|#var| Some(i64::from(#cond))).sum::<Option<i64>>()?
+        }));
+    }
+    Some(Operand::Integer(
+        quote! { (self.#field.iter().filter(|&#var| #cond).count() as i64) },
+    ))
+}
+
+/// Explicit attribute of the entity referred as `attr` or `SELF.attr`
+fn attribute<'a>(entity: &'a Entity, expr: &ast::Expression) -> Option<&'a EntityAttribute> {
+    let name = match expr {
+        ast::Expression::QualifiableFactor { factor, qualifiers } => {
+            match (factor, qualifiers.as_slice()) {
+                (ast::QualifiableFactor::Reference(name), []) => name,
+                (
+                    ast::QualifiableFactor::BuiltInConstant(ast::BuiltInConstant::Self_),
+                    [ast::Qualifier::Attribute(name)],
+                ) => name,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    entity.attributes.iter().find(|attr| &attr.name == name)
+}
+
+impl Entity {
+    /// Generate `impl Validate` checking domain rules of this entity and its supertypes
+    ///
+    /// Rules which cannot be translated into Rust are not checked.
+    /// Rules using integer arithmetic are evaluated in private methods returning `None` on overflow,
+    /// and are regarded as satisfied then since indeterminate rules are not violated in EXPRESS.
+    pub(super) fn generate_validate(
+        &self,
+        ruststep_path: &syn::Path,
+        edition: TargetEdition,
+    ) -> TokenStream {
+        let name = format_ident!("{}", self.name.to_pascal_case());
+        let supertypes: Vec<_> = self
+            .supertypes
+            .iter()
+            .filter_map(|ty| match ty {
+                TypeRef::Entity { name, .. } => Some(edition.ident(name)),
+                _ => None,
+            })
+            .collect();
+        let mut labels = Vec::new();
+        let mut checks = Vec::new();
+        let mut methods = Vec::new();
+        for rule in &self.domain_rules {
+            let Some(Rule { expr, fallible }) = translate(self, &rule.expr, edition) else {
+                continue;
+            };
+            labels.push(format!("{}.{}", self.name, rule.label));
+            if fallible {
+                let method = format_ident!("validate_{}", rule.label.to_snake_case());
+                checks.push(quote! { (self.#method() == Some(false)) });
+                methods.push(quote! {
+                    fn #method(&self) -> Option<bool> {
+                        Some(#expr)
+                    }
+                });
+            } else {
+                checks.push(quote! { !#expr });
+            }
+        }
+        let methods = if methods.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                impl #name {
+                    #(#methods)*
+                }
+            }
+        };
+        quote! {
+            #methods
+            impl #ruststep_path::tables::Validate for #name {
+                fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                    #(#ruststep_path::tables::Validate::validate(&self.#supertypes)?;)*
+                    #(
+                    if #checks {
+                        return Err(#labels);
+                    }
+                    )*
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(exp: &str) -> Vec<Option<String>> {
+        let st = crate::ast::SyntaxTree::parse(exp).unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let entity = &ir.schemas[0].entities[0];
+        entity
+            .domain_rules
+            .iter()
+            .map(|rule| {
+                translate(entity, &rule.expr, TargetEdition::default())
+                    .map(|rule| rule.expr.to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn translate_rules() {
+        let translated = rules(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                x: REAL;
+                n: INTEGER;
+                label: OPTIONAL STRING;
+                items: LIST [0:?] OF INTEGER;
+              WHERE
+                wr1: SELF.x > 0;
+                wr2: (n >= 0) AND (n < x * 2);
+                wr3: EXISTS(label) OR (SIZEOF(items) = 3);
+                wr4: label = 'a';
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        );
+        assert_eq!(
+            translated,
+            [
+                Some("(self . x > 0.0)".to_string()),
+                Some("((self . n >= 0) && ((self . n as f64) < (self . x * 2.0)))".to_string()),
+                Some(
+                    "(self . label . is_some () || ((self . items . len () as i64) == 3))"
+                        .to_string()
+                ),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn translate_query() {
+        let translated = rules(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                n: INTEGER;
+                items: LIST [0:?] OF INTEGER;
+                names: SET [0:?] OF STRING;
+              WHERE
+                wr1: SIZEOF(QUERY(t <* items | t < 0)) = 0;
+                wr2: SIZEOF(QUERY(t <* SELF.items | (t = n) OR (SIZEOF(QUERY(u <* items | u = t)) > 1))) = 0;
+                wr3: SIZEOF(QUERY(s <* names | s = '')) = 0;
+                wr4: SIZEOF(QUERY(t <* n | t < 0)) = 0;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        );
+        assert_eq!(
+            translated,
+            [
+                Some(
+                    "((self . items . iter () . filter (| & t | ((* t) < 0)) . count () as i64) == 0)"
+                        .to_string()
+                ),
+                Some(
+                    "((self . items . iter () . filter (| & t | (((* t) == self . n) || ((self . items . iter () . filter (| & u | ((* u) == (* t))) . count () as i64) > 1))) . count () as i64) == 0)"
+                        .to_string()
+                ),
+                Some(
+                    "((self . names . iter () . filter (| & s | (s . as_str () == \"\")) . count () as i64) == 0)"
+                        .to_string()
+                ),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let st = crate::ast::SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                n: INTEGER;
+                items: LIST [0:?] OF INTEGER;
+              WHERE
+                wr1: n + 1 > -n;
+                wr2: SIZEOF(QUERY(t <* items | t * 2 < n)) = 0;
+                wr3: n > 0;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let entity = &ir.schemas[0].entities[0];
+        let translated: Vec<_> = entity
+            .domain_rules
+            .iter()
+            .map(|rule| {
+                let rule = translate(entity, &rule.expr, TargetEdition::default()).unwrap();
+                (rule.expr.to_string(), rule.fallible)
+            })
+            .collect();
+        assert_eq!(
+            translated,
+            [
+                (
+                    "(i64 :: checked_add (self . n , 1) ? > i64 :: checked_neg (self . n) ?)"
+                        .to_string(),
+                    true
+                ),
+                (
+                    "(self . items . iter () . map (| t | Some (i64 :: from ((i64 :: checked_mul ((* t) , 2) ? < self . n)))) . sum :: < Option < i64 >> () ? == 0)"
+                        .to_string(),
+                    true
+                ),
+                ("(self . n > 0)".to_string(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn unique_methods() {
+        let st = crate::ast::SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                n: INTEGER;
+              WHERE
+                n + 1 > 0;
+                n * 2 > 0;
+                unlabeled_1: n - 1 > 0;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let entity = &ir.schemas[0].entities[0];
+        let code = entity
+            .generate_validate(&syn::parse_quote!(ruststep), TargetEdition::default())
+            .to_string();
+        let methods: Vec<_> = code
+            .split("fn ")
+            .skip(1)
+            .map(|s| s.split_whitespace().next().unwrap())
+            .collect();
+        assert_eq!(
+            methods,
+            [
+                "validate_unlabeled_1_2",
+                "validate_unlabeled_2",
+                "validate_unlabeled_1",
+                "validate"
+            ]
+        );
+    }
+}
@@ -0,0 +1,1008 @@
+//! Evaluator of EXPRESS [Expression] used in `WHERE` rules and `DERIVE` attributes
+//!
+//! The evaluator works on dynamic [Value]s. An entity instance is represented
+//! by [Value::Entity] holding its attributes by name,
+//! and local variables, e.g. `SELF` or the variable of `QUERY`, are bound in [Env].
+//!
+//! ```
+//! use espr::{eval::*, parser::expression};
+//! use nom::Finish;
+//!
+//! let (_residual, (expr, _remarks)) = expression("SIZEOF(QUERY(t <* [1, 2, 3] | t > 1))")
+//!     .finish()
+//!     .unwrap();
+//! assert_eq!(Env::new().eval(&expr).unwrap(), Value::Integer(2));
+//! ```
+//!
+//! `USEDIN` searches instances referring an instance, and requires them as a [Population] set by [Env::with_population].
+
+use crate::ast::*;
+use std::{collections::HashMap, fmt, rc::Rc};
+use thiserror::Error;
+
+/// Maximum number of elements built by an aggregate initializer with repetitions, e.g. `[0 : 1000000]`
+pub const MAX_AGGREGATE_SIZE: usize = 1 << 20;
+
+/// Dynamic value appears while evaluating an [Expression]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Real(f64),
+    String(String),
+    Logical(Logical),
+    /// Enumeration item, e.g. `red` for `color.red`
+    Enumeration(String),
+    /// Elements of `SET`, `BAG`, and `LIST` indexed from 1
+    Aggregate(Vec<Value>),
+    /// Elements of `ARRAY` indexed from its lower bound, e.g. `lower = 0` for `ARRAY [0:2]`
+    Array {
+        lower: i64,
+        elements: Vec<Value>,
+    },
+    /// Entity instance with its name and attributes
+    Entity {
+        name: String,
+        attributes: HashMap<String, Value>,
+    },
+    /// `?`
+    Indeterminate,
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Logical(if value { Logical::True } else { Logical::False })
+    }
+}
+
+impl From<Logical> for Value {
+    fn from(value: Logical) -> Self {
+        Value::Logical(value)
+    }
+}
+
+impl Value {
+    fn as_real(&self) -> Option<f64> {
+        match self {
+            Value::Integer(i) => Some(*i as f64),
+            Value::Real(r) => Some(*r),
+            _ => None,
+        }
+    }
+
+    fn as_logical(&self) -> Result<Logical> {
+        match self {
+            Value::Logical(l) => Ok(l.clone()),
+            Value::Indeterminate => Ok(Logical::Unknown),
+            _ => Err(EvalError::TypeMismatch {
+                expected: "LOGICAL",
+                found: self.clone(),
+            }),
+        }
+    }
+
+    fn into_aggregate(self) -> Result<Vec<Value>> {
+        Ok(self.into_indexed()?.1)
+    }
+
+    /// Lowest index and elements of an aggregate
+    fn into_indexed(self) -> Result<(i64, Vec<Value>)> {
+        match self {
+            Value::Aggregate(elements) => Ok((1, elements)),
+            Value::Array { lower, elements } => Ok((lower, elements)),
+            _ => Err(EvalError::TypeMismatch {
+                expected: "AGGREGATE",
+                found: self,
+            }),
+        }
+    }
+
+    fn as_elements(&self) -> Option<&[Value]> {
+        match self {
+            Value::Aggregate(elements) | Value::Array { elements, .. } => Some(elements),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum EvalError {
+    #[error("Undefined variable: {0}")]
+    UndefinedVariable(String),
+
+    #[error("Attribute {attribute} not found in {value:?}")]
+    AttributeNotFound { attribute: String, value: Value },
+
+    #[error("Type mismatch: expected {expected}, found {found:?}")]
+    TypeMismatch {
+        expected: &'static str,
+        found: Value,
+    },
+
+    #[error("Index {index} out of range for aggregate of size {size}")]
+    IndexOutOfRange { index: i64, size: usize },
+
+    #[error("Division by zero")]
+    DivisionByZero,
+
+    #[error("Integer overflow")]
+    Overflow,
+
+    #[error("Aggregate of {0} elements exceeds the limit {MAX_AGGREGATE_SIZE}")]
+    AggregateTooLarge(i64),
+
+    #[error("Unsupported expression: {0}")]
+    Unsupported(String),
+
+    #[error("USEDIN requires population of entity instances, see Env::with_population")]
+    MissingPopulation,
+
+    #[error("Not a literal: {0}")]
+    NotLiteral(String),
+}
+
+pub type Result<T> = std::result::Result<T, EvalError>;
+
+/// Role of `USEDIN`, e.g. `'SCHEMA.ENTITY.ATTR'`, in lower case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Role {
+    pub schema: String,
+    pub entity: String,
+    pub attribute: String,
+}
+
+impl Role {
+    /// Parse a role, and returns `None` for empty one matching any attribute
+    fn parse(role: &str) -> Result<Option<Self>> {
+        if role.is_empty() {
+            return Ok(None);
+        }
+        match role
+            .to_lowercase()
+            .split('.')
+            .collect::<Vec<_>>()
+            .as_slice()
+        {
+            [schema, entity, attribute] => Ok(Some(Role {
+                schema: schema.to_string(),
+                entity: entity.to_string(),
+                attribute: attribute.to_string(),
+            })),
+            _ => Err(EvalError::Unsupported(format!("USEDIN role '{}'", role))),
+        }
+    }
+}
+
+/// Entity instances searched by `USEDIN`
+pub trait Population {
+    /// Instances referring `item` by the attribute of `role`, or by any attribute if `role` is `None`
+    fn used_in(&self, item: &Value, role: Option<&Role>) -> Vec<Value>;
+}
+
+/// Instances are compared by their values since [Value::Entity] does not have its identity.
+/// The schema of role is not checked.
+impl Population for Vec<Value> {
+    fn used_in(&self, item: &Value, role: Option<&Role>) -> Vec<Value> {
+        let refers = |value: &Value| match value {
+            Value::Aggregate(elements) | Value::Array { elements, .. } => elements
+                .iter()
+                .any(|e| compare(e, item).is_some_and(|o| o.is_eq())),
+            value => compare(value, item).is_some_and(|o| o.is_eq()),
+        };
+        self.iter()
+            .filter(|instance| {
+                let Value::Entity { name, attributes } = instance else {
+                    return false;
+                };
+                match role {
+                    Some(role) => {
+                        name.to_lowercase() == role.entity
+                            && attributes.get(&role.attribute).is_some_and(refers)
+                    }
+                    None => attributes.values().any(refers),
+                }
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Variables bound while evaluation
+#[derive(Clone, Default)]
+pub struct Env {
+    variables: HashMap<String, Value>,
+    population: Option<Rc<dyn Population>>,
+}
+
+impl fmt::Debug for Env {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Env")
+            .field("variables", &self.variables)
+            .field("population", &self.population.is_some())
+            .finish()
+    }
+}
+
+impl Env {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind `SELF`
+    pub fn with_self(value: Value) -> Self {
+        Self::new().bind("SELF", value)
+    }
+
+    /// Bind a value to a variable
+    pub fn bind(mut self, name: &str, value: Value) -> Self {
+        self.variables.insert(name.to_string(), value);
+        self
+    }
+
+    /// Set entity instances searched by `USEDIN`
+    pub fn with_population(mut self, population: Rc<dyn Population>) -> Self {
+        self.population = Some(population);
+        self
+    }
+
+    /// `USEDIN(item, role)`
+    fn used_in(&self, args: Vec<Value>) -> Result<Value> {
+        let [item, role] = <[Value; 2]>::try_from(args)
+            .map_err(|args| EvalError::Unsupported(format!("USEDIN with {:?}", args)))?;
+        if item == Value::Indeterminate {
+            return Ok(Value::Indeterminate);
+        }
+        let role = match role {
+            Value::String(role) => Role::parse(&role)?,
+            role => {
+                return Err(EvalError::TypeMismatch {
+                    expected: "STRING",
+                    found: role,
+                })
+            }
+        };
+        let population = self
+            .population
+            .as_ref()
+            .ok_or(EvalError::MissingPopulation)?;
+        Ok(Value::Aggregate(population.used_in(&item, role.as_ref())))
+    }
+
+    fn lookup(&self, name: &str) -> Result<&Value> {
+        self.variables
+            .get(name)
+            .ok_or_else(|| EvalError::UndefinedVariable(name.to_string()))
+    }
+
+    /// Check a `WHERE` rule, which is violated only if it evaluates to `FALSE`
+    ///
+    /// `UNKNOWN`, e.g. comparison with a missing optional attribute, does not violate the rule.
+    ///
+    /// ```
+    /// use espr::{eval::*, parser::expression};
+    /// use nom::Finish;
+    ///
+    /// let (_residual, (expr, _remarks)) = expression("(? > 0) OR FALSE").finish().unwrap();
+    /// assert_eq!(Env::new().eval(&expr).unwrap(), Value::Logical(espr::ast::Logical::Unknown));
+    /// assert!(Env::new().check_rule(&expr).unwrap());
+    /// ```
+    pub fn check_rule(&self, expr: &Expression) -> Result<bool> {
+        Ok(self.eval(expr)?.as_logical()? != Logical::False)
+    }
+
+    pub fn eval(&self, expr: &Expression) -> Result<Value> {
+        match expr {
+            Expression::Literal(literal) => Ok(match literal {
+                Literal::Integer(value) => Value::Integer(*value),
+                Literal::Real(value) => Value::Real(*value),
+                Literal::String(value) => Value::String(value.clone()),
+                Literal::Logial(value) => Value::Logical(value.clone()),
+            }),
+            Expression::Unary { op, arg } => unary(*op, self.eval(arg)?),
+            Expression::Binary { op, arg1, arg2 } => {
+                let lhs = self.eval(arg1)?;
+                // `FALSE AND x` and `TRUE OR x` are determined without `x`
+                match (op, &lhs) {
+                    (BinaryOperator::And, Value::Logical(Logical::False)) => return Ok(lhs),
+                    (BinaryOperator::Or, Value::Logical(Logical::True)) => return Ok(lhs),
+                    _ => {}
+                }
+                binary(*op, lhs, self.eval(arg2)?)
+            }
+            Expression::Relation { op, lhs, rhs } => {
+                relation(*op, self.eval(lhs)?, self.eval(rhs)?)
+            }
+            Expression::QualifiableFactor { factor, qualifiers } => {
+                let mut value = self.eval_factor(factor)?;
+                for qualifier in qualifiers {
+                    value = self.qualify(value, qualifier)?;
+                }
+                Ok(value)
+            }
+            Expression::EnumerationReference { enum_ref, .. } => {
+                Ok(Value::Enumeration(enum_ref.clone()))
+            }
+            Expression::AggregateInitializer { elements } => {
+                let mut values = Vec::new();
+                for Element { expr, repetition } in elements {
+                    let value = self.eval(expr)?;
+                    let n = match repetition {
+                        Some(repetition) => as_integer(&self.eval(repetition)?)?,
+                        None => 1,
+                    };
+                    let size = (values.len() as i64).saturating_add(n);
+                    if size > MAX_AGGREGATE_SIZE as i64 {
+                        return Err(EvalError::AggregateTooLarge(size));
+                    }
+                    for _ in 0..n {
+                        values.push(value.clone());
+                    }
+                }
+                Ok(Value::Aggregate(values))
+            }
+            Expression::Interval {
+                op_low,
+                op_high,
+                high,
+                low,
+                item,
+            } => {
+                let item = self.eval(item)?;
+                let low = interval(*op_low, self.eval(low)?, item.clone())?;
+                let high = interval(*op_high, item, self.eval(high)?)?;
+                binary(BinaryOperator::And, low, high)
+            }
+            Expression::Query {
+                variable,
+                source,
+                expr,
+            } => {
+                let mut filtered = Vec::new();
+                for element in self.eval(source)?.into_aggregate()? {
+                    let env = self.clone().bind(variable, element.clone());
+                    if env.eval(expr)?.as_logical()? == Logical::True {
+                        filtered.push(element);
+                    }
+                }
+                Ok(Value::Aggregate(filtered))
+            }
+            Expression::EntityConstructor { .. } => {
+                Err(EvalError::Unsupported(format!("{:?}", expr)))
+            }
+        }
+    }
+
+    fn eval_factor(&self, factor: &QualifiableFactor) -> Result<Value> {
+        match factor {
+            QualifiableFactor::Reference(name) => self.lookup(name).cloned(),
+            QualifiableFactor::BuiltInConstant(constant) => Ok(match constant {
+                BuiltInConstant::Napier => Value::Real(std::f64::consts::E),
+                BuiltInConstant::Pi => Value::Real(std::f64::consts::PI),
+                BuiltInConstant::Self_ => self.lookup("SELF")?.clone(),
+                BuiltInConstant::Indeterminate => Value::Indeterminate,
+            }),
+            QualifiableFactor::FunctionCall { name, args } => {
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>>>()?;
+                match name {
+                    FunctionCallName::BuiltInFunction(BuiltInFunction::USEDIN) => {
+                        self.used_in(args)
+                    }
+                    FunctionCallName::BuiltInFunction(f) => built_in_function(f, args),
+                    FunctionCallName::Reference(name) => {
+                        Err(EvalError::Unsupported(format!("function call {}", name)))
+                    }
+                }
+            }
+        }
+    }
+
+    fn qualify(&self, value: Value, qualifier: &Qualifier) -> Result<Value> {
+        match qualifier {
+            Qualifier::Attribute(attribute) => match value {
+                Value::Indeterminate => Ok(Value::Indeterminate),
+                Value::Entity { ref attributes, .. } => attributes
+                    .get(attribute)
+                    .cloned()
+                    .ok_or_else(|| EvalError::AttributeNotFound {
+                        attribute: attribute.clone(),
+                        value: value.clone(),
+                    }),
+                _ => Err(EvalError::TypeMismatch {
+                    expected: "ENTITY",
+                    found: value,
+                }),
+            },
+            // Partial complex entity value is not distinguished
+            Qualifier::Group(_) => Ok(value),
+            Qualifier::Index(index) => {
+                if value == Value::Indeterminate {
+                    return Ok(value);
+                }
+                let index = as_integer(&self.eval(index)?)?;
+                let (lower, elements) = value.into_indexed()?;
+                let offset = index.checked_sub(lower).ok_or(EvalError::Overflow)?;
+                usize::try_from(offset)
+                    .ok()
+                    .and_then(|i| elements.get(i).cloned())
+                    .ok_or(EvalError::IndexOutOfRange {
+                        index,
+                        size: elements.len(),
+                    })
+            }
+            Qualifier::Range { begin, end } => {
+                let begin = as_integer(&self.eval(begin)?)?;
+                let end = as_integer(&self.eval(end)?)?;
+                let (lower, elements) = value.into_indexed()?;
+                let skip = begin.checked_sub(lower).ok_or(EvalError::Overflow)?;
+                let take = end
+                    .checked_sub(begin)
+                    .and_then(|n| n.checked_add(1))
+                    .ok_or(EvalError::Overflow)?;
+                Ok(Value::Aggregate(
+                    elements
+                        .into_iter()
+                        .skip(skip.max(0) as usize)
+                        .take(take.max(0) as usize)
+                        .collect(),
+                ))
+            }
+        }
+    }
+}
+
+fn as_integer(value: &Value) -> Result<i64> {
+    match value {
+        Value::Integer(i) => Ok(*i),
+        // Whole real numbers are also accepted, e.g. `4.0 DIV 2`
+        Value::Real(r) if r.fract() == 0.0 => Ok(*r as i64),
+        _ => Err(EvalError::TypeMismatch {
+            expected: "INTEGER",
+            found: value.clone(),
+        }),
+    }
+}
+
+fn number_mismatch(found: Value) -> EvalError {
+    EvalError::TypeMismatch {
+        expected: "NUMBER",
+        found,
+    }
+}
+
+fn unary(op: UnaryOperator, arg: Value) -> Result<Value> {
+    match (op, arg) {
+        (_, Value::Indeterminate) => Ok(Value::Indeterminate),
+        (UnaryOperator::Plus, Value::Integer(i)) => Ok(Value::Integer(i)),
+        (UnaryOperator::Plus, Value::Real(r)) => Ok(Value::Real(r)),
+        (UnaryOperator::Minus, Value::Integer(i)) => {
+            Ok(Value::Integer(i.checked_neg().ok_or(EvalError::Overflow)?))
+        }
+        (UnaryOperator::Minus, Value::Real(r)) => Ok(Value::Real(-r)),
+        (UnaryOperator::Not, arg) => Ok(Value::Logical(match arg.as_logical()? {
+            Logical::True => Logical::False,
+            Logical::False => Logical::True,
+            Logical::Unknown => Logical::Unknown,
+        })),
+        (_, arg) => Err(number_mismatch(arg)),
+    }
+}
+
+/// Three-valued logic of `AND`, `OR` and `XOR`, e.g. `FALSE AND UNKNOWN` is `FALSE`
+fn logical(op: BinaryOperator, lhs: Logical, rhs: Logical) -> Logical {
+    use Logical::*;
+    match (op, lhs, rhs) {
+        (BinaryOperator::And, False, _) | (BinaryOperator::And, _, False) => False,
+        (BinaryOperator::Or, True, _) | (BinaryOperator::Or, _, True) => True,
+        (_, Unknown, _) | (_, _, Unknown) => Unknown,
+        (op, lhs, rhs) => {
+            let (lhs, rhs) = (lhs == True, rhs == True);
+            let value = match op {
+                BinaryOperator::And => lhs && rhs,
+                BinaryOperator::Or => lhs || rhs,
+                BinaryOperator::Xor => lhs ^ rhs,
+                _ => unreachable!(),
+            };
+            if value {
+                True
+            } else {
+                False
+            }
+        }
+    }
+}
+
+fn binary(op: BinaryOperator, lhs: Value, rhs: Value) -> Result<Value> {
+    use BinaryOperator::*;
+    if let And | Or | Xor = op {
+        return Ok(Value::Logical(logical(
+            op,
+            lhs.as_logical()?,
+            rhs.as_logical()?,
+        )));
+    }
+    if lhs == Value::Indeterminate || rhs == Value::Indeterminate {
+        return Ok(Value::Indeterminate);
+    }
+    match (op, &lhs, &rhs) {
+        (Add, Value::String(a), Value::String(b)) => {
+            return Ok(Value::String(format!("{}{}", a, b)))
+        }
+        (Add, Value::Aggregate(a), Value::Aggregate(b)) => {
+            return Ok(Value::Aggregate(
+                a.iter().chain(b.iter()).cloned().collect(),
+            ))
+        }
+        (Add, Value::Aggregate(a), _) => {
+            let mut a = a.clone();
+            a.push(rhs);
+            return Ok(Value::Aggregate(a));
+        }
+        (Add | Sub | Mul | IntegerDiv | Mod, Value::Integer(a), Value::Integer(b)) => {
+            let (a, b) = (*a, *b);
+            let value = match op {
+                Add => a.checked_add(b),
+                Sub => a.checked_sub(b),
+                Mul => a.checked_mul(b),
+                IntegerDiv | Mod if b == 0 => return Err(EvalError::DivisionByZero),
+                IntegerDiv => a.checked_div_euclid(b),
+                Mod => a.checked_rem_euclid(b),
+                _ => unreachable!(),
+            };
+            return value.map(Value::Integer).ok_or(EvalError::Overflow);
+        }
+        // Negative exponents yield REAL
+        (Power, Value::Integer(a), Value::Integer(b)) if *b >= 0 => {
+            return u32::try_from(*b)
+                .ok()
+                .and_then(|b| a.checked_pow(b))
+                .map(Value::Integer)
+                .ok_or(EvalError::Overflow);
+        }
+        (IntegerDiv | Mod, _, _) => {
+            return binary(
+                op,
+                Value::Integer(as_integer(&lhs)?),
+                Value::Integer(as_integer(&rhs)?),
+            )
+        }
+        _ => {}
+    }
+    let a = lhs.as_real().ok_or_else(|| number_mismatch(lhs.clone()))?;
+    let b = rhs.as_real().ok_or_else(|| number_mismatch(rhs.clone()))?;
+    Ok(Value::Real(match op {
+        Add => a + b,
+        Sub => a - b,
+        Mul => a * b,
+        RealDiv if b == 0.0 => return Err(EvalError::DivisionByZero),
+        RealDiv => a / b,
+        Power => a.powf(b),
+        _ => return Err(EvalError::Unsupported(format!("{:?}", op))),
+    }))
+}
+
+fn compare(lhs: &Value, rhs: &Value) -> Option<std::cmp::Ordering> {
+    match (lhs, rhs) {
+        (Value::String(a), Value::String(b)) => a.partial_cmp(b),
+        (Value::Enumeration(a), Value::Enumeration(b)) => {
+            (a.to_lowercase() == b.to_lowercase()).then_some(std::cmp::Ordering::Equal)
+        }
+        (Value::Logical(a), Value::Logical(b)) => (a == b).then_some(std::cmp::Ordering::Equal),
+        (Value::Aggregate(_) | Value::Array { .. }, Value::Aggregate(_) | Value::Array { .. }) => {
+            let (a, b) = (lhs.as_elements()?, rhs.as_elements()?);
+            (a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|(a, b)| compare(a, b).is_some_and(|o| o.is_eq())))
+            .then_some(std::cmp::Ordering::Equal)
+        }
+        (
+            Value::Entity {
+                name: a,
+                attributes: x,
+            },
+            Value::Entity {
+                name: b,
+                attributes: y,
+            },
+        ) => (a.eq_ignore_ascii_case(b)
+            && x.len() == y.len()
+            && x.iter().all(|(key, x)| {
+                y.get(key)
+                    .is_some_and(|y| compare(x, y).is_some_and(|o| o.is_eq()))
+            }))
+        .then_some(std::cmp::Ordering::Equal),
+        _ => lhs.as_real()?.partial_cmp(&rhs.as_real()?),
+    }
+}
+
+fn relation(op: RelationOperator, lhs: Value, rhs: Value) -> Result<Value> {
+    use RelationOperator::*;
+    if op == In {
+        let elements = rhs.into_aggregate()?;
+        return Ok(elements
+            .iter()
+            .any(|e| compare(&lhs, e).is_some_and(|o| o.is_eq()))
+            .into());
+    }
+    if lhs == Value::Indeterminate || rhs == Value::Indeterminate {
+        return Ok(Value::Logical(Logical::Unknown));
+    }
+    let ordering = compare(&lhs, &rhs);
+    Ok(match op {
+        Equal | InstanceEqual => ordering.is_some_and(|o| o.is_eq()),
+        NotEqual | InstanceNotEqual => !ordering.is_some_and(|o| o.is_eq()),
+        Lt => ordering.is_some_and(|o| o.is_lt()),
+        Gt => ordering.is_some_and(|o| o.is_gt()),
+        Leq => ordering.is_some_and(|o| o.is_le()),
+        Geq => ordering.is_some_and(|o| o.is_ge()),
+        In => unreachable!(),
+        Like => return Err(EvalError::Unsupported("LIKE".to_string())),
+    }
+    .into())
+}
+
+fn interval(op: IntervalOperator, lhs: Value, rhs: Value) -> Result<Value> {
+    match op {
+        IntervalOperator::LessThan => relation(RelationOperator::Lt, lhs, rhs),
+        IntervalOperator::LessThanEqual => relation(RelationOperator::Leq, lhs, rhs),
+    }
+}
+
+fn built_in_function(f: &BuiltInFunction, args: Vec<Value>) -> Result<Value> {
+    use BuiltInFunction::*;
+    let mut args = args.into_iter();
+    let arg = args
+        .next()
+        .ok_or_else(|| EvalError::Unsupported(format!("{:?} without argument", f)))?;
+    let real = |arg: &Value| arg.as_real().ok_or_else(|| number_mismatch(arg.clone()));
+    Ok(match f {
+        EXISTS => (arg != Value::Indeterminate).into(),
+        SIZEOF => match arg {
+            Value::Indeterminate => Value::Indeterminate,
+            arg => Value::Integer(arg.into_aggregate()?.len() as i64),
+        },
+        LOINDEX | HIINDEX => match arg {
+            Value::Indeterminate => Value::Indeterminate,
+            arg => {
+                let (lower, elements) = arg.into_indexed()?;
+                if *f == LOINDEX {
+                    Value::Integer(lower)
+                } else {
+                    let upper = (elements.len() as i64)
+                        .checked_sub(1)
+                        .and_then(|n| lower.checked_add(n))
+                        .ok_or(EvalError::Overflow)?;
+                    Value::Integer(upper)
+                }
+            }
+        },
+        ABS => match arg {
+            Value::Integer(i) => Value::Integer(i.checked_abs().ok_or(EvalError::Overflow)?),
+            arg => Value::Real(real(&arg)?.abs()),
+        },
+        SQRT => Value::Real(real(&arg)?.sqrt()),
+        SIN => Value::Real(real(&arg)?.sin()),
+        COS => Value::Real(real(&arg)?.cos()),
+        TAN => Value::Real(real(&arg)?.tan()),
+        EXP => Value::Real(real(&arg)?.exp()),
+        LOG => Value::Real(real(&arg)?.ln()),
+        LOG2 => Value::Real(real(&arg)?.log2()),
+        LOG10 => Value::Real(real(&arg)?.log10()),
+        ODD => (as_integer(&arg)? % 2 != 0).into(),
+        LENGTH => match arg {
+            Value::String(s) => Value::Integer(s.chars().count() as i64),
+            arg => {
+                return Err(EvalError::TypeMismatch {
+                    expected: "STRING",
+                    found: arg,
+                })
+            }
+        },
+        NVL => match arg {
+            Value::Indeterminate => args.next().unwrap_or(Value::Indeterminate),
+            arg => arg,
+        },
+        _ => return Err(EvalError::Unsupported(format!("{:?}", f))),
+    })
+}
+
+/// Value of a literal expression, e.g. `-1.5`, `'text'`, `TRUE`, `UNKNOWN` or `color.red`
+///
+/// A name without qualifiers, e.g. `red`, is regarded as an enumeration item.
+pub fn literal_value(expr: &Expression) -> Result<Value> {
+    match expr {
+        Expression::Literal(_) | Expression::EnumerationReference { .. } => Env::new().eval(expr),
+        Expression::Unary {
+            op: UnaryOperator::Plus | UnaryOperator::Minus,
+            arg,
+        } if matches!(
+            **arg,
+            Expression::Literal(Literal::Integer(_) | Literal::Real(_))
+        ) =>
+        {
+            Env::new().eval(expr)
+        }
+        Expression::QualifiableFactor {
+            factor: QualifiableFactor::Reference(name),
+            qualifiers,
+        } if qualifiers.is_empty() => Ok(Value::Enumeration(name.clone())),
+        // `color.red` is not distinguished from attribute access until legalized
+        Expression::QualifiableFactor {
+            factor: QualifiableFactor::Reference(_),
+            qualifiers,
+        } => match qualifiers.as_slice() {
+            [Qualifier::Attribute(item)] => Ok(Value::Enumeration(item.clone())),
+            _ => Err(EvalError::NotLiteral(format!("{:?}", expr))),
+        },
+        _ => Err(EvalError::NotLiteral(format!("{:?}", expr))),
+    }
+}
+
+/// Fold an expression consisting only of literals and built-in constants, e.g. `2 * PI`
+///
+/// `None` is returned for non-constant expressions, e.g. references to attributes,
+/// and ones evaluating to `?`, which are left symbolic.
+pub fn fold_constant(expr: &Expression) -> Option<Value> {
+    match Env::new().eval(expr) {
+        Ok(Value::Indeterminate) | Err(_) => None,
+        Ok(value) => Some(value),
+    }
+}
+
+/// Parse an EXPRESS literal into its value, e.g. for constants and default values
+///
+/// ```
+/// use espr::{ast::Logical, eval::*};
+///
+/// assert_eq!(parse_literal("-2").unwrap(), Value::Integer(-2));
+/// assert_eq!(parse_literal("2.5").unwrap(), Value::Real(2.5));
+/// assert_eq!(parse_literal("'abc'").unwrap(), Value::String("abc".to_string()));
+/// assert_eq!(parse_literal("FALSE").unwrap(), Value::Logical(Logical::False));
+/// assert_eq!(parse_literal("color.red").unwrap(), Value::Enumeration("red".to_string()));
+/// assert!(parse_literal("1 + 2").is_err());
+/// ```
+pub fn parse_literal(input: &str) -> Result<Value> {
+    use nom::Finish;
+    let input = input.trim();
+    let not_literal = || EvalError::NotLiteral(input.to_string());
+    let digits = input.strip_prefix(['-', '+']).unwrap_or(input);
+    if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+        return input.parse().map(Value::Integer).map_err(|_| not_literal());
+    }
+    match crate::parser::expression(input).finish() {
+        Ok(("", (expr, _remarks))) => literal_value(&expr).map_err(|_| not_literal()),
+        _ => Err(not_literal()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::expression;
+    use nom::Finish;
+
+    fn eval(env: &Env, input: &str) -> Value {
+        let (residual, (expr, _remarks)) = expression(input).finish().unwrap();
+        assert_eq!(residual, "");
+        env.eval(&expr).unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let env = Env::new();
+        assert_eq!(eval(&env, "1.0 + 2.0 * 3.0"), Value::Real(7.0));
+        assert_eq!(eval(&env, "1 <= 2"), Value::from(true));
+        assert_eq!(eval(&env, "NOT (1 > 2)"), Value::from(true));
+    }
+
+    #[test]
+    fn overflow() {
+        let env = Env::new()
+            .bind("max", Value::Integer(i64::MAX))
+            .bind("min", Value::Integer(i64::MIN))
+            .bind("one", Value::Integer(1))
+            .bind("a", Value::Aggregate(vec![Value::Integer(1)]));
+        let eval = |input| env.eval(&expression(input).finish().unwrap().1 .0);
+        for input in [
+            "max + one",
+            "min - one",
+            "max * max",
+            "-min",
+            "min DIV -1",
+            "min MOD -1",
+            "ABS(min)",
+            "max ** 2",
+            "a[min]",
+            "a[min:1]",
+            "a[-1:max]",
+        ] {
+            assert_eq!(eval(input), Err(EvalError::Overflow), "{}", input);
+        }
+        assert_eq!(eval("max - one"), Ok(Value::Integer(i64::MAX - 1)));
+        assert_eq!(
+            eval("[0 : max]"),
+            Err(EvalError::AggregateTooLarge(i64::MAX))
+        );
+        assert_eq!(
+            eval("[0 : 1, 0 : max]"),
+            Err(EvalError::AggregateTooLarge(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn power() {
+        let env = Env::new();
+        assert_eq!(eval(&env, "2 ** 10"), Value::Integer(1024));
+        assert_eq!(eval(&env, "(-3) ** 3"), Value::Integer(-27));
+        assert_eq!(eval(&env, "2 ** 0"), Value::Integer(1));
+        assert_eq!(eval(&env, "2 ** -1"), Value::Real(0.5));
+        assert_eq!(eval(&env, "2.0 ** 2"), Value::Real(4.0));
+    }
+
+    #[test]
+    fn fold() {
+        let fold = |input| fold_constant(&expression(input).finish().unwrap().1 .0);
+        assert_eq!(fold("(1 + 2) * 3"), Some(Value::Integer(9)));
+        assert_eq!(
+            fold("2 * PI"),
+            Some(Value::Real(2.0 * std::f64::consts::PI))
+        );
+        assert_eq!(fold("'a' + 'b'"), Some(Value::String("ab".to_string())));
+        assert_eq!(fold("x + 1"), None);
+        assert_eq!(fold("? + 1"), None);
+    }
+
+    #[test]
+    fn three_valued_logic() {
+        let env = Env::new();
+        let unknown = Value::Logical(Logical::Unknown);
+        assert_eq!(eval(&env, "UNKNOWN AND TRUE"), unknown);
+        assert_eq!(eval(&env, "UNKNOWN AND FALSE"), Value::from(false));
+        assert_eq!(eval(&env, "FALSE AND UNKNOWN"), Value::from(false));
+        assert_eq!(eval(&env, "UNKNOWN OR TRUE"), Value::from(true));
+        assert_eq!(eval(&env, "UNKNOWN OR FALSE"), unknown);
+        assert_eq!(eval(&env, "UNKNOWN XOR TRUE"), unknown);
+        assert_eq!(eval(&env, "NOT UNKNOWN"), unknown);
+        // `?` in comparison propagates through logical operators
+        assert_eq!(eval(&env, "(? > 0) AND (1 > 0)"), unknown);
+        assert_eq!(eval(&env, "NOT ((? > 0) OR (1 < 0))"), unknown);
+
+        // short-circuit does not evaluate undefined `x`
+        assert_eq!(eval(&env, "FALSE AND (x > 0)"), Value::from(false));
+        assert_eq!(eval(&env, "TRUE OR (x > 0)"), Value::from(true));
+    }
+
+    #[test]
+    fn check_rule() {
+        let check = |input: &str| {
+            let (_residual, (expr, _remarks)) = expression(input).finish().unwrap();
+            Env::new().check_rule(&expr).unwrap()
+        };
+        assert!(check("1 > 0"));
+        assert!(check("(? > 0) AND TRUE"));
+        assert!(!check("(? > 0) AND FALSE"));
+        assert!(!check("1 < 0"));
+    }
+
+    #[test]
+    fn literal() {
+        assert_eq!(parse_literal("+3").unwrap(), Value::Integer(3));
+        assert_eq!(parse_literal("1.0e3").unwrap(), Value::Real(1000.0));
+        assert_eq!(parse_literal("-0.5").unwrap(), Value::Real(-0.5));
+        assert_eq!(
+            parse_literal("'it''s'").unwrap(),
+            Value::String("it's".to_string())
+        );
+        assert_eq!(
+            parse_literal("UNKNOWN").unwrap(),
+            Value::Logical(Logical::Unknown)
+        );
+        assert_eq!(
+            parse_literal("red").unwrap(),
+            Value::Enumeration("red".to_string())
+        );
+        assert!(matches!(
+            parse_literal("SELF.x"),
+            Err(EvalError::NotLiteral(_))
+        ));
+        assert!(matches!(
+            parse_literal("f(1)"),
+            Err(EvalError::NotLiteral(_))
+        ));
+        assert!(matches!(
+            parse_literal("99999999999999999999"),
+            Err(EvalError::NotLiteral(_))
+        ));
+    }
+
+    #[test]
+    fn query() {
+        let env = Env::new();
+        assert_eq!(
+            eval(&env, "QUERY(t <* [1, 2, 3] | t >= 2)"),
+            Value::Aggregate(vec![Value::Integer(2), Value::Integer(3)])
+        );
+        assert_eq!(
+            eval(&env, "SIZEOF(QUERY(t <* [1, 2, 3] | t > 3)) = 0"),
+            Value::from(true)
+        );
+    }
+
+    #[test]
+    fn query_self() {
+        let item = |r: f64| Value::Entity {
+            name: "item".to_string(),
+            attributes: maplit::hashmap! { "radius".to_string() => Value::Real(r) },
+        };
+        let env = Env::with_self(Value::Entity {
+            name: "items".to_string(),
+            attributes: maplit::hashmap! {
+                "items".to_string() => Value::Aggregate(vec![item(1.0), item(-1.0)])
+            },
+        });
+        assert_eq!(
+            eval(&env, "SIZEOF(QUERY(t <* SELF.items | t.radius < 0.0))"),
+            Value::Integer(1)
+        );
+        assert_eq!(eval(&env, "SELF.items[1].radius"), Value::Real(1.0));
+    }
+
+    #[test]
+    fn array_index() {
+        let coords = |lower: i64| Value::Array {
+            lower,
+            elements: vec![Value::Real(1.0), Value::Real(2.0), Value::Real(3.0)],
+        };
+        let env = Env::with_self(Value::Entity {
+            name: "point".to_string(),
+            attributes: maplit::hashmap! { "coords".to_string() => coords(1) },
+        });
+        assert_eq!(eval(&env, "SELF.coords[1]"), Value::Real(1.0));
+        assert_eq!(eval(&env, "HIINDEX(SELF.coords)"), Value::Integer(3));
+
+        let env = env.bind("c", coords(0));
+        assert_eq!(eval(&env, "c[0]"), Value::Real(1.0));
+        assert_eq!(
+            eval(&env, "c[1:2]"),
+            Value::Aggregate(vec![Value::Real(2.0), Value::Real(3.0)])
+        );
+        assert_eq!(eval(&env, "LOINDEX(c)"), Value::Integer(0));
+        assert_eq!(eval(&env, "HIINDEX(c)"), Value::Integer(2));
+        assert_eq!(eval(&env, "c = [1.0, 2.0, 3.0]"), Value::from(true));
+    }
+
+    #[test]
+    fn used_in() {
+        let point = |x: f64| Value::Entity {
+            name: "point".to_string(),
+            attributes: maplit::hashmap! { "x".to_string() => Value::Real(x) },
+        };
+        let line = |start: Value, end: Value| Value::Entity {
+            name: "line".to_string(),
+            attributes: maplit::hashmap! {
+                "start".to_string() => start,
+                "end".to_string() => end,
+            },
+        };
+        let population: Vec<Value> = vec![
+            point(0.0),
+            point(1.0),
+            line(point(0.0), point(1.0)),
+            line(point(1.0), point(1.0)),
+        ];
+        let env = Env::with_self(point(1.0)).with_population(Rc::new(population));
+        assert_eq!(
+            eval(&env, "SIZEOF(USEDIN(SELF, 'S.LINE.START'))"),
+            Value::Integer(1)
+        );
+        assert_eq!(eval(&env, "SIZEOF(USEDIN(SELF, ''))"), Value::Integer(2));
+
+        let (_residual, (expr, _remarks)) = expression("USEDIN(SELF, '')").finish().unwrap();
+        assert_eq!(
+            Env::with_self(point(1.0)).eval(&expr),
+            Err(EvalError::MissingPopulation)
+        );
+    }
+}
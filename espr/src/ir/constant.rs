@@ -0,0 +1,159 @@
+use super::*;
+use crate::ast;
+
+/// Check constant references qualified by schema names, e.g. `geometry.origin`, in the expression
+///
+/// `attributes` are names visible in the expression, e.g. attributes of the entity,
+/// which take precedence over schema names.
+pub(super) fn check_constant_references(
+    ns: &Namespace,
+    scope: &Scope,
+    attributes: &[&str],
+    expr: &ast::Expression,
+) -> Result<(), SemanticError> {
+    use ast::Expression::*;
+    let check = |expr: &ast::Expression| check_constant_references(ns, scope, attributes, expr);
+    match expr {
+        Literal(_) | EnumerationReference { .. } => {}
+        Unary { arg, .. } => check(arg)?,
+        Binary { arg1, arg2, .. } => {
+            check(arg1)?;
+            check(arg2)?;
+        }
+        Relation { lhs, rhs, .. } => {
+            check(lhs)?;
+            check(rhs)?;
+        }
+        QualifiableFactor { factor, qualifiers } => {
+            match (factor, qualifiers.first()) {
+                (
+                    ast::QualifiableFactor::Reference(schema),
+                    Some(ast::Qualifier::Attribute(name)),
+                ) if !attributes.contains(&schema.as_str()) => {
+                    ns.resolve_constant(scope, schema, name)?;
+                }
+                (ast::QualifiableFactor::FunctionCall { args, .. }, _) => {
+                    for arg in args {
+                        check(arg)?;
+                    }
+                }
+                _ => {}
+            }
+            for qualifier in qualifiers {
+                match qualifier {
+                    ast::Qualifier::Index(index) => check(index)?,
+                    ast::Qualifier::Range { begin, end } => {
+                        check(begin)?;
+                        check(end)?;
+                    }
+                    ast::Qualifier::Attribute(_) | ast::Qualifier::Group(_) => {}
+                }
+            }
+        }
+        EntityConstructor { values, .. } => {
+            for value in values {
+                check(value)?;
+            }
+        }
+        Interval {
+            high, low, item, ..
+        } => {
+            check(high)?;
+            check(low)?;
+            check(item)?;
+        }
+        AggregateInitializer { elements } => {
+            for element in elements {
+                check(&element.expr)?;
+                if let Some(repetition) = &element.repetition {
+                    check(repetition)?;
+                }
+            }
+        }
+        Query { source, expr, .. } => {
+            check(source)?;
+            check(expr)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legalize(exp: &str) -> Result<IR, SemanticError> {
+        let st = SyntaxTree::parse(exp.trim()).unwrap();
+        IR::from_syntax_tree(&st)
+    }
+
+    const GEOMETRY: &str = r#"
+        SCHEMA geometry;
+          CONSTANT
+            origin: REAL := 0.0;
+          END_CONSTANT;
+        END_SCHEMA;
+    "#;
+
+    #[test]
+    fn qualified_constant() {
+        legalize(&format!(
+            r#"
+            {GEOMETRY}
+            SCHEMA drawing;
+              REFERENCE FROM geometry (origin);
+              ENTITY point;
+                x: REAL;
+              DERIVE
+                distance: REAL := x - geometry.origin;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn constant_not_found() {
+        let err = legalize(&format!(
+            r#"
+            {GEOMETRY}
+            SCHEMA drawing;
+              REFERENCE FROM geometry;
+              ENTITY point;
+                x: REAL;
+              DERIVE
+                distance: REAL := x - geometry.center;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            &err,
+            SemanticError::ConstantNotFound { schema, name, .. } if schema == "geometry" && name == "center"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Constant center referred in drawing.point is not declared in schema geometry"
+        );
+    }
+
+    #[test]
+    fn constant_not_imported() {
+        let err = legalize(&format!(
+            r#"
+            {GEOMETRY}
+            SCHEMA drawing;
+              ENTITY point;
+                x: REAL;
+              DERIVE
+                distance: REAL := x - geometry.origin;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+        ))
+        .unwrap_err();
+        assert!(matches!(err, SemanticError::ConstantNotImported { .. }));
+    }
+}
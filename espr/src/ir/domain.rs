@@ -0,0 +1,360 @@
+use super::*;
+use crate::ast;
+use inflector::Inflector;
+use std::collections::{HashMap, HashSet};
+
+/// Domain rule in `WHERE` clause, e.g. `wr1: SELF > 0;`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainRule {
+    /// Label of the rule
+    ///
+    /// For rules without a label, e.g. `WHERE SELF > 0;`,
+    /// a label `unlabeled_{n}` is synthesized from its 1-based position `n` in the `WHERE` clause.
+    /// Labels are unique in snake case, which generated `validate_{label}` methods use,
+    /// and a repeated one gets a suffix of the smallest index from 2, e.g. `wr1_2`.
+    pub label: String,
+
+    /// True if the label is written in EXPRESS schema
+    pub is_labeled: bool,
+
+    /// The rule expression
+    ///
+    /// This is not legalized yet except that qualified enumeration references,
+    /// e.g. `color.red`, are resolved into [ast::Expression::EnumerationReference].
+    pub expr: ast::Expression,
+}
+
+impl DomainRule {
+    /// Legalize rules in a `WHERE` clause keeping their order
+    ///
+    /// `attributes` are names visible in the rule, e.g. attributes of the entity,
+    /// which take precedence over types in resolving qualified enumeration references.
+    pub fn legalize_where_clause(
+        ns: &Namespace,
+        scope: &Scope,
+        attributes: &[&str],
+        clause: &ast::WhereClause,
+    ) -> Vec<Self> {
+        // Written labels are reserved against synthesized and suffixed ones
+        let reserved: HashSet<String> = clause
+            .rules
+            .iter()
+            .filter_map(|rule| rule.label.as_deref())
+            .map(|label| label.to_snake_case())
+            .collect();
+        let mut used = HashSet::new();
+        clause
+            .rules
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| {
+                let mut expr = rule.expr.clone();
+                resolve_enumeration_references(ns, scope, attributes, &mut expr);
+                let base = match &rule.label {
+                    Some(label) => label.clone(),
+                    None => format!("unlabeled_{}", i + 1),
+                };
+                let mut label = base.clone();
+                let mut index = 1;
+                loop {
+                    let key = label.to_snake_case();
+                    let written = index == 1 && rule.label.is_some();
+                    if !used.contains(&key) && (written || !reserved.contains(&key)) {
+                        used.insert(key);
+                        break;
+                    }
+                    index += 1;
+                    label = format!("{}_{}", base, index);
+                }
+                DomainRule {
+                    label,
+                    is_labeled: rule.label.is_some(),
+                    expr,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Rewrite `color.red`, parsed as a reference `color` with an attribute qualifier `.red`,
+/// into an enumeration reference if `color` is an enumeration type containing `red`
+fn resolve_enumeration_references(
+    ns: &Namespace,
+    scope: &Scope,
+    attributes: &[&str],
+    expr: &mut ast::Expression,
+) {
+    use ast::Expression::*;
+    let mut resolve =
+        |expr: &mut ast::Expression| resolve_enumeration_references(ns, scope, attributes, expr);
+    match expr {
+        Literal(_) | EnumerationReference { .. } => {}
+        Unary { arg, .. } => resolve(arg),
+        Binary { arg1, arg2, .. } => {
+            resolve(arg1);
+            resolve(arg2);
+        }
+        Relation { lhs, rhs, .. } => {
+            resolve(lhs);
+            resolve(rhs);
+        }
+        QualifiableFactor { factor, qualifiers } => {
+            if let (ast::QualifiableFactor::Reference(ty), [ast::Qualifier::Attribute(item)]) =
+                (&*factor, qualifiers.as_slice())
+            {
+                if !attributes.contains(&ty.as_str()) && is_enumeration_item(ns, scope, ty, item) {
+                    *expr = EnumerationReference {
+                        ty: Some(ty.clone()),
+                        enum_ref: item.clone(),
+                    };
+                    return;
+                }
+            }
+            if let ast::QualifiableFactor::FunctionCall { args, .. } = factor {
+                args.iter_mut().for_each(&mut resolve);
+            }
+            for qualifier in qualifiers {
+                match qualifier {
+                    ast::Qualifier::Index(index) => resolve(index),
+                    ast::Qualifier::Range { begin, end } => {
+                        resolve(begin);
+                        resolve(end);
+                    }
+                    ast::Qualifier::Attribute(_) | ast::Qualifier::Group(_) => {}
+                }
+            }
+        }
+        EntityConstructor { values, .. } => values.iter_mut().for_each(resolve),
+        Interval {
+            high, low, item, ..
+        } => {
+            resolve(high);
+            resolve(low);
+            resolve(item);
+        }
+        AggregateInitializer { elements } => {
+            for element in elements {
+                resolve(&mut element.expr);
+                if let Some(repetition) = &mut element.repetition {
+                    resolve(repetition);
+                }
+            }
+        }
+        Query { source, expr, .. } => {
+            resolve(source);
+            resolve(expr);
+        }
+    }
+}
+
+fn is_enumeration_item(ns: &Namespace, scope: &Scope, ty: &str, item: &str) -> bool {
+    let Ok((path, _index)) = ns.resolve(scope, ty) else {
+        return false;
+    };
+    matches!(
+        ns.get(&path),
+        Ok((Named::Type(ast::TypeDecl {
+            underlying_type: ast::Type::Enumeration { items, .. },
+            ..
+        }), _)) if items.iter().any(|i| i == item)
+    )
+}
+
+impl IR {
+    /// Look up an entity declared in the schema scope
+    pub fn lookup_entity(&self, scope: &Scope, name: &str) -> Option<&Entity> {
+        self.schemas
+            .iter()
+            .find(|schema| Scope::root().schema(&schema.name) == *scope)?
+            .entities
+            .iter()
+            .find(|entity| entity.name == name)
+    }
+
+    /// Domain rules of an entity including those inherited from its supertypes
+    ///
+    /// Rules of supertypes appear first.
+    /// When the same label is used in several entities in the inheritance tree,
+    /// the labels are qualified by the entity name, e.g. `point.wr1`.
+    /// Returns `None` if the entity is not found.
+    pub fn inherited_domain_rules(&self, scope: &Scope, name: &str) -> Option<Vec<DomainRule>> {
+        let mut visited = HashSet::new();
+        let mut rules = Vec::new();
+        self.collect_domain_rules(scope, name, &mut visited, &mut rules)?;
+
+        let mut count: HashMap<String, usize> = HashMap::new();
+        for (_entity, rule) in &rules {
+            *count.entry(rule.label.clone()).or_default() += 1;
+        }
+        Some(
+            rules
+                .into_iter()
+                .map(|(entity, mut rule)| {
+                    if count[&rule.label] > 1 {
+                        rule.label = format!("{}.{}", entity, rule.label);
+                    }
+                    rule
+                })
+                .collect(),
+        )
+    }
+
+    fn collect_domain_rules(
+        &self,
+        scope: &Scope,
+        name: &str,
+        visited: &mut HashSet<(Scope, String)>,
+        rules: &mut Vec<(String, DomainRule)>,
+    ) -> Option<()> {
+        if !visited.insert((scope.clone(), name.to_string())) {
+            // Already collected through another path of the inheritance tree
+            return Some(());
+        }
+        let entity = self.lookup_entity(scope, name)?;
+        for supertype in &entity.supertypes {
+            if let TypeRef::Entity { name, scope, .. } = supertype {
+                self.collect_domain_rules(scope, name, visited, rules)?;
+            }
+        }
+        rules.extend(
+            entity
+                .domain_rules
+                .iter()
+                .map(|rule| (entity.name.clone(), rule.clone())),
+        );
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn synthesize_label() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                x: REAL;
+              WHERE
+                SELF.x > 0.0;
+                unlabeled_1: SELF.x < 1.0;
+                wr1: SELF.x <> 0.5;
+                SELF.x <> 0.25;
+                wr1: SELF.x <> 0.75;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let labels: Vec<_> = ir.schemas[0].entities[0]
+            .domain_rules
+            .iter()
+            .map(|rule| (rule.label.as_str(), rule.is_labeled))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                ("unlabeled_1_2", false),
+                ("unlabeled_1", true),
+                ("wr1", true),
+                ("unlabeled_4", false),
+                ("wr1_2", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn inherited() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY base;
+                x: REAL;
+              WHERE
+                wr1: SELF.x > 0.0;
+                positive: SELF.x > 0.0;
+              END_ENTITY;
+              ENTITY sub1 SUBTYPE OF (base);
+              WHERE
+                wr1: SELF.x < 1.0;
+              END_ENTITY;
+              ENTITY sub2 SUBTYPE OF (base);
+              END_ENTITY;
+              ENTITY diamond SUBTYPE OF (sub1, sub2);
+              WHERE
+                SELF.x <> 0.5;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let scope = Scope::root().schema("s");
+        let labels: Vec<_> = ir
+            .inherited_domain_rules(&scope, "diamond")
+            .unwrap()
+            .into_iter()
+            .map(|rule| rule.label)
+            .collect();
+        assert_eq!(labels, ["base.wr1", "positive", "sub1.wr1", "unlabeled_1"]);
+        assert!(ir.inherited_domain_rules(&scope, "unknown").is_none());
+    }
+
+    #[test]
+    fn enumeration_reference() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE color = ENUMERATION OF (red, green);
+              END_TYPE;
+              ENTITY a;
+                kind: color;
+              WHERE
+                wr1: SELF.kind = color.red;
+                wr2: kind.red = 1;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let rules = &ir.schemas[0].entities[0].domain_rules;
+        let wr1 = &rules[0].expr;
+        assert_eq!(
+            wr1,
+            &ast::Expression::self_qualified(vec![ast::Qualifier::Attribute("kind".to_string())])
+                .eq(ast::Expression::EnumerationReference {
+                    ty: Some("color".to_string()),
+                    enum_ref: "red".to_string(),
+                })
+        );
+        // `kind` is an attribute, not a type
+        assert!(matches!(
+            &rules[1].expr,
+            ast::Expression::Relation { lhs, .. }
+                if matches!(**lhs, ast::Expression::QualifiableFactor { .. })
+        ));
+
+        use crate::eval::{Env, Value};
+        let entity = |kind: &str| Value::Entity {
+            name: "a".to_string(),
+            attributes: [("kind".to_string(), Value::Enumeration(kind.to_string()))]
+                .into_iter()
+                .collect(),
+        };
+        assert_eq!(
+            Env::with_self(entity("red")).eval(wr1).unwrap(),
+            Value::Logical(ast::Logical::True)
+        );
+        assert_eq!(
+            Env::with_self(entity("green")).eval(wr1).unwrap(),
+            Value::Logical(ast::Logical::False)
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use super::{namespace::*, scope::*, type_ref::*, *};
-use crate::ast;
+use crate::{
+    ast,
+    eval::{fold_constant, Value},
+};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Entity {
     /// Name of entity in snake_case
     pub name: String,
@@ -23,6 +26,22 @@ pub struct Entity {
     /// then this `supertypes` is `[base]`.
     ///
     pub supertypes: Vec<TypeRef>,
+
+    /// Domain rules declared in `WHERE` clause of this entity, not including inherited ones
+    pub domain_rules: Vec<DomainRule>,
+
+    /// Inverse attributes declared in `INVERSE` clause
+    pub inverse_attributes: Vec<InverseAttribute>,
+
+    /// Derived attributes declared in `DERIVE` clause, not including ones redeclaring inherited attributes
+    pub derived_attributes: Vec<DerivedAttribute>,
+
+    /// Uniqueness rules declared in `UNIQUE` clause, which generated code does not check yet
+    pub unique_rules: Vec<UniqueRule>,
+
+    /// True if declared as `ABSTRACT` or `ABSTRACT SUPERTYPE`,
+    /// i.e. this entity cannot be instantiated by itself
+    pub is_abstract: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -30,6 +49,66 @@ pub struct EntityAttribute {
     pub name: String,
     pub ty: TypeRef,
     pub optional: bool,
+
+    /// True if the entity holding this attribute is reachable from this attribute
+    /// only through entity references not wrapped by aggregates,
+    /// e.g. `parent: tree_node;` in `ENTITY tree_node`.
+    /// Such field must be boxed to avoid infinite size struct in Rust.
+    pub is_recursive: bool,
+}
+
+/// Uniqueness rule in `UNIQUE` clause, e.g. `ur1: name, id;`
+///
+/// Values of `attributes` are unique as a tuple among all instances of the entity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniqueRule {
+    /// Label of the rule, `None` if not written in EXPRESS schema
+    pub label: Option<String>,
+    /// Names of attributes, where an inherited one like `SELF\base.id` is stored as `id`
+    pub attributes: Vec<String>,
+}
+
+/// Attribute declared in `DERIVE` clause, e.g. `area : REAL := PI * radius ** 2;`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedAttribute {
+    pub name: String,
+    pub ty: TypeRef,
+    pub expr: ast::Expression,
+
+    /// Value of `expr` folded at compile time, `None` if it is not a constant expression
+    pub value: Option<Value>,
+}
+
+impl Entity {
+    /// Name of entity in snake_case
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Explicit attributes defined in this entity, not including inherited ones
+    pub fn attributes(&self) -> &[EntityAttribute] {
+        &self.attributes
+    }
+
+    /// Uniqueness rules declared in `UNIQUE` clause of this entity
+    pub fn unique_rules(&self) -> &[UniqueRule] {
+        &self.unique_rules
+    }
+}
+
+impl EntityAttribute {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn ty(&self) -> &TypeRef {
+        &self.ty
+    }
+
+    /// If the attribute is declared as `OPTIONAL`
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
 }
 
 impl Legalize for EntityAttribute {
@@ -50,6 +129,8 @@ impl Legalize for EntityAttribute {
             name,
             ty,
             optional: attr.optional,
+            // determined after all entities in the schema are legalized
+            is_recursive: false,
         })
     }
 }
@@ -94,11 +175,94 @@ impl Legalize for Entity {
             Vec::new()
         };
 
+        let attribute_names: Vec<&str> = attributes.iter().map(|attr| attr.name()).collect();
+        let domain_rules = entity
+            .where_clause
+            .as_ref()
+            .map(|clause| DomainRule::legalize_where_clause(ns, scope, &attribute_names, clause))
+            .unwrap_or_default();
+
+        let checker = type_check::TypeChecker::new(ns, scope, entity);
+        for rule in &domain_rules {
+            checker.check(&rule.expr)?;
+        }
+        let derived_attributes: Vec<_> = entity
+            .derive_clause
+            .iter()
+            .flat_map(|clause| &clause.attributes)
+            .collect();
+        let visible: Vec<&str> = attribute_names
+            .iter()
+            .copied()
+            .chain(
+                derived_attributes
+                    .iter()
+                    .filter_map(|derived| match &derived.attr {
+                        ast::AttributeDecl::Reference(name) => Some(name.as_str()),
+                        ast::AttributeDecl::Qualified { .. } => None,
+                    }),
+            )
+            .collect();
+        let entity_scope = scope.entity(&entity.name);
+        for derived in &derived_attributes {
+            checker.check(&derived.expr)?;
+            constant::check_constant_references(ns, &entity_scope, &visible, &derived.expr)?;
+        }
+        let derived_attributes = derived_attributes
+            .into_iter()
+            .filter_map(|derived| match &derived.attr {
+                ast::AttributeDecl::Reference(name) => Some((name, derived)),
+                ast::AttributeDecl::Qualified { .. } => None,
+            })
+            .map(|(name, derived)| {
+                Ok(DerivedAttribute {
+                    name: name.clone(),
+                    ty: TypeRef::legalize(ns, ss, scope, &derived.ty)?,
+                    expr: derived.expr.clone(),
+                    value: fold_constant(&derived.expr),
+                })
+            })
+            .collect::<Result<Vec<_>, SemanticError>>()?;
+
+        let inverse_attributes = entity
+            .inverse_clause
+            .iter()
+            .flat_map(|clause| &clause.attributes)
+            .map(|inverse| InverseAttribute::legalize(ns, ss, scope, inverse))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let unique_rules = entity
+            .unique_clause
+            .iter()
+            .flat_map(|clause| &clause.rules)
+            .map(|rule| UniqueRule {
+                label: rule.name.clone(),
+                attributes: rule
+                    .attributes
+                    .iter()
+                    .map(|attr| match attr {
+                        ast::AttributeDecl::Reference(name) => name.clone(),
+                        ast::AttributeDecl::Qualified { attribute, .. } => attribute.clone(),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let is_abstract = matches!(
+            entity.constraint,
+            Some(ast::Constraint::AbstractEntity | ast::Constraint::AbstractSuperType(..))
+        );
+
         Ok(Entity {
             name,
             attributes,
             constraints,
             supertypes,
+            domain_rules,
+            inverse_attributes,
+            derived_attributes,
+            unique_rules,
+            is_abstract,
         })
     }
 }
@@ -117,5 +281,47 @@ mod tests {
         let scope = Scope::root().pushed(ScopeType::Schema, &example.schemas[0].name);
         let entity = Entity::legalize(&ns, &ss, &scope, entity).unwrap();
         dbg!(&entity);
+
+        assert_eq!(entity.name(), "first");
+        let attr = &entity.attributes()[0];
+        assert_eq!(attr.name(), "m_ref");
+        assert!(!attr.is_optional());
+    }
+
+    #[test]
+    fn unique_rules() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY base;
+                id: STRING;
+              END_ENTITY;
+              ENTITY item SUBTYPE OF (base);
+                name: STRING;
+                revision: STRING;
+              UNIQUE
+                ur1: name, revision;
+                SELF\base.id;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let item = &ir.schemas[0].entities[1];
+        assert_eq!(
+            item.unique_rules(),
+            [
+                UniqueRule {
+                    label: Some("ur1".to_string()),
+                    attributes: vec!["name".to_string(), "revision".to_string()],
+                },
+                UniqueRule {
+                    label: None,
+                    attributes: vec!["id".to_string()],
+                },
+            ]
+        );
     }
 }
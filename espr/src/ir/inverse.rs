@@ -0,0 +1,212 @@
+use super::*;
+use crate::ast;
+
+/// Inverse attribute declared in `INVERSE` clause, e.g. `opens : door FOR handle;`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InverseAttribute {
+    /// Name of this inverse relationship, `opens` in the above example
+    pub name: String,
+
+    /// The entity which refers `SELF` by [InverseAttribute::attribute], `door` in the above example
+    pub dest: TypeRef,
+
+    /// The attribute of `dest` entity referring `SELF`, `handle` in the above example
+    pub attribute: String,
+
+    /// True if `attribute` is not declared in `dest` itself but inherited from its supertype
+    pub is_inherited: bool,
+
+    /// Supertype declaring the inverse attribute redeclared by this,
+    /// e.g. `knob` for `SELF\knob.opens : door FOR handle;`
+    pub redeclares: Option<String>,
+}
+
+impl Legalize for InverseAttribute {
+    type Input = ast::InverseAttribute;
+
+    fn legalize(
+        ns: &Namespace,
+        ss: &Constraints,
+        scope: &Scope,
+        inverse: &Self::Input,
+    ) -> Result<Self, SemanticError> {
+        let (name, redeclares) = match &inverse.name {
+            ast::AttributeDecl::Reference(name) => (name.clone(), None),
+            ast::AttributeDecl::Qualified {
+                group,
+                attribute,
+                rename,
+            } => {
+                let (supertype, _index) = ns.resolve(scope, group)?;
+                if supertype.ty != ScopeType::Entity {
+                    return Err(SemanticError::NotAnEntity { path: supertype });
+                }
+                let name = rename.as_ref().unwrap_or(attribute).clone();
+                (name, Some(supertype.name))
+            }
+        };
+        let (path, _index) = ns.resolve(scope, &inverse.dest)?;
+        if path.ty != ScopeType::Entity {
+            return Err(SemanticError::NotAnEntity { path });
+        }
+        let is_inherited = match declared_in(ns, &path, &inverse.attribute)? {
+            Some(declared) => declared != path,
+            None => {
+                return Err(SemanticError::AttributeNotFound {
+                    entity: path,
+                    attribute: inverse.attribute.clone(),
+                })
+            }
+        };
+        Ok(InverseAttribute {
+            name,
+            dest: TypeRef::from_path(ns, ss, &path)?,
+            attribute: inverse.attribute.clone(),
+            is_inherited,
+            redeclares,
+        })
+    }
+}
+
+/// Entity declaring the explicit attribute, searched from `entity` to its supertypes
+fn declared_in(
+    ns: &Namespace,
+    entity: &Path,
+    attribute: &str,
+) -> Result<Option<Path>, SemanticError> {
+    let ast = match ns.get(entity)?.0 {
+        Named::Entity(ast) => ast,
+        _ => {
+            return Err(SemanticError::NotAnEntity {
+                path: entity.clone(),
+            })
+        }
+    };
+    if ast
+        .attributes
+        .iter()
+        .any(|attr| matches!(&attr.name, ast::AttributeDecl::Reference(name) if name == attribute))
+    {
+        return Ok(Some(entity.clone()));
+    }
+    if let Some(subtype_of) = &ast.subtype_of {
+        for supertype in &subtype_of.entity_references {
+            let (supertype, _index) = ns.resolve(&entity.scope, supertype)?;
+            if let Some(found) = declared_in(ns, &supertype, attribute)? {
+                return Ok(Some(found));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legalize() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY door;
+                handle: knob;
+              END_ENTITY;
+              ENTITY sliding_door SUBTYPE OF (door);
+              END_ENTITY;
+              ENTITY knob;
+              INVERSE
+                opens: door FOR handle;
+                slides: SET [0:?] OF sliding_door FOR handle;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let knob = &ir.schemas[0].entities[2];
+        let inverses: Vec<_> = knob
+            .inverse_attributes
+            .iter()
+            .map(|inv| (inv.name.as_str(), inv.attribute.as_str(), inv.is_inherited))
+            .collect();
+        assert_eq!(
+            inverses,
+            [("opens", "handle", false), ("slides", "handle", true)]
+        );
+    }
+
+    #[test]
+    fn redeclared() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY door;
+                handle: knob;
+              END_ENTITY;
+              ENTITY knob;
+              INVERSE
+                opens: SET [0:?] OF door FOR handle;
+              END_ENTITY;
+              ENTITY round_knob SUBTYPE OF (knob);
+              INVERSE
+                SELF\knob.opens: SET [0:1] OF door FOR handle;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let round_knob = &ir.schemas[0].entities[2];
+        let inverse = &round_knob.inverse_attributes[0];
+        assert_eq!(inverse.name, "opens");
+        assert_eq!(inverse.redeclares.as_deref(), Some("knob"));
+    }
+
+    #[test]
+    fn attribute_not_found() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY door;
+                handle: knob;
+              END_ENTITY;
+              ENTITY knob;
+              INVERSE
+                opens: door FOR hinge;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert!(matches!(
+            IR::from_syntax_tree(&st),
+            Err(SemanticError::AttributeNotFound { attribute, .. }) if attribute == "hinge"
+        ));
+    }
+
+    #[test]
+    fn not_an_entity() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              ENTITY knob;
+              INVERSE
+                opens: label FOR handle;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert!(matches!(
+            IR::from_syntax_tree(&st),
+            Err(SemanticError::NotAnEntity { path }) if path.ty == ScopeType::Type && path.name == "label"
+        ));
+    }
+}
@@ -166,22 +166,31 @@
 //!
 
 mod complex_entity;
+mod constant;
 mod constraints;
+mod domain;
 mod entity;
+mod inverse;
 mod namespace;
 mod schema;
 mod scope;
+mod type_check;
 mod type_decl;
 mod type_ref;
+mod usage;
+mod warning;
 
 pub use complex_entity::*;
 pub use constraints::*;
+pub use domain::*;
 pub use entity::*;
+pub use inverse::*;
 pub use namespace::*;
 pub use schema::*;
 pub use scope::*;
 pub use type_decl::*;
 pub use type_ref::*;
+pub use warning::*;
 
 use crate::ast::SyntaxTree;
 use thiserror::Error;
@@ -192,11 +201,75 @@ pub enum SemanticError {
     #[error("Not found the Type {name} referred in scope {scope}")]
     TypeNotFound { name: String, scope: Scope },
 
-    #[error("Invalid path: {0}")]
-    InvalidPath(Path),
+    /// `segment` is the index of the first undeclared item in the scopes of `path` followed by its name,
+    /// and `candidates` are some of the items of the same type declared in the parent of the segment
+    #[error(
+        "Invalid path: {path}, segment {segment} is not declared{}",
+        display_candidates(.candidates)
+    )]
+    InvalidPath {
+        path: Path,
+        segment: usize,
+        candidates: Vec<String>,
+    },
 
     #[error("Same item ({0}) is declared multiple times")]
     DuplicatedDeclaration(Path),
+
+    #[error("Attribute {attribute} is not declared in entity {entity}")]
+    AttributeNotFound { entity: Path, attribute: String },
+
+    #[error("{ty} type is not allowed in {scope}")]
+    UnexpectedType { ty: String, scope: Scope },
+
+    #[error("Type {path} is defined cyclically by renaming itself")]
+    CyclicTypeDefinition { path: Path },
+
+    #[error("Invalid bound of aggregate: {0}")]
+    InvalidBound(String),
+
+    #[error("{lhs} cannot be compared with {rhs} in {scope}")]
+    TypeMismatch {
+        lhs: String,
+        rhs: String,
+        scope: Scope,
+    },
+
+    #[error("Constant {name} referred in {scope} is not declared in schema {schema}")]
+    ConstantNotFound {
+        schema: String,
+        name: String,
+        scope: Scope,
+    },
+
+    #[error("Constant {schema}.{name} referred in {scope} is not imported by USE FROM or REFERENCE FROM")]
+    ConstantNotImported {
+        schema: String,
+        name: String,
+        scope: Scope,
+    },
+
+    #[error("Procedure {name} called in {scope} is not declared nor imported")]
+    ProcedureNotFound { name: String, scope: Scope },
+
+    #[error("Function {name} called in {scope} is not declared nor imported")]
+    FunctionNotFound { name: String, scope: Scope },
+
+    /// e.g. a constant or a function used as the type of an attribute
+    #[error("{path} is used as a type, but it is neither an entity nor a type")]
+    NotAType { path: Path },
+
+    /// e.g. a type used as the destination of an inverse attribute
+    #[error("{path} is used as an entity, but it is not an entity")]
+    NotAnEntity { path: Path },
+}
+
+fn display_candidates(candidates: &[String]) -> String {
+    if candidates.is_empty() {
+        String::new()
+    } else {
+        format!(" (available: {})", candidates.join(", "))
+    }
 }
 
 /// Legalize partial AST input into corresponding intermediate representation
@@ -213,7 +286,7 @@ pub trait Legalize: Sized {
 }
 
 /// Intermediate Representation
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct IR {
     pub schemas: Vec<Schema>,
 }
@@ -225,6 +298,32 @@ impl IR {
         let ir = Self::legalize(&ns, &ss, &Scope::root(), st)?;
         Ok(ir)
     }
+
+    /// Legalize schemas one by one sharing the namespace and constraints
+    ///
+    /// Unlike [IR::from_syntax_tree], each schema is legalized when the iterator is advanced,
+    /// and can be dropped after use, e.g. after generating its code by `Schema::to_token_stream`.
+    ///
+    /// ```
+    /// use espr::{ast::SyntaxTree, ir::*};
+    ///
+    /// let st = SyntaxTree::parse("SCHEMA a; END_SCHEMA; SCHEMA b; END_SCHEMA;").unwrap();
+    /// let ns = Namespace::new(&st);
+    /// let ss = Constraints::new(&ns, &st).unwrap();
+    /// for schema in IR::legalize_lazily(&ns, &ss, &st) {
+    ///     let schema = schema.unwrap();
+    ///     assert!(schema.name == "a" || schema.name == "b");
+    /// }
+    /// ```
+    pub fn legalize_lazily<'a>(
+        ns: &'a Namespace<'a>,
+        ss: &'a Constraints,
+        st: &'a SyntaxTree,
+    ) -> impl Iterator<Item = Result<Schema, SemanticError>> + 'a {
+        st.schemas
+            .iter()
+            .map(move |schema| Schema::legalize(ns, ss, &Scope::root(), schema))
+    }
 }
 
 impl Legalize for IR {
@@ -8,6 +8,41 @@ use std::collections::HashMap;
 pub enum Named<'st> {
     Type(&'st ast::TypeDecl),
     Entity(&'st ast::Entity),
+    /// Built-in simple type, e.g. `INTEGER`, available without declaration
+    SimpleType(ast::SimpleType),
+    /// Built-in constant, i.e. `PI` or `CONST_E`
+    Constant(ast::BuiltInConstant),
+    /// Constant declared in `CONSTANT` block of a schema
+    DeclaredConstant(&'st ast::Constant),
+}
+
+/// Built-in items registered in the root scope of every [Namespace]
+fn builtins() -> Vec<(Path, Named<'static>)> {
+    use ast::{BuiltInConstant, SimpleType::*};
+    let root = Scope::root();
+    let types = [
+        ("number", Number),
+        ("real", Real),
+        ("integer", Integer),
+        ("logical", Logical),
+        ("boolean", Boolen),
+        ("string", String_ { width_spec: None }),
+        ("binary", Binary { width_spec: None }),
+    ];
+    let constants = [
+        ("pi", BuiltInConstant::Pi),
+        ("const_e", BuiltInConstant::Napier),
+    ];
+    types
+        .into_iter()
+        .map(|(name, ty)| (Path::r#type(&root, name), Named::SimpleType(ty)))
+        .chain(constants.into_iter().map(|(name, constant)| {
+            (
+                Path::new(&root, ScopeType::Constant, name),
+                Named::Constant(constant),
+            )
+        }))
+        .collect()
 }
 
 /// Namespace of loaded EXPRESS schema
@@ -23,6 +58,14 @@ pub struct Namespace<'st> {
     pub names: HashMap<Scope, Vec<(ScopeType, String, usize)>>,
     /// Indexed AST portion
     pub ast: Vec<(Path, Named<'st>)>,
+    /// Constants declared in `CONSTANT` block of each schema
+    pub constants: HashMap<Scope, &'st [ast::Constant]>,
+    /// `USE FROM` and `REFERENCE FROM` of each schema
+    pub interfaces: HashMap<Scope, &'st [ast::InterfaceSpec]>,
+    /// Procedures declared in each schema
+    pub procedures: HashMap<Scope, &'st [ast::Procedure]>,
+    /// Functions declared in each schema
+    pub functions: HashMap<Scope, &'st [ast::Function]>,
 }
 
 impl<'st> std::ops::Index<usize> for Namespace<'st> {
@@ -43,10 +86,26 @@ impl<'st> Namespace<'st> {
     pub fn new(st: &'st SyntaxTree) -> Self {
         let mut names = HashMap::new();
         let mut ast = Vec::new();
+        let mut constants = HashMap::new();
+        let mut interfaces = HashMap::new();
+        let mut procedures = HashMap::new();
+        let mut functions = HashMap::new();
         let root = Scope::root();
 
         for schema in &st.schemas {
             let here = root.pushed(ScopeType::Schema, &schema.name);
+            if !schema.constants.is_empty() {
+                constants.insert(here.clone(), schema.constants.as_slice());
+            }
+            if !schema.interfaces.is_empty() {
+                interfaces.insert(here.clone(), schema.interfaces.as_slice());
+            }
+            if !schema.procedures.is_empty() {
+                procedures.insert(here.clone(), schema.procedures.as_slice());
+            }
+            if !schema.functions.is_empty() {
+                functions.insert(here.clone(), schema.functions.as_slice());
+            }
             let mut current_names = Vec::new();
             for ty in &schema.types {
                 let name = &ty.type_id;
@@ -62,10 +121,75 @@ impl<'st> Namespace<'st> {
                 ast.push((path, Named::Entity(entity)));
                 current_names.push((ScopeType::Entity, name.to_string(), index));
             }
+            for constant in &schema.constants {
+                let name = &constant.name;
+                let path = Path::new(&here, ScopeType::Constant, name);
+                let index = ast.len();
+                ast.push((path, Named::DeclaredConstant(constant)));
+                current_names.push((ScopeType::Constant, name.to_string(), index));
+            }
             names.insert(here, current_names);
         }
 
-        Namespace { names, ast }
+        // Register names imported by `USE FROM` or `REFERENCE FROM` using their local names.
+        // Imported names refer the AST of the original declaration.
+        // Repeated until no new name is registered, since imported names can be imported again,
+        // e.g. `c.point` imported into `b` is imported into `a` by `USE FROM b (point);`.
+        loop {
+            let current = names.clone();
+            let mut registered = false;
+            for schema in &st.schemas {
+                let here = root.pushed(ScopeType::Schema, &schema.name);
+                let mut imported = Vec::new();
+                for interface in &schema.interfaces {
+                    let (source, items) = match interface {
+                        ast::InterfaceSpec::Use { name, types } => (name, types),
+                        ast::InterfaceSpec::Reference { name, resources } => (name, resources),
+                    };
+                    let Some(source_names) = current.get(&root.pushed(ScopeType::Schema, source))
+                    else {
+                        continue;
+                    };
+                    if items.is_empty() {
+                        imported.extend(source_names.iter().cloned());
+                    }
+                    for (item, rename) in items {
+                        if let Some((ty, _, index)) = source_names
+                            .iter()
+                            .find(|(_, n, _)| n.eq_ignore_ascii_case(item))
+                        {
+                            let local = rename.as_ref().unwrap_or(item);
+                            imported.push((*ty, local.clone(), *index));
+                        }
+                    }
+                }
+                let names = names.entry(here).or_default();
+                for name in imported {
+                    if !names.contains(&name) {
+                        names.push(name);
+                        registered = true;
+                    }
+                }
+            }
+            if !registered {
+                break;
+            }
+        }
+        ast.extend(builtins());
+
+        Namespace {
+            names,
+            ast,
+            constants,
+            interfaces,
+            procedures,
+            functions,
+        }
+    }
+
+    /// Check if the schema `name` is declared
+    pub fn contains_schema(&self, name: &str) -> bool {
+        self.names.contains_key(&Scope::root().schema(name))
     }
 
     pub fn is_empty(&self) -> bool {
@@ -79,6 +203,11 @@ impl<'st> Namespace<'st> {
 
     /// Resolve a `name` referred in a `scope` into the full path.
     ///
+    /// A name imported by `USE FROM` or `REFERENCE FROM`, possibly renamed by `AS`,
+    /// is resolved into the path of the original declaration.
+    /// Built-in simple types and constants, e.g. `INTEGER` or `PI`,
+    /// are resolved case-insensitively into paths in the root scope.
+    ///
     /// Error
     /// ------
     /// - If no corresponding definition found.
@@ -87,17 +216,181 @@ impl<'st> Namespace<'st> {
         let mut scope = scope.clone();
         loop {
             if let Some(names) = self.names.get(&scope) {
-                for (ty, n, index) in names {
-                    if name == n {
-                        return Ok((Path::new(&scope, *ty, n), *index));
+                for (_ty, n, index) in names {
+                    if name.eq_ignore_ascii_case(n) {
+                        return Ok((self.ast[*index].0.clone(), *index));
                     }
                 }
             }
-            scope = scope.popped().ok_or_else(|| SemanticError::TypeNotFound {
+            scope = match scope.popped() {
+                Some(scope) => scope,
+                None => break,
+            };
+        }
+        let root = Scope::root();
+        self.ast
+            .iter()
+            .position(|(path, _)| path.scope == root && path.name.eq_ignore_ascii_case(name))
+            .map(|index| (self.ast[index].0.clone(), index))
+            .ok_or_else(|| SemanticError::TypeNotFound {
+                scope: root,
+                name: name.to_string(),
+            })
+    }
+
+    /// Resolve a constant `name` qualified by `schema`, e.g. `geometry.origin`, referred in a `scope`
+    ///
+    /// The constant must be declared in the same schema as `scope`,
+    /// or imported by `USE FROM` or `REFERENCE FROM` the schema `schema`.
+    /// Returns `None` if `schema` is not a schema name, i.e. this is not a qualified constant reference.
+    ///
+    /// Error
+    /// ------
+    /// - If the constant is not declared in `schema`, or is not imported.
+    ///
+    pub fn resolve_constant(
+        &self,
+        scope: &Scope,
+        schema: &str,
+        name: &str,
+    ) -> Result<Option<&'st ast::Constant>, SemanticError> {
+        let source = Scope::root().schema(schema);
+        if !self.names.contains_key(&source) {
+            return Ok(None);
+        }
+        let constant = self
+            .constants
+            .get(&source)
+            .and_then(|constants| constants.iter().find(|c| c.name.eq_ignore_ascii_case(name)))
+            .ok_or_else(|| SemanticError::ConstantNotFound {
+                schema: schema.to_string(),
+                name: name.to_string(),
                 scope: scope.clone(),
+            })?;
+
+        let here = scope.schema_name().map(|here| Scope::root().schema(here));
+        if here.as_ref() == Some(&source) {
+            return Ok(Some(constant));
+        }
+        let imported = here
+            .and_then(|here| self.interfaces.get(&here))
+            .into_iter()
+            .flat_map(|interfaces| interfaces.iter())
+            .any(|interface| {
+                let (source, items) = match interface {
+                    ast::InterfaceSpec::Use { name, types } => (name, types),
+                    ast::InterfaceSpec::Reference { name, resources } => (name, resources),
+                };
+                source == schema
+                    && (items.is_empty()
+                        || items
+                            .iter()
+                            .any(|(item, _)| item.eq_ignore_ascii_case(name)))
+            });
+        if imported {
+            Ok(Some(constant))
+        } else {
+            Err(SemanticError::ConstantNotImported {
+                schema: schema.to_string(),
+                name: name.to_string(),
+                scope: scope.clone(),
+            })
+        }
+    }
+
+    /// Resolve a procedure `name` called in a `scope`
+    ///
+    /// The procedure must be declared in the same schema as `scope`,
+    /// or imported by `USE FROM` or `REFERENCE FROM`, possibly renamed by `AS`.
+    /// Procedures declared locally in functions, procedures, or rules are not registered.
+    ///
+    /// Error
+    /// ------
+    /// - If no corresponding procedure found.
+    ///
+    pub fn resolve_procedure(
+        &self,
+        scope: &Scope,
+        name: &str,
+    ) -> Result<&'st ast::Procedure, SemanticError> {
+        self.resolve_algorithm(&self.procedures, |p| &p.name, scope, name)
+            .map(|(_schema, procedure)| procedure)
+            .ok_or_else(|| SemanticError::ProcedureNotFound {
+                name: name.to_string(),
+                scope: scope.clone(),
+            })
+    }
+
+    /// Resolve a function `name` called in a `scope` into its [Path] in [ScopeType::Function]
+    ///
+    /// The function is looked up as [Namespace::resolve_procedure] does,
+    /// and the path is in the schema declaring it, e.g. `geometry.double` for `USE FROM geometry (double AS twice);`.
+    ///
+    /// Error
+    /// ------
+    /// - If no corresponding function found.
+    ///
+    pub fn resolve_function(
+        &self,
+        scope: &Scope,
+        name: &str,
+    ) -> Result<(Path, &'st ast::Function), SemanticError> {
+        let (schema, function) = self
+            .resolve_algorithm(&self.functions, |f| &f.name, scope, name)
+            .ok_or_else(|| SemanticError::FunctionNotFound {
                 name: name.to_string(),
+                scope: scope.clone(),
+            })?;
+        Ok((Path::function(&schema, &function.name), function))
+    }
+
+    /// Find an algorithm, i.e. a procedure or a function, declared in or imported into the schema of `scope`,
+    /// with the schema declaring it
+    fn resolve_algorithm<T>(
+        &self,
+        declared: &HashMap<Scope, &'st [T]>,
+        name_of: impl Fn(&T) -> &str,
+        scope: &Scope,
+        name: &str,
+    ) -> Option<(Scope, &'st T)> {
+        let find = |schema: &str, name: &str| {
+            let schema = Scope::root().schema(schema);
+            let item = declared.get(&schema).and_then(|items| {
+                items
+                    .iter()
+                    .find(|item| name_of(item).eq_ignore_ascii_case(name))
             })?;
+            Some((schema, item))
+        };
+        let here = scope.schema_name()?;
+        if let Some(item) = find(here, name) {
+            return Some(item);
         }
+        let interfaces = self
+            .interfaces
+            .get(&Scope::root().schema(here))
+            .into_iter()
+            .flat_map(|interfaces| interfaces.iter());
+        for interface in interfaces {
+            let (source, items) = match interface {
+                ast::InterfaceSpec::Use { name, types } => (name, types),
+                ast::InterfaceSpec::Reference { name, resources } => (name, resources),
+            };
+            let original = if items.is_empty() {
+                Some(name)
+            } else {
+                items
+                    .iter()
+                    .find(|(item, rename)| {
+                        rename.as_ref().unwrap_or(item).eq_ignore_ascii_case(name)
+                    })
+                    .map(|(item, _)| item.as_str())
+            };
+            if let Some(item) = original.and_then(|original| find(source, original)) {
+                return Some(item);
+            }
+        }
+        None
     }
 
     /// Get an AST portion and its index corresponding the [Path]
@@ -112,10 +405,76 @@ impl<'st> Namespace<'st> {
                 return Ok((*ast, index));
             }
         }
-        Err(SemanticError::InvalidPath(path.clone()))
+        Err(self.invalid_path(path))
+    }
+
+    /// [SemanticError::InvalidPath] with the first undeclared segment of `path`
+    ///
+    /// When every segment is declared, e.g. the path exists but is not an expected kind of item,
+    /// the last segment, i.e. the name of `path`, is reported.
+    pub fn invalid_path(&self, path: &Path) -> SemanticError {
+        let segments: Vec<(ScopeType, &str)> = path
+            .scope
+            .segments()
+            .iter()
+            .map(|(ty, name)| (*ty, name.as_str()))
+            .chain(std::iter::once((path.ty, path.name.as_str())))
+            .collect();
+        let mut parent = Scope::root();
+        for (segment, (ty, name)) in segments.iter().enumerate() {
+            let is_last = segment + 1 == segments.len();
+            if is_last || !self.is_declared(&parent, *ty, name) {
+                let mut candidates = self.declared_names(&parent, *ty);
+                candidates.truncate(MAX_CANDIDATES);
+                return SemanticError::InvalidPath {
+                    path: path.clone(),
+                    segment,
+                    candidates,
+                };
+            }
+            parent = parent.pushed(*ty, name);
+        }
+        unreachable!("Path has at least one segment")
+    }
+
+    fn is_declared(&self, parent: &Scope, ty: ScopeType, name: &str) -> bool {
+        self.declared_names(parent, ty).iter().any(|n| n == name)
+    }
+
+    /// Sorted names of `ty` items declared in `scope`
+    fn declared_names(&self, scope: &Scope, ty: ScopeType) -> Vec<String> {
+        let mut names: Vec<String> = if ty == ScopeType::Schema && scope == &Scope::root() {
+            self.names
+                .keys()
+                .filter_map(|scope| match scope.segments() {
+                    [(ScopeType::Schema, name)] => Some(name.clone()),
+                    _ => None,
+                })
+                .collect()
+        } else {
+            self.ast
+                .iter()
+                .filter(|(path, _)| &path.scope == scope && path.ty == ty)
+                .map(|(path, _)| path.name.clone())
+                .chain(
+                    self.names
+                        .get(scope)
+                        .into_iter()
+                        .flatten()
+                        .filter(|(t, _, _)| *t == ty)
+                        .map(|(_, name, _)| name.clone()),
+                )
+                .collect()
+        };
+        names.sort();
+        names.dedup();
+        names
     }
 }
 
+/// Number of names listed in [SemanticError::InvalidPath]
+const MAX_CANDIDATES: usize = 8;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +516,243 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resolve_imported() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              ENTITY point;
+                x, y, z: REAL;
+              END_ENTITY;
+              TYPE label = STRING;
+              END_TYPE;
+            END_SCHEMA;
+
+            SCHEMA drawing;
+              USE FROM geometry (point AS pt);
+              REFERENCE FROM geometry;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let drawing = Scope::root().schema("drawing");
+        assert_eq!(
+            ns.resolve(&drawing, "pt").unwrap().0,
+            Path::entity(&geometry, "point")
+        );
+        assert_eq!(
+            ns.resolve(&drawing, "label").unwrap().0,
+            Path::r#type(&geometry, "label")
+        );
+        assert!(ns.resolve(&geometry, "pt").is_err());
+    }
+
+    #[test]
+    fn resolve_case_insensitive() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              ENTITY cartesian_point;
+                x, y, z: REAL;
+              END_ENTITY;
+              ENTITY line;
+                p: Cartesian_Point;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA drawing;
+              USE FROM geometry (CARTESIAN_POINT AS pt);
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let point = Path::entity(&geometry, "cartesian_point");
+        assert_eq!(ns.resolve(&geometry, "Cartesian_Point").unwrap().0, point);
+        assert_eq!(
+            ns.resolve(&Scope::root().schema("drawing"), "PT")
+                .unwrap()
+                .0,
+            point
+        );
+
+        // Declared spelling is kept in IR
+        let ir = crate::ir::IR::from_syntax_tree(&st).unwrap();
+        let line = &ir.schemas[0].entities[1];
+        assert!(matches!(
+            &line.attributes[0].ty,
+            crate::ir::TypeRef::Entity { name, .. } if name == "cartesian_point"
+        ));
+    }
+
+    #[test]
+    fn resolve_constant_name() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              CONSTANT
+                origin : REAL := 0.0;
+              END_CONSTANT;
+            END_SCHEMA;
+
+            SCHEMA drawing;
+              REFERENCE FROM geometry (origin);
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let origin = Path::new(&geometry, ScopeType::Constant, "origin");
+        let (path, index) = ns.resolve(&geometry.entity("e"), "origin").unwrap();
+        assert_eq!(path, origin);
+        assert!(matches!(ns[index].1, Named::DeclaredConstant(c) if c.name == "origin"));
+        assert_eq!(
+            ns.resolve(&Scope::root().schema("drawing"), "origin")
+                .unwrap()
+                .0,
+            origin
+        );
+        crate::ir::IR::from_syntax_tree(&st).unwrap();
+    }
+
+    #[test]
+    fn constant_as_type() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              CONSTANT
+                c : REAL := 0.0;
+              END_CONSTANT;
+              ENTITY e;
+                x : c;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let c = Path::new(&Scope::root().schema("geometry"), ScopeType::Constant, "c");
+        assert!(matches!(
+            crate::ir::IR::from_syntax_tree(&st),
+            Err(crate::ir::SemanticError::NotAType { path }) if path == c
+        ));
+    }
+
+    #[test]
+    fn resolve_builtin() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              TYPE integer_like = INTEGER;
+              END_TYPE;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let (path, index) = ns.resolve(&geometry.entity("e"), "INTEGER").unwrap();
+        assert_eq!(path, Path::r#type(&Scope::root(), "integer"));
+        assert!(matches!(
+            ns[index].1,
+            Named::SimpleType(ast::SimpleType::Integer)
+        ));
+        let (_path, index) = ns.resolve(&geometry, "pi").unwrap();
+        assert!(matches!(
+            ns[index].1,
+            Named::Constant(ast::BuiltInConstant::Pi)
+        ));
+        assert!(ns.resolve(&geometry, "integer_like").is_ok());
+        assert!(ns.resolve(&geometry, "undefined").is_err());
+    }
+
+    #[test]
+    fn resolve_procedure() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              PROCEDURE swap(VAR a, b : REAL);
+                LOCAL
+                  t : REAL;
+                END_LOCAL;
+                t := a;
+                a := b;
+                b := t;
+              END_PROCEDURE;
+            END_SCHEMA;
+
+            SCHEMA drawing;
+              USE FROM geometry (swap AS exchange);
+            END_SCHEMA;
+
+            SCHEMA sketch;
+              REFERENCE FROM geometry;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let drawing = Scope::root().schema("drawing");
+        let sketch = Scope::root().schema("sketch");
+        assert!(ns.resolve_procedure(&geometry, "swap").unwrap().parameters[0].is_variable);
+        assert_eq!(
+            ns.resolve_procedure(&drawing.rule("r"), "exchange")
+                .unwrap()
+                .name,
+            "swap"
+        );
+        assert_eq!(ns.resolve_procedure(&sketch, "swap").unwrap().name, "swap");
+        assert!(matches!(
+            ns.resolve_procedure(&drawing, "swap"),
+            Err(SemanticError::ProcedureNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn resolve_function() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              FUNCTION double(x : REAL) : REAL;
+                RETURN (2.0 * x);
+              END_FUNCTION;
+            END_SCHEMA;
+
+            SCHEMA drawing;
+              USE FROM geometry (double AS twice);
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let geometry = Scope::root().schema("geometry");
+        let drawing = Scope::root().schema("drawing");
+        let (path, function) = ns.resolve_function(&drawing.entity("e"), "twice").unwrap();
+        assert_eq!(path, Path::function(&geometry, "double"));
+        assert_eq!(function.parameters.len(), 1);
+        assert_eq!(
+            ns.resolve_function(&geometry.function("double"), "DOUBLE")
+                .unwrap()
+                .0,
+            path
+        );
+        assert!(matches!(
+            ns.resolve_function(&drawing, "double"),
+            Err(SemanticError::FunctionNotFound { .. })
+        ));
+    }
+
     #[test]
     fn namespace_debug() {
         let st = ast::SyntaxTree::parse(
@@ -301,8 +897,112 @@ mod tests {
                         },
                     ),
                 ),
+                (
+                    Scope().number[Type],
+                    SimpleType(
+                        Number,
+                    ),
+                ),
+                (
+                    Scope().real[Type],
+                    SimpleType(
+                        Real,
+                    ),
+                ),
+                (
+                    Scope().integer[Type],
+                    SimpleType(
+                        Integer,
+                    ),
+                ),
+                (
+                    Scope().logical[Type],
+                    SimpleType(
+                        Logical,
+                    ),
+                ),
+                (
+                    Scope().boolean[Type],
+                    SimpleType(
+                        Boolen,
+                    ),
+                ),
+                (
+                    Scope().string[Type],
+                    SimpleType(
+                        String_ {
+                            width_spec: None,
+                        },
+                    ),
+                ),
+                (
+                    Scope().binary[Type],
+                    SimpleType(
+                        Binary {
+                            width_spec: None,
+                        },
+                    ),
+                ),
+                (
+                    Scope().pi[Constant],
+                    Constant(
+                        Pi,
+                    ),
+                ),
+                (
+                    Scope().const_e[Constant],
+                    Constant(
+                        Napier,
+                    ),
+                ),
             ],
+            constants: {},
+            interfaces: {},
+            procedures: {},
+            functions: {},
         }
         "###);
     }
+
+    #[test]
+    fn invalid_path() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA one;
+              ENTITY first;
+                x : REAL;
+              END_ENTITY;
+              ENTITY second;
+                y : REAL;
+              END_ENTITY;
+              TYPE label = STRING;
+              END_TYPE;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ns = Namespace::new(&st);
+        let one = Scope::root().schema("one");
+
+        let e = ns.get(&Path::entity(&one, "third")).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "Invalid path: one.third, segment 1 is not declared (available: first, second)"
+        );
+        let e = ns
+            .get(&Path::entity(&one.entity("third"), "x"))
+            .unwrap_err();
+        assert!(matches!(
+            e,
+            SemanticError::InvalidPath { segment: 1, ref candidates, .. } if candidates == &["first", "second"]
+        ));
+        let e = ns
+            .get(&Path::entity(&Scope::root().schema("two"), "first"))
+            .unwrap_err();
+        assert!(matches!(
+            e,
+            SemanticError::InvalidPath { segment: 0, ref candidates, .. } if candidates == &["one"]
+        ));
+    }
 }
@@ -1,11 +1,27 @@
 use super::{entity::*, namespace::*, scope::*, type_decl::*, *};
 use crate::ast;
+use std::collections::{HashMap, HashSet};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Schema {
     pub name: String,
     pub entities: Vec<Entity>,
     pub types: Vec<TypeDecl>,
+    /// Items imported by `USE FROM` or `REFERENCE FROM` with their item lists
+    pub aliases: Vec<ImportAlias>,
+    /// Schemas whose all items are imported, e.g. `other` for `USE FROM other;`
+    pub imported_schemas: Vec<String>,
+}
+
+/// Item imported with a local name, e.g. `USE FROM other (point AS pt);`
+///
+/// The local name is the same as the original one if not renamed by `AS`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportAlias {
+    /// Local name, `pt` in the above example
+    pub name: String,
+    /// Reference to the original declaration, `other.point` in the above example
+    pub ty: TypeRef,
 }
 
 impl Legalize for Schema {
@@ -18,24 +34,155 @@ impl Legalize for Schema {
     ) -> Result<Self, SemanticError> {
         let name = schema.name.clone();
         let here = scope.pushed(ScopeType::Schema, &name);
-        let entities = schema
+        let mut entities = schema
             .entities
             .iter()
             .map(|entity| Entity::legalize(ns, ss, &here, entity))
             .collect::<Result<Vec<Entity>, _>>()?;
-        let types = schema
+        let mut types = schema
             .types
             .iter()
             .map(|entity| TypeDecl::legalize(ns, ss, &here, entity))
             .collect::<Result<Vec<TypeDecl>, _>>()?;
+        mark_recursive_attributes(&here, &mut entities, &types);
+        if entities
+            .iter()
+            .flat_map(|entity| &entity.attributes)
+            .any(|attr| attr.ty.is_generic_entity())
+        {
+            types.push(generic_entity(&here, &entities));
+        }
+        let mut aliases = Vec::new();
+        let mut imported_schemas = Vec::new();
+        for interface in &schema.interfaces {
+            let (source, items) = match interface {
+                ast::InterfaceSpec::Use { name, types } => (name, types),
+                ast::InterfaceSpec::Reference { name, resources } => (name, resources),
+            };
+            if items.is_empty() {
+                if ns.contains_schema(source) && !imported_schemas.contains(source) {
+                    imported_schemas.push(source.clone());
+                }
+                continue;
+            }
+            for (item, rename) in items {
+                let path = match rename {
+                    Some(rename) => ns.resolve(&here, rename)?.0,
+                    // Items other than entities, types and constants, e.g. functions,
+                    // are not registered in the namespace
+                    None => match ns.resolve(&here, item) {
+                        Ok((path, _index)) if path.scope != here => path,
+                        _ => continue,
+                    },
+                };
+                // Constants are referred through the namespace, not as types
+                if path.ty == ScopeType::Constant {
+                    continue;
+                }
+                aliases.push(ImportAlias {
+                    name: rename.as_ref().unwrap_or(item).clone(),
+                    ty: TypeRef::from_path(ns, ss, &path)?,
+                });
+            }
+        }
         Ok(Schema {
             name,
             entities,
             types,
+            aliases,
+            imported_schemas,
         })
     }
 }
 
+/// `SELECT` of all entities declared in the schema, which `GENERIC_ENTITY` attributes refer
+///
+/// Each member is the entity itself rather than `XxxAny` since subtypes are also members.
+fn generic_entity(scope: &Scope, entities: &[Entity]) -> TypeDecl {
+    TypeDecl::Select(Select {
+        id: GENERIC_ENTITY.to_string(),
+        types: entities
+            .iter()
+            .map(|entity| TypeRef::Entity {
+                name: entity.name.clone(),
+                scope: scope.clone(),
+                is_supertype: false,
+            })
+            .collect(),
+    })
+}
+
+/// Entity or defined type declared in `scope` which is directly contained in a struct of the field typed `ty`
+fn owned_type<'a>(scope: &Scope, ty: &'a TypeRef) -> Option<&'a str> {
+    match ty {
+        // `XxxAny` enum boxes its variants, and aggregates are stored in `Vec`
+        TypeRef::Entity {
+            name,
+            scope: s,
+            is_supertype: false,
+        } if s == scope => Some(name),
+        TypeRef::Named { name, scope: s, .. } if s == scope => Some(name),
+        _ => None,
+    }
+}
+
+/// Set [EntityAttribute::is_recursive] using dependency graph of entities and defined types in `scope`
+fn mark_recursive_attributes(scope: &Scope, entities: &mut [Entity], types: &[TypeDecl]) {
+    // Edges to entities and defined types whose struct is directly contained, including supertypes
+    let mut graph: HashMap<String, Vec<String>> = entities
+        .iter()
+        .map(|entity| {
+            let deps = entity
+                .attributes
+                .iter()
+                .map(|attr| &attr.ty)
+                .chain(entity.supertypes.iter())
+                .filter_map(|ty| owned_type(scope, ty).map(str::to_string))
+                .collect();
+            (entity.name.clone(), deps)
+        })
+        .collect();
+    // Defined types wrap the underlying type, e.g. `TYPE a = b;` into `struct A(B)`,
+    // while `SELECT` boxes its members
+    graph.extend(types.iter().filter_map(|decl| {
+        match decl {
+            TypeDecl::Rename(Rename { id, ty }) => Some((
+                id.clone(),
+                owned_type(scope, ty)
+                    .map(str::to_string)
+                    .into_iter()
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }));
+
+    let reachable = |start: &str, goal: &str| -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            if current == goal {
+                return true;
+            }
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(deps) = graph.get(current) {
+                stack.extend(deps.iter().map(String::as_str));
+            }
+        }
+        false
+    };
+
+    for entity in entities.iter_mut() {
+        for attr in &mut entity.attributes {
+            if let Some(target) = owned_type(scope, &attr.ty) {
+                attr.is_recursive = reachable(target, &entity.name);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,4 +198,96 @@ mod tests {
         let schema = Schema::legalize(&ns, &ss, &scope, schema).unwrap();
         dbg!(&schema);
     }
+
+    #[test]
+    fn recursive_attributes() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              ENTITY tree_node;
+                parent: OPTIONAL tree_node;
+                children: LIST OF tree_node;
+                leaf: leaf;
+              END_ENTITY;
+              ENTITY leaf;
+                x: REAL;
+              END_ENTITY;
+              ENTITY a;
+                b: b;
+              END_ENTITY;
+              ENTITY b;
+                a: OPTIONAL a;
+              END_ENTITY;
+              TYPE wrapped = c;
+              END_TYPE;
+              TYPE wrapped_twice = wrapped;
+              END_TYPE;
+              TYPE c_select = SELECT(c);
+              END_TYPE;
+              ENTITY c;
+                next: OPTIONAL wrapped_twice;
+                choice: OPTIONAL c_select;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let recursive: Vec<_> = ir.schemas[0]
+            .entities
+            .iter()
+            .flat_map(|entity| {
+                entity
+                    .attributes
+                    .iter()
+                    .map(move |attr| (entity.name.as_str(), attr.name.as_str(), attr.is_recursive))
+            })
+            .collect();
+        assert_eq!(
+            recursive,
+            [
+                ("tree_node", "parent", true),
+                ("tree_node", "children", false),
+                ("tree_node", "leaf", false),
+                ("leaf", "x", false),
+                ("a", "b", true),
+                ("b", "a", true),
+                // `SELECT` boxes its members
+                ("c", "next", true),
+                ("c", "choice", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn function_call() {
+        // Functions called in domain rules and derived attributes are not types
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              FUNCTION double(x : REAL) : REAL;
+                LOCAL
+                  y : REAL := 2.0;
+                END_LOCAL;
+                RETURN (x * y);
+              END_FUNCTION;
+              ENTITY a;
+                x : REAL;
+              DERIVE
+                d : REAL := double(x);
+              WHERE
+                wr1: double(x) > 0.0;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert_eq!(st.schemas[0].functions[0].name, "double");
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let a = &ir.schemas[0].entities[0];
+        assert_eq!(a.derived_attributes.len(), 1);
+        assert_eq!(a.domain_rules.len(), 1);
+    }
 }
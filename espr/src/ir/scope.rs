@@ -15,6 +15,33 @@ pub enum ScopeType {
     Schema,
     SubType,
     Type,
+    /// Built-in constants, e.g. `PI`, which do not define scopes but identifiers
+    Constant,
+}
+
+impl ScopeType {
+    /// Check if a scope of this type can be declared directly in a scope of `parent` type
+    ///
+    /// `parent` is `None` for the root scope. The legal nesting is:
+    ///
+    /// - Root contains only `Schema`
+    /// - `Schema` contains `Entity`, `Type`, `SubType`, `Function`, `Procedure` and `Rule`
+    /// - `Function`, `Procedure` and `Rule` contain declarations in `Schema` except `Rule`,
+    ///   and `Alias`, `Repeat` and `Query` in their bodies
+    /// - `Alias` and `Repeat` statements contain `Alias`, `Repeat` and `Query`
+    /// - `Entity`, `Type` and `Query` contain `Query` in their expressions
+    /// - `Constant` is not a scope, and never nests
+    pub fn can_nest_in(self, parent: Option<ScopeType>) -> bool {
+        use ScopeType::*;
+        match parent {
+            None => self == Schema,
+            Some(Schema) => matches!(self, Entity | Type | SubType | Function | Procedure | Rule),
+            Some(Function | Procedure | Rule) => !matches!(self, Schema | Rule | Constant),
+            Some(Alias | Repeat) => matches!(self, Alias | Repeat | Query),
+            Some(Entity | Type | Query) => self == Query,
+            Some(SubType | Constant) => false,
+        }
+    }
 }
 
 /// Scope declaration
@@ -96,7 +123,18 @@ impl Scope {
         Self(Vec::new())
     }
 
+    /// Push a new scope of `ty` type into this scope
+    ///
+    /// The nesting must be legal in the sense of [ScopeType::can_nest_in],
+    /// which is checked by debug assertion.
     pub fn pushed(&self, ty: ScopeType, name: &str) -> Self {
+        debug_assert!(
+            ty.can_nest_in(self.current_type()),
+            "{:?} scope `{}` cannot be declared in {:?}",
+            ty,
+            name,
+            self
+        );
         let mut new = self.clone();
         new.0.push((ty, name.to_string()));
         new
@@ -113,6 +151,24 @@ impl Scope {
     add_scope!(subtype, SubType);
     add_scope!(r#type, Type);
 
+    /// Type of the innermost scope, `None` for root
+    pub fn current_type(&self) -> Option<ScopeType> {
+        self.0.last().map(|(ty, _name)| *ty)
+    }
+
+    /// Name of the schema containing this scope, `None` for root
+    pub fn schema_name(&self) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(ty, _name)| *ty == ScopeType::Schema)
+            .map(|(_ty, name)| name.as_str())
+    }
+
+    /// Scopes from the outermost, e.g. the schema, to the innermost
+    pub fn segments(&self) -> &[(ScopeType, String)] {
+        &self.0
+    }
+
     /// Pop the last scope
     ///
     /// Returns `None` when `self` is root.
@@ -192,4 +248,28 @@ mod tests {
             "Scope(schema1[Schema].entity1[Entity])"
         );
     }
+
+    #[test]
+    fn nesting() {
+        use ScopeType::*;
+        assert!(Schema.can_nest_in(None));
+        assert!(!Entity.can_nest_in(None));
+        assert!(Entity.can_nest_in(Some(Schema)));
+        assert!(!Schema.can_nest_in(Some(Schema)));
+        assert!(Function.can_nest_in(Some(Rule)));
+        assert!(!Rule.can_nest_in(Some(Function)));
+        assert!(Query.can_nest_in(Some(Entity)));
+        assert!(!Entity.can_nest_in(Some(Entity)));
+
+        let scope = Scope::root().schema("s").function("f").repeat("i");
+        assert_eq!(scope.current_type(), Some(Repeat));
+        assert_eq!(Scope::root().current_type(), None);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "Entity scope `entity1` cannot be declared in Scope()")]
+    fn entity_in_root() {
+        let _ = Scope::root().pushed(ScopeType::Entity, "entity1");
+    }
 }
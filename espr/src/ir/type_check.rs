@@ -0,0 +1,321 @@
+use super::*;
+use crate::ast;
+use std::fmt;
+
+/// Coarse type of an expression, enough to find obviously invalid comparisons
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Number,
+    String,
+    Binary,
+    Logical,
+    Enumeration,
+    Entity,
+    Aggregate,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Kind::Number => "NUMBER",
+            Kind::String => "STRING",
+            Kind::Binary => "BINARY",
+            Kind::Logical => "LOGICAL",
+            Kind::Enumeration => "ENUMERATION",
+            Kind::Entity => "ENTITY",
+            Kind::Aggregate => "AGGREGATE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Named types are resolved through at most this depth, to stop at cyclic definitions
+const MAX_DEPTH: usize = 16;
+
+/// Light type checker of expressions in an entity, e.g. `WHERE` and `DERIVE` clauses
+pub(super) struct TypeChecker<'a, 'st> {
+    ns: &'a Namespace<'st>,
+    /// Scope where the entity is declared
+    scope: &'a Scope,
+    entity: &'a str,
+    /// Attributes of the entity visible in the expressions
+    attributes: Vec<(&'a str, &'a ast::Type)>,
+}
+
+impl<'a, 'st> TypeChecker<'a, 'st> {
+    pub(super) fn new(ns: &'a Namespace<'st>, scope: &'a Scope, entity: &'a ast::Entity) -> Self {
+        let explicit = entity.attributes.iter().map(|attr| (&attr.name, &attr.ty));
+        let derived = entity
+            .derive_clause
+            .iter()
+            .flat_map(|clause| &clause.attributes)
+            .map(|attr| (&attr.attr, &attr.ty));
+        let attributes = explicit
+            .chain(derived)
+            .filter_map(|(name, ty)| match name {
+                ast::AttributeDecl::Reference(name) => Some((name.as_str(), ty)),
+                ast::AttributeDecl::Qualified { .. } => None,
+            })
+            .collect();
+        TypeChecker {
+            ns,
+            scope,
+            entity: &entity.name,
+            attributes,
+        }
+    }
+
+    /// Check comparisons in the expression, e.g. `x = 'a'` for `x: REAL`
+    ///
+    /// Operands whose types cannot be inferred easily, e.g. function calls or inherited attributes,
+    /// are compatible with anything.
+    pub(super) fn check(&self, expr: &ast::Expression) -> Result<(), SemanticError> {
+        use ast::Expression::*;
+        match expr {
+            Literal(_) | EnumerationReference { .. } => {}
+            Unary { arg, .. } => self.check(arg)?,
+            Binary { arg1, arg2, .. } => {
+                self.check(arg1)?;
+                self.check(arg2)?;
+            }
+            Relation { op, lhs, rhs } => {
+                self.check(lhs)?;
+                self.check(rhs)?;
+                if is_comparison(*op) {
+                    if let (Some(l), Some(r)) = (self.infer(lhs), self.infer(rhs)) {
+                        if l != r {
+                            return Err(SemanticError::TypeMismatch {
+                                lhs: l.to_string(),
+                                rhs: r.to_string(),
+                                scope: self.scope.entity(self.entity),
+                            });
+                        }
+                    }
+                }
+            }
+            QualifiableFactor { factor, qualifiers } => {
+                if let ast::QualifiableFactor::FunctionCall { args, .. } = factor {
+                    for arg in args {
+                        self.check(arg)?;
+                    }
+                }
+                for qualifier in qualifiers {
+                    match qualifier {
+                        ast::Qualifier::Index(index) => self.check(index)?,
+                        ast::Qualifier::Range { begin, end } => {
+                            self.check(begin)?;
+                            self.check(end)?;
+                        }
+                        ast::Qualifier::Attribute(_) | ast::Qualifier::Group(_) => {}
+                    }
+                }
+            }
+            EntityConstructor { values, .. } => {
+                for value in values {
+                    self.check(value)?;
+                }
+            }
+            Interval {
+                high, low, item, ..
+            } => {
+                self.check(high)?;
+                self.check(low)?;
+                self.check(item)?;
+            }
+            AggregateInitializer { elements } => {
+                for element in elements {
+                    self.check(&element.expr)?;
+                }
+            }
+            // Query variable is not an attribute, and is not inferred
+            Query { source, .. } => self.check(source)?,
+        }
+        Ok(())
+    }
+
+    fn infer(&self, expr: &ast::Expression) -> Option<Kind> {
+        use ast::{BinaryOperator::*, BuiltInFunction::*, Expression, UnaryOperator::*};
+        match expr {
+            Expression::Literal(ast::Literal::Integer(_) | ast::Literal::Real(_)) => {
+                Some(Kind::Number)
+            }
+            Expression::Literal(ast::Literal::String(_)) => Some(Kind::String),
+            Expression::Literal(ast::Literal::Logial(_)) => Some(Kind::Logical),
+            Expression::EnumerationReference { .. } => Some(Kind::Enumeration),
+            Expression::Unary { op: Not, .. } => Some(Kind::Logical),
+            Expression::Unary { arg, .. } => self.infer(arg).filter(|kind| *kind == Kind::Number),
+            Expression::Binary { op, arg1, arg2 } => match op {
+                And | Or | Xor => Some(Kind::Logical),
+                Mul | RealDiv | IntegerDiv | Mod | Sub | Power => {
+                    numeric(self.infer(arg1)?, self.infer(arg2)?)
+                }
+                // `+` also concatenates strings and aggregates
+                Add => match (self.infer(arg1)?, self.infer(arg2)?) {
+                    (l, r) if l == r => Some(l),
+                    _ => None,
+                },
+                ComplexEntityInstanceConstruction => Some(Kind::Entity),
+            },
+            Expression::Relation { .. } | Expression::Interval { .. } => Some(Kind::Logical),
+            Expression::EntityConstructor { .. } => Some(Kind::Entity),
+            Expression::AggregateInitializer { .. } | Expression::Query { .. } => {
+                Some(Kind::Aggregate)
+            }
+            Expression::QualifiableFactor { factor, qualifiers } => {
+                match (factor, qualifiers.as_slice()) {
+                    (ast::QualifiableFactor::Reference(name), []) => self.attribute(name),
+                    (
+                        ast::QualifiableFactor::BuiltInConstant(ast::BuiltInConstant::Self_),
+                        [ast::Qualifier::Attribute(name)],
+                    ) => self.attribute(name),
+                    (ast::QualifiableFactor::BuiltInConstant(constant), []) => match constant {
+                        ast::BuiltInConstant::Napier | ast::BuiltInConstant::Pi => {
+                            Some(Kind::Number)
+                        }
+                        ast::BuiltInConstant::Self_ => Some(Kind::Entity),
+                        ast::BuiltInConstant::Indeterminate => None,
+                    },
+                    (
+                        ast::QualifiableFactor::FunctionCall {
+                            name: ast::FunctionCallName::BuiltInFunction(f),
+                            ..
+                        },
+                        [],
+                    ) => match f {
+                        ABS | ACOS | ASIN | ATAN | BLENGTH | COS | EXP | HIBOUND | HIINDEX
+                        | LENGTH | LOBOUND | LOINDEX | LOG | LOG2 | LOG10 | SIN | SIZEOF | SQRT
+                        | TAN | VALUE => Some(Kind::Number),
+                        EXISTS | ODD | VALUE_IN | VALUE_UNIQUE => Some(Kind::Logical),
+                        FORMAT => Some(Kind::String),
+                        ROLESOF | TYPEOF | USEDIN => Some(Kind::Aggregate),
+                        NVL => None,
+                    },
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn attribute(&self, name: &str) -> Option<Kind> {
+        let (_, ty) = self.attributes.iter().find(|(attr, _)| *attr == name)?;
+        self.kind_of(self.scope, ty, 0)
+    }
+
+    fn kind_of(&self, scope: &Scope, ty: &ast::Type, depth: usize) -> Option<Kind> {
+        use ast::SimpleType::*;
+        match ty {
+            ast::Type::Simple(ty) => Some(match ty {
+                Number | Real | Integer => Kind::Number,
+                Logical | Boolen => Kind::Logical,
+                String_ { .. } => Kind::String,
+                Binary { .. } => Kind::Binary,
+            }),
+            ast::Type::Set { .. }
+            | ast::Type::Bag { .. }
+            | ast::Type::List { .. }
+            | ast::Type::Array { .. }
+            | ast::Type::Aggregate { .. } => Some(Kind::Aggregate),
+            ast::Type::Enumeration { .. } => Some(Kind::Enumeration),
+            ast::Type::GenericEntity(_) => Some(Kind::Entity),
+            ast::Type::Select { .. } | ast::Type::Generic(_) => None,
+            ast::Type::Named(name) => {
+                if depth >= MAX_DEPTH {
+                    return None;
+                }
+                let (path, _index) = self.ns.resolve(scope, name).ok()?;
+                match self.ns.get(&path).ok()?.0 {
+                    Named::Entity(_) => Some(Kind::Entity),
+                    Named::Type(decl) => {
+                        self.kind_of(&path.scope, &decl.underlying_type, depth + 1)
+                    }
+                    Named::SimpleType(ty) => {
+                        self.kind_of(&path.scope, &ast::Type::Simple(ty), depth + 1)
+                    }
+                    Named::Constant(_) | Named::DeclaredConstant(_) => None,
+                }
+            }
+        }
+    }
+}
+
+fn is_comparison(op: ast::RelationOperator) -> bool {
+    use ast::RelationOperator::*;
+    matches!(op, Equal | NotEqual | Lt | Gt | Leq | Geq)
+}
+
+fn numeric(lhs: Kind, rhs: Kind) -> Option<Kind> {
+    (lhs == Kind::Number && rhs == Kind::Number).then_some(Kind::Number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn legalize(exp: &str) -> Result<IR, SemanticError> {
+        let st = SyntaxTree::parse(exp.trim()).unwrap();
+        IR::from_syntax_tree(&st)
+    }
+
+    #[test]
+    fn type_mismatch() {
+        let err = legalize(
+            r#"
+            SCHEMA s;
+              TYPE length_measure = REAL;
+              END_TYPE;
+              ENTITY a;
+                x: length_measure;
+                label: STRING;
+              WHERE
+                wr1: SELF.x <> label;
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SemanticError::TypeMismatch { lhs, rhs, .. } if lhs == "NUMBER" && rhs == "STRING"
+        ));
+
+        let err = legalize(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                x: REAL;
+              DERIVE
+                positive: BOOLEAN := (x > 0) = 'yes';
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SemanticError::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn compatible() {
+        legalize(
+            r#"
+            SCHEMA s;
+              TYPE color = ENUMERATION OF (red, green);
+              END_TYPE;
+              ENTITY a;
+                x: REAL;
+                n: INTEGER;
+                kind: color;
+                items: LIST [0:?] OF REAL;
+              WHERE
+                wr1: x * 2 <> n;
+                wr2: kind = color.red;
+                wr3: SIZEOF(items) = n;
+                wr4: ? = x;
+                wr5: SELF.y = 'inherited or unknown';
+                wr6: items[1] = 'not inferred';
+              END_ENTITY;
+            END_SCHEMA;
+            "#,
+        )
+        .unwrap();
+    }
+}
@@ -1,5 +1,5 @@
 use super::{namespace::*, scope::*, *};
-use crate::ast;
+use crate::{ast, eval::Value};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SimpleType(pub ast::SimpleType);
@@ -16,8 +16,16 @@ impl Legalize for SimpleType {
     }
 }
 
+/// Bound of aggregate type, e.g. `[1:?]`
+///
+/// Bounds are folded if they are constant expressions, e.g. `[1:2*3]`,
+/// and ones referring constants or parameters are kept as `None`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Bound {}
+pub struct Bound {
+    pub lower: Option<i64>,
+    /// `None` also for the indeterminate upper bound `?`
+    pub upper: Option<i64>,
+}
 
 impl Legalize for Bound {
     type Input = ast::Bound;
@@ -25,10 +33,32 @@ impl Legalize for Bound {
         _ns: &Namespace,
         _ss: &Constraints,
         _scope: &Scope,
-        _input: &Self::Input,
+        input: &Self::Input,
     ) -> Result<Self, SemanticError> {
-        // FIXME
-        Ok(Bound {})
+        let lower = bound_value(&input.lower)?;
+        let upper = bound_value(&input.upper)?;
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower > upper {
+                return Err(SemanticError::InvalidBound(format!(
+                    "lower bound {} is greater than upper bound {}",
+                    lower, upper
+                )));
+            }
+        }
+        Ok(Bound { lower, upper })
+    }
+}
+
+/// Integer value of a bound if it is a constant expression
+fn bound_value(expr: &ast::Expression) -> Result<Option<i64>, SemanticError> {
+    match crate::eval::fold_constant(expr) {
+        Some(Value::Integer(value)) => Ok(Some(value)),
+        Some(Value::Real(value)) if value.fract() == 0.0 => Ok(Some(value as i64)),
+        Some(value) => Err(SemanticError::InvalidBound(format!(
+            "bound must be an integer, but {:?} is found",
+            value
+        ))),
+        None => Ok(None),
     }
 }
 
@@ -67,24 +97,70 @@ pub enum TypeRef {
         base: Box<TypeRef>,
         bound: Option<Bound>,
     },
+    Bag {
+        base: Box<TypeRef>,
+        bound: Option<Bound>,
+    },
     List {
         base: Box<TypeRef>,
         bound: Option<Bound>,
         unique: bool,
     },
+    Array {
+        base: Box<TypeRef>,
+        bound: Option<Bound>,
+        unique: bool,
+        /// Elements may be indeterminate, i.e. `ARRAY [1:3] OF OPTIONAL REAL`
+        optional: bool,
+    },
+    /// Generic aggregate `AGGREGATE [:label] OF base` used in function and procedure parameters
+    Aggregate {
+        base: Box<TypeRef>,
+        label: Option<String>,
+    },
 }
 
+/// Name of the `SELECT` of all entities in a schema referred by `GENERIC_ENTITY` attributes
+///
+/// This never conflicts with user declarations since `GENERIC_ENTITY` is a reserved word.
+pub const GENERIC_ENTITY: &str = "generic_entity";
+
 impl TypeRef {
     /// Returns `true` iff `self` is:
     /// - a simple type,
     /// - a named type whose underlying type is simple, or,
-    /// - a set or list of a type `x` such that `x.is_simple() == true`.
+    /// - an aggregate of a type `x` such that `x.is_simple() == true`.
     pub fn is_simple(&self) -> bool {
         match self {
             TypeRef::SimpleType(..) => true,
             TypeRef::Named { is_simple, .. } => *is_simple,
-            TypeRef::Set { base, .. } | TypeRef::List { base, .. } => base.is_simple(),
-            _ => false,
+            _ => match self.aggregate_base() {
+                Some(base) => base.is_simple(),
+                None => false,
+            },
+        }
+    }
+
+    /// Returns `true` if `self` is `GENERIC_ENTITY` or an aggregate of it
+    pub fn is_generic_entity(&self) -> bool {
+        match self {
+            TypeRef::Named { name, .. } => name == GENERIC_ENTITY,
+            _ => match self.aggregate_base() {
+                Some(base) => base.is_generic_entity(),
+                None => false,
+            },
+        }
+    }
+
+    /// Element type if `self` is an aggregate, i.e. set, bag, list, array or generic aggregate
+    pub fn aggregate_base(&self) -> Option<&TypeRef> {
+        match self {
+            TypeRef::Set { base, .. }
+            | TypeRef::Bag { base, .. }
+            | TypeRef::List { base, .. }
+            | TypeRef::Array { base, .. }
+            | TypeRef::Aggregate { base, .. } => Some(base),
+            _ => None,
         }
     }
 
@@ -99,8 +175,16 @@ impl TypeRef {
                 })
             }
             ScopeType::Type => {
+                if let Named::SimpleType(ty) = ns.get(path)?.0 {
+                    return Ok(TypeRef::SimpleType(SimpleType(ty)));
+                }
                 let mut p = path.clone();
+                // Types already followed, to detect cyclic renames e.g. `TYPE a = b; TYPE b = a;`
+                let mut visited = std::collections::HashSet::new();
                 let is_simple = loop {
+                    if !visited.insert(p.clone()) {
+                        return Err(SemanticError::CyclicTypeDefinition { path: p });
+                    }
                     match ns.get(&p)?.0 {
                         Named::Type(ast::TypeDecl {
                             underlying_type, ..
@@ -119,7 +203,10 @@ impl TypeRef {
                             }
                             _ => break false,
                         },
-                        Named::Entity(_) => break false,
+                        Named::SimpleType(_) => break true,
+                        Named::Entity(_) | Named::Constant(_) | Named::DeclaredConstant(_) => {
+                            break false
+                        }
                     }
                 };
                 let is_enumerate = match ns.get(path)?.0 {
@@ -137,7 +224,7 @@ impl TypeRef {
                         ast::Type::Enumeration { .. } => true,
                         _ => false,
                     },
-                    Named::Entity(_) => false,
+                    _ => false,
                 };
                 Ok(TypeRef::Named {
                     scope: path.scope.clone(),
@@ -146,7 +233,8 @@ impl TypeRef {
                     is_enumerate,
                 })
             }
-            _ => unimplemented!("Path to TypeRef conversion only supports Entity and Types yet."),
+            // e.g. `x: c;` for a constant `c`
+            _ => Err(SemanticError::NotAType { path: path.clone() }),
         }
     }
 }
@@ -167,36 +255,227 @@ impl Legalize for TypeRef {
                 let (path, _index) = ns.resolve(scope, name)?;
                 Self::from_path(ns, ss, &path)?
             }
-            Set { base, bound } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
-                Self::Set {
-                    base: Box::new(base),
-                    bound,
-                }
-            }
+            Set { base, bound } => Self::Set {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+            },
+            Bag { base, bound } => Self::Bag {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+            },
             List {
                 base,
                 bound,
                 unique,
-            } => {
-                let base = TypeRef::legalize(ns, ss, scope, base.as_ref())?;
-                let bound = if let Some(bound) = bound {
-                    Some(Legalize::legalize(ns, ss, scope, bound)?)
-                } else {
-                    None
-                };
-                Self::List {
-                    base: Box::new(base),
-                    bound,
-                    unique: *unique,
-                }
-            }
-            _ => todo!(),
+            } => Self::List {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+                unique: *unique,
+            },
+            Array {
+                base,
+                bound,
+                unique,
+                optional,
+            } => Self::Array {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                bound: legalize_bound(ns, ss, scope, bound)?,
+                unique: *unique,
+                optional: *optional,
+            },
+            Aggregate { base, label } => Self::Aggregate {
+                base: Box::new(TypeRef::legalize(ns, ss, scope, base.as_ref())?),
+                label: label.clone(),
+            },
+            // Constructed types are only allowed as underlying types of `TYPE` declarations,
+            // and generic types only in parameters of functions and procedures
+            Enumeration { .. } => return Err(unexpected_type("ENUMERATION", scope)),
+            Select { .. } => return Err(unexpected_type("SELECT", scope)),
+            // Any entity of the schema, expressed by the `SELECT` synthesized in [Schema]
+            GenericEntity(_) => match scope.schema_name() {
+                Some(schema) => Self::Named {
+                    name: GENERIC_ENTITY.to_string(),
+                    scope: Scope::root().pushed(ScopeType::Schema, schema),
+                    is_simple: false,
+                    is_enumerate: false,
+                },
+                None => return Err(unexpected_type("GENERIC_ENTITY", scope)),
+            },
+            Generic(_) => return Err(unexpected_type("GENERIC", scope)),
         })
     }
 }
+
+fn legalize_bound(
+    ns: &Namespace,
+    ss: &Constraints,
+    scope: &Scope,
+    bound: &Option<ast::Bound>,
+) -> Result<Option<Bound>, SemanticError> {
+    bound
+        .as_ref()
+        .map(|bound| Bound::legalize(ns, ss, scope, bound))
+        .transpose()
+}
+
+fn unexpected_type(ty: &str, scope: &Scope) -> SemanticError {
+    SemanticError::UnexpectedType {
+        ty: ty.to_string(),
+        scope: scope.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute_types(exp: &str) -> Result<Vec<TypeRef>, SemanticError> {
+        let st = SyntaxTree::parse(exp).unwrap();
+        let ir = IR::from_syntax_tree(&st)?;
+        Ok(ir.schemas[0].entities[0]
+            .attributes
+            .iter()
+            .map(|attr| attr.ty.clone())
+            .collect())
+    }
+
+    #[test]
+    fn nested_aggregates() {
+        let types = attribute_types(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              ENTITY a;
+                x: SET [1:?] OF LIST [3:3] OF REAL;
+                y: BAG OF ARRAY [-1:1] OF OPTIONAL label;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let real = TypeRef::SimpleType(SimpleType(ast::SimpleType::Real));
+        assert_eq!(
+            types[0],
+            TypeRef::Set {
+                base: Box::new(TypeRef::List {
+                    base: Box::new(real),
+                    bound: Some(Bound {
+                        lower: Some(3),
+                        upper: Some(3)
+                    }),
+                    unique: false,
+                }),
+                bound: Some(Bound {
+                    lower: Some(1),
+                    upper: None
+                }),
+            }
+        );
+        let TypeRef::Bag { base, bound: None } = &types[1] else {
+            panic!("{:?}", types[1]);
+        };
+        assert!(matches!(
+            base.as_ref(),
+            TypeRef::Array {
+                bound: Some(Bound {
+                    lower: Some(-1),
+                    upper: Some(1)
+                }),
+                optional: true,
+                ..
+            }
+        ));
+        assert!(base.is_simple());
+    }
+
+    #[test]
+    fn folded_bound() {
+        let types = attribute_types(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                x: ARRAY [1:2+1] OF REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert!(matches!(
+            &types[0],
+            TypeRef::Array {
+                bound: Some(Bound {
+                    lower: Some(1),
+                    upper: Some(3)
+                }),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn invalid_bound() {
+        let result = attribute_types(
+            r#"
+            SCHEMA s;
+              ENTITY a;
+                x: LIST [3:1] OF REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        );
+        assert!(matches!(result, Err(SemanticError::InvalidBound(_))));
+    }
+
+    #[test]
+    fn cyclic_type_definition() {
+        let err = attribute_types(
+            r#"
+            SCHEMA s;
+              TYPE a = b;
+              END_TYPE;
+              TYPE b = a;
+              END_TYPE;
+              ENTITY e;
+                x: a;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SemanticError::CyclicTypeDefinition { path } if path.name == "a"
+        ));
+    }
+
+    #[test]
+    fn supertype_reference() {
+        let types = attribute_types(
+            r#"
+            SCHEMA s;
+              ENTITY holder;
+                x: a;
+                y: b;
+              END_ENTITY;
+              ENTITY a ABSTRACT SUPERTYPE;
+              END_ENTITY;
+              ENTITY b SUBTYPE OF (a);
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert!(
+            matches!(&types[0], TypeRef::Entity { name, is_supertype: true, .. } if name == "a")
+        );
+        assert!(
+            matches!(&types[1], TypeRef::Entity { name, is_supertype: false, .. } if name == "b")
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use super::*;
+use std::collections::HashSet;
+
+impl IR {
+    /// Types and entities declared but never referenced
+    ///
+    /// A declaration is referenced when it is used as an attribute type,
+    /// a supertype, a member of `SELECT`, or an underlying type of another `TYPE`.
+    /// Entities which are not `ABSTRACT` are not reported
+    /// since they can be instantiated directly in exchange structure.
+    pub fn unused_declarations(&self) -> Vec<Path> {
+        let mut used = HashSet::new();
+        for schema in &self.schemas {
+            for entity in &schema.entities {
+                for attr in &entity.attributes {
+                    mark_used(&attr.ty, &mut used);
+                }
+                for ty in &entity.supertypes {
+                    mark_used(ty, &mut used);
+                }
+            }
+            for ty in &schema.types {
+                match ty {
+                    TypeDecl::Rename(Rename { ty, .. }) => mark_used(ty, &mut used),
+                    TypeDecl::Select(Select { types, .. }) => {
+                        for ty in types {
+                            mark_used(ty, &mut used);
+                        }
+                    }
+                    TypeDecl::Simple(_) | TypeDecl::Enumeration(_) => {}
+                }
+            }
+        }
+
+        let mut unused = Vec::new();
+        for schema in &self.schemas {
+            let scope = Scope::root().schema(&schema.name);
+            for ty in &schema.types {
+                let path = Path::r#type(&scope, ty.id());
+                if !used.contains(&path) {
+                    unused.push(path);
+                }
+            }
+            for entity in &schema.entities {
+                let path = Path::entity(&scope, &entity.name);
+                if entity.is_abstract && !used.contains(&path) {
+                    unused.push(path);
+                }
+            }
+        }
+        unused
+    }
+
+    /// Pruned IR containing only `roots` and declarations they depend on transitively
+    ///
+    /// Dependencies are types of attributes including derived ones, destinations of inverse attributes,
+    /// supertypes, members of `SELECT`, and underlying types of `TYPE`.
+    /// Subtypes of a kept entity are also kept since a reference to it may refer their instances.
+    /// Schemas without kept declarations are removed.
+    /// Expressions in domain rules and derived attributes are not analyzed.
+    ///
+    /// The result can be saved as a standalone EXPRESS schema by [IR::to_express].
+    pub fn subset(&self, roots: &[Path]) -> IR {
+        let mut kept = HashSet::new();
+        let mut stack = roots.to_vec();
+        while let Some(path) = stack.pop() {
+            if !kept.insert(path.clone()) {
+                continue;
+            }
+            let mut deps = HashSet::new();
+            self.mark_dependencies(&path, &mut deps);
+            stack.extend(deps.into_iter().filter(|dep| !kept.contains(dep)));
+        }
+
+        let mut schemas: Vec<Schema> = self
+            .schemas
+            .iter()
+            .filter_map(|schema| {
+                let scope = Scope::root().schema(&schema.name);
+                let is_kept = |ty: &TypeRef| {
+                    let mut used = HashSet::new();
+                    mark_used(ty, &mut used);
+                    used.is_subset(&kept)
+                };
+                let entities: Vec<Entity> = schema
+                    .entities
+                    .iter()
+                    .filter(|entity| kept.contains(&Path::entity(&scope, &entity.name)))
+                    .map(|entity| Entity {
+                        constraints: entity
+                            .constraints
+                            .iter()
+                            .filter(|ty| is_kept(ty))
+                            .cloned()
+                            .collect(),
+                        ..entity.clone()
+                    })
+                    .collect();
+                let types: Vec<TypeDecl> = schema
+                    .types
+                    .iter()
+                    .filter(|ty| kept.contains(&Path::r#type(&scope, ty.id())))
+                    .map(|ty| match ty {
+                        // Members of the synthesized `SELECT` are not dependencies
+                        TypeDecl::Select(Select { id, types }) if id == GENERIC_ENTITY => {
+                            TypeDecl::Select(Select {
+                                id: id.clone(),
+                                types: types.iter().filter(|ty| is_kept(ty)).cloned().collect(),
+                            })
+                        }
+                        _ => ty.clone(),
+                    })
+                    .collect();
+                if entities.is_empty() && types.is_empty() {
+                    return None;
+                }
+                Some(Schema {
+                    name: schema.name.clone(),
+                    entities,
+                    types,
+                    aliases: schema
+                        .aliases
+                        .iter()
+                        .filter(|alias| is_kept(&alias.ty))
+                        .cloned()
+                        .collect(),
+                    imported_schemas: schema.imported_schemas.clone(),
+                })
+            })
+            .collect();
+        let names: HashSet<String> = schemas.iter().map(|schema| schema.name.clone()).collect();
+        for schema in &mut schemas {
+            schema.imported_schemas.retain(|name| names.contains(name));
+        }
+        IR { schemas }
+    }
+
+    /// Mark declarations which the declaration of `path` directly depends on, see [IR::subset]
+    fn mark_dependencies(&self, path: &Path, used: &mut HashSet<Path>) {
+        let Some(schema) = self
+            .schemas
+            .iter()
+            .find(|schema| path.scope == Scope::root().schema(&schema.name))
+        else {
+            return;
+        };
+        match path.ty {
+            ScopeType::Entity => {
+                let Some(entity) = schema.entities.iter().find(|e| e.name == path.name) else {
+                    return;
+                };
+                for attr in &entity.attributes {
+                    mark_used(&attr.ty, used);
+                }
+                for attr in &entity.derived_attributes {
+                    mark_used(&attr.ty, used);
+                }
+                for attr in &entity.inverse_attributes {
+                    mark_used(&attr.dest, used);
+                }
+                for ty in &entity.supertypes {
+                    mark_used(ty, used);
+                }
+                for schema in &self.schemas {
+                    let scope = Scope::root().schema(&schema.name);
+                    for sub in &schema.entities {
+                        let mut supertypes = HashSet::new();
+                        for ty in &sub.supertypes {
+                            mark_used(ty, &mut supertypes);
+                        }
+                        if supertypes.contains(path) {
+                            used.insert(Path::entity(&scope, &sub.name));
+                        }
+                    }
+                }
+            }
+            ScopeType::Type => match schema.types.iter().find(|ty| ty.id() == path.name) {
+                Some(TypeDecl::Rename(Rename { ty, .. })) => mark_used(ty, used),
+                Some(TypeDecl::Select(Select { id, types })) if id != GENERIC_ENTITY => {
+                    for ty in types {
+                        mark_used(ty, used);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn mark_used(ty: &TypeRef, used: &mut HashSet<Path>) {
+    match ty {
+        TypeRef::SimpleType(_) => {}
+        TypeRef::Named { name, scope, .. } => {
+            used.insert(Path::r#type(scope, name));
+        }
+        TypeRef::Entity { name, scope, .. } => {
+            used.insert(Path::entity(scope, name));
+        }
+        TypeRef::Set { base, .. }
+        | TypeRef::Bag { base, .. }
+        | TypeRef::List { base, .. }
+        | TypeRef::Array { base, .. }
+        | TypeRef::Aggregate { base, .. } => mark_used(base, used),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unused_declarations() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              TYPE unused_label = STRING;
+              END_TYPE;
+              TYPE distance = REAL;
+              END_TYPE;
+              TYPE shape_select = SELECT (circle);
+              END_TYPE;
+              ENTITY shape ABSTRACT SUPERTYPE;
+                name: label;
+              END_ENTITY;
+              ENTITY circle SUBTYPE OF (shape);
+                radius: distance;
+              END_ENTITY;
+              ENTITY unused_base ABSTRACT;
+              END_ENTITY;
+              ENTITY drawing;
+                shapes: LIST OF shape_select;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let scope = Scope::root().schema("s");
+        assert_eq!(
+            ir.unused_declarations(),
+            [
+                Path::r#type(&scope, "unused_label"),
+                Path::entity(&scope, "unused_base"),
+            ]
+        );
+    }
+
+    #[test]
+    fn subset() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              TYPE distance = REAL;
+              END_TYPE;
+              TYPE color = ENUMERATION OF (red, green);
+              END_TYPE;
+              ENTITY shape ABSTRACT SUPERTYPE;
+                name: label;
+              END_ENTITY;
+              ENTITY circle SUBTYPE OF (shape);
+                radius: distance;
+              END_ENTITY;
+              ENTITY drawing;
+                shapes: LIST OF shape;
+                color: color;
+              END_ENTITY;
+              ENTITY layer;
+                drawings: SET OF drawing;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA unrelated;
+              ENTITY point;
+                x: REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let subset = ir.subset(&[Path::entity(&Scope::root().schema("s"), "shape")]);
+
+        // Same as the IR of the schema written by hand
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              TYPE distance = REAL;
+              END_TYPE;
+              ENTITY shape ABSTRACT SUPERTYPE;
+                name: label;
+              END_ENTITY;
+              ENTITY circle SUBTYPE OF (shape);
+                radius: distance;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        assert_eq!(subset, IR::from_syntax_tree(&st).unwrap());
+    }
+
+    #[test]
+    fn subset_round_trip() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA geometry;
+              TYPE distance = REAL;
+              END_TYPE;
+              ENTITY point;
+                x, y: distance;
+              END_ENTITY;
+              ENTITY unused;
+              END_ENTITY;
+            END_SCHEMA;
+
+            SCHEMA s;
+              USE FROM geometry (point AS pt, distance);
+              TYPE label = STRING(8) FIXED;
+              END_TYPE;
+              TYPE color = ENUMERATION OF (red, green);
+              END_TYPE;
+              ENTITY shape ABSTRACT SUPERTYPE;
+                name: OPTIONAL label;
+                fill: color;
+              UNIQUE
+                ur1: name;
+              WHERE
+                wr1: EXISTS(name) AND (fill <> color.green);
+              END_ENTITY;
+              ENTITY circle SUBTYPE OF (shape);
+                center: pt;
+                radius: distance;
+              DERIVE
+                area: REAL := PI * radius ** 2;
+              WHERE
+                radius > 0.0;
+              END_ENTITY;
+              ENTITY polyline SUBTYPE OF (shape);
+                points: LIST [2:?] OF UNIQUE pt;
+              END_ENTITY;
+              ENTITY drawing;
+                shapes: SET [0:?] OF shape;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let ir = IR::from_syntax_tree(&st).unwrap();
+        let subset = ir.subset(&[Path::entity(&Scope::root().schema("s"), "shape")]);
+        assert_eq!(subset.schemas.len(), 2);
+
+        let printed = subset.to_express();
+        let st = SyntaxTree::parse(&printed).unwrap();
+        assert_eq!(IR::from_syntax_tree(&st).unwrap(), subset);
+    }
+}
@@ -0,0 +1,120 @@
+use super::*;
+use std::collections::HashSet;
+
+/// Semantic warnings, non-fatal issues found in legalization
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SemanticWarning {
+    #[error("{0} is declared but never referenced")]
+    UnusedDeclaration(Path),
+
+    #[error("Attribute {attribute} of {entity} shadows the attribute of supertype {supertype}")]
+    ShadowedAttribute {
+        entity: Path,
+        attribute: String,
+        supertype: Path,
+    },
+}
+
+impl IR {
+    /// Legalize [SyntaxTree] as [IR::from_syntax_tree] with warnings found in the schemas
+    pub fn from_syntax_tree_with_warnings(
+        st: &SyntaxTree,
+    ) -> Result<(Self, Vec<SemanticWarning>), SemanticError> {
+        let ir = Self::from_syntax_tree(st)?;
+        let warnings = ir.warnings();
+        Ok((ir, warnings))
+    }
+
+    /// Non-fatal issues in the schemas
+    pub fn warnings(&self) -> Vec<SemanticWarning> {
+        let mut warnings: Vec<_> = self
+            .unused_declarations()
+            .into_iter()
+            .map(SemanticWarning::UnusedDeclaration)
+            .collect();
+        for schema in &self.schemas {
+            let scope = Scope::root().schema(&schema.name);
+            for entity in &schema.entities {
+                for attr in &entity.attributes {
+                    let mut visited = HashSet::new();
+                    if let Some(supertype) =
+                        self.declaring_supertype(&entity.supertypes, &attr.name, &mut visited)
+                    {
+                        warnings.push(SemanticWarning::ShadowedAttribute {
+                            entity: Path::entity(&scope, &entity.name),
+                            attribute: attr.name.clone(),
+                            supertype,
+                        });
+                    }
+                }
+            }
+        }
+        warnings
+    }
+
+    /// Find a supertype declaring the attribute, searched recursively
+    fn declaring_supertype(
+        &self,
+        supertypes: &[TypeRef],
+        attribute: &str,
+        visited: &mut HashSet<Path>,
+    ) -> Option<Path> {
+        for supertype in supertypes {
+            if let TypeRef::Entity { name, scope, .. } = supertype {
+                let path = Path::entity(scope, name);
+                if !visited.insert(path.clone()) {
+                    continue;
+                }
+                let entity = self.lookup_entity(scope, name)?;
+                if entity.attributes.iter().any(|attr| attr.name == attribute) {
+                    return Some(path);
+                }
+                if let Some(found) =
+                    self.declaring_supertype(&entity.supertypes, attribute, visited)
+                {
+                    return Some(found);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings() {
+        let st = SyntaxTree::parse(
+            r#"
+            SCHEMA s;
+              TYPE label = STRING;
+              END_TYPE;
+              ENTITY base;
+                x: REAL;
+              END_ENTITY;
+              ENTITY sub SUBTYPE OF (base);
+                x: INTEGER;
+                y: REAL;
+              END_ENTITY;
+            END_SCHEMA;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let (_ir, warnings) = IR::from_syntax_tree_with_warnings(&st).unwrap();
+        let scope = Scope::root().schema("s");
+        assert_eq!(
+            warnings,
+            [
+                SemanticWarning::UnusedDeclaration(Path::r#type(&scope, "label")),
+                SemanticWarning::ShadowedAttribute {
+                    entity: Path::entity(&scope, "sub"),
+                    attribute: "x".to_string(),
+                    supertype: Path::entity(&scope, "base"),
+                },
+            ]
+        );
+    }
+}
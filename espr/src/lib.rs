@@ -34,6 +34,9 @@
 //!   - [ir] module defines IR structs, and they implements [ir::Legalize] trait for legalizing from AST
 //! - Code Generation
 //!   - [codegen::rust] module generates Rust code from IR
+//!   - [generate] returns formatted Rust code from IR, e.g. for `build.rs`
+//!
+//! In addition, [eval] module evaluates EXPRESS expressions appear in `WHERE` rules.
 //!
 //! Introduction to STEP
 //! ---------------------
@@ -153,5 +156,33 @@
 
 pub mod ast;
 pub mod codegen;
+pub mod eval;
 pub mod ir;
 pub mod parser;
+
+use codegen::rust::{try_rustfmt, CodegenOptions, CratePrefix};
+
+/// Generate formatted Rust code from IR, e.g. in `build.rs`
+///
+/// The generated code refers `::ruststep` crate, and is formatted by `rustfmt` in PATH.
+///
+/// ```
+/// use espr::{ast::SyntaxTree, ir::IR};
+///
+/// let st = SyntaxTree::parse("SCHEMA s; ENTITY a; x: REAL; END_ENTITY; END_SCHEMA;").unwrap();
+/// let ir = IR::from_syntax_tree(&st).unwrap();
+/// let code = espr::generate(&ir).unwrap();
+/// assert!(code.contains("pub struct A"));
+/// ```
+pub fn generate(ir: &ir::IR) -> std::io::Result<String> {
+    generate_with_options(ir, CratePrefix::External, &CodegenOptions::default())
+}
+
+/// [generate] with crate prefix and codegen options
+pub fn generate_with_options(
+    ir: &ir::IR,
+    prefix: CratePrefix,
+    options: &CodegenOptions,
+) -> std::io::Result<String> {
+    try_rustfmt(ir.to_token_stream_with_options(prefix, options).to_string())
+}
@@ -51,7 +51,8 @@ pub fn encoded_string_literal(input: &str) -> RawParseResult<String> {
 
 /// 144 simple_string_literal = \q { ( \q \q ) | not_quote | \s | \x9 | \xA | \xD } \q .
 pub fn simple_string_literal(input: &str) -> RawParseResult<String> {
-    tuple((char('\''), many0(none_of("'")), char('\'')))
+    let quote = nom::combinator::value('\'', nom::bytes::complete::tag("''"));
+    tuple((char('\''), many0(alt((quote, none_of("'")))), char('\'')))
         .map(|(_open, chars, _close)| chars.into_iter().collect())
         .parse(input)
 }
@@ -81,6 +82,13 @@ pub fn simple_id(input: &str) -> RawParseResult<String> {
 mod tests {
     use nom::Finish;
 
+    #[test]
+    fn simple_string_literal() {
+        let (residual, s) = super::simple_string_literal("'it''s' x").finish().unwrap();
+        assert_eq!(s, "it's");
+        assert_eq!(residual, " x");
+    }
+
     #[test]
     fn letter() {
         let (residual, l) = super::letter("h").finish().unwrap();
@@ -27,6 +27,7 @@ pub fn derived_attr(input: &str) -> ParseResult<DerivedAttribute> {
 
 #[cfg(test)]
 mod tests {
+    use crate::ast::*;
     use nom::Finish;
 
     #[test]
@@ -43,4 +44,33 @@ mod tests {
         assert_eq!(residual, "");
         assert_eq!(c.attributes.len(), 1);
     }
+
+    #[test]
+    fn derive_clause_redeclared() {
+        let (residual, (c, _remarks)) = super::derive_clause(
+            r#"
+            DERIVE
+              area : REAL := PI * radius ** 2;
+              SELF\curve.dim : INTEGER := 2;
+            "#
+            .trim(),
+        )
+        .finish()
+        .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(c.attributes.len(), 2);
+        assert_eq!(
+            c.attributes[0].attr,
+            AttributeDecl::Reference("area".to_string())
+        );
+        assert_eq!(c.attributes[0].ty, Type::Simple(SimpleType::Real));
+        assert_eq!(
+            c.attributes[1].attr,
+            AttributeDecl::Qualified {
+                group: "curve".to_string(),
+                attribute: "dim".to_string(),
+                rename: None,
+            }
+        );
+    }
 }
@@ -2,8 +2,10 @@ use super::super::{combinator::*, expression::*, identifier::*};
 use crate::ast::*;
 
 /// 338 where_clause = WHERE [domain_rule] `;` { [domain_rule] `;` } .
+///
+/// Redundant semicolons after a rule, e.g. `wr1: SELF > 0;;`, are accepted.
 pub fn where_clause(input: &str) -> ParseResult<WhereClause> {
-    tuple((tag("WHERE"), many0(tuple((domain_rule, char(';'))))))
+    tuple((tag("WHERE"), many0(tuple((domain_rule, many1(char(';')))))))
         .map(|(_where, rules)| {
             let rules = rules.into_iter().map(|(rule, _semicolon)| rule).collect();
             WhereClause { rules }
@@ -79,4 +81,22 @@ mod tests {
         assert_eq!(residual, "");
         dbg!(w);
     }
+
+    #[test]
+    fn where_clause_multiple() {
+        let (residual, (w, _remarks)) = super::where_clause(
+            r#"
+            WHERE
+                wr1: SELF.radius > 0.0;
+                SELF.radius < 10.0;
+                wr3: EXISTS(SELF.name);
+            "#
+            .trim(),
+        )
+        .finish()
+        .unwrap();
+        assert_eq!(residual, "");
+        let labels: Vec<_> = w.rules.iter().map(|rule| rule.label.as_deref()).collect();
+        assert_eq!(labels, [Some("wr1"), None, Some("wr3")]);
+    }
 }
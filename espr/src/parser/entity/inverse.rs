@@ -94,8 +94,8 @@ mod tests {
             inv.dest_aggregation,
             AggregationOption::Set {
                 bound: Some(Bound {
-                    upper: Expression::real(1.0),
-                    lower: Expression::real(0.0)
+                    upper: Expression::integer(1),
+                    lower: Expression::integer(0)
                 })
             }
         );
@@ -61,4 +61,19 @@ mod tests {
         assert_eq!(residual, "END_ENTITY;");
         assert_eq!(c.rules.len(), 2);
     }
+
+    #[test]
+    fn unique_rule_unlabeled() {
+        let (residual, (rule, _remarks)) = super::unique_rule("name").finish().unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(rule.name, None);
+        assert_eq!(rule.attributes.len(), 1);
+
+        let (residual, (rule, _remarks)) = super::unique_rule("ur1 : SELF\\base.id, name")
+            .finish()
+            .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(rule.name, Some("ur1".to_string()));
+        assert_eq!(rule.attributes.len(), 2);
+    }
 }
@@ -53,29 +53,29 @@ mod tests {
             Expression::AggregateInitializer {
                 elements: vec![
                     Element {
-                        expr: Expression::Literal(Literal::Real(1.0)),
+                        expr: Expression::Literal(Literal::Integer(1)),
                         repetition: None,
                     },
                     Element {
-                        expr: Expression::Literal(Literal::Real(3.0)),
+                        expr: Expression::Literal(Literal::Integer(3)),
                         repetition: None,
                     },
                     Element {
-                        expr: Expression::Literal(Literal::Real(6.0)),
+                        expr: Expression::Literal(Literal::Integer(6)),
                         repetition: None,
                     },
                     Element {
                         expr: Expression::Binary {
                             op: BinaryOperator::Mul,
-                            arg1: Box::new(Expression::Literal(Literal::Real(9.0))),
-                            arg2: Box::new(Expression::Literal(Literal::Real(8.0)))
+                            arg1: Box::new(Expression::Literal(Literal::Integer(9))),
+                            arg2: Box::new(Expression::Literal(Literal::Integer(8)))
                         },
                         repetition: None,
                     },
                     Element {
                         expr: Expression::Unary {
                             op: UnaryOperator::Minus,
-                            arg: Box::new(Expression::Literal(Literal::Real(12.0))),
+                            arg: Box::new(Expression::Literal(Literal::Integer(12))),
                         },
                         repetition: None,
                     },
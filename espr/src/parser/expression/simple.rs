@@ -259,14 +259,14 @@ mod tests {
     fn relation() {
         let (res, (expr, _remarks)) = super::expression("1 <= 2").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(expr, Expression::real(1.0).leq(Expression::real(2.0)));
+        assert_eq!(expr, Expression::integer(1).leq(Expression::integer(2)));
     }
 
     #[test]
     fn relation_self() {
         let (res, (expr, _remarks)) = super::expression("1 <= SELF").finish().unwrap();
         assert_eq!(res, "");
-        assert_eq!(expr, Expression::real(1.0).leq(Expression::self_()));
+        assert_eq!(expr, Expression::integer(1).leq(Expression::self_()));
     }
 
     #[test]
@@ -277,9 +277,9 @@ mod tests {
         assert_eq!(res, "");
         assert_eq!(
             expr,
-            Expression::real(1.0)
+            Expression::integer(1)
                 .leq(Expression::self_())
-                .and(Expression::self_().leq(Expression::real(12.0)))
+                .and(Expression::self_().leq(Expression::integer(12)))
         );
     }
 
@@ -3,12 +3,13 @@ use crate::ast::*;
 
 /// 251 literal = binary_literal | [logical_literal] | [real_literal] | [string_literal] .
 ///
-/// Integer value, e.g. `23`, will be recognized as a real number `23.0`.
-/// Use [integer_literal] if you wish to parse it as an integer.
+/// Integer value, e.g. `23`, will be recognized as [Literal::Integer],
+/// and as a real number `23.0` if it exceeds the range of `i64`.
 pub fn literal(input: &str) -> ParseResult<Literal> {
     // FIXME binary_literal,
     alt((
         logical_literal.map(Literal::Logial),
+        remarked(integer_value).map(Literal::Integer),
         real_literal.map(Literal::Real),
         string_literal.map(Literal::String),
     ))
@@ -17,31 +18,53 @@ pub fn literal(input: &str) -> ParseResult<Literal> {
 
 /// 255 logical_literal = `FALSE` | `TRUE` | `UNKNOWN` .
 pub fn logical_literal(input: &str) -> ParseResult<Logical> {
-    tuple((
-        alt((
-            value(Logical::True, tag("TRUE")),
-            value(Logical::False, tag("FALSE")),
-            value(Logical::Unknown, tag("UNKNOWN")),
-        )),
-        peek(not(remarked(nom::character::complete::alpha1))),
+    let (input, logical) = alt((
+        value(Logical::True, tag("TRUE")),
+        value(Logical::False, tag("FALSE")),
+        value(Logical::Unknown, tag("UNKNOWN")),
     ))
-    .map(|(logical, _non_alpha)| logical)
-    .parse(input)
+    .parse(input)?;
+    // Must not be followed directly by a letter, e.g. `TRUEx` is an identifier.
+    // This must not skip spaces since `TRUE AND x` is valid.
+    let (input, _) = nom::combinator::not(nom::character::complete::satisfy(|c| {
+        c.is_ascii_alphanumeric() || c == '_'
+    }))(input)?;
+    Ok((input, logical))
 }
 
 /// 141 integer_literal = digits .
 ///
 /// Negative integer, e.g. `-23`,
 /// will be represented by the combination of `-` unary operator and integer literal `23`
+///
+/// Digits exceeding the range of `u64` are rejected.
 pub fn integer_literal(input: &str) -> ParseResult<u64> {
-    remarked(nom::character::complete::digit1)
-        .map(|d: &str| d.parse().unwrap())
-        .parse(input)
+    remarked(digits_u64).parse(input)
+}
+
+fn digits_u64(input: &str) -> RawParseResult<'_, u64> {
+    nom::combinator::map_res(nom::character::complete::digit1, str::parse::<u64>)(input)
+}
+
+/// Digits in the range of `i64` not followed by `.` or exponent of [real_literal]
+fn integer_value(input: &str) -> RawParseResult<'_, i64> {
+    nom::sequence::terminated(
+        nom::combinator::map_res(nom::character::complete::digit1, str::parse::<i64>),
+        nom::combinator::not(nom::character::complete::one_of(".eE")),
+    )(input)
 }
 
 /// 142 real_literal = integer_literal | ( digits `.` \[ digits \] \[ `e` \[ sign \] digits \] ) .
+///
+/// Literals overflowing `f64`, e.g. `1.0e400`, are rejected instead of being infinity.
 pub fn real_literal(input: &str) -> ParseResult<f64> {
-    remarked(nom::number::complete::double).parse(input)
+    remarked(finite_double).parse(input)
+}
+
+fn finite_double(input: &str) -> RawParseResult<'_, f64> {
+    nom::combinator::verify(nom::number::complete::double, |value: &f64| {
+        value.is_finite()
+    })(input)
 }
 
 /// 310 string_literal = simple_string_literal | encoded_string_literal .
@@ -57,11 +80,50 @@ pub fn string_literal(input: &str) -> ParseResult<String> {
 mod tests {
     use nom::Finish;
 
+    #[test]
+    fn logical_literal() {
+        let (residual, (value, _remarks)) =
+            super::logical_literal("UNKNOWN AND x").finish().unwrap();
+        assert_eq!(value, super::Logical::Unknown);
+        assert_eq!(residual, " AND x");
+
+        assert!(super::logical_literal("TRUEx").finish().is_err());
+        assert!(super::logical_literal("FALSE_1").finish().is_err());
+    }
+
     #[test]
     fn integer_literal() {
         let (residual, (value, _remarks)) = super::integer_literal("123").finish().unwrap();
         assert_eq!(value, 123);
         assert_eq!(residual, "");
+
+        let (residual, (value, _remarks)) = super::integer_literal("18446744073709551615")
+            .finish()
+            .unwrap();
+        assert_eq!(value, u64::MAX);
+        assert_eq!(residual, "");
+
+        assert!(super::integer_literal("18446744073709551616")
+            .finish()
+            .is_err());
+    }
+
+    #[test]
+    fn literal() {
+        let (residual, (value, _remarks)) = super::literal("23").finish().unwrap();
+        assert_eq!(value, super::Literal::Integer(23));
+        assert_eq!(residual, "");
+
+        for (input, expected) in [("23.0", 23.0), ("23.", 23.0), ("2e3", 2000.0)] {
+            let (residual, (value, _remarks)) = super::literal(input).finish().unwrap();
+            assert_eq!(value, super::Literal::Real(expected));
+            assert_eq!(residual, "");
+        }
+
+        // exceeds `i64`
+        let (residual, (value, _remarks)) = super::literal("9223372036854775808").finish().unwrap();
+        assert_eq!(value, super::Literal::Real(9223372036854775808.0));
+        assert_eq!(residual, "");
     }
 
     #[test]
@@ -78,4 +140,29 @@ mod tests {
         assert_eq!(value, 1.23e-5);
         assert_eq!(residual, "");
     }
+
+    #[test]
+    fn real_literal_bounds() {
+        let (residual, (value, _remarks)) = super::real_literal("1.0E+308").finish().unwrap();
+        assert_eq!(value, 1.0e308);
+        assert_eq!(residual, "");
+
+        let (residual, (value, _remarks)) = super::real_literal("1.7976931348623157E308")
+            .finish()
+            .unwrap();
+        assert_eq!(value, f64::MAX);
+        assert_eq!(residual, "");
+
+        let (residual, (value, _remarks)) = super::real_literal("1.0E-308").finish().unwrap();
+        assert_eq!(value, 1.0e-308);
+        assert_eq!(residual, "");
+
+        // subnormal
+        let (residual, (value, _remarks)) = super::real_literal("5.0E-324").finish().unwrap();
+        assert_eq!(value, 5.0e-324);
+        assert_eq!(residual, "");
+
+        assert!(super::real_literal("1.0E400").finish().is_err());
+        assert!(super::real_literal("1.8E308").finish().is_err());
+    }
 }
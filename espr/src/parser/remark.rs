@@ -2,8 +2,13 @@ use super::{basis::simple_id, combinator::RawParseResult};
 use crate::ast::Remark;
 use itertools::Itertools;
 use nom::{
-    branch::alt, bytes::complete::*, character::complete::*, combinator::opt, multi::*,
-    sequence::*, Parser,
+    branch::alt,
+    bytes::complete::*,
+    character::complete::*,
+    combinator::{not, opt, recognize},
+    multi::*,
+    sequence::*,
+    Parser,
 };
 
 fn begin(input: &str) -> RawParseResult<()> {
@@ -33,13 +38,27 @@ fn quoted(input: &str) -> RawParseResult<String> {
         .parse(input)
 }
 
-/// String which does not include `*` and \`
+/// String which does not include `*`, \` and `(`
 fn non_quoted(input: &str) -> RawParseResult<String> {
-    many1(none_of("`*"))
+    many1(none_of("`*("))
         .map(|chars| chars.iter().collect::<String>())
         .parse(input)
 }
 
+/// `(` not starting a nested remark
+fn lparen(input: &str) -> RawParseResult<'_, String> {
+    terminated(char('('), not(char('*')))
+        .map(|c| c.to_string())
+        .parse(input)
+}
+
+/// Nested remark kept as is in the outer remark, e.g. `(* b *)` in `(* a (* b *) c *)`
+fn nested(input: &str) -> RawParseResult<'_, String> {
+    recognize(embedded_remark)
+        .map(|remark: &str| remark.to_string())
+        .parse(input)
+}
+
 /// 999 embedded_remark
 ///
 /// Extended the original definition
@@ -59,13 +78,15 @@ fn non_quoted(input: &str) -> RawParseResult<String> {
 /// (* The `(*` symbol starts a remark, and the `*)` symbol ends it *)
 /// ```
 ///
+/// Remarks may be nested, e.g. `(* a (* b *) c *)`, as the `embedded_remark` in the definition.
+///
 pub fn embedded_remark(input: &str) -> RawParseResult<Remark> {
     tuple((
         begin,
         multispace0,
         opt(remark_tag),
         multispace0,
-        many0(alt((non_quoted, quoted, middle_star))),
+        many0(alt((non_quoted, quoted, nested, lparen, middle_star))),
         end,
     ))
     .map(|(_begin, _sp1, tag, _sp2, chars, end)| Remark {
@@ -222,6 +243,24 @@ mod tests {
         assert_eq!(remark.remark, "a * b");
     }
 
+    #[test]
+    fn nested() {
+        let (res, remark) = super::embedded_remark("(* a (* b *) c *) d")
+            .finish()
+            .unwrap();
+        assert_eq!(res, " d");
+        assert_eq!(remark.remark, "a (* b *) c");
+
+        let (res, remark) = super::embedded_remark("(* (f(x)) (* (* *) `*)` *) *)")
+            .finish()
+            .unwrap();
+        assert_eq!(res, "");
+        assert_eq!(remark.remark, "(f(x)) (* (* *) `*)` *)");
+
+        // Unbalanced
+        assert!(super::embedded_remark("(* a (* b *)").finish().is_err());
+    }
+
     #[test]
     fn tail_remark() {
         let (res, remark) = super::tail_remark("-- aaa\nbbb").finish().unwrap();
@@ -101,8 +101,8 @@ pub fn procedure_decl(input: &str) -> ParseResult<Procedure> {
 ///                      \[ VAR \] [formal_parameter]
 ///                    }
 ///                    `)` \] `;` .
-pub fn procedure_head(input: &str) -> ParseResult<(String, Vec<FormalParameter>)> {
-    let param = tuple((opt(tag("VAR")), formal_parameter)).map(|(var, mut params)| {
+pub fn procedure_head(input: &str) -> ParseResult<'_, (String, Vec<FormalParameter>)> {
+    let param = tuple((opt(var_keyword), formal_parameter)).map(|(var, mut params)| {
         for param in &mut params {
             param.is_variable = var.is_some();
         }
@@ -125,6 +125,15 @@ pub fn procedure_head(input: &str) -> ParseResult<(String, Vec<FormalParameter>)
     .parse(input)
 }
 
+/// `VAR` not followed directly by a letter, e.g. `variance` is a parameter name
+fn var_keyword(input: &str) -> ParseResult<'_, &str> {
+    let (input, var) = tag("VAR").parse(input)?;
+    let (input, _) = nom::combinator::not(nom::character::complete::satisfy(|c| {
+        c.is_ascii_alphanumeric() || c == '_'
+    }))(input)?;
+    Ok((input, var))
+}
+
 /// 220 function_decl = [function_head] [algorithm_head] [stmt] { [stmt] } END_FUNCTION `;` .
 pub fn function_decl(input: &str) -> ParseResult<Function> {
     tuple((
@@ -542,6 +551,39 @@ mod tests {
         assert_eq!(residual, "");
     }
 
+    #[test]
+    fn procedure() {
+        let exp_str = r#"
+        PROCEDURE p(VAR x, y : REAL; variance : INTEGER);
+            LOCAL
+                z : REAL := 0.0;
+            END_LOCAL;
+            z := x;
+            x := y;
+            y := z * variance;
+        END_PROCEDURE;
+        "#
+        .trim();
+        let (residual, (procedure, _remark)) = super::procedure_decl(exp_str).finish().unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(procedure.name, "p");
+        let params: Vec<_> = procedure
+            .parameters
+            .iter()
+            .map(|p| (p.name.as_str(), p.is_variable))
+            .collect();
+        assert_eq!(params, [("x", true), ("y", true), ("variance", false)]);
+        assert_eq!(procedure.variables.len(), 1);
+        assert_eq!(procedure.statements.len(), 3);
+
+        // without parameters
+        let (residual, (procedure, _remark)) = super::procedure_decl("PROCEDURE q; END_PROCEDURE;")
+            .finish()
+            .unwrap();
+        assert_eq!(residual, "");
+        assert!(procedure.parameters.is_empty());
+    }
+
     #[test]
     fn function_aggregate() {
         // From ISO-10303-11 p.66-67
@@ -180,8 +180,13 @@ pub fn abstract_supertype(input: &str) -> ParseResult<()> {
 
 #[cfg(test)]
 mod tests {
+    use crate::ast::SuperTypeExpression::{self, *};
     use nom::Finish;
 
+    fn r(name: &str) -> SuperTypeExpression {
+        Reference(name.to_string())
+    }
+
     #[test]
     fn subtype_constraint_oneof() {
         let exp_str = r#"
@@ -197,4 +202,51 @@ mod tests {
         dbg!(&entity);
         assert_eq!(residual, "");
     }
+
+    #[test]
+    fn supertype_expression_nested() {
+        let (residual, (expr, _remarks)) = super::supertype_expression("ONEOF (a, b) AND c")
+            .finish()
+            .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(
+            expr,
+            And {
+                terms: vec![
+                    OneOf {
+                        exprs: vec![r("a"), r("b")]
+                    },
+                    r("c")
+                ]
+            }
+        );
+
+        // AND binds tighter than ANDOR
+        let (residual, (expr, _remarks)) =
+            super::supertype_expression("a ANDOR b AND (ONEOF (c, d ANDOR e))")
+                .finish()
+                .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(
+            expr,
+            AndOr {
+                factors: vec![
+                    r("a"),
+                    And {
+                        terms: vec![
+                            r("b"),
+                            OneOf {
+                                exprs: vec![
+                                    r("c"),
+                                    AndOr {
+                                        factors: vec![r("d"), r("e")]
+                                    }
+                                ]
+                            }
+                        ]
+                    }
+                ]
+            }
+        );
+    }
 }
@@ -96,3 +96,73 @@ pub fn bound_spec(input: &str) -> ParseResult<Bound> {
 pub fn instantiable_type(input: &str) -> ParseResult<Type> {
     alt((concrete_types, entity_ref.map(Type::Named))).parse(input)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::Finish;
+
+    #[test]
+    fn array() {
+        let (res, (array, _remarks)) = super::array_type("ARRAY [1:3] OF REAL").finish().unwrap();
+        assert_eq!(res, "");
+        assert_eq!(
+            array,
+            Type::Array {
+                base: Box::new(Type::Simple(SimpleType::Real)),
+                bound: Some(Bound {
+                    lower: Expression::integer(1),
+                    upper: Expression::integer(3),
+                }),
+                unique: false,
+                optional: false,
+            }
+        );
+    }
+
+    #[test]
+    fn array_optional_unique() {
+        let (res, (array, _remarks)) =
+            super::aggregation_types("ARRAY [0:2] OF OPTIONAL UNIQUE ARRAY [1:3] OF point")
+                .finish()
+                .unwrap();
+        assert_eq!(res, "");
+        let Type::Array {
+            base,
+            unique,
+            optional,
+            ..
+        } = array
+        else {
+            panic!("ARRAY is expected");
+        };
+        assert!(unique && optional);
+        assert!(matches!(
+            *base,
+            Type::Array { ref base, unique: false, optional: false, .. }
+                if **base == Type::Named("point".to_string())
+        ));
+    }
+
+    #[test]
+    fn bag() {
+        let (res, (bag, _remarks)) = super::aggregation_types("BAG [0:?] OF point")
+            .finish()
+            .unwrap();
+        assert_eq!(res, "");
+        assert_eq!(
+            bag,
+            Type::Bag {
+                base: Box::new(Type::Named("point".to_string())),
+                bound: Some(Bound {
+                    lower: Expression::integer(0),
+                    upper: Expression::indeterminate(),
+                }),
+            }
+        );
+
+        let (res, (bag, _remarks)) = super::aggregation_types("BAG OF REAL").finish().unwrap();
+        assert_eq!(res, "");
+        assert!(matches!(bag, Type::Bag { bound: None, .. }));
+    }
+}
@@ -171,7 +171,7 @@ mod tests {
                 base: Box::new(Type::Named("curve".to_string())),
                 bound: Some(Bound {
                     upper: Expression::indeterminate(),
-                    lower: Expression::real(1.0),
+                    lower: Expression::integer(1),
                 })
             }
         )
@@ -24,13 +24,16 @@ pub fn underlying_type(input: &str) -> ParseResult<Type> {
 }
 
 /// 327 type_decl = TYPE [type_id] `=` [underlying_type] `;` \[ [where_clause] \] END_TYPE `;` .
+///
+/// Redundant semicolons after the underlying type, e.g. `TYPE a = INTEGER;; END_TYPE;`,
+/// are accepted as found in some schemas.
 pub fn type_decl(input: &str) -> ParseResult<TypeDecl> {
     tuple((
         tag("TYPE"),
         type_id,
         char('='),
         underlying_type,
-        char(';'),
+        many1(char(';')),
         opt(where_clause),
         tag("END_TYPE"),
         char(';'),
@@ -94,4 +97,30 @@ mod tests {
         assert_eq!(residual, "");
         dbg!(ty);
     }
+
+    #[test]
+    fn type_decl_where_multiple() {
+        let (residual, (ty, remarks)) = super::type_decl(
+            r#"
+            TYPE month = INTEGER;; (* remark before WHERE *)
+            WHERE
+              wr1: 1 <= SELF; -- lower bound
+              wr2: SELF <= 12;;
+            END_TYPE;
+            "#
+            .trim(),
+        )
+        .finish()
+        .unwrap();
+        assert_eq!(residual, "");
+        assert_eq!(remarks.len(), 2);
+        let labels: Vec<_> = ty
+            .where_clause
+            .unwrap()
+            .rules
+            .into_iter()
+            .map(|rule| rule.label.unwrap())
+            .collect();
+        assert_eq!(labels, ["wr1", "wr2"]);
+    }
 }
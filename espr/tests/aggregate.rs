@@ -0,0 +1,85 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY a;
+    x: AGGREGATE OF REAL;
+  END_ENTITY;
+
+  ENTITY b;
+    a: AGGREGATE:label OF a;
+  END_ENTITY;
+
+  FUNCTION scale(input : AGGREGATE:intype OF REAL; scalar: REAL): AGGREGATE:intype OF REAL;
+    LOCAL
+      result : AGGREGATE:intype OF REAL := input;
+    END_LOCAL;
+    RETURN(result);
+  END_FUNCTION;
+END_SCHEMA;
+"#;
+
+#[test]
+fn aggregate() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+            b: HashMap<::ruststep::tables::InstanceId, as_holder!(B)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
+                &self.a
+            }
+            pub fn b_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(B)> {
+                &self.b
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = a)]
+        #[holder(type_name = "a")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct A {
+            pub x: Vec<f64>,
+        }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = b)]
+        #[holder(type_name = "b")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct B {
+            #[holder(use_place_holder)]
+            pub a: Vec<A>,
+        }
+        impl ::ruststep::tables::Validate for B {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
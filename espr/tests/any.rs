@@ -24,37 +24,48 @@ fn any() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            base: HashMap<u64, as_holder!(Base)>,
-            sub1: HashMap<u64, as_holder!(Sub1)>,
-            sub2: HashMap<u64, as_holder!(Sub2)>,
+            base: HashMap<::ruststep::tables::InstanceId, as_holder!(Base)>,
+            sub1: HashMap<::ruststep::tables::InstanceId, as_holder!(Sub1)>,
+            sub2: HashMap<::ruststep::tables::InstanceId, as_holder!(Sub2)>,
         }
         impl Tables {
-            pub fn base_holders(&self) -> &HashMap<u64, as_holder!(Base)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn base_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Base)> {
                 &self.base
             }
-            pub fn sub1_holders(&self) -> &HashMap<u64, as_holder!(Sub1)> {
+            pub fn sub1_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Sub1)> {
                 &self.sub1
             }
-            pub fn sub2_holders(&self) -> &HashMap<u64, as_holder!(Sub2)> {
+            pub fn sub2_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Sub2)> {
                 &self.sub2
             }
         }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = base)]
+        #[holder(type_name = "base")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Base {
             pub x: f64,
         }
         #[derive(Debug, Clone, PartialEq, Holder)]
         # [holder (table = Tables)]
+        #[holder(type_name = "base")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub enum BaseAny {
             #[holder(use_place_holder)]
             Base(Box<Base>),
@@ -87,36 +98,61 @@ fn any() {
                 }
             }
         }
+        impl ::ruststep::tables::Validate for Base {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
         # [holder (table = Tables)]
         # [holder (field = sub1)]
+        #[holder(type_name = "sub1")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Sub1 {
             #[as_ref]
             #[as_mut]
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y1: f64,
         }
+        impl ::ruststep::tables::Validate for Sub1 {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.base)?;
+                Ok(())
+            }
+        }
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
         # [holder (table = Tables)]
         # [holder (field = sub2)]
+        #[holder(type_name = "sub2")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Sub2 {
             #[as_ref]
             #[as_mut]
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y2: f64,
         }
+        impl ::ruststep::tables::Validate for Sub2 {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.base)?;
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
@@ -0,0 +1,136 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY base;
+    x: REAL;
+  END_ENTITY;
+
+  ENTITY sub SUBTYPE OF (base);
+    y: REAL;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn inherited_last() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        attribute_order: AttributeOrder::InheritedLast,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            base: HashMap<::ruststep::tables::InstanceId, as_holder!(Base)>,
+            sub: HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn base_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Base)> {
+                &self.base
+            }
+            pub fn sub_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)> {
+                &self.sub
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = base)]
+        #[holder(type_name = "base")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Base {
+            pub x: f64,
+        }
+        #[derive(Debug, Clone, PartialEq, Holder)]
+        # [holder (table = Tables)]
+        #[holder(type_name = "base")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub enum BaseAny {
+            #[holder(use_place_holder)]
+            Base(Box<Base>),
+            #[holder(use_place_holder)]
+            Sub(Box<Sub>),
+        }
+        impl Into<BaseAny> for Base {
+            fn into(self) -> BaseAny {
+                BaseAny::Base(Box::new(self))
+            }
+        }
+        impl Into<BaseAny> for Sub {
+            fn into(self) -> BaseAny {
+                BaseAny::Sub(Box::new(self.into()))
+            }
+        }
+        impl AsRef<Base> for BaseAny {
+            fn as_ref(&self) -> &Base {
+                match self {
+                    BaseAny::Base(x) => x.as_ref(),
+                    BaseAny::Sub(x) => (**x).as_ref(),
+                }
+            }
+        }
+        impl ::ruststep::tables::Validate for Base {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(
+            Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
+        )]
+        # [holder (table = Tables)]
+        # [holder (field = sub)]
+        #[holder(type_name = "sub")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Sub {
+            pub y: f64,
+            #[as_ref]
+            #[as_mut]
+            #[deref]
+            #[deref_mut]
+            #[holder(use_place_holder)]
+            #[holder(supertype)]
+            pub base: Base,
+        }
+        impl ::ruststep::tables::Validate for Sub {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.base)?;
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn entity_to_tokens() {
+    use quote::ToTokens;
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let sub = &ir.schemas[0].entities[1];
+    let tt = rustfmt(sub.to_token_stream().to_string());
+    // Supertype fields precede by default
+    let base = tt.find("pub base: Base").unwrap();
+    let y = tt.find("pub y: f64").unwrap();
+    assert!(base < y);
+}
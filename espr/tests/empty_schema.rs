@@ -0,0 +1,31 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA empty_schema;
+END_SCHEMA;
+"#;
+
+#[test]
+fn empty_schema() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["EMPTY_SCHEMA"];
+    pub mod empty_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {}
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "EMPTY_SCHEMA";
+        }
+    }
+    "#);
+}
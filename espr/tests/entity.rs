@@ -22,40 +22,61 @@ fn entity() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            a: HashMap<u64, as_holder!(A)>,
-            b: HashMap<u64, as_holder!(B)>,
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+            b: HashMap<::ruststep::tables::InstanceId, as_holder!(B)>,
         }
         impl Tables {
-            pub fn a_holders(&self) -> &HashMap<u64, as_holder!(A)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
                 &self.a
             }
-            pub fn b_holders(&self) -> &HashMap<u64, as_holder!(B)> {
+            pub fn b_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(B)> {
                 &self.b
             }
         }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
+        #[holder(type_name = "a")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct A {
             pub x: f64,
             pub y: f64,
         }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = b)]
+        #[holder(type_name = "b")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct B {
             pub z: f64,
             #[holder(use_place_holder)]
             pub a: A,
         }
+        impl ::ruststep::tables::Validate for B {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
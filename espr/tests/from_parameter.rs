@@ -0,0 +1,162 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE color = ENUMERATION OF (red, light_blue);
+  END_TYPE;
+
+  TYPE label = STRING;
+  END_TYPE;
+
+  TYPE shape = SELECT (point);
+  END_TYPE;
+
+  ENTITY point;
+    x: REAL;
+    c: color;
+    name: OPTIONAL label;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn from_parameter() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        deserialize_backend: DeserializeBackend::FromParameter,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(from_parameter)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            point: HashMap<::ruststep::tables::InstanceId, as_holder!(Point)>,
+            label: HashMap<::ruststep::tables::InstanceId, as_holder!(Label)>,
+            shape: HashMap<::ruststep::tables::InstanceId, as_holder!(Shape)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn point_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Point)> {
+                &self.point
+            }
+            pub fn label_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Label)> {
+                &self.label
+            }
+            pub fn shape_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Shape)> {
+                &self.shape
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum Color {
+            Red,
+            LightBlue,
+        }
+        #[derive(
+            Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
+        )]
+        # [holder (table = Tables)]
+        # [holder (field = label)]
+        #[holder(type_name = "label")]
+        #[holder(generate_from_parameter)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Label(pub String);
+        #[derive(Debug, Clone, PartialEq, Holder)]
+        # [holder (table = Tables)]
+        #[holder(type_name = "shape")]
+        #[holder(generate_from_parameter)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub enum Shape {
+            #[holder(use_place_holder)]
+            Point(Box<Point>),
+        }
+        impl Color {
+            #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+            pub fn to_part21(&self) -> &'static str {
+                match self {
+                    Color::Red => ".RED.",
+                    Color::LightBlue => ".LIGHT_BLUE.",
+                }
+            }
+        }
+        impl ::std::fmt::Display for Color {
+            #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    Color::Red => "RED",
+                    Color::LightBlue => "LIGHT_BLUE",
+                })
+            }
+        }
+        impl ::std::str::FromStr for Color {
+            type Err = ::ruststep::error::Error;
+            #[doc = r" Accepts both `RED` and `.RED.`"]
+            fn from_str(s: &str) -> ::ruststep::error::Result<Self> {
+                let item = s
+                    .strip_prefix('.')
+                    .and_then(|s| s.strip_suffix('.'))
+                    .unwrap_or(s);
+                match item {
+                    "RED" => Ok(Color::Red),
+                    "LIGHT_BLUE" => Ok(Color::LightBlue),
+                    _ => Err(::ruststep::tables::unexpected_parameter::<Self>(
+                        &::ruststep::ast::Parameter::Enumeration(item.to_string()),
+                    )),
+                }
+            }
+        }
+        impl ::ruststep::tables::IntoParameter for Color {
+            fn into_parameter(self) -> ::ruststep::ast::Parameter {
+                ::ruststep::ast::Parameter::Enumeration(self.to_string())
+            }
+        }
+        impl ::ruststep::tables::FromParameter for Color {
+            fn from_parameter(
+                parameter: &::ruststep::ast::Parameter,
+            ) -> ::ruststep::error::Result<Self> {
+                match parameter {
+                    ::ruststep::ast::Parameter::Enumeration(item) => match item.as_str() {
+                        "RED" => Ok(Color::Red),
+                        "LIGHT_BLUE" => Ok(Color::LightBlue),
+                        _ => Err(::ruststep::tables::unexpected_parameter::<Self>(parameter)),
+                    },
+                    _ => Err(::ruststep::tables::unexpected_parameter::<Self>(parameter)),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = point)]
+        #[holder(type_name = "point")]
+        #[holder(generate_from_parameter)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Point {
+            pub x: f64,
+            pub c: Color,
+            #[holder(use_place_holder)]
+            pub name: Option<Label>,
+        }
+        impl ::ruststep::tables::Validate for Point {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
@@ -0,0 +1,96 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY point;
+    x: REAL;
+  END_ENTITY;
+
+  ENTITY group;
+    items: SET [1:?] OF GENERIC_ENTITY;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn generic_entity() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            point: HashMap<::ruststep::tables::InstanceId, as_holder!(Point)>,
+            group: HashMap<::ruststep::tables::InstanceId, as_holder!(Group)>,
+            generic_entity: HashMap<::ruststep::tables::InstanceId, as_holder!(GenericEntity)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn point_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Point)> {
+                &self.point
+            }
+            pub fn group_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Group)> {
+                &self.group
+            }
+            pub fn generic_entity_holders(
+                &self,
+            ) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(GenericEntity)> {
+                &self.generic_entity
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, Holder)]
+        # [holder (table = Tables)]
+        #[holder(type_name = "generic_entity")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub enum GenericEntity {
+            #[holder(use_place_holder)]
+            Point(Box<Point>),
+            #[holder(use_place_holder)]
+            Group(Box<Group>),
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = point)]
+        #[holder(type_name = "point")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Point {
+            pub x: f64,
+        }
+        impl ::ruststep::tables::Validate for Point {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = group)]
+        #[holder(type_name = "group")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Group {
+            #[holder(use_place_holder)]
+            pub items: Vec<GenericEntity>,
+        }
+        impl ::ruststep::tables::Validate for Group {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
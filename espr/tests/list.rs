@@ -26,28 +26,33 @@ fn list() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            a: HashMap<u64, as_holder!(A)>,
-            b: HashMap<u64, as_holder!(B)>,
-            c: HashMap<u64, as_holder!(C)>,
-            d: HashMap<u64, as_holder!(D)>,
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+            b: HashMap<::ruststep::tables::InstanceId, as_holder!(B)>,
+            c: HashMap<::ruststep::tables::InstanceId, as_holder!(C)>,
+            d: HashMap<::ruststep::tables::InstanceId, as_holder!(D)>,
         }
         impl Tables {
-            pub fn a_holders(&self) -> &HashMap<u64, as_holder!(A)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
                 &self.a
             }
-            pub fn b_holders(&self) -> &HashMap<u64, as_holder!(B)> {
+            pub fn b_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(B)> {
                 &self.b
             }
-            pub fn c_holders(&self) -> &HashMap<u64, as_holder!(C)> {
+            pub fn c_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(C)> {
                 &self.c
             }
-            pub fn d_holders(&self) -> &HashMap<u64, as_holder!(D)> {
+            pub fn d_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(D)> {
                 &self.d
             }
         }
@@ -56,30 +61,52 @@ fn list() {
         )]
         # [holder (table = Tables)]
         # [holder (field = c)]
+        #[holder(type_name = "c")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct C(#[holder(use_place_holder)] pub Vec<f64>);
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
         # [holder (table = Tables)]
         # [holder (field = d)]
+        #[holder(type_name = "d")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct D(#[holder(use_place_holder)] pub Vec<A>);
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
+        #[holder(type_name = "a")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct A {
             pub x: Vec<f64>,
         }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = b)]
+        #[holder(type_name = "b")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct B {
             #[holder(use_place_holder)]
             pub a: Vec<A>,
         }
+        impl ::ruststep::tables::Validate for B {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
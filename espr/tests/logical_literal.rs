@@ -19,29 +19,44 @@ fn logical_literal() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["IFC4X3_DEV_6A23AE8"];
     pub mod IFC4X3_DEV_6a23ae8 {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
+            #[table(validate)]
             IfcGeometricRepresentationContext:
-                HashMap<u64, as_holder!(IfcGeometricRepresentationContext)>,
+                HashMap<::ruststep::tables::InstanceId, as_holder!(IfcGeometricRepresentationContext)>,
         }
         impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "IFC4X3_DEV_6A23AE8";
             pub fn IfcGeometricRepresentationContext_holders(
                 &self,
-            ) -> &HashMap<u64, as_holder!(IfcGeometricRepresentationContext)> {
+            ) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(IfcGeometricRepresentationContext)>
+            {
                 &self.IfcGeometricRepresentationContext
             }
         }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = IfcGeometricRepresentationContext)]
+        #[holder(type_name = "IfcGeometricRepresentationContext")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct IfcGeometricRepresentationContext {
             pub TrueNorth: Option<bool>,
         }
+        impl ::ruststep::tables::Validate for IfcGeometricRepresentationContext {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
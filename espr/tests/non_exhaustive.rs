@@ -0,0 +1,192 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE color = ENUMERATION OF (red, green);
+  END_TYPE;
+
+  TYPE shape = SELECT (base, color);
+  END_TYPE;
+
+  ENTITY base;
+    x: REAL;
+  END_ENTITY;
+
+  ENTITY sub SUBTYPE OF (base);
+    y: REAL;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn non_exhaustive() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        non_exhaustive: true,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            base: HashMap<::ruststep::tables::InstanceId, as_holder!(Base)>,
+            sub: HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)>,
+            shape: HashMap<::ruststep::tables::InstanceId, as_holder!(Shape)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn base_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Base)> {
+                &self.base
+            }
+            pub fn sub_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)> {
+                &self.sub
+            }
+            pub fn shape_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Shape)> {
+                &self.shape
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+        #[non_exhaustive]
+        pub enum Color {
+            Red,
+            Green,
+        }
+        #[derive(Debug, Clone, PartialEq, Holder)]
+        # [holder (table = Tables)]
+        #[holder(type_name = "shape")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        #[non_exhaustive]
+        pub enum Shape {
+            #[holder(use_place_holder)]
+            Base(BaseAny),
+            Color(Color),
+        }
+        impl Color {
+            #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+            pub fn to_part21(&self) -> &'static str {
+                match self {
+                    Color::Red => ".RED.",
+                    Color::Green => ".GREEN.",
+                }
+            }
+        }
+        impl ::std::fmt::Display for Color {
+            #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    Color::Red => "RED",
+                    Color::Green => "GREEN",
+                })
+            }
+        }
+        impl ::std::str::FromStr for Color {
+            type Err = ::ruststep::error::Error;
+            #[doc = r" Accepts both `RED` and `.RED.`"]
+            fn from_str(s: &str) -> ::ruststep::error::Result<Self> {
+                let item = s
+                    .strip_prefix('.')
+                    .and_then(|s| s.strip_suffix('.'))
+                    .unwrap_or(s);
+                match item {
+                    "RED" => Ok(Color::Red),
+                    "GREEN" => Ok(Color::Green),
+                    _ => Err(::ruststep::tables::unexpected_parameter::<Self>(
+                        &::ruststep::ast::Parameter::Enumeration(item.to_string()),
+                    )),
+                }
+            }
+        }
+        impl ::ruststep::tables::IntoParameter for Color {
+            fn into_parameter(self) -> ::ruststep::ast::Parameter {
+                ::ruststep::ast::Parameter::Enumeration(self.to_string())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = base)]
+        #[holder(type_name = "base")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Base {
+            pub x: f64,
+        }
+        #[derive(Debug, Clone, PartialEq, Holder)]
+        # [holder (table = Tables)]
+        #[holder(type_name = "base")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        #[non_exhaustive]
+        pub enum BaseAny {
+            #[holder(use_place_holder)]
+            Base(Box<Base>),
+            #[holder(use_place_holder)]
+            Sub(Box<Sub>),
+        }
+        impl Into<BaseAny> for Base {
+            fn into(self) -> BaseAny {
+                BaseAny::Base(Box::new(self))
+            }
+        }
+        impl Into<BaseAny> for Sub {
+            fn into(self) -> BaseAny {
+                BaseAny::Sub(Box::new(self.into()))
+            }
+        }
+        impl AsRef<Base> for BaseAny {
+            fn as_ref(&self) -> &Base {
+                match self {
+                    BaseAny::Base(x) => x.as_ref(),
+                    BaseAny::Sub(x) => (**x).as_ref(),
+                }
+            }
+        }
+        impl ::ruststep::tables::Validate for Base {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(
+            Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
+        )]
+        # [holder (table = Tables)]
+        # [holder (field = sub)]
+        #[holder(type_name = "sub")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Sub {
+            #[as_ref]
+            #[as_mut]
+            #[deref]
+            #[deref_mut]
+            #[holder(use_place_holder)]
+            #[holder(supertype)]
+            pub base: Base,
+            pub y: f64,
+        }
+        impl ::ruststep::tables::Validate for Sub {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.base)?;
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
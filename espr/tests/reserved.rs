@@ -28,28 +28,33 @@ fn reserved_keyword() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            r#loop: HashMap<u64, as_holder!(Loop)>,
-            a: HashMap<u64, as_holder!(A)>,
-            c: HashMap<u64, as_holder!(C)>,
-            b: HashMap<u64, as_holder!(B)>,
+            r#loop: HashMap<::ruststep::tables::InstanceId, as_holder!(Loop)>,
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+            c: HashMap<::ruststep::tables::InstanceId, as_holder!(C)>,
+            b: HashMap<::ruststep::tables::InstanceId, as_holder!(B)>,
         }
         impl Tables {
-            pub fn loop_holders(&self) -> &HashMap<u64, as_holder!(Loop)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn loop_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Loop)> {
                 &self.r#loop
             }
-            pub fn a_holders(&self) -> &HashMap<u64, as_holder!(A)> {
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
                 &self.a
             }
-            pub fn c_holders(&self) -> &HashMap<u64, as_holder!(C)> {
+            pub fn c_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(C)> {
                 &self.c
             }
-            pub fn b_holders(&self) -> &HashMap<u64, as_holder!(B)> {
+            pub fn b_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(B)> {
                 &self.b
             }
         }
@@ -58,32 +63,59 @@ fn reserved_keyword() {
         )]
         # [holder (table = Tables)]
         # [holder (field = b)]
+        #[holder(type_name = "b")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct B(#[holder(use_place_holder)] pub Loop);
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = r#loop)]
+        #[holder(type_name = "loop")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Loop {
             pub a: f64,
         }
+        impl ::ruststep::tables::Validate for Loop {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = a)]
+        #[holder(type_name = "a")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct A {
             pub z: f64,
             #[holder(use_place_holder)]
             pub a_loop: Loop,
         }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = c)]
+        #[holder(type_name = "c")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct C {
             #[holder(use_place_holder)]
             pub r#loop: B,
         }
+        impl ::ruststep::tables::Validate for C {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
@@ -0,0 +1,115 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY point;
+    x: REAL;
+  END_ENTITY;
+
+  ENTITY line;
+    start: point;
+    finish: OPTIONAL point;
+  END_ENTITY;
+
+  ENTITY polyline;
+    points: LIST [2:?] OF point;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+#[test]
+fn shared_references() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        shared_references: true,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            point: HashMap<::ruststep::tables::InstanceId, as_holder!(Point)>,
+            line: HashMap<::ruststep::tables::InstanceId, as_holder!(Line)>,
+            polyline: HashMap<::ruststep::tables::InstanceId, as_holder!(Polyline)>,
+            #[table(shared_cache)]
+            shared_cache: ::ruststep::tables::SharedCache,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn point_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Point)> {
+                &self.point
+            }
+            pub fn line_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Line)> {
+                &self.line
+            }
+            pub fn polyline_holders(
+                &self,
+            ) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Polyline)> {
+                &self.polyline
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = point)]
+        #[holder(type_name = "point")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Point {
+            pub x: f64,
+        }
+        impl ::ruststep::tables::Validate for Point {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = line)]
+        #[holder(type_name = "line")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Line {
+            #[holder(use_place_holder)]
+            pub start: ::std::rc::Rc<Point>,
+            #[holder(use_place_holder)]
+            pub finish: Option<::std::rc::Rc<Point>>,
+        }
+        impl ::ruststep::tables::Validate for Line {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = polyline)]
+        #[holder(type_name = "polyline")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Polyline {
+            #[holder(use_place_holder)]
+            pub points: Vec<::std::rc::Rc<Point>>,
+        }
+        impl ::ruststep::tables::Validate for Polyline {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
@@ -26,37 +26,50 @@ fn subsuper() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            base: HashMap<u64, as_holder!(Base)>,
-            sub: HashMap<u64, as_holder!(Sub)>,
-            subsub: HashMap<u64, as_holder!(Subsub)>,
+            base: HashMap<::ruststep::tables::InstanceId, as_holder!(Base)>,
+            sub: HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)>,
+            subsub: HashMap<::ruststep::tables::InstanceId, as_holder!(Subsub)>,
         }
         impl Tables {
-            pub fn base_holders(&self) -> &HashMap<u64, as_holder!(Base)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn base_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Base)> {
                 &self.base
             }
-            pub fn sub_holders(&self) -> &HashMap<u64, as_holder!(Sub)> {
+            pub fn sub_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Sub)> {
                 &self.sub
             }
-            pub fn subsub_holders(&self) -> &HashMap<u64, as_holder!(Subsub)> {
+            pub fn subsub_holders(
+                &self,
+            ) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Subsub)> {
                 &self.subsub
             }
         }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = base)]
+        #[holder(type_name = "base")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Base {
             pub x: f64,
         }
         #[derive(Debug, Clone, PartialEq, Holder)]
         # [holder (table = Tables)]
+        #[holder(type_name = "base")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub enum BaseAny {
             #[holder(use_place_holder)]
             Base(Box<Base>),
@@ -81,24 +94,36 @@ fn subsuper() {
                 }
             }
         }
+        impl ::ruststep::tables::Validate for Base {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
         # [holder (table = Tables)]
         # [holder (field = sub)]
+        #[holder(type_name = "sub")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Sub {
             #[as_ref]
             #[as_mut]
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub base: Base,
             pub y: f64,
         }
         #[derive(Debug, Clone, PartialEq, Holder)]
         # [holder (table = Tables)]
+        #[holder(type_name = "sub")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub enum SubAny {
             #[holder(use_place_holder)]
             Sub(Box<Sub>),
@@ -131,21 +156,37 @@ fn subsuper() {
                 }
             }
         }
+        impl ::ruststep::tables::Validate for Sub {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.base)?;
+                Ok(())
+            }
+        }
         #[derive(
             Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
         )]
         # [holder (table = Tables)]
         # [holder (field = subsub)]
+        #[holder(type_name = "subsub")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct Subsub {
             #[as_ref]
             #[as_mut]
             #[deref]
             #[deref_mut]
             #[holder(use_place_holder)]
+            #[holder(supertype)]
             pub sub: Sub,
             pub z: f64,
         }
+        impl ::ruststep::tables::Validate for Subsub {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                ::ruststep::tables::Validate::validate(&self.sub)?;
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
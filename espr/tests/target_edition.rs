@@ -0,0 +1,127 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  ENTITY a;
+    gen: INTEGER;
+    x: ARRAY [1:3] OF REAL;
+  END_ENTITY;
+END_SCHEMA;
+"#;
+
+fn generate(target_edition: TargetEdition) -> String {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        target_edition,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+    rustfmt(tt)
+}
+
+#[test]
+fn edition2018() {
+    insta::assert_snapshot!(generate(TargetEdition::Edition2018), @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
+                &self.a
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = a)]
+        #[holder(type_name = "a")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct A {
+            pub gen: i64,
+            pub x: Vec<f64>,
+        }
+        impl A {
+            #[doc = "Element of `x` by EXPRESS index starting from 1"]
+            pub fn x_at(&self, index: i64) -> Option<&f64> {
+                let index = <usize as ::std::convert::TryFrom<i64>>::try_from(index - 1i64).ok()?;
+                self.x.get(index)
+            }
+            #[doc = "Element of `x` by EXPRESS index starting from 1"]
+            pub fn x_at_mut(&mut self, index: i64) -> Option<&mut f64> {
+                let index = <usize as ::std::convert::TryFrom<i64>>::try_from(index - 1i64).ok()?;
+                self.x.get_mut(index)
+            }
+        }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
+
+#[test]
+fn edition2024() {
+    insta::assert_snapshot!(generate(TargetEdition::Edition2024), @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
+                &self.a
+            }
+        }
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = a)]
+        #[holder(type_name = "a")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct A {
+            pub r#gen: i64,
+            pub x: Vec<f64>,
+        }
+        impl A {
+            #[doc = "Element of `x` by EXPRESS index starting from 1"]
+            pub fn x_at(&self, index: i64) -> Option<&f64> {
+                let index = usize::try_from(index - 1i64).ok()?;
+                self.x.get(index)
+            }
+            #[doc = "Element of `x` by EXPRESS index starting from 1"]
+            pub fn x_at_mut(&mut self, index: i64) -> Option<&mut f64> {
+                let index = usize::try_from(index - 1i64).ok()?;
+                self.x.get_mut(index)
+            }
+        }
+        impl ::ruststep::tables::Validate for A {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    "#);
+}
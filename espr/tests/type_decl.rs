@@ -35,28 +35,33 @@ fn type_decl() {
 
     let tt = rustfmt(tt);
 
-    insta::assert_snapshot!(tt, @r###"
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
     pub mod test_schema {
         use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
         use std::collections::HashMap;
         #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
         pub struct Tables {
-            e: HashMap<u64, as_holder!(E)>,
-            a: HashMap<u64, as_holder!(A)>,
-            c: HashMap<u64, as_holder!(C)>,
-            d: HashMap<u64, as_holder!(D)>,
+            e: HashMap<::ruststep::tables::InstanceId, as_holder!(E)>,
+            a: HashMap<::ruststep::tables::InstanceId, as_holder!(A)>,
+            c: HashMap<::ruststep::tables::InstanceId, as_holder!(C)>,
+            d: HashMap<::ruststep::tables::InstanceId, as_holder!(D)>,
         }
         impl Tables {
-            pub fn e_holders(&self) -> &HashMap<u64, as_holder!(E)> {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+            pub fn e_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(E)> {
                 &self.e
             }
-            pub fn a_holders(&self) -> &HashMap<u64, as_holder!(A)> {
+            pub fn a_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(A)> {
                 &self.a
             }
-            pub fn c_holders(&self) -> &HashMap<u64, as_holder!(C)> {
+            pub fn c_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(C)> {
                 &self.c
             }
-            pub fn d_holders(&self) -> &HashMap<u64, as_holder!(D)> {
+            pub fn d_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(D)> {
                 &self.d
             }
         }
@@ -65,7 +70,10 @@ fn type_decl() {
         )]
         # [holder (table = Tables)]
         # [holder (field = a)]
+        #[holder(type_name = "a")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct A(pub String);
         #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
         pub enum B {
@@ -78,19 +86,71 @@ fn type_decl() {
         )]
         # [holder (table = Tables)]
         # [holder (field = c)]
+        #[holder(type_name = "c")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct C(#[holder(use_place_holder)] pub A);
         #[derive(
             Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
         )]
         # [holder (table = Tables)]
         # [holder (field = d)]
+        #[holder(type_name = "d")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct D(pub B);
+        impl B {
+            #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+            pub fn to_part21(&self) -> &'static str {
+                match self {
+                    B::Are => ".ARE.",
+                    B::Sore => ".SORE.",
+                    B::Dore => ".DORE.",
+                }
+            }
+        }
+        impl ::std::fmt::Display for B {
+            #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    B::Are => "ARE",
+                    B::Sore => "SORE",
+                    B::Dore => "DORE",
+                })
+            }
+        }
+        impl ::std::str::FromStr for B {
+            type Err = ::ruststep::error::Error;
+            #[doc = r" Accepts both `RED` and `.RED.`"]
+            fn from_str(s: &str) -> ::ruststep::error::Result<Self> {
+                let item = s
+                    .strip_prefix('.')
+                    .and_then(|s| s.strip_suffix('.'))
+                    .unwrap_or(s);
+                match item {
+                    "ARE" => Ok(B::Are),
+                    "SORE" => Ok(B::Sore),
+                    "DORE" => Ok(B::Dore),
+                    _ => Err(::ruststep::tables::unexpected_parameter::<Self>(
+                        &::ruststep::ast::Parameter::Enumeration(item.to_string()),
+                    )),
+                }
+            }
+        }
+        impl ::ruststep::tables::IntoParameter for B {
+            fn into_parameter(self) -> ::ruststep::ast::Parameter {
+                ::ruststep::ast::Parameter::Enumeration(self.to_string())
+            }
+        }
         #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
         # [holder (table = Tables)]
         # [holder (field = e)]
+        #[holder(type_name = "e")]
         #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
         pub struct E {
             #[holder(use_place_holder)]
             pub a: A,
@@ -100,6 +160,11 @@ fn type_decl() {
             #[holder(use_place_holder)]
             pub d: D,
         }
+        impl ::ruststep::tables::Validate for E {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
     }
-    "###);
+    "#);
 }
@@ -0,0 +1,180 @@
+use espr::{
+    ast::SyntaxTree,
+    codegen::rust::*,
+    ir::{TypeRef, IR},
+};
+
+const EXPRESS: &str = r#"
+SCHEMA geometry;
+  ENTITY point;
+    x: REAL;
+  END_ENTITY;
+  TYPE label = STRING;
+  END_TYPE;
+END_SCHEMA;
+
+SCHEMA drawing;
+  USE FROM geometry (point AS pt, label AS caption);
+END_SCHEMA;
+"#;
+
+#[test]
+fn use_rename() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["GEOMETRY", "DRAWING"];
+    pub mod geometry {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {
+            point: HashMap<::ruststep::tables::InstanceId, as_holder!(Point)>,
+            label: HashMap<::ruststep::tables::InstanceId, as_holder!(Label)>,
+        }
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "GEOMETRY";
+            pub fn point_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Point)> {
+                &self.point
+            }
+            pub fn label_holders(&self) -> &HashMap<::ruststep::tables::InstanceId, as_holder!(Label)> {
+                &self.label
+            }
+        }
+        #[derive(
+            Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
+        )]
+        # [holder (table = Tables)]
+        # [holder (field = label)]
+        #[holder(type_name = "label")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Label(pub String);
+        #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
+        # [holder (table = Tables)]
+        # [holder (field = point)]
+        #[holder(type_name = "point")]
+        #[holder(generate_deserialize)]
+        #[holder(generate_into_parameter)]
+        #[holder(generate_from_owned)]
+        pub struct Point {
+            pub x: f64,
+        }
+        impl ::ruststep::tables::Validate for Point {
+            fn validate(&self) -> ::std::result::Result<(), &'static str> {
+                Ok(())
+            }
+        }
+    }
+    pub mod drawing {
+        #[doc = "`geometry.label` imported as `caption`"]
+        pub use super::geometry::{Label as Caption, LabelHolder as CaptionHolder};
+        #[doc = "`geometry.point` imported as `pt`"]
+        pub use super::geometry::{Point as Pt, PointHolder as PtHolder};
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {}
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "DRAWING";
+        }
+    }
+    "#);
+}
+
+#[test]
+fn foreign_entity_reference() {
+    // Entities and `SELECT` of them in other schemas cannot be resolved by `Tables` of this schema
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA geometry;
+          ENTITY point;
+            x: REAL;
+          END_ENTITY;
+          TYPE label = STRING;
+          END_TYPE;
+        END_SCHEMA;
+
+        SCHEMA drawing;
+          USE FROM geometry (point AS pt, label AS caption);
+          ENTITY line;
+            points: LIST OF pt;
+            caption: caption;
+          END_ENTITY;
+          TYPE shape = SELECT (line, pt);
+          END_TYPE;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+    let errors: Vec<_> = tt.match_indices("compile_error !").collect();
+    assert_eq!(errors.len(), 2);
+    assert!(tt.contains(
+        "`drawing.line.points` refers `geometry.point` declared in another schema, whose references `#N` cannot be resolved by `drawing::Tables`"
+    ));
+    assert!(tt.contains("`drawing.shape` refers `geometry.point`"));
+}
+
+#[test]
+fn transitive() {
+    // `drawing` imports `geometry.point` through `shape` importing it
+    let st = SyntaxTree::parse(
+        r#"
+        SCHEMA geometry;
+          ENTITY point;
+            x: REAL;
+          END_ENTITY;
+          TYPE label = STRING;
+          END_TYPE;
+        END_SCHEMA;
+
+        SCHEMA shape;
+          USE FROM geometry (point AS pt, label);
+        END_SCHEMA;
+
+        SCHEMA drawing;
+          USE FROM shape (pt AS p, label AS caption);
+          TYPE title = caption;
+          END_TYPE;
+        END_SCHEMA;
+        "#,
+    )
+    .unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let drawing = &ir.schemas[2];
+    let aliases: Vec<_> = drawing
+        .aliases
+        .iter()
+        .map(|alias| match &alias.ty {
+            TypeRef::Entity { name, scope, .. } | TypeRef::Named { name, scope, .. } => {
+                (alias.name.as_str(), format!("{}.{}", scope, name))
+            }
+            ty => panic!("Unexpected alias {:?}", ty),
+        })
+        .collect();
+    assert_eq!(
+        aliases,
+        [
+            ("p", "geometry.point".to_string()),
+            ("caption", "geometry.label".to_string())
+        ]
+    );
+
+    let tt = ir.to_token_stream(CratePrefix::External).to_string();
+    assert!(tt.contains("pub use super :: geometry :: { Point as P , PointHolder as PHolder } ;"));
+    assert!(tt.contains(
+        "pub use super :: geometry :: { Label as Caption , LabelHolder as CaptionHolder } ;"
+    ));
+}
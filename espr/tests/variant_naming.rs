@@ -0,0 +1,87 @@
+use espr::{ast::SyntaxTree, codegen::rust::*, ir::IR};
+
+const EXPRESS: &str = r#"
+SCHEMA test_schema;
+  TYPE mode = ENUMERATION OF (some_multi_word_value, level_1);
+  END_TYPE;
+END_SCHEMA;
+"#;
+
+#[test]
+fn screaming_snake_case() {
+    let st = SyntaxTree::parse(EXPRESS).unwrap();
+    let ir = IR::from_syntax_tree(&st).unwrap();
+    let options = CodegenOptions {
+        variant_naming: VariantNaming::ScreamingSnakeCase,
+        ..Default::default()
+    };
+    let tt = ir
+        .to_token_stream_with_options(CratePrefix::External, &options)
+        .to_string();
+
+    let tt = rustfmt(tt);
+
+    insta::assert_snapshot!(tt, @r#"
+    #[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+    pub const SCHEMA_IDENTIFIERS: &[&str] = &["TEST_SCHEMA"];
+    pub mod test_schema {
+        use ruststep::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
+        use std::collections::HashMap;
+        #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+        #[table(into_parameter)]
+        pub struct Tables {}
+        impl Tables {
+            #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+            pub const SCHEMA_IDENTIFIER: &str = "TEST_SCHEMA";
+        }
+        #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
+        #[allow(non_camel_case_types)]
+        pub enum Mode {
+            #[serde(rename = "SomeMultiWordValue")]
+            SOME_MULTI_WORD_VALUE,
+            #[serde(rename = "Level1")]
+            LEVEL_1,
+        }
+        impl Mode {
+            #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+            pub fn to_part21(&self) -> &'static str {
+                match self {
+                    Mode::SOME_MULTI_WORD_VALUE => ".SOME_MULTI_WORD_VALUE.",
+                    Mode::LEVEL_1 => ".LEVEL_1.",
+                }
+            }
+        }
+        impl ::std::fmt::Display for Mode {
+            #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(match self {
+                    Mode::SOME_MULTI_WORD_VALUE => "SOME_MULTI_WORD_VALUE",
+                    Mode::LEVEL_1 => "LEVEL_1",
+                })
+            }
+        }
+        impl ::std::str::FromStr for Mode {
+            type Err = ::ruststep::error::Error;
+            #[doc = r" Accepts both `RED` and `.RED.`"]
+            fn from_str(s: &str) -> ::ruststep::error::Result<Self> {
+                let item = s
+                    .strip_prefix('.')
+                    .and_then(|s| s.strip_suffix('.'))
+                    .unwrap_or(s);
+                match item {
+                    "SOME_MULTI_WORD_VALUE" => Ok(Mode::SOME_MULTI_WORD_VALUE),
+                    "LEVEL_1" => Ok(Mode::LEVEL_1),
+                    _ => Err(::ruststep::tables::unexpected_parameter::<Self>(
+                        &::ruststep::ast::Parameter::Enumeration(item.to_string()),
+                    )),
+                }
+            }
+        }
+        impl ::ruststep::tables::IntoParameter for Mode {
+            fn into_parameter(self) -> ::ruststep::ast::Parameter {
+                ::ruststep::ast::Parameter::Enumeration(self.to_string())
+            }
+        }
+    }
+    "#);
+}
@@ -1,15 +1,21 @@
 use inflector::Inflector;
 use proc_macro2::{Span, TokenStream as TokenStream2};
-use proc_macro_error::OptionExt;
-use quote::quote;
+use proc_macro_error::{abort, OptionExt};
+use quote::{format_ident, quote};
 use std::convert::*;
+use syn::ext::IdentExt;
 
 use super::*;
 
-pub fn derive_deserialize(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+pub fn derive_deserialize(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    st: &syn::DataStruct,
+) -> TokenStream2 {
     let name = ident.to_string().to_screaming_snake_case();
-    let def_visitor_tt = def_visitor(ident, &name, st);
-    let impl_deserialize_tt = impl_deserialize(ident, &name, st);
+    let lifetimes = Lifetimes::parse(generics);
+    let def_visitor_tt = def_visitor(ident, &name, st, &lifetimes);
+    let impl_deserialize_tt = impl_deserialize(ident, &name, st, &lifetimes);
     quote! {
         #def_visitor_tt
         #impl_deserialize_tt
@@ -22,9 +28,29 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let impl_from_parameter_tt = if attr.generate_from_parameter {
+        impl_from_parameter(&holder_ident, &name, st)
+    } else {
+        quote! {}
+    };
+    let impl_into_parameter_tt = if attr.generate_into_parameter {
+        impl_into_parameter(ident, st)
+    } else {
+        quote! {}
+    };
+    let impl_serialize_tt = if attr.generate_serialize {
+        impl_serialize(&holder_ident, &name, st)
+    } else {
+        quote! {}
+    };
+    let impl_from_owned_tt = if attr.generate_from_owned {
+        impl_from_owned(ident, st)
+    } else {
+        quote! {}
+    };
     if attr.generate_deserialize {
-        let def_visitor_tt = def_visitor(&holder_ident, &name, st);
-        let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
+        let def_visitor_tt = def_visitor(&holder_ident, &name, st, &Lifetimes::default());
+        let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st, &Lifetimes::default());
         let impl_with_visitor_tt = impl_with_visitor(ident);
         quote! {
             #def_holder_tt
@@ -33,12 +59,20 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
+            #impl_from_parameter_tt
+            #impl_into_parameter_tt
+            #impl_serialize_tt
+            #impl_from_owned_tt
         }
     } else {
         quote! {
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_from_parameter_tt
+            #impl_into_parameter_tt
+            #impl_serialize_tt
+            #impl_from_owned_tt
         }
     }
 }
@@ -52,15 +86,31 @@ struct FieldEntries {
     attributes: Vec<syn::Ident>,
     holder_types: Vec<syn::Type>,
     into_owned: Vec<TokenStream2>,
+    /// Field initializers of holders by `ruststep::tables::FromOwned`
+    from_owned: Vec<TokenStream2>,
+    /// Statements pushing references of fields into `refs`
+    collect_refs: Vec<TokenStream2>,
+    /// True if any field requires the table to resolve
+    use_place_holder: bool,
+    /// Holders of fields with `#[holder(supertype)]`
+    supertypes: Vec<syn::Type>,
+    /// Fields with `#[holder(supertype)]`
+    supertype_attributes: Vec<syn::Ident>,
 }
 
 impl FieldEntries {
     fn parse(st: &syn::DataStruct) -> Self {
         let table_arg = table_arg();
+        let ruststep = ruststep_crate();
 
         let mut attributes = Vec::new();
         let mut holder_types = Vec::new();
         let mut into_owned = Vec::new();
+        let mut from_owned = Vec::new();
+        let mut collect_refs = Vec::new();
+        let mut use_place_holder = false;
+        let mut supertypes = Vec::new();
+        let mut supertype_attributes = Vec::new();
 
         for field in &st.fields {
             let ident = field.ident.as_ref().expect_or_abort("st is not struct");
@@ -68,26 +118,63 @@ impl FieldEntries {
 
             let ft: FieldType = field.ty.clone().try_into().unwrap();
 
-            let HolderAttr { place_holder, .. } = HolderAttr::parse(&field.attrs);
+            let HolderAttr {
+                place_holder,
+                supertype,
+                ..
+            } = HolderAttr::parse(&field.attrs);
+            if supertype {
+                supertypes.push(ft.clone().into_holder().into());
+                supertype_attributes.push(ident.clone());
+            }
             if place_holder {
                 match &ft {
                     FieldType::Path(_) => {
                         into_owned.push(quote! { #ident.into_owned(#table_arg)? });
                     }
+                    FieldType::Boxed(_) => {
+                        into_owned.push(quote! { Box::new(#ident.into_owned(#table_arg)?) });
+                    }
+                    FieldType::Optional(ty) if matches!(**ty, FieldType::Boxed(_)) => {
+                        into_owned.push(quote! { #ident.map(|holder| holder.into_owned(#table_arg).map(Box::new)).transpose()? });
+                    }
+                    FieldType::Shared(_) => {
+                        into_owned.push(quote! { #ident.into_shared(#table_arg)? });
+                    }
+                    FieldType::Optional(ty) if matches!(**ty, FieldType::Shared(_)) => {
+                        into_owned.push(quote! { #ident.map(|holder| holder.into_shared(#table_arg)).transpose()? });
+                    }
                     FieldType::Optional(_) => {
                         into_owned.push(quote! { #ident.map(|holder| holder.into_owned(#table_arg)).transpose()? });
                     }
+                    FieldType::List(ty) if matches!(**ty, FieldType::Shared(_)) => {
+                        into_owned.push(quote! {
+                            #ident
+                                .into_iter()
+                                .map(|v| v.into_shared(#table_arg))
+                                .collect::<::std::result::Result<Vec<_>, _>>()?
+                        })
+                    }
                     FieldType::List(_) => into_owned.push(quote! {
                         #ident
                             .into_iter()
                             .map(|v| v.into_owned(#table_arg))
                             .collect::<::std::result::Result<Vec<_>, _>>()?
                     }),
-                    FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
                 }
+                let expr = ft
+                    .clone()
+                    .into_holder()
+                    .place_holder_from_owned(quote! { #ident }, supertype);
+                from_owned.push(quote! { #ident: #expr? });
+                collect_refs.push(
+                    quote! { #ruststep::tables::ContainsRef::collect_refs(&self.#ident, refs); },
+                );
+                use_place_holder = true;
                 holder_types.push(ft.into_holder().into_place_holder().into());
             } else {
                 into_owned.push(quote! { #ident });
+                from_owned.push(quote! { #ident });
                 holder_types.push(ft.into());
             }
         }
@@ -95,10 +182,128 @@ impl FieldEntries {
             attributes,
             holder_types,
             into_owned,
+            from_owned,
+            collect_refs,
+            use_place_holder,
+            supertypes,
+            supertype_attributes,
         }
     }
 }
 
+// Parameters of fields in order, see `ruststep::tables::IntoParameter::into_parameter_list`
+fn impl_into_parameter(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+    let name = ident.to_string().to_screaming_snake_case();
+    let holder_ident = as_holder_ident(ident);
+    let FieldEntries {
+        attributes,
+        supertypes,
+        supertype_attributes,
+        ..
+    } = FieldEntries::parse(st);
+    let ruststep = ruststep_crate();
+    let record = quote! {
+        vec![#ruststep::ast::Record {
+            name: #name.to_string(),
+            parameter: #ruststep::ast::Parameter::List(vec![
+                #(#ruststep::tables::IntoParameter::write_parameter(#attributes, writer)?),*
+            ]),
+        }]
+    };
+    // Owned supertypes are written as partial records of a complex instance,
+    // while referred ones are kept in the record
+    let write_records = if supertypes.is_empty() {
+        quote! {
+            let #holder_ident { #(#attributes),* } = self;
+            Ok(#record)
+        }
+    } else {
+        let own_attributes: Vec<_> = attributes
+            .iter()
+            .filter(|attr| !supertype_attributes.contains(attr))
+            .collect();
+        quote! {
+            let #holder_ident { #(#attributes),* } = self;
+            match (#(#supertype_attributes,)*) {
+                (#(#ruststep::tables::PlaceHolder::Owned(#supertype_attributes),)*) => {
+                    let mut records = Vec::new();
+                    #(
+                    records.extend(#ruststep::tables::IntoParameter::write_records(
+                        #supertype_attributes,
+                        <#supertypes as #ruststep::tables::Holder>::name(),
+                        writer,
+                    )?);
+                    )*
+                    records.push(#ruststep::ast::Record {
+                        name: #name.to_string(),
+                        parameter: #ruststep::ast::Parameter::List(vec![
+                            #(#ruststep::tables::IntoParameter::write_parameter(#own_attributes, writer)?),*
+                        ]),
+                    });
+                    // Common supertypes of multiple supertypes appear only once
+                    records.sort_by(|a, b| a.name.cmp(&b.name));
+                    records.dedup_by(|a, b| a.name == b.name);
+                    Ok(records)
+                }
+                (#(#supertype_attributes,)*) => Ok(#record),
+            }
+        }
+    };
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::IntoParameter for #holder_ident {
+            fn into_parameter(self) -> #ruststep::ast::Parameter {
+                #ruststep::ast::Parameter::Typed {
+                    keyword: #name.to_string(),
+                    parameter: Box::new(#ruststep::ast::Parameter::List(
+                        #ruststep::tables::IntoParameter::into_parameter_list(self),
+                    )),
+                }
+            }
+            fn into_parameter_list(self) -> Vec<#ruststep::ast::Parameter> {
+                let #holder_ident { #(#attributes),* } = self;
+                vec![#(#ruststep::tables::IntoParameter::into_parameter(#attributes)),*]
+            }
+            fn write_parameter(
+                self,
+                writer: &mut #ruststep::tables::InstanceWriter,
+            ) -> #ruststep::error::Result<#ruststep::ast::Parameter> {
+                let records = #ruststep::tables::IntoParameter::write_records(self, #name, writer)?;
+                writer.push(records)
+            }
+            fn write_records(
+                self,
+                _name: &str,
+                writer: &mut #ruststep::tables::InstanceWriter,
+            ) -> #ruststep::error::Result<Vec<#ruststep::ast::Record>> {
+                #write_records
+            }
+        }
+    } // quote!
+}
+
+// Record `NAME(...)` with fields in order, see `ruststep::ast::ser::to_record`
+fn impl_serialize(holder_ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+    let FieldEntries { attributes, .. } = FieldEntries::parse(st);
+    let keys = attribute_names(st);
+    let len = attributes.len();
+    let serde = serde_crate();
+    quote! {
+        #[automatically_derived]
+        impl #serde::Serialize for #holder_ident {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: #serde::Serializer,
+            {
+                use #serde::ser::SerializeStruct;
+                let mut state = serializer.serialize_struct(#name, #len)?;
+                #( state.serialize_field(#keys, &self.#attributes)?; )*
+                state.end()
+            }
+        }
+    } // quote!
+}
+
 pub fn def_holder(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
     let holder_ident = as_holder_ident(ident);
     let FieldEntries {
@@ -115,19 +320,80 @@ pub fn def_holder(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
     }
 }
 
-pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct) -> TokenStream2 {
+pub fn impl_holder(ident: &syn::Ident, attr: &HolderAttr, st: &syn::DataStruct) -> TokenStream2 {
     let name = ident.to_string().to_screaming_snake_case();
+    let type_name = attr.type_name(ident);
     let holder_ident = as_holder_ident(ident);
     let FieldEntries {
         attributes,
         into_owned,
+        collect_refs,
+        use_place_holder,
+        supertypes,
         ..
     } = FieldEntries::parse(st);
     let attr_len = attributes.len();
-    let HolderAttr { table, .. } = table;
+    let HolderAttr { table, .. } = attr;
     let table_arg = table_arg();
     let ruststep = ruststep_crate();
 
+    // Holder and owned are converted into each other without table if no field refers others
+    let impl_from_holder = if use_place_holder {
+        quote! {}
+    } else {
+        quote! {
+            #[automatically_derived]
+            impl ::std::convert::From<#holder_ident> for #ident {
+                fn from(holder: #holder_ident) -> Self {
+                    let #holder_ident { #(#attributes),* } = holder;
+                    #ident { #(#attributes),* }
+                }
+            }
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #holder_ident {
+                fn from(owned: #ident) -> Self {
+                    let #ident { #(#attributes),* } = owned;
+                    #holder_ident { #(#attributes),* }
+                }
+            }
+        }
+    };
+    let impl_collect_refs = if use_place_holder {
+        quote! {
+            fn collect_refs(&self, refs: &mut Vec<#ruststep::tables::InstanceId>) {
+                #(#collect_refs)*
+            }
+        }
+    } else {
+        quote! {}
+    };
+    // Supertype fields precede own fields, see `supertype_fields` in espr
+    let impl_complex = if supertypes.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn complex_keywords(keywords: &mut Vec<&'static str>) {
+                keywords.push(#name);
+                #( <#supertypes as #ruststep::tables::Holder>::complex_keywords(keywords); )*
+            }
+            fn complex_parameters(
+                records: &[#ruststep::ast::Record],
+            ) -> #ruststep::error::Result<Vec<#ruststep::ast::Parameter>> {
+                let mut parameters = Vec::new();
+                #(
+                parameters.push(#ruststep::ast::Parameter::Typed {
+                    keyword: <#supertypes as #ruststep::tables::Holder>::name().to_string(),
+                    parameter: Box::new(#ruststep::ast::Parameter::List(
+                        <#supertypes as #ruststep::tables::Holder>::complex_parameters(records)?,
+                    )),
+                });
+                )*
+                parameters.extend(#ruststep::tables::partial_parameters(records, #name)?);
+                Ok(parameters)
+            }
+        }
+    };
+
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::IntoOwned for #holder_ident {
@@ -143,9 +409,55 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
             fn name() -> &'static str {
                 #name
             }
+            fn type_name() -> &'static str {
+                #type_name
+            }
             fn attr_len() -> usize {
                 #attr_len
             }
+            #impl_collect_refs
+            #impl_complex
+        }
+        #impl_from_holder
+    } // quote!
+}
+
+// Not generated by default since ids are allocated through `ruststep::tables::TypeIndex` of the table
+fn impl_from_owned(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+    let holder_ident = as_holder_ident(ident);
+    let FieldEntries {
+        attributes,
+        from_owned,
+        use_place_holder,
+        ..
+    } = FieldEntries::parse(st);
+    let ruststep = ruststep_crate();
+    let (table_param, ids_param) = if use_place_holder {
+        (quote! { table }, quote! { ids })
+    } else {
+        (quote! { _table }, quote! { _ids })
+    };
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::FromOwned for #holder_ident {
+            fn from_owned(
+                owned: Self::Owned,
+                #table_param: &mut Self::Table,
+                #ids_param: &mut #ruststep::tables::IdAllocator,
+            ) -> #ruststep::error::Result<Self> {
+                let #ident { #(#attributes),* } = owned;
+                Ok(#holder_ident { #(#from_owned),* })
+            }
+            fn place_holder(
+                owned: Self::Owned,
+                table: &mut Self::Table,
+                ids: &mut #ruststep::tables::IdAllocator,
+            ) -> #ruststep::error::Result<#ruststep::tables::PlaceHolder<Self>> {
+                let holder = Self::from_owned(owned, table, ids)?;
+                let id = ids.allocate(table)?;
+                #ruststep::tables::EntityTable::<Self>::insert_checked(table, id, holder)?;
+                Ok(#ruststep::tables::PlaceHolder::Ref(#ruststep::ast::Name::Entity(id)))
+            }
         }
     } // quote!
 }
@@ -158,30 +470,136 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-            fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
+            fn get_owned(&self, entity_id: #ruststep::tables::InstanceId) -> #ruststep::error::Result<#ident> {
                 #ruststep::tables::get_owned(self, &self.#field, entity_id)
             }
             fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
                 #ruststep::tables::owned_iter(self, &self.#field)
             }
+            fn insert_checked(&mut self, entity_id: #ruststep::tables::InstanceId, holder: #holder_ident) -> #ruststep::error::Result<()> {
+                #ruststep::tables::insert_checked(self, |table| &mut table.#field, entity_id, holder)
+            }
+        }
+    }
+}
+
+/// Field names of the struct, whose types may not be supported by [FieldType], e.g. `&'a str`
+fn attribute_idents(st: &syn::DataStruct) -> Vec<syn::Ident> {
+    st.fields
+        .iter()
+        .map(|field| field.ident.clone().expect_or_abort("st is not struct"))
+        .collect()
+}
+
+/// EXPRESS names of attributes, `#[holder(rename = "...")]` or the identifier without `r#`
+fn attribute_names(st: &syn::DataStruct) -> Vec<String> {
+    st.fields
+        .iter()
+        .map(|field| match HolderAttr::parse(&field.attrs).rename {
+            Some(name) => name.value(),
+            None => field
+                .ident
+                .as_ref()
+                .expect_or_abort("st is not struct")
+                .unraw()
+                .to_string(),
+        })
+        .collect()
+}
+
+/// Lifetime parameters of a struct borrowing from the deserializer, e.g. `'a` of `A<'a>`
+#[derive(Default)]
+struct Lifetimes(Vec<syn::Lifetime>);
+
+impl Lifetimes {
+    fn parse(generics: &syn::Generics) -> Self {
+        let lifetimes = generics
+            .params
+            .iter()
+            .map(|param| match param {
+                syn::GenericParam::Lifetime(param) => param.lifetime.clone(),
+                _ => abort!(param, "Only lifetime parameters are supported"),
+            })
+            .collect();
+        Lifetimes(lifetimes)
+    }
+
+    /// `<'a, 'b>`, empty if no lifetime
+    fn params(&self) -> TokenStream2 {
+        if self.0.is_empty() {
+            return quote! {};
+        }
+        let lifetimes = &self.0;
+        quote! { <#(#lifetimes),*> }
+    }
+
+    /// `<'de: 'a + 'b, 'a, 'b>` for impls of serde traits
+    fn de_params(&self) -> TokenStream2 {
+        let lifetimes = &self.0;
+        if lifetimes.is_empty() {
+            quote! { <'de> }
+        } else {
+            quote! { <'de: #(#lifetimes)+*, #(#lifetimes),*> }
         }
     }
 }
 
 // `name` may be different from `ident`
 // because this will be used for both Entity struct and its `*Holder` struct.
-fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+fn def_visitor(
+    ident: &syn::Ident,
+    name: &str,
+    st: &syn::DataStruct,
+    lifetimes: &Lifetimes,
+) -> TokenStream2 {
     let visitor_ident = as_visitor_ident(ident);
-    let FieldEntries { attributes, .. } = FieldEntries::parse(st);
+    let attributes = attribute_idents(st);
     let attr_len = attributes.len();
+    let keys = attribute_names(st);
+    // Prefixed not to be shadowed by attributes, e.g. `map` or `key`
+    let values: Vec<_> = attributes
+        .iter()
+        .map(|attr| format_ident!("__{}", attr.unraw()))
+        .collect();
     let serde = serde_crate();
+    let params = lifetimes.params();
+    let de_params = lifetimes.de_params();
+    // Attributes keyed by their EXPRESS names, e.g. `{"x": 1.0, "type": 2.0}`
+    let visit_fields = if attributes.is_empty() {
+        quote! {
+            use #serde::de::Error;
+            Err(A::Error::unknown_field(&key, &[]))
+        }
+    } else {
+        quote! {
+            use #serde::de::Error;
+            const FIELDS: &[&str] = &[#(#keys),*];
+            #( let mut #values = None; )*
+            let mut key = Some(key);
+            while let Some(k) = key {
+                match k.as_str() {
+                    #( #keys => #values = Some(map.next_value()?), )*
+                    _ => return Err(A::Error::unknown_field(&k, FIELDS)),
+                }
+                key = map.next_key()?;
+            }
+            #( let #values = #values.ok_or_else(|| A::Error::missing_field(#keys))?; )*
+            Ok(#ident { #(#attributes: #values),* })
+        }
+    };
+    let def_visitor = if lifetimes.0.is_empty() {
+        quote! { pub struct #visitor_ident; }
+    } else {
+        let lifetimes = &lifetimes.0;
+        quote! { pub struct #visitor_ident #params(::std::marker::PhantomData<(#(&#lifetimes ()),*)>); }
+    };
     quote! {
         #[doc(hidden)]
-        pub struct #visitor_ident;
+        #def_visitor
 
         #[automatically_derived]
-        impl<'de> #serde::de::Visitor<'de> for #visitor_ident {
-            type Value = #ident;
+        impl #de_params #serde::de::Visitor<'de> for #visitor_ident #params {
+            type Value = #ident #params;
             fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
                 write!(formatter, #name)
             }
@@ -208,12 +626,12 @@ fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStr
                 let key: String = map
                     .next_key()?
                     .expect("Empty map cannot be accepted as ruststep Holder"); // this must be a bug, not runtime error
-                if key != #name {
-                    use #serde::de::{Error, Unexpected};
-                    return Err(A::Error::invalid_value(Unexpected::Other(&key), &self));
+                if key == #name {
+                    let value = map.next_value()?; // send to Self::visit_seq
+                    return Ok(value);
                 }
-                let value = map.next_value()?; // send to Self::visit_seq
-                Ok(value)
+
+                #visit_fields
             }
         }
     } // quote!
@@ -221,19 +639,31 @@ fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStr
 
 // `name` may be different from `ident`
 // because this will be used for both Entity struct and its `*Holder` struct.
-fn impl_deserialize(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+fn impl_deserialize(
+    ident: &syn::Ident,
+    name: &str,
+    st: &syn::DataStruct,
+    lifetimes: &Lifetimes,
+) -> TokenStream2 {
     let visitor_ident = as_visitor_ident(ident);
-    let FieldEntries { attributes, .. } = FieldEntries::parse(st);
+    let attributes = attribute_idents(st);
     let attr_len = attributes.len();
     let serde = serde_crate();
+    let params = lifetimes.params();
+    let de_params = lifetimes.de_params();
+    let visitor = if lifetimes.0.is_empty() {
+        quote! { #visitor_ident {} }
+    } else {
+        quote! { #visitor_ident(::std::marker::PhantomData) }
+    };
     quote! {
         #[automatically_derived]
-        impl<'de> #serde::de::Deserialize<'de> for #ident {
+        impl #de_params #serde::de::Deserialize<'de> for #ident #params {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
             where
                 D: #serde::de::Deserializer<'de>,
             {
-                deserializer.deserialize_tuple_struct(#name, #attr_len, #visitor_ident {})
+                deserializer.deserialize_tuple_struct(#name, #attr_len, #visitor)
             }
         }
     } // quote!
@@ -255,3 +685,28 @@ fn impl_with_visitor(ident: &syn::Ident) -> TokenStream2 {
         }
     } // quote!
 }
+
+// Same mapping as `def_visitor`, without serde
+fn impl_from_parameter(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+    let FieldEntries { attributes, .. } = FieldEntries::parse(st);
+    let attr_len = attributes.len();
+    let index = 0..attr_len;
+    let ruststep = ruststep_crate();
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::FromParameter for #ident {
+            fn from_parameter(parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                use #ruststep::tables::FromParameter;
+                let parameters = #ruststep::tables::attribute_parameters(#name, parameter, #attr_len)?;
+                Ok(#ident { #(#attributes: FromParameter::from_parameter(&parameters[#index])?),* })
+            }
+
+            fn from_typed(keyword: &str, parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                if keyword != #name {
+                    return Err(#ruststep::tables::unexpected_keyword::<Self>(keyword));
+                }
+                Self::from_parameter(parameter)
+            }
+        }
+    } // quote!
+}
@@ -14,6 +14,8 @@ pub enum FieldType {
     List(Box<FieldType>),
     /// Like `Box<T>`
     Boxed(Box<FieldType>),
+    /// Like `Rc<T>`, resolved through the shared cache of the table
+    Shared(Box<FieldType>),
 }
 
 impl FieldType {
@@ -48,6 +50,10 @@ impl FieldType {
                 let holder = ty.into_holder();
                 FieldType::Boxed(Box::new(holder))
             }
+            FieldType::Shared(ty) => {
+                let holder = ty.into_holder();
+                FieldType::Shared(Box::new(holder))
+            }
         }
     }
 
@@ -70,6 +76,51 @@ impl FieldType {
                 let place_holder = ty.into_place_holder();
                 FieldType::Boxed(Box::new(place_holder))
             }
+            // Holders keep a reference, and `Rc` only appears in owned struct
+            FieldType::Shared(ty) => ty.into_place_holder(),
+        }
+    }
+}
+
+impl FieldType {
+    /// Expression converting owned value `expr` of this holder type into its place holder
+    /// by `ruststep::tables::FromOwned`, which is a `Result` using `table` and `ids` in scope
+    ///
+    /// Entities are inserted into `table` and referred by id,
+    /// while `inline` keeps them as `PlaceHolder::Owned`, e.g. for supertype fields.
+    pub fn place_holder_from_owned(&self, expr: TokenStream2, inline: bool) -> TokenStream2 {
+        let ruststep = ruststep_crate();
+        match self {
+            FieldType::Path(path) => {
+                if inline {
+                    quote! {
+                        <#path as #ruststep::tables::FromOwned>::from_owned(#expr, table, ids)
+                            .map(#ruststep::tables::PlaceHolder::Owned)
+                    }
+                } else {
+                    quote! { <#path as #ruststep::tables::FromOwned>::place_holder(#expr, table, ids) }
+                }
+            }
+            FieldType::Optional(ty) => {
+                let inner = ty.place_holder_from_owned(quote! { v }, inline);
+                quote! { #expr.map(|v| #inner).transpose() }
+            }
+            FieldType::List(ty) => {
+                let inner = ty.place_holder_from_owned(quote! { v }, inline);
+                quote! {
+                    #expr
+                        .into_iter()
+                        .map(|v| #inner)
+                        .collect::<#ruststep::error::Result<Vec<_>>>()
+                }
+            }
+            FieldType::Boxed(ty) => {
+                let inner = ty.place_holder_from_owned(quote! { (*#expr) }, inline);
+                quote! { #inner.map(Box::new) }
+            }
+            FieldType::Shared(ty) => {
+                ty.place_holder_from_owned(quote! { (*#expr).clone() }, inline)
+            }
         }
     }
 }
@@ -102,6 +153,10 @@ impl From<FieldType> for syn::Type {
                 let ty: syn::Type = (*ty).into();
                 syn::parse_quote! { Box<#ty> }
             }
+            FieldType::Shared(ty) => {
+                let ty: syn::Type = (*ty).into();
+                syn::parse_quote! { ::std::rc::Rc<#ty> }
+            }
         };
         syn::Type::Path(syn::TypePath { qself: None, path })
     }
@@ -138,6 +193,9 @@ impl TryFrom<syn::Type> for FieldType {
                     if last_seg.ident == "Box" {
                         return Ok(FieldType::Boxed(ty));
                     }
+                    if last_seg.ident == "Rc" {
+                        return Ok(FieldType::Shared(ty));
+                    }
                 }
                 Err(UnsupportedTypeError {})
             }
@@ -232,5 +290,12 @@ mod tests {
         let ans: syn::Type =
             syn::parse_str("Option<Vec<::ruststep::tables::PlaceHolder<THolder>>>").unwrap();
         assert_eq!(<FieldType as Into<syn::Type>>::into(place_holder), ans);
+
+        let ty: syn::Type = syn::parse_str("Vec<Rc<T>>").unwrap();
+        let f: FieldType = ty.try_into().unwrap();
+        let place_holder = f.into_holder().into_place_holder();
+        let ans: syn::Type =
+            syn::parse_str("Vec<::ruststep::tables::PlaceHolder<THolder>>").unwrap();
+        assert_eq!(<FieldType as Into<syn::Type>>::into(place_holder), ans);
     }
 }
@@ -6,6 +6,13 @@
 //! - `#[holder(field = {field_ident})]`
 //! - `#[holder(use_place_holder)]`
 //! - `#[holder(generate_deserialize)]`
+//! - `#[holder(generate_from_parameter)]`
+//! - `#[holder(generate_into_parameter)]`
+//! - `#[holder(generate_from_owned)]`
+//! - `#[holder(generate_serialize)]`
+//! - `#[holder(type_name = "{express_name}")]`
+//! - `#[holder(rename = "{express_name}")]`
+//! - `#[holder(supertype)]`
 //!
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +21,13 @@ pub struct HolderAttr {
     pub field: Option<syn::Ident>,
     pub place_holder: bool,
     pub generate_deserialize: bool,
+    pub generate_from_parameter: bool,
+    pub generate_into_parameter: bool,
+    pub generate_from_owned: bool,
+    pub generate_serialize: bool,
+    pub type_name: Option<syn::LitStr>,
+    pub rename: Option<syn::LitStr>,
+    pub supertype: bool,
 }
 
 impl HolderAttr {
@@ -22,6 +36,13 @@ impl HolderAttr {
         let mut field = None;
         let mut place_holder = false;
         let mut generate_deserialize = false;
+        let mut generate_from_parameter = false;
+        let mut generate_into_parameter = false;
+        let mut generate_from_owned = false;
+        let mut generate_serialize = false;
+        let mut type_name = None;
+        let mut rename = None;
+        let mut supertype = false;
 
         for attr in attrs {
             // Only read `#[holder(...)]`
@@ -46,6 +67,27 @@ impl HolderAttr {
                 Attr::GenerateDeserialize => {
                     generate_deserialize = true;
                 }
+                Attr::GenerateFromParameter => {
+                    generate_from_parameter = true;
+                }
+                Attr::GenerateIntoParameter => {
+                    generate_into_parameter = true;
+                }
+                Attr::GenerateFromOwned => {
+                    generate_from_owned = true;
+                }
+                Attr::GenerateSerialize => {
+                    generate_serialize = true;
+                }
+                Attr::TypeName(name) => {
+                    type_name = Some(name);
+                }
+                Attr::Rename(name) => {
+                    rename = Some(name);
+                }
+                Attr::Supertype => {
+                    supertype = true;
+                }
             }
         }
         HolderAttr {
@@ -53,6 +95,23 @@ impl HolderAttr {
             field,
             place_holder,
             generate_deserialize,
+            generate_from_parameter,
+            generate_into_parameter,
+            generate_from_owned,
+            generate_serialize,
+            type_name,
+            rename,
+            supertype,
+        }
+    }
+}
+
+impl HolderAttr {
+    /// EXPRESS type name given by `#[holder(type_name = "...")]`, or the snake_case of `ident`
+    pub fn type_name(&self, ident: &syn::Ident) -> String {
+        match &self.type_name {
+            Some(name) => name.value(),
+            None => inflector::Inflector::to_snake_case(ident.to_string().as_str()),
         }
     }
 }
@@ -63,6 +122,13 @@ enum Attr {
     Field(syn::Ident),
     PlaceHolder,
     GenerateDeserialize,
+    GenerateFromParameter,
+    GenerateIntoParameter,
+    GenerateFromOwned,
+    GenerateSerialize,
+    TypeName(syn::LitStr),
+    Rename(syn::LitStr),
+    Supertype,
 }
 
 impl syn::parse::Parse for Attr {
@@ -81,6 +147,21 @@ impl syn::parse::Parse for Attr {
             }
             "use_place_holder" => Ok(Attr::PlaceHolder),
             "generate_deserialize" => Ok(Attr::GenerateDeserialize),
+            "generate_from_parameter" => Ok(Attr::GenerateFromParameter),
+            "generate_into_parameter" => Ok(Attr::GenerateIntoParameter),
+            "generate_from_owned" => Ok(Attr::GenerateFromOwned),
+            "generate_serialize" => Ok(Attr::GenerateSerialize),
+            "supertype" => Ok(Attr::Supertype),
+            "type_name" => {
+                let _eq: syn::Token![=] = input.parse()?;
+                let name = input.parse()?;
+                Ok(Attr::TypeName(name))
+            }
+            "rename" => {
+                let _eq: syn::Token![=] = input.parse()?;
+                let name = input.parse()?;
+                Ok(Attr::Rename(name))
+            }
             _ => Err(syn::parse::Error::new(
                 ident.span(),
                 "expected `table`, `field`, or `use_place_holder`",
@@ -132,4 +213,47 @@ mod tests {
         // typo
         assert!(syn::parse_str::<Attr>("use_place_helder").is_err());
     }
+
+    #[test]
+    fn parse_attr_supertype() {
+        let attr: Attr = syn::parse_str("supertype").unwrap();
+        assert_eq!(attr, Attr::Supertype);
+        assert!(syn::parse_str::<Attr>("supertype = true").is_err());
+    }
+
+    #[test]
+    fn parse_attr_generate() {
+        let attr: Attr = syn::parse_str("generate_deserialize").unwrap();
+        assert_eq!(attr, Attr::GenerateDeserialize);
+        let attr: Attr = syn::parse_str("generate_from_parameter").unwrap();
+        assert_eq!(attr, Attr::GenerateFromParameter);
+        let attr: Attr = syn::parse_str("generate_into_parameter").unwrap();
+        assert_eq!(attr, Attr::GenerateIntoParameter);
+        let attr: Attr = syn::parse_str("generate_from_owned").unwrap();
+        assert_eq!(attr, Attr::GenerateFromOwned);
+        let attr: Attr = syn::parse_str("generate_serialize").unwrap();
+        assert_eq!(attr, Attr::GenerateSerialize);
+    }
+
+    #[test]
+    fn parse_attr_type_name() {
+        let attr: Attr = syn::parse_str(r#"type_name = "cartesian_point""#).unwrap();
+        assert_eq!(
+            attr,
+            Attr::TypeName(syn::parse_str(r#""cartesian_point""#).unwrap())
+        );
+
+        // type_name must be a string literal
+        assert!(syn::parse_str::<Attr>("type_name = cartesian_point").is_err());
+        assert!(syn::parse_str::<Attr>("type_name").is_err());
+    }
+
+    #[test]
+    fn parse_attr_rename() {
+        let attr: Attr = syn::parse_str(r#"rename = "type""#).unwrap();
+        assert_eq!(attr, Attr::Rename(syn::parse_str(r#""type""#).unwrap()));
+
+        // rename must be a string literal
+        assert!(syn::parse_str::<Attr>("rename = type").is_err());
+    }
 }
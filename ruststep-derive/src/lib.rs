@@ -23,7 +23,7 @@
 
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use proc_macro_error::{abort_call_site, proc_macro_error};
+use proc_macro_error::{abort, abort_call_site, proc_macro_error};
 use quote::quote;
 use std::convert::*;
 
@@ -44,12 +44,13 @@ use table_init::*;
 ///
 /// ```
 /// use ruststep_derive::{as_holder, Holder, TableInit};
+/// use ruststep::tables::InstanceId;
 /// use std::collections::HashMap;
 ///
 /// #[derive(TableInit, Default)]
 /// pub struct Table {
-///     a: HashMap<u64, as_holder!(A)>,
-///     b: HashMap<u64, as_holder!(B)>,
+///     a: HashMap<InstanceId, as_holder!(A)>,
+///     b: HashMap<InstanceId, as_holder!(B)>,
 /// }
 ///
 /// #[derive(Debug, Clone, PartialEq, Holder)]
@@ -71,13 +72,34 @@ use table_init::*;
 ///     pub a: A,
 /// }
 /// ```
+///
+/// Tables marked by `#[table(validate)]` are checked in `TableInit::validate_rules`
+/// using `ruststep::tables::Validate` implemented for their owned types.
+///
+/// A field of `ruststep::tables::SharedCache` marked by `#[table(shared_cache)]` is not a table,
+/// but is used for resolving `Rc<T>` fields of owned structs through `ruststep::tables::SharedTable`.
+///
+/// Records are deserialized by serde by default.
+/// Tables marked by `#[table(from_parameter)]` use `ruststep::tables::FromParameter` instead,
+/// which requires `#[holder(generate_from_parameter)]` for all holders.
+///
+/// Tables marked by `#[table(into_parameter)]` implement `ruststep::tables::WriteTables`
+/// to be written into ISO 10303-21 files,
+/// which requires `#[holder(generate_into_parameter)]` for all holders.
 #[proc_macro_error]
-#[proc_macro_derive(TableInit)]
+#[proc_macro_derive(TableInit, attributes(table))]
 pub fn derive_table_init_entry(input: TokenStream) -> TokenStream {
     derive_table_init(&syn::parse(input).unwrap()).into()
 }
 
 /// Generate `impl Deserialize` for entity structs
+///
+/// Structs with lifetime parameters may borrow strings from the deserialized parameters,
+/// e.g. `name: &'a str`, through `impl<'de: 'a, 'a> Deserialize<'de> for A<'a>`.
+/// Strings are borrowed from `ruststep::ast::Parameter` where escapes like `''`
+/// are already resolved by the parser, not from the STEP file itself,
+/// and thus they never fall back to owned strings.
+/// `Holder` cannot be derived for such structs since holders stored in tables must be `'static`.
 #[proc_macro_error]
 #[proc_macro_derive(Deserialize)]
 pub fn derive_deserialize_entry(input: TokenStream) -> TokenStream {
@@ -87,7 +109,7 @@ pub fn derive_deserialize_entry(input: TokenStream) -> TokenStream {
 fn derive_deserialize(ast: &syn::DeriveInput) -> TokenStream2 {
     let ident = &ast.ident;
     match &ast.data {
-        syn::Data::Struct(st) => entity::derive_deserialize(ident, st),
+        syn::Data::Struct(st) => entity::derive_deserialize(ident, &ast.generics, st),
         syn::Data::Enum(e) => select::derive_deserialize(ident, e),
         _ => abort_call_site!("Only struct is supprted currently"),
     }
@@ -95,6 +117,10 @@ fn derive_deserialize(ast: &syn::DeriveInput) -> TokenStream2 {
 
 /// Generates `Holder` struct and related implementation for each `ENTITY` struct
 ///
+/// `impl From<B> for BHolder` and its reverse are also generated if no field uses `use_place_holder`,
+/// since otherwise the table is required to resolve and insert references,
+/// see `#[holder(generate_from_owned)]`.
+///
 /// `#[holder]` attribute
 /// ---------------------
 ///
@@ -128,9 +154,33 @@ fn derive_deserialize(ast: &syn::DeriveInput) -> TokenStream2 {
 /// - `#[holder(generate_deserialize)]`
 ///   - This must be a container attribute
 ///   - Flag for generating `impl Deserialize for XxxHolder`
+/// - `#[holder(generate_from_parameter)]`
+///   - This must be a container attribute
+///   - Flag for generating `impl ruststep::tables::FromParameter for XxxHolder` without serde
+/// - `#[holder(generate_into_parameter)]`
+///   - This must be a container attribute
+///   - Flag for generating `impl ruststep::tables::IntoParameter for XxxHolder`,
+///     which requires all fields to implement `IntoParameter`
+/// - `#[holder(generate_from_owned)]`
+///   - This must be a container attribute
+///   - Flag for generating `impl ruststep::tables::FromOwned for XxxHolder`,
+///     which inserts entities in fields into the table and requires `ruststep::tables::TypeIndex` for it
+/// - `#[holder(generate_serialize)]`
+///   - This must be a container attribute
+///   - Flag for generating `impl Serialize for XxxHolder` of structs into a record `XXX(...)`,
+///     where fields with `use_place_holder` are serialized as references `#id` unless owned
+/// - `#[holder(type_name = "{express_name}")]`
+///   - This must be a container attribute
+///   - Name of the EXPRESS type returned by `Holder::type_name`, e.g. `"cartesian_point"`
+///   - snake_case of the struct or enum name is used if not specified
 /// - `#[holder(use_place_holder)]`
 ///   - This can be both in field or variant attribute
 ///   - Specify the field is not a simple type
+/// - `#[holder(rename = "{express_name}")]`
+///   - This must be a field attribute
+///   - EXPRESS name of the attribute, e.g. `"type"` for a field `kind`, used as its key
+///     in maps of self-describing formats and in `Serialize`
+///   - The identifier without `r#` is used if not specified
 ///
 #[proc_macro_error]
 #[proc_macro_derive(Holder, attributes(holder))]
@@ -141,6 +191,13 @@ pub fn derive_holder_entry(input: TokenStream) -> TokenStream {
 fn derive_holder(ast: &syn::DeriveInput) -> TokenStream2 {
     let attr = HolderAttr::parse(&ast.attrs);
     let ident = &ast.ident;
+    if !ast.generics.params.is_empty() {
+        // `IntoOwned` requires `'static` since holders are stored in tables
+        abort!(
+            ast.generics,
+            "Holder cannot be derived for generic structs. Use `#[derive(Deserialize)]` for structs borrowing strings."
+        );
+    }
     match &ast.data {
         syn::Data::Struct(st) => match st.fields {
             syn::Fields::Named(_) => entity::derive_holder(ident, st, &attr),
@@ -174,6 +231,7 @@ mod snapshot_tests {
             r#"
             #[holder(table = Table)]
             #[holder(generate_deserialize)]
+            #[holder(generate_from_owned)]
             pub enum S1 {
                 #[holder(use_place_holder)]
                 A(Box<A>),
@@ -187,7 +245,7 @@ mod snapshot_tests {
         let tt = derive_holder(&input);
         let out = espr::codegen::rust::rustfmt(tt.to_string());
 
-        insta::assert_snapshot!(out, @r###"
+        insta::assert_snapshot!(out, @r#"
         #[doc = r" Auto-generated by `#[derive(Holder)]`"]
         #[derive(Clone, Debug, PartialEq)]
         pub enum S1Holder {
@@ -208,9 +266,55 @@ mod snapshot_tests {
             fn name() -> &'static str {
                 "S1"
             }
+            fn type_name() -> &'static str {
+                "s1"
+            }
             fn attr_len() -> usize {
                 0
             }
+            fn accepts(keyword: &str) -> bool {
+                false
+                    || <AHolder as ::ruststep::tables::Holder>::accepts(keyword)
+                    || <BHolder as ::ruststep::tables::Holder>::accepts(keyword)
+            }
+            fn collect_refs(&self, refs: &mut Vec<::ruststep::tables::InstanceId>) {
+                match self {
+                    S1Holder::A(sub) => <AHolder as ::ruststep::tables::Holder>::collect_refs(sub, refs),
+                    S1Holder::B(sub) => <BHolder as ::ruststep::tables::Holder>::collect_refs(sub, refs),
+                }
+            }
+        }
+        impl ::ruststep::tables::FromOwned for S1Holder {
+            fn from_owned(
+                owned: Self::Owned,
+                table: &mut Self::Table,
+                ids: &mut ::ruststep::tables::IdAllocator,
+            ) -> ::ruststep::error::Result<Self> {
+                Ok(match owned {
+                    S1::A(owned) => S1Holder::A(Box::new(
+                        <AHolder as ::ruststep::tables::FromOwned>::from_owned(*owned, table, ids)?,
+                    )),
+                    S1::B(owned) => S1Holder::B(Box::new(
+                        <BHolder as ::ruststep::tables::FromOwned>::from_owned(*owned, table, ids)?,
+                    )),
+                })
+            }
+            fn place_holder(
+                owned: Self::Owned,
+                table: &mut Self::Table,
+                ids: &mut ::ruststep::tables::IdAllocator,
+            ) -> ::ruststep::error::Result<::ruststep::tables::PlaceHolder<Self>> {
+                Ok(match owned {
+                    S1::A(owned) => {
+                        <AHolder as ::ruststep::tables::FromOwned>::place_holder(*owned, table, ids)?
+                            .map(|sub| S1Holder::A(Box::new(sub)))
+                    }
+                    S1::B(owned) => {
+                        <BHolder as ::ruststep::tables::FromOwned>::place_holder(*owned, table, ids)?
+                            .map(|sub| S1Holder::B(Box::new(sub)))
+                    }
+                })
+            }
         }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for S1Holder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
@@ -243,6 +347,16 @@ mod snapshot_tests {
                         let owned = map.next_value()?;
                         return Ok(S1Holder::B(Box::new(owned)));
                     }
+                    keyword if <AHolder as ::ruststep::tables::Holder>::accepts(keyword) => {
+                        let owned: AHolder =
+                            map.next_value_seed(::ruststep::tables::KeywordSeed::new(key.clone()))?;
+                        return Ok(S1Holder::A(Box::new(owned)));
+                    }
+                    keyword if <BHolder as ::ruststep::tables::Holder>::accepts(keyword) => {
+                        let owned: BHolder =
+                            map.next_value_seed(::ruststep::tables::KeywordSeed::new(key.clone()))?;
+                        return Ok(S1Holder::B(Box::new(owned)));
+                    }
                     _ => {
                         use ruststep::serde::de::{Error, Unexpected};
                         return Err(A::Error::invalid_value(Unexpected::Other(&key), &self));
@@ -257,14 +371,34 @@ mod snapshot_tests {
             }
         }
         impl ::ruststep::tables::EntityTable<S1Holder> for Table {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<S1> {
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<AHolder>::get_owned(self, entity_id) {
+            fn get_owned(
+                &self,
+                entity_id: ::ruststep::tables::InstanceId,
+            ) -> ::ruststep::error::Result<S1> {
+                use ruststep::tables::{Holder, TypeIndex};
+                let keywords = self.entity_types(entity_id);
+                if keywords.is_empty() {
+                    return Err(::ruststep::error::Error::UnknownEntity(entity_id));
+                }
+                if keywords
+                    .iter()
+                    .any(|keyword| <AHolder as Holder>::accepts(keyword))
+                {
+                    let owned = ::ruststep::tables::EntityTable::<AHolder>::get_owned(self, entity_id)?;
                     return Ok(S1::A(Box::new(owned.into())));
                 }
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<BHolder>::get_owned(self, entity_id) {
+                if keywords
+                    .iter()
+                    .any(|keyword| <BHolder as Holder>::accepts(keyword))
+                {
+                    let owned = ::ruststep::tables::EntityTable::<BHolder>::get_owned(self, entity_id)?;
                     return Ok(S1::B(Box::new(owned.into())));
                 }
-                Err(::ruststep::error::Error::UnknownEntity(entity_id))
+                Err(::ruststep::error::Error::UnexpectedEntityType {
+                    id: entity_id,
+                    keyword: keywords.join(" "),
+                    expected: <S1Holder as Holder>::name(),
+                })
             }
             fn owned_iter<'table>(
                 &'table self,
@@ -276,8 +410,22 @@ mod snapshot_tests {
                         .map(|owned| owned.map(|owned| S1::B(Box::new(owned.into()))))
                 ])
             }
+            fn insert_checked(
+                &mut self,
+                entity_id: ::ruststep::tables::InstanceId,
+                holder: S1Holder,
+            ) -> ::ruststep::error::Result<()> {
+                match holder {
+                    S1Holder::A(sub) => {
+                        ::ruststep::tables::EntityTable::<AHolder>::insert_checked(self, entity_id, *sub)
+                    }
+                    S1Holder::B(sub) => {
+                        ::ruststep::tables::EntityTable::<BHolder>::insert_checked(self, entity_id, *sub)
+                    }
+                }
+            }
         }
-        "###);
+        "#);
     }
 
     #[test]
@@ -301,7 +449,7 @@ mod snapshot_tests {
         let tt = derive_holder(&input);
         let out = espr::codegen::rust::rustfmt(tt.to_string());
 
-        insta::assert_snapshot!(out, @r###"
+        insta::assert_snapshot!(out, @r#"
         #[doc = r" Auto-generated by `#[derive(Holder)]`"]
         #[derive(Clone, Debug, PartialEq)]
         pub enum BaseAnyHolder {
@@ -322,9 +470,27 @@ mod snapshot_tests {
             fn name() -> &'static str {
                 "BASE_ANY"
             }
+            fn type_name() -> &'static str {
+                "base_any"
+            }
             fn attr_len() -> usize {
                 0
             }
+            fn accepts(keyword: &str) -> bool {
+                false
+                    || <BaseHolder as ::ruststep::tables::Holder>::accepts(keyword)
+                    || <SubAnyHolder as ::ruststep::tables::Holder>::accepts(keyword)
+            }
+            fn collect_refs(&self, refs: &mut Vec<::ruststep::tables::InstanceId>) {
+                match self {
+                    BaseAnyHolder::Base(sub) => {
+                        <BaseHolder as ::ruststep::tables::Holder>::collect_refs(sub, refs)
+                    }
+                    BaseAnyHolder::Sub(sub) => {
+                        <SubAnyHolder as ::ruststep::tables::Holder>::collect_refs(sub, refs)
+                    }
+                }
+            }
         }
         impl<'de> ::ruststep::serde::de::Deserialize<'de> for BaseAnyHolder {
             fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
@@ -357,6 +523,16 @@ mod snapshot_tests {
                         let owned = map.next_value()?;
                         return Ok(BaseAnyHolder::Sub(Box::new(owned)));
                     }
+                    keyword if <BaseHolder as ::ruststep::tables::Holder>::accepts(keyword) => {
+                        let owned: BaseHolder =
+                            map.next_value_seed(::ruststep::tables::KeywordSeed::new(key.clone()))?;
+                        return Ok(BaseAnyHolder::Base(Box::new(owned)));
+                    }
+                    keyword if <SubAnyHolder as ::ruststep::tables::Holder>::accepts(keyword) => {
+                        let owned: SubAnyHolder =
+                            map.next_value_seed(::ruststep::tables::KeywordSeed::new(key.clone()))?;
+                        return Ok(BaseAnyHolder::Sub(Box::new(owned)));
+                    }
                     _ => {
                         use ruststep::serde::de::{Error, Unexpected};
                         return Err(A::Error::invalid_value(Unexpected::Other(&key), &self));
@@ -371,17 +547,35 @@ mod snapshot_tests {
             }
         }
         impl ::ruststep::tables::EntityTable<BaseAnyHolder> for Tables {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<BaseAny> {
-                if let Ok(owned) = ::ruststep::tables::EntityTable::<BaseHolder>::get_owned(self, entity_id)
+            fn get_owned(
+                &self,
+                entity_id: ::ruststep::tables::InstanceId,
+            ) -> ::ruststep::error::Result<BaseAny> {
+                use ruststep::tables::{Holder, TypeIndex};
+                let keywords = self.entity_types(entity_id);
+                if keywords.is_empty() {
+                    return Err(::ruststep::error::Error::UnknownEntity(entity_id));
+                }
+                if keywords
+                    .iter()
+                    .any(|keyword| <BaseHolder as Holder>::accepts(keyword))
                 {
+                    let owned = ::ruststep::tables::EntityTable::<BaseHolder>::get_owned(self, entity_id)?;
                     return Ok(BaseAny::Base(Box::new(owned.into())));
                 }
-                if let Ok(owned) =
-                    ::ruststep::tables::EntityTable::<SubAnyHolder>::get_owned(self, entity_id)
+                if keywords
+                    .iter()
+                    .any(|keyword| <SubAnyHolder as Holder>::accepts(keyword))
                 {
+                    let owned =
+                        ::ruststep::tables::EntityTable::<SubAnyHolder>::get_owned(self, entity_id)?;
                     return Ok(BaseAny::Sub(Box::new(owned.into())));
                 }
-                Err(::ruststep::error::Error::UnknownEntity(entity_id))
+                Err(::ruststep::error::Error::UnexpectedEntityType {
+                    id: entity_id,
+                    keyword: keywords.join(" "),
+                    expected: <BaseAnyHolder as Holder>::name(),
+                })
             }
             fn owned_iter<'table>(
                 &'table self,
@@ -393,8 +587,24 @@ mod snapshot_tests {
                         .map(|owned| owned.map(|owned| BaseAny::Sub(Box::new(owned.into()))))
                 ])
             }
+            fn insert_checked(
+                &mut self,
+                entity_id: ::ruststep::tables::InstanceId,
+                holder: BaseAnyHolder,
+            ) -> ::ruststep::error::Result<()> {
+                match holder {
+                    BaseAnyHolder::Base(sub) => {
+                        ::ruststep::tables::EntityTable::<BaseHolder>::insert_checked(self, entity_id, *sub)
+                    }
+                    BaseAnyHolder::Sub(sub) => {
+                        ::ruststep::tables::EntityTable::<SubAnyHolder>::insert_checked(
+                            self, entity_id, *sub,
+                        )
+                    }
+                }
+            }
         }
-        "###);
+        "#);
     }
 
     #[test]
@@ -407,6 +617,7 @@ mod snapshot_tests {
             # [holder (table = Tables)]
             # [holder (field = sub1)]
             #[holder(generate_deserialize)]
+            #[holder(generate_from_owned)]
             pub struct Sub1 {
                 #[as_ref]
                 #[as_mut]
@@ -423,7 +634,7 @@ mod snapshot_tests {
         let tt = derive_holder(&input);
         let out = espr::codegen::rust::rustfmt(tt.to_string());
 
-        insta::assert_snapshot!(out, @r###"
+        insta::assert_snapshot!(out, @r#"
         #[doc = r" Auto-generated by `#[derive(Holder)]`"]
         #[derive(Debug, Clone, PartialEq)]
         pub struct Sub1Holder {
@@ -447,13 +658,22 @@ mod snapshot_tests {
             fn name() -> &'static str {
                 "SUB_1"
             }
+            fn type_name() -> &'static str {
+                "sub_1"
+            }
             fn attr_len() -> usize {
                 2usize
             }
+            fn collect_refs(&self, refs: &mut Vec<::ruststep::tables::InstanceId>) {
+                ::ruststep::tables::ContainsRef::collect_refs(&self.base, refs);
+            }
         }
         #[automatically_derived]
         impl ::ruststep::tables::EntityTable<Sub1Holder> for Tables {
-            fn get_owned(&self, entity_id: u64) -> ::ruststep::error::Result<Sub1> {
+            fn get_owned(
+                &self,
+                entity_id: ::ruststep::tables::InstanceId,
+            ) -> ::ruststep::error::Result<Sub1> {
                 ::ruststep::tables::get_owned(self, &self.sub1, entity_id)
             }
             fn owned_iter<'table>(
@@ -461,6 +681,13 @@ mod snapshot_tests {
             ) -> Box<dyn Iterator<Item = ::ruststep::error::Result<Sub1>> + 'table> {
                 ::ruststep::tables::owned_iter(self, &self.sub1)
             }
+            fn insert_checked(
+                &mut self,
+                entity_id: ::ruststep::tables::InstanceId,
+                holder: Sub1Holder,
+            ) -> ::ruststep::error::Result<()> {
+                ::ruststep::tables::insert_checked(self, |table| &mut table.sub1, entity_id, holder)
+            }
         }
         #[doc(hidden)]
         pub struct Sub1HolderVisitor;
@@ -491,12 +718,29 @@ mod snapshot_tests {
                 let key: String = map
                     .next_key()?
                     .expect("Empty map cannot be accepted as ruststep Holder");
-                if key != "SUB_1" {
-                    use ruststep::serde::de::{Error, Unexpected};
-                    return Err(A::Error::invalid_value(Unexpected::Other(&key), &self));
+                if key == "SUB_1" {
+                    let value = map.next_value()?;
+                    return Ok(value);
                 }
-                let value = map.next_value()?;
-                Ok(value)
+                use ruststep::serde::de::Error;
+                const FIELDS: &[&str] = &["base", "y1"];
+                let mut __base = None;
+                let mut __y1 = None;
+                let mut key = Some(key);
+                while let Some(k) = key {
+                    match k.as_str() {
+                        "base" => __base = Some(map.next_value()?),
+                        "y1" => __y1 = Some(map.next_value()?),
+                        _ => return Err(A::Error::unknown_field(&k, FIELDS)),
+                    }
+                    key = map.next_key()?;
+                }
+                let __base = __base.ok_or_else(|| A::Error::missing_field("base"))?;
+                let __y1 = __y1.ok_or_else(|| A::Error::missing_field("y1"))?;
+                Ok(Sub1Holder {
+                    base: __base,
+                    y1: __y1,
+                })
             }
         }
         #[automatically_derived]
@@ -515,6 +759,32 @@ mod snapshot_tests {
                 Sub1HolderVisitor {}
             }
         }
-        "###);
+        #[automatically_derived]
+        impl ::ruststep::tables::FromOwned for Sub1Holder {
+            fn from_owned(
+                owned: Self::Owned,
+                table: &mut Self::Table,
+                ids: &mut ::ruststep::tables::IdAllocator,
+            ) -> ::ruststep::error::Result<Self> {
+                let Sub1 { base, y1 } = owned;
+                Ok(Sub1Holder {
+                    base: <BaseHolder as ::ruststep::tables::FromOwned>::place_holder(base, table, ids)?,
+                    y1,
+                })
+            }
+            fn place_holder(
+                owned: Self::Owned,
+                table: &mut Self::Table,
+                ids: &mut ::ruststep::tables::IdAllocator,
+            ) -> ::ruststep::error::Result<::ruststep::tables::PlaceHolder<Self>> {
+                let holder = Self::from_owned(owned, table, ids)?;
+                let id = ids.allocate(table)?;
+                ::ruststep::tables::EntityTable::<Self>::insert_checked(table, id, holder)?;
+                Ok(::ruststep::tables::PlaceHolder::Ref(
+                    ::ruststep::ast::Name::Entity(id),
+                ))
+            }
+        }
+        "#);
     }
 }
@@ -6,6 +6,7 @@ use quote::quote;
 
 struct Input {
     name: String,
+    type_name: String,
     table: syn::Path,
     ident: syn::Ident,
     holder_ident: syn::Ident,
@@ -16,6 +17,10 @@ struct Input {
     variant_into_exprs: Vec<TokenStream2>,
     holder_types: Vec<syn::Type>,
     holder_exprs: Vec<TokenStream2>,
+    /// Holders by `ruststep::tables::FromOwned::from_owned` from `owned` of each variant
+    from_owned_exprs: Vec<TokenStream2>,
+    /// Place holders by `ruststep::tables::FromOwned::place_holder` from `owned` of each variant
+    place_holder_exprs: Vec<TokenStream2>,
     place_holders: Vec<bool>,
 }
 
@@ -38,8 +43,12 @@ impl Input {
         let mut holder_types = Vec::new();
         let mut variant_exprs = Vec::new();
         let mut variant_into_exprs = Vec::new();
+        let mut from_owned_exprs = Vec::new();
+        let mut place_holder_exprs = Vec::new();
         let mut place_holders = Vec::new();
+        let ruststep = ruststep_crate();
         for var in &e.variants {
+            let var_ident = &var.ident;
             let HolderAttr { place_holder, .. } = HolderAttr::parse(&var.attrs);
             place_holders.push(place_holder);
 
@@ -51,6 +60,19 @@ impl Input {
                         // ENTITY case
                         holder_types.push(as_holder_path(&f.ty));
                         holder_exprs.push(quote! { Box::new(sub.into_owned(table)?) });
+                        let holder: syn::Type = match ty.clone().into_holder() {
+                            FieldType::Boxed(holder) => (*holder).into(),
+                            _ => unreachable!(),
+                        };
+                        from_owned_exprs.push(quote! {
+                            #holder_ident::#var_ident(Box::new(
+                                <#holder as #ruststep::tables::FromOwned>::from_owned(*owned, table, ids)?,
+                            ))
+                        });
+                        place_holder_exprs.push(quote! {
+                            <#holder as #ruststep::tables::FromOwned>::place_holder(*owned, table, ids)?
+                                .map(|sub| #holder_ident::#var_ident(Box::new(sub)))
+                        });
                         variant_exprs.push(quote! { Box::new(owned) });
                         variant_into_exprs.push(quote! { Box::new(owned.into()) });
                     } else {
@@ -63,10 +85,24 @@ impl Input {
                         // *Any case
                         holder_types.push(as_holder_path(&f.ty));
                         holder_exprs.push(quote! { sub.into_owned(table)? });
+                        let holder: syn::Type = ty.clone().into_holder().into();
+                        from_owned_exprs.push(quote! {
+                            #holder_ident::#var_ident(
+                                <#holder as #ruststep::tables::FromOwned>::from_owned(owned, table, ids)?,
+                            )
+                        });
+                        place_holder_exprs.push(quote! {
+                            <#holder as #ruststep::tables::FromOwned>::place_holder(owned, table, ids)?
+                                .map(#holder_ident::#var_ident)
+                        });
                     } else {
                         // SimpleType case
                         holder_types.push(f.ty.clone());
                         holder_exprs.push(quote! { sub });
+                        from_owned_exprs.push(quote! { #holder_ident::#var_ident(owned) });
+                        place_holder_exprs.push(quote! {
+                            #ruststep::tables::PlaceHolder::Owned(#holder_ident::#var_ident(owned))
+                        });
                     }
                 }
             }
@@ -74,6 +110,7 @@ impl Input {
 
         Input {
             name,
+            type_name: attr.type_name(ident),
             table,
             ident: ident.clone(),
             holder_ident,
@@ -84,6 +121,8 @@ impl Input {
             variant_into_exprs,
             holder_types,
             holder_exprs,
+            from_owned_exprs,
+            place_holder_exprs,
             place_holders,
         }
     }
@@ -107,14 +146,49 @@ impl Input {
     fn impl_holder(&self) -> TokenStream2 {
         let Input {
             name,
+            type_name,
             ident,
             holder_ident,
             variants,
             table,
             holder_exprs,
+            from_owned_exprs,
             ..
         } = self;
         let ruststep = ruststep_crate();
+        let (vars, accepted, _exprs) = self.place_holder_variants();
+        // Members are converted without table if all of them are simple types
+        let impl_from = if vars.is_empty() {
+            quote! {
+                impl ::std::convert::From<#ident> for #holder_ident {
+                    fn from(owned: #ident) -> Self {
+                        match owned {
+                            #(#ident::#variants(owned) => #from_owned_exprs),*
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        let impl_collect_refs = if vars.is_empty() {
+            quote! {}
+        } else {
+            // Simple type variants have no reference
+            let others = if vars.len() < variants.len() {
+                quote! { _ => {} }
+            } else {
+                quote! {}
+            };
+            quote! {
+                fn collect_refs(&self, refs: &mut Vec<#ruststep::tables::InstanceId>) {
+                    match self {
+                        #(#holder_ident::#vars(sub) => <#accepted as #ruststep::tables::Holder>::collect_refs(sub, refs),)*
+                        #others
+                    }
+                }
+            }
+        };
 
         quote! {
             impl #ruststep::tables::IntoOwned for #holder_ident {
@@ -130,9 +204,106 @@ impl Input {
                 fn name() -> &'static str {
                     #name
                 }
+                fn type_name() -> &'static str {
+                    #type_name
+                }
                 fn attr_len() -> usize {
                     0
                 }
+                fn accepts(keyword: &str) -> bool {
+                    false #(|| <#accepted as #ruststep::tables::Holder>::accepts(keyword))*
+                }
+                #impl_collect_refs
+            }
+            #impl_from
+        } // quote!
+    }
+
+    /// Members of entities are inserted into the table, see `FromOwned::place_holder`
+    fn impl_from_owned(&self) -> TokenStream2 {
+        let Input {
+            ident,
+            holder_ident,
+            variants,
+            from_owned_exprs,
+            place_holder_exprs,
+            ..
+        } = self;
+        let ruststep = ruststep_crate();
+        if self.place_holders.iter().all(|place_holder| !place_holder) {
+            return quote! {
+                impl #ruststep::tables::FromOwned for #holder_ident {
+                    fn from_owned(
+                        owned: Self::Owned,
+                        _table: &mut Self::Table,
+                        _ids: &mut #ruststep::tables::IdAllocator,
+                    ) -> #ruststep::error::Result<Self> {
+                        Ok(owned.into())
+                    }
+                }
+            };
+        }
+        quote! {
+            impl #ruststep::tables::FromOwned for #holder_ident {
+                fn from_owned(
+                    owned: Self::Owned,
+                    table: &mut Self::Table,
+                    ids: &mut #ruststep::tables::IdAllocator,
+                ) -> #ruststep::error::Result<Self> {
+                    Ok(match owned {
+                        #(#ident::#variants(owned) => #from_owned_exprs),*
+                    })
+                }
+                fn place_holder(
+                    owned: Self::Owned,
+                    table: &mut Self::Table,
+                    ids: &mut #ruststep::tables::IdAllocator,
+                ) -> #ruststep::error::Result<#ruststep::tables::PlaceHolder<Self>> {
+                    Ok(match owned {
+                        #(#ident::#variants(owned) => #place_holder_exprs),*
+                    })
+                }
+            }
+        } // quote!
+    }
+
+    /// Parameters of the member held by each variant
+    fn impl_into_parameter(&self) -> TokenStream2 {
+        let Input {
+            holder_ident,
+            variants,
+            ..
+        } = self;
+        let ruststep = ruststep_crate();
+        quote! {
+            impl #ruststep::tables::IntoParameter for #holder_ident {
+                fn into_parameter(self) -> #ruststep::ast::Parameter {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #ruststep::tables::IntoParameter::into_parameter(sub)),*
+                    }
+                }
+                fn into_parameter_list(self) -> Vec<#ruststep::ast::Parameter> {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #ruststep::tables::IntoParameter::into_parameter_list(sub)),*
+                    }
+                }
+                fn write_parameter(
+                    self,
+                    writer: &mut #ruststep::tables::InstanceWriter,
+                ) -> #ruststep::error::Result<#ruststep::ast::Parameter> {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #ruststep::tables::IntoParameter::write_parameter(sub, writer)),*
+                    }
+                }
+                fn write_records(
+                    self,
+                    name: &str,
+                    writer: &mut #ruststep::tables::InstanceWriter,
+                ) -> #ruststep::error::Result<Vec<#ruststep::ast::Record>> {
+                    match self {
+                        #(#holder_ident::#variants(sub) => #ruststep::tables::IntoParameter::write_records(sub, name, writer)),*
+                    }
+                }
             }
         } // quote!
     }
@@ -169,6 +340,7 @@ impl Input {
         } = self;
         let ruststep = ruststep_crate();
         let serde = serde_crate();
+        let (members, member_holders, member_exprs) = self.place_holder_members();
 
         quote! {
             #[doc(hidden)]
@@ -195,6 +367,13 @@ impl Input {
                             return Ok(#holder_ident::#variants(#variant_exprs));
                         }
                         )*
+                        // Keyword of a member of nested SELECT, e.g. `DISTANCE(1.0)` for `SELECT (measure, ...)`
+                        #(
+                        keyword if <#member_holders as #ruststep::tables::Holder>::accepts(keyword) => {
+                            let owned: #member_holders = map.next_value_seed(#ruststep::tables::KeywordSeed::new(key.clone()))?;
+                            return Ok(#holder_ident::#members(#member_exprs));
+                        }
+                        )*
                         _ => {
                             use #serde::de::{Error, Unexpected};
                             return Err(A::Error::invalid_value(Unexpected::Other(&key), &self));
@@ -212,27 +391,71 @@ impl Input {
         } // quote!
     }
 
-    fn impl_entity_table(&self) -> TokenStream2 {
+    // Same mapping as `def_visitor`, without serde
+    fn impl_from_parameter(&self) -> TokenStream2 {
         let Input {
-            ident,
             holder_ident,
             variants,
-            holder_types,
-            table,
-            variant_into_exprs,
-            place_holders,
+            variant_names,
+            variant_exprs,
             ..
         } = self;
         let ruststep = ruststep_crate();
-        let itertools = itertools_crate();
+        let (members, member_holders, member_exprs) = self.place_holder_members();
+
+        quote! {
+            impl #ruststep::tables::FromParameter for #holder_ident {
+                fn from_parameter(parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                    match parameter {
+                        #ruststep::ast::Parameter::Typed { keyword, parameter } => Self::from_typed(keyword, parameter),
+                        _ => Err(#ruststep::tables::unexpected_parameter::<Self>(parameter)),
+                    }
+                }
+
+                fn from_typed(keyword: &str, parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                    match keyword {
+                        #(
+                        #variant_names => {
+                            let owned = #ruststep::tables::FromParameter::from_parameter(parameter)?;
+                            Ok(#holder_ident::#variants(#variant_exprs))
+                        }
+                        )*
+                        #(
+                        keyword if <#member_holders as #ruststep::tables::Holder>::accepts(keyword) => {
+                            let owned = <#member_holders as #ruststep::tables::FromParameter>::from_typed(keyword, parameter)?;
+                            Ok(#holder_ident::#members(#member_exprs))
+                        }
+                        )*
+                        _ => Err(#ruststep::tables::unexpected_keyword::<Self>(keyword)),
+                    }
+                }
+            }
+        } // quote!
+    }
+
+    /// Variants using place holder, their holder types without `Box`, and expressions converting owned value
+    fn place_holder_variants(&self) -> (Vec<&syn::Ident>, Vec<syn::Type>, Vec<&TokenStream2>) {
+        self.place_holder_variants_with(&self.variant_into_exprs)
+    }
+
+    /// Same as [Input::place_holder_variants], but with expressions of variants from sub holders
+    fn place_holder_members(&self) -> (Vec<&syn::Ident>, Vec<syn::Type>, Vec<&TokenStream2>) {
+        self.place_holder_variants_with(&self.variant_exprs)
+    }
+
+    fn place_holder_variants_with<'a>(
+        &'a self,
+        variant_exprs: &'a [TokenStream2],
+    ) -> (Vec<&'a syn::Ident>, Vec<syn::Type>, Vec<&'a TokenStream2>) {
         let mut vars = Vec::new();
         let mut holders = Vec::<syn::Type>::new();
         let mut exprs = Vec::new();
-        for (((var, holder), expr), place_holder) in variants
+        for (((var, holder), expr), place_holder) in self
+            .variants
             .iter()
-            .zip(holder_types)
-            .zip(variant_into_exprs)
-            .zip(place_holders)
+            .zip(&self.holder_types)
+            .zip(variant_exprs)
+            .zip(&self.place_holders)
         {
             if *place_holder {
                 vars.push(var);
@@ -244,16 +467,67 @@ impl Input {
                 }
             }
         }
+        (vars, holders, exprs)
+    }
+
+    fn impl_entity_table(&self) -> TokenStream2 {
+        let Input {
+            ident,
+            holder_ident,
+            table,
+            ..
+        } = self;
+        let ruststep = ruststep_crate();
+        let itertools = itertools_crate();
+        let (vars, holders, exprs) = self.place_holder_variants();
+        let mut inserts = Vec::new();
+        for (((var, name), holder), place_holder) in self
+            .variants
+            .iter()
+            .zip(&self.variant_names)
+            .zip(&self.holder_types)
+            .zip(&self.place_holders)
+        {
+            inserts.push(if *place_holder {
+                let (holder, sub) = match holder.clone().try_into().unwrap() {
+                    FieldType::Boxed(path) => (path.as_ref().clone().into(), quote! { *sub }),
+                    _ => (holder.clone(), quote! { sub }),
+                };
+                quote! {
+                    #holder_ident::#var(sub) => #ruststep::tables::EntityTable::<#holder>::insert_checked(self, entity_id, #sub)
+                }
+            } else {
+                // Simple type variants are values, not entity instances
+                quote! {
+                    #holder_ident::#var(_) => Err(#ruststep::error::Error::UnexpectedEntityType {
+                        id: entity_id,
+                        keyword: #name.to_string(),
+                        expected: <#holder_ident as #ruststep::tables::Holder>::name(),
+                    })
+                }
+            });
+        }
 
         quote! {
             impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-                fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
+                fn get_owned(&self, entity_id: #ruststep::tables::InstanceId) -> #ruststep::error::Result<#ident> {
+                    use #ruststep::tables::{Holder, TypeIndex};
+                    // Complex entity instances of sibling subtypes have several keywords
+                    let keywords = self.entity_types(entity_id);
+                    if keywords.is_empty() {
+                        return Err(#ruststep::error::Error::UnknownEntity(entity_id));
+                    }
                     #(
-                    if let Ok(owned) = #ruststep::tables::EntityTable::<#holders>::get_owned(self, entity_id) {
+                    if keywords.iter().any(|keyword| <#holders as Holder>::accepts(keyword)) {
+                        let owned = #ruststep::tables::EntityTable::<#holders>::get_owned(self, entity_id)?;
                         return Ok(#ident::#vars(#exprs));
                     }
                     )*
-                    Err(#ruststep::error::Error::UnknownEntity(entity_id))
+                    Err(#ruststep::error::Error::UnexpectedEntityType {
+                        id: entity_id,
+                        keyword: keywords.join(" "),
+                        expected: <#holder_ident as Holder>::name(),
+                    })
                 }
                 fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
                     Box::new(#itertools::chain![
@@ -263,6 +537,11 @@ impl Input {
                         ),*
                     ])
                 }
+                fn insert_checked(&mut self, entity_id: #ruststep::tables::InstanceId, holder: #holder_ident) -> #ruststep::error::Result<()> {
+                    match holder {
+                        #(#inserts),*
+                    }
+                }
             }
         } // quote!
     }
@@ -272,6 +551,22 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
     let input = Input::parse(ident, e, attr);
     let def_holder_tt = input.def_holder();
     let impl_holder_tt = input.impl_holder();
+    let impl_into_parameter_tt = if attr.generate_into_parameter {
+        input.impl_into_parameter()
+    } else {
+        quote! {}
+    };
+
+    let impl_from_parameter_tt = if attr.generate_from_parameter {
+        input.impl_from_parameter()
+    } else {
+        quote! {}
+    };
+    let impl_from_owned_tt = if attr.generate_from_owned {
+        input.impl_from_owned()
+    } else {
+        quote! {}
+    };
 
     if attr.generate_deserialize {
         let impl_deserialize_tt = input.impl_deserialize();
@@ -280,14 +575,29 @@ pub fn derive_holder(ident: &syn::Ident, e: &syn::DataEnum, attr: &HolderAttr) -
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_into_parameter_tt
+            #impl_from_owned_tt
             #impl_deserialize_tt
             #def_visitor_tt
             #impl_entity_table_tt
+            #impl_from_parameter_tt
+        } // quote!
+    } else if attr.generate_from_parameter {
+        let impl_entity_table_tt = input.impl_entity_table();
+        quote! {
+            #def_holder_tt
+            #impl_holder_tt
+            #impl_into_parameter_tt
+            #impl_from_owned_tt
+            #impl_entity_table_tt
+            #impl_from_parameter_tt
         } // quote!
     } else {
         quote! {
             #def_holder_tt
             #impl_holder_tt
+            #impl_into_parameter_tt
+            #impl_from_owned_tt
         } // quote!
     }
 }
@@ -1,6 +1,6 @@
 use inflector::Inflector;
 use proc_macro2::TokenStream as TokenStream2;
-use proc_macro_error::{abort_call_site, OptionExt};
+use proc_macro_error::{abort, abort_call_site, OptionExt};
 use quote::quote;
 
 use crate::common::ruststep_crate;
@@ -9,7 +9,7 @@ pub fn derive_table_init(ast: &syn::DeriveInput) -> TokenStream2 {
     let ident = &ast.ident;
     match &ast.data {
         syn::Data::Struct(st) => match st.fields {
-            syn::Fields::Named(_) => entity_impl_table_init(ident, st),
+            syn::Fields::Named(_) => entity_impl_table_init(ident, &ast.attrs, st),
             syn::Fields::Unnamed(_) => tuple_impl_table_init(ident, st),
             syn::Fields::Unit => panic!("Unit struct is not supported."),
         },
@@ -17,18 +17,107 @@ pub fn derive_table_init(ast: &syn::DeriveInput) -> TokenStream2 {
     }
 }
 
-fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+fn entity_impl_table_init(
+    ident: &syn::Ident,
+    attrs: &[syn::Attribute],
+    st: &syn::DataStruct,
+) -> TokenStream2 {
     let mut table_names = Vec::new();
     let mut entity_names = Vec::new();
+    let mut holders = Vec::new();
+    let mut validated = Vec::new();
+    let mut shared_cache = None;
     for field in &st.fields {
         let ident = field.ident.as_ref().expect_or_abort("unreachable!");
+        let TableAttr {
+            validate,
+            shared_cache: is_cache,
+            ..
+        } = TableAttr::parse(&field.attrs);
+        if is_cache {
+            shared_cache = Some(ident);
+            continue;
+        }
         let name = ident.to_string().to_screaming_snake_case();
         table_names.push(ident);
         entity_names.push(name);
+        holders.push(holder_type(&field.ty));
+        if validate {
+            validated.push(ident);
+        }
     }
     assert_eq!(table_names.len(), entity_names.len());
 
     let ruststep = ruststep_crate();
+    let tables_attr = TableAttr::parse(attrs);
+    let insert_record = if tables_attr.from_parameter {
+        quote! { #ruststep::tables::insert_record_from_parameter }
+    } else {
+        quote! { #ruststep::tables::insert_record }
+    };
+    let validate_rules = if validated.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn validate_rules(&self) -> #ruststep::error::Result<()> {
+                use #ruststep::{error::Error, tables::{IntoOwned, Validate}};
+                #(
+                let mut ids: Vec<_> = self.#validated.keys().collect();
+                ids.sort();
+                for id in ids {
+                    let owned = self.#validated[id].clone().into_owned(self)?;
+                    owned
+                        .validate()
+                        .map_err(|rule| Error::RuleViolation { id: *id, rule })?;
+                }
+                )*
+                Ok(())
+            }
+        }
+    };
+
+    let impl_write_tables = if tables_attr.into_parameter {
+        quote! {
+            #[automatically_derived]
+            impl #ruststep::tables::WriteTables for #ident {
+                fn to_data_section(&self) -> #ruststep::error::Result<#ruststep::ast::DataSection> {
+                    use #ruststep::{error::Error, tables::InstanceId};
+                    // Entities in inline owned values get ids after the existing instances
+                    let last_ids: &[Option<InstanceId>] = &[#(self.#table_names.keys().max().copied()),*];
+                    let last = last_ids.iter().flatten().max().copied().unwrap_or_default();
+                    let first = last.0.checked_add(1).ok_or(Error::InstanceIdExhausted(last))?;
+                    let mut writer = #ruststep::tables::InstanceWriter::new(InstanceId(first));
+                    let mut entities = Vec::new();
+                    #(
+                    #ruststep::tables::push_records(&self.#table_names, &mut entities, &mut writer)?;
+                    )*
+                    entities.extend(writer.into_instances());
+                    Ok(#ruststep::ast::DataSection {
+                        meta: Vec::new(),
+                        entities: #ruststep::tables::merge_instances(entities),
+                    })
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let (clear_cache, impl_shared_table) = match shared_cache {
+        Some(cache) => (
+            // Cached values may be invalidated by appended instances
+            quote! { self.#cache.clear(); },
+            quote! {
+                #[automatically_derived]
+                impl #ruststep::tables::SharedTable for #ident {
+                    fn shared_cache(&self) -> &#ruststep::tables::SharedCache {
+                        &self.#cache
+                    }
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    };
 
     quote! {
         #[automatically_derived]
@@ -37,12 +126,13 @@ fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStre
                 &mut self,
                 data_sec: &#ruststep::ast::DataSection
             ) -> #ruststep::error::Result<()> {
-                use #ruststep::{error::Error, tables::insert_record, ast::EntityInstance};
+                use #ruststep::{error::Error, ast::EntityInstance};
+                #clear_cache
                 for entity in &data_sec.entities {
                     match entity {
                         EntityInstance::Simple { id, record } => match record.name.as_str() {
                             #(
-                            #entity_names => insert_record(&mut self.#table_names, *id, record)?,
+                            #entity_names => #insert_record(&mut self.#table_names, (*id).into(), record)?,
                             )*
                             _ => {
                                 return Err(Error::UnknownEntityName {
@@ -51,13 +141,26 @@ fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStre
                                 });
                             }
                         },
-                        EntityInstance::Complex { .. } => {
-                            unimplemented!("Complex entity is not supported")
+                        EntityInstance::Complex { id, subsuper } => {
+                            let mut selected = #ruststep::tables::select_complex_tables(
+                                (*id).into(),
+                                subsuper,
+                                &[#(<#holders as #ruststep::tables::Holder>::complex_keywords),*],
+                            )?
+                            .into_iter();
+                            #(
+                            if selected.next() == Some(true) {
+                                let record = #ruststep::tables::complex_to_record::<#holders>(subsuper)?;
+                                #insert_record(&mut self.#table_names, (*id).into(), &record)?;
+                            }
+                            )*
                         }
                     }
                 }
                 Ok(())
             }
+
+            #validate_rules
         }
 
         #[automatically_derived]
@@ -66,9 +169,65 @@ fn entity_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStre
             fn from_str(input: &str) -> #ruststep::error::Result<Self> {
                 use #ruststep::{tables::TableInit, ast::DataSection};
                 let data_sec = DataSection::from_str(input)?;
-                Ok(Self::from_data_section(&data_sec)?)
+                Self::from_data_section(&data_sec).map_err(|e| e.locate(input))
+            }
+        }
+
+        #[automatically_derived]
+        impl #ruststep::tables::TypeIndex for #ident {
+            fn entity_type(&self, id: #ruststep::tables::InstanceId) -> Option<&str> {
+                #(
+                if self.#table_names.contains_key(&id) {
+                    return Some(#entity_names);
+                }
+                )*
+                None
+            }
+
+            fn entity_types(&self, id: #ruststep::tables::InstanceId) -> Vec<&str> {
+                let mut keywords = Vec::new();
+                #(
+                if self.#table_names.contains_key(&id) {
+                    keywords.push(#entity_names);
+                }
+                )*
+                keywords
+            }
+
+            fn statistics(&self) -> ::std::collections::BTreeMap<String, usize> {
+                let mut statistics = ::std::collections::BTreeMap::new();
+                #(
+                if !self.#table_names.is_empty() {
+                    statistics.insert(#entity_names.to_string(), self.#table_names.len());
+                }
+                )*
+                statistics
+            }
+
+            fn validate_references(
+                &self,
+            ) -> Vec<(#ruststep::tables::InstanceId, #ruststep::tables::InstanceId)> {
+                use #ruststep::tables::Holder;
+                let mut dangling = Vec::new();
+                #(
+                for (id, holder) in &self.#table_names {
+                    let mut refs = Vec::new();
+                    holder.collect_refs(&mut refs);
+                    for target in refs {
+                        if self.entity_type(target).is_none() {
+                            dangling.push((*id, target));
+                        }
+                    }
+                }
+                )*
+                dangling.sort();
+                dangling.dedup();
+                dangling
             }
         }
+
+        #impl_shared_table
+        #impl_write_tables
     }
 }
 
@@ -97,7 +256,7 @@ fn tuple_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStrea
                     match entity {
                         EntityInstance::Simple { id, record } => match record.name.as_str() {
                             #(
-                            #entity_names => insert_record(&mut self.#table_names, *id, record)?,
+                            #entity_names => insert_record(&mut self.#table_names, (*id).into(), record)?,
                             )*
                             _ => {
                                 return Err(Error::UnknownEntityName {
@@ -121,8 +280,57 @@ fn tuple_impl_table_init(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStrea
             fn from_str(input: &str) -> #ruststep::error::Result<Self> {
                 use #ruststep::{tables::TableInit, ast::DataSection};
                 let data_sec = DataSection::from_str(input)?;
-                Ok(Self::from_data_section(&data_sec)?)
+                Self::from_data_section(&data_sec).map_err(|e| e.locate(input))
+            }
+        }
+    }
+}
+
+/// Holder type `T` of a table `HashMap<InstanceId, T>`
+fn holder_type(ty: &syn::Type) -> &syn::Type {
+    if let syn::Type::Path(path) = ty {
+        if let Some(syn::PathArguments::AngleBracketed(args)) =
+            path.path.segments.last().map(|segment| &segment.arguments)
+        {
+            if let Some(syn::GenericArgument::Type(holder)) = args.args.iter().last() {
+                return holder;
+            }
+        }
+    }
+    abort!(ty, "Table must be `HashMap<InstanceId, Holder>`")
+}
+
+/// Arguments of `#[table(...)]` on tables or their fields
+#[derive(Debug, Default)]
+struct TableAttr {
+    /// `#[table(validate)]`, check domain rules of the entities in this table
+    validate: bool,
+    /// `#[table(shared_cache)]`, this field is a `SharedCache` instead of a table
+    shared_cache: bool,
+    /// `#[table(from_parameter)]` on tables, insert records by `FromParameter` instead of serde
+    from_parameter: bool,
+    /// `#[table(into_parameter)]` on tables, write tables back by `IntoParameter` of holders
+    into_parameter: bool,
+}
+
+impl TableAttr {
+    fn parse(attrs: &[syn::Attribute]) -> Self {
+        let mut attr = TableAttr::default();
+        for a in attrs {
+            if !a.path().is_ident("table") {
+                continue;
+            }
+            match a.parse_args::<syn::Ident>() {
+                Ok(ident) if ident == "validate" => attr.validate = true,
+                Ok(ident) if ident == "shared_cache" => attr.shared_cache = true,
+                Ok(ident) if ident == "from_parameter" => attr.from_parameter = true,
+                Ok(ident) if ident == "into_parameter" => attr.into_parameter = true,
+                _ => abort!(
+                    a,
+                    "Only `#[table(validate)]`, `#[table(shared_cache)]`, `#[table(from_parameter)]`, and `#[table(into_parameter)]` are supported"
+                ),
             }
         }
+        attr
     }
 }
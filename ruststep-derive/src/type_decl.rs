@@ -11,6 +11,21 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
     let def_holder_tt = def_holder(ident, st);
     let impl_holder_tt = impl_holder(ident, attr, st);
     let impl_entity_table_tt = impl_entity_table(ident, attr);
+    let impl_from_parameter_tt = if attr.generate_from_parameter {
+        impl_from_parameter(&holder_ident, &name, st)
+    } else {
+        quote! {}
+    };
+    let impl_into_parameter_tt = if attr.generate_into_parameter {
+        impl_into_parameter(ident, st)
+    } else {
+        quote! {}
+    };
+    let impl_from_owned_tt = if attr.generate_from_owned {
+        impl_from_owned(ident, st)
+    } else {
+        quote! {}
+    };
     if attr.generate_deserialize {
         let def_visitor_tt = def_visitor(&holder_ident, &name, st);
         let impl_deserialize_tt = impl_deserialize(&holder_ident, &name, st);
@@ -22,16 +37,82 @@ pub fn derive_holder(ident: &syn::Ident, st: &syn::DataStruct, attr: &HolderAttr
             #def_visitor_tt
             #impl_deserialize_tt
             #impl_with_visitor_tt
+            #impl_from_parameter_tt
+            #impl_into_parameter_tt
+            #impl_from_owned_tt
         }
     } else {
         quote! {
             #def_holder_tt
             #impl_holder_tt
             #impl_entity_table_tt
+            #impl_from_parameter_tt
+            #impl_into_parameter_tt
+            #impl_from_owned_tt
         }
     }
 }
 
+// Typed parameter of a single value is not enclosed by a list, e.g. `LABEL('a')`
+fn impl_into_parameter(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+    let name = ident.to_string().to_screaming_snake_case();
+    let holder_ident = as_holder_ident(ident);
+    let FieldEntries { holder_types, .. } = FieldEntries::parse(st);
+    let index: Vec<_> = (0..holder_types.len()).map(syn::Index::from).collect();
+    let ruststep = ruststep_crate();
+    let parameter = if index.len() == 1 {
+        quote! { #ruststep::tables::IntoParameter::into_parameter(self.0) }
+    } else {
+        quote! {
+            #ruststep::ast::Parameter::List(#ruststep::tables::IntoParameter::into_parameter_list(self))
+        }
+    };
+    let write_parameter = if index.len() == 1 {
+        quote! { #ruststep::tables::IntoParameter::write_parameter(self.0, writer)? }
+    } else {
+        quote! {
+            #ruststep::ast::Parameter::List(vec![
+                #(#ruststep::tables::IntoParameter::write_parameter(self.#index, writer)?),*
+            ])
+        }
+    };
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::IntoParameter for #holder_ident {
+            fn into_parameter(self) -> #ruststep::ast::Parameter {
+                #ruststep::ast::Parameter::Typed {
+                    keyword: #name.to_string(),
+                    parameter: Box::new(#parameter),
+                }
+            }
+            fn into_parameter_list(self) -> Vec<#ruststep::ast::Parameter> {
+                vec![#(#ruststep::tables::IntoParameter::into_parameter(self.#index)),*]
+            }
+            fn write_parameter(
+                self,
+                writer: &mut #ruststep::tables::InstanceWriter,
+            ) -> #ruststep::error::Result<#ruststep::ast::Parameter> {
+                Ok(#ruststep::ast::Parameter::Typed {
+                    keyword: #name.to_string(),
+                    parameter: Box::new(#write_parameter),
+                })
+            }
+            fn write_records(
+                self,
+                _name: &str,
+                writer: &mut #ruststep::tables::InstanceWriter,
+            ) -> #ruststep::error::Result<Vec<#ruststep::ast::Record>> {
+                Ok(vec![#ruststep::ast::Record {
+                    name: #name.to_string(),
+                    parameter: #ruststep::ast::Parameter::List(vec![
+                        #(#ruststep::tables::IntoParameter::write_parameter(self.#index, writer)?),*
+                    ]),
+                }])
+            }
+        }
+    } // quote!
+}
+
 pub fn def_holder(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
     let holder_ident = as_holder_ident(ident);
     let FieldEntries { holder_types, .. } = FieldEntries::parse(st);
@@ -44,16 +125,50 @@ pub fn def_holder(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
 
 pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct) -> TokenStream2 {
     let name = ident.to_string().to_screaming_snake_case();
+    let type_name = table.type_name(ident);
     let holder_ident = as_holder_ident(ident);
     let FieldEntries {
         holder_types,
         into_owned,
+        collect_refs,
+        use_place_holder,
+        ..
     } = FieldEntries::parse(st);
     let HolderAttr { table, .. } = table;
     let tuple_len = holder_types.len();
     let table_arg = table_arg();
     let ruststep = ruststep_crate();
 
+    // Holder and owned are converted into each other without table if no field refers others
+    let impl_from_holder = if use_place_holder {
+        quote! {}
+    } else {
+        let index: Vec<_> = (0..tuple_len).map(syn::Index::from).collect();
+        quote! {
+            #[automatically_derived]
+            impl ::std::convert::From<#holder_ident> for #ident {
+                fn from(holder: #holder_ident) -> Self {
+                    #ident ( #(holder.#index),* )
+                }
+            }
+            #[automatically_derived]
+            impl ::std::convert::From<#ident> for #holder_ident {
+                fn from(owned: #ident) -> Self {
+                    #holder_ident ( #(owned.#index),* )
+                }
+            }
+        }
+    };
+    let impl_collect_refs = if use_place_holder {
+        quote! {
+            fn collect_refs(&self, refs: &mut Vec<#ruststep::tables::InstanceId>) {
+                #(#collect_refs)*
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::IntoOwned for #holder_ident {
@@ -68,9 +183,42 @@ pub fn impl_holder(ident: &syn::Ident, table: &HolderAttr, st: &syn::DataStruct)
             fn name() -> &'static str {
                 #name
             }
+            fn type_name() -> &'static str {
+                #type_name
+            }
             fn attr_len() -> usize {
                 #tuple_len
             }
+            #impl_collect_refs
+        }
+        #impl_from_holder
+    } // quote!
+}
+
+// Entities in fields are inserted into the table, see `place_holder_from_owned`
+fn impl_from_owned(ident: &syn::Ident, st: &syn::DataStruct) -> TokenStream2 {
+    let holder_ident = as_holder_ident(ident);
+    let FieldEntries {
+        from_owned,
+        use_place_holder,
+        ..
+    } = FieldEntries::parse(st);
+    let ruststep = ruststep_crate();
+    let (table_param, ids_param) = if use_place_holder {
+        (quote! { table }, quote! { ids })
+    } else {
+        (quote! { _table }, quote! { _ids })
+    };
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::FromOwned for #holder_ident {
+            fn from_owned(
+                owned: Self::Owned,
+                #table_param: &mut Self::Table,
+                #ids_param: &mut #ruststep::tables::IdAllocator,
+            ) -> #ruststep::error::Result<Self> {
+                Ok(#holder_ident ( #(#from_owned),* ))
+            }
         }
     } // quote!
 }
@@ -83,12 +231,15 @@ pub fn impl_entity_table(ident: &syn::Ident, table: &HolderAttr) -> TokenStream2
     quote! {
         #[automatically_derived]
         impl #ruststep::tables::EntityTable<#holder_ident> for #table {
-            fn get_owned(&self, entity_id: u64) -> #ruststep::error::Result<#ident> {
+            fn get_owned(&self, entity_id: #ruststep::tables::InstanceId) -> #ruststep::error::Result<#ident> {
                 #ruststep::tables::get_owned(self, &self.#field, entity_id)
             }
             fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = #ruststep::error::Result<#ident>> + 'table> {
                 #ruststep::tables::owned_iter(self, &self.#field)
             }
+            fn insert_checked(&mut self, entity_id: #ruststep::tables::InstanceId, holder: #holder_ident) -> #ruststep::error::Result<()> {
+                #ruststep::tables::insert_checked(self, |table| &mut table.#field, entity_id, holder)
+            }
         }
     }
 }
@@ -103,6 +254,30 @@ fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStr
         .map(|i| format_ident!("a_{}", i))
         .collect::<Vec<_>>();
     let serde = serde_crate();
+    // Parameter of a defined type is not always typed, e.g. `'a'` for `TYPE label = STRING;`
+    let visit_values = if attr_len == 1 {
+        let visits = [
+            (quote! { visit_i64 }, quote! { i64 }),
+            (quote! { visit_f64 }, quote! { f64 }),
+            (quote! { visit_bool }, quote! { bool }),
+            (quote! { visit_str }, quote! { &str }),
+        ]
+        .into_iter()
+        .map(|(visit, ty)| {
+            quote! {
+                fn #visit<E>(self, v: #ty) -> ::std::result::Result<Self::Value, E>
+                where
+                    E: #serde::de::Error,
+                {
+                    use #serde::de::IntoDeserializer;
+                    Ok(#ident(#serde::Deserialize::deserialize(v.into_deserializer())?))
+                }
+            }
+        });
+        quote! { #(#visits)* }
+    } else {
+        TokenStream2::new()
+    };
     quote! {
         #[doc(hidden)]
         pub struct #visitor_ident;
@@ -143,6 +318,8 @@ fn def_visitor(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStr
                 let value = map.next_value()?; // send to Self::visit_seq
                 Ok(value)
             }
+
+            #visit_values
         }
     } // quote!
 }
@@ -184,17 +361,52 @@ fn impl_with_visitor(ident: &syn::Ident) -> TokenStream2 {
     } // quote!
 }
 
+// Same mapping as `def_visitor`, without serde
+fn impl_from_parameter(ident: &syn::Ident, name: &str, st: &syn::DataStruct) -> TokenStream2 {
+    let FieldEntries { holder_types, .. } = FieldEntries::parse(st);
+    let attr_len = holder_types.len();
+    let index = 0..attr_len;
+    let ruststep = ruststep_crate();
+    quote! {
+        #[automatically_derived]
+        impl #ruststep::tables::FromParameter for #ident {
+            fn from_parameter(parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                use #ruststep::tables::FromParameter;
+                let parameters = #ruststep::tables::attribute_parameters(#name, parameter, #attr_len)?;
+                Ok(#ident ( #(FromParameter::from_parameter(&parameters[#index])?),* ))
+            }
+
+            fn from_typed(keyword: &str, parameter: &#ruststep::ast::Parameter) -> #ruststep::error::Result<Self> {
+                if keyword != #name {
+                    return Err(#ruststep::tables::unexpected_keyword::<Self>(keyword));
+                }
+                Self::from_parameter(parameter)
+            }
+        }
+    } // quote!
+}
+
 struct FieldEntries {
     holder_types: Vec<syn::Type>,
     into_owned: Vec<TokenStream2>,
+    /// Fields of holders by `ruststep::tables::FromOwned`
+    from_owned: Vec<TokenStream2>,
+    /// Statements pushing references of fields into `refs`
+    collect_refs: Vec<TokenStream2>,
+    /// True if any field requires the table to resolve
+    use_place_holder: bool,
 }
 
 impl FieldEntries {
     fn parse(st: &syn::DataStruct) -> Self {
         let table_arg = table_arg();
+        let ruststep = ruststep_crate();
 
         let mut holder_types = Vec::new();
         let mut into_owned = Vec::new();
+        let mut from_owned = Vec::new();
+        let mut collect_refs = Vec::new();
+        let mut use_place_holder = false;
 
         for (i, field) in st.fields.iter().enumerate() {
             let ft: FieldType = field.ty.clone().try_into().unwrap();
@@ -216,16 +428,30 @@ impl FieldEntries {
                             .collect::<::std::result::Result<Vec<_>, _>>()?
                     }),
                     FieldType::Boxed(_) => abort_call_site!("Unexpected Box<T>"),
+                    FieldType::Shared(_) => abort_call_site!("Unexpected Rc<T>"),
                 }
+                let expr = ft
+                    .clone()
+                    .into_holder()
+                    .place_holder_from_owned(quote! { owned.#index }, false);
+                from_owned.push(quote! { #expr? });
+                collect_refs.push(
+                    quote! { #ruststep::tables::ContainsRef::collect_refs(&self.#index, refs); },
+                );
+                use_place_holder = true;
                 holder_types.push(ft.into_holder().into_place_holder().into());
             } else {
                 into_owned.push(quote! { self.#index });
+                from_owned.push(quote! { owned.#index });
                 holder_types.push(ft.into());
             }
         }
         FieldEntries {
             holder_types,
             into_owned,
+            from_owned,
+            collect_refs,
+            use_place_holder,
         }
     }
 }
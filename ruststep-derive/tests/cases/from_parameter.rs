@@ -0,0 +1,121 @@
+use ruststep::{
+    ast::Parameter,
+    error::Result,
+    tables::{unexpected_parameter, EntityTable, FromParameter, InstanceId},
+};
+use ruststep_derive::{as_holder, Holder, TableInit};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
+#[table(from_parameter)]
+pub struct Table {
+    point: HashMap<InstanceId, as_holder!(Point)>,
+    label: HashMap<InstanceId, as_holder!(Label)>,
+    line: HashMap<InstanceId, as_holder!(Line)>,
+    shape: HashMap<InstanceId, as_holder!(Shape)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Color {
+    Red,
+    Blue,
+}
+
+impl FromParameter for Color {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Enumeration(item) if item == "RED" => Ok(Color::Red),
+            Parameter::Enumeration(item) if item == "BLUE" => Ok(Color::Blue),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = point)]
+#[holder(generate_from_parameter)]
+pub struct Point {
+    pub x: f64,
+    pub visible: bool,
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = label)]
+#[holder(generate_from_parameter)]
+pub struct Label(pub String);
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = line)]
+#[holder(generate_from_parameter)]
+pub struct Line {
+    #[holder(use_place_holder)]
+    pub start: Point,
+    #[holder(use_place_holder)]
+    pub end: Point,
+    #[holder(use_place_holder)]
+    pub label: Option<Label>,
+    pub weights: Vec<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(generate_from_parameter)]
+pub enum Shape {
+    #[holder(use_place_holder)]
+    Line(Box<Line>),
+    #[holder(use_place_holder)]
+    Point(Box<Point>),
+}
+
+fn main() {
+    let table = Table::from_str(
+        r#"
+        DATA;
+          #1 = POINT(1, .T., .RED.);
+          #2 = LABEL('diagonal');
+          #3 = LINE(#1, POINT((2.0, .F., $)), #2, (1, 2));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+
+    let start = Point {
+        x: 1.0,
+        visible: true,
+        color: Some(Color::Red),
+    };
+    let line = Line {
+        start: start.clone(),
+        end: Point {
+            x: 2.0,
+            visible: false,
+            color: None,
+        },
+        label: Some(Label("diagonal".to_string())),
+        weights: vec![1, 2],
+    };
+    assert_eq!(
+        EntityTable::<PointHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        start
+    );
+    assert_eq!(
+        EntityTable::<LineHolder>::get_owned(&table, InstanceId(3)).unwrap(),
+        line
+    );
+    assert_eq!(
+        EntityTable::<ShapeHolder>::get_owned(&table, InstanceId(3)).unwrap(),
+        Shape::Line(Box::new(line))
+    );
+
+    let shape = ShapeHolder::from_parameter(&Parameter::from_str("POINT((0.5, .T., .BLUE.))").unwrap()).unwrap();
+    assert!(matches!(shape, ShapeHolder::Point(_)));
+
+    // wrong number of attributes
+    assert!(Table::from_str("DATA; #1 = POINT(1.0, .T.); ENDSEC;").is_err());
+    // wrong keyword of inline entity
+    assert!(Table::from_str("DATA; #1 = POINT(1.0, .T., $); #2 = LINE(#1, LABEL('a'), $, ()); ENDSEC;").is_err());
+}
@@ -1,10 +1,11 @@
 use ruststep_derive::{as_holder, Holder};
+use ruststep::tables::InstanceId;
 use std::collections::HashMap;
 
 pub struct Table {
-    a: HashMap<u64, as_holder!(A)>,
-    b: HashMap<u64, as_holder!(B)>,
-    c: HashMap<u64, as_holder!(C)>,
+    a: HashMap<InstanceId, as_holder!(A)>,
+    b: HashMap<InstanceId, as_holder!(B)>,
+    c: HashMap<InstanceId, as_holder!(C)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Holder)]
@@ -0,0 +1,55 @@
+use ruststep::{ast::ser::to_record, tables::InstanceId};
+use ruststep_derive::{as_holder, Holder, TableInit};
+use serde::{
+    de::{value::MapDeserializer, Error as _},
+    Deserialize,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
+pub struct Table {
+    a: HashMap<InstanceId, as_holder!(A)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = a)]
+#[holder(generate_deserialize)]
+#[holder(generate_serialize)]
+pub struct A {
+    #[holder(rename = "type")]
+    pub r#type: i64,
+    #[holder(rename = "ref")]
+    pub reference: i64,
+    pub map: i64,
+}
+
+type Error = serde::de::value::Error;
+
+fn deserialize(entries: Vec<(&'static str, i64)>) -> Result<AHolder, Error> {
+    AHolder::deserialize(MapDeserializer::<_, Error>::new(entries.into_iter()))
+}
+
+fn main() {
+    // Keyed by the EXPRESS names instead of the Rust identifiers
+    let a = deserialize(vec![("ref", 2), ("type", 1), ("map", 3)]).unwrap();
+    assert_eq!(
+        a,
+        AHolder {
+            r#type: 1,
+            reference: 2,
+            map: 3
+        }
+    );
+    assert_eq!(
+        deserialize(vec![("reference", 2)]).unwrap_err(),
+        Error::unknown_field("reference", &["type", "ref", "map"])
+    );
+    assert_eq!(
+        deserialize(vec![("type", 1), ("ref", 2)]).unwrap_err(),
+        Error::missing_field("map")
+    );
+
+    // Positional records are not affected
+    assert_eq!(to_record(&a).unwrap().to_string(), "A(1,2,3)");
+}
@@ -1,10 +1,15 @@
-use ruststep_derive::{as_holder, Holder};
+use ruststep_derive::{as_holder, Holder, TableInit};
+use ruststep::{
+    ast::{Name, Parameter},
+    tables::{InstanceId, IntoOwned, PlaceHolder},
+};
+use serde::Deserialize;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
 pub struct Table {
-    a: HashMap<u64, as_holder!(A)>,
-    b: HashMap<u64, as_holder!(B)>,
+    a: HashMap<InstanceId, as_holder!(A)>,
+    b: HashMap<InstanceId, as_holder!(B)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Holder)]
@@ -43,4 +48,29 @@ pub enum S2 {
     P(f64),
 }
 
-fn main() {}
+fn main() {
+    let table = Table {
+        a: [(InstanceId(1), AHolder { x: 1.0 })].into(),
+        b: HashMap::new(),
+    };
+
+    // Inline value dispatched by its keyword
+    let p = Parameter::Typed {
+        keyword: "B".to_string(),
+        parameter: Box::new(Parameter::List(vec![Parameter::Real(2.0)])),
+    };
+    let s1 = S1Holder::deserialize(&p).unwrap();
+    assert_eq!(s1.into_owned(&table).unwrap(), S1::B(Box::new(B { y: 2.0 })));
+
+    // Reference resolved by the keyword of the instance in the table
+    let p = Parameter::Ref(Name::Entity(InstanceId(1)));
+    let s1 = PlaceHolder::<S1Holder>::deserialize(&p).unwrap();
+    assert_eq!(s1.into_owned(&table).unwrap(), S1::A(Box::new(A { x: 1.0 })));
+
+    let p = Parameter::Typed {
+        keyword: "P".to_string(),
+        parameter: Box::new(Parameter::Real(3.0)),
+    };
+    let s2 = S2Holder::deserialize(&p).unwrap();
+    assert_eq!(s2.into_owned(&table).unwrap(), S2::P(3.0));
+}
@@ -0,0 +1,58 @@
+use ruststep::{
+    ast::{ser::to_record, Name, Record},
+    tables::{InstanceId, PlaceHolder},
+};
+use ruststep_derive::{as_holder, Holder, TableInit};
+use std::{collections::HashMap, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
+pub struct Table {
+    a: HashMap<InstanceId, as_holder!(A)>,
+    b: HashMap<InstanceId, as_holder!(B)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = a)]
+#[holder(generate_deserialize)]
+#[holder(generate_serialize)]
+pub struct A {
+    pub x: f64,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = b)]
+#[holder(generate_deserialize)]
+#[holder(generate_serialize)]
+pub struct B {
+    pub z: i64,
+    #[holder(use_place_holder)]
+    pub a: A,
+    #[holder(use_place_holder)]
+    pub r#as: Vec<A>,
+}
+
+fn main() {
+    let a = AHolder {
+        x: 1.0,
+        label: Some("it's".to_string()),
+    };
+    assert_eq!(to_record(&a).unwrap().to_string(), "A(1.,'it''s')");
+
+    // Referenced by `#id`, and inlined if owned
+    let b = BHolder {
+        z: 2,
+        a: PlaceHolder::Ref(Name::Entity(InstanceId(1))),
+        r#as: vec![
+            PlaceHolder::Owned(AHolder { x: 0.5, label: None }),
+            PlaceHolder::Ref(Name::Entity(InstanceId(3))),
+        ],
+    };
+    let record = to_record(&b).unwrap();
+    assert_eq!(record.to_string(), "B(2,#1,(A((0.5,$)),#3))");
+
+    // Parsed back into the same record
+    assert_eq!(Record::from_str(&record.to_string()).unwrap(), record);
+}
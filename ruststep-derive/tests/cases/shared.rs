@@ -0,0 +1,64 @@
+use ruststep_derive::{as_holder, Holder, TableInit};
+use ruststep::tables::{EntityTable, InstanceId, SharedCache, SharedTable};
+use std::{collections::HashMap, rc::Rc, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
+pub struct Table {
+    point: HashMap<InstanceId, as_holder!(Point)>,
+    line: HashMap<InstanceId, as_holder!(Line)>,
+    polyline: HashMap<InstanceId, as_holder!(Polyline)>,
+    #[table(shared_cache)]
+    cache: SharedCache,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = point)]
+#[holder(generate_deserialize)]
+pub struct Point {
+    pub x: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = line)]
+#[holder(generate_deserialize)]
+pub struct Line {
+    #[holder(use_place_holder)]
+    pub start: Rc<Point>,
+    #[holder(use_place_holder)]
+    pub end: Option<Rc<Point>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder)]
+#[holder(table = Table)]
+#[holder(field = polyline)]
+#[holder(generate_deserialize)]
+pub struct Polyline {
+    #[holder(use_place_holder)]
+    pub points: Vec<Rc<Point>>,
+}
+
+fn main() {
+    let table = Table::from_str(
+        r#"
+        DATA;
+          #1 = POINT(1.0);
+          #2 = LINE(#1, #1);
+          #3 = POLYLINE((#1, #4, #1));
+          #4 = POINT(2.0);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+
+    let line = EntityTable::<LineHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert!(Rc::ptr_eq(&line.start, line.end.as_ref().unwrap()));
+
+    let polyline = EntityTable::<PolylineHolder>::get_owned(&table, InstanceId(3)).unwrap();
+    assert!(Rc::ptr_eq(&polyline.points[0], &line.start));
+    assert!(Rc::ptr_eq(&polyline.points[0], &polyline.points[2]));
+    assert_eq!(*polyline.points[1], Point { x: 2.0 });
+
+    assert_eq!(table.shared_cache().len(), 2);
+}
@@ -1,10 +1,11 @@
 use ruststep_derive::{as_holder, Holder};
+use ruststep::tables::InstanceId;
 use std::collections::HashMap;
 
 pub struct Table {
-    e: HashMap<u64, as_holder!(E)>,
-    a: HashMap<u64, as_holder!(A)>,
-    b: HashMap<u64, as_holder!(B)>,
+    e: HashMap<InstanceId, as_holder!(E)>,
+    a: HashMap<InstanceId, as_holder!(A)>,
+    b: HashMap<InstanceId, as_holder!(B)>,
 }
 
 #[derive(Debug, Clone, PartialEq, ::serde::Deserialize)]
@@ -1,9 +1,10 @@
 use ruststep_derive::{as_holder, Holder};
+use ruststep::tables::InstanceId;
 use std::collections::HashMap;
 
 pub struct Table {
-    a: HashMap<u64, as_holder!(A)>,
-    b: HashMap<u64, as_holder!(B)>,
+    a: HashMap<InstanceId, as_holder!(A)>,
+    b: HashMap<InstanceId, as_holder!(B)>,
 }
 
 #[derive(Debug, Clone, PartialEq, Holder)]
@@ -6,4 +6,8 @@ fn try_build() {
     t.pass("tests/cases/vec.rs");
     t.pass("tests/cases/select.rs");
     t.pass("tests/cases/tuple.rs");
+    t.pass("tests/cases/shared.rs");
+    t.pass("tests/cases/from_parameter.rs");
+    t.pass("tests/cases/serialize.rs");
+    t.pass("tests/cases/rename.rs");
 }
@@ -1,1437 +1,2300 @@
 #![allow(dead_code)]
+#[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+pub const SCHEMA_IDENTIFIERS: &[&str] = &["EXPLICIT_DRAUGHTING"];
 pub mod explicit_draughting {
     use crate::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
     use std::collections::HashMap;
     #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+    #[table(into_parameter)]
     pub struct Tables {
-        address: HashMap<u64, as_holder!(Address)>,
-        angular_dimension: HashMap<u64, as_holder!(AngularDimension)>,
-        annotation_curve_occurrence: HashMap<u64, as_holder!(AnnotationCurveOccurrence)>,
-        annotation_fill_area: HashMap<u64, as_holder!(AnnotationFillArea)>,
-        annotation_fill_area_occurrence: HashMap<u64, as_holder!(AnnotationFillAreaOccurrence)>,
-        annotation_occurrence: HashMap<u64, as_holder!(AnnotationOccurrence)>,
-        annotation_subfigure_occurrence: HashMap<u64, as_holder!(AnnotationSubfigureOccurrence)>,
-        annotation_symbol: HashMap<u64, as_holder!(AnnotationSymbol)>,
-        annotation_symbol_occurrence: HashMap<u64, as_holder!(AnnotationSymbolOccurrence)>,
-        annotation_text: HashMap<u64, as_holder!(AnnotationText)>,
-        annotation_text_occurrence: HashMap<u64, as_holder!(AnnotationTextOccurrence)>,
-        application_context: HashMap<u64, as_holder!(ApplicationContext)>,
-        application_context_element: HashMap<u64, as_holder!(ApplicationContextElement)>,
-        application_protocol_definition: HashMap<u64, as_holder!(ApplicationProtocolDefinition)>,
-        approval: HashMap<u64, as_holder!(Approval)>,
-        approval_assignment: HashMap<u64, as_holder!(ApprovalAssignment)>,
-        approval_date_time: HashMap<u64, as_holder!(ApprovalDateTime)>,
-        approval_person_organization: HashMap<u64, as_holder!(ApprovalPersonOrganization)>,
-        approval_role: HashMap<u64, as_holder!(ApprovalRole)>,
-        approval_status: HashMap<u64, as_holder!(ApprovalStatus)>,
-        area_in_set: HashMap<u64, as_holder!(AreaInSet)>,
-        axis2_placement_2d: HashMap<u64, as_holder!(Axis2Placement2D)>,
-        b_spline_curve: HashMap<u64, as_holder!(BSplineCurve)>,
-        b_spline_curve_with_knots: HashMap<u64, as_holder!(BSplineCurveWithKnots)>,
-        bezier_curve: HashMap<u64, as_holder!(BezierCurve)>,
-        bounded_curve: HashMap<u64, as_holder!(BoundedCurve)>,
-        calendar_date: HashMap<u64, as_holder!(CalendarDate)>,
-        camera_image: HashMap<u64, as_holder!(CameraImage)>,
-        camera_image_2d_with_scale: HashMap<u64, as_holder!(CameraImage2DWithScale)>,
-        camera_model: HashMap<u64, as_holder!(CameraModel)>,
-        camera_model_d2: HashMap<u64, as_holder!(CameraModelD2)>,
-        camera_usage: HashMap<u64, as_holder!(CameraUsage)>,
-        cartesian_point: HashMap<u64, as_holder!(CartesianPoint)>,
-        circle: HashMap<u64, as_holder!(Circle)>,
-        colour: HashMap<u64, as_holder!(Colour)>,
-        colour_rgb: HashMap<u64, as_holder!(ColourRgb)>,
-        colour_specification: HashMap<u64, as_holder!(ColourSpecification)>,
-        composite_curve: HashMap<u64, as_holder!(CompositeCurve)>,
-        composite_curve_segment: HashMap<u64, as_holder!(CompositeCurveSegment)>,
-        composite_text: HashMap<u64, as_holder!(CompositeText)>,
+        #[table(validate)]
+        address: HashMap<crate::tables::InstanceId, as_holder!(Address)>,
+        #[table(validate)]
+        angular_dimension: HashMap<crate::tables::InstanceId, as_holder!(AngularDimension)>,
+        #[table(validate)]
+        annotation_curve_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(AnnotationCurveOccurrence)>,
+        #[table(validate)]
+        annotation_fill_area: HashMap<crate::tables::InstanceId, as_holder!(AnnotationFillArea)>,
+        #[table(validate)]
+        annotation_fill_area_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(AnnotationFillAreaOccurrence)>,
+        #[table(validate)]
+        annotation_occurrence: HashMap<crate::tables::InstanceId, as_holder!(AnnotationOccurrence)>,
+        #[table(validate)]
+        annotation_subfigure_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(AnnotationSubfigureOccurrence)>,
+        #[table(validate)]
+        annotation_symbol: HashMap<crate::tables::InstanceId, as_holder!(AnnotationSymbol)>,
+        #[table(validate)]
+        annotation_symbol_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(AnnotationSymbolOccurrence)>,
+        #[table(validate)]
+        annotation_text: HashMap<crate::tables::InstanceId, as_holder!(AnnotationText)>,
+        #[table(validate)]
+        annotation_text_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(AnnotationTextOccurrence)>,
+        application_context: HashMap<crate::tables::InstanceId, as_holder!(ApplicationContext)>,
+        application_context_element:
+            HashMap<crate::tables::InstanceId, as_holder!(ApplicationContextElement)>,
+        application_protocol_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(ApplicationProtocolDefinition)>,
+        approval: HashMap<crate::tables::InstanceId, as_holder!(Approval)>,
+        approval_assignment: HashMap<crate::tables::InstanceId, as_holder!(ApprovalAssignment)>,
+        approval_date_time: HashMap<crate::tables::InstanceId, as_holder!(ApprovalDateTime)>,
+        approval_person_organization:
+            HashMap<crate::tables::InstanceId, as_holder!(ApprovalPersonOrganization)>,
+        approval_role: HashMap<crate::tables::InstanceId, as_holder!(ApprovalRole)>,
+        approval_status: HashMap<crate::tables::InstanceId, as_holder!(ApprovalStatus)>,
+        area_in_set: HashMap<crate::tables::InstanceId, as_holder!(AreaInSet)>,
+        #[table(validate)]
+        axis2_placement_2d: HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement2D)>,
+        #[table(validate)]
+        b_spline_curve: HashMap<crate::tables::InstanceId, as_holder!(BSplineCurve)>,
+        #[table(validate)]
+        b_spline_curve_with_knots:
+            HashMap<crate::tables::InstanceId, as_holder!(BSplineCurveWithKnots)>,
+        #[table(validate)]
+        bezier_curve: HashMap<crate::tables::InstanceId, as_holder!(BezierCurve)>,
+        #[table(validate)]
+        bounded_curve: HashMap<crate::tables::InstanceId, as_holder!(BoundedCurve)>,
+        #[table(validate)]
+        calendar_date: HashMap<crate::tables::InstanceId, as_holder!(CalendarDate)>,
+        #[table(validate)]
+        camera_image: HashMap<crate::tables::InstanceId, as_holder!(CameraImage)>,
+        #[table(validate)]
+        camera_image_2d_with_scale:
+            HashMap<crate::tables::InstanceId, as_holder!(CameraImage2DWithScale)>,
+        #[table(validate)]
+        camera_model: HashMap<crate::tables::InstanceId, as_holder!(CameraModel)>,
+        #[table(validate)]
+        camera_model_d2: HashMap<crate::tables::InstanceId, as_holder!(CameraModelD2)>,
+        #[table(validate)]
+        camera_usage: HashMap<crate::tables::InstanceId, as_holder!(CameraUsage)>,
+        #[table(validate)]
+        cartesian_point: HashMap<crate::tables::InstanceId, as_holder!(CartesianPoint)>,
+        #[table(validate)]
+        circle: HashMap<crate::tables::InstanceId, as_holder!(Circle)>,
+        colour: HashMap<crate::tables::InstanceId, as_holder!(Colour)>,
+        #[table(validate)]
+        colour_rgb: HashMap<crate::tables::InstanceId, as_holder!(ColourRgb)>,
+        colour_specification: HashMap<crate::tables::InstanceId, as_holder!(ColourSpecification)>,
+        #[table(validate)]
+        composite_curve: HashMap<crate::tables::InstanceId, as_holder!(CompositeCurve)>,
+        #[table(validate)]
+        composite_curve_segment:
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveSegment)>,
+        #[table(validate)]
+        composite_text: HashMap<crate::tables::InstanceId, as_holder!(CompositeText)>,
+        #[table(validate)]
         composite_text_with_associated_curves:
-            HashMap<u64, as_holder!(CompositeTextWithAssociatedCurves)>,
-        composite_text_with_blanking_box: HashMap<u64, as_holder!(CompositeTextWithBlankingBox)>,
-        composite_text_with_extent: HashMap<u64, as_holder!(CompositeTextWithExtent)>,
-        conic: HashMap<u64, as_holder!(Conic)>,
-        context_dependent_invisibility: HashMap<u64, as_holder!(ContextDependentInvisibility)>,
-        contract: HashMap<u64, as_holder!(Contract)>,
-        contract_assignment: HashMap<u64, as_holder!(ContractAssignment)>,
-        contract_type: HashMap<u64, as_holder!(ContractType)>,
-        conversion_based_unit: HashMap<u64, as_holder!(ConversionBasedUnit)>,
-        curve: HashMap<u64, as_holder!(Curve)>,
-        curve_dimension: HashMap<u64, as_holder!(CurveDimension)>,
-        curve_style: HashMap<u64, as_holder!(CurveStyle)>,
-        curve_style_font: HashMap<u64, as_holder!(CurveStyleFont)>,
-        curve_style_font_pattern: HashMap<u64, as_holder!(CurveStyleFontPattern)>,
-        date: HashMap<u64, as_holder!(Date)>,
-        datum_feature_callout: HashMap<u64, as_holder!(DatumFeatureCallout)>,
-        datum_target_callout: HashMap<u64, as_holder!(DatumTargetCallout)>,
-        defined_symbol: HashMap<u64, as_holder!(DefinedSymbol)>,
-        diameter_dimension: HashMap<u64, as_holder!(DiameterDimension)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithAssociatedCurves)>,
+        #[table(validate)]
+        composite_text_with_blanking_box:
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithBlankingBox)>,
+        #[table(validate)]
+        composite_text_with_extent:
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithExtent)>,
+        #[table(validate)]
+        conic: HashMap<crate::tables::InstanceId, as_holder!(Conic)>,
+        context_dependent_invisibility:
+            HashMap<crate::tables::InstanceId, as_holder!(ContextDependentInvisibility)>,
+        contract: HashMap<crate::tables::InstanceId, as_holder!(Contract)>,
+        contract_assignment: HashMap<crate::tables::InstanceId, as_holder!(ContractAssignment)>,
+        contract_type: HashMap<crate::tables::InstanceId, as_holder!(ContractType)>,
+        conversion_based_unit: HashMap<crate::tables::InstanceId, as_holder!(ConversionBasedUnit)>,
+        #[table(validate)]
+        curve: HashMap<crate::tables::InstanceId, as_holder!(Curve)>,
+        #[table(validate)]
+        curve_dimension: HashMap<crate::tables::InstanceId, as_holder!(CurveDimension)>,
+        curve_style: HashMap<crate::tables::InstanceId, as_holder!(CurveStyle)>,
+        curve_style_font: HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFont)>,
+        curve_style_font_pattern:
+            HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFontPattern)>,
+        date: HashMap<crate::tables::InstanceId, as_holder!(Date)>,
+        #[table(validate)]
+        datum_feature_callout: HashMap<crate::tables::InstanceId, as_holder!(DatumFeatureCallout)>,
+        #[table(validate)]
+        datum_target_callout: HashMap<crate::tables::InstanceId, as_holder!(DatumTargetCallout)>,
+        #[table(validate)]
+        defined_symbol: HashMap<crate::tables::InstanceId, as_holder!(DefinedSymbol)>,
+        #[table(validate)]
+        diameter_dimension: HashMap<crate::tables::InstanceId, as_holder!(DiameterDimension)>,
+        #[table(validate)]
         dimension_callout_component_relationship:
-            HashMap<u64, as_holder!(DimensionCalloutComponentRelationship)>,
-        dimension_callout_relationship: HashMap<u64, as_holder!(DimensionCalloutRelationship)>,
-        dimension_curve: HashMap<u64, as_holder!(DimensionCurve)>,
-        dimension_curve_directed_callout: HashMap<u64, as_holder!(DimensionCurveDirectedCallout)>,
-        dimension_curve_terminator: HashMap<u64, as_holder!(DimensionCurveTerminator)>,
-        dimension_pair: HashMap<u64, as_holder!(DimensionPair)>,
-        dimensional_exponents: HashMap<u64, as_holder!(DimensionalExponents)>,
-        direction: HashMap<u64, as_holder!(Direction)>,
-        document: HashMap<u64, as_holder!(Document)>,
-        document_reference: HashMap<u64, as_holder!(DocumentReference)>,
-        document_type: HashMap<u64, as_holder!(DocumentType)>,
-        draughting_annotation_occurrence: HashMap<u64, as_holder!(DraughtingAnnotationOccurrence)>,
-        draughting_approval_assignment: HashMap<u64, as_holder!(DraughtingApprovalAssignment)>,
-        draughting_callout: HashMap<u64, as_holder!(DraughtingCallout)>,
-        draughting_callout_relationship: HashMap<u64, as_holder!(DraughtingCalloutRelationship)>,
-        draughting_contract_assignment: HashMap<u64, as_holder!(DraughtingContractAssignment)>,
-        draughting_drawing_revision: HashMap<u64, as_holder!(DraughtingDrawingRevision)>,
-        draughting_elements: HashMap<u64, as_holder!(DraughtingElements)>,
-        draughting_group_assignment: HashMap<u64, as_holder!(DraughtingGroupAssignment)>,
-        draughting_model: HashMap<u64, as_holder!(DraughtingModel)>,
+            HashMap<crate::tables::InstanceId, as_holder!(DimensionCalloutComponentRelationship)>,
+        #[table(validate)]
+        dimension_callout_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(DimensionCalloutRelationship)>,
+        #[table(validate)]
+        dimension_curve: HashMap<crate::tables::InstanceId, as_holder!(DimensionCurve)>,
+        #[table(validate)]
+        dimension_curve_directed_callout:
+            HashMap<crate::tables::InstanceId, as_holder!(DimensionCurveDirectedCallout)>,
+        #[table(validate)]
+        dimension_curve_terminator:
+            HashMap<crate::tables::InstanceId, as_holder!(DimensionCurveTerminator)>,
+        #[table(validate)]
+        dimension_pair: HashMap<crate::tables::InstanceId, as_holder!(DimensionPair)>,
+        dimensional_exponents: HashMap<crate::tables::InstanceId, as_holder!(DimensionalExponents)>,
+        #[table(validate)]
+        direction: HashMap<crate::tables::InstanceId, as_holder!(Direction)>,
+        document: HashMap<crate::tables::InstanceId, as_holder!(Document)>,
+        document_reference: HashMap<crate::tables::InstanceId, as_holder!(DocumentReference)>,
+        document_type: HashMap<crate::tables::InstanceId, as_holder!(DocumentType)>,
+        #[table(validate)]
+        draughting_annotation_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingAnnotationOccurrence)>,
+        #[table(validate)]
+        draughting_approval_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingApprovalAssignment)>,
+        #[table(validate)]
+        draughting_callout: HashMap<crate::tables::InstanceId, as_holder!(DraughtingCallout)>,
+        draughting_callout_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingCalloutRelationship)>,
+        draughting_contract_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingContractAssignment)>,
+        #[table(validate)]
+        draughting_drawing_revision:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingDrawingRevision)>,
+        #[table(validate)]
+        draughting_elements: HashMap<crate::tables::InstanceId, as_holder!(DraughtingElements)>,
+        draughting_group_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingGroupAssignment)>,
+        #[table(validate)]
+        draughting_model: HashMap<crate::tables::InstanceId, as_holder!(DraughtingModel)>,
         draughting_organization_assignment:
-            HashMap<u64, as_holder!(DraughtingOrganizationAssignment)>,
-        draughting_person_and_organization_assignment:
-            HashMap<u64, as_holder!(DraughtingPersonAndOrganizationAssignment)>,
-        draughting_person_assignment: HashMap<u64, as_holder!(DraughtingPersonAssignment)>,
-        draughting_pre_defined_colour: HashMap<u64, as_holder!(DraughtingPreDefinedColour)>,
-        draughting_pre_defined_curve_font: HashMap<u64, as_holder!(DraughtingPreDefinedCurveFont)>,
-        draughting_pre_defined_text_font: HashMap<u64, as_holder!(DraughtingPreDefinedTextFont)>,
-        draughting_presented_item: HashMap<u64, as_holder!(DraughtingPresentedItem)>,
-        draughting_security_classification_assignment:
-            HashMap<u64, as_holder!(DraughtingSecurityClassificationAssignment)>,
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingOrganizationAssignment)>,
+        draughting_person_and_organization_assignment: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(DraughtingPersonAndOrganizationAssignment),
+        >,
+        draughting_person_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPersonAssignment)>,
+        #[table(validate)]
+        draughting_pre_defined_colour:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedColour)>,
+        #[table(validate)]
+        draughting_pre_defined_curve_font:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedCurveFont)>,
+        #[table(validate)]
+        draughting_pre_defined_text_font:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedTextFont)>,
+        #[table(validate)]
+        draughting_presented_item:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPresentedItem)>,
+        draughting_security_classification_assignment: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(DraughtingSecurityClassificationAssignment),
+        >,
+        #[table(validate)]
         draughting_specification_reference:
-            HashMap<u64, as_holder!(DraughtingSpecificationReference)>,
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingSpecificationReference)>,
+        #[table(validate)]
         draughting_subfigure_representation:
-            HashMap<u64, as_holder!(DraughtingSubfigureRepresentation)>,
-        draughting_symbol_representation: HashMap<u64, as_holder!(DraughtingSymbolRepresentation)>,
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingSubfigureRepresentation)>,
+        #[table(validate)]
+        draughting_symbol_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingSymbolRepresentation)>,
+        #[table(validate)]
         draughting_text_literal_with_delineation:
-            HashMap<u64, as_holder!(DraughtingTextLiteralWithDelineation)>,
-        draughting_title: HashMap<u64, as_holder!(DraughtingTitle)>,
-        drawing_definition: HashMap<u64, as_holder!(DrawingDefinition)>,
-        drawing_revision: HashMap<u64, as_holder!(DrawingRevision)>,
-        drawing_sheet_layout: HashMap<u64, as_holder!(DrawingSheetLayout)>,
-        drawing_sheet_revision: HashMap<u64, as_holder!(DrawingSheetRevision)>,
-        drawing_sheet_revision_usage: HashMap<u64, as_holder!(DrawingSheetRevisionUsage)>,
-        ellipse: HashMap<u64, as_holder!(Ellipse)>,
-        external_source: HashMap<u64, as_holder!(ExternalSource)>,
-        externally_defined_curve_font: HashMap<u64, as_holder!(ExternallyDefinedCurveFont)>,
-        externally_defined_hatch_style: HashMap<u64, as_holder!(ExternallyDefinedHatchStyle)>,
-        externally_defined_item: HashMap<u64, as_holder!(ExternallyDefinedItem)>,
-        externally_defined_symbol: HashMap<u64, as_holder!(ExternallyDefinedSymbol)>,
-        externally_defined_text_font: HashMap<u64, as_holder!(ExternallyDefinedTextFont)>,
-        externally_defined_tile_style: HashMap<u64, as_holder!(ExternallyDefinedTileStyle)>,
-        fill_area_style: HashMap<u64, as_holder!(FillAreaStyle)>,
-        fill_area_style_colour: HashMap<u64, as_holder!(FillAreaStyleColour)>,
-        fill_area_style_hatching: HashMap<u64, as_holder!(FillAreaStyleHatching)>,
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingTextLiteralWithDelineation)>,
+        draughting_title: HashMap<crate::tables::InstanceId, as_holder!(DraughtingTitle)>,
+        drawing_definition: HashMap<crate::tables::InstanceId, as_holder!(DrawingDefinition)>,
+        drawing_revision: HashMap<crate::tables::InstanceId, as_holder!(DrawingRevision)>,
+        #[table(validate)]
+        drawing_sheet_layout: HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetLayout)>,
+        #[table(validate)]
+        drawing_sheet_revision:
+            HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetRevision)>,
+        #[table(validate)]
+        drawing_sheet_revision_usage:
+            HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetRevisionUsage)>,
+        #[table(validate)]
+        ellipse: HashMap<crate::tables::InstanceId, as_holder!(Ellipse)>,
+        external_source: HashMap<crate::tables::InstanceId, as_holder!(ExternalSource)>,
+        externally_defined_curve_font:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedCurveFont)>,
+        #[table(validate)]
+        externally_defined_hatch_style:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedHatchStyle)>,
+        externally_defined_item:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedItem)>,
+        externally_defined_symbol:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedSymbol)>,
+        externally_defined_text_font:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedTextFont)>,
+        #[table(validate)]
+        externally_defined_tile_style:
+            HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedTileStyle)>,
+        #[table(validate)]
+        fill_area_style: HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyle)>,
+        fill_area_style_colour: HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleColour)>,
+        #[table(validate)]
+        fill_area_style_hatching:
+            HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleHatching)>,
+        #[table(validate)]
         fill_area_style_tile_symbol_with_style:
-            HashMap<u64, as_holder!(FillAreaStyleTileSymbolWithStyle)>,
-        fill_area_style_tiles: HashMap<u64, as_holder!(FillAreaStyleTiles)>,
-        geometric_curve_set: HashMap<u64, as_holder!(GeometricCurveSet)>,
-        geometric_representation_context: HashMap<u64, as_holder!(GeometricRepresentationContext)>,
-        geometric_representation_item: HashMap<u64, as_holder!(GeometricRepresentationItem)>,
-        geometric_set: HashMap<u64, as_holder!(GeometricSet)>,
-        geometrical_tolerance_callout: HashMap<u64, as_holder!(GeometricalToleranceCallout)>,
-        geometrically_bounded_2d_wireframe_representation:
-            HashMap<u64, as_holder!(GeometricallyBounded2DWireframeRepresentation)>,
-        global_unit_assigned_context: HashMap<u64, as_holder!(GlobalUnitAssignedContext)>,
-        group: HashMap<u64, as_holder!(Group)>,
-        group_assignment: HashMap<u64, as_holder!(GroupAssignment)>,
-        group_relationship: HashMap<u64, as_holder!(GroupRelationship)>,
-        hyperbola: HashMap<u64, as_holder!(Hyperbola)>,
-        invisibility: HashMap<u64, as_holder!(Invisibility)>,
-        leader_curve: HashMap<u64, as_holder!(LeaderCurve)>,
-        leader_directed_callout: HashMap<u64, as_holder!(LeaderDirectedCallout)>,
-        leader_directed_dimension: HashMap<u64, as_holder!(LeaderDirectedDimension)>,
-        leader_terminator: HashMap<u64, as_holder!(LeaderTerminator)>,
-        length_measure_with_unit: HashMap<u64, as_holder!(LengthMeasureWithUnit)>,
-        length_unit: HashMap<u64, as_holder!(LengthUnit)>,
-        line: HashMap<u64, as_holder!(Line)>,
-        linear_dimension: HashMap<u64, as_holder!(LinearDimension)>,
-        mapped_item: HashMap<u64, as_holder!(MappedItem)>,
-        measure_with_unit: HashMap<u64, as_holder!(MeasureWithUnit)>,
-        named_unit: HashMap<u64, as_holder!(NamedUnit)>,
-        offset_curve_2d: HashMap<u64, as_holder!(OffsetCurve2D)>,
-        one_direction_repeat_factor: HashMap<u64, as_holder!(OneDirectionRepeatFactor)>,
-        ordinate_dimension: HashMap<u64, as_holder!(OrdinateDimension)>,
-        organization: HashMap<u64, as_holder!(Organization)>,
-        organization_assignment: HashMap<u64, as_holder!(OrganizationAssignment)>,
-        organization_role: HashMap<u64, as_holder!(OrganizationRole)>,
-        organizational_address: HashMap<u64, as_holder!(OrganizationalAddress)>,
-        parabola: HashMap<u64, as_holder!(Parabola)>,
-        person: HashMap<u64, as_holder!(Person)>,
-        person_and_organization: HashMap<u64, as_holder!(PersonAndOrganization)>,
+            HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTileSymbolWithStyle)>,
+        #[table(validate)]
+        fill_area_style_tiles: HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTiles)>,
+        #[table(validate)]
+        geometric_curve_set: HashMap<crate::tables::InstanceId, as_holder!(GeometricCurveSet)>,
+        geometric_representation_context:
+            HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationContext)>,
+        #[table(validate)]
+        geometric_representation_item:
+            HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationItem)>,
+        #[table(validate)]
+        geometric_set: HashMap<crate::tables::InstanceId, as_holder!(GeometricSet)>,
+        #[table(validate)]
+        geometrical_tolerance_callout:
+            HashMap<crate::tables::InstanceId, as_holder!(GeometricalToleranceCallout)>,
+        #[table(validate)]
+        geometrically_bounded_2d_wireframe_representation: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBounded2DWireframeRepresentation),
+        >,
+        global_unit_assigned_context:
+            HashMap<crate::tables::InstanceId, as_holder!(GlobalUnitAssignedContext)>,
+        group: HashMap<crate::tables::InstanceId, as_holder!(Group)>,
+        group_assignment: HashMap<crate::tables::InstanceId, as_holder!(GroupAssignment)>,
+        group_relationship: HashMap<crate::tables::InstanceId, as_holder!(GroupRelationship)>,
+        #[table(validate)]
+        hyperbola: HashMap<crate::tables::InstanceId, as_holder!(Hyperbola)>,
+        invisibility: HashMap<crate::tables::InstanceId, as_holder!(Invisibility)>,
+        #[table(validate)]
+        leader_curve: HashMap<crate::tables::InstanceId, as_holder!(LeaderCurve)>,
+        #[table(validate)]
+        leader_directed_callout:
+            HashMap<crate::tables::InstanceId, as_holder!(LeaderDirectedCallout)>,
+        #[table(validate)]
+        leader_directed_dimension:
+            HashMap<crate::tables::InstanceId, as_holder!(LeaderDirectedDimension)>,
+        #[table(validate)]
+        leader_terminator: HashMap<crate::tables::InstanceId, as_holder!(LeaderTerminator)>,
+        #[table(validate)]
+        length_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(LengthMeasureWithUnit)>,
+        #[table(validate)]
+        length_unit: HashMap<crate::tables::InstanceId, as_holder!(LengthUnit)>,
+        #[table(validate)]
+        line: HashMap<crate::tables::InstanceId, as_holder!(Line)>,
+        #[table(validate)]
+        linear_dimension: HashMap<crate::tables::InstanceId, as_holder!(LinearDimension)>,
+        #[table(validate)]
+        mapped_item: HashMap<crate::tables::InstanceId, as_holder!(MappedItem)>,
+        #[table(validate)]
+        measure_with_unit: HashMap<crate::tables::InstanceId, as_holder!(MeasureWithUnit)>,
+        named_unit: HashMap<crate::tables::InstanceId, as_holder!(NamedUnit)>,
+        #[table(validate)]
+        offset_curve_2d: HashMap<crate::tables::InstanceId, as_holder!(OffsetCurve2D)>,
+        #[table(validate)]
+        one_direction_repeat_factor:
+            HashMap<crate::tables::InstanceId, as_holder!(OneDirectionRepeatFactor)>,
+        #[table(validate)]
+        ordinate_dimension: HashMap<crate::tables::InstanceId, as_holder!(OrdinateDimension)>,
+        organization: HashMap<crate::tables::InstanceId, as_holder!(Organization)>,
+        organization_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(OrganizationAssignment)>,
+        organization_role: HashMap<crate::tables::InstanceId, as_holder!(OrganizationRole)>,
+        #[table(validate)]
+        organizational_address:
+            HashMap<crate::tables::InstanceId, as_holder!(OrganizationalAddress)>,
+        #[table(validate)]
+        parabola: HashMap<crate::tables::InstanceId, as_holder!(Parabola)>,
+        #[table(validate)]
+        person: HashMap<crate::tables::InstanceId, as_holder!(Person)>,
+        person_and_organization:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganization)>,
         person_and_organization_assignment:
-            HashMap<u64, as_holder!(PersonAndOrganizationAssignment)>,
-        person_and_organization_role: HashMap<u64, as_holder!(PersonAndOrganizationRole)>,
-        person_assignment: HashMap<u64, as_holder!(PersonAssignment)>,
-        person_role: HashMap<u64, as_holder!(PersonRole)>,
-        personal_address: HashMap<u64, as_holder!(PersonalAddress)>,
-        placement: HashMap<u64, as_holder!(Placement)>,
-        planar_box: HashMap<u64, as_holder!(PlanarBox)>,
-        planar_extent: HashMap<u64, as_holder!(PlanarExtent)>,
-        plane_angle_measure_with_unit: HashMap<u64, as_holder!(PlaneAngleMeasureWithUnit)>,
-        plane_angle_unit: HashMap<u64, as_holder!(PlaneAngleUnit)>,
-        point: HashMap<u64, as_holder!(Point)>,
-        point_on_curve: HashMap<u64, as_holder!(PointOnCurve)>,
-        polyline: HashMap<u64, as_holder!(Polyline)>,
-        pre_defined_colour: HashMap<u64, as_holder!(PreDefinedColour)>,
-        pre_defined_curve_font: HashMap<u64, as_holder!(PreDefinedCurveFont)>,
-        pre_defined_dimension_symbol: HashMap<u64, as_holder!(PreDefinedDimensionSymbol)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationAssignment)>,
+        person_and_organization_role:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationRole)>,
+        person_assignment: HashMap<crate::tables::InstanceId, as_holder!(PersonAssignment)>,
+        person_role: HashMap<crate::tables::InstanceId, as_holder!(PersonRole)>,
+        #[table(validate)]
+        personal_address: HashMap<crate::tables::InstanceId, as_holder!(PersonalAddress)>,
+        #[table(validate)]
+        placement: HashMap<crate::tables::InstanceId, as_holder!(Placement)>,
+        #[table(validate)]
+        planar_box: HashMap<crate::tables::InstanceId, as_holder!(PlanarBox)>,
+        #[table(validate)]
+        planar_extent: HashMap<crate::tables::InstanceId, as_holder!(PlanarExtent)>,
+        #[table(validate)]
+        plane_angle_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasureWithUnit)>,
+        #[table(validate)]
+        plane_angle_unit: HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleUnit)>,
+        #[table(validate)]
+        point: HashMap<crate::tables::InstanceId, as_holder!(Point)>,
+        #[table(validate)]
+        point_on_curve: HashMap<crate::tables::InstanceId, as_holder!(PointOnCurve)>,
+        #[table(validate)]
+        polyline: HashMap<crate::tables::InstanceId, as_holder!(Polyline)>,
+        pre_defined_colour: HashMap<crate::tables::InstanceId, as_holder!(PreDefinedColour)>,
+        pre_defined_curve_font: HashMap<crate::tables::InstanceId, as_holder!(PreDefinedCurveFont)>,
+        #[table(validate)]
+        pre_defined_dimension_symbol:
+            HashMap<crate::tables::InstanceId, as_holder!(PreDefinedDimensionSymbol)>,
+        #[table(validate)]
         pre_defined_geometrical_tolerance_symbol:
-            HashMap<u64, as_holder!(PreDefinedGeometricalToleranceSymbol)>,
-        pre_defined_item: HashMap<u64, as_holder!(PreDefinedItem)>,
-        pre_defined_point_marker_symbol: HashMap<u64, as_holder!(PreDefinedPointMarkerSymbol)>,
-        pre_defined_symbol: HashMap<u64, as_holder!(PreDefinedSymbol)>,
-        pre_defined_terminator_symbol: HashMap<u64, as_holder!(PreDefinedTerminatorSymbol)>,
-        pre_defined_text_font: HashMap<u64, as_holder!(PreDefinedTextFont)>,
-        presentation_area: HashMap<u64, as_holder!(PresentationArea)>,
-        presentation_layer_assignment: HashMap<u64, as_holder!(PresentationLayerAssignment)>,
-        presentation_layer_usage: HashMap<u64, as_holder!(PresentationLayerUsage)>,
-        presentation_representation: HashMap<u64, as_holder!(PresentationRepresentation)>,
-        presentation_set: HashMap<u64, as_holder!(PresentationSet)>,
-        presentation_size: HashMap<u64, as_holder!(PresentationSize)>,
-        presentation_style_assignment: HashMap<u64, as_holder!(PresentationStyleAssignment)>,
-        presentation_style_by_context: HashMap<u64, as_holder!(PresentationStyleByContext)>,
-        presentation_view: HashMap<u64, as_holder!(PresentationView)>,
-        presented_item: HashMap<u64, as_holder!(PresentedItem)>,
-        presented_item_representation: HashMap<u64, as_holder!(PresentedItemRepresentation)>,
-        product: HashMap<u64, as_holder!(Product)>,
-        product_context: HashMap<u64, as_holder!(ProductContext)>,
-        product_definition: HashMap<u64, as_holder!(ProductDefinition)>,
-        product_definition_context: HashMap<u64, as_holder!(ProductDefinitionContext)>,
-        product_definition_formation: HashMap<u64, as_holder!(ProductDefinitionFormation)>,
-        product_definition_shape: HashMap<u64, as_holder!(ProductDefinitionShape)>,
-        projection_curve: HashMap<u64, as_holder!(ProjectionCurve)>,
-        projection_directed_callout: HashMap<u64, as_holder!(ProjectionDirectedCallout)>,
-        property_definition: HashMap<u64, as_holder!(PropertyDefinition)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PreDefinedGeometricalToleranceSymbol)>,
+        pre_defined_item: HashMap<crate::tables::InstanceId, as_holder!(PreDefinedItem)>,
+        #[table(validate)]
+        pre_defined_point_marker_symbol:
+            HashMap<crate::tables::InstanceId, as_holder!(PreDefinedPointMarkerSymbol)>,
+        pre_defined_symbol: HashMap<crate::tables::InstanceId, as_holder!(PreDefinedSymbol)>,
+        #[table(validate)]
+        pre_defined_terminator_symbol:
+            HashMap<crate::tables::InstanceId, as_holder!(PreDefinedTerminatorSymbol)>,
+        pre_defined_text_font: HashMap<crate::tables::InstanceId, as_holder!(PreDefinedTextFont)>,
+        #[table(validate)]
+        presentation_area: HashMap<crate::tables::InstanceId, as_holder!(PresentationArea)>,
+        presentation_layer_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationLayerAssignment)>,
+        presentation_layer_usage:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationLayerUsage)>,
+        #[table(validate)]
+        presentation_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationRepresentation)>,
+        presentation_set: HashMap<crate::tables::InstanceId, as_holder!(PresentationSet)>,
+        #[table(validate)]
+        presentation_size: HashMap<crate::tables::InstanceId, as_holder!(PresentationSize)>,
+        #[table(validate)]
+        presentation_style_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleAssignment)>,
+        #[table(validate)]
+        presentation_style_by_context:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleByContext)>,
+        #[table(validate)]
+        presentation_view: HashMap<crate::tables::InstanceId, as_holder!(PresentationView)>,
+        presented_item: HashMap<crate::tables::InstanceId, as_holder!(PresentedItem)>,
+        presented_item_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentedItemRepresentation)>,
+        product: HashMap<crate::tables::InstanceId, as_holder!(Product)>,
+        product_context: HashMap<crate::tables::InstanceId, as_holder!(ProductContext)>,
+        product_definition: HashMap<crate::tables::InstanceId, as_holder!(ProductDefinition)>,
+        product_definition_context:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionContext)>,
+        product_definition_formation:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionFormation)>,
+        #[table(validate)]
+        product_definition_shape:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionShape)>,
+        #[table(validate)]
+        projection_curve: HashMap<crate::tables::InstanceId, as_holder!(ProjectionCurve)>,
+        #[table(validate)]
+        projection_directed_callout:
+            HashMap<crate::tables::InstanceId, as_holder!(ProjectionDirectedCallout)>,
+        property_definition: HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinition)>,
         property_definition_representation:
-            HashMap<u64, as_holder!(PropertyDefinitionRepresentation)>,
-        quasi_uniform_curve: HashMap<u64, as_holder!(QuasiUniformCurve)>,
-        radius_dimension: HashMap<u64, as_holder!(RadiusDimension)>,
-        rational_b_spline_curve: HashMap<u64, as_holder!(RationalBSplineCurve)>,
-        representation: HashMap<u64, as_holder!(Representation)>,
-        representation_context: HashMap<u64, as_holder!(RepresentationContext)>,
-        representation_item: HashMap<u64, as_holder!(RepresentationItem)>,
-        representation_map: HashMap<u64, as_holder!(RepresentationMap)>,
-        security_classification: HashMap<u64, as_holder!(SecurityClassification)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinitionRepresentation)>,
+        #[table(validate)]
+        quasi_uniform_curve: HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformCurve)>,
+        #[table(validate)]
+        radius_dimension: HashMap<crate::tables::InstanceId, as_holder!(RadiusDimension)>,
+        #[table(validate)]
+        rational_b_spline_curve:
+            HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineCurve)>,
+        representation: HashMap<crate::tables::InstanceId, as_holder!(Representation)>,
+        representation_context:
+            HashMap<crate::tables::InstanceId, as_holder!(RepresentationContext)>,
+        #[table(validate)]
+        representation_item: HashMap<crate::tables::InstanceId, as_holder!(RepresentationItem)>,
+        #[table(validate)]
+        representation_map: HashMap<crate::tables::InstanceId, as_holder!(RepresentationMap)>,
+        security_classification:
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassification)>,
         security_classification_assignment:
-            HashMap<u64, as_holder!(SecurityClassificationAssignment)>,
-        security_classification_level: HashMap<u64, as_holder!(SecurityClassificationLevel)>,
-        shape_definition_representation: HashMap<u64, as_holder!(ShapeDefinitionRepresentation)>,
-        shape_representation: HashMap<u64, as_holder!(ShapeRepresentation)>,
-        si_unit: HashMap<u64, as_holder!(SiUnit)>,
-        structured_dimension_callout: HashMap<u64, as_holder!(StructuredDimensionCallout)>,
-        styled_item: HashMap<u64, as_holder!(StyledItem)>,
-        symbol_colour: HashMap<u64, as_holder!(SymbolColour)>,
-        symbol_representation: HashMap<u64, as_holder!(SymbolRepresentation)>,
-        symbol_representation_map: HashMap<u64, as_holder!(SymbolRepresentationMap)>,
-        symbol_style: HashMap<u64, as_holder!(SymbolStyle)>,
-        symbol_target: HashMap<u64, as_holder!(SymbolTarget)>,
-        terminator_symbol: HashMap<u64, as_holder!(TerminatorSymbol)>,
-        text_literal: HashMap<u64, as_holder!(TextLiteral)>,
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationAssignment)>,
+        security_classification_level:
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationLevel)>,
+        #[table(validate)]
+        shape_definition_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinitionRepresentation)>,
+        shape_representation: HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentation)>,
+        si_unit: HashMap<crate::tables::InstanceId, as_holder!(SiUnit)>,
+        #[table(validate)]
+        structured_dimension_callout:
+            HashMap<crate::tables::InstanceId, as_holder!(StructuredDimensionCallout)>,
+        #[table(validate)]
+        styled_item: HashMap<crate::tables::InstanceId, as_holder!(StyledItem)>,
+        symbol_colour: HashMap<crate::tables::InstanceId, as_holder!(SymbolColour)>,
+        symbol_representation: HashMap<crate::tables::InstanceId, as_holder!(SymbolRepresentation)>,
+        #[table(validate)]
+        symbol_representation_map:
+            HashMap<crate::tables::InstanceId, as_holder!(SymbolRepresentationMap)>,
+        symbol_style: HashMap<crate::tables::InstanceId, as_holder!(SymbolStyle)>,
+        #[table(validate)]
+        symbol_target: HashMap<crate::tables::InstanceId, as_holder!(SymbolTarget)>,
+        #[table(validate)]
+        terminator_symbol: HashMap<crate::tables::InstanceId, as_holder!(TerminatorSymbol)>,
+        #[table(validate)]
+        text_literal: HashMap<crate::tables::InstanceId, as_holder!(TextLiteral)>,
+        #[table(validate)]
         text_literal_with_associated_curves:
-            HashMap<u64, as_holder!(TextLiteralWithAssociatedCurves)>,
-        text_literal_with_blanking_box: HashMap<u64, as_holder!(TextLiteralWithBlankingBox)>,
-        text_literal_with_delineation: HashMap<u64, as_holder!(TextLiteralWithDelineation)>,
-        text_literal_with_extent: HashMap<u64, as_holder!(TextLiteralWithExtent)>,
-        text_style: HashMap<u64, as_holder!(TextStyle)>,
-        text_style_for_defined_font: HashMap<u64, as_holder!(TextStyleForDefinedFont)>,
+            HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithAssociatedCurves)>,
+        #[table(validate)]
+        text_literal_with_blanking_box:
+            HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithBlankingBox)>,
+        #[table(validate)]
+        text_literal_with_delineation:
+            HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithDelineation)>,
+        #[table(validate)]
+        text_literal_with_extent:
+            HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithExtent)>,
+        text_style: HashMap<crate::tables::InstanceId, as_holder!(TextStyle)>,
+        text_style_for_defined_font:
+            HashMap<crate::tables::InstanceId, as_holder!(TextStyleForDefinedFont)>,
+        #[table(validate)]
         text_style_with_box_characteristics:
-            HashMap<u64, as_holder!(TextStyleWithBoxCharacteristics)>,
-        text_style_with_mirror: HashMap<u64, as_holder!(TextStyleWithMirror)>,
-        trimmed_curve: HashMap<u64, as_holder!(TrimmedCurve)>,
-        two_direction_repeat_factor: HashMap<u64, as_holder!(TwoDirectionRepeatFactor)>,
-        uniform_curve: HashMap<u64, as_holder!(UniformCurve)>,
-        vector: HashMap<u64, as_holder!(Vector)>,
-        approved_item: HashMap<u64, as_holder!(ApprovedItem)>,
-        area_or_view: HashMap<u64, as_holder!(AreaOrView)>,
-        axis2_placement: HashMap<u64, as_holder!(Axis2Placement)>,
-        box_characteristic_select: HashMap<u64, as_holder!(BoxCharacteristicSelect)>,
-        box_height: HashMap<u64, as_holder!(BoxHeight)>,
-        box_rotate_angle: HashMap<u64, as_holder!(BoxRotateAngle)>,
-        box_slant_angle: HashMap<u64, as_holder!(BoxSlantAngle)>,
-        box_width: HashMap<u64, as_holder!(BoxWidth)>,
-        character_spacing_select: HashMap<u64, as_holder!(CharacterSpacingSelect)>,
-        character_style_select: HashMap<u64, as_holder!(CharacterStyleSelect)>,
-        characterized_definition: HashMap<u64, as_holder!(CharacterizedDefinition)>,
-        characterized_product_definition: HashMap<u64, as_holder!(CharacterizedProductDefinition)>,
-        classified_item: HashMap<u64, as_holder!(ClassifiedItem)>,
-        contracted_item: HashMap<u64, as_holder!(ContractedItem)>,
+            HashMap<crate::tables::InstanceId, as_holder!(TextStyleWithBoxCharacteristics)>,
+        text_style_with_mirror: HashMap<crate::tables::InstanceId, as_holder!(TextStyleWithMirror)>,
+        #[table(validate)]
+        trimmed_curve: HashMap<crate::tables::InstanceId, as_holder!(TrimmedCurve)>,
+        #[table(validate)]
+        two_direction_repeat_factor:
+            HashMap<crate::tables::InstanceId, as_holder!(TwoDirectionRepeatFactor)>,
+        #[table(validate)]
+        uniform_curve: HashMap<crate::tables::InstanceId, as_holder!(UniformCurve)>,
+        #[table(validate)]
+        vector: HashMap<crate::tables::InstanceId, as_holder!(Vector)>,
+        approved_item: HashMap<crate::tables::InstanceId, as_holder!(ApprovedItem)>,
+        area_or_view: HashMap<crate::tables::InstanceId, as_holder!(AreaOrView)>,
+        axis2_placement: HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement)>,
+        box_characteristic_select:
+            HashMap<crate::tables::InstanceId, as_holder!(BoxCharacteristicSelect)>,
+        box_height: HashMap<crate::tables::InstanceId, as_holder!(BoxHeight)>,
+        box_rotate_angle: HashMap<crate::tables::InstanceId, as_holder!(BoxRotateAngle)>,
+        box_slant_angle: HashMap<crate::tables::InstanceId, as_holder!(BoxSlantAngle)>,
+        box_width: HashMap<crate::tables::InstanceId, as_holder!(BoxWidth)>,
+        character_spacing_select:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterSpacingSelect)>,
+        character_style_select:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterStyleSelect)>,
+        characterized_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterizedDefinition)>,
+        characterized_product_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterizedProductDefinition)>,
+        classified_item: HashMap<crate::tables::InstanceId, as_holder!(ClassifiedItem)>,
+        contracted_item: HashMap<crate::tables::InstanceId, as_holder!(ContractedItem)>,
         curve_font_or_scaled_curve_font_select:
-            HashMap<u64, as_holder!(CurveFontOrScaledCurveFontSelect)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CurveFontOrScaledCurveFontSelect)>,
         curve_or_annotation_curve_occurrence:
-            HashMap<u64, as_holder!(CurveOrAnnotationCurveOccurrence)>,
-        curve_or_render: HashMap<u64, as_holder!(CurveOrRender)>,
-        curve_style_font_select: HashMap<u64, as_holder!(CurveStyleFontSelect)>,
-        date_time_select: HashMap<u64, as_holder!(DateTimeSelect)>,
-        day_in_month_number: HashMap<u64, as_holder!(DayInMonthNumber)>,
-        defined_symbol_select: HashMap<u64, as_holder!(DefinedSymbolSelect)>,
-        dimension_count: HashMap<u64, as_holder!(DimensionCount)>,
-        draughting_callout_element: HashMap<u64, as_holder!(DraughtingCalloutElement)>,
-        draughting_grouped_item: HashMap<u64, as_holder!(DraughtingGroupedItem)>,
-        draughting_organization_item: HashMap<u64, as_holder!(DraughtingOrganizationItem)>,
-        draughting_presented_item_select: HashMap<u64, as_holder!(DraughtingPresentedItemSelect)>,
-        draughting_titled_item: HashMap<u64, as_holder!(DraughtingTitledItem)>,
-        fill_area_style_tile_shape_select: HashMap<u64, as_holder!(FillAreaStyleTileShapeSelect)>,
-        fill_style_select: HashMap<u64, as_holder!(FillStyleSelect)>,
-        font_select: HashMap<u64, as_holder!(FontSelect)>,
-        geometric_set_select: HashMap<u64, as_holder!(GeometricSetSelect)>,
-        hiding_or_blanking_select: HashMap<u64, as_holder!(HidingOrBlankingSelect)>,
-        identifier: HashMap<u64, as_holder!(Identifier)>,
-        invisibility_context: HashMap<u64, as_holder!(InvisibilityContext)>,
-        invisible_item: HashMap<u64, as_holder!(InvisibleItem)>,
-        label: HashMap<u64, as_holder!(Label)>,
-        layered_item: HashMap<u64, as_holder!(LayeredItem)>,
-        length_measure: HashMap<u64, as_holder!(LengthMeasure)>,
-        measure_value: HashMap<u64, as_holder!(MeasureValue)>,
-        month_in_year_number: HashMap<u64, as_holder!(MonthInYearNumber)>,
-        parameter_value: HashMap<u64, as_holder!(ParameterValue)>,
-        person_organization_select: HashMap<u64, as_holder!(PersonOrganizationSelect)>,
-        plane_angle_measure: HashMap<u64, as_holder!(PlaneAngleMeasure)>,
-        positive_length_measure: HashMap<u64, as_holder!(PositiveLengthMeasure)>,
-        positive_ratio_measure: HashMap<u64, as_holder!(PositiveRatioMeasure)>,
-        presentable_text: HashMap<u64, as_holder!(PresentableText)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CurveOrAnnotationCurveOccurrence)>,
+        curve_or_render: HashMap<crate::tables::InstanceId, as_holder!(CurveOrRender)>,
+        curve_style_font_select:
+            HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFontSelect)>,
+        date_time_select: HashMap<crate::tables::InstanceId, as_holder!(DateTimeSelect)>,
+        day_in_month_number: HashMap<crate::tables::InstanceId, as_holder!(DayInMonthNumber)>,
+        defined_symbol_select: HashMap<crate::tables::InstanceId, as_holder!(DefinedSymbolSelect)>,
+        dimension_count: HashMap<crate::tables::InstanceId, as_holder!(DimensionCount)>,
+        draughting_callout_element:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingCalloutElement)>,
+        draughting_grouped_item:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingGroupedItem)>,
+        draughting_organization_item:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingOrganizationItem)>,
+        draughting_presented_item_select:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingPresentedItemSelect)>,
+        draughting_titled_item:
+            HashMap<crate::tables::InstanceId, as_holder!(DraughtingTitledItem)>,
+        fill_area_style_tile_shape_select:
+            HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTileShapeSelect)>,
+        fill_style_select: HashMap<crate::tables::InstanceId, as_holder!(FillStyleSelect)>,
+        font_select: HashMap<crate::tables::InstanceId, as_holder!(FontSelect)>,
+        geometric_set_select: HashMap<crate::tables::InstanceId, as_holder!(GeometricSetSelect)>,
+        hiding_or_blanking_select:
+            HashMap<crate::tables::InstanceId, as_holder!(HidingOrBlankingSelect)>,
+        identifier: HashMap<crate::tables::InstanceId, as_holder!(Identifier)>,
+        invisibility_context: HashMap<crate::tables::InstanceId, as_holder!(InvisibilityContext)>,
+        invisible_item: HashMap<crate::tables::InstanceId, as_holder!(InvisibleItem)>,
+        label: HashMap<crate::tables::InstanceId, as_holder!(Label)>,
+        layered_item: HashMap<crate::tables::InstanceId, as_holder!(LayeredItem)>,
+        length_measure: HashMap<crate::tables::InstanceId, as_holder!(LengthMeasure)>,
+        measure_value: HashMap<crate::tables::InstanceId, as_holder!(MeasureValue)>,
+        month_in_year_number: HashMap<crate::tables::InstanceId, as_holder!(MonthInYearNumber)>,
+        parameter_value: HashMap<crate::tables::InstanceId, as_holder!(ParameterValue)>,
+        person_organization_select:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationSelect)>,
+        plane_angle_measure: HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasure)>,
+        positive_length_measure:
+            HashMap<crate::tables::InstanceId, as_holder!(PositiveLengthMeasure)>,
+        positive_ratio_measure:
+            HashMap<crate::tables::InstanceId, as_holder!(PositiveRatioMeasure)>,
+        presentable_text: HashMap<crate::tables::InstanceId, as_holder!(PresentableText)>,
         presentation_representation_select:
-            HashMap<u64, as_holder!(PresentationRepresentationSelect)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationRepresentationSelect)>,
         presentation_size_assignment_select:
-            HashMap<u64, as_holder!(PresentationSizeAssignmentSelect)>,
-        presentation_style_select: HashMap<u64, as_holder!(PresentationStyleSelect)>,
-        ratio_measure: HashMap<u64, as_holder!(RatioMeasure)>,
-        shape_definition: HashMap<u64, as_holder!(ShapeDefinition)>,
-        size_select: HashMap<u64, as_holder!(SizeSelect)>,
-        source_item: HashMap<u64, as_holder!(SourceItem)>,
-        specified_item: HashMap<u64, as_holder!(SpecifiedItem)>,
-        style_context_select: HashMap<u64, as_holder!(StyleContextSelect)>,
-        symbol_style_select: HashMap<u64, as_holder!(SymbolStyleSelect)>,
-        text: HashMap<u64, as_holder!(Text)>,
-        text_alignment: HashMap<u64, as_holder!(TextAlignment)>,
-        text_delineation: HashMap<u64, as_holder!(TextDelineation)>,
-        text_or_character: HashMap<u64, as_holder!(TextOrCharacter)>,
-        trimming_select: HashMap<u64, as_holder!(TrimmingSelect)>,
-        unit: HashMap<u64, as_holder!(Unit)>,
-        vector_or_direction: HashMap<u64, as_holder!(VectorOrDirection)>,
-        year_number: HashMap<u64, as_holder!(YearNumber)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationSizeAssignmentSelect)>,
+        presentation_style_select:
+            HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleSelect)>,
+        ratio_measure: HashMap<crate::tables::InstanceId, as_holder!(RatioMeasure)>,
+        shape_definition: HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinition)>,
+        size_select: HashMap<crate::tables::InstanceId, as_holder!(SizeSelect)>,
+        source_item: HashMap<crate::tables::InstanceId, as_holder!(SourceItem)>,
+        specified_item: HashMap<crate::tables::InstanceId, as_holder!(SpecifiedItem)>,
+        style_context_select: HashMap<crate::tables::InstanceId, as_holder!(StyleContextSelect)>,
+        symbol_style_select: HashMap<crate::tables::InstanceId, as_holder!(SymbolStyleSelect)>,
+        text: HashMap<crate::tables::InstanceId, as_holder!(Text)>,
+        text_alignment: HashMap<crate::tables::InstanceId, as_holder!(TextAlignment)>,
+        text_delineation: HashMap<crate::tables::InstanceId, as_holder!(TextDelineation)>,
+        text_or_character: HashMap<crate::tables::InstanceId, as_holder!(TextOrCharacter)>,
+        trimming_select: HashMap<crate::tables::InstanceId, as_holder!(TrimmingSelect)>,
+        unit: HashMap<crate::tables::InstanceId, as_holder!(Unit)>,
+        vector_or_direction: HashMap<crate::tables::InstanceId, as_holder!(VectorOrDirection)>,
+        year_number: HashMap<crate::tables::InstanceId, as_holder!(YearNumber)>,
     }
     impl Tables {
-        pub fn address_holders(&self) -> &HashMap<u64, as_holder!(Address)> {
+        #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+        pub const SCHEMA_IDENTIFIER: &str = "EXPLICIT_DRAUGHTING";
+        pub fn address_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Address)> {
             &self.address
         }
-        pub fn angular_dimension_holders(&self) -> &HashMap<u64, as_holder!(AngularDimension)> {
+        pub fn angular_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AngularDimension)> {
             &self.angular_dimension
         }
         pub fn annotation_curve_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationCurveOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationCurveOccurrence)> {
             &self.annotation_curve_occurrence
         }
         pub fn annotation_fill_area_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationFillArea)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationFillArea)> {
             &self.annotation_fill_area
         }
         pub fn annotation_fill_area_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationFillAreaOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationFillAreaOccurrence)> {
             &self.annotation_fill_area_occurrence
         }
         pub fn annotation_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationOccurrence)> {
             &self.annotation_occurrence
         }
         pub fn annotation_subfigure_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationSubfigureOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationSubfigureOccurrence)>
+        {
             &self.annotation_subfigure_occurrence
         }
-        pub fn annotation_symbol_holders(&self) -> &HashMap<u64, as_holder!(AnnotationSymbol)> {
+        pub fn annotation_symbol_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationSymbol)> {
             &self.annotation_symbol
         }
         pub fn annotation_symbol_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationSymbolOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationSymbolOccurrence)> {
             &self.annotation_symbol_occurrence
         }
-        pub fn annotation_text_holders(&self) -> &HashMap<u64, as_holder!(AnnotationText)> {
+        pub fn annotation_text_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationText)> {
             &self.annotation_text
         }
         pub fn annotation_text_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AnnotationTextOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AnnotationTextOccurrence)> {
             &self.annotation_text_occurrence
         }
-        pub fn application_context_holders(&self) -> &HashMap<u64, as_holder!(ApplicationContext)> {
+        pub fn application_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationContext)> {
             &self.application_context
         }
         pub fn application_context_element_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApplicationContextElement)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationContextElement)> {
             &self.application_context_element
         }
         pub fn application_protocol_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApplicationProtocolDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationProtocolDefinition)>
+        {
             &self.application_protocol_definition
         }
-        pub fn approval_holders(&self) -> &HashMap<u64, as_holder!(Approval)> {
+        pub fn approval_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Approval)> {
             &self.approval
         }
-        pub fn approval_assignment_holders(&self) -> &HashMap<u64, as_holder!(ApprovalAssignment)> {
+        pub fn approval_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalAssignment)> {
             &self.approval_assignment
         }
-        pub fn approval_date_time_holders(&self) -> &HashMap<u64, as_holder!(ApprovalDateTime)> {
+        pub fn approval_date_time_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalDateTime)> {
             &self.approval_date_time
         }
         pub fn approval_person_organization_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApprovalPersonOrganization)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalPersonOrganization)> {
             &self.approval_person_organization
         }
-        pub fn approval_role_holders(&self) -> &HashMap<u64, as_holder!(ApprovalRole)> {
+        pub fn approval_role_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalRole)> {
             &self.approval_role
         }
-        pub fn approval_status_holders(&self) -> &HashMap<u64, as_holder!(ApprovalStatus)> {
+        pub fn approval_status_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalStatus)> {
             &self.approval_status
         }
-        pub fn area_in_set_holders(&self) -> &HashMap<u64, as_holder!(AreaInSet)> {
+        pub fn area_in_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AreaInSet)> {
             &self.area_in_set
         }
-        pub fn axis2_placement_2d_holders(&self) -> &HashMap<u64, as_holder!(Axis2Placement2D)> {
+        pub fn axis2_placement_2d_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement2D)> {
             &self.axis2_placement_2d
         }
-        pub fn b_spline_curve_holders(&self) -> &HashMap<u64, as_holder!(BSplineCurve)> {
+        pub fn b_spline_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineCurve)> {
             &self.b_spline_curve
         }
         pub fn b_spline_curve_with_knots_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(BSplineCurveWithKnots)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineCurveWithKnots)> {
             &self.b_spline_curve_with_knots
         }
-        pub fn bezier_curve_holders(&self) -> &HashMap<u64, as_holder!(BezierCurve)> {
+        pub fn bezier_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BezierCurve)> {
             &self.bezier_curve
         }
-        pub fn bounded_curve_holders(&self) -> &HashMap<u64, as_holder!(BoundedCurve)> {
+        pub fn bounded_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundedCurve)> {
             &self.bounded_curve
         }
-        pub fn calendar_date_holders(&self) -> &HashMap<u64, as_holder!(CalendarDate)> {
+        pub fn calendar_date_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CalendarDate)> {
             &self.calendar_date
         }
-        pub fn camera_image_holders(&self) -> &HashMap<u64, as_holder!(CameraImage)> {
+        pub fn camera_image_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CameraImage)> {
             &self.camera_image
         }
         pub fn camera_image_2d_with_scale_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CameraImage2DWithScale)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CameraImage2DWithScale)> {
             &self.camera_image_2d_with_scale
         }
-        pub fn camera_model_holders(&self) -> &HashMap<u64, as_holder!(CameraModel)> {
+        pub fn camera_model_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CameraModel)> {
             &self.camera_model
         }
-        pub fn camera_model_d2_holders(&self) -> &HashMap<u64, as_holder!(CameraModelD2)> {
+        pub fn camera_model_d2_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CameraModelD2)> {
             &self.camera_model_d2
         }
-        pub fn camera_usage_holders(&self) -> &HashMap<u64, as_holder!(CameraUsage)> {
+        pub fn camera_usage_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CameraUsage)> {
             &self.camera_usage
         }
-        pub fn cartesian_point_holders(&self) -> &HashMap<u64, as_holder!(CartesianPoint)> {
+        pub fn cartesian_point_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CartesianPoint)> {
             &self.cartesian_point
         }
-        pub fn circle_holders(&self) -> &HashMap<u64, as_holder!(Circle)> {
+        pub fn circle_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Circle)> {
             &self.circle
         }
-        pub fn colour_holders(&self) -> &HashMap<u64, as_holder!(Colour)> {
+        pub fn colour_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Colour)> {
             &self.colour
         }
-        pub fn colour_rgb_holders(&self) -> &HashMap<u64, as_holder!(ColourRgb)> {
+        pub fn colour_rgb_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ColourRgb)> {
             &self.colour_rgb
         }
         pub fn colour_specification_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ColourSpecification)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ColourSpecification)> {
             &self.colour_specification
         }
-        pub fn composite_curve_holders(&self) -> &HashMap<u64, as_holder!(CompositeCurve)> {
+        pub fn composite_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeCurve)> {
             &self.composite_curve
         }
         pub fn composite_curve_segment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeCurveSegment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveSegment)> {
             &self.composite_curve_segment
         }
-        pub fn composite_text_holders(&self) -> &HashMap<u64, as_holder!(CompositeText)> {
+        pub fn composite_text_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeText)> {
             &self.composite_text
         }
         pub fn composite_text_with_associated_curves_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeTextWithAssociatedCurves)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithAssociatedCurves)>
+        {
             &self.composite_text_with_associated_curves
         }
         pub fn composite_text_with_blanking_box_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeTextWithBlankingBox)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithBlankingBox)> {
             &self.composite_text_with_blanking_box
         }
         pub fn composite_text_with_extent_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeTextWithExtent)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeTextWithExtent)> {
             &self.composite_text_with_extent
         }
-        pub fn conic_holders(&self) -> &HashMap<u64, as_holder!(Conic)> {
+        pub fn conic_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Conic)> {
             &self.conic
         }
         pub fn context_dependent_invisibility_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ContextDependentInvisibility)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContextDependentInvisibility)> {
             &self.context_dependent_invisibility
         }
-        pub fn contract_holders(&self) -> &HashMap<u64, as_holder!(Contract)> {
+        pub fn contract_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Contract)> {
             &self.contract
         }
-        pub fn contract_assignment_holders(&self) -> &HashMap<u64, as_holder!(ContractAssignment)> {
+        pub fn contract_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractAssignment)> {
             &self.contract_assignment
         }
-        pub fn contract_type_holders(&self) -> &HashMap<u64, as_holder!(ContractType)> {
+        pub fn contract_type_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractType)> {
             &self.contract_type
         }
         pub fn conversion_based_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ConversionBasedUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConversionBasedUnit)> {
             &self.conversion_based_unit
         }
-        pub fn curve_holders(&self) -> &HashMap<u64, as_holder!(Curve)> {
+        pub fn curve_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Curve)> {
             &self.curve
         }
-        pub fn curve_dimension_holders(&self) -> &HashMap<u64, as_holder!(CurveDimension)> {
+        pub fn curve_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveDimension)> {
             &self.curve_dimension
         }
-        pub fn curve_style_holders(&self) -> &HashMap<u64, as_holder!(CurveStyle)> {
+        pub fn curve_style_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveStyle)> {
             &self.curve_style
         }
-        pub fn curve_style_font_holders(&self) -> &HashMap<u64, as_holder!(CurveStyleFont)> {
+        pub fn curve_style_font_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFont)> {
             &self.curve_style_font
         }
         pub fn curve_style_font_pattern_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CurveStyleFontPattern)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFontPattern)> {
             &self.curve_style_font_pattern
         }
-        pub fn date_holders(&self) -> &HashMap<u64, as_holder!(Date)> {
+        pub fn date_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Date)> {
             &self.date
         }
         pub fn datum_feature_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DatumFeatureCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DatumFeatureCallout)> {
             &self.datum_feature_callout
         }
         pub fn datum_target_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DatumTargetCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DatumTargetCallout)> {
             &self.datum_target_callout
         }
-        pub fn defined_symbol_holders(&self) -> &HashMap<u64, as_holder!(DefinedSymbol)> {
+        pub fn defined_symbol_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DefinedSymbol)> {
             &self.defined_symbol
         }
-        pub fn diameter_dimension_holders(&self) -> &HashMap<u64, as_holder!(DiameterDimension)> {
+        pub fn diameter_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DiameterDimension)> {
             &self.diameter_dimension
         }
         pub fn dimension_callout_component_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionCalloutComponentRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCalloutComponentRelationship)>
+        {
             &self.dimension_callout_component_relationship
         }
         pub fn dimension_callout_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionCalloutRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCalloutRelationship)> {
             &self.dimension_callout_relationship
         }
-        pub fn dimension_curve_holders(&self) -> &HashMap<u64, as_holder!(DimensionCurve)> {
+        pub fn dimension_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCurve)> {
             &self.dimension_curve
         }
         pub fn dimension_curve_directed_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionCurveDirectedCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCurveDirectedCallout)>
+        {
             &self.dimension_curve_directed_callout
         }
         pub fn dimension_curve_terminator_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionCurveTerminator)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCurveTerminator)> {
             &self.dimension_curve_terminator
         }
-        pub fn dimension_pair_holders(&self) -> &HashMap<u64, as_holder!(DimensionPair)> {
+        pub fn dimension_pair_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionPair)> {
             &self.dimension_pair
         }
         pub fn dimensional_exponents_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionalExponents)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionalExponents)> {
             &self.dimensional_exponents
         }
-        pub fn direction_holders(&self) -> &HashMap<u64, as_holder!(Direction)> {
+        pub fn direction_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Direction)> {
             &self.direction
         }
-        pub fn document_holders(&self) -> &HashMap<u64, as_holder!(Document)> {
+        pub fn document_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Document)> {
             &self.document
         }
-        pub fn document_reference_holders(&self) -> &HashMap<u64, as_holder!(DocumentReference)> {
+        pub fn document_reference_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentReference)> {
             &self.document_reference
         }
-        pub fn document_type_holders(&self) -> &HashMap<u64, as_holder!(DocumentType)> {
+        pub fn document_type_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentType)> {
             &self.document_type
         }
         pub fn draughting_annotation_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingAnnotationOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingAnnotationOccurrence)>
+        {
             &self.draughting_annotation_occurrence
         }
         pub fn draughting_approval_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingApprovalAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingApprovalAssignment)> {
             &self.draughting_approval_assignment
         }
-        pub fn draughting_callout_holders(&self) -> &HashMap<u64, as_holder!(DraughtingCallout)> {
+        pub fn draughting_callout_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingCallout)> {
             &self.draughting_callout
         }
         pub fn draughting_callout_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingCalloutRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingCalloutRelationship)>
+        {
             &self.draughting_callout_relationship
         }
         pub fn draughting_contract_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingContractAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingContractAssignment)> {
             &self.draughting_contract_assignment
         }
         pub fn draughting_drawing_revision_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingDrawingRevision)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingDrawingRevision)> {
             &self.draughting_drawing_revision
         }
-        pub fn draughting_elements_holders(&self) -> &HashMap<u64, as_holder!(DraughtingElements)> {
+        pub fn draughting_elements_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingElements)> {
             &self.draughting_elements
         }
         pub fn draughting_group_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingGroupAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingGroupAssignment)> {
             &self.draughting_group_assignment
         }
-        pub fn draughting_model_holders(&self) -> &HashMap<u64, as_holder!(DraughtingModel)> {
+        pub fn draughting_model_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingModel)> {
             &self.draughting_model
         }
         pub fn draughting_organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingOrganizationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingOrganizationAssignment)>
+        {
             &self.draughting_organization_assignment
         }
         pub fn draughting_person_and_organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPersonAndOrganizationAssignment)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(DraughtingPersonAndOrganizationAssignment),
+        > {
             &self.draughting_person_and_organization_assignment
         }
         pub fn draughting_person_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPersonAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPersonAssignment)> {
             &self.draughting_person_assignment
         }
         pub fn draughting_pre_defined_colour_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPreDefinedColour)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedColour)> {
             &self.draughting_pre_defined_colour
         }
         pub fn draughting_pre_defined_curve_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPreDefinedCurveFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedCurveFont)>
+        {
             &self.draughting_pre_defined_curve_font
         }
         pub fn draughting_pre_defined_text_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPreDefinedTextFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPreDefinedTextFont)> {
             &self.draughting_pre_defined_text_font
         }
         pub fn draughting_presented_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPresentedItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPresentedItem)> {
             &self.draughting_presented_item
         }
         pub fn draughting_security_classification_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingSecurityClassificationAssignment)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(DraughtingSecurityClassificationAssignment),
+        > {
             &self.draughting_security_classification_assignment
         }
         pub fn draughting_specification_reference_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingSpecificationReference)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingSpecificationReference)>
+        {
             &self.draughting_specification_reference
         }
         pub fn draughting_subfigure_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingSubfigureRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingSubfigureRepresentation)>
+        {
             &self.draughting_subfigure_representation
         }
         pub fn draughting_symbol_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingSymbolRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingSymbolRepresentation)>
+        {
             &self.draughting_symbol_representation
         }
         pub fn draughting_text_literal_with_delineation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingTextLiteralWithDelineation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingTextLiteralWithDelineation)>
+        {
             &self.draughting_text_literal_with_delineation
         }
-        pub fn draughting_title_holders(&self) -> &HashMap<u64, as_holder!(DraughtingTitle)> {
+        pub fn draughting_title_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingTitle)> {
             &self.draughting_title
         }
-        pub fn drawing_definition_holders(&self) -> &HashMap<u64, as_holder!(DrawingDefinition)> {
+        pub fn drawing_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DrawingDefinition)> {
             &self.drawing_definition
         }
-        pub fn drawing_revision_holders(&self) -> &HashMap<u64, as_holder!(DrawingRevision)> {
+        pub fn drawing_revision_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DrawingRevision)> {
             &self.drawing_revision
         }
         pub fn drawing_sheet_layout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DrawingSheetLayout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetLayout)> {
             &self.drawing_sheet_layout
         }
         pub fn drawing_sheet_revision_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DrawingSheetRevision)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetRevision)> {
             &self.drawing_sheet_revision
         }
         pub fn drawing_sheet_revision_usage_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DrawingSheetRevisionUsage)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DrawingSheetRevisionUsage)> {
             &self.drawing_sheet_revision_usage
         }
-        pub fn ellipse_holders(&self) -> &HashMap<u64, as_holder!(Ellipse)> {
+        pub fn ellipse_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Ellipse)> {
             &self.ellipse
         }
-        pub fn external_source_holders(&self) -> &HashMap<u64, as_holder!(ExternalSource)> {
+        pub fn external_source_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternalSource)> {
             &self.external_source
         }
         pub fn externally_defined_curve_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedCurveFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedCurveFont)> {
             &self.externally_defined_curve_font
         }
         pub fn externally_defined_hatch_style_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedHatchStyle)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedHatchStyle)> {
             &self.externally_defined_hatch_style
         }
         pub fn externally_defined_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedItem)> {
             &self.externally_defined_item
         }
         pub fn externally_defined_symbol_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedSymbol)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedSymbol)> {
             &self.externally_defined_symbol
         }
         pub fn externally_defined_text_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedTextFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedTextFont)> {
             &self.externally_defined_text_font
         }
         pub fn externally_defined_tile_style_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ExternallyDefinedTileStyle)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExternallyDefinedTileStyle)> {
             &self.externally_defined_tile_style
         }
-        pub fn fill_area_style_holders(&self) -> &HashMap<u64, as_holder!(FillAreaStyle)> {
+        pub fn fill_area_style_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyle)> {
             &self.fill_area_style
         }
         pub fn fill_area_style_colour_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FillAreaStyleColour)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleColour)> {
             &self.fill_area_style_colour
         }
         pub fn fill_area_style_hatching_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FillAreaStyleHatching)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleHatching)> {
             &self.fill_area_style_hatching
         }
         pub fn fill_area_style_tile_symbol_with_style_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FillAreaStyleTileSymbolWithStyle)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTileSymbolWithStyle)>
+        {
             &self.fill_area_style_tile_symbol_with_style
         }
         pub fn fill_area_style_tiles_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FillAreaStyleTiles)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTiles)> {
             &self.fill_area_style_tiles
         }
-        pub fn geometric_curve_set_holders(&self) -> &HashMap<u64, as_holder!(GeometricCurveSet)> {
+        pub fn geometric_curve_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricCurveSet)> {
             &self.geometric_curve_set
         }
         pub fn geometric_representation_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricRepresentationContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationContext)>
+        {
             &self.geometric_representation_context
         }
         pub fn geometric_representation_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricRepresentationItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationItem)> {
             &self.geometric_representation_item
         }
-        pub fn geometric_set_holders(&self) -> &HashMap<u64, as_holder!(GeometricSet)> {
+        pub fn geometric_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricSet)> {
             &self.geometric_set
         }
         pub fn geometrical_tolerance_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricalToleranceCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricalToleranceCallout)> {
             &self.geometrical_tolerance_callout
         }
         pub fn geometrically_bounded_2d_wireframe_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricallyBounded2DWireframeRepresentation)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBounded2DWireframeRepresentation),
+        > {
             &self.geometrically_bounded_2d_wireframe_representation
         }
         pub fn global_unit_assigned_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GlobalUnitAssignedContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GlobalUnitAssignedContext)> {
             &self.global_unit_assigned_context
         }
-        pub fn group_holders(&self) -> &HashMap<u64, as_holder!(Group)> {
+        pub fn group_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Group)> {
             &self.group
         }
-        pub fn group_assignment_holders(&self) -> &HashMap<u64, as_holder!(GroupAssignment)> {
+        pub fn group_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GroupAssignment)> {
             &self.group_assignment
         }
-        pub fn group_relationship_holders(&self) -> &HashMap<u64, as_holder!(GroupRelationship)> {
+        pub fn group_relationship_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GroupRelationship)> {
             &self.group_relationship
         }
-        pub fn hyperbola_holders(&self) -> &HashMap<u64, as_holder!(Hyperbola)> {
+        pub fn hyperbola_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Hyperbola)> {
             &self.hyperbola
         }
-        pub fn invisibility_holders(&self) -> &HashMap<u64, as_holder!(Invisibility)> {
+        pub fn invisibility_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Invisibility)> {
             &self.invisibility
         }
-        pub fn leader_curve_holders(&self) -> &HashMap<u64, as_holder!(LeaderCurve)> {
+        pub fn leader_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LeaderCurve)> {
             &self.leader_curve
         }
         pub fn leader_directed_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(LeaderDirectedCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LeaderDirectedCallout)> {
             &self.leader_directed_callout
         }
         pub fn leader_directed_dimension_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(LeaderDirectedDimension)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LeaderDirectedDimension)> {
             &self.leader_directed_dimension
         }
-        pub fn leader_terminator_holders(&self) -> &HashMap<u64, as_holder!(LeaderTerminator)> {
+        pub fn leader_terminator_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LeaderTerminator)> {
             &self.leader_terminator
         }
         pub fn length_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(LengthMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthMeasureWithUnit)> {
             &self.length_measure_with_unit
         }
-        pub fn length_unit_holders(&self) -> &HashMap<u64, as_holder!(LengthUnit)> {
+        pub fn length_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthUnit)> {
             &self.length_unit
         }
-        pub fn line_holders(&self) -> &HashMap<u64, as_holder!(Line)> {
+        pub fn line_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Line)> {
             &self.line
         }
-        pub fn linear_dimension_holders(&self) -> &HashMap<u64, as_holder!(LinearDimension)> {
+        pub fn linear_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LinearDimension)> {
             &self.linear_dimension
         }
-        pub fn mapped_item_holders(&self) -> &HashMap<u64, as_holder!(MappedItem)> {
+        pub fn mapped_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MappedItem)> {
             &self.mapped_item
         }
-        pub fn measure_with_unit_holders(&self) -> &HashMap<u64, as_holder!(MeasureWithUnit)> {
+        pub fn measure_with_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MeasureWithUnit)> {
             &self.measure_with_unit
         }
-        pub fn named_unit_holders(&self) -> &HashMap<u64, as_holder!(NamedUnit)> {
+        pub fn named_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(NamedUnit)> {
             &self.named_unit
         }
-        pub fn offset_curve_2d_holders(&self) -> &HashMap<u64, as_holder!(OffsetCurve2D)> {
+        pub fn offset_curve_2d_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OffsetCurve2D)> {
             &self.offset_curve_2d
         }
         pub fn one_direction_repeat_factor_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OneDirectionRepeatFactor)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OneDirectionRepeatFactor)> {
             &self.one_direction_repeat_factor
         }
-        pub fn ordinate_dimension_holders(&self) -> &HashMap<u64, as_holder!(OrdinateDimension)> {
+        pub fn ordinate_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrdinateDimension)> {
             &self.ordinate_dimension
         }
-        pub fn organization_holders(&self) -> &HashMap<u64, as_holder!(Organization)> {
+        pub fn organization_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Organization)> {
             &self.organization
         }
         pub fn organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrganizationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationAssignment)> {
             &self.organization_assignment
         }
-        pub fn organization_role_holders(&self) -> &HashMap<u64, as_holder!(OrganizationRole)> {
+        pub fn organization_role_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationRole)> {
             &self.organization_role
         }
         pub fn organizational_address_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrganizationalAddress)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationalAddress)> {
             &self.organizational_address
         }
-        pub fn parabola_holders(&self) -> &HashMap<u64, as_holder!(Parabola)> {
+        pub fn parabola_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Parabola)> {
             &self.parabola
         }
-        pub fn person_holders(&self) -> &HashMap<u64, as_holder!(Person)> {
+        pub fn person_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Person)> {
             &self.person
         }
         pub fn person_and_organization_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganization)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganization)> {
             &self.person_and_organization
         }
         pub fn person_and_organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganizationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationAssignment)>
+        {
             &self.person_and_organization_assignment
         }
         pub fn person_and_organization_role_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganizationRole)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationRole)> {
             &self.person_and_organization_role
         }
-        pub fn person_assignment_holders(&self) -> &HashMap<u64, as_holder!(PersonAssignment)> {
+        pub fn person_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAssignment)> {
             &self.person_assignment
         }
-        pub fn person_role_holders(&self) -> &HashMap<u64, as_holder!(PersonRole)> {
+        pub fn person_role_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonRole)> {
             &self.person_role
         }
-        pub fn personal_address_holders(&self) -> &HashMap<u64, as_holder!(PersonalAddress)> {
+        pub fn personal_address_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonalAddress)> {
             &self.personal_address
         }
-        pub fn placement_holders(&self) -> &HashMap<u64, as_holder!(Placement)> {
+        pub fn placement_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Placement)> {
             &self.placement
         }
-        pub fn planar_box_holders(&self) -> &HashMap<u64, as_holder!(PlanarBox)> {
+        pub fn planar_box_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlanarBox)> {
             &self.planar_box
         }
-        pub fn planar_extent_holders(&self) -> &HashMap<u64, as_holder!(PlanarExtent)> {
+        pub fn planar_extent_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlanarExtent)> {
             &self.planar_extent
         }
         pub fn plane_angle_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PlaneAngleMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasureWithUnit)> {
             &self.plane_angle_measure_with_unit
         }
-        pub fn plane_angle_unit_holders(&self) -> &HashMap<u64, as_holder!(PlaneAngleUnit)> {
+        pub fn plane_angle_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleUnit)> {
             &self.plane_angle_unit
         }
-        pub fn point_holders(&self) -> &HashMap<u64, as_holder!(Point)> {
+        pub fn point_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Point)> {
             &self.point
         }
-        pub fn point_on_curve_holders(&self) -> &HashMap<u64, as_holder!(PointOnCurve)> {
+        pub fn point_on_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PointOnCurve)> {
             &self.point_on_curve
         }
-        pub fn polyline_holders(&self) -> &HashMap<u64, as_holder!(Polyline)> {
+        pub fn polyline_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Polyline)> {
             &self.polyline
         }
-        pub fn pre_defined_colour_holders(&self) -> &HashMap<u64, as_holder!(PreDefinedColour)> {
+        pub fn pre_defined_colour_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedColour)> {
             &self.pre_defined_colour
         }
         pub fn pre_defined_curve_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedCurveFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedCurveFont)> {
             &self.pre_defined_curve_font
         }
         pub fn pre_defined_dimension_symbol_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedDimensionSymbol)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedDimensionSymbol)> {
             &self.pre_defined_dimension_symbol
         }
         pub fn pre_defined_geometrical_tolerance_symbol_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedGeometricalToleranceSymbol)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedGeometricalToleranceSymbol)>
+        {
             &self.pre_defined_geometrical_tolerance_symbol
         }
-        pub fn pre_defined_item_holders(&self) -> &HashMap<u64, as_holder!(PreDefinedItem)> {
+        pub fn pre_defined_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedItem)> {
             &self.pre_defined_item
         }
         pub fn pre_defined_point_marker_symbol_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedPointMarkerSymbol)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedPointMarkerSymbol)> {
             &self.pre_defined_point_marker_symbol
         }
-        pub fn pre_defined_symbol_holders(&self) -> &HashMap<u64, as_holder!(PreDefinedSymbol)> {
+        pub fn pre_defined_symbol_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedSymbol)> {
             &self.pre_defined_symbol
         }
         pub fn pre_defined_terminator_symbol_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedTerminatorSymbol)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedTerminatorSymbol)> {
             &self.pre_defined_terminator_symbol
         }
         pub fn pre_defined_text_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PreDefinedTextFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PreDefinedTextFont)> {
             &self.pre_defined_text_font
         }
-        pub fn presentation_area_holders(&self) -> &HashMap<u64, as_holder!(PresentationArea)> {
+        pub fn presentation_area_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationArea)> {
             &self.presentation_area
         }
         pub fn presentation_layer_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationLayerAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationLayerAssignment)> {
             &self.presentation_layer_assignment
         }
         pub fn presentation_layer_usage_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationLayerUsage)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationLayerUsage)> {
             &self.presentation_layer_usage
         }
         pub fn presentation_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationRepresentation)> {
             &self.presentation_representation
         }
-        pub fn presentation_set_holders(&self) -> &HashMap<u64, as_holder!(PresentationSet)> {
+        pub fn presentation_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationSet)> {
             &self.presentation_set
         }
-        pub fn presentation_size_holders(&self) -> &HashMap<u64, as_holder!(PresentationSize)> {
+        pub fn presentation_size_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationSize)> {
             &self.presentation_size
         }
         pub fn presentation_style_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationStyleAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleAssignment)> {
             &self.presentation_style_assignment
         }
         pub fn presentation_style_by_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationStyleByContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleByContext)> {
             &self.presentation_style_by_context
         }
-        pub fn presentation_view_holders(&self) -> &HashMap<u64, as_holder!(PresentationView)> {
+        pub fn presentation_view_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationView)> {
             &self.presentation_view
         }
-        pub fn presented_item_holders(&self) -> &HashMap<u64, as_holder!(PresentedItem)> {
+        pub fn presented_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentedItem)> {
             &self.presented_item
         }
         pub fn presented_item_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentedItemRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentedItemRepresentation)> {
             &self.presented_item_representation
         }
-        pub fn product_holders(&self) -> &HashMap<u64, as_holder!(Product)> {
+        pub fn product_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Product)> {
             &self.product
         }
-        pub fn product_context_holders(&self) -> &HashMap<u64, as_holder!(ProductContext)> {
+        pub fn product_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductContext)> {
             &self.product_context
         }
-        pub fn product_definition_holders(&self) -> &HashMap<u64, as_holder!(ProductDefinition)> {
+        pub fn product_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinition)> {
             &self.product_definition
         }
         pub fn product_definition_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionContext)> {
             &self.product_definition_context
         }
         pub fn product_definition_formation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionFormation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionFormation)> {
             &self.product_definition_formation
         }
         pub fn product_definition_shape_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionShape)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionShape)> {
             &self.product_definition_shape
         }
-        pub fn projection_curve_holders(&self) -> &HashMap<u64, as_holder!(ProjectionCurve)> {
+        pub fn projection_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProjectionCurve)> {
             &self.projection_curve
         }
         pub fn projection_directed_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProjectionDirectedCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProjectionDirectedCallout)> {
             &self.projection_directed_callout
         }
-        pub fn property_definition_holders(&self) -> &HashMap<u64, as_holder!(PropertyDefinition)> {
+        pub fn property_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinition)> {
             &self.property_definition
         }
         pub fn property_definition_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PropertyDefinitionRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinitionRepresentation)>
+        {
             &self.property_definition_representation
         }
-        pub fn quasi_uniform_curve_holders(&self) -> &HashMap<u64, as_holder!(QuasiUniformCurve)> {
+        pub fn quasi_uniform_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformCurve)> {
             &self.quasi_uniform_curve
         }
-        pub fn radius_dimension_holders(&self) -> &HashMap<u64, as_holder!(RadiusDimension)> {
+        pub fn radius_dimension_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RadiusDimension)> {
             &self.radius_dimension
         }
         pub fn rational_b_spline_curve_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RationalBSplineCurve)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineCurve)> {
             &self.rational_b_spline_curve
         }
-        pub fn representation_holders(&self) -> &HashMap<u64, as_holder!(Representation)> {
+        pub fn representation_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Representation)> {
             &self.representation
         }
         pub fn representation_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RepresentationContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationContext)> {
             &self.representation_context
         }
-        pub fn representation_item_holders(&self) -> &HashMap<u64, as_holder!(RepresentationItem)> {
+        pub fn representation_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationItem)> {
             &self.representation_item
         }
-        pub fn representation_map_holders(&self) -> &HashMap<u64, as_holder!(RepresentationMap)> {
+        pub fn representation_map_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationMap)> {
             &self.representation_map
         }
         pub fn security_classification_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassification)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassification)> {
             &self.security_classification
         }
         pub fn security_classification_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassificationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationAssignment)>
+        {
             &self.security_classification_assignment
         }
         pub fn security_classification_level_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassificationLevel)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationLevel)> {
             &self.security_classification_level
         }
         pub fn shape_definition_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeDefinitionRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinitionRepresentation)>
+        {
             &self.shape_definition_representation
         }
         pub fn shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentation)> {
             &self.shape_representation
         }
-        pub fn si_unit_holders(&self) -> &HashMap<u64, as_holder!(SiUnit)> {
+        pub fn si_unit_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(SiUnit)> {
             &self.si_unit
         }
         pub fn structured_dimension_callout_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(StructuredDimensionCallout)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StructuredDimensionCallout)> {
             &self.structured_dimension_callout
         }
-        pub fn styled_item_holders(&self) -> &HashMap<u64, as_holder!(StyledItem)> {
+        pub fn styled_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StyledItem)> {
             &self.styled_item
         }
-        pub fn symbol_colour_holders(&self) -> &HashMap<u64, as_holder!(SymbolColour)> {
+        pub fn symbol_colour_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolColour)> {
             &self.symbol_colour
         }
         pub fn symbol_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SymbolRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolRepresentation)> {
             &self.symbol_representation
         }
         pub fn symbol_representation_map_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SymbolRepresentationMap)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolRepresentationMap)> {
             &self.symbol_representation_map
         }
-        pub fn symbol_style_holders(&self) -> &HashMap<u64, as_holder!(SymbolStyle)> {
+        pub fn symbol_style_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolStyle)> {
             &self.symbol_style
         }
-        pub fn symbol_target_holders(&self) -> &HashMap<u64, as_holder!(SymbolTarget)> {
+        pub fn symbol_target_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolTarget)> {
             &self.symbol_target
         }
-        pub fn terminator_symbol_holders(&self) -> &HashMap<u64, as_holder!(TerminatorSymbol)> {
+        pub fn terminator_symbol_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TerminatorSymbol)> {
             &self.terminator_symbol
         }
-        pub fn text_literal_holders(&self) -> &HashMap<u64, as_holder!(TextLiteral)> {
+        pub fn text_literal_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextLiteral)> {
             &self.text_literal
         }
         pub fn text_literal_with_associated_curves_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextLiteralWithAssociatedCurves)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithAssociatedCurves)>
+        {
             &self.text_literal_with_associated_curves
         }
         pub fn text_literal_with_blanking_box_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextLiteralWithBlankingBox)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithBlankingBox)> {
             &self.text_literal_with_blanking_box
         }
         pub fn text_literal_with_delineation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextLiteralWithDelineation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithDelineation)> {
             &self.text_literal_with_delineation
         }
         pub fn text_literal_with_extent_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextLiteralWithExtent)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextLiteralWithExtent)> {
             &self.text_literal_with_extent
         }
-        pub fn text_style_holders(&self) -> &HashMap<u64, as_holder!(TextStyle)> {
+        pub fn text_style_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextStyle)> {
             &self.text_style
         }
         pub fn text_style_for_defined_font_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextStyleForDefinedFont)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextStyleForDefinedFont)> {
             &self.text_style_for_defined_font
         }
         pub fn text_style_with_box_characteristics_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextStyleWithBoxCharacteristics)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextStyleWithBoxCharacteristics)>
+        {
             &self.text_style_with_box_characteristics
         }
         pub fn text_style_with_mirror_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TextStyleWithMirror)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextStyleWithMirror)> {
             &self.text_style_with_mirror
         }
-        pub fn trimmed_curve_holders(&self) -> &HashMap<u64, as_holder!(TrimmedCurve)> {
+        pub fn trimmed_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TrimmedCurve)> {
             &self.trimmed_curve
         }
         pub fn two_direction_repeat_factor_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TwoDirectionRepeatFactor)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TwoDirectionRepeatFactor)> {
             &self.two_direction_repeat_factor
         }
-        pub fn uniform_curve_holders(&self) -> &HashMap<u64, as_holder!(UniformCurve)> {
+        pub fn uniform_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(UniformCurve)> {
             &self.uniform_curve
         }
-        pub fn vector_holders(&self) -> &HashMap<u64, as_holder!(Vector)> {
+        pub fn vector_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Vector)> {
             &self.vector
         }
-        pub fn approved_item_holders(&self) -> &HashMap<u64, as_holder!(ApprovedItem)> {
+        pub fn approved_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovedItem)> {
             &self.approved_item
         }
-        pub fn area_or_view_holders(&self) -> &HashMap<u64, as_holder!(AreaOrView)> {
+        pub fn area_or_view_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AreaOrView)> {
             &self.area_or_view
         }
-        pub fn axis2_placement_holders(&self) -> &HashMap<u64, as_holder!(Axis2Placement)> {
+        pub fn axis2_placement_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement)> {
             &self.axis2_placement
         }
         pub fn box_characteristic_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(BoxCharacteristicSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoxCharacteristicSelect)> {
             &self.box_characteristic_select
         }
-        pub fn box_height_holders(&self) -> &HashMap<u64, as_holder!(BoxHeight)> {
+        pub fn box_height_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoxHeight)> {
             &self.box_height
         }
-        pub fn box_rotate_angle_holders(&self) -> &HashMap<u64, as_holder!(BoxRotateAngle)> {
+        pub fn box_rotate_angle_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoxRotateAngle)> {
             &self.box_rotate_angle
         }
-        pub fn box_slant_angle_holders(&self) -> &HashMap<u64, as_holder!(BoxSlantAngle)> {
+        pub fn box_slant_angle_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoxSlantAngle)> {
             &self.box_slant_angle
         }
-        pub fn box_width_holders(&self) -> &HashMap<u64, as_holder!(BoxWidth)> {
+        pub fn box_width_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoxWidth)> {
             &self.box_width
         }
         pub fn character_spacing_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterSpacingSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterSpacingSelect)> {
             &self.character_spacing_select
         }
         pub fn character_style_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterStyleSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterStyleSelect)> {
             &self.character_style_select
         }
         pub fn characterized_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterizedDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterizedDefinition)> {
             &self.characterized_definition
         }
         pub fn characterized_product_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterizedProductDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterizedProductDefinition)>
+        {
             &self.characterized_product_definition
         }
-        pub fn classified_item_holders(&self) -> &HashMap<u64, as_holder!(ClassifiedItem)> {
+        pub fn classified_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ClassifiedItem)> {
             &self.classified_item
         }
-        pub fn contracted_item_holders(&self) -> &HashMap<u64, as_holder!(ContractedItem)> {
+        pub fn contracted_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractedItem)> {
             &self.contracted_item
         }
         pub fn curve_font_or_scaled_curve_font_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CurveFontOrScaledCurveFontSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveFontOrScaledCurveFontSelect)>
+        {
             &self.curve_font_or_scaled_curve_font_select
         }
         pub fn curve_or_annotation_curve_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CurveOrAnnotationCurveOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveOrAnnotationCurveOccurrence)>
+        {
             &self.curve_or_annotation_curve_occurrence
         }
-        pub fn curve_or_render_holders(&self) -> &HashMap<u64, as_holder!(CurveOrRender)> {
+        pub fn curve_or_render_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveOrRender)> {
             &self.curve_or_render
         }
         pub fn curve_style_font_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CurveStyleFontSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveStyleFontSelect)> {
             &self.curve_style_font_select
         }
-        pub fn date_time_select_holders(&self) -> &HashMap<u64, as_holder!(DateTimeSelect)> {
+        pub fn date_time_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateTimeSelect)> {
             &self.date_time_select
         }
-        pub fn day_in_month_number_holders(&self) -> &HashMap<u64, as_holder!(DayInMonthNumber)> {
+        pub fn day_in_month_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DayInMonthNumber)> {
             &self.day_in_month_number
         }
         pub fn defined_symbol_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DefinedSymbolSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DefinedSymbolSelect)> {
             &self.defined_symbol_select
         }
-        pub fn dimension_count_holders(&self) -> &HashMap<u64, as_holder!(DimensionCount)> {
+        pub fn dimension_count_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCount)> {
             &self.dimension_count
         }
         pub fn draughting_callout_element_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingCalloutElement)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingCalloutElement)> {
             &self.draughting_callout_element
         }
         pub fn draughting_grouped_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingGroupedItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingGroupedItem)> {
             &self.draughting_grouped_item
         }
         pub fn draughting_organization_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingOrganizationItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingOrganizationItem)> {
             &self.draughting_organization_item
         }
         pub fn draughting_presented_item_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingPresentedItemSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingPresentedItemSelect)>
+        {
             &self.draughting_presented_item_select
         }
         pub fn draughting_titled_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DraughtingTitledItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DraughtingTitledItem)> {
             &self.draughting_titled_item
         }
         pub fn fill_area_style_tile_shape_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FillAreaStyleTileShapeSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillAreaStyleTileShapeSelect)> {
             &self.fill_area_style_tile_shape_select
         }
-        pub fn fill_style_select_holders(&self) -> &HashMap<u64, as_holder!(FillStyleSelect)> {
+        pub fn fill_style_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FillStyleSelect)> {
             &self.fill_style_select
         }
-        pub fn font_select_holders(&self) -> &HashMap<u64, as_holder!(FontSelect)> {
+        pub fn font_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FontSelect)> {
             &self.font_select
         }
         pub fn geometric_set_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricSetSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricSetSelect)> {
             &self.geometric_set_select
         }
         pub fn hiding_or_blanking_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(HidingOrBlankingSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(HidingOrBlankingSelect)> {
             &self.hiding_or_blanking_select
         }
-        pub fn identifier_holders(&self) -> &HashMap<u64, as_holder!(Identifier)> {
+        pub fn identifier_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Identifier)> {
             &self.identifier
         }
         pub fn invisibility_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(InvisibilityContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(InvisibilityContext)> {
             &self.invisibility_context
         }
-        pub fn invisible_item_holders(&self) -> &HashMap<u64, as_holder!(InvisibleItem)> {
+        pub fn invisible_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(InvisibleItem)> {
             &self.invisible_item
         }
-        pub fn label_holders(&self) -> &HashMap<u64, as_holder!(Label)> {
+        pub fn label_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Label)> {
             &self.label
         }
-        pub fn layered_item_holders(&self) -> &HashMap<u64, as_holder!(LayeredItem)> {
+        pub fn layered_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LayeredItem)> {
             &self.layered_item
         }
-        pub fn length_measure_holders(&self) -> &HashMap<u64, as_holder!(LengthMeasure)> {
+        pub fn length_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthMeasure)> {
             &self.length_measure
         }
-        pub fn measure_value_holders(&self) -> &HashMap<u64, as_holder!(MeasureValue)> {
+        pub fn measure_value_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MeasureValue)> {
             &self.measure_value
         }
-        pub fn month_in_year_number_holders(&self) -> &HashMap<u64, as_holder!(MonthInYearNumber)> {
+        pub fn month_in_year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MonthInYearNumber)> {
             &self.month_in_year_number
         }
-        pub fn parameter_value_holders(&self) -> &HashMap<u64, as_holder!(ParameterValue)> {
+        pub fn parameter_value_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ParameterValue)> {
             &self.parameter_value
         }
         pub fn person_organization_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonOrganizationSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationSelect)> {
             &self.person_organization_select
         }
-        pub fn plane_angle_measure_holders(&self) -> &HashMap<u64, as_holder!(PlaneAngleMeasure)> {
+        pub fn plane_angle_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasure)> {
             &self.plane_angle_measure
         }
         pub fn positive_length_measure_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PositiveLengthMeasure)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PositiveLengthMeasure)> {
             &self.positive_length_measure
         }
         pub fn positive_ratio_measure_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PositiveRatioMeasure)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PositiveRatioMeasure)> {
             &self.positive_ratio_measure
         }
-        pub fn presentable_text_holders(&self) -> &HashMap<u64, as_holder!(PresentableText)> {
+        pub fn presentable_text_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentableText)> {
             &self.presentable_text
         }
         pub fn presentation_representation_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationRepresentationSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationRepresentationSelect)>
+        {
             &self.presentation_representation_select
         }
         pub fn presentation_size_assignment_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationSizeAssignmentSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationSizeAssignmentSelect)>
+        {
             &self.presentation_size_assignment_select
         }
         pub fn presentation_style_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PresentationStyleSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PresentationStyleSelect)> {
             &self.presentation_style_select
         }
-        pub fn ratio_measure_holders(&self) -> &HashMap<u64, as_holder!(RatioMeasure)> {
+        pub fn ratio_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RatioMeasure)> {
             &self.ratio_measure
         }
-        pub fn shape_definition_holders(&self) -> &HashMap<u64, as_holder!(ShapeDefinition)> {
+        pub fn shape_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinition)> {
             &self.shape_definition
         }
-        pub fn size_select_holders(&self) -> &HashMap<u64, as_holder!(SizeSelect)> {
+        pub fn size_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SizeSelect)> {
             &self.size_select
         }
-        pub fn source_item_holders(&self) -> &HashMap<u64, as_holder!(SourceItem)> {
+        pub fn source_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SourceItem)> {
             &self.source_item
         }
-        pub fn specified_item_holders(&self) -> &HashMap<u64, as_holder!(SpecifiedItem)> {
+        pub fn specified_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SpecifiedItem)> {
             &self.specified_item
         }
         pub fn style_context_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(StyleContextSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StyleContextSelect)> {
             &self.style_context_select
         }
-        pub fn symbol_style_select_holders(&self) -> &HashMap<u64, as_holder!(SymbolStyleSelect)> {
+        pub fn symbol_style_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SymbolStyleSelect)> {
             &self.symbol_style_select
         }
-        pub fn text_holders(&self) -> &HashMap<u64, as_holder!(Text)> {
+        pub fn text_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Text)> {
             &self.text
         }
-        pub fn text_alignment_holders(&self) -> &HashMap<u64, as_holder!(TextAlignment)> {
+        pub fn text_alignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextAlignment)> {
             &self.text_alignment
         }
-        pub fn text_delineation_holders(&self) -> &HashMap<u64, as_holder!(TextDelineation)> {
+        pub fn text_delineation_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextDelineation)> {
             &self.text_delineation
         }
-        pub fn text_or_character_holders(&self) -> &HashMap<u64, as_holder!(TextOrCharacter)> {
+        pub fn text_or_character_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TextOrCharacter)> {
             &self.text_or_character
         }
-        pub fn trimming_select_holders(&self) -> &HashMap<u64, as_holder!(TrimmingSelect)> {
+        pub fn trimming_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TrimmingSelect)> {
             &self.trimming_select
         }
-        pub fn unit_holders(&self) -> &HashMap<u64, as_holder!(Unit)> {
+        pub fn unit_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Unit)> {
             &self.unit
         }
-        pub fn vector_or_direction_holders(&self) -> &HashMap<u64, as_holder!(VectorOrDirection)> {
+        pub fn vector_or_direction_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VectorOrDirection)> {
             &self.vector_or_direction
         }
-        pub fn year_number_holders(&self) -> &HashMap<u64, as_holder!(YearNumber)> {
+        pub fn year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(YearNumber)> {
             &self.year_number
         }
+        #[doc = "Instances of `application_context_element` and its subtypes referring `application_context` by `frame_of_reference`, i.e. inverse attribute `application_context.context_elements`"]
+        pub fn inverse_context_elements(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.application_context_element
+                    .iter()
+                    .filter(|(_, holder)| holder.frame_of_reference.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.product_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.application_context_element {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.frame_of_reference.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.product_definition_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.application_context_element {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.frame_of_reference.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `composite_curve` and its subtypes referring `composite_curve_segment` by `segments`, i.e. inverse attribute `composite_curve_segment.using_curves`"]
+        pub fn inverse_using_curves(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.composite_curve
+                    .iter()
+                    .filter(|(_, holder)| holder.segments.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `area_in_set` and its subtypes referring `presentation_set` by `in_set`, i.e. inverse attribute `presentation_set.areas`"]
+        pub fn inverse_areas(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.area_in_set
+                    .iter()
+                    .filter(|(_, holder)| holder.in_set.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.drawing_sheet_revision_usage
+                    .iter()
+                    .filter(|(_, holder)| match &holder.area_in_set {
+                        crate::tables::PlaceHolder::Owned(holder) => holder.in_set.contains_ref(id),
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `representation` and its subtypes referring `representation_context` by `context_of_items`, i.e. inverse attribute `representation_context.representations_in_context`"]
+        pub fn inverse_representations_in_context(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.representation
+                    .iter()
+                    .filter(|(_, holder)| holder.context_of_items.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.draughting_model
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.presentation_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.symbol_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.presentation_area
+                    .iter()
+                    .filter(|(_, holder)| match &holder.presentation_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.presentation_view
+                    .iter()
+                    .filter(|(_, holder)| match &holder.presentation_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.geometrically_bounded_2d_wireframe_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.draughting_subfigure_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.symbol_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.draughting_symbol_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.symbol_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.drawing_sheet_revision
+                    .iter()
+                    .filter(|(_, holder)| match &holder.presentation_area {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            match &holder.presentation_representation {
+                                crate::tables::PlaceHolder::Owned(holder) => {
+                                    match &holder.representation {
+                                        crate::tables::PlaceHolder::Owned(holder) => {
+                                            holder.context_of_items.contains_ref(id)
+                                        }
+                                        crate::tables::PlaceHolder::Ref(_) => false,
+                                    }
+                                }
+                                crate::tables::PlaceHolder::Ref(_) => false,
+                            }
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.drawing_sheet_layout
+                    .iter()
+                    .filter(
+                        |(_, holder)| match &holder.draughting_symbol_representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                match &holder.symbol_representation {
+                                    crate::tables::PlaceHolder::Owned(holder) => {
+                                        match &holder.representation {
+                                            crate::tables::PlaceHolder::Owned(holder) => {
+                                                holder.context_of_items.contains_ref(id)
+                                            }
+                                            crate::tables::PlaceHolder::Ref(_) => false,
+                                        }
+                                    }
+                                    crate::tables::PlaceHolder::Ref(_) => false,
+                                }
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                    )
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `mapped_item` and its subtypes referring `representation_map` by `mapping_source`, i.e. inverse attribute `representation_map.map_usage`"]
+        pub fn inverse_map_usage(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.mapped_item
+                    .iter()
+                    .filter(|(_, holder)| holder.mapping_source.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.annotation_symbol
+                    .iter()
+                    .filter(|(_, holder)| match &holder.mapped_item {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.mapping_source.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.annotation_text
+                    .iter()
+                    .filter(|(_, holder)| match &holder.mapped_item {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.mapping_source.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.camera_image
+                    .iter()
+                    .filter(|(_, holder)| match &holder.mapped_item {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.mapping_source.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.camera_image_2d_with_scale
+                    .iter()
+                    .filter(|(_, holder)| match &holder.camera_image {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.mapped_item {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.mapping_source.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "approved_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApprovedItem {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
@@ -1440,7 +2303,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "area_or_view")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AreaOrView {
         #[holder(use_place_holder)]
         PresentationArea(PresentationAreaAny),
@@ -1449,7 +2315,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "axis2_placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Axis2Placement {
         #[holder(use_place_holder)]
         Axis2Placement2D(Box<Axis2Placement2D>),
@@ -1465,7 +2334,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "box_characteristic_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BoxCharacteristicSelect {
         #[holder(use_place_holder)]
         BoxHeight(Box<BoxHeight>),
@@ -1481,32 +2353,47 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = box_height)]
+    #[holder(type_name = "box_height")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoxHeight(#[holder(use_place_holder)] pub PositiveRatioMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = box_rotate_angle)]
+    #[holder(type_name = "box_rotate_angle")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoxRotateAngle(#[holder(use_place_holder)] pub PlaneAngleMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = box_slant_angle)]
+    #[holder(type_name = "box_slant_angle")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoxSlantAngle(#[holder(use_place_holder)] pub PlaneAngleMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = box_width)]
+    #[holder(type_name = "box_width")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoxWidth(#[holder(use_place_holder)] pub PositiveRatioMeasure);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "character_spacing_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterSpacingSelect {
         #[holder(use_place_holder)]
         LengthMeasure(Box<LengthMeasure>),
@@ -1517,14 +2404,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "character_style_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterStyleSelect {
         #[holder(use_place_holder)]
         TextStyleForDefinedFont(Box<TextStyleForDefinedFont>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "characterized_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterizedDefinition {
         #[holder(use_place_holder)]
         CharacterizedProductDefinition(Box<CharacterizedProductDefinition>),
@@ -1533,14 +2426,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "characterized_product_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterizedProductDefinition {
         #[holder(use_place_holder)]
         ProductDefinition(Box<ProductDefinition>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "classified_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ClassifiedItem {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
@@ -1549,21 +2448,30 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "contracted_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ContractedItem {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve_font_or_scaled_curve_font_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveFontOrScaledCurveFontSelect {
         #[holder(use_place_holder)]
         CurveStyleFontSelect(Box<CurveStyleFontSelect>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve_or_annotation_curve_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveOrAnnotationCurveOccurrence {
         #[holder(use_place_holder)]
         Curve(CurveAny),
@@ -1572,14 +2480,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve_or_render")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveOrRender {
         #[holder(use_place_holder)]
         CurveStyle(Box<CurveStyle>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve_style_font_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveStyleFontSelect {
         #[holder(use_place_holder)]
         CurveStyleFont(Box<CurveStyleFont>),
@@ -1590,7 +2504,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date_time_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateTimeSelect {
         #[holder(use_place_holder)]
         Date(DateAny),
@@ -1600,11 +2517,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = day_in_month_number)]
+    #[holder(type_name = "day_in_month_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DayInMonthNumber(pub i64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "defined_symbol_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DefinedSymbolSelect {
         #[holder(use_place_holder)]
         PreDefinedSymbol(PreDefinedSymbolAny),
@@ -1616,7 +2539,10 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_count)]
+    #[holder(type_name = "dimension_count")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCount(pub i64);
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum DimensionExtentUsage {
@@ -1625,7 +2551,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_callout_element")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingCalloutElement {
         #[holder(use_place_holder)]
         AnnotationTextOccurrence(Box<AnnotationTextOccurrence>),
@@ -1636,7 +2565,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_grouped_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingGroupedItem {
         #[holder(use_place_holder)]
         AnnotationOccurrence(AnnotationOccurrenceAny),
@@ -1645,7 +2577,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_organization_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingOrganizationItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(Box<ProductDefinitionFormation>),
@@ -1656,14 +2591,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_presented_item_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingPresentedItemSelect {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(Box<ProductDefinitionFormation>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_titled_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingTitledItem {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
@@ -1672,14 +2613,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "fill_area_style_tile_shape_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FillAreaStyleTileShapeSelect {
         #[holder(use_place_holder)]
         FillAreaStyleTileSymbolWithStyle(Box<FillAreaStyleTileSymbolWithStyle>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "fill_style_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FillStyleSelect {
         #[holder(use_place_holder)]
         FillAreaStyleColour(Box<FillAreaStyleColour>),
@@ -1694,7 +2641,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "font_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FontSelect {
         #[holder(use_place_holder)]
         PreDefinedTextFont(PreDefinedTextFontAny),
@@ -1703,7 +2653,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_set_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricSetSelect {
         #[holder(use_place_holder)]
         Point(PointAny),
@@ -1712,7 +2665,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "hiding_or_blanking_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum HidingOrBlankingSelect {
         #[holder(use_place_holder)]
         PresentationArea(PresentationAreaAny),
@@ -1726,11 +2682,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = identifier)]
+    #[holder(type_name = "identifier")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Identifier(pub String);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "invisibility_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum InvisibilityContext {
         #[holder(use_place_holder)]
         PresentationLayerUsage(Box<PresentationLayerUsage>),
@@ -1741,7 +2703,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "invisible_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum InvisibleItem {
         #[holder(use_place_holder)]
         StyledItem(StyledItemAny),
@@ -1762,11 +2727,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = label)]
+    #[holder(type_name = "label")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Label(pub String);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "layered_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum LayeredItem {
         #[holder(use_place_holder)]
         PresentationRepresentation(PresentationRepresentationAny),
@@ -1778,11 +2749,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = length_measure)]
+    #[holder(type_name = "length_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "measure_value")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum MeasureValue {
         #[holder(use_place_holder)]
         LengthMeasure(Box<LengthMeasure>),
@@ -1802,7 +2779,10 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = month_in_year_number)]
+    #[holder(type_name = "month_in_year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MonthInYearNumber(pub i64);
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum NullStyle {
@@ -1813,11 +2793,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = parameter_value)]
+    #[holder(type_name = "parameter_value")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ParameterValue(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_organization_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonOrganizationSelect {
         #[holder(use_place_holder)]
         Person(Box<Person>),
@@ -1831,32 +2817,47 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_measure)]
+    #[holder(type_name = "plane_angle_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleMeasure(pub f64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = positive_length_measure)]
+    #[holder(type_name = "positive_length_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PositiveLengthMeasure(#[holder(use_place_holder)] pub LengthMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = positive_ratio_measure)]
+    #[holder(type_name = "positive_ratio_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PositiveRatioMeasure(#[holder(use_place_holder)] pub RatioMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = presentable_text)]
+    #[holder(type_name = "presentable_text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentableText(pub String);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_representation_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationRepresentationSelect {
         #[holder(use_place_holder)]
         PresentationRepresentation(PresentationRepresentationAny),
@@ -1865,7 +2866,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_size_assignment_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationSizeAssignmentSelect {
         #[holder(use_place_holder)]
         PresentationView(Box<PresentationView>),
@@ -1876,7 +2880,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_style_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationStyleSelect {
         #[holder(use_place_holder)]
         CurveStyle(Box<CurveStyle>),
@@ -1893,11 +2900,17 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = ratio_measure)]
+    #[holder(type_name = "ratio_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RatioMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "shape_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ShapeDefinition {
         #[holder(use_place_holder)]
         ProductDefinitionShape(Box<ProductDefinitionShape>),
@@ -1954,7 +2967,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "size_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SizeSelect {
         #[holder(use_place_holder)]
         PositiveLengthMeasure(Box<PositiveLengthMeasure>),
@@ -1963,21 +2979,30 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "source_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SourceItem {
         #[holder(use_place_holder)]
         Identifier(Box<Identifier>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "specified_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SpecifiedItem {
         #[holder(use_place_holder)]
         DrawingRevision(DrawingRevisionAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "style_context_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum StyleContextSelect {
         #[holder(use_place_holder)]
         Representation(RepresentationAny),
@@ -1988,7 +3013,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "symbol_style_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SymbolStyleSelect {
         #[holder(use_place_holder)]
         SymbolColour(Box<SymbolColour>),
@@ -1998,25 +3026,37 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = text)]
+    #[holder(type_name = "text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Text(pub String);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_alignment)]
+    #[holder(type_name = "text_alignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextAlignment(#[holder(use_place_holder)] pub Label);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_delineation)]
+    #[holder(type_name = "text_delineation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextDelineation(#[holder(use_place_holder)] pub Label);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "text_or_character")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TextOrCharacter {
         #[holder(use_place_holder)]
         AnnotationText(Box<AnnotationText>),
@@ -2047,7 +3087,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "trimming_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TrimmingSelect {
         #[holder(use_place_holder)]
         CartesianPoint(Box<CartesianPoint>),
@@ -2056,14 +3099,20 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Unit {
         #[holder(use_place_holder)]
         NamedUnit(NamedUnitAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "vector_or_direction")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum VectorOrDirection {
         #[holder(use_place_holder)]
         Vector(Box<Vector>),
@@ -2075,12 +3124,534 @@ pub mod explicit_draughting {
     )]
     # [holder (table = Tables)]
     # [holder (field = year_number)]
+    #[holder(type_name = "year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct YearNumber(pub i64);
+    impl BSplineCurveForm {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                BSplineCurveForm::EllipticArc => ".ELLIPTIC_ARC.",
+                BSplineCurveForm::PolylineForm => ".POLYLINE_FORM.",
+                BSplineCurveForm::ParabolicArc => ".PARABOLIC_ARC.",
+                BSplineCurveForm::CircularArc => ".CIRCULAR_ARC.",
+                BSplineCurveForm::Unspecified => ".UNSPECIFIED.",
+                BSplineCurveForm::HyperbolicArc => ".HYPERBOLIC_ARC.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for BSplineCurveForm {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                BSplineCurveForm::EllipticArc => "ELLIPTIC_ARC",
+                BSplineCurveForm::PolylineForm => "POLYLINE_FORM",
+                BSplineCurveForm::ParabolicArc => "PARABOLIC_ARC",
+                BSplineCurveForm::CircularArc => "CIRCULAR_ARC",
+                BSplineCurveForm::Unspecified => "UNSPECIFIED",
+                BSplineCurveForm::HyperbolicArc => "HYPERBOLIC_ARC",
+            })
+        }
+    }
+    impl ::std::str::FromStr for BSplineCurveForm {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "ELLIPTIC_ARC" => Ok(BSplineCurveForm::EllipticArc),
+                "POLYLINE_FORM" => Ok(BSplineCurveForm::PolylineForm),
+                "PARABOLIC_ARC" => Ok(BSplineCurveForm::ParabolicArc),
+                "CIRCULAR_ARC" => Ok(BSplineCurveForm::CircularArc),
+                "UNSPECIFIED" => Ok(BSplineCurveForm::Unspecified),
+                "HYPERBOLIC_ARC" => Ok(BSplineCurveForm::HyperbolicArc),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for BSplineCurveForm {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl DimensionExtentUsage {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                DimensionExtentUsage::Origin => ".ORIGIN.",
+                DimensionExtentUsage::Target => ".TARGET.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for DimensionExtentUsage {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                DimensionExtentUsage::Origin => "ORIGIN",
+                DimensionExtentUsage::Target => "TARGET",
+            })
+        }
+    }
+    impl ::std::str::FromStr for DimensionExtentUsage {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "ORIGIN" => Ok(DimensionExtentUsage::Origin),
+                "TARGET" => Ok(DimensionExtentUsage::Target),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for DimensionExtentUsage {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl KnotType {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                KnotType::UniformKnots => ".UNIFORM_KNOTS.",
+                KnotType::QuasiUniformKnots => ".QUASI_UNIFORM_KNOTS.",
+                KnotType::PiecewiseBezierKnots => ".PIECEWISE_BEZIER_KNOTS.",
+                KnotType::Unspecified => ".UNSPECIFIED.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for KnotType {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                KnotType::UniformKnots => "UNIFORM_KNOTS",
+                KnotType::QuasiUniformKnots => "QUASI_UNIFORM_KNOTS",
+                KnotType::PiecewiseBezierKnots => "PIECEWISE_BEZIER_KNOTS",
+                KnotType::Unspecified => "UNSPECIFIED",
+            })
+        }
+    }
+    impl ::std::str::FromStr for KnotType {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "UNIFORM_KNOTS" => Ok(KnotType::UniformKnots),
+                "QUASI_UNIFORM_KNOTS" => Ok(KnotType::QuasiUniformKnots),
+                "PIECEWISE_BEZIER_KNOTS" => Ok(KnotType::PiecewiseBezierKnots),
+                "UNSPECIFIED" => Ok(KnotType::Unspecified),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for KnotType {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl NullStyle {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                NullStyle::Null => ".NULL.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for NullStyle {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                NullStyle::Null => "NULL",
+            })
+        }
+    }
+    impl ::std::str::FromStr for NullStyle {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "NULL" => Ok(NullStyle::Null),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for NullStyle {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl SiPrefix {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                SiPrefix::Exa => ".EXA.",
+                SiPrefix::Pico => ".PICO.",
+                SiPrefix::Mega => ".MEGA.",
+                SiPrefix::Femto => ".FEMTO.",
+                SiPrefix::Atto => ".ATTO.",
+                SiPrefix::Centi => ".CENTI.",
+                SiPrefix::Nano => ".NANO.",
+                SiPrefix::Hecto => ".HECTO.",
+                SiPrefix::Micro => ".MICRO.",
+                SiPrefix::Tera => ".TERA.",
+                SiPrefix::Giga => ".GIGA.",
+                SiPrefix::Milli => ".MILLI.",
+                SiPrefix::Peta => ".PETA.",
+                SiPrefix::Deci => ".DECI.",
+                SiPrefix::Kilo => ".KILO.",
+                SiPrefix::Deca => ".DECA.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for SiPrefix {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                SiPrefix::Exa => "EXA",
+                SiPrefix::Pico => "PICO",
+                SiPrefix::Mega => "MEGA",
+                SiPrefix::Femto => "FEMTO",
+                SiPrefix::Atto => "ATTO",
+                SiPrefix::Centi => "CENTI",
+                SiPrefix::Nano => "NANO",
+                SiPrefix::Hecto => "HECTO",
+                SiPrefix::Micro => "MICRO",
+                SiPrefix::Tera => "TERA",
+                SiPrefix::Giga => "GIGA",
+                SiPrefix::Milli => "MILLI",
+                SiPrefix::Peta => "PETA",
+                SiPrefix::Deci => "DECI",
+                SiPrefix::Kilo => "KILO",
+                SiPrefix::Deca => "DECA",
+            })
+        }
+    }
+    impl ::std::str::FromStr for SiPrefix {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "EXA" => Ok(SiPrefix::Exa),
+                "PICO" => Ok(SiPrefix::Pico),
+                "MEGA" => Ok(SiPrefix::Mega),
+                "FEMTO" => Ok(SiPrefix::Femto),
+                "ATTO" => Ok(SiPrefix::Atto),
+                "CENTI" => Ok(SiPrefix::Centi),
+                "NANO" => Ok(SiPrefix::Nano),
+                "HECTO" => Ok(SiPrefix::Hecto),
+                "MICRO" => Ok(SiPrefix::Micro),
+                "TERA" => Ok(SiPrefix::Tera),
+                "GIGA" => Ok(SiPrefix::Giga),
+                "MILLI" => Ok(SiPrefix::Milli),
+                "PETA" => Ok(SiPrefix::Peta),
+                "DECI" => Ok(SiPrefix::Deci),
+                "KILO" => Ok(SiPrefix::Kilo),
+                "DECA" => Ok(SiPrefix::Deca),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for SiPrefix {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl SiUnitName {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                SiUnitName::Hertz => ".HERTZ.",
+                SiUnitName::DegreeCelsius => ".DEGREE_CELSIUS.",
+                SiUnitName::Siemens => ".SIEMENS.",
+                SiUnitName::Sievert => ".SIEVERT.",
+                SiUnitName::Lux => ".LUX.",
+                SiUnitName::Watt => ".WATT.",
+                SiUnitName::Ohm => ".OHM.",
+                SiUnitName::Second => ".SECOND.",
+                SiUnitName::Becquerel => ".BECQUEREL.",
+                SiUnitName::Pascal => ".PASCAL.",
+                SiUnitName::Henry => ".HENRY.",
+                SiUnitName::Tesla => ".TESLA.",
+                SiUnitName::Volt => ".VOLT.",
+                SiUnitName::Joule => ".JOULE.",
+                SiUnitName::Kelvin => ".KELVIN.",
+                SiUnitName::Ampere => ".AMPERE.",
+                SiUnitName::Gram => ".GRAM.",
+                SiUnitName::Steradian => ".STERADIAN.",
+                SiUnitName::Mole => ".MOLE.",
+                SiUnitName::Lumen => ".LUMEN.",
+                SiUnitName::Gray => ".GRAY.",
+                SiUnitName::Candela => ".CANDELA.",
+                SiUnitName::Farad => ".FARAD.",
+                SiUnitName::Radian => ".RADIAN.",
+                SiUnitName::Newton => ".NEWTON.",
+                SiUnitName::Metre => ".METRE.",
+                SiUnitName::Weber => ".WEBER.",
+                SiUnitName::Coulomb => ".COULOMB.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for SiUnitName {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                SiUnitName::Hertz => "HERTZ",
+                SiUnitName::DegreeCelsius => "DEGREE_CELSIUS",
+                SiUnitName::Siemens => "SIEMENS",
+                SiUnitName::Sievert => "SIEVERT",
+                SiUnitName::Lux => "LUX",
+                SiUnitName::Watt => "WATT",
+                SiUnitName::Ohm => "OHM",
+                SiUnitName::Second => "SECOND",
+                SiUnitName::Becquerel => "BECQUEREL",
+                SiUnitName::Pascal => "PASCAL",
+                SiUnitName::Henry => "HENRY",
+                SiUnitName::Tesla => "TESLA",
+                SiUnitName::Volt => "VOLT",
+                SiUnitName::Joule => "JOULE",
+                SiUnitName::Kelvin => "KELVIN",
+                SiUnitName::Ampere => "AMPERE",
+                SiUnitName::Gram => "GRAM",
+                SiUnitName::Steradian => "STERADIAN",
+                SiUnitName::Mole => "MOLE",
+                SiUnitName::Lumen => "LUMEN",
+                SiUnitName::Gray => "GRAY",
+                SiUnitName::Candela => "CANDELA",
+                SiUnitName::Farad => "FARAD",
+                SiUnitName::Radian => "RADIAN",
+                SiUnitName::Newton => "NEWTON",
+                SiUnitName::Metre => "METRE",
+                SiUnitName::Weber => "WEBER",
+                SiUnitName::Coulomb => "COULOMB",
+            })
+        }
+    }
+    impl ::std::str::FromStr for SiUnitName {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "HERTZ" => Ok(SiUnitName::Hertz),
+                "DEGREE_CELSIUS" => Ok(SiUnitName::DegreeCelsius),
+                "SIEMENS" => Ok(SiUnitName::Siemens),
+                "SIEVERT" => Ok(SiUnitName::Sievert),
+                "LUX" => Ok(SiUnitName::Lux),
+                "WATT" => Ok(SiUnitName::Watt),
+                "OHM" => Ok(SiUnitName::Ohm),
+                "SECOND" => Ok(SiUnitName::Second),
+                "BECQUEREL" => Ok(SiUnitName::Becquerel),
+                "PASCAL" => Ok(SiUnitName::Pascal),
+                "HENRY" => Ok(SiUnitName::Henry),
+                "TESLA" => Ok(SiUnitName::Tesla),
+                "VOLT" => Ok(SiUnitName::Volt),
+                "JOULE" => Ok(SiUnitName::Joule),
+                "KELVIN" => Ok(SiUnitName::Kelvin),
+                "AMPERE" => Ok(SiUnitName::Ampere),
+                "GRAM" => Ok(SiUnitName::Gram),
+                "STERADIAN" => Ok(SiUnitName::Steradian),
+                "MOLE" => Ok(SiUnitName::Mole),
+                "LUMEN" => Ok(SiUnitName::Lumen),
+                "GRAY" => Ok(SiUnitName::Gray),
+                "CANDELA" => Ok(SiUnitName::Candela),
+                "FARAD" => Ok(SiUnitName::Farad),
+                "RADIAN" => Ok(SiUnitName::Radian),
+                "NEWTON" => Ok(SiUnitName::Newton),
+                "METRE" => Ok(SiUnitName::Metre),
+                "WEBER" => Ok(SiUnitName::Weber),
+                "COULOMB" => Ok(SiUnitName::Coulomb),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for SiUnitName {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl TextPath {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                TextPath::Up => ".UP.",
+                TextPath::Right => ".RIGHT.",
+                TextPath::Down => ".DOWN.",
+                TextPath::Left => ".LEFT.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for TextPath {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                TextPath::Up => "UP",
+                TextPath::Right => "RIGHT",
+                TextPath::Down => "DOWN",
+                TextPath::Left => "LEFT",
+            })
+        }
+    }
+    impl ::std::str::FromStr for TextPath {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "UP" => Ok(TextPath::Up),
+                "RIGHT" => Ok(TextPath::Right),
+                "DOWN" => Ok(TextPath::Down),
+                "LEFT" => Ok(TextPath::Left),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for TextPath {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl TransitionCode {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                TransitionCode::Discontinuous => ".DISCONTINUOUS.",
+                TransitionCode::ContSameGradientSameCurvature => {
+                    ".CONT_SAME_GRADIENT_SAME_CURVATURE."
+                }
+                TransitionCode::ContSameGradient => ".CONT_SAME_GRADIENT.",
+                TransitionCode::Continuous => ".CONTINUOUS.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for TransitionCode {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                TransitionCode::Discontinuous => "DISCONTINUOUS",
+                TransitionCode::ContSameGradientSameCurvature => {
+                    "CONT_SAME_GRADIENT_SAME_CURVATURE"
+                }
+                TransitionCode::ContSameGradient => "CONT_SAME_GRADIENT",
+                TransitionCode::Continuous => "CONTINUOUS",
+            })
+        }
+    }
+    impl ::std::str::FromStr for TransitionCode {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "DISCONTINUOUS" => Ok(TransitionCode::Discontinuous),
+                "CONT_SAME_GRADIENT_SAME_CURVATURE" => {
+                    Ok(TransitionCode::ContSameGradientSameCurvature)
+                }
+                "CONT_SAME_GRADIENT" => Ok(TransitionCode::ContSameGradient),
+                "CONTINUOUS" => Ok(TransitionCode::Continuous),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for TransitionCode {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl TrimmingPreference {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                TrimmingPreference::Parameter => ".PARAMETER.",
+                TrimmingPreference::Unspecified => ".UNSPECIFIED.",
+                TrimmingPreference::Cartesian => ".CARTESIAN.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for TrimmingPreference {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                TrimmingPreference::Parameter => "PARAMETER",
+                TrimmingPreference::Unspecified => "UNSPECIFIED",
+                TrimmingPreference::Cartesian => "CARTESIAN",
+            })
+        }
+    }
+    impl ::std::str::FromStr for TrimmingPreference {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "PARAMETER" => Ok(TrimmingPreference::Parameter),
+                "UNSPECIFIED" => Ok(TrimmingPreference::Unspecified),
+                "CARTESIAN" => Ok(TrimmingPreference::Cartesian),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for TrimmingPreference {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = address)]
+    #[holder(type_name = "address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Address {
         #[holder(use_place_holder)]
         pub internal_location: Option<Label>,
@@ -2109,7 +3680,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AddressAny {
         #[holder(use_place_holder)]
         Address(Box<Address>),
@@ -2142,37 +3716,74 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Address {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            if !(((((((((((self.internal_location.is_some()
+                || self.street_number.is_some())
+                || self.street.is_some())
+                || self.postal_box.is_some())
+                || self.town.is_some())
+                || self.region.is_some())
+                || self.postal_code.is_some())
+                || self.country.is_some())
+                || self.facsimile_number.is_some())
+                || self.telephone_number.is_some())
+                || self.electronic_mail_address.is_some())
+                || self.telex_number.is_some())
+            {
+                return Err("address.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = angular_dimension)]
+    #[holder(type_name = "angular_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AngularDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    impl crate::tables::Validate for AngularDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.dimension_curve_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_curve_occurrence)]
+    #[holder(type_name = "annotation_curve_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationCurveOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "annotation_curve_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AnnotationCurveOccurrenceAny {
         #[holder(use_place_holder)]
         AnnotationCurveOccurrence(Box<AnnotationCurveOccurrence>),
@@ -2231,55 +3842,88 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for AnnotationCurveOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_fill_area)]
+    #[holder(type_name = "annotation_fill_area")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationFillArea {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub boundaries: Vec<CurveAny>,
     }
+    impl crate::tables::Validate for AnnotationFillArea {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_fill_area_occurrence)]
+    #[holder(type_name = "annotation_fill_area_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationFillAreaOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
         #[holder(use_place_holder)]
         pub fill_style_target: PointAny,
     }
+    impl crate::tables::Validate for AnnotationFillAreaOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_occurrence)]
+    #[holder(type_name = "annotation_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub styled_item: StyledItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "annotation_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AnnotationOccurrenceAny {
         #[holder(use_place_holder)]
         AnnotationOccurrence(Box<AnnotationOccurrence>),
@@ -2360,51 +4004,84 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for AnnotationOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.styled_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_subfigure_occurrence)]
+    #[holder(type_name = "annotation_subfigure_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationSubfigureOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_symbol_occurrence: AnnotationSymbolOccurrence,
     }
+    impl crate::tables::Validate for AnnotationSubfigureOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_symbol_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_symbol)]
+    #[holder(type_name = "annotation_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
+    impl crate::tables::Validate for AnnotationSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.mapped_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_symbol_occurrence)]
+    #[holder(type_name = "annotation_symbol_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationSymbolOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "annotation_symbol_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AnnotationSymbolOccurrenceAny {
         #[holder(use_place_holder)]
         AnnotationSymbolOccurrence(Box<AnnotationSymbolOccurrence>),
@@ -2452,46 +4129,83 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for AnnotationSymbolOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_text)]
+    #[holder(type_name = "annotation_text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationText {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
+    impl crate::tables::Validate for AnnotationText {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.mapped_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = annotation_text_occurrence)]
+    #[holder(type_name = "annotation_text_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AnnotationTextOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
+    impl crate::tables::Validate for AnnotationTextOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context)]
+    #[holder(type_name = "application_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationContext {
         #[holder(use_place_holder)]
         pub application: Text,
     }
+    impl crate::tables::Validate for ApplicationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context_element)]
+    #[holder(type_name = "application_context_element")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationContextElement {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2500,7 +4214,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "application_context_element")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApplicationContextElementAny {
         #[holder(use_place_holder)]
         ApplicationContextElement(Box<ApplicationContextElement>),
@@ -2533,10 +4250,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ApplicationContextElement {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_protocol_definition)]
+    #[holder(type_name = "application_protocol_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationProtocolDefinition {
         #[holder(use_place_holder)]
         pub status: Label,
@@ -2547,27 +4272,46 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub application: ApplicationContext,
     }
+    impl crate::tables::Validate for ApplicationProtocolDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval)]
+    #[holder(type_name = "approval")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Approval {
         #[holder(use_place_holder)]
         pub status: ApprovalStatus,
         #[holder(use_place_holder)]
         pub level: Label,
     }
+    impl crate::tables::Validate for Approval {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_assignment)]
+    #[holder(type_name = "approval_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalAssignment {
         #[holder(use_place_holder)]
         pub assigned_approval: Approval,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "approval_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApprovalAssignmentAny {
         #[holder(use_place_holder)]
         ApprovalAssignment(Box<ApprovalAssignment>),
@@ -2592,20 +4336,36 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ApprovalAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_date_time)]
+    #[holder(type_name = "approval_date_time")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalDateTime {
         #[holder(use_place_holder)]
         pub date_time: DateTimeSelect,
         #[holder(use_place_holder)]
         pub dated_approval: Approval,
     }
+    impl crate::tables::Validate for ApprovalDateTime {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_person_organization)]
+    #[holder(type_name = "approval_person_organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalPersonOrganization {
         #[holder(use_place_holder)]
         pub person_organization: PersonOrganizationSelect,
@@ -2614,26 +4374,50 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub role: ApprovalRole,
     }
+    impl crate::tables::Validate for ApprovalPersonOrganization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_role)]
+    #[holder(type_name = "approval_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalRole {
         #[holder(use_place_holder)]
         pub role: Label,
     }
+    impl crate::tables::Validate for ApprovalRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_status)]
+    #[holder(type_name = "approval_status")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalStatus {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for ApprovalStatus {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = area_in_set)]
+    #[holder(type_name = "area_in_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AreaInSet {
         #[holder(use_place_holder)]
         pub area: PresentationAreaAny,
@@ -2642,7 +4426,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "area_in_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AreaInSetAny {
         #[holder(use_place_holder)]
         AreaInSet(Box<AreaInSet>),
@@ -2667,34 +4454,53 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for AreaInSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = axis2_placement_2d)]
+    #[holder(type_name = "axis2_placement_2d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Axis2Placement2D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    impl crate::tables::Validate for Axis2Placement2D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.placement)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_curve)]
+    #[holder(type_name = "b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
@@ -2705,7 +4511,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BSplineCurveAny {
         #[holder(use_place_holder)]
         BSplineCurve(Box<BSplineCurve>),
@@ -2784,55 +4593,88 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for BSplineCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_curve_with_knots)]
+    #[holder(type_name = "b_spline_curve_with_knots")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineCurveWithKnots {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
         pub knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    impl crate::tables::Validate for BSplineCurveWithKnots {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bezier_curve)]
+    #[holder(type_name = "bezier_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BezierCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for BezierCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bounded_curve)]
+    #[holder(type_name = "bounded_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundedCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "bounded_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BoundedCurveAny {
         #[holder(use_place_holder)]
         BoundedCurve(Box<BoundedCurve>),
@@ -2898,41 +4740,64 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for BoundedCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = calendar_date)]
+    #[holder(type_name = "calendar_date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CalendarDate {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInMonthNumber,
         #[holder(use_place_holder)]
         pub month_component: MonthInYearNumber,
     }
+    impl crate::tables::Validate for CalendarDate {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.date)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = camera_image)]
+    #[holder(type_name = "camera_image")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CameraImage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub mapped_item: MappedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "camera_image")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CameraImageAny {
         #[holder(use_place_holder)]
         CameraImage(Box<CameraImage>),
@@ -2967,37 +4832,60 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for CameraImage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.mapped_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = camera_image_2d_with_scale)]
+    #[holder(type_name = "camera_image_2d_with_scale")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CameraImage2DWithScale {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub camera_image: CameraImage,
     }
+    impl crate::tables::Validate for CameraImage2DWithScale {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.camera_image)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = camera_model)]
+    #[holder(type_name = "camera_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CameraModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "camera_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CameraModelAny {
         #[holder(use_place_holder)]
         CameraModel(Box<CameraModel>),
@@ -3032,77 +4920,129 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for CameraModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = camera_model_d2)]
+    #[holder(type_name = "camera_model_d2")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CameraModelD2 {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub camera_model: CameraModel,
         #[holder(use_place_holder)]
         pub view_window: PlanarBox,
         pub view_window_clipping: bool,
     }
+    impl crate::tables::Validate for CameraModelD2 {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.camera_model)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = camera_usage)]
+    #[holder(type_name = "camera_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CameraUsage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_map: RepresentationMap,
     }
+    impl crate::tables::Validate for CameraUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_map)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cartesian_point)]
+    #[holder(type_name = "cartesian_point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CartesianPoint {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub coordinates: Vec<LengthMeasure>,
     }
+    impl crate::tables::Validate for CartesianPoint {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = circle)]
+    #[holder(type_name = "circle")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Circle {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Circle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = colour)]
+    #[holder(type_name = "colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Colour {}
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ColourAny {
         #[holder(use_place_holder)]
         Colour(Box<Colour>),
@@ -3135,42 +5075,73 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Colour {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = colour_rgb)]
+    #[holder(type_name = "colour_rgb")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ColourRgb {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour_specification: ColourSpecification,
         pub red: f64,
         pub green: f64,
         pub blue: f64,
     }
+    impl crate::tables::Validate for ColourRgb {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.colour_specification)?;
+            if !((0.0 <= self.red) && (self.red <= 1.0)) {
+                return Err("colour_rgb.wr1");
+            }
+            if !((0.0 <= self.green) && (self.green <= 1.0)) {
+                return Err("colour_rgb.wr2");
+            }
+            if !((0.0 <= self.blue) && (self.blue <= 1.0)) {
+                return Err("colour_rgb.wr3");
+            }
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = colour_specification)]
+    #[holder(type_name = "colour_specification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ColourSpecification {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour: Colour,
         #[holder(use_place_holder)]
         pub name: ColourAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "colour_specification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ColourSpecificationAny {
         #[holder(use_place_holder)]
         ColourSpecification(Box<ColourSpecification>),
@@ -3207,52 +5178,83 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ColourSpecification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.colour)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_curve)]
+    #[holder(type_name = "composite_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub segments: Vec<CompositeCurveSegment>,
         pub self_intersect: Logical,
     }
+    impl crate::tables::Validate for CompositeCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = composite_curve_segment)]
+    #[holder(type_name = "composite_curve_segment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeCurveSegment {
         pub transition: TransitionCode,
         pub same_sense: bool,
         #[holder(use_place_holder)]
         pub parent_curve: CurveAny,
     }
+    impl crate::tables::Validate for CompositeCurveSegment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_text)]
+    #[holder(type_name = "composite_text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeText {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub collected_text: Vec<TextOrCharacter>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "composite_text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CompositeTextAny {
         #[holder(use_place_holder)]
         CompositeText(Box<CompositeText>),
@@ -3309,73 +5311,116 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for CompositeText {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_text_with_associated_curves)]
+    #[holder(type_name = "composite_text_with_associated_curves")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeTextWithAssociatedCurves {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub associated_curves: Vec<CurveAny>,
     }
+    impl crate::tables::Validate for CompositeTextWithAssociatedCurves {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_text)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_text_with_blanking_box)]
+    #[holder(type_name = "composite_text_with_blanking_box")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeTextWithBlankingBox {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
     }
+    impl crate::tables::Validate for CompositeTextWithBlankingBox {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_text)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_text_with_extent)]
+    #[holder(type_name = "composite_text_with_extent")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeTextWithExtent {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_text: CompositeText,
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
     }
+    impl crate::tables::Validate for CompositeTextWithExtent {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_text)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = conic)]
+    #[holder(type_name = "conic")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Conic {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub position: Axis2Placement,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "conic")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ConicAny {
         #[holder(use_place_holder)]
         Conic(Box<Conic>),
@@ -3435,26 +5480,45 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Conic {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = context_dependent_invisibility)]
+    #[holder(type_name = "context_dependent_invisibility")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContextDependentInvisibility {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub invisibility: Invisibility,
         #[holder(use_place_holder)]
         pub presentation_context: InvisibilityContext,
     }
+    impl crate::tables::Validate for ContextDependentInvisibility {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.invisibility)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract)]
+    #[holder(type_name = "contract")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Contract {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -3463,17 +5527,28 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub kind: ContractType,
     }
+    impl crate::tables::Validate for Contract {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_assignment)]
+    #[holder(type_name = "contract_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContractAssignment {
         #[holder(use_place_holder)]
         pub assigned_contract: Contract,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "contract_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ContractAssignmentAny {
         #[holder(use_place_holder)]
         ContractAssignment(Box<ContractAssignment>),
@@ -3498,49 +5573,79 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ContractAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_type)]
+    #[holder(type_name = "contract_type")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContractType {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    impl crate::tables::Validate for ContractType {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = conversion_based_unit)]
+    #[holder(type_name = "conversion_based_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConversionBasedUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub conversion_factor: MeasureWithUnitAny,
     }
+    impl crate::tables::Validate for ConversionBasedUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = curve)]
+    #[holder(type_name = "curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Curve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveAny {
         #[holder(use_place_holder)]
         Curve(Box<Curve>),
@@ -3600,24 +5705,43 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Curve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = curve_dimension)]
+    #[holder(type_name = "curve_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    impl crate::tables::Validate for CurveDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.dimension_curve_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style)]
+    #[holder(type_name = "curve_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveStyle {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -3628,37 +5752,64 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub curve_colour: ColourAny,
     }
+    impl crate::tables::Validate for CurveStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style_font)]
+    #[holder(type_name = "curve_style_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveStyleFont {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub pattern_list: Vec<CurveStyleFontPattern>,
     }
+    impl crate::tables::Validate for CurveStyleFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = curve_style_font_pattern)]
+    #[holder(type_name = "curve_style_font_pattern")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveStyleFontPattern {
         #[holder(use_place_holder)]
         pub visible_segment_length: PositiveLengthMeasure,
         #[holder(use_place_holder)]
         pub invisible_segment_length: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for CurveStyleFontPattern {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date)]
+    #[holder(type_name = "date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Date {
         #[holder(use_place_holder)]
         pub year_component: YearNumber,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateAny {
         #[holder(use_place_holder)]
         Date(Box<Date>),
@@ -3683,125 +5834,207 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Date {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = datum_feature_callout)]
+    #[holder(type_name = "datum_feature_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DatumFeatureCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
+    impl crate::tables::Validate for DatumFeatureCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = datum_target_callout)]
+    #[holder(type_name = "datum_target_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DatumTargetCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
+    impl crate::tables::Validate for DatumTargetCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = defined_symbol)]
+    #[holder(type_name = "defined_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DefinedSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub definition: DefinedSymbolSelect,
         #[holder(use_place_holder)]
         pub target: SymbolTarget,
     }
+    impl crate::tables::Validate for DefinedSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = diameter_dimension)]
+    #[holder(type_name = "diameter_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DiameterDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    impl crate::tables::Validate for DiameterDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.dimension_curve_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_callout_component_relationship)]
+    #[holder(type_name = "dimension_callout_component_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCalloutComponentRelationship {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    impl crate::tables::Validate for DimensionCalloutComponentRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_callout_relationship)]
+    #[holder(type_name = "dimension_callout_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCalloutRelationship {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    impl crate::tables::Validate for DimensionCalloutRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_curve)]
+    #[holder(type_name = "dimension_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    impl crate::tables::Validate for DimensionCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_curve_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_curve_directed_callout)]
+    #[holder(type_name = "dimension_curve_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCurveDirectedCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "dimension_curve_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DimensionCurveDirectedCalloutAny {
         #[holder(use_place_holder)]
         DimensionCurveDirectedCallout(Box<DimensionCurveDirectedCallout>),
@@ -3882,39 +6115,68 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DimensionCurveDirectedCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_curve_terminator)]
+    #[holder(type_name = "dimension_curve_terminator")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCurveTerminator {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub terminator_symbol: TerminatorSymbol,
         pub role: DimensionExtentUsage,
     }
+    impl crate::tables::Validate for DimensionCurveTerminator {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.terminator_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_pair)]
+    #[holder(type_name = "dimension_pair")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionPair {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout_relationship: DraughtingCalloutRelationship,
     }
+    impl crate::tables::Validate for DimensionPair {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = dimensional_exponents)]
+    #[holder(type_name = "dimensional_exponents")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionalExponents {
         pub length_exponent: f64,
         pub mass_exponent: f64,
@@ -3924,25 +6186,52 @@ pub mod explicit_draughting {
         pub amount_of_substance_exponent: f64,
         pub luminous_intensity_exponent: f64,
     }
+    impl crate::tables::Validate for DimensionalExponents {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = direction)]
+    #[holder(type_name = "direction")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Direction {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         pub direction_ratios: Vec<f64>,
     }
+    impl crate::tables::Validate for Direction {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            if !((self
+                .direction_ratios
+                .iter()
+                .filter(|&tmp| ((*tmp) != 0.0))
+                .count() as i64)
+                > 0)
+            {
+                return Err("direction.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document)]
+    #[holder(type_name = "document")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Document {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -3953,10 +6242,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub kind: DocumentType,
     }
+    impl crate::tables::Validate for Document {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_reference)]
+    #[holder(type_name = "document_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentReference {
         #[holder(use_place_holder)]
         pub assigned_document: Document,
@@ -3965,7 +6262,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "document_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DocumentReferenceAny {
         #[holder(use_place_holder)]
         DocumentReference(Box<DocumentReference>),
@@ -3990,63 +6290,103 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DocumentReference {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_type)]
+    #[holder(type_name = "document_type")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentType {
         #[holder(use_place_holder)]
         pub product_data_type: Label,
     }
+    impl crate::tables::Validate for DocumentType {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_annotation_occurrence)]
+    #[holder(type_name = "draughting_annotation_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingAnnotationOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_occurrence: AnnotationOccurrence,
     }
+    impl crate::tables::Validate for DraughtingAnnotationOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_approval_assignment)]
+    #[holder(type_name = "draughting_approval_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingApprovalAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
         pub approved_items: Vec<ApprovedItem>,
     }
+    impl crate::tables::Validate for DraughtingApprovalAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.approval_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_callout)]
+    #[holder(type_name = "draughting_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub contents: Vec<DraughtingCalloutElement>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingCalloutAny {
         #[holder(use_place_holder)]
         DraughtingCallout(Box<DraughtingCallout>),
@@ -4160,10 +6500,19 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DraughtingCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = draughting_callout_relationship)]
+    #[holder(type_name = "draughting_callout_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingCalloutRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -4176,7 +6525,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_callout_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingCalloutRelationshipAny {
         #[holder(use_place_holder)]
         DraughtingCalloutRelationship(Box<DraughtingCalloutRelationship>),
@@ -4221,249 +6573,411 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DraughtingCalloutRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_contract_assignment)]
+    #[holder(type_name = "draughting_contract_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingContractAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ContractedItem>,
     }
+    impl crate::tables::Validate for DraughtingContractAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.contract_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_drawing_revision)]
+    #[holder(type_name = "draughting_drawing_revision")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingDrawingRevision {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub drawing_revision: DrawingRevision,
     }
+    impl crate::tables::Validate for DraughtingDrawingRevision {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.drawing_revision)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_elements)]
+    #[holder(type_name = "draughting_elements")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingElements {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
+    impl crate::tables::Validate for DraughtingElements {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_group_assignment)]
+    #[holder(type_name = "draughting_group_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingGroupAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub group_assignment: GroupAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<DraughtingGroupedItem>,
     }
+    impl crate::tables::Validate for DraughtingGroupAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.group_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_model)]
+    #[holder(type_name = "draughting_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
+    impl crate::tables::Validate for DraughtingModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_organization_assignment)]
+    #[holder(type_name = "draughting_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingOrganizationAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub organization_assignment: OrganizationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    impl crate::tables::Validate for DraughtingOrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.organization_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_person_and_organization_assignment)]
+    #[holder(type_name = "draughting_person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPersonAndOrganizationAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    impl crate::tables::Validate for DraughtingPersonAndOrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.person_and_organization_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_person_assignment)]
+    #[holder(type_name = "draughting_person_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPersonAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_assignment: PersonAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<DraughtingOrganizationItem>,
     }
+    impl crate::tables::Validate for DraughtingPersonAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.person_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_pre_defined_colour)]
+    #[holder(type_name = "draughting_pre_defined_colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPreDefinedColour {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_colour: PreDefinedColour,
     }
+    impl crate::tables::Validate for DraughtingPreDefinedColour {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_colour)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_pre_defined_curve_font)]
+    #[holder(type_name = "draughting_pre_defined_curve_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPreDefinedCurveFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_curve_font: PreDefinedCurveFont,
     }
+    impl crate::tables::Validate for DraughtingPreDefinedCurveFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_curve_font)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_pre_defined_text_font)]
+    #[holder(type_name = "draughting_pre_defined_text_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPreDefinedTextFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_text_font: PreDefinedTextFont,
     }
+    impl crate::tables::Validate for DraughtingPreDefinedTextFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_text_font)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_presented_item)]
+    #[holder(type_name = "draughting_presented_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingPresentedItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presented_item: PresentedItem,
         #[holder(use_place_holder)]
         pub items: Vec<DraughtingPresentedItemSelect>,
     }
+    impl crate::tables::Validate for DraughtingPresentedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presented_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_security_classification_assignment)]
+    #[holder(type_name = "draughting_security_classification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingSecurityClassificationAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
         pub assigned_items: Vec<ClassifiedItem>,
     }
+    impl crate::tables::Validate for DraughtingSecurityClassificationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.security_classification_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_specification_reference)]
+    #[holder(type_name = "draughting_specification_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingSpecificationReference {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
         pub specified_items: Vec<SpecifiedItem>,
     }
+    impl crate::tables::Validate for DraughtingSpecificationReference {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.document_reference)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_subfigure_representation)]
+    #[holder(type_name = "draughting_subfigure_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingSubfigureRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub symbol_representation: SymbolRepresentation,
     }
+    impl crate::tables::Validate for DraughtingSubfigureRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.symbol_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_symbol_representation)]
+    #[holder(type_name = "draughting_symbol_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingSymbolRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub symbol_representation: SymbolRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "draughting_symbol_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DraughtingSymbolRepresentationAny {
         #[holder(use_place_holder)]
         DraughtingSymbolRepresentation(Box<DraughtingSymbolRepresentation>),
@@ -4500,24 +7014,43 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DraughtingSymbolRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.symbol_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = draughting_text_literal_with_delineation)]
+    #[holder(type_name = "draughting_text_literal_with_delineation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingTextLiteralWithDelineation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal_with_delineation: TextLiteralWithDelineation,
     }
+    impl crate::tables::Validate for DraughtingTextLiteralWithDelineation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_literal_with_delineation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = draughting_title)]
+    #[holder(type_name = "draughting_title")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DraughtingTitle {
         #[holder(use_place_holder)]
         pub items: Vec<DraughtingTitledItem>,
@@ -4526,28 +7059,45 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub contents: Text,
     }
+    impl crate::tables::Validate for DraughtingTitle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = drawing_definition)]
+    #[holder(type_name = "drawing_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DrawingDefinition {
         #[holder(use_place_holder)]
         pub drawing_number: Identifier,
         #[holder(use_place_holder)]
         pub drawing_type: Option<Label>,
     }
+    impl crate::tables::Validate for DrawingDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = drawing_revision)]
+    #[holder(type_name = "drawing_revision")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DrawingRevision {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_set: PresentationSet,
         #[holder(use_place_holder)]
         pub revision_identifier: Identifier,
@@ -4558,7 +7108,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "drawing_revision")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DrawingRevisionAny {
         #[holder(use_place_holder)]
         DrawingRevision(Box<DrawingRevision>),
@@ -4595,110 +7148,189 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for DrawingRevision {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presentation_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = drawing_sheet_layout)]
+    #[holder(type_name = "drawing_sheet_layout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DrawingSheetLayout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_symbol_representation: DraughtingSymbolRepresentation,
     }
+    impl crate::tables::Validate for DrawingSheetLayout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_symbol_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = drawing_sheet_revision)]
+    #[holder(type_name = "drawing_sheet_revision")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DrawingSheetRevision {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_area: PresentationArea,
         #[holder(use_place_holder)]
         pub revision_identifier: Identifier,
     }
+    impl crate::tables::Validate for DrawingSheetRevision {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presentation_area)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = drawing_sheet_revision_usage)]
+    #[holder(type_name = "drawing_sheet_revision_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DrawingSheetRevisionUsage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub area_in_set: AreaInSet,
         #[holder(use_place_holder)]
         pub sheet_number: Identifier,
     }
+    impl crate::tables::Validate for DrawingSheetRevisionUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.area_in_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = ellipse)]
+    #[holder(type_name = "ellipse")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Ellipse {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis_1: PositiveLengthMeasure,
         #[holder(use_place_holder)]
         pub semi_axis_2: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Ellipse {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = external_source)]
+    #[holder(type_name = "external_source")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternalSource {
         #[holder(use_place_holder)]
         pub source_id: SourceItem,
     }
+    impl crate::tables::Validate for ExternalSource {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_curve_font)]
+    #[holder(type_name = "externally_defined_curve_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedCurveFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    impl crate::tables::Validate for ExternallyDefinedCurveFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.externally_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_hatch_style)]
+    #[holder(type_name = "externally_defined_hatch_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedHatchStyle {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
+    impl crate::tables::Validate for ExternallyDefinedHatchStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.externally_defined_item)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_item)]
+    #[holder(type_name = "externally_defined_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedItem {
         #[holder(use_place_holder)]
         pub item_id: SourceItem,
@@ -4707,7 +7339,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "externally_defined_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ExternallyDefinedItemAny {
         #[holder(use_place_holder)]
         ExternallyDefinedItem(Box<ExternallyDefinedItem>),
@@ -4764,80 +7399,137 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ExternallyDefinedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_symbol)]
+    #[holder(type_name = "externally_defined_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    impl crate::tables::Validate for ExternallyDefinedSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.externally_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_text_font)]
+    #[holder(type_name = "externally_defined_text_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedTextFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
     }
+    impl crate::tables::Validate for ExternallyDefinedTextFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.externally_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = externally_defined_tile_style)]
+    #[holder(type_name = "externally_defined_tile_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExternallyDefinedTileStyle {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub externally_defined_item: ExternallyDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
+    impl crate::tables::Validate for ExternallyDefinedTileStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.externally_defined_item)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style)]
+    #[holder(type_name = "fill_area_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FillAreaStyle {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub fill_styles: Vec<FillStyleSelect>,
     }
+    impl crate::tables::Validate for FillAreaStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style_colour)]
+    #[holder(type_name = "fill_area_style_colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FillAreaStyleColour {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub fill_colour: ColourAny,
     }
+    impl crate::tables::Validate for FillAreaStyleColour {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style_hatching)]
+    #[holder(type_name = "fill_area_style_hatching")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FillAreaStyleHatching {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub hatch_line_appearance: CurveStyle,
@@ -4850,34 +7542,54 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub hatch_line_angle: PlaneAngleMeasure,
     }
+    impl crate::tables::Validate for FillAreaStyleHatching {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style_tile_symbol_with_style)]
+    #[holder(type_name = "fill_area_style_tile_symbol_with_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FillAreaStyleTileSymbolWithStyle {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub symbol: AnnotationSymbolOccurrenceAny,
     }
+    impl crate::tables::Validate for FillAreaStyleTileSymbolWithStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = fill_area_style_tiles)]
+    #[holder(type_name = "fill_area_style_tiles")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FillAreaStyleTiles {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub tiling_pattern: TwoDirectionRepeatFactor,
@@ -4886,53 +7598,86 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub tiling_scale: PositiveRatioMeasure,
     }
+    impl crate::tables::Validate for FillAreaStyleTiles {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_curve_set)]
+    #[holder(type_name = "geometric_curve_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricCurveSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_set: GeometricSet,
     }
+    impl crate::tables::Validate for GeometricCurveSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_representation_context)]
+    #[holder(type_name = "geometric_representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricRepresentationContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
     }
+    impl crate::tables::Validate for GeometricRepresentationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_representation_item)]
+    #[holder(type_name = "geometric_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricRepresentationItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricRepresentationItemAny {
         #[holder(use_place_holder)]
         GeometricRepresentationItem(Box<GeometricRepresentationItem>),
@@ -5180,25 +7925,38 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for GeometricRepresentationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_set)]
+    #[holder(type_name = "geometric_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub elements: Vec<GeometricSetSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricSetAny {
         #[holder(use_place_holder)]
         GeometricSet(Box<GeometricSet>),
@@ -5233,71 +7991,121 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for GeometricSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometrical_tolerance_callout)]
+    #[holder(type_name = "geometrical_tolerance_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricalToleranceCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
+    impl crate::tables::Validate for GeometricalToleranceCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometrically_bounded_2d_wireframe_representation)]
+    #[holder(type_name = "geometrically_bounded_2d_wireframe_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricallyBounded2DWireframeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for GeometricallyBounded2DWireframeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = global_unit_assigned_context)]
+    #[holder(type_name = "global_unit_assigned_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GlobalUnitAssignedContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub units: Vec<Unit>,
     }
+    impl crate::tables::Validate for GlobalUnitAssignedContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group)]
+    #[holder(type_name = "group")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Group {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for Group {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group_assignment)]
+    #[holder(type_name = "group_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GroupAssignment {
         #[holder(use_place_holder)]
         pub assigned_group: Group,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "group_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GroupAssignmentAny {
         #[holder(use_place_holder)]
         GroupAssignment(Box<GroupAssignment>),
@@ -5322,10 +8130,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for GroupAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = group_relationship)]
+    #[holder(type_name = "group_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GroupRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -5336,35 +8152,56 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub related_group: Group,
     }
+    impl crate::tables::Validate for GroupRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = hyperbola)]
+    #[holder(type_name = "hyperbola")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Hyperbola {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis: PositiveLengthMeasure,
         #[holder(use_place_holder)]
         pub semi_imag_axis: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Hyperbola {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = invisibility)]
+    #[holder(type_name = "invisibility")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Invisibility {
         #[holder(use_place_holder)]
         pub invisible_items: Vec<InvisibleItem>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "invisibility")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum InvisibilityAny {
         #[holder(use_place_holder)]
         Invisibility(Box<Invisibility>),
@@ -5389,37 +8226,59 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Invisibility {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = leader_curve)]
+    #[holder(type_name = "leader_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LeaderCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    impl crate::tables::Validate for LeaderCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_curve_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = leader_directed_callout)]
+    #[holder(type_name = "leader_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LeaderDirectedCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "leader_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum LeaderDirectedCalloutAny {
         #[holder(use_place_holder)]
         LeaderDirectedCallout(Box<LeaderDirectedCallout>),
@@ -5456,106 +8315,176 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for LeaderDirectedCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = leader_directed_dimension)]
+    #[holder(type_name = "leader_directed_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LeaderDirectedDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub leader_directed_callout: LeaderDirectedCallout,
     }
+    impl crate::tables::Validate for LeaderDirectedDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.leader_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = leader_terminator)]
+    #[holder(type_name = "leader_terminator")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LeaderTerminator {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub terminator_symbol: TerminatorSymbol,
     }
+    impl crate::tables::Validate for LeaderTerminator {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.terminator_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = length_measure_with_unit)]
+    #[holder(type_name = "length_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for LengthMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = length_unit)]
+    #[holder(type_name = "length_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for LengthUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = line)]
+    #[holder(type_name = "line")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Line {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub pnt: CartesianPoint,
         #[holder(use_place_holder)]
         pub dir: Vector,
     }
+    impl crate::tables::Validate for Line {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = linear_dimension)]
+    #[holder(type_name = "linear_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LinearDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    impl crate::tables::Validate for LinearDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.dimension_curve_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = mapped_item)]
+    #[holder(type_name = "mapped_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MappedItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub mapping_source: RepresentationMapAny,
@@ -5564,7 +8493,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "mapped_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum MappedItemAny {
         #[holder(use_place_holder)]
         MappedItem(Box<MappedItem>),
@@ -5619,10 +8551,19 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for MappedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = measure_with_unit)]
+    #[holder(type_name = "measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MeasureWithUnit {
         #[holder(use_place_holder)]
         pub value_component: MeasureValue,
@@ -5631,7 +8572,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum MeasureWithUnitAny {
         #[holder(use_place_holder)]
         MeasureWithUnit(Box<MeasureWithUnit>),
@@ -5664,17 +8608,28 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for MeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = named_unit)]
+    #[holder(type_name = "named_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct NamedUnit {
         #[holder(use_place_holder)]
         pub dimensions: DimensionalExponents,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "named_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum NamedUnitAny {
         #[holder(use_place_holder)]
         NamedUnit(Box<NamedUnit>),
@@ -5723,18 +8678,27 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for NamedUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = offset_curve_2d)]
+    #[holder(type_name = "offset_curve_2d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OffsetCurve2D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -5742,25 +8706,38 @@ pub mod explicit_draughting {
         pub distance: LengthMeasure,
         pub self_intersect: Logical,
     }
+    impl crate::tables::Validate for OffsetCurve2D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = one_direction_repeat_factor)]
+    #[holder(type_name = "one_direction_repeat_factor")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OneDirectionRepeatFactor {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub repeat_factor: Vector,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "one_direction_repeat_factor")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum OneDirectionRepeatFactorAny {
         #[holder(use_place_holder)]
         OneDirectionRepeatFactor(Box<OneDirectionRepeatFactor>),
@@ -5797,24 +8774,43 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for OneDirectionRepeatFactor {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = ordinate_dimension)]
+    #[holder(type_name = "ordinate_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrdinateDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub projection_directed_callout: ProjectionDirectedCallout,
     }
+    impl crate::tables::Validate for OrdinateDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.projection_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization)]
+    #[holder(type_name = "organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Organization {
         #[holder(use_place_holder)]
         pub id: Option<Identifier>,
@@ -5823,10 +8819,18 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for Organization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_assignment)]
+    #[holder(type_name = "organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationAssignment {
         #[holder(use_place_holder)]
         pub assigned_organization: Organization,
@@ -5835,7 +8839,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum OrganizationAssignmentAny {
         #[holder(use_place_holder)]
         OrganizationAssignment(Box<OrganizationAssignment>),
@@ -5860,52 +8867,88 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for OrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_role)]
+    #[holder(type_name = "organization_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationRole {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for OrganizationRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = organizational_address)]
+    #[holder(type_name = "organizational_address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationalAddress {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub organizations: Vec<Organization>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for OrganizationalAddress {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.address)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = parabola)]
+    #[holder(type_name = "parabola")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Parabola {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
     }
+    impl crate::tables::Validate for Parabola {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person)]
+    #[holder(type_name = "person")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Person {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -5920,20 +8963,39 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub suffix_titles: Option<Vec<Label>>,
     }
+    impl crate::tables::Validate for Person {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            if !(self.last_name.is_some() || self.first_name.is_some()) {
+                return Err("person.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization)]
+    #[holder(type_name = "person_and_organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganization {
         #[holder(use_place_holder)]
         pub the_person: Person,
         #[holder(use_place_holder)]
         pub the_organization: Organization,
     }
+    impl crate::tables::Validate for PersonAndOrganization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_assignment)]
+    #[holder(type_name = "person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganizationAssignment {
         #[holder(use_place_holder)]
         pub assigned_person_and_organization: PersonAndOrganization,
@@ -5942,7 +9004,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonAndOrganizationAssignmentAny {
         #[holder(use_place_holder)]
         PersonAndOrganizationAssignment(Box<PersonAndOrganizationAssignment>),
@@ -5973,18 +9038,34 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PersonAndOrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_role)]
+    #[holder(type_name = "person_and_organization_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganizationRole {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for PersonAndOrganizationRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_assignment)]
+    #[holder(type_name = "person_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAssignment {
         #[holder(use_place_holder)]
         pub assigned_person: Person,
@@ -5993,7 +9074,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonAssignmentAny {
         #[holder(use_place_holder)]
         PersonAssignment(Box<PersonAssignment>),
@@ -6018,51 +9102,81 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PersonAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_role)]
+    #[holder(type_name = "person_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonRole {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for PersonRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = personal_address)]
+    #[holder(type_name = "personal_address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonalAddress {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub people: Vec<Person>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for PersonalAddress {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.address)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = placement)]
+    #[holder(type_name = "placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Placement {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub location: CartesianPoint,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PlacementAny {
         #[holder(use_place_holder)]
         Placement(Box<Placement>),
@@ -6097,34 +9211,54 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Placement {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = planar_box)]
+    #[holder(type_name = "planar_box")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlanarBox {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub planar_extent: PlanarExtent,
         #[holder(use_place_holder)]
         pub placement: Axis2Placement,
     }
+    impl crate::tables::Validate for PlanarBox {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.planar_extent)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = planar_extent)]
+    #[holder(type_name = "planar_extent")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlanarExtent {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub size_in_x: LengthMeasure,
@@ -6133,7 +9267,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "planar_extent")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PlanarExtentAny {
         #[holder(use_place_holder)]
         PlanarExtent(Box<PlanarExtent>),
@@ -6166,51 +9303,84 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PlanarExtent {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_measure_with_unit)]
+    #[holder(type_name = "plane_angle_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for PlaneAngleMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_unit)]
+    #[holder(type_name = "plane_angle_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for PlaneAngleUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point)]
+    #[holder(type_name = "point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Point {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PointAny {
         #[holder(use_place_holder)]
         Point(Box<Point>),
@@ -6252,57 +9422,91 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Point {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point_on_curve)]
+    #[holder(type_name = "point_on_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PointOnCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
         #[holder(use_place_holder)]
         pub point_parameter: ParameterValue,
     }
+    impl crate::tables::Validate for PointOnCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = polyline)]
+    #[holder(type_name = "polyline")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Polyline {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub points: Vec<CartesianPoint>,
     }
+    impl crate::tables::Validate for Polyline {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_colour)]
+    #[holder(type_name = "pre_defined_colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedColour {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub colour: Colour,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pre_defined_colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PreDefinedColourAny {
         #[holder(use_place_holder)]
         PreDefinedColour(Box<PreDefinedColour>),
@@ -6351,23 +9555,37 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PreDefinedColour {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_item)?;
+            crate::tables::Validate::validate(&self.colour)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_curve_font)]
+    #[holder(type_name = "pre_defined_curve_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedCurveFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pre_defined_curve_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PreDefinedCurveFontAny {
         #[holder(use_place_holder)]
         PreDefinedCurveFont(Box<PreDefinedCurveFont>),
@@ -6404,45 +9622,77 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PreDefinedCurveFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_dimension_symbol)]
+    #[holder(type_name = "pre_defined_dimension_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedDimensionSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    impl crate::tables::Validate for PreDefinedDimensionSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_geometrical_tolerance_symbol)]
+    #[holder(type_name = "pre_defined_geometrical_tolerance_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedGeometricalToleranceSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    impl crate::tables::Validate for PreDefinedGeometricalToleranceSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_item)]
+    #[holder(type_name = "pre_defined_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedItem {
         #[holder(use_place_holder)]
         pub name: Label,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pre_defined_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PreDefinedItemAny {
         #[holder(use_place_holder)]
         PreDefinedItem(Box<PreDefinedItem>),
@@ -6491,37 +9741,59 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PreDefinedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_point_marker_symbol)]
+    #[holder(type_name = "pre_defined_point_marker_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedPointMarkerSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    impl crate::tables::Validate for PreDefinedPointMarkerSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_symbol)]
+    #[holder(type_name = "pre_defined_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pre_defined_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PreDefinedSymbolAny {
         #[holder(use_place_holder)]
         PreDefinedSymbol(Box<PreDefinedSymbol>),
@@ -6591,37 +9863,60 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PreDefinedSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_terminator_symbol)]
+    #[holder(type_name = "pre_defined_terminator_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedTerminatorSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_symbol: PreDefinedSymbol,
     }
+    impl crate::tables::Validate for PreDefinedTerminatorSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_symbol)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pre_defined_text_font)]
+    #[holder(type_name = "pre_defined_text_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PreDefinedTextFont {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pre_defined_item: PreDefinedItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pre_defined_text_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PreDefinedTextFontAny {
         #[holder(use_place_holder)]
         PreDefinedTextFont(Box<PreDefinedTextFont>),
@@ -6658,23 +9953,36 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PreDefinedTextFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pre_defined_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = presentation_area)]
+    #[holder(type_name = "presentation_area")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationArea {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_representation: PresentationRepresentation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_area")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationAreaAny {
         #[holder(use_place_holder)]
         PresentationArea(Box<PresentationArea>),
@@ -6711,10 +10019,19 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PresentationArea {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presentation_representation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_layer_assignment)]
+    #[holder(type_name = "presentation_layer_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationLayerAssignment {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -6723,33 +10040,53 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub assigned_items: Vec<LayeredItem>,
     }
+    impl crate::tables::Validate for PresentationLayerAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_layer_usage)]
+    #[holder(type_name = "presentation_layer_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationLayerUsage {
         #[holder(use_place_holder)]
         pub assignment: PresentationLayerAssignment,
         #[holder(use_place_holder)]
         pub presentation: PresentationRepresentationAny,
     }
+    impl crate::tables::Validate for PresentationLayerUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = presentation_representation)]
+    #[holder(type_name = "presentation_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationRepresentationAny {
         #[holder(use_place_holder)]
         PresentationRepresentation(Box<PresentationRepresentation>),
@@ -6797,14 +10134,26 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PresentationRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_set)]
+    #[holder(type_name = "presentation_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationSet {}
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationSetAny {
         #[holder(use_place_holder)]
         PresentationSet(Box<PresentationSet>),
@@ -6829,27 +10178,46 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PresentationSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_size)]
+    #[holder(type_name = "presentation_size")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationSize {
         #[holder(use_place_holder)]
         pub unit: PresentationSizeAssignmentSelect,
         #[holder(use_place_holder)]
         pub size: PlanarBox,
     }
+    impl crate::tables::Validate for PresentationSize {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presentation_style_assignment)]
+    #[holder(type_name = "presentation_style_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationStyleAssignment {
         #[holder(use_place_holder)]
         pub styles: Vec<PresentationStyleSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presentation_style_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentationStyleAssignmentAny {
         #[holder(use_place_holder)]
         PresentationStyleAssignment(Box<PresentationStyleAssignment>),
@@ -6874,44 +10242,75 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PresentationStyleAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = presentation_style_by_context)]
+    #[holder(type_name = "presentation_style_by_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationStyleByContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_style_assignment: PresentationStyleAssignment,
         #[holder(use_place_holder)]
         pub style_context: StyleContextSelect,
     }
+    impl crate::tables::Validate for PresentationStyleByContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presentation_style_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = presentation_view)]
+    #[holder(type_name = "presentation_view")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentationView {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub presentation_representation: PresentationRepresentation,
     }
+    impl crate::tables::Validate for PresentationView {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.presentation_representation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presented_item)]
+    #[holder(type_name = "presented_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentedItem {}
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "presented_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PresentedItemAny {
         #[holder(use_place_holder)]
         PresentedItem(Box<PresentedItem>),
@@ -6936,20 +10335,36 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PresentedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = presented_item_representation)]
+    #[holder(type_name = "presented_item_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PresentedItemRepresentation {
         #[holder(use_place_holder)]
         pub presentation: PresentationRepresentationSelect,
         #[holder(use_place_holder)]
         pub item: PresentedItemAny,
     }
+    impl crate::tables::Validate for PresentedItemRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product)]
+    #[holder(type_name = "product")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Product {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -6960,26 +10375,44 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub frame_of_reference: Vec<ProductContext>,
     }
+    impl crate::tables::Validate for Product {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_context)]
+    #[holder(type_name = "product_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub discipline_type: Label,
     }
+    impl crate::tables::Validate for ProductContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.application_context_element)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition)]
+    #[holder(type_name = "product_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinition {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -6990,26 +10423,44 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub frame_of_reference: ProductDefinitionContext,
     }
+    impl crate::tables::Validate for ProductDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_context)]
+    #[holder(type_name = "product_definition_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub life_cycle_stage: Label,
     }
+    impl crate::tables::Validate for ProductDefinitionContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.application_context_element)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_formation)]
+    #[holder(type_name = "product_definition_formation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionFormation {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7018,51 +10469,83 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub of_product: Product,
     }
+    impl crate::tables::Validate for ProductDefinitionFormation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_shape)]
+    #[holder(type_name = "product_definition_shape")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionShape {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition: PropertyDefinition,
     }
+    impl crate::tables::Validate for ProductDefinitionShape {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.property_definition)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = projection_curve)]
+    #[holder(type_name = "projection_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProjectionCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_curve_occurrence: AnnotationCurveOccurrence,
     }
+    impl crate::tables::Validate for ProjectionCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_curve_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = projection_directed_callout)]
+    #[holder(type_name = "projection_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProjectionDirectedCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "projection_directed_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProjectionDirectedCalloutAny {
         #[holder(use_place_holder)]
         ProjectionDirectedCallout(Box<ProjectionDirectedCallout>),
@@ -7099,10 +10582,19 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ProjectionDirectedCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition)]
+    #[holder(type_name = "property_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PropertyDefinition {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7113,7 +10605,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "property_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PropertyDefinitionAny {
         #[holder(use_place_holder)]
         PropertyDefinition(Box<PropertyDefinition>),
@@ -7138,10 +10633,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PropertyDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition_representation)]
+    #[holder(type_name = "property_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PropertyDefinitionRepresentation {
         #[holder(use_place_holder)]
         pub definition: PropertyDefinitionAny,
@@ -7150,7 +10653,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "property_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PropertyDefinitionRepresentationAny {
         #[holder(use_place_holder)]
         PropertyDefinitionRepresentation(Box<PropertyDefinitionRepresentation>),
@@ -7181,53 +10687,91 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for PropertyDefinitionRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = quasi_uniform_curve)]
+    #[holder(type_name = "quasi_uniform_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct QuasiUniformCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for QuasiUniformCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = radius_dimension)]
+    #[holder(type_name = "radius_dimension")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RadiusDimension {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub dimension_curve_directed_callout: DimensionCurveDirectedCallout,
     }
+    impl crate::tables::Validate for RadiusDimension {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.dimension_curve_directed_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = rational_b_spline_curve)]
+    #[holder(type_name = "rational_b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RationalBSplineCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub weights_data: Vec<f64>,
     }
+    impl crate::tables::Validate for RationalBSplineCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation)]
+    #[holder(type_name = "representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Representation {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7238,7 +10782,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationAny {
         #[holder(use_place_holder)]
         Representation(Box<Representation>),
@@ -7287,10 +10834,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for Representation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_context)]
+    #[holder(type_name = "representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationContext {
         #[holder(use_place_holder)]
         pub context_identifier: Identifier,
@@ -7299,7 +10854,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationContextAny {
         #[holder(use_place_holder)]
         RepresentationContext(Box<RepresentationContext>),
@@ -7332,17 +10890,28 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_item)]
+    #[holder(type_name = "representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationItem {
         #[holder(use_place_holder)]
         pub name: Label,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationItemAny {
         #[holder(use_place_holder)]
         RepresentationItem(Box<RepresentationItem>),
@@ -7383,10 +10952,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_map)]
+    #[holder(type_name = "representation_map")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationMap {
         #[holder(use_place_holder)]
         pub mapping_origin: RepresentationItemAny,
@@ -7395,7 +10972,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_map")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationMapAny {
         #[holder(use_place_holder)]
         RepresentationMap(Box<RepresentationMap>),
@@ -7428,10 +11008,18 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationMap {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification)]
+    #[holder(type_name = "security_classification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassification {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7440,17 +11028,28 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub security_level: SecurityClassificationLevel,
     }
+    impl crate::tables::Validate for SecurityClassification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_assignment)]
+    #[holder(type_name = "security_classification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassificationAssignment {
         #[holder(use_place_holder)]
         pub assigned_security_classification: SecurityClassification,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "security_classification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SecurityClassificationAssignmentAny {
         #[holder(use_place_holder)]
         SecurityClassificationAssignment(Box<SecurityClassificationAssignment>),
@@ -7481,45 +11080,75 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for SecurityClassificationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_level)]
+    #[holder(type_name = "security_classification_level")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassificationLevel {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for SecurityClassificationLevel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shape_definition_representation)]
+    #[holder(type_name = "shape_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeDefinitionRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
+    impl crate::tables::Validate for ShapeDefinitionRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.property_definition_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shape_representation)]
+    #[holder(type_name = "shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ShapeRepresentationAny {
         #[holder(use_place_holder)]
         ShapeRepresentation(Box<ShapeRepresentation>),
@@ -7562,48 +11191,78 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for ShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = si_unit)]
+    #[holder(type_name = "si_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SiUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
     }
+    impl crate::tables::Validate for SiUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = structured_dimension_callout)]
+    #[holder(type_name = "structured_dimension_callout")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct StructuredDimensionCallout {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub draughting_callout: DraughtingCallout,
     }
+    impl crate::tables::Validate for StructuredDimensionCallout {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.draughting_callout)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = styled_item)]
+    #[holder(type_name = "styled_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct StyledItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub styles: Vec<PresentationStyleAssignmentAny>,
@@ -7612,7 +11271,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "styled_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum StyledItemAny {
         #[holder(use_place_holder)]
         StyledItem(Box<StyledItem>),
@@ -7647,31 +11309,52 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for StyledItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = symbol_colour)]
+    #[holder(type_name = "symbol_colour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SymbolColour {
         #[holder(use_place_holder)]
         pub colour_of_symbol: ColourAny,
     }
+    impl crate::tables::Validate for SymbolColour {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = symbol_representation)]
+    #[holder(type_name = "symbol_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SymbolRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "symbol_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SymbolRepresentationAny {
         #[holder(use_place_holder)]
         SymbolRepresentation(Box<SymbolRepresentation>),
@@ -7719,42 +11402,70 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for SymbolRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = symbol_representation_map)]
+    #[holder(type_name = "symbol_representation_map")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SymbolRepresentationMap {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_map: RepresentationMap,
     }
+    impl crate::tables::Validate for SymbolRepresentationMap {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_map)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = symbol_style)]
+    #[holder(type_name = "symbol_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SymbolStyle {
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub style_of_symbol: SymbolStyleSelect,
     }
+    impl crate::tables::Validate for SymbolStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = symbol_target)]
+    #[holder(type_name = "symbol_target")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SymbolTarget {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub placement: Axis2Placement,
@@ -7763,25 +11474,38 @@ pub mod explicit_draughting {
         #[holder(use_place_holder)]
         pub y_scale: PositiveRatioMeasure,
     }
+    impl crate::tables::Validate for SymbolTarget {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = terminator_symbol)]
+    #[holder(type_name = "terminator_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TerminatorSymbol {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub annotation_symbol_occurrence: AnnotationSymbolOccurrence,
         #[holder(use_place_holder)]
         pub annotated_curve: AnnotationCurveOccurrenceAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "terminator_symbol")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TerminatorSymbolAny {
         #[holder(use_place_holder)]
         TerminatorSymbol(Box<TerminatorSymbol>),
@@ -7829,18 +11553,28 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for TerminatorSymbol {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.annotation_symbol_occurrence)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_literal)]
+    #[holder(type_name = "text_literal")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextLiteral {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub literal: PresentableText,
@@ -7854,7 +11588,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "text_literal")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TextLiteralAny {
         #[holder(use_place_holder)]
         TextLiteral(Box<TextLiteral>),
@@ -7922,57 +11659,90 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for TextLiteral {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_literal_with_associated_curves)]
+    #[holder(type_name = "text_literal_with_associated_curves")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextLiteralWithAssociatedCurves {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub associated_curves: Vec<CurveAny>,
     }
+    impl crate::tables::Validate for TextLiteralWithAssociatedCurves {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_literal)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_literal_with_blanking_box)]
+    #[holder(type_name = "text_literal_with_blanking_box")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextLiteralWithBlankingBox {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub blanking: PlanarBox,
     }
+    impl crate::tables::Validate for TextLiteralWithBlankingBox {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_literal)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_literal_with_delineation)]
+    #[holder(type_name = "text_literal_with_delineation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextLiteralWithDelineation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub delineation: TextDelineation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "text_literal_with_delineation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TextLiteralWithDelineationAny {
         #[holder(use_place_holder)]
         TextLiteralWithDelineation(Box<TextLiteralWithDelineation>),
@@ -8013,26 +11783,45 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for TextLiteralWithDelineation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_literal)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_literal_with_extent)]
+    #[holder(type_name = "text_literal_with_extent")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextLiteralWithExtent {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_literal: TextLiteral,
         #[holder(use_place_holder)]
         pub extent: PlanarExtentAny,
     }
+    impl crate::tables::Validate for TextLiteralWithExtent {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_literal)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = text_style)]
+    #[holder(type_name = "text_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextStyle {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -8041,7 +11830,10 @@ pub mod explicit_draughting {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "text_style")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TextStyleAny {
         #[holder(use_place_holder)]
         TextStyle(Box<TextStyle>),
@@ -8074,58 +11866,95 @@ pub mod explicit_draughting {
             }
         }
     }
+    impl crate::tables::Validate for TextStyle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = text_style_for_defined_font)]
+    #[holder(type_name = "text_style_for_defined_font")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextStyleForDefinedFont {
         #[holder(use_place_holder)]
         pub text_colour: ColourAny,
     }
+    impl crate::tables::Validate for TextStyleForDefinedFont {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_style_with_box_characteristics)]
+    #[holder(type_name = "text_style_with_box_characteristics")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextStyleWithBoxCharacteristics {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_style: TextStyle,
         #[holder(use_place_holder)]
         pub characteristics: Vec<BoxCharacteristicSelect>,
     }
+    impl crate::tables::Validate for TextStyleWithBoxCharacteristics {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_style)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = text_style_with_mirror)]
+    #[holder(type_name = "text_style_with_mirror")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TextStyleWithMirror {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub text_style: TextStyle,
         #[holder(use_place_holder)]
         pub mirror_placement: Axis2Placement,
     }
+    impl crate::tables::Validate for TextStyleWithMirror {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.text_style)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = trimmed_curve)]
+    #[holder(type_name = "trimmed_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TrimmedCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -8136,52 +11965,88 @@ pub mod explicit_draughting {
         pub sense_agreement: bool,
         pub master_representation: TrimmingPreference,
     }
+    impl crate::tables::Validate for TrimmedCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = two_direction_repeat_factor)]
+    #[holder(type_name = "two_direction_repeat_factor")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TwoDirectionRepeatFactor {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub one_direction_repeat_factor: OneDirectionRepeatFactor,
         #[holder(use_place_holder)]
         pub second_repeat_factor: Vector,
     }
+    impl crate::tables::Validate for TwoDirectionRepeatFactor {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.one_direction_repeat_factor)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = uniform_curve)]
+    #[holder(type_name = "uniform_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct UniformCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for UniformCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = vector)]
+    #[holder(type_name = "vector")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Vector {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub orientation: Direction,
         #[holder(use_place_holder)]
         pub magnitude: LengthMeasure,
     }
+    impl crate::tables::Validate for Vector {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
 }
@@ -1,1526 +1,2469 @@
 #![allow(dead_code)]
+#[doc = r" Identifiers of all EXPRESS schemas, e.g. for `FILE_SCHEMA` header record"]
+pub const SCHEMA_IDENTIFIERS: &[&str] = &["CONFIG_CONTROL_DESIGN"];
 pub mod config_control_design {
     use crate::{as_holder, derive_more::*, primitive::*, Holder, TableInit};
     use std::collections::HashMap;
     #[derive(Debug, Clone, PartialEq, Default, TableInit)]
+    #[table(into_parameter)]
     pub struct Tables {
-        action: HashMap<u64, as_holder!(Action)>,
-        action_assignment: HashMap<u64, as_holder!(ActionAssignment)>,
-        action_directive: HashMap<u64, as_holder!(ActionDirective)>,
-        action_method: HashMap<u64, as_holder!(ActionMethod)>,
-        action_request_assignment: HashMap<u64, as_holder!(ActionRequestAssignment)>,
-        action_request_solution: HashMap<u64, as_holder!(ActionRequestSolution)>,
-        action_request_status: HashMap<u64, as_holder!(ActionRequestStatus)>,
-        action_status: HashMap<u64, as_holder!(ActionStatus)>,
-        address: HashMap<u64, as_holder!(Address)>,
+        action: HashMap<crate::tables::InstanceId, as_holder!(Action)>,
+        action_assignment: HashMap<crate::tables::InstanceId, as_holder!(ActionAssignment)>,
+        action_directive: HashMap<crate::tables::InstanceId, as_holder!(ActionDirective)>,
+        action_method: HashMap<crate::tables::InstanceId, as_holder!(ActionMethod)>,
+        action_request_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(ActionRequestAssignment)>,
+        action_request_solution:
+            HashMap<crate::tables::InstanceId, as_holder!(ActionRequestSolution)>,
+        action_request_status: HashMap<crate::tables::InstanceId, as_holder!(ActionRequestStatus)>,
+        action_status: HashMap<crate::tables::InstanceId, as_holder!(ActionStatus)>,
+        #[table(validate)]
+        address: HashMap<crate::tables::InstanceId, as_holder!(Address)>,
+        #[table(validate)]
         advanced_brep_shape_representation:
-            HashMap<u64, as_holder!(AdvancedBrepShapeRepresentation)>,
-        advanced_face: HashMap<u64, as_holder!(AdvancedFace)>,
-        alternate_product_relationship: HashMap<u64, as_holder!(AlternateProductRelationship)>,
-        application_context: HashMap<u64, as_holder!(ApplicationContext)>,
-        application_context_element: HashMap<u64, as_holder!(ApplicationContextElement)>,
-        application_protocol_definition: HashMap<u64, as_holder!(ApplicationProtocolDefinition)>,
-        approval: HashMap<u64, as_holder!(Approval)>,
-        approval_assignment: HashMap<u64, as_holder!(ApprovalAssignment)>,
-        approval_date_time: HashMap<u64, as_holder!(ApprovalDateTime)>,
-        approval_person_organization: HashMap<u64, as_holder!(ApprovalPersonOrganization)>,
-        approval_relationship: HashMap<u64, as_holder!(ApprovalRelationship)>,
-        approval_role: HashMap<u64, as_holder!(ApprovalRole)>,
-        approval_status: HashMap<u64, as_holder!(ApprovalStatus)>,
-        area_measure_with_unit: HashMap<u64, as_holder!(AreaMeasureWithUnit)>,
-        area_unit: HashMap<u64, as_holder!(AreaUnit)>,
-        assembly_component_usage: HashMap<u64, as_holder!(AssemblyComponentUsage)>,
+            HashMap<crate::tables::InstanceId, as_holder!(AdvancedBrepShapeRepresentation)>,
+        #[table(validate)]
+        advanced_face: HashMap<crate::tables::InstanceId, as_holder!(AdvancedFace)>,
+        #[table(validate)]
+        alternate_product_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(AlternateProductRelationship)>,
+        application_context: HashMap<crate::tables::InstanceId, as_holder!(ApplicationContext)>,
+        application_context_element:
+            HashMap<crate::tables::InstanceId, as_holder!(ApplicationContextElement)>,
+        application_protocol_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(ApplicationProtocolDefinition)>,
+        approval: HashMap<crate::tables::InstanceId, as_holder!(Approval)>,
+        approval_assignment: HashMap<crate::tables::InstanceId, as_holder!(ApprovalAssignment)>,
+        approval_date_time: HashMap<crate::tables::InstanceId, as_holder!(ApprovalDateTime)>,
+        approval_person_organization:
+            HashMap<crate::tables::InstanceId, as_holder!(ApprovalPersonOrganization)>,
+        approval_relationship: HashMap<crate::tables::InstanceId, as_holder!(ApprovalRelationship)>,
+        approval_role: HashMap<crate::tables::InstanceId, as_holder!(ApprovalRole)>,
+        approval_status: HashMap<crate::tables::InstanceId, as_holder!(ApprovalStatus)>,
+        #[table(validate)]
+        area_measure_with_unit: HashMap<crate::tables::InstanceId, as_holder!(AreaMeasureWithUnit)>,
+        #[table(validate)]
+        area_unit: HashMap<crate::tables::InstanceId, as_holder!(AreaUnit)>,
+        #[table(validate)]
+        assembly_component_usage:
+            HashMap<crate::tables::InstanceId, as_holder!(AssemblyComponentUsage)>,
+        #[table(validate)]
         assembly_component_usage_substitute:
-            HashMap<u64, as_holder!(AssemblyComponentUsageSubstitute)>,
-        axis1_placement: HashMap<u64, as_holder!(Axis1Placement)>,
-        axis2_placement_2d: HashMap<u64, as_holder!(Axis2Placement2D)>,
-        axis2_placement_3d: HashMap<u64, as_holder!(Axis2Placement3D)>,
-        b_spline_curve: HashMap<u64, as_holder!(BSplineCurve)>,
-        b_spline_curve_with_knots: HashMap<u64, as_holder!(BSplineCurveWithKnots)>,
-        b_spline_surface: HashMap<u64, as_holder!(BSplineSurface)>,
-        b_spline_surface_with_knots: HashMap<u64, as_holder!(BSplineSurfaceWithKnots)>,
-        bezier_curve: HashMap<u64, as_holder!(BezierCurve)>,
-        bezier_surface: HashMap<u64, as_holder!(BezierSurface)>,
-        boundary_curve: HashMap<u64, as_holder!(BoundaryCurve)>,
-        bounded_curve: HashMap<u64, as_holder!(BoundedCurve)>,
-        bounded_pcurve: HashMap<u64, as_holder!(BoundedPcurve)>,
-        bounded_surface: HashMap<u64, as_holder!(BoundedSurface)>,
-        bounded_surface_curve: HashMap<u64, as_holder!(BoundedSurfaceCurve)>,
-        brep_with_voids: HashMap<u64, as_holder!(BrepWithVoids)>,
-        calendar_date: HashMap<u64, as_holder!(CalendarDate)>,
-        cartesian_point: HashMap<u64, as_holder!(CartesianPoint)>,
+            HashMap<crate::tables::InstanceId, as_holder!(AssemblyComponentUsageSubstitute)>,
+        #[table(validate)]
+        axis1_placement: HashMap<crate::tables::InstanceId, as_holder!(Axis1Placement)>,
+        #[table(validate)]
+        axis2_placement_2d: HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement2D)>,
+        #[table(validate)]
+        axis2_placement_3d: HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement3D)>,
+        #[table(validate)]
+        b_spline_curve: HashMap<crate::tables::InstanceId, as_holder!(BSplineCurve)>,
+        #[table(validate)]
+        b_spline_curve_with_knots:
+            HashMap<crate::tables::InstanceId, as_holder!(BSplineCurveWithKnots)>,
+        #[table(validate)]
+        b_spline_surface: HashMap<crate::tables::InstanceId, as_holder!(BSplineSurface)>,
+        #[table(validate)]
+        b_spline_surface_with_knots:
+            HashMap<crate::tables::InstanceId, as_holder!(BSplineSurfaceWithKnots)>,
+        #[table(validate)]
+        bezier_curve: HashMap<crate::tables::InstanceId, as_holder!(BezierCurve)>,
+        #[table(validate)]
+        bezier_surface: HashMap<crate::tables::InstanceId, as_holder!(BezierSurface)>,
+        #[table(validate)]
+        boundary_curve: HashMap<crate::tables::InstanceId, as_holder!(BoundaryCurve)>,
+        #[table(validate)]
+        bounded_curve: HashMap<crate::tables::InstanceId, as_holder!(BoundedCurve)>,
+        #[table(validate)]
+        bounded_pcurve: HashMap<crate::tables::InstanceId, as_holder!(BoundedPcurve)>,
+        #[table(validate)]
+        bounded_surface: HashMap<crate::tables::InstanceId, as_holder!(BoundedSurface)>,
+        #[table(validate)]
+        bounded_surface_curve: HashMap<crate::tables::InstanceId, as_holder!(BoundedSurfaceCurve)>,
+        #[table(validate)]
+        brep_with_voids: HashMap<crate::tables::InstanceId, as_holder!(BrepWithVoids)>,
+        #[table(validate)]
+        calendar_date: HashMap<crate::tables::InstanceId, as_holder!(CalendarDate)>,
+        #[table(validate)]
+        cartesian_point: HashMap<crate::tables::InstanceId, as_holder!(CartesianPoint)>,
+        #[table(validate)]
         cartesian_transformation_operator:
-            HashMap<u64, as_holder!(CartesianTransformationOperator)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CartesianTransformationOperator)>,
+        #[table(validate)]
         cartesian_transformation_operator_3d:
-            HashMap<u64, as_holder!(CartesianTransformationOperator3D)>,
-        cc_design_approval: HashMap<u64, as_holder!(CcDesignApproval)>,
-        cc_design_certification: HashMap<u64, as_holder!(CcDesignCertification)>,
-        cc_design_contract: HashMap<u64, as_holder!(CcDesignContract)>,
-        cc_design_date_and_time_assignment: HashMap<u64, as_holder!(CcDesignDateAndTimeAssignment)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CartesianTransformationOperator3D)>,
+        cc_design_approval: HashMap<crate::tables::InstanceId, as_holder!(CcDesignApproval)>,
+        cc_design_certification:
+            HashMap<crate::tables::InstanceId, as_holder!(CcDesignCertification)>,
+        cc_design_contract: HashMap<crate::tables::InstanceId, as_holder!(CcDesignContract)>,
+        #[table(validate)]
+        cc_design_date_and_time_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(CcDesignDateAndTimeAssignment)>,
+        #[table(validate)]
         cc_design_person_and_organization_assignment:
-            HashMap<u64, as_holder!(CcDesignPersonAndOrganizationAssignment)>,
-        cc_design_security_classification: HashMap<u64, as_holder!(CcDesignSecurityClassification)>,
-        cc_design_specification_reference: HashMap<u64, as_holder!(CcDesignSpecificationReference)>,
-        certification: HashMap<u64, as_holder!(Certification)>,
-        certification_assignment: HashMap<u64, as_holder!(CertificationAssignment)>,
-        certification_type: HashMap<u64, as_holder!(CertificationType)>,
-        change: HashMap<u64, as_holder!(Change)>,
-        change_request: HashMap<u64, as_holder!(ChangeRequest)>,
-        circle: HashMap<u64, as_holder!(Circle)>,
-        closed_shell: HashMap<u64, as_holder!(ClosedShell)>,
-        composite_curve: HashMap<u64, as_holder!(CompositeCurve)>,
-        composite_curve_on_surface: HashMap<u64, as_holder!(CompositeCurveOnSurface)>,
-        composite_curve_segment: HashMap<u64, as_holder!(CompositeCurveSegment)>,
-        configuration_design: HashMap<u64, as_holder!(ConfigurationDesign)>,
-        configuration_effectivity: HashMap<u64, as_holder!(ConfigurationEffectivity)>,
-        configuration_item: HashMap<u64, as_holder!(ConfigurationItem)>,
-        conic: HashMap<u64, as_holder!(Conic)>,
-        conical_surface: HashMap<u64, as_holder!(ConicalSurface)>,
-        connected_edge_set: HashMap<u64, as_holder!(ConnectedEdgeSet)>,
-        connected_face_set: HashMap<u64, as_holder!(ConnectedFaceSet)>,
+            HashMap<crate::tables::InstanceId, as_holder!(CcDesignPersonAndOrganizationAssignment)>,
+        cc_design_security_classification:
+            HashMap<crate::tables::InstanceId, as_holder!(CcDesignSecurityClassification)>,
+        cc_design_specification_reference:
+            HashMap<crate::tables::InstanceId, as_holder!(CcDesignSpecificationReference)>,
+        certification: HashMap<crate::tables::InstanceId, as_holder!(Certification)>,
+        certification_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(CertificationAssignment)>,
+        certification_type: HashMap<crate::tables::InstanceId, as_holder!(CertificationType)>,
+        change: HashMap<crate::tables::InstanceId, as_holder!(Change)>,
+        change_request: HashMap<crate::tables::InstanceId, as_holder!(ChangeRequest)>,
+        #[table(validate)]
+        circle: HashMap<crate::tables::InstanceId, as_holder!(Circle)>,
+        #[table(validate)]
+        closed_shell: HashMap<crate::tables::InstanceId, as_holder!(ClosedShell)>,
+        #[table(validate)]
+        composite_curve: HashMap<crate::tables::InstanceId, as_holder!(CompositeCurve)>,
+        #[table(validate)]
+        composite_curve_on_surface:
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveOnSurface)>,
+        #[table(validate)]
+        composite_curve_segment:
+            HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveSegment)>,
+        configuration_design: HashMap<crate::tables::InstanceId, as_holder!(ConfigurationDesign)>,
+        #[table(validate)]
+        configuration_effectivity:
+            HashMap<crate::tables::InstanceId, as_holder!(ConfigurationEffectivity)>,
+        configuration_item: HashMap<crate::tables::InstanceId, as_holder!(ConfigurationItem)>,
+        #[table(validate)]
+        conic: HashMap<crate::tables::InstanceId, as_holder!(Conic)>,
+        #[table(validate)]
+        conical_surface: HashMap<crate::tables::InstanceId, as_holder!(ConicalSurface)>,
+        #[table(validate)]
+        connected_edge_set: HashMap<crate::tables::InstanceId, as_holder!(ConnectedEdgeSet)>,
+        #[table(validate)]
+        connected_face_set: HashMap<crate::tables::InstanceId, as_holder!(ConnectedFaceSet)>,
+        #[table(validate)]
         context_dependent_shape_representation:
-            HashMap<u64, as_holder!(ContextDependentShapeRepresentation)>,
-        context_dependent_unit: HashMap<u64, as_holder!(ContextDependentUnit)>,
-        contract: HashMap<u64, as_holder!(Contract)>,
-        contract_assignment: HashMap<u64, as_holder!(ContractAssignment)>,
-        contract_type: HashMap<u64, as_holder!(ContractType)>,
-        conversion_based_unit: HashMap<u64, as_holder!(ConversionBasedUnit)>,
-        coordinated_universal_time_offset: HashMap<u64, as_holder!(CoordinatedUniversalTimeOffset)>,
-        curve: HashMap<u64, as_holder!(Curve)>,
-        curve_bounded_surface: HashMap<u64, as_holder!(CurveBoundedSurface)>,
-        curve_replica: HashMap<u64, as_holder!(CurveReplica)>,
-        cylindrical_surface: HashMap<u64, as_holder!(CylindricalSurface)>,
-        date: HashMap<u64, as_holder!(Date)>,
-        date_and_time: HashMap<u64, as_holder!(DateAndTime)>,
-        date_and_time_assignment: HashMap<u64, as_holder!(DateAndTimeAssignment)>,
-        date_time_role: HashMap<u64, as_holder!(DateTimeRole)>,
-        dated_effectivity: HashMap<u64, as_holder!(DatedEffectivity)>,
-        definitional_representation: HashMap<u64, as_holder!(DefinitionalRepresentation)>,
-        degenerate_pcurve: HashMap<u64, as_holder!(DegeneratePcurve)>,
-        degenerate_toroidal_surface: HashMap<u64, as_holder!(DegenerateToroidalSurface)>,
-        design_context: HashMap<u64, as_holder!(DesignContext)>,
-        design_make_from_relationship: HashMap<u64, as_holder!(DesignMakeFromRelationship)>,
-        dimensional_exponents: HashMap<u64, as_holder!(DimensionalExponents)>,
-        directed_action: HashMap<u64, as_holder!(DirectedAction)>,
-        direction: HashMap<u64, as_holder!(Direction)>,
-        document: HashMap<u64, as_holder!(Document)>,
-        document_reference: HashMap<u64, as_holder!(DocumentReference)>,
-        document_relationship: HashMap<u64, as_holder!(DocumentRelationship)>,
-        document_type: HashMap<u64, as_holder!(DocumentType)>,
-        document_usage_constraint: HashMap<u64, as_holder!(DocumentUsageConstraint)>,
-        document_with_class: HashMap<u64, as_holder!(DocumentWithClass)>,
-        edge: HashMap<u64, as_holder!(Edge)>,
-        edge_based_wireframe_model: HashMap<u64, as_holder!(EdgeBasedWireframeModel)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ContextDependentShapeRepresentation)>,
+        context_dependent_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(ContextDependentUnit)>,
+        contract: HashMap<crate::tables::InstanceId, as_holder!(Contract)>,
+        contract_assignment: HashMap<crate::tables::InstanceId, as_holder!(ContractAssignment)>,
+        contract_type: HashMap<crate::tables::InstanceId, as_holder!(ContractType)>,
+        conversion_based_unit: HashMap<crate::tables::InstanceId, as_holder!(ConversionBasedUnit)>,
+        coordinated_universal_time_offset:
+            HashMap<crate::tables::InstanceId, as_holder!(CoordinatedUniversalTimeOffset)>,
+        #[table(validate)]
+        curve: HashMap<crate::tables::InstanceId, as_holder!(Curve)>,
+        #[table(validate)]
+        curve_bounded_surface: HashMap<crate::tables::InstanceId, as_holder!(CurveBoundedSurface)>,
+        #[table(validate)]
+        curve_replica: HashMap<crate::tables::InstanceId, as_holder!(CurveReplica)>,
+        #[table(validate)]
+        cylindrical_surface: HashMap<crate::tables::InstanceId, as_holder!(CylindricalSurface)>,
+        date: HashMap<crate::tables::InstanceId, as_holder!(Date)>,
+        date_and_time: HashMap<crate::tables::InstanceId, as_holder!(DateAndTime)>,
+        date_and_time_assignment:
+            HashMap<crate::tables::InstanceId, as_holder!(DateAndTimeAssignment)>,
+        date_time_role: HashMap<crate::tables::InstanceId, as_holder!(DateTimeRole)>,
+        dated_effectivity: HashMap<crate::tables::InstanceId, as_holder!(DatedEffectivity)>,
+        #[table(validate)]
+        definitional_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(DefinitionalRepresentation)>,
+        #[table(validate)]
+        degenerate_pcurve: HashMap<crate::tables::InstanceId, as_holder!(DegeneratePcurve)>,
+        #[table(validate)]
+        degenerate_toroidal_surface:
+            HashMap<crate::tables::InstanceId, as_holder!(DegenerateToroidalSurface)>,
+        #[table(validate)]
+        design_context: HashMap<crate::tables::InstanceId, as_holder!(DesignContext)>,
+        design_make_from_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(DesignMakeFromRelationship)>,
+        dimensional_exponents: HashMap<crate::tables::InstanceId, as_holder!(DimensionalExponents)>,
+        directed_action: HashMap<crate::tables::InstanceId, as_holder!(DirectedAction)>,
+        #[table(validate)]
+        direction: HashMap<crate::tables::InstanceId, as_holder!(Direction)>,
+        document: HashMap<crate::tables::InstanceId, as_holder!(Document)>,
+        document_reference: HashMap<crate::tables::InstanceId, as_holder!(DocumentReference)>,
+        document_relationship: HashMap<crate::tables::InstanceId, as_holder!(DocumentRelationship)>,
+        document_type: HashMap<crate::tables::InstanceId, as_holder!(DocumentType)>,
+        document_usage_constraint:
+            HashMap<crate::tables::InstanceId, as_holder!(DocumentUsageConstraint)>,
+        document_with_class: HashMap<crate::tables::InstanceId, as_holder!(DocumentWithClass)>,
+        #[table(validate)]
+        edge: HashMap<crate::tables::InstanceId, as_holder!(Edge)>,
+        #[table(validate)]
+        edge_based_wireframe_model:
+            HashMap<crate::tables::InstanceId, as_holder!(EdgeBasedWireframeModel)>,
+        #[table(validate)]
         edge_based_wireframe_shape_representation:
-            HashMap<u64, as_holder!(EdgeBasedWireframeShapeRepresentation)>,
-        edge_curve: HashMap<u64, as_holder!(EdgeCurve)>,
-        edge_loop: HashMap<u64, as_holder!(EdgeLoop)>,
-        effectivity: HashMap<u64, as_holder!(Effectivity)>,
-        elementary_surface: HashMap<u64, as_holder!(ElementarySurface)>,
-        ellipse: HashMap<u64, as_holder!(Ellipse)>,
-        evaluated_degenerate_pcurve: HashMap<u64, as_holder!(EvaluatedDegeneratePcurve)>,
-        executed_action: HashMap<u64, as_holder!(ExecutedAction)>,
-        face: HashMap<u64, as_holder!(Face)>,
-        face_bound: HashMap<u64, as_holder!(FaceBound)>,
-        face_outer_bound: HashMap<u64, as_holder!(FaceOuterBound)>,
-        face_surface: HashMap<u64, as_holder!(FaceSurface)>,
-        faceted_brep: HashMap<u64, as_holder!(FacetedBrep)>,
-        faceted_brep_shape_representation: HashMap<u64, as_holder!(FacetedBrepShapeRepresentation)>,
-        founded_item: HashMap<u64, as_holder!(FoundedItem)>,
+            HashMap<crate::tables::InstanceId, as_holder!(EdgeBasedWireframeShapeRepresentation)>,
+        #[table(validate)]
+        edge_curve: HashMap<crate::tables::InstanceId, as_holder!(EdgeCurve)>,
+        #[table(validate)]
+        edge_loop: HashMap<crate::tables::InstanceId, as_holder!(EdgeLoop)>,
+        effectivity: HashMap<crate::tables::InstanceId, as_holder!(Effectivity)>,
+        #[table(validate)]
+        elementary_surface: HashMap<crate::tables::InstanceId, as_holder!(ElementarySurface)>,
+        #[table(validate)]
+        ellipse: HashMap<crate::tables::InstanceId, as_holder!(Ellipse)>,
+        #[table(validate)]
+        evaluated_degenerate_pcurve:
+            HashMap<crate::tables::InstanceId, as_holder!(EvaluatedDegeneratePcurve)>,
+        executed_action: HashMap<crate::tables::InstanceId, as_holder!(ExecutedAction)>,
+        #[table(validate)]
+        face: HashMap<crate::tables::InstanceId, as_holder!(Face)>,
+        #[table(validate)]
+        face_bound: HashMap<crate::tables::InstanceId, as_holder!(FaceBound)>,
+        #[table(validate)]
+        face_outer_bound: HashMap<crate::tables::InstanceId, as_holder!(FaceOuterBound)>,
+        #[table(validate)]
+        face_surface: HashMap<crate::tables::InstanceId, as_holder!(FaceSurface)>,
+        #[table(validate)]
+        faceted_brep: HashMap<crate::tables::InstanceId, as_holder!(FacetedBrep)>,
+        #[table(validate)]
+        faceted_brep_shape_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(FacetedBrepShapeRepresentation)>,
+        founded_item: HashMap<crate::tables::InstanceId, as_holder!(FoundedItem)>,
         functionally_defined_transformation:
-            HashMap<u64, as_holder!(FunctionallyDefinedTransformation)>,
-        geometric_curve_set: HashMap<u64, as_holder!(GeometricCurveSet)>,
-        geometric_representation_context: HashMap<u64, as_holder!(GeometricRepresentationContext)>,
-        geometric_representation_item: HashMap<u64, as_holder!(GeometricRepresentationItem)>,
-        geometric_set: HashMap<u64, as_holder!(GeometricSet)>,
-        geometrically_bounded_surface_shape_representation:
-            HashMap<u64, as_holder!(GeometricallyBoundedSurfaceShapeRepresentation)>,
-        geometrically_bounded_wireframe_shape_representation:
-            HashMap<u64, as_holder!(GeometricallyBoundedWireframeShapeRepresentation)>,
+            HashMap<crate::tables::InstanceId, as_holder!(FunctionallyDefinedTransformation)>,
+        #[table(validate)]
+        geometric_curve_set: HashMap<crate::tables::InstanceId, as_holder!(GeometricCurveSet)>,
+        geometric_representation_context:
+            HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationContext)>,
+        #[table(validate)]
+        geometric_representation_item:
+            HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationItem)>,
+        #[table(validate)]
+        geometric_set: HashMap<crate::tables::InstanceId, as_holder!(GeometricSet)>,
+        #[table(validate)]
+        geometrically_bounded_surface_shape_representation: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBoundedSurfaceShapeRepresentation),
+        >,
+        #[table(validate)]
+        geometrically_bounded_wireframe_shape_representation: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBoundedWireframeShapeRepresentation),
+        >,
         global_uncertainty_assigned_context:
-            HashMap<u64, as_holder!(GlobalUncertaintyAssignedContext)>,
-        global_unit_assigned_context: HashMap<u64, as_holder!(GlobalUnitAssignedContext)>,
-        hyperbola: HashMap<u64, as_holder!(Hyperbola)>,
-        intersection_curve: HashMap<u64, as_holder!(IntersectionCurve)>,
-        item_defined_transformation: HashMap<u64, as_holder!(ItemDefinedTransformation)>,
-        length_measure_with_unit: HashMap<u64, as_holder!(LengthMeasureWithUnit)>,
-        length_unit: HashMap<u64, as_holder!(LengthUnit)>,
-        line: HashMap<u64, as_holder!(Line)>,
-        local_time: HashMap<u64, as_holder!(LocalTime)>,
-        r#loop: HashMap<u64, as_holder!(Loop)>,
-        lot_effectivity: HashMap<u64, as_holder!(LotEffectivity)>,
-        manifold_solid_brep: HashMap<u64, as_holder!(ManifoldSolidBrep)>,
+            HashMap<crate::tables::InstanceId, as_holder!(GlobalUncertaintyAssignedContext)>,
+        global_unit_assigned_context:
+            HashMap<crate::tables::InstanceId, as_holder!(GlobalUnitAssignedContext)>,
+        #[table(validate)]
+        hyperbola: HashMap<crate::tables::InstanceId, as_holder!(Hyperbola)>,
+        #[table(validate)]
+        intersection_curve: HashMap<crate::tables::InstanceId, as_holder!(IntersectionCurve)>,
+        item_defined_transformation:
+            HashMap<crate::tables::InstanceId, as_holder!(ItemDefinedTransformation)>,
+        #[table(validate)]
+        length_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(LengthMeasureWithUnit)>,
+        #[table(validate)]
+        length_unit: HashMap<crate::tables::InstanceId, as_holder!(LengthUnit)>,
+        #[table(validate)]
+        line: HashMap<crate::tables::InstanceId, as_holder!(Line)>,
+        #[table(validate)]
+        local_time: HashMap<crate::tables::InstanceId, as_holder!(LocalTime)>,
+        #[table(validate)]
+        r#loop: HashMap<crate::tables::InstanceId, as_holder!(Loop)>,
+        lot_effectivity: HashMap<crate::tables::InstanceId, as_holder!(LotEffectivity)>,
+        #[table(validate)]
+        manifold_solid_brep: HashMap<crate::tables::InstanceId, as_holder!(ManifoldSolidBrep)>,
+        #[table(validate)]
         manifold_surface_shape_representation:
-            HashMap<u64, as_holder!(ManifoldSurfaceShapeRepresentation)>,
-        mapped_item: HashMap<u64, as_holder!(MappedItem)>,
-        mass_measure_with_unit: HashMap<u64, as_holder!(MassMeasureWithUnit)>,
-        mass_unit: HashMap<u64, as_holder!(MassUnit)>,
-        measure_with_unit: HashMap<u64, as_holder!(MeasureWithUnit)>,
-        mechanical_context: HashMap<u64, as_holder!(MechanicalContext)>,
-        named_unit: HashMap<u64, as_holder!(NamedUnit)>,
-        next_assembly_usage_occurrence: HashMap<u64, as_holder!(NextAssemblyUsageOccurrence)>,
-        offset_curve_3d: HashMap<u64, as_holder!(OffsetCurve3D)>,
-        offset_surface: HashMap<u64, as_holder!(OffsetSurface)>,
-        open_shell: HashMap<u64, as_holder!(OpenShell)>,
-        ordinal_date: HashMap<u64, as_holder!(OrdinalDate)>,
-        organization: HashMap<u64, as_holder!(Organization)>,
-        organization_relationship: HashMap<u64, as_holder!(OrganizationRelationship)>,
-        organizational_address: HashMap<u64, as_holder!(OrganizationalAddress)>,
-        organizational_project: HashMap<u64, as_holder!(OrganizationalProject)>,
-        oriented_closed_shell: HashMap<u64, as_holder!(OrientedClosedShell)>,
-        oriented_edge: HashMap<u64, as_holder!(OrientedEdge)>,
-        oriented_face: HashMap<u64, as_holder!(OrientedFace)>,
-        oriented_open_shell: HashMap<u64, as_holder!(OrientedOpenShell)>,
-        oriented_path: HashMap<u64, as_holder!(OrientedPath)>,
-        outer_boundary_curve: HashMap<u64, as_holder!(OuterBoundaryCurve)>,
-        parabola: HashMap<u64, as_holder!(Parabola)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ManifoldSurfaceShapeRepresentation)>,
+        #[table(validate)]
+        mapped_item: HashMap<crate::tables::InstanceId, as_holder!(MappedItem)>,
+        #[table(validate)]
+        mass_measure_with_unit: HashMap<crate::tables::InstanceId, as_holder!(MassMeasureWithUnit)>,
+        #[table(validate)]
+        mass_unit: HashMap<crate::tables::InstanceId, as_holder!(MassUnit)>,
+        #[table(validate)]
+        measure_with_unit: HashMap<crate::tables::InstanceId, as_holder!(MeasureWithUnit)>,
+        #[table(validate)]
+        mechanical_context: HashMap<crate::tables::InstanceId, as_holder!(MechanicalContext)>,
+        named_unit: HashMap<crate::tables::InstanceId, as_holder!(NamedUnit)>,
+        #[table(validate)]
+        next_assembly_usage_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(NextAssemblyUsageOccurrence)>,
+        #[table(validate)]
+        offset_curve_3d: HashMap<crate::tables::InstanceId, as_holder!(OffsetCurve3D)>,
+        #[table(validate)]
+        offset_surface: HashMap<crate::tables::InstanceId, as_holder!(OffsetSurface)>,
+        #[table(validate)]
+        open_shell: HashMap<crate::tables::InstanceId, as_holder!(OpenShell)>,
+        #[table(validate)]
+        ordinal_date: HashMap<crate::tables::InstanceId, as_holder!(OrdinalDate)>,
+        organization: HashMap<crate::tables::InstanceId, as_holder!(Organization)>,
+        organization_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(OrganizationRelationship)>,
+        #[table(validate)]
+        organizational_address:
+            HashMap<crate::tables::InstanceId, as_holder!(OrganizationalAddress)>,
+        organizational_project:
+            HashMap<crate::tables::InstanceId, as_holder!(OrganizationalProject)>,
+        #[table(validate)]
+        oriented_closed_shell: HashMap<crate::tables::InstanceId, as_holder!(OrientedClosedShell)>,
+        #[table(validate)]
+        oriented_edge: HashMap<crate::tables::InstanceId, as_holder!(OrientedEdge)>,
+        #[table(validate)]
+        oriented_face: HashMap<crate::tables::InstanceId, as_holder!(OrientedFace)>,
+        #[table(validate)]
+        oriented_open_shell: HashMap<crate::tables::InstanceId, as_holder!(OrientedOpenShell)>,
+        #[table(validate)]
+        oriented_path: HashMap<crate::tables::InstanceId, as_holder!(OrientedPath)>,
+        #[table(validate)]
+        outer_boundary_curve: HashMap<crate::tables::InstanceId, as_holder!(OuterBoundaryCurve)>,
+        #[table(validate)]
+        parabola: HashMap<crate::tables::InstanceId, as_holder!(Parabola)>,
         parametric_representation_context:
-            HashMap<u64, as_holder!(ParametricRepresentationContext)>,
-        path: HashMap<u64, as_holder!(Path)>,
-        pcurve: HashMap<u64, as_holder!(Pcurve)>,
-        person: HashMap<u64, as_holder!(Person)>,
-        person_and_organization: HashMap<u64, as_holder!(PersonAndOrganization)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ParametricRepresentationContext)>,
+        #[table(validate)]
+        path: HashMap<crate::tables::InstanceId, as_holder!(Path)>,
+        #[table(validate)]
+        pcurve: HashMap<crate::tables::InstanceId, as_holder!(Pcurve)>,
+        #[table(validate)]
+        person: HashMap<crate::tables::InstanceId, as_holder!(Person)>,
+        person_and_organization:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganization)>,
         person_and_organization_assignment:
-            HashMap<u64, as_holder!(PersonAndOrganizationAssignment)>,
-        person_and_organization_role: HashMap<u64, as_holder!(PersonAndOrganizationRole)>,
-        personal_address: HashMap<u64, as_holder!(PersonalAddress)>,
-        placement: HashMap<u64, as_holder!(Placement)>,
-        plane: HashMap<u64, as_holder!(Plane)>,
-        plane_angle_measure_with_unit: HashMap<u64, as_holder!(PlaneAngleMeasureWithUnit)>,
-        plane_angle_unit: HashMap<u64, as_holder!(PlaneAngleUnit)>,
-        point: HashMap<u64, as_holder!(Point)>,
-        point_on_curve: HashMap<u64, as_holder!(PointOnCurve)>,
-        point_on_surface: HashMap<u64, as_holder!(PointOnSurface)>,
-        point_replica: HashMap<u64, as_holder!(PointReplica)>,
-        poly_loop: HashMap<u64, as_holder!(PolyLoop)>,
-        polyline: HashMap<u64, as_holder!(Polyline)>,
-        product: HashMap<u64, as_holder!(Product)>,
-        product_category: HashMap<u64, as_holder!(ProductCategory)>,
-        product_category_relationship: HashMap<u64, as_holder!(ProductCategoryRelationship)>,
-        product_concept: HashMap<u64, as_holder!(ProductConcept)>,
-        product_concept_context: HashMap<u64, as_holder!(ProductConceptContext)>,
-        product_context: HashMap<u64, as_holder!(ProductContext)>,
-        product_definition: HashMap<u64, as_holder!(ProductDefinition)>,
-        product_definition_context: HashMap<u64, as_holder!(ProductDefinitionContext)>,
-        product_definition_effectivity: HashMap<u64, as_holder!(ProductDefinitionEffectivity)>,
-        product_definition_formation: HashMap<u64, as_holder!(ProductDefinitionFormation)>,
-        product_definition_formation_with_specified_source:
-            HashMap<u64, as_holder!(ProductDefinitionFormationWithSpecifiedSource)>,
-        product_definition_relationship: HashMap<u64, as_holder!(ProductDefinitionRelationship)>,
-        product_definition_shape: HashMap<u64, as_holder!(ProductDefinitionShape)>,
-        product_definition_usage: HashMap<u64, as_holder!(ProductDefinitionUsage)>,
-        product_definition_with_associated_documents:
-            HashMap<u64, as_holder!(ProductDefinitionWithAssociatedDocuments)>,
-        product_related_product_category: HashMap<u64, as_holder!(ProductRelatedProductCategory)>,
-        promissory_usage_occurrence: HashMap<u64, as_holder!(PromissoryUsageOccurrence)>,
-        property_definition: HashMap<u64, as_holder!(PropertyDefinition)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationAssignment)>,
+        person_and_organization_role:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationRole)>,
+        #[table(validate)]
+        personal_address: HashMap<crate::tables::InstanceId, as_holder!(PersonalAddress)>,
+        #[table(validate)]
+        placement: HashMap<crate::tables::InstanceId, as_holder!(Placement)>,
+        #[table(validate)]
+        plane: HashMap<crate::tables::InstanceId, as_holder!(Plane)>,
+        #[table(validate)]
+        plane_angle_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasureWithUnit)>,
+        #[table(validate)]
+        plane_angle_unit: HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleUnit)>,
+        #[table(validate)]
+        point: HashMap<crate::tables::InstanceId, as_holder!(Point)>,
+        #[table(validate)]
+        point_on_curve: HashMap<crate::tables::InstanceId, as_holder!(PointOnCurve)>,
+        #[table(validate)]
+        point_on_surface: HashMap<crate::tables::InstanceId, as_holder!(PointOnSurface)>,
+        #[table(validate)]
+        point_replica: HashMap<crate::tables::InstanceId, as_holder!(PointReplica)>,
+        #[table(validate)]
+        poly_loop: HashMap<crate::tables::InstanceId, as_holder!(PolyLoop)>,
+        #[table(validate)]
+        polyline: HashMap<crate::tables::InstanceId, as_holder!(Polyline)>,
+        product: HashMap<crate::tables::InstanceId, as_holder!(Product)>,
+        product_category: HashMap<crate::tables::InstanceId, as_holder!(ProductCategory)>,
+        #[table(validate)]
+        product_category_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductCategoryRelationship)>,
+        product_concept: HashMap<crate::tables::InstanceId, as_holder!(ProductConcept)>,
+        product_concept_context:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductConceptContext)>,
+        product_context: HashMap<crate::tables::InstanceId, as_holder!(ProductContext)>,
+        product_definition: HashMap<crate::tables::InstanceId, as_holder!(ProductDefinition)>,
+        product_definition_context:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionContext)>,
+        product_definition_effectivity:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionEffectivity)>,
+        product_definition_formation:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionFormation)>,
+        product_definition_formation_with_specified_source: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(ProductDefinitionFormationWithSpecifiedSource),
+        >,
+        product_definition_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionRelationship)>,
+        #[table(validate)]
+        product_definition_shape:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionShape)>,
+        #[table(validate)]
+        product_definition_usage:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionUsage)>,
+        product_definition_with_associated_documents: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(ProductDefinitionWithAssociatedDocuments),
+        >,
+        product_related_product_category:
+            HashMap<crate::tables::InstanceId, as_holder!(ProductRelatedProductCategory)>,
+        #[table(validate)]
+        promissory_usage_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(PromissoryUsageOccurrence)>,
+        property_definition: HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinition)>,
         property_definition_representation:
-            HashMap<u64, as_holder!(PropertyDefinitionRepresentation)>,
+            HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinitionRepresentation)>,
+        #[table(validate)]
         quantified_assembly_component_usage:
-            HashMap<u64, as_holder!(QuantifiedAssemblyComponentUsage)>,
-        quasi_uniform_curve: HashMap<u64, as_holder!(QuasiUniformCurve)>,
-        quasi_uniform_surface: HashMap<u64, as_holder!(QuasiUniformSurface)>,
-        rational_b_spline_curve: HashMap<u64, as_holder!(RationalBSplineCurve)>,
-        rational_b_spline_surface: HashMap<u64, as_holder!(RationalBSplineSurface)>,
-        rectangular_composite_surface: HashMap<u64, as_holder!(RectangularCompositeSurface)>,
-        rectangular_trimmed_surface: HashMap<u64, as_holder!(RectangularTrimmedSurface)>,
+            HashMap<crate::tables::InstanceId, as_holder!(QuantifiedAssemblyComponentUsage)>,
+        #[table(validate)]
+        quasi_uniform_curve: HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformCurve)>,
+        #[table(validate)]
+        quasi_uniform_surface: HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformSurface)>,
+        #[table(validate)]
+        rational_b_spline_curve:
+            HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineCurve)>,
+        #[table(validate)]
+        rational_b_spline_surface:
+            HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineSurface)>,
+        #[table(validate)]
+        rectangular_composite_surface:
+            HashMap<crate::tables::InstanceId, as_holder!(RectangularCompositeSurface)>,
+        #[table(validate)]
+        rectangular_trimmed_surface:
+            HashMap<crate::tables::InstanceId, as_holder!(RectangularTrimmedSurface)>,
+        #[table(validate)]
         reparametrised_composite_curve_segment:
-            HashMap<u64, as_holder!(ReparametrisedCompositeCurveSegment)>,
-        representation: HashMap<u64, as_holder!(Representation)>,
-        representation_context: HashMap<u64, as_holder!(RepresentationContext)>,
-        representation_item: HashMap<u64, as_holder!(RepresentationItem)>,
-        representation_map: HashMap<u64, as_holder!(RepresentationMap)>,
-        representation_relationship: HashMap<u64, as_holder!(RepresentationRelationship)>,
-        representation_relationship_with_transformation:
-            HashMap<u64, as_holder!(RepresentationRelationshipWithTransformation)>,
-        seam_curve: HashMap<u64, as_holder!(SeamCurve)>,
-        security_classification: HashMap<u64, as_holder!(SecurityClassification)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ReparametrisedCompositeCurveSegment)>,
+        representation: HashMap<crate::tables::InstanceId, as_holder!(Representation)>,
+        representation_context:
+            HashMap<crate::tables::InstanceId, as_holder!(RepresentationContext)>,
+        #[table(validate)]
+        representation_item: HashMap<crate::tables::InstanceId, as_holder!(RepresentationItem)>,
+        #[table(validate)]
+        representation_map: HashMap<crate::tables::InstanceId, as_holder!(RepresentationMap)>,
+        representation_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(RepresentationRelationship)>,
+        #[table(validate)]
+        representation_relationship_with_transformation: HashMap<
+            crate::tables::InstanceId,
+            as_holder!(RepresentationRelationshipWithTransformation),
+        >,
+        #[table(validate)]
+        seam_curve: HashMap<crate::tables::InstanceId, as_holder!(SeamCurve)>,
+        security_classification:
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassification)>,
         security_classification_assignment:
-            HashMap<u64, as_holder!(SecurityClassificationAssignment)>,
-        security_classification_level: HashMap<u64, as_holder!(SecurityClassificationLevel)>,
-        serial_numbered_effectivity: HashMap<u64, as_holder!(SerialNumberedEffectivity)>,
-        shape_aspect: HashMap<u64, as_holder!(ShapeAspect)>,
-        shape_aspect_relationship: HashMap<u64, as_holder!(ShapeAspectRelationship)>,
-        shape_definition_representation: HashMap<u64, as_holder!(ShapeDefinitionRepresentation)>,
-        shape_representation: HashMap<u64, as_holder!(ShapeRepresentation)>,
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationAssignment)>,
+        security_classification_level:
+            HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationLevel)>,
+        serial_numbered_effectivity:
+            HashMap<crate::tables::InstanceId, as_holder!(SerialNumberedEffectivity)>,
+        shape_aspect: HashMap<crate::tables::InstanceId, as_holder!(ShapeAspect)>,
+        shape_aspect_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(ShapeAspectRelationship)>,
+        #[table(validate)]
+        shape_definition_representation:
+            HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinitionRepresentation)>,
+        shape_representation: HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentation)>,
+        #[table(validate)]
         shape_representation_relationship:
-            HashMap<u64, as_holder!(ShapeRepresentationRelationship)>,
-        shell_based_surface_model: HashMap<u64, as_holder!(ShellBasedSurfaceModel)>,
-        shell_based_wireframe_model: HashMap<u64, as_holder!(ShellBasedWireframeModel)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentationRelationship)>,
+        #[table(validate)]
+        shell_based_surface_model:
+            HashMap<crate::tables::InstanceId, as_holder!(ShellBasedSurfaceModel)>,
+        #[table(validate)]
+        shell_based_wireframe_model:
+            HashMap<crate::tables::InstanceId, as_holder!(ShellBasedWireframeModel)>,
+        #[table(validate)]
         shell_based_wireframe_shape_representation:
-            HashMap<u64, as_holder!(ShellBasedWireframeShapeRepresentation)>,
-        si_unit: HashMap<u64, as_holder!(SiUnit)>,
-        solid_angle_measure_with_unit: HashMap<u64, as_holder!(SolidAngleMeasureWithUnit)>,
-        solid_angle_unit: HashMap<u64, as_holder!(SolidAngleUnit)>,
-        solid_model: HashMap<u64, as_holder!(SolidModel)>,
-        specified_higher_usage_occurrence: HashMap<u64, as_holder!(SpecifiedHigherUsageOccurrence)>,
-        spherical_surface: HashMap<u64, as_holder!(SphericalSurface)>,
-        start_request: HashMap<u64, as_holder!(StartRequest)>,
-        start_work: HashMap<u64, as_holder!(StartWork)>,
-        supplied_part_relationship: HashMap<u64, as_holder!(SuppliedPartRelationship)>,
-        surface: HashMap<u64, as_holder!(Surface)>,
-        surface_curve: HashMap<u64, as_holder!(SurfaceCurve)>,
-        surface_of_linear_extrusion: HashMap<u64, as_holder!(SurfaceOfLinearExtrusion)>,
-        surface_of_revolution: HashMap<u64, as_holder!(SurfaceOfRevolution)>,
-        surface_patch: HashMap<u64, as_holder!(SurfacePatch)>,
-        surface_replica: HashMap<u64, as_holder!(SurfaceReplica)>,
-        swept_surface: HashMap<u64, as_holder!(SweptSurface)>,
-        topological_representation_item: HashMap<u64, as_holder!(TopologicalRepresentationItem)>,
-        toroidal_surface: HashMap<u64, as_holder!(ToroidalSurface)>,
-        trimmed_curve: HashMap<u64, as_holder!(TrimmedCurve)>,
-        uncertainty_measure_with_unit: HashMap<u64, as_holder!(UncertaintyMeasureWithUnit)>,
-        uniform_curve: HashMap<u64, as_holder!(UniformCurve)>,
-        uniform_surface: HashMap<u64, as_holder!(UniformSurface)>,
-        vector: HashMap<u64, as_holder!(Vector)>,
-        versioned_action_request: HashMap<u64, as_holder!(VersionedActionRequest)>,
-        vertex: HashMap<u64, as_holder!(Vertex)>,
-        vertex_loop: HashMap<u64, as_holder!(VertexLoop)>,
-        vertex_point: HashMap<u64, as_holder!(VertexPoint)>,
-        vertex_shell: HashMap<u64, as_holder!(VertexShell)>,
-        volume_measure_with_unit: HashMap<u64, as_holder!(VolumeMeasureWithUnit)>,
-        volume_unit: HashMap<u64, as_holder!(VolumeUnit)>,
-        week_of_year_and_day_date: HashMap<u64, as_holder!(WeekOfYearAndDayDate)>,
-        wire_shell: HashMap<u64, as_holder!(WireShell)>,
-        approved_item: HashMap<u64, as_holder!(ApprovedItem)>,
-        area_measure: HashMap<u64, as_holder!(AreaMeasure)>,
-        axis2_placement: HashMap<u64, as_holder!(Axis2Placement)>,
-        boolean_operand: HashMap<u64, as_holder!(BooleanOperand)>,
-        certified_item: HashMap<u64, as_holder!(CertifiedItem)>,
-        change_request_item: HashMap<u64, as_holder!(ChangeRequestItem)>,
-        characterized_definition: HashMap<u64, as_holder!(CharacterizedDefinition)>,
-        characterized_product_definition: HashMap<u64, as_holder!(CharacterizedProductDefinition)>,
-        classified_item: HashMap<u64, as_holder!(ClassifiedItem)>,
-        context_dependent_measure: HashMap<u64, as_holder!(ContextDependentMeasure)>,
-        contracted_item: HashMap<u64, as_holder!(ContractedItem)>,
-        count_measure: HashMap<u64, as_holder!(CountMeasure)>,
-        curve_on_surface: HashMap<u64, as_holder!(CurveOnSurface)>,
-        date_time_item: HashMap<u64, as_holder!(DateTimeItem)>,
-        date_time_select: HashMap<u64, as_holder!(DateTimeSelect)>,
-        day_in_month_number: HashMap<u64, as_holder!(DayInMonthNumber)>,
-        day_in_week_number: HashMap<u64, as_holder!(DayInWeekNumber)>,
-        day_in_year_number: HashMap<u64, as_holder!(DayInYearNumber)>,
-        descriptive_measure: HashMap<u64, as_holder!(DescriptiveMeasure)>,
-        dimension_count: HashMap<u64, as_holder!(DimensionCount)>,
-        founded_item_select: HashMap<u64, as_holder!(FoundedItemSelect)>,
-        geometric_set_select: HashMap<u64, as_holder!(GeometricSetSelect)>,
-        hour_in_day: HashMap<u64, as_holder!(HourInDay)>,
-        identifier: HashMap<u64, as_holder!(Identifier)>,
-        label: HashMap<u64, as_holder!(Label)>,
-        length_measure: HashMap<u64, as_holder!(LengthMeasure)>,
-        list_of_reversible_topology_item: HashMap<u64, as_holder!(ListOfReversibleTopologyItem)>,
-        mass_measure: HashMap<u64, as_holder!(MassMeasure)>,
-        measure_value: HashMap<u64, as_holder!(MeasureValue)>,
-        minute_in_hour: HashMap<u64, as_holder!(MinuteInHour)>,
-        month_in_year_number: HashMap<u64, as_holder!(MonthInYearNumber)>,
-        parameter_value: HashMap<u64, as_holder!(ParameterValue)>,
-        pcurve_or_surface: HashMap<u64, as_holder!(PcurveOrSurface)>,
-        person_organization_item: HashMap<u64, as_holder!(PersonOrganizationItem)>,
-        person_organization_select: HashMap<u64, as_holder!(PersonOrganizationSelect)>,
-        plane_angle_measure: HashMap<u64, as_holder!(PlaneAngleMeasure)>,
-        positive_length_measure: HashMap<u64, as_holder!(PositiveLengthMeasure)>,
-        positive_plane_angle_measure: HashMap<u64, as_holder!(PositivePlaneAngleMeasure)>,
-        reversible_topology: HashMap<u64, as_holder!(ReversibleTopology)>,
-        reversible_topology_item: HashMap<u64, as_holder!(ReversibleTopologyItem)>,
-        second_in_minute: HashMap<u64, as_holder!(SecondInMinute)>,
-        set_of_reversible_topology_item: HashMap<u64, as_holder!(SetOfReversibleTopologyItem)>,
-        shape_definition: HashMap<u64, as_holder!(ShapeDefinition)>,
-        shell: HashMap<u64, as_holder!(Shell)>,
-        solid_angle_measure: HashMap<u64, as_holder!(SolidAngleMeasure)>,
-        specified_item: HashMap<u64, as_holder!(SpecifiedItem)>,
-        start_request_item: HashMap<u64, as_holder!(StartRequestItem)>,
-        supported_item: HashMap<u64, as_holder!(SupportedItem)>,
-        surface_model: HashMap<u64, as_holder!(SurfaceModel)>,
-        text: HashMap<u64, as_holder!(Text)>,
-        transformation: HashMap<u64, as_holder!(Transformation)>,
-        trimming_select: HashMap<u64, as_holder!(TrimmingSelect)>,
-        unit: HashMap<u64, as_holder!(Unit)>,
-        vector_or_direction: HashMap<u64, as_holder!(VectorOrDirection)>,
-        volume_measure: HashMap<u64, as_holder!(VolumeMeasure)>,
-        week_in_year_number: HashMap<u64, as_holder!(WeekInYearNumber)>,
-        wireframe_model: HashMap<u64, as_holder!(WireframeModel)>,
-        work_item: HashMap<u64, as_holder!(WorkItem)>,
-        year_number: HashMap<u64, as_holder!(YearNumber)>,
+            HashMap<crate::tables::InstanceId, as_holder!(ShellBasedWireframeShapeRepresentation)>,
+        si_unit: HashMap<crate::tables::InstanceId, as_holder!(SiUnit)>,
+        #[table(validate)]
+        solid_angle_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(SolidAngleMeasureWithUnit)>,
+        #[table(validate)]
+        solid_angle_unit: HashMap<crate::tables::InstanceId, as_holder!(SolidAngleUnit)>,
+        #[table(validate)]
+        solid_model: HashMap<crate::tables::InstanceId, as_holder!(SolidModel)>,
+        #[table(validate)]
+        specified_higher_usage_occurrence:
+            HashMap<crate::tables::InstanceId, as_holder!(SpecifiedHigherUsageOccurrence)>,
+        #[table(validate)]
+        spherical_surface: HashMap<crate::tables::InstanceId, as_holder!(SphericalSurface)>,
+        start_request: HashMap<crate::tables::InstanceId, as_holder!(StartRequest)>,
+        start_work: HashMap<crate::tables::InstanceId, as_holder!(StartWork)>,
+        supplied_part_relationship:
+            HashMap<crate::tables::InstanceId, as_holder!(SuppliedPartRelationship)>,
+        #[table(validate)]
+        surface: HashMap<crate::tables::InstanceId, as_holder!(Surface)>,
+        #[table(validate)]
+        surface_curve: HashMap<crate::tables::InstanceId, as_holder!(SurfaceCurve)>,
+        #[table(validate)]
+        surface_of_linear_extrusion:
+            HashMap<crate::tables::InstanceId, as_holder!(SurfaceOfLinearExtrusion)>,
+        #[table(validate)]
+        surface_of_revolution: HashMap<crate::tables::InstanceId, as_holder!(SurfaceOfRevolution)>,
+        #[table(validate)]
+        surface_patch: HashMap<crate::tables::InstanceId, as_holder!(SurfacePatch)>,
+        #[table(validate)]
+        surface_replica: HashMap<crate::tables::InstanceId, as_holder!(SurfaceReplica)>,
+        #[table(validate)]
+        swept_surface: HashMap<crate::tables::InstanceId, as_holder!(SweptSurface)>,
+        #[table(validate)]
+        topological_representation_item:
+            HashMap<crate::tables::InstanceId, as_holder!(TopologicalRepresentationItem)>,
+        #[table(validate)]
+        toroidal_surface: HashMap<crate::tables::InstanceId, as_holder!(ToroidalSurface)>,
+        #[table(validate)]
+        trimmed_curve: HashMap<crate::tables::InstanceId, as_holder!(TrimmedCurve)>,
+        #[table(validate)]
+        uncertainty_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(UncertaintyMeasureWithUnit)>,
+        #[table(validate)]
+        uniform_curve: HashMap<crate::tables::InstanceId, as_holder!(UniformCurve)>,
+        #[table(validate)]
+        uniform_surface: HashMap<crate::tables::InstanceId, as_holder!(UniformSurface)>,
+        #[table(validate)]
+        vector: HashMap<crate::tables::InstanceId, as_holder!(Vector)>,
+        versioned_action_request:
+            HashMap<crate::tables::InstanceId, as_holder!(VersionedActionRequest)>,
+        #[table(validate)]
+        vertex: HashMap<crate::tables::InstanceId, as_holder!(Vertex)>,
+        #[table(validate)]
+        vertex_loop: HashMap<crate::tables::InstanceId, as_holder!(VertexLoop)>,
+        #[table(validate)]
+        vertex_point: HashMap<crate::tables::InstanceId, as_holder!(VertexPoint)>,
+        #[table(validate)]
+        vertex_shell: HashMap<crate::tables::InstanceId, as_holder!(VertexShell)>,
+        #[table(validate)]
+        volume_measure_with_unit:
+            HashMap<crate::tables::InstanceId, as_holder!(VolumeMeasureWithUnit)>,
+        #[table(validate)]
+        volume_unit: HashMap<crate::tables::InstanceId, as_holder!(VolumeUnit)>,
+        week_of_year_and_day_date:
+            HashMap<crate::tables::InstanceId, as_holder!(WeekOfYearAndDayDate)>,
+        #[table(validate)]
+        wire_shell: HashMap<crate::tables::InstanceId, as_holder!(WireShell)>,
+        approved_item: HashMap<crate::tables::InstanceId, as_holder!(ApprovedItem)>,
+        area_measure: HashMap<crate::tables::InstanceId, as_holder!(AreaMeasure)>,
+        axis2_placement: HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement)>,
+        boolean_operand: HashMap<crate::tables::InstanceId, as_holder!(BooleanOperand)>,
+        certified_item: HashMap<crate::tables::InstanceId, as_holder!(CertifiedItem)>,
+        change_request_item: HashMap<crate::tables::InstanceId, as_holder!(ChangeRequestItem)>,
+        characterized_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterizedDefinition)>,
+        characterized_product_definition:
+            HashMap<crate::tables::InstanceId, as_holder!(CharacterizedProductDefinition)>,
+        classified_item: HashMap<crate::tables::InstanceId, as_holder!(ClassifiedItem)>,
+        context_dependent_measure:
+            HashMap<crate::tables::InstanceId, as_holder!(ContextDependentMeasure)>,
+        contracted_item: HashMap<crate::tables::InstanceId, as_holder!(ContractedItem)>,
+        count_measure: HashMap<crate::tables::InstanceId, as_holder!(CountMeasure)>,
+        curve_on_surface: HashMap<crate::tables::InstanceId, as_holder!(CurveOnSurface)>,
+        date_time_item: HashMap<crate::tables::InstanceId, as_holder!(DateTimeItem)>,
+        date_time_select: HashMap<crate::tables::InstanceId, as_holder!(DateTimeSelect)>,
+        day_in_month_number: HashMap<crate::tables::InstanceId, as_holder!(DayInMonthNumber)>,
+        day_in_week_number: HashMap<crate::tables::InstanceId, as_holder!(DayInWeekNumber)>,
+        day_in_year_number: HashMap<crate::tables::InstanceId, as_holder!(DayInYearNumber)>,
+        descriptive_measure: HashMap<crate::tables::InstanceId, as_holder!(DescriptiveMeasure)>,
+        dimension_count: HashMap<crate::tables::InstanceId, as_holder!(DimensionCount)>,
+        founded_item_select: HashMap<crate::tables::InstanceId, as_holder!(FoundedItemSelect)>,
+        geometric_set_select: HashMap<crate::tables::InstanceId, as_holder!(GeometricSetSelect)>,
+        hour_in_day: HashMap<crate::tables::InstanceId, as_holder!(HourInDay)>,
+        identifier: HashMap<crate::tables::InstanceId, as_holder!(Identifier)>,
+        label: HashMap<crate::tables::InstanceId, as_holder!(Label)>,
+        length_measure: HashMap<crate::tables::InstanceId, as_holder!(LengthMeasure)>,
+        list_of_reversible_topology_item:
+            HashMap<crate::tables::InstanceId, as_holder!(ListOfReversibleTopologyItem)>,
+        mass_measure: HashMap<crate::tables::InstanceId, as_holder!(MassMeasure)>,
+        measure_value: HashMap<crate::tables::InstanceId, as_holder!(MeasureValue)>,
+        minute_in_hour: HashMap<crate::tables::InstanceId, as_holder!(MinuteInHour)>,
+        month_in_year_number: HashMap<crate::tables::InstanceId, as_holder!(MonthInYearNumber)>,
+        parameter_value: HashMap<crate::tables::InstanceId, as_holder!(ParameterValue)>,
+        pcurve_or_surface: HashMap<crate::tables::InstanceId, as_holder!(PcurveOrSurface)>,
+        person_organization_item:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationItem)>,
+        person_organization_select:
+            HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationSelect)>,
+        plane_angle_measure: HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasure)>,
+        positive_length_measure:
+            HashMap<crate::tables::InstanceId, as_holder!(PositiveLengthMeasure)>,
+        positive_plane_angle_measure:
+            HashMap<crate::tables::InstanceId, as_holder!(PositivePlaneAngleMeasure)>,
+        reversible_topology: HashMap<crate::tables::InstanceId, as_holder!(ReversibleTopology)>,
+        reversible_topology_item:
+            HashMap<crate::tables::InstanceId, as_holder!(ReversibleTopologyItem)>,
+        second_in_minute: HashMap<crate::tables::InstanceId, as_holder!(SecondInMinute)>,
+        set_of_reversible_topology_item:
+            HashMap<crate::tables::InstanceId, as_holder!(SetOfReversibleTopologyItem)>,
+        shape_definition: HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinition)>,
+        shell: HashMap<crate::tables::InstanceId, as_holder!(Shell)>,
+        solid_angle_measure: HashMap<crate::tables::InstanceId, as_holder!(SolidAngleMeasure)>,
+        specified_item: HashMap<crate::tables::InstanceId, as_holder!(SpecifiedItem)>,
+        start_request_item: HashMap<crate::tables::InstanceId, as_holder!(StartRequestItem)>,
+        supported_item: HashMap<crate::tables::InstanceId, as_holder!(SupportedItem)>,
+        surface_model: HashMap<crate::tables::InstanceId, as_holder!(SurfaceModel)>,
+        text: HashMap<crate::tables::InstanceId, as_holder!(Text)>,
+        transformation: HashMap<crate::tables::InstanceId, as_holder!(Transformation)>,
+        trimming_select: HashMap<crate::tables::InstanceId, as_holder!(TrimmingSelect)>,
+        unit: HashMap<crate::tables::InstanceId, as_holder!(Unit)>,
+        vector_or_direction: HashMap<crate::tables::InstanceId, as_holder!(VectorOrDirection)>,
+        volume_measure: HashMap<crate::tables::InstanceId, as_holder!(VolumeMeasure)>,
+        week_in_year_number: HashMap<crate::tables::InstanceId, as_holder!(WeekInYearNumber)>,
+        wireframe_model: HashMap<crate::tables::InstanceId, as_holder!(WireframeModel)>,
+        work_item: HashMap<crate::tables::InstanceId, as_holder!(WorkItem)>,
+        year_number: HashMap<crate::tables::InstanceId, as_holder!(YearNumber)>,
     }
     impl Tables {
-        pub fn action_holders(&self) -> &HashMap<u64, as_holder!(Action)> {
+        #[doc = r" Identifier of EXPRESS schema, e.g. for `FILE_SCHEMA` header record"]
+        pub const SCHEMA_IDENTIFIER: &str = "CONFIG_CONTROL_DESIGN";
+        pub fn action_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Action)> {
             &self.action
         }
-        pub fn action_assignment_holders(&self) -> &HashMap<u64, as_holder!(ActionAssignment)> {
+        pub fn action_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionAssignment)> {
             &self.action_assignment
         }
-        pub fn action_directive_holders(&self) -> &HashMap<u64, as_holder!(ActionDirective)> {
+        pub fn action_directive_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionDirective)> {
             &self.action_directive
         }
-        pub fn action_method_holders(&self) -> &HashMap<u64, as_holder!(ActionMethod)> {
+        pub fn action_method_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionMethod)> {
             &self.action_method
         }
         pub fn action_request_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ActionRequestAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionRequestAssignment)> {
             &self.action_request_assignment
         }
         pub fn action_request_solution_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ActionRequestSolution)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionRequestSolution)> {
             &self.action_request_solution
         }
         pub fn action_request_status_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ActionRequestStatus)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionRequestStatus)> {
             &self.action_request_status
         }
-        pub fn action_status_holders(&self) -> &HashMap<u64, as_holder!(ActionStatus)> {
+        pub fn action_status_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ActionStatus)> {
             &self.action_status
         }
-        pub fn address_holders(&self) -> &HashMap<u64, as_holder!(Address)> {
+        pub fn address_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Address)> {
             &self.address
         }
         pub fn advanced_brep_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AdvancedBrepShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AdvancedBrepShapeRepresentation)>
+        {
             &self.advanced_brep_shape_representation
         }
-        pub fn advanced_face_holders(&self) -> &HashMap<u64, as_holder!(AdvancedFace)> {
+        pub fn advanced_face_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AdvancedFace)> {
             &self.advanced_face
         }
         pub fn alternate_product_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AlternateProductRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AlternateProductRelationship)> {
             &self.alternate_product_relationship
         }
-        pub fn application_context_holders(&self) -> &HashMap<u64, as_holder!(ApplicationContext)> {
+        pub fn application_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationContext)> {
             &self.application_context
         }
         pub fn application_context_element_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApplicationContextElement)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationContextElement)> {
             &self.application_context_element
         }
         pub fn application_protocol_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApplicationProtocolDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApplicationProtocolDefinition)>
+        {
             &self.application_protocol_definition
         }
-        pub fn approval_holders(&self) -> &HashMap<u64, as_holder!(Approval)> {
+        pub fn approval_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Approval)> {
             &self.approval
         }
-        pub fn approval_assignment_holders(&self) -> &HashMap<u64, as_holder!(ApprovalAssignment)> {
+        pub fn approval_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalAssignment)> {
             &self.approval_assignment
         }
-        pub fn approval_date_time_holders(&self) -> &HashMap<u64, as_holder!(ApprovalDateTime)> {
+        pub fn approval_date_time_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalDateTime)> {
             &self.approval_date_time
         }
         pub fn approval_person_organization_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApprovalPersonOrganization)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalPersonOrganization)> {
             &self.approval_person_organization
         }
         pub fn approval_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ApprovalRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalRelationship)> {
             &self.approval_relationship
         }
-        pub fn approval_role_holders(&self) -> &HashMap<u64, as_holder!(ApprovalRole)> {
+        pub fn approval_role_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalRole)> {
             &self.approval_role
         }
-        pub fn approval_status_holders(&self) -> &HashMap<u64, as_holder!(ApprovalStatus)> {
+        pub fn approval_status_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovalStatus)> {
             &self.approval_status
         }
         pub fn area_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AreaMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AreaMeasureWithUnit)> {
             &self.area_measure_with_unit
         }
-        pub fn area_unit_holders(&self) -> &HashMap<u64, as_holder!(AreaUnit)> {
+        pub fn area_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AreaUnit)> {
             &self.area_unit
         }
         pub fn assembly_component_usage_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AssemblyComponentUsage)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AssemblyComponentUsage)> {
             &self.assembly_component_usage
         }
         pub fn assembly_component_usage_substitute_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(AssemblyComponentUsageSubstitute)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AssemblyComponentUsageSubstitute)>
+        {
             &self.assembly_component_usage_substitute
         }
-        pub fn axis1_placement_holders(&self) -> &HashMap<u64, as_holder!(Axis1Placement)> {
+        pub fn axis1_placement_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis1Placement)> {
             &self.axis1_placement
         }
-        pub fn axis2_placement_2d_holders(&self) -> &HashMap<u64, as_holder!(Axis2Placement2D)> {
+        pub fn axis2_placement_2d_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement2D)> {
             &self.axis2_placement_2d
         }
-        pub fn axis2_placement_3d_holders(&self) -> &HashMap<u64, as_holder!(Axis2Placement3D)> {
+        pub fn axis2_placement_3d_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement3D)> {
             &self.axis2_placement_3d
         }
-        pub fn b_spline_curve_holders(&self) -> &HashMap<u64, as_holder!(BSplineCurve)> {
+        pub fn b_spline_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineCurve)> {
             &self.b_spline_curve
         }
         pub fn b_spline_curve_with_knots_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(BSplineCurveWithKnots)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineCurveWithKnots)> {
             &self.b_spline_curve_with_knots
         }
-        pub fn b_spline_surface_holders(&self) -> &HashMap<u64, as_holder!(BSplineSurface)> {
+        pub fn b_spline_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineSurface)> {
             &self.b_spline_surface
         }
         pub fn b_spline_surface_with_knots_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(BSplineSurfaceWithKnots)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BSplineSurfaceWithKnots)> {
             &self.b_spline_surface_with_knots
         }
-        pub fn bezier_curve_holders(&self) -> &HashMap<u64, as_holder!(BezierCurve)> {
+        pub fn bezier_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BezierCurve)> {
             &self.bezier_curve
         }
-        pub fn bezier_surface_holders(&self) -> &HashMap<u64, as_holder!(BezierSurface)> {
+        pub fn bezier_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BezierSurface)> {
             &self.bezier_surface
         }
-        pub fn boundary_curve_holders(&self) -> &HashMap<u64, as_holder!(BoundaryCurve)> {
+        pub fn boundary_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundaryCurve)> {
             &self.boundary_curve
         }
-        pub fn bounded_curve_holders(&self) -> &HashMap<u64, as_holder!(BoundedCurve)> {
+        pub fn bounded_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundedCurve)> {
             &self.bounded_curve
         }
-        pub fn bounded_pcurve_holders(&self) -> &HashMap<u64, as_holder!(BoundedPcurve)> {
+        pub fn bounded_pcurve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundedPcurve)> {
             &self.bounded_pcurve
         }
-        pub fn bounded_surface_holders(&self) -> &HashMap<u64, as_holder!(BoundedSurface)> {
+        pub fn bounded_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundedSurface)> {
             &self.bounded_surface
         }
         pub fn bounded_surface_curve_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(BoundedSurfaceCurve)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BoundedSurfaceCurve)> {
             &self.bounded_surface_curve
         }
-        pub fn brep_with_voids_holders(&self) -> &HashMap<u64, as_holder!(BrepWithVoids)> {
+        pub fn brep_with_voids_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BrepWithVoids)> {
             &self.brep_with_voids
         }
-        pub fn calendar_date_holders(&self) -> &HashMap<u64, as_holder!(CalendarDate)> {
+        pub fn calendar_date_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CalendarDate)> {
             &self.calendar_date
         }
-        pub fn cartesian_point_holders(&self) -> &HashMap<u64, as_holder!(CartesianPoint)> {
+        pub fn cartesian_point_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CartesianPoint)> {
             &self.cartesian_point
         }
         pub fn cartesian_transformation_operator_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CartesianTransformationOperator)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CartesianTransformationOperator)>
+        {
             &self.cartesian_transformation_operator
         }
         pub fn cartesian_transformation_operator_3d_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CartesianTransformationOperator3D)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CartesianTransformationOperator3D)>
+        {
             &self.cartesian_transformation_operator_3d
         }
-        pub fn cc_design_approval_holders(&self) -> &HashMap<u64, as_holder!(CcDesignApproval)> {
+        pub fn cc_design_approval_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignApproval)> {
             &self.cc_design_approval
         }
         pub fn cc_design_certification_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CcDesignCertification)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignCertification)> {
             &self.cc_design_certification
         }
-        pub fn cc_design_contract_holders(&self) -> &HashMap<u64, as_holder!(CcDesignContract)> {
+        pub fn cc_design_contract_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignContract)> {
             &self.cc_design_contract
         }
         pub fn cc_design_date_and_time_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CcDesignDateAndTimeAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignDateAndTimeAssignment)>
+        {
             &self.cc_design_date_and_time_assignment
         }
         pub fn cc_design_person_and_organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CcDesignPersonAndOrganizationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignPersonAndOrganizationAssignment)>
+        {
             &self.cc_design_person_and_organization_assignment
         }
         pub fn cc_design_security_classification_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CcDesignSecurityClassification)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignSecurityClassification)>
+        {
             &self.cc_design_security_classification
         }
         pub fn cc_design_specification_reference_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CcDesignSpecificationReference)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CcDesignSpecificationReference)>
+        {
             &self.cc_design_specification_reference
         }
-        pub fn certification_holders(&self) -> &HashMap<u64, as_holder!(Certification)> {
+        pub fn certification_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Certification)> {
             &self.certification
         }
         pub fn certification_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CertificationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CertificationAssignment)> {
             &self.certification_assignment
         }
-        pub fn certification_type_holders(&self) -> &HashMap<u64, as_holder!(CertificationType)> {
+        pub fn certification_type_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CertificationType)> {
             &self.certification_type
         }
-        pub fn change_holders(&self) -> &HashMap<u64, as_holder!(Change)> {
+        pub fn change_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Change)> {
             &self.change
         }
-        pub fn change_request_holders(&self) -> &HashMap<u64, as_holder!(ChangeRequest)> {
+        pub fn change_request_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ChangeRequest)> {
             &self.change_request
         }
-        pub fn circle_holders(&self) -> &HashMap<u64, as_holder!(Circle)> {
+        pub fn circle_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Circle)> {
             &self.circle
         }
-        pub fn closed_shell_holders(&self) -> &HashMap<u64, as_holder!(ClosedShell)> {
+        pub fn closed_shell_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ClosedShell)> {
             &self.closed_shell
         }
-        pub fn composite_curve_holders(&self) -> &HashMap<u64, as_holder!(CompositeCurve)> {
+        pub fn composite_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeCurve)> {
             &self.composite_curve
         }
         pub fn composite_curve_on_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeCurveOnSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveOnSurface)> {
             &self.composite_curve_on_surface
         }
         pub fn composite_curve_segment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CompositeCurveSegment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CompositeCurveSegment)> {
             &self.composite_curve_segment
         }
         pub fn configuration_design_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ConfigurationDesign)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConfigurationDesign)> {
             &self.configuration_design
         }
         pub fn configuration_effectivity_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ConfigurationEffectivity)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConfigurationEffectivity)> {
             &self.configuration_effectivity
         }
-        pub fn configuration_item_holders(&self) -> &HashMap<u64, as_holder!(ConfigurationItem)> {
+        pub fn configuration_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConfigurationItem)> {
             &self.configuration_item
         }
-        pub fn conic_holders(&self) -> &HashMap<u64, as_holder!(Conic)> {
+        pub fn conic_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Conic)> {
             &self.conic
         }
-        pub fn conical_surface_holders(&self) -> &HashMap<u64, as_holder!(ConicalSurface)> {
+        pub fn conical_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConicalSurface)> {
             &self.conical_surface
         }
-        pub fn connected_edge_set_holders(&self) -> &HashMap<u64, as_holder!(ConnectedEdgeSet)> {
+        pub fn connected_edge_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConnectedEdgeSet)> {
             &self.connected_edge_set
         }
-        pub fn connected_face_set_holders(&self) -> &HashMap<u64, as_holder!(ConnectedFaceSet)> {
+        pub fn connected_face_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConnectedFaceSet)> {
             &self.connected_face_set
         }
         pub fn context_dependent_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ContextDependentShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContextDependentShapeRepresentation)>
+        {
             &self.context_dependent_shape_representation
         }
         pub fn context_dependent_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ContextDependentUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContextDependentUnit)> {
             &self.context_dependent_unit
         }
-        pub fn contract_holders(&self) -> &HashMap<u64, as_holder!(Contract)> {
+        pub fn contract_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Contract)> {
             &self.contract
         }
-        pub fn contract_assignment_holders(&self) -> &HashMap<u64, as_holder!(ContractAssignment)> {
+        pub fn contract_assignment_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractAssignment)> {
             &self.contract_assignment
         }
-        pub fn contract_type_holders(&self) -> &HashMap<u64, as_holder!(ContractType)> {
+        pub fn contract_type_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractType)> {
             &self.contract_type
         }
         pub fn conversion_based_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ConversionBasedUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ConversionBasedUnit)> {
             &self.conversion_based_unit
         }
         pub fn coordinated_universal_time_offset_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CoordinatedUniversalTimeOffset)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CoordinatedUniversalTimeOffset)>
+        {
             &self.coordinated_universal_time_offset
         }
-        pub fn curve_holders(&self) -> &HashMap<u64, as_holder!(Curve)> {
+        pub fn curve_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Curve)> {
             &self.curve
         }
         pub fn curve_bounded_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CurveBoundedSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveBoundedSurface)> {
             &self.curve_bounded_surface
         }
-        pub fn curve_replica_holders(&self) -> &HashMap<u64, as_holder!(CurveReplica)> {
+        pub fn curve_replica_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveReplica)> {
             &self.curve_replica
         }
-        pub fn cylindrical_surface_holders(&self) -> &HashMap<u64, as_holder!(CylindricalSurface)> {
+        pub fn cylindrical_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CylindricalSurface)> {
             &self.cylindrical_surface
         }
-        pub fn date_holders(&self) -> &HashMap<u64, as_holder!(Date)> {
+        pub fn date_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Date)> {
             &self.date
         }
-        pub fn date_and_time_holders(&self) -> &HashMap<u64, as_holder!(DateAndTime)> {
+        pub fn date_and_time_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateAndTime)> {
             &self.date_and_time
         }
         pub fn date_and_time_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DateAndTimeAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateAndTimeAssignment)> {
             &self.date_and_time_assignment
         }
-        pub fn date_time_role_holders(&self) -> &HashMap<u64, as_holder!(DateTimeRole)> {
+        pub fn date_time_role_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateTimeRole)> {
             &self.date_time_role
         }
-        pub fn dated_effectivity_holders(&self) -> &HashMap<u64, as_holder!(DatedEffectivity)> {
+        pub fn dated_effectivity_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DatedEffectivity)> {
             &self.dated_effectivity
         }
         pub fn definitional_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DefinitionalRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DefinitionalRepresentation)> {
             &self.definitional_representation
         }
-        pub fn degenerate_pcurve_holders(&self) -> &HashMap<u64, as_holder!(DegeneratePcurve)> {
+        pub fn degenerate_pcurve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DegeneratePcurve)> {
             &self.degenerate_pcurve
         }
         pub fn degenerate_toroidal_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DegenerateToroidalSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DegenerateToroidalSurface)> {
             &self.degenerate_toroidal_surface
         }
-        pub fn design_context_holders(&self) -> &HashMap<u64, as_holder!(DesignContext)> {
+        pub fn design_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DesignContext)> {
             &self.design_context
         }
         pub fn design_make_from_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DesignMakeFromRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DesignMakeFromRelationship)> {
             &self.design_make_from_relationship
         }
         pub fn dimensional_exponents_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DimensionalExponents)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionalExponents)> {
             &self.dimensional_exponents
         }
-        pub fn directed_action_holders(&self) -> &HashMap<u64, as_holder!(DirectedAction)> {
+        pub fn directed_action_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DirectedAction)> {
             &self.directed_action
         }
-        pub fn direction_holders(&self) -> &HashMap<u64, as_holder!(Direction)> {
+        pub fn direction_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Direction)> {
             &self.direction
         }
-        pub fn document_holders(&self) -> &HashMap<u64, as_holder!(Document)> {
+        pub fn document_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Document)> {
             &self.document
         }
-        pub fn document_reference_holders(&self) -> &HashMap<u64, as_holder!(DocumentReference)> {
+        pub fn document_reference_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentReference)> {
             &self.document_reference
         }
         pub fn document_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DocumentRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentRelationship)> {
             &self.document_relationship
         }
-        pub fn document_type_holders(&self) -> &HashMap<u64, as_holder!(DocumentType)> {
+        pub fn document_type_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentType)> {
             &self.document_type
         }
         pub fn document_usage_constraint_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(DocumentUsageConstraint)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentUsageConstraint)> {
             &self.document_usage_constraint
         }
-        pub fn document_with_class_holders(&self) -> &HashMap<u64, as_holder!(DocumentWithClass)> {
+        pub fn document_with_class_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DocumentWithClass)> {
             &self.document_with_class
         }
-        pub fn edge_holders(&self) -> &HashMap<u64, as_holder!(Edge)> {
+        pub fn edge_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Edge)> {
             &self.edge
         }
         pub fn edge_based_wireframe_model_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(EdgeBasedWireframeModel)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(EdgeBasedWireframeModel)> {
             &self.edge_based_wireframe_model
         }
         pub fn edge_based_wireframe_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(EdgeBasedWireframeShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(EdgeBasedWireframeShapeRepresentation)>
+        {
             &self.edge_based_wireframe_shape_representation
         }
-        pub fn edge_curve_holders(&self) -> &HashMap<u64, as_holder!(EdgeCurve)> {
+        pub fn edge_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(EdgeCurve)> {
             &self.edge_curve
         }
-        pub fn edge_loop_holders(&self) -> &HashMap<u64, as_holder!(EdgeLoop)> {
+        pub fn edge_loop_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(EdgeLoop)> {
             &self.edge_loop
         }
-        pub fn effectivity_holders(&self) -> &HashMap<u64, as_holder!(Effectivity)> {
+        pub fn effectivity_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Effectivity)> {
             &self.effectivity
         }
-        pub fn elementary_surface_holders(&self) -> &HashMap<u64, as_holder!(ElementarySurface)> {
+        pub fn elementary_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ElementarySurface)> {
             &self.elementary_surface
         }
-        pub fn ellipse_holders(&self) -> &HashMap<u64, as_holder!(Ellipse)> {
+        pub fn ellipse_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Ellipse)> {
             &self.ellipse
         }
         pub fn evaluated_degenerate_pcurve_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(EvaluatedDegeneratePcurve)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(EvaluatedDegeneratePcurve)> {
             &self.evaluated_degenerate_pcurve
         }
-        pub fn executed_action_holders(&self) -> &HashMap<u64, as_holder!(ExecutedAction)> {
+        pub fn executed_action_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ExecutedAction)> {
             &self.executed_action
         }
-        pub fn face_holders(&self) -> &HashMap<u64, as_holder!(Face)> {
+        pub fn face_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Face)> {
             &self.face
         }
-        pub fn face_bound_holders(&self) -> &HashMap<u64, as_holder!(FaceBound)> {
+        pub fn face_bound_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FaceBound)> {
             &self.face_bound
         }
-        pub fn face_outer_bound_holders(&self) -> &HashMap<u64, as_holder!(FaceOuterBound)> {
+        pub fn face_outer_bound_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FaceOuterBound)> {
             &self.face_outer_bound
         }
-        pub fn face_surface_holders(&self) -> &HashMap<u64, as_holder!(FaceSurface)> {
+        pub fn face_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FaceSurface)> {
             &self.face_surface
         }
-        pub fn faceted_brep_holders(&self) -> &HashMap<u64, as_holder!(FacetedBrep)> {
+        pub fn faceted_brep_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FacetedBrep)> {
             &self.faceted_brep
         }
         pub fn faceted_brep_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FacetedBrepShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FacetedBrepShapeRepresentation)>
+        {
             &self.faceted_brep_shape_representation
         }
-        pub fn founded_item_holders(&self) -> &HashMap<u64, as_holder!(FoundedItem)> {
+        pub fn founded_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FoundedItem)> {
             &self.founded_item
         }
         pub fn functionally_defined_transformation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(FunctionallyDefinedTransformation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FunctionallyDefinedTransformation)>
+        {
             &self.functionally_defined_transformation
         }
-        pub fn geometric_curve_set_holders(&self) -> &HashMap<u64, as_holder!(GeometricCurveSet)> {
+        pub fn geometric_curve_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricCurveSet)> {
             &self.geometric_curve_set
         }
         pub fn geometric_representation_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricRepresentationContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationContext)>
+        {
             &self.geometric_representation_context
         }
         pub fn geometric_representation_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricRepresentationItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricRepresentationItem)> {
             &self.geometric_representation_item
         }
-        pub fn geometric_set_holders(&self) -> &HashMap<u64, as_holder!(GeometricSet)> {
+        pub fn geometric_set_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricSet)> {
             &self.geometric_set
         }
         pub fn geometrically_bounded_surface_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricallyBoundedSurfaceShapeRepresentation)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBoundedSurfaceShapeRepresentation),
+        > {
             &self.geometrically_bounded_surface_shape_representation
         }
         pub fn geometrically_bounded_wireframe_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricallyBoundedWireframeShapeRepresentation)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(GeometricallyBoundedWireframeShapeRepresentation),
+        > {
             &self.geometrically_bounded_wireframe_shape_representation
         }
         pub fn global_uncertainty_assigned_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GlobalUncertaintyAssignedContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GlobalUncertaintyAssignedContext)>
+        {
             &self.global_uncertainty_assigned_context
         }
         pub fn global_unit_assigned_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GlobalUnitAssignedContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GlobalUnitAssignedContext)> {
             &self.global_unit_assigned_context
         }
-        pub fn hyperbola_holders(&self) -> &HashMap<u64, as_holder!(Hyperbola)> {
+        pub fn hyperbola_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Hyperbola)> {
             &self.hyperbola
         }
-        pub fn intersection_curve_holders(&self) -> &HashMap<u64, as_holder!(IntersectionCurve)> {
+        pub fn intersection_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(IntersectionCurve)> {
             &self.intersection_curve
         }
         pub fn item_defined_transformation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ItemDefinedTransformation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ItemDefinedTransformation)> {
             &self.item_defined_transformation
         }
         pub fn length_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(LengthMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthMeasureWithUnit)> {
             &self.length_measure_with_unit
         }
-        pub fn length_unit_holders(&self) -> &HashMap<u64, as_holder!(LengthUnit)> {
+        pub fn length_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthUnit)> {
             &self.length_unit
         }
-        pub fn line_holders(&self) -> &HashMap<u64, as_holder!(Line)> {
+        pub fn line_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Line)> {
             &self.line
         }
-        pub fn local_time_holders(&self) -> &HashMap<u64, as_holder!(LocalTime)> {
+        pub fn local_time_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LocalTime)> {
             &self.local_time
         }
-        pub fn loop_holders(&self) -> &HashMap<u64, as_holder!(Loop)> {
+        pub fn loop_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Loop)> {
             &self.r#loop
         }
-        pub fn lot_effectivity_holders(&self) -> &HashMap<u64, as_holder!(LotEffectivity)> {
+        pub fn lot_effectivity_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LotEffectivity)> {
             &self.lot_effectivity
         }
-        pub fn manifold_solid_brep_holders(&self) -> &HashMap<u64, as_holder!(ManifoldSolidBrep)> {
+        pub fn manifold_solid_brep_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ManifoldSolidBrep)> {
             &self.manifold_solid_brep
         }
         pub fn manifold_surface_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ManifoldSurfaceShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ManifoldSurfaceShapeRepresentation)>
+        {
             &self.manifold_surface_shape_representation
         }
-        pub fn mapped_item_holders(&self) -> &HashMap<u64, as_holder!(MappedItem)> {
+        pub fn mapped_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MappedItem)> {
             &self.mapped_item
         }
         pub fn mass_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(MassMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MassMeasureWithUnit)> {
             &self.mass_measure_with_unit
         }
-        pub fn mass_unit_holders(&self) -> &HashMap<u64, as_holder!(MassUnit)> {
+        pub fn mass_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MassUnit)> {
             &self.mass_unit
         }
-        pub fn measure_with_unit_holders(&self) -> &HashMap<u64, as_holder!(MeasureWithUnit)> {
+        pub fn measure_with_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MeasureWithUnit)> {
             &self.measure_with_unit
         }
-        pub fn mechanical_context_holders(&self) -> &HashMap<u64, as_holder!(MechanicalContext)> {
+        pub fn mechanical_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MechanicalContext)> {
             &self.mechanical_context
         }
-        pub fn named_unit_holders(&self) -> &HashMap<u64, as_holder!(NamedUnit)> {
+        pub fn named_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(NamedUnit)> {
             &self.named_unit
         }
         pub fn next_assembly_usage_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(NextAssemblyUsageOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(NextAssemblyUsageOccurrence)> {
             &self.next_assembly_usage_occurrence
         }
-        pub fn offset_curve_3d_holders(&self) -> &HashMap<u64, as_holder!(OffsetCurve3D)> {
+        pub fn offset_curve_3d_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OffsetCurve3D)> {
             &self.offset_curve_3d
         }
-        pub fn offset_surface_holders(&self) -> &HashMap<u64, as_holder!(OffsetSurface)> {
+        pub fn offset_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OffsetSurface)> {
             &self.offset_surface
         }
-        pub fn open_shell_holders(&self) -> &HashMap<u64, as_holder!(OpenShell)> {
+        pub fn open_shell_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OpenShell)> {
             &self.open_shell
         }
-        pub fn ordinal_date_holders(&self) -> &HashMap<u64, as_holder!(OrdinalDate)> {
+        pub fn ordinal_date_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrdinalDate)> {
             &self.ordinal_date
         }
-        pub fn organization_holders(&self) -> &HashMap<u64, as_holder!(Organization)> {
+        pub fn organization_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Organization)> {
             &self.organization
         }
         pub fn organization_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrganizationRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationRelationship)> {
             &self.organization_relationship
         }
         pub fn organizational_address_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrganizationalAddress)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationalAddress)> {
             &self.organizational_address
         }
         pub fn organizational_project_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrganizationalProject)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrganizationalProject)> {
             &self.organizational_project
         }
         pub fn oriented_closed_shell_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OrientedClosedShell)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrientedClosedShell)> {
             &self.oriented_closed_shell
         }
-        pub fn oriented_edge_holders(&self) -> &HashMap<u64, as_holder!(OrientedEdge)> {
+        pub fn oriented_edge_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrientedEdge)> {
             &self.oriented_edge
         }
-        pub fn oriented_face_holders(&self) -> &HashMap<u64, as_holder!(OrientedFace)> {
+        pub fn oriented_face_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrientedFace)> {
             &self.oriented_face
         }
-        pub fn oriented_open_shell_holders(&self) -> &HashMap<u64, as_holder!(OrientedOpenShell)> {
+        pub fn oriented_open_shell_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrientedOpenShell)> {
             &self.oriented_open_shell
         }
-        pub fn oriented_path_holders(&self) -> &HashMap<u64, as_holder!(OrientedPath)> {
+        pub fn oriented_path_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OrientedPath)> {
             &self.oriented_path
         }
         pub fn outer_boundary_curve_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(OuterBoundaryCurve)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(OuterBoundaryCurve)> {
             &self.outer_boundary_curve
         }
-        pub fn parabola_holders(&self) -> &HashMap<u64, as_holder!(Parabola)> {
+        pub fn parabola_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Parabola)> {
             &self.parabola
         }
         pub fn parametric_representation_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ParametricRepresentationContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ParametricRepresentationContext)>
+        {
             &self.parametric_representation_context
         }
-        pub fn path_holders(&self) -> &HashMap<u64, as_holder!(Path)> {
+        pub fn path_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Path)> {
             &self.path
         }
-        pub fn pcurve_holders(&self) -> &HashMap<u64, as_holder!(Pcurve)> {
+        pub fn pcurve_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Pcurve)> {
             &self.pcurve
         }
-        pub fn person_holders(&self) -> &HashMap<u64, as_holder!(Person)> {
+        pub fn person_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Person)> {
             &self.person
         }
         pub fn person_and_organization_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganization)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganization)> {
             &self.person_and_organization
         }
         pub fn person_and_organization_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganizationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationAssignment)>
+        {
             &self.person_and_organization_assignment
         }
         pub fn person_and_organization_role_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonAndOrganizationRole)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonAndOrganizationRole)> {
             &self.person_and_organization_role
         }
-        pub fn personal_address_holders(&self) -> &HashMap<u64, as_holder!(PersonalAddress)> {
+        pub fn personal_address_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonalAddress)> {
             &self.personal_address
         }
-        pub fn placement_holders(&self) -> &HashMap<u64, as_holder!(Placement)> {
+        pub fn placement_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Placement)> {
             &self.placement
         }
-        pub fn plane_holders(&self) -> &HashMap<u64, as_holder!(Plane)> {
+        pub fn plane_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Plane)> {
             &self.plane
         }
         pub fn plane_angle_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PlaneAngleMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasureWithUnit)> {
             &self.plane_angle_measure_with_unit
         }
-        pub fn plane_angle_unit_holders(&self) -> &HashMap<u64, as_holder!(PlaneAngleUnit)> {
+        pub fn plane_angle_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleUnit)> {
             &self.plane_angle_unit
         }
-        pub fn point_holders(&self) -> &HashMap<u64, as_holder!(Point)> {
+        pub fn point_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Point)> {
             &self.point
         }
-        pub fn point_on_curve_holders(&self) -> &HashMap<u64, as_holder!(PointOnCurve)> {
+        pub fn point_on_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PointOnCurve)> {
             &self.point_on_curve
         }
-        pub fn point_on_surface_holders(&self) -> &HashMap<u64, as_holder!(PointOnSurface)> {
+        pub fn point_on_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PointOnSurface)> {
             &self.point_on_surface
         }
-        pub fn point_replica_holders(&self) -> &HashMap<u64, as_holder!(PointReplica)> {
+        pub fn point_replica_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PointReplica)> {
             &self.point_replica
         }
-        pub fn poly_loop_holders(&self) -> &HashMap<u64, as_holder!(PolyLoop)> {
+        pub fn poly_loop_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PolyLoop)> {
             &self.poly_loop
         }
-        pub fn polyline_holders(&self) -> &HashMap<u64, as_holder!(Polyline)> {
+        pub fn polyline_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Polyline)> {
             &self.polyline
         }
-        pub fn product_holders(&self) -> &HashMap<u64, as_holder!(Product)> {
+        pub fn product_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Product)> {
             &self.product
         }
-        pub fn product_category_holders(&self) -> &HashMap<u64, as_holder!(ProductCategory)> {
+        pub fn product_category_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductCategory)> {
             &self.product_category
         }
         pub fn product_category_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductCategoryRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductCategoryRelationship)> {
             &self.product_category_relationship
         }
-        pub fn product_concept_holders(&self) -> &HashMap<u64, as_holder!(ProductConcept)> {
+        pub fn product_concept_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductConcept)> {
             &self.product_concept
         }
         pub fn product_concept_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductConceptContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductConceptContext)> {
             &self.product_concept_context
         }
-        pub fn product_context_holders(&self) -> &HashMap<u64, as_holder!(ProductContext)> {
+        pub fn product_context_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductContext)> {
             &self.product_context
         }
-        pub fn product_definition_holders(&self) -> &HashMap<u64, as_holder!(ProductDefinition)> {
+        pub fn product_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinition)> {
             &self.product_definition
         }
         pub fn product_definition_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionContext)> {
             &self.product_definition_context
         }
         pub fn product_definition_effectivity_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionEffectivity)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionEffectivity)> {
             &self.product_definition_effectivity
         }
         pub fn product_definition_formation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionFormation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionFormation)> {
             &self.product_definition_formation
         }
         pub fn product_definition_formation_with_specified_source_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionFormationWithSpecifiedSource)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(ProductDefinitionFormationWithSpecifiedSource),
+        > {
             &self.product_definition_formation_with_specified_source
         }
         pub fn product_definition_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionRelationship)>
+        {
             &self.product_definition_relationship
         }
         pub fn product_definition_shape_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionShape)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionShape)> {
             &self.product_definition_shape
         }
         pub fn product_definition_usage_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionUsage)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionUsage)> {
             &self.product_definition_usage
         }
         pub fn product_definition_with_associated_documents_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductDefinitionWithAssociatedDocuments)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductDefinitionWithAssociatedDocuments)>
+        {
             &self.product_definition_with_associated_documents
         }
         pub fn product_related_product_category_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ProductRelatedProductCategory)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ProductRelatedProductCategory)>
+        {
             &self.product_related_product_category
         }
         pub fn promissory_usage_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PromissoryUsageOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PromissoryUsageOccurrence)> {
             &self.promissory_usage_occurrence
         }
-        pub fn property_definition_holders(&self) -> &HashMap<u64, as_holder!(PropertyDefinition)> {
+        pub fn property_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinition)> {
             &self.property_definition
         }
         pub fn property_definition_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PropertyDefinitionRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PropertyDefinitionRepresentation)>
+        {
             &self.property_definition_representation
         }
         pub fn quantified_assembly_component_usage_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(QuantifiedAssemblyComponentUsage)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(QuantifiedAssemblyComponentUsage)>
+        {
             &self.quantified_assembly_component_usage
         }
-        pub fn quasi_uniform_curve_holders(&self) -> &HashMap<u64, as_holder!(QuasiUniformCurve)> {
+        pub fn quasi_uniform_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformCurve)> {
             &self.quasi_uniform_curve
         }
         pub fn quasi_uniform_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(QuasiUniformSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(QuasiUniformSurface)> {
             &self.quasi_uniform_surface
         }
         pub fn rational_b_spline_curve_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RationalBSplineCurve)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineCurve)> {
             &self.rational_b_spline_curve
         }
         pub fn rational_b_spline_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RationalBSplineSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RationalBSplineSurface)> {
             &self.rational_b_spline_surface
         }
         pub fn rectangular_composite_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RectangularCompositeSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RectangularCompositeSurface)> {
             &self.rectangular_composite_surface
         }
         pub fn rectangular_trimmed_surface_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RectangularTrimmedSurface)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RectangularTrimmedSurface)> {
             &self.rectangular_trimmed_surface
         }
         pub fn reparametrised_composite_curve_segment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ReparametrisedCompositeCurveSegment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ReparametrisedCompositeCurveSegment)>
+        {
             &self.reparametrised_composite_curve_segment
         }
-        pub fn representation_holders(&self) -> &HashMap<u64, as_holder!(Representation)> {
+        pub fn representation_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Representation)> {
             &self.representation
         }
         pub fn representation_context_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RepresentationContext)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationContext)> {
             &self.representation_context
         }
-        pub fn representation_item_holders(&self) -> &HashMap<u64, as_holder!(RepresentationItem)> {
+        pub fn representation_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationItem)> {
             &self.representation_item
         }
-        pub fn representation_map_holders(&self) -> &HashMap<u64, as_holder!(RepresentationMap)> {
+        pub fn representation_map_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationMap)> {
             &self.representation_map
         }
         pub fn representation_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RepresentationRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(RepresentationRelationship)> {
             &self.representation_relationship
         }
         pub fn representation_relationship_with_transformation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(RepresentationRelationshipWithTransformation)> {
+        ) -> &HashMap<
+            crate::tables::InstanceId,
+            as_holder!(RepresentationRelationshipWithTransformation),
+        > {
             &self.representation_relationship_with_transformation
         }
-        pub fn seam_curve_holders(&self) -> &HashMap<u64, as_holder!(SeamCurve)> {
+        pub fn seam_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SeamCurve)> {
             &self.seam_curve
         }
         pub fn security_classification_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassification)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassification)> {
             &self.security_classification
         }
         pub fn security_classification_assignment_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassificationAssignment)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationAssignment)>
+        {
             &self.security_classification_assignment
         }
         pub fn security_classification_level_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SecurityClassificationLevel)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecurityClassificationLevel)> {
             &self.security_classification_level
         }
         pub fn serial_numbered_effectivity_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SerialNumberedEffectivity)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SerialNumberedEffectivity)> {
             &self.serial_numbered_effectivity
         }
-        pub fn shape_aspect_holders(&self) -> &HashMap<u64, as_holder!(ShapeAspect)> {
+        pub fn shape_aspect_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeAspect)> {
             &self.shape_aspect
         }
         pub fn shape_aspect_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeAspectRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeAspectRelationship)> {
             &self.shape_aspect_relationship
         }
         pub fn shape_definition_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeDefinitionRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinitionRepresentation)>
+        {
             &self.shape_definition_representation
         }
         pub fn shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentation)> {
             &self.shape_representation
         }
         pub fn shape_representation_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShapeRepresentationRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeRepresentationRelationship)>
+        {
             &self.shape_representation_relationship
         }
         pub fn shell_based_surface_model_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShellBasedSurfaceModel)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShellBasedSurfaceModel)> {
             &self.shell_based_surface_model
         }
         pub fn shell_based_wireframe_model_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShellBasedWireframeModel)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShellBasedWireframeModel)> {
             &self.shell_based_wireframe_model
         }
         pub fn shell_based_wireframe_shape_representation_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ShellBasedWireframeShapeRepresentation)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShellBasedWireframeShapeRepresentation)>
+        {
             &self.shell_based_wireframe_shape_representation
         }
-        pub fn si_unit_holders(&self) -> &HashMap<u64, as_holder!(SiUnit)> {
+        pub fn si_unit_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(SiUnit)> {
             &self.si_unit
         }
         pub fn solid_angle_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SolidAngleMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SolidAngleMeasureWithUnit)> {
             &self.solid_angle_measure_with_unit
         }
-        pub fn solid_angle_unit_holders(&self) -> &HashMap<u64, as_holder!(SolidAngleUnit)> {
+        pub fn solid_angle_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SolidAngleUnit)> {
             &self.solid_angle_unit
         }
-        pub fn solid_model_holders(&self) -> &HashMap<u64, as_holder!(SolidModel)> {
+        pub fn solid_model_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SolidModel)> {
             &self.solid_model
         }
         pub fn specified_higher_usage_occurrence_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SpecifiedHigherUsageOccurrence)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SpecifiedHigherUsageOccurrence)>
+        {
             &self.specified_higher_usage_occurrence
         }
-        pub fn spherical_surface_holders(&self) -> &HashMap<u64, as_holder!(SphericalSurface)> {
+        pub fn spherical_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SphericalSurface)> {
             &self.spherical_surface
         }
-        pub fn start_request_holders(&self) -> &HashMap<u64, as_holder!(StartRequest)> {
+        pub fn start_request_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StartRequest)> {
             &self.start_request
         }
-        pub fn start_work_holders(&self) -> &HashMap<u64, as_holder!(StartWork)> {
+        pub fn start_work_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StartWork)> {
             &self.start_work
         }
         pub fn supplied_part_relationship_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SuppliedPartRelationship)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SuppliedPartRelationship)> {
             &self.supplied_part_relationship
         }
-        pub fn surface_holders(&self) -> &HashMap<u64, as_holder!(Surface)> {
+        pub fn surface_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Surface)> {
             &self.surface
         }
-        pub fn surface_curve_holders(&self) -> &HashMap<u64, as_holder!(SurfaceCurve)> {
+        pub fn surface_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfaceCurve)> {
             &self.surface_curve
         }
         pub fn surface_of_linear_extrusion_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SurfaceOfLinearExtrusion)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfaceOfLinearExtrusion)> {
             &self.surface_of_linear_extrusion
         }
         pub fn surface_of_revolution_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SurfaceOfRevolution)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfaceOfRevolution)> {
             &self.surface_of_revolution
         }
-        pub fn surface_patch_holders(&self) -> &HashMap<u64, as_holder!(SurfacePatch)> {
+        pub fn surface_patch_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfacePatch)> {
             &self.surface_patch
         }
-        pub fn surface_replica_holders(&self) -> &HashMap<u64, as_holder!(SurfaceReplica)> {
+        pub fn surface_replica_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfaceReplica)> {
             &self.surface_replica
         }
-        pub fn swept_surface_holders(&self) -> &HashMap<u64, as_holder!(SweptSurface)> {
+        pub fn swept_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SweptSurface)> {
             &self.swept_surface
         }
         pub fn topological_representation_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(TopologicalRepresentationItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TopologicalRepresentationItem)>
+        {
             &self.topological_representation_item
         }
-        pub fn toroidal_surface_holders(&self) -> &HashMap<u64, as_holder!(ToroidalSurface)> {
+        pub fn toroidal_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ToroidalSurface)> {
             &self.toroidal_surface
         }
-        pub fn trimmed_curve_holders(&self) -> &HashMap<u64, as_holder!(TrimmedCurve)> {
+        pub fn trimmed_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TrimmedCurve)> {
             &self.trimmed_curve
         }
         pub fn uncertainty_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(UncertaintyMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(UncertaintyMeasureWithUnit)> {
             &self.uncertainty_measure_with_unit
         }
-        pub fn uniform_curve_holders(&self) -> &HashMap<u64, as_holder!(UniformCurve)> {
+        pub fn uniform_curve_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(UniformCurve)> {
             &self.uniform_curve
         }
-        pub fn uniform_surface_holders(&self) -> &HashMap<u64, as_holder!(UniformSurface)> {
+        pub fn uniform_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(UniformSurface)> {
             &self.uniform_surface
         }
-        pub fn vector_holders(&self) -> &HashMap<u64, as_holder!(Vector)> {
+        pub fn vector_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Vector)> {
             &self.vector
         }
         pub fn versioned_action_request_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(VersionedActionRequest)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VersionedActionRequest)> {
             &self.versioned_action_request
         }
-        pub fn vertex_holders(&self) -> &HashMap<u64, as_holder!(Vertex)> {
+        pub fn vertex_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Vertex)> {
             &self.vertex
         }
-        pub fn vertex_loop_holders(&self) -> &HashMap<u64, as_holder!(VertexLoop)> {
+        pub fn vertex_loop_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VertexLoop)> {
             &self.vertex_loop
         }
-        pub fn vertex_point_holders(&self) -> &HashMap<u64, as_holder!(VertexPoint)> {
+        pub fn vertex_point_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VertexPoint)> {
             &self.vertex_point
         }
-        pub fn vertex_shell_holders(&self) -> &HashMap<u64, as_holder!(VertexShell)> {
+        pub fn vertex_shell_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VertexShell)> {
             &self.vertex_shell
         }
         pub fn volume_measure_with_unit_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(VolumeMeasureWithUnit)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VolumeMeasureWithUnit)> {
             &self.volume_measure_with_unit
         }
-        pub fn volume_unit_holders(&self) -> &HashMap<u64, as_holder!(VolumeUnit)> {
+        pub fn volume_unit_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VolumeUnit)> {
             &self.volume_unit
         }
         pub fn week_of_year_and_day_date_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(WeekOfYearAndDayDate)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(WeekOfYearAndDayDate)> {
             &self.week_of_year_and_day_date
         }
-        pub fn wire_shell_holders(&self) -> &HashMap<u64, as_holder!(WireShell)> {
+        pub fn wire_shell_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(WireShell)> {
             &self.wire_shell
         }
-        pub fn approved_item_holders(&self) -> &HashMap<u64, as_holder!(ApprovedItem)> {
+        pub fn approved_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ApprovedItem)> {
             &self.approved_item
         }
-        pub fn area_measure_holders(&self) -> &HashMap<u64, as_holder!(AreaMeasure)> {
+        pub fn area_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(AreaMeasure)> {
             &self.area_measure
         }
-        pub fn axis2_placement_holders(&self) -> &HashMap<u64, as_holder!(Axis2Placement)> {
+        pub fn axis2_placement_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Axis2Placement)> {
             &self.axis2_placement
         }
-        pub fn boolean_operand_holders(&self) -> &HashMap<u64, as_holder!(BooleanOperand)> {
+        pub fn boolean_operand_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(BooleanOperand)> {
             &self.boolean_operand
         }
-        pub fn certified_item_holders(&self) -> &HashMap<u64, as_holder!(CertifiedItem)> {
+        pub fn certified_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CertifiedItem)> {
             &self.certified_item
         }
-        pub fn change_request_item_holders(&self) -> &HashMap<u64, as_holder!(ChangeRequestItem)> {
+        pub fn change_request_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ChangeRequestItem)> {
             &self.change_request_item
         }
         pub fn characterized_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterizedDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterizedDefinition)> {
             &self.characterized_definition
         }
         pub fn characterized_product_definition_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(CharacterizedProductDefinition)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CharacterizedProductDefinition)>
+        {
             &self.characterized_product_definition
         }
-        pub fn classified_item_holders(&self) -> &HashMap<u64, as_holder!(ClassifiedItem)> {
+        pub fn classified_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ClassifiedItem)> {
             &self.classified_item
         }
         pub fn context_dependent_measure_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ContextDependentMeasure)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContextDependentMeasure)> {
             &self.context_dependent_measure
         }
-        pub fn contracted_item_holders(&self) -> &HashMap<u64, as_holder!(ContractedItem)> {
+        pub fn contracted_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ContractedItem)> {
             &self.contracted_item
         }
-        pub fn count_measure_holders(&self) -> &HashMap<u64, as_holder!(CountMeasure)> {
+        pub fn count_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CountMeasure)> {
             &self.count_measure
         }
-        pub fn curve_on_surface_holders(&self) -> &HashMap<u64, as_holder!(CurveOnSurface)> {
+        pub fn curve_on_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(CurveOnSurface)> {
             &self.curve_on_surface
         }
-        pub fn date_time_item_holders(&self) -> &HashMap<u64, as_holder!(DateTimeItem)> {
+        pub fn date_time_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateTimeItem)> {
             &self.date_time_item
         }
-        pub fn date_time_select_holders(&self) -> &HashMap<u64, as_holder!(DateTimeSelect)> {
+        pub fn date_time_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DateTimeSelect)> {
             &self.date_time_select
         }
-        pub fn day_in_month_number_holders(&self) -> &HashMap<u64, as_holder!(DayInMonthNumber)> {
+        pub fn day_in_month_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DayInMonthNumber)> {
             &self.day_in_month_number
         }
-        pub fn day_in_week_number_holders(&self) -> &HashMap<u64, as_holder!(DayInWeekNumber)> {
+        pub fn day_in_week_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DayInWeekNumber)> {
             &self.day_in_week_number
         }
-        pub fn day_in_year_number_holders(&self) -> &HashMap<u64, as_holder!(DayInYearNumber)> {
+        pub fn day_in_year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DayInYearNumber)> {
             &self.day_in_year_number
         }
-        pub fn descriptive_measure_holders(&self) -> &HashMap<u64, as_holder!(DescriptiveMeasure)> {
+        pub fn descriptive_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DescriptiveMeasure)> {
             &self.descriptive_measure
         }
-        pub fn dimension_count_holders(&self) -> &HashMap<u64, as_holder!(DimensionCount)> {
+        pub fn dimension_count_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(DimensionCount)> {
             &self.dimension_count
         }
-        pub fn founded_item_select_holders(&self) -> &HashMap<u64, as_holder!(FoundedItemSelect)> {
+        pub fn founded_item_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(FoundedItemSelect)> {
             &self.founded_item_select
         }
         pub fn geometric_set_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(GeometricSetSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(GeometricSetSelect)> {
             &self.geometric_set_select
         }
-        pub fn hour_in_day_holders(&self) -> &HashMap<u64, as_holder!(HourInDay)> {
+        pub fn hour_in_day_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(HourInDay)> {
             &self.hour_in_day
         }
-        pub fn identifier_holders(&self) -> &HashMap<u64, as_holder!(Identifier)> {
+        pub fn identifier_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Identifier)> {
             &self.identifier
         }
-        pub fn label_holders(&self) -> &HashMap<u64, as_holder!(Label)> {
+        pub fn label_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Label)> {
             &self.label
         }
-        pub fn length_measure_holders(&self) -> &HashMap<u64, as_holder!(LengthMeasure)> {
+        pub fn length_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(LengthMeasure)> {
             &self.length_measure
         }
         pub fn list_of_reversible_topology_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ListOfReversibleTopologyItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ListOfReversibleTopologyItem)> {
             &self.list_of_reversible_topology_item
         }
-        pub fn mass_measure_holders(&self) -> &HashMap<u64, as_holder!(MassMeasure)> {
+        pub fn mass_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MassMeasure)> {
             &self.mass_measure
         }
-        pub fn measure_value_holders(&self) -> &HashMap<u64, as_holder!(MeasureValue)> {
+        pub fn measure_value_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MeasureValue)> {
             &self.measure_value
         }
-        pub fn minute_in_hour_holders(&self) -> &HashMap<u64, as_holder!(MinuteInHour)> {
+        pub fn minute_in_hour_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MinuteInHour)> {
             &self.minute_in_hour
         }
-        pub fn month_in_year_number_holders(&self) -> &HashMap<u64, as_holder!(MonthInYearNumber)> {
+        pub fn month_in_year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(MonthInYearNumber)> {
             &self.month_in_year_number
         }
-        pub fn parameter_value_holders(&self) -> &HashMap<u64, as_holder!(ParameterValue)> {
+        pub fn parameter_value_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ParameterValue)> {
             &self.parameter_value
         }
-        pub fn pcurve_or_surface_holders(&self) -> &HashMap<u64, as_holder!(PcurveOrSurface)> {
+        pub fn pcurve_or_surface_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PcurveOrSurface)> {
             &self.pcurve_or_surface
         }
         pub fn person_organization_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonOrganizationItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationItem)> {
             &self.person_organization_item
         }
         pub fn person_organization_select_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PersonOrganizationSelect)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PersonOrganizationSelect)> {
             &self.person_organization_select
         }
-        pub fn plane_angle_measure_holders(&self) -> &HashMap<u64, as_holder!(PlaneAngleMeasure)> {
+        pub fn plane_angle_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PlaneAngleMeasure)> {
             &self.plane_angle_measure
         }
         pub fn positive_length_measure_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PositiveLengthMeasure)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PositiveLengthMeasure)> {
             &self.positive_length_measure
         }
         pub fn positive_plane_angle_measure_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(PositivePlaneAngleMeasure)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(PositivePlaneAngleMeasure)> {
             &self.positive_plane_angle_measure
         }
-        pub fn reversible_topology_holders(&self) -> &HashMap<u64, as_holder!(ReversibleTopology)> {
+        pub fn reversible_topology_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ReversibleTopology)> {
             &self.reversible_topology
         }
         pub fn reversible_topology_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(ReversibleTopologyItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ReversibleTopologyItem)> {
             &self.reversible_topology_item
         }
-        pub fn second_in_minute_holders(&self) -> &HashMap<u64, as_holder!(SecondInMinute)> {
+        pub fn second_in_minute_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SecondInMinute)> {
             &self.second_in_minute
         }
         pub fn set_of_reversible_topology_item_holders(
             &self,
-        ) -> &HashMap<u64, as_holder!(SetOfReversibleTopologyItem)> {
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SetOfReversibleTopologyItem)> {
             &self.set_of_reversible_topology_item
         }
-        pub fn shape_definition_holders(&self) -> &HashMap<u64, as_holder!(ShapeDefinition)> {
+        pub fn shape_definition_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(ShapeDefinition)> {
             &self.shape_definition
         }
-        pub fn shell_holders(&self) -> &HashMap<u64, as_holder!(Shell)> {
+        pub fn shell_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Shell)> {
             &self.shell
         }
-        pub fn solid_angle_measure_holders(&self) -> &HashMap<u64, as_holder!(SolidAngleMeasure)> {
+        pub fn solid_angle_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SolidAngleMeasure)> {
             &self.solid_angle_measure
         }
-        pub fn specified_item_holders(&self) -> &HashMap<u64, as_holder!(SpecifiedItem)> {
+        pub fn specified_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SpecifiedItem)> {
             &self.specified_item
         }
-        pub fn start_request_item_holders(&self) -> &HashMap<u64, as_holder!(StartRequestItem)> {
+        pub fn start_request_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(StartRequestItem)> {
             &self.start_request_item
         }
-        pub fn supported_item_holders(&self) -> &HashMap<u64, as_holder!(SupportedItem)> {
+        pub fn supported_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SupportedItem)> {
             &self.supported_item
         }
-        pub fn surface_model_holders(&self) -> &HashMap<u64, as_holder!(SurfaceModel)> {
+        pub fn surface_model_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(SurfaceModel)> {
             &self.surface_model
         }
-        pub fn text_holders(&self) -> &HashMap<u64, as_holder!(Text)> {
+        pub fn text_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Text)> {
             &self.text
         }
-        pub fn transformation_holders(&self) -> &HashMap<u64, as_holder!(Transformation)> {
+        pub fn transformation_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(Transformation)> {
             &self.transformation
         }
-        pub fn trimming_select_holders(&self) -> &HashMap<u64, as_holder!(TrimmingSelect)> {
+        pub fn trimming_select_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(TrimmingSelect)> {
             &self.trimming_select
         }
-        pub fn unit_holders(&self) -> &HashMap<u64, as_holder!(Unit)> {
+        pub fn unit_holders(&self) -> &HashMap<crate::tables::InstanceId, as_holder!(Unit)> {
             &self.unit
         }
-        pub fn vector_or_direction_holders(&self) -> &HashMap<u64, as_holder!(VectorOrDirection)> {
+        pub fn vector_or_direction_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VectorOrDirection)> {
             &self.vector_or_direction
         }
-        pub fn volume_measure_holders(&self) -> &HashMap<u64, as_holder!(VolumeMeasure)> {
+        pub fn volume_measure_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(VolumeMeasure)> {
             &self.volume_measure
         }
-        pub fn week_in_year_number_holders(&self) -> &HashMap<u64, as_holder!(WeekInYearNumber)> {
+        pub fn week_in_year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(WeekInYearNumber)> {
             &self.week_in_year_number
         }
-        pub fn wireframe_model_holders(&self) -> &HashMap<u64, as_holder!(WireframeModel)> {
+        pub fn wireframe_model_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(WireframeModel)> {
             &self.wireframe_model
         }
-        pub fn work_item_holders(&self) -> &HashMap<u64, as_holder!(WorkItem)> {
+        pub fn work_item_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(WorkItem)> {
             &self.work_item
         }
-        pub fn year_number_holders(&self) -> &HashMap<u64, as_holder!(YearNumber)> {
+        pub fn year_number_holders(
+            &self,
+        ) -> &HashMap<crate::tables::InstanceId, as_holder!(YearNumber)> {
             &self.year_number
         }
+        #[doc = "Instances of `application_context_element` and its subtypes referring `application_context` by `frame_of_reference`, i.e. inverse attribute `application_context.context_elements`"]
+        pub fn inverse_context_elements(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.application_context_element
+                    .iter()
+                    .filter(|(_, holder)| holder.frame_of_reference.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.product_concept_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.application_context_element {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.frame_of_reference.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.product_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.application_context_element {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.frame_of_reference.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.product_definition_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.application_context_element {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.frame_of_reference.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.mechanical_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.product_context {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            match &holder.application_context_element {
+                                crate::tables::PlaceHolder::Owned(holder) => {
+                                    holder.frame_of_reference.contains_ref(id)
+                                }
+                                crate::tables::PlaceHolder::Ref(_) => false,
+                            }
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.design_context
+                    .iter()
+                    .filter(|(_, holder)| match &holder.product_definition_context {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            match &holder.application_context_element {
+                                crate::tables::PlaceHolder::Owned(holder) => {
+                                    holder.frame_of_reference.contains_ref(id)
+                                }
+                                crate::tables::PlaceHolder::Ref(_) => false,
+                            }
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `composite_curve` and its subtypes referring `composite_curve_segment` by `segments`, i.e. inverse attribute `composite_curve_segment.using_curves`"]
+        pub fn inverse_using_curves(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.composite_curve
+                    .iter()
+                    .filter(|(_, holder)| holder.segments.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.composite_curve_on_surface
+                    .iter()
+                    .filter(|(_, holder)| match &holder.composite_curve {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.segments.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.boundary_curve
+                    .iter()
+                    .filter(|(_, holder)| match &holder.composite_curve_on_surface {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            match &holder.composite_curve {
+                                crate::tables::PlaceHolder::Owned(holder) => {
+                                    holder.segments.contains_ref(id)
+                                }
+                                crate::tables::PlaceHolder::Ref(_) => false,
+                            }
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.outer_boundary_curve
+                    .iter()
+                    .filter(|(_, holder)| match &holder.boundary_curve {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            match &holder.composite_curve_on_surface {
+                                crate::tables::PlaceHolder::Owned(holder) => {
+                                    match &holder.composite_curve {
+                                        crate::tables::PlaceHolder::Owned(holder) => {
+                                            holder.segments.contains_ref(id)
+                                        }
+                                        crate::tables::PlaceHolder::Ref(_) => false,
+                                    }
+                                }
+                                crate::tables::PlaceHolder::Ref(_) => false,
+                            }
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `representation` and its subtypes referring `representation_context` by `context_of_items`, i.e. inverse attribute `representation_context.representations_in_context`"]
+        pub fn inverse_representations_in_context(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.representation
+                    .iter()
+                    .filter(|(_, holder)| holder.context_of_items.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.definitional_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.representation {
+                        crate::tables::PlaceHolder::Owned(holder) => {
+                            holder.context_of_items.contains_ref(id)
+                        }
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.advanced_brep_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.edge_based_wireframe_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.faceted_brep_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.geometrically_bounded_surface_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.geometrically_bounded_wireframe_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.manifold_surface_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.extend(
+                self.shell_based_wireframe_shape_representation
+                    .iter()
+                    .filter(|(_, holder)| match &holder.shape_representation {
+                        crate::tables::PlaceHolder::Owned(holder) => match &holder.representation {
+                            crate::tables::PlaceHolder::Owned(holder) => {
+                                holder.context_of_items.contains_ref(id)
+                            }
+                            crate::tables::PlaceHolder::Ref(_) => false,
+                        },
+                        crate::tables::PlaceHolder::Ref(_) => false,
+                    })
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `mapped_item` and its subtypes referring `representation_map` by `mapping_source`, i.e. inverse attribute `representation_map.map_usage`"]
+        pub fn inverse_map_usage(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.mapped_item
+                    .iter()
+                    .filter(|(_, holder)| holder.mapping_source.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
+        #[doc = "Instances of `rectangular_composite_surface` and its subtypes referring `surface_patch` by `segments`, i.e. inverse attribute `surface_patch.using_surfaces`"]
+        pub fn inverse_using_surfaces(
+            &self,
+            id: crate::tables::InstanceId,
+        ) -> Vec<crate::tables::InstanceId> {
+            use crate::tables::ContainsRef;
+            let mut ids = Vec::new();
+            ids.extend(
+                self.rectangular_composite_surface
+                    .iter()
+                    .filter(|(_, holder)| holder.segments.contains_ref(id))
+                    .map(|(id, _)| *id),
+            );
+            ids.sort();
+            ids
+        }
     }
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum AheadOrBehind {
@@ -1529,7 +2472,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "approved_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApprovedItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
@@ -1559,11 +2505,17 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = area_measure)]
+    #[holder(type_name = "area_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AreaMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "axis2_placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Axis2Placement {
         #[holder(use_place_holder)]
         Axis2Placement2D(Box<Axis2Placement2D>),
@@ -1595,28 +2547,40 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "boolean_operand")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BooleanOperand {
         #[holder(use_place_holder)]
         SolidModel(SolidModelAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "certified_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CertifiedItem {
         #[holder(use_place_holder)]
         SuppliedPartRelationship(Box<SuppliedPartRelationship>),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "change_request_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ChangeRequestItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "characterized_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterizedDefinition {
         #[holder(use_place_holder)]
         CharacterizedProductDefinition(Box<CharacterizedProductDefinition>),
@@ -1625,7 +2589,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "characterized_product_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CharacterizedProductDefinition {
         #[holder(use_place_holder)]
         ProductDefinition(ProductDefinitionAny),
@@ -1634,7 +2601,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "classified_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ClassifiedItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
@@ -1646,11 +2616,17 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = context_dependent_measure)]
+    #[holder(type_name = "context_dependent_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContextDependentMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "contracted_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ContractedItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
@@ -1660,11 +2636,17 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = count_measure)]
+    #[holder(type_name = "count_measure")]
     #[holder(generate_deserialize)]
-    pub struct CountMeasure(pub f64);
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
+    pub struct CountMeasure(pub Number);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve_on_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveOnSurface {
         #[holder(use_place_holder)]
         Pcurve(PcurveAny),
@@ -1675,7 +2657,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date_time_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateTimeItem {
         #[holder(use_place_holder)]
         ProductDefinition(ProductDefinitionAny),
@@ -1698,7 +2683,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date_time_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateTimeSelect {
         #[holder(use_place_holder)]
         Date(DateAny),
@@ -1712,39 +2700,57 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = day_in_month_number)]
+    #[holder(type_name = "day_in_month_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DayInMonthNumber(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = day_in_week_number)]
+    #[holder(type_name = "day_in_week_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DayInWeekNumber(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = day_in_year_number)]
+    #[holder(type_name = "day_in_year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DayInYearNumber(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = descriptive_measure)]
+    #[holder(type_name = "descriptive_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DescriptiveMeasure(pub String);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = dimension_count)]
+    #[holder(type_name = "dimension_count")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionCount(pub i64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "founded_item_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FoundedItemSelect {
         #[holder(use_place_holder)]
         FoundedItem(FoundedItemAny),
@@ -1753,7 +2759,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_set_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricSetSelect {
         #[holder(use_place_holder)]
         Point(PointAny),
@@ -1767,14 +2776,20 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = hour_in_day)]
+    #[holder(type_name = "hour_in_day")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct HourInDay(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = identifier)]
+    #[holder(type_name = "identifier")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Identifier(pub String);
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum KnotType {
@@ -1788,21 +2803,30 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = label)]
+    #[holder(type_name = "label")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Label(pub String);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = length_measure)]
+    #[holder(type_name = "length_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthMeasure(pub f64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = list_of_reversible_topology_item)]
+    #[holder(type_name = "list_of_reversible_topology_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ListOfReversibleTopologyItem(
         #[holder(use_place_holder)] pub Vec<ReversibleTopologyItem>,
     );
@@ -1811,11 +2835,17 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = mass_measure)]
+    #[holder(type_name = "mass_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MassMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "measure_value")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum MeasureValue {
         #[holder(use_place_holder)]
         LengthMeasure(Box<LengthMeasure>),
@@ -1847,25 +2877,37 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = minute_in_hour)]
+    #[holder(type_name = "minute_in_hour")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MinuteInHour(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = month_in_year_number)]
+    #[holder(type_name = "month_in_year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MonthInYearNumber(pub i64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = parameter_value)]
+    #[holder(type_name = "parameter_value")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ParameterValue(pub f64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pcurve_or_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PcurveOrSurface {
         #[holder(use_place_holder)]
         Pcurve(PcurveAny),
@@ -1874,7 +2916,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_organization_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonOrganizationItem {
         #[holder(use_place_holder)]
         Change(Box<Change>),
@@ -1899,7 +2944,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_organization_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonOrganizationSelect {
         #[holder(use_place_holder)]
         Person(Box<Person>),
@@ -1913,21 +2961,30 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_measure)]
+    #[holder(type_name = "plane_angle_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleMeasure(pub f64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = positive_length_measure)]
+    #[holder(type_name = "positive_length_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PositiveLengthMeasure(#[holder(use_place_holder)] pub LengthMeasure);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = positive_plane_angle_measure)]
+    #[holder(type_name = "positive_plane_angle_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PositivePlaneAngleMeasure(#[holder(use_place_holder)] pub PlaneAngleMeasure);
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum PreferredSurfaceCurveRepresentation {
@@ -1937,7 +2994,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "reversible_topology")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ReversibleTopology {
         #[holder(use_place_holder)]
         ReversibleTopologyItem(Box<ReversibleTopologyItem>),
@@ -1948,7 +3008,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "reversible_topology_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ReversibleTopologyItem {
         #[holder(use_place_holder)]
         Edge(EdgeAny),
@@ -1968,20 +3031,29 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = second_in_minute)]
+    #[holder(type_name = "second_in_minute")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecondInMinute(pub f64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = set_of_reversible_topology_item)]
+    #[holder(type_name = "set_of_reversible_topology_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SetOfReversibleTopologyItem(
         #[holder(use_place_holder)] pub Vec<ReversibleTopologyItem>,
     );
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "shape_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ShapeDefinition {
         #[holder(use_place_holder)]
         ProductDefinitionShape(Box<ProductDefinitionShape>),
@@ -1992,7 +3064,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Shell {
         #[holder(use_place_holder)]
         VertexShell(Box<VertexShell>),
@@ -2058,7 +3133,10 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = solid_angle_measure)]
+    #[holder(type_name = "solid_angle_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SolidAngleMeasure(pub f64);
     #[derive(Debug, Clone, PartialEq, :: serde :: Deserialize)]
     pub enum Source {
@@ -2068,7 +3146,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "specified_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SpecifiedItem {
         #[holder(use_place_holder)]
         ProductDefinition(ProductDefinitionAny),
@@ -2077,14 +3158,20 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "start_request_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum StartRequestItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "supported_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SupportedItem {
         #[holder(use_place_holder)]
         ActionDirective(Box<ActionDirective>),
@@ -2095,7 +3182,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "surface_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SurfaceModel {
         #[holder(use_place_holder)]
         ShellBasedSurfaceModel(Box<ShellBasedSurfaceModel>),
@@ -2105,11 +3195,17 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = text)]
+    #[holder(type_name = "text")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Text(pub String);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "transformation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Transformation {
         #[holder(use_place_holder)]
         ItemDefinedTransformation(Box<ItemDefinedTransformation>),
@@ -2131,7 +3227,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "trimming_select")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TrimmingSelect {
         #[holder(use_place_holder)]
         CartesianPoint(Box<CartesianPoint>),
@@ -2140,14 +3239,20 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum Unit {
         #[holder(use_place_holder)]
         NamedUnit(NamedUnitAny),
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "vector_or_direction")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum VectorOrDirection {
         #[holder(use_place_holder)]
         Vector(Box<Vector>),
@@ -2159,18 +3264,27 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = volume_measure)]
+    #[holder(type_name = "volume_measure")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VolumeMeasure(pub f64);
     #[derive(
         Clone, Debug, PartialEq, AsRef, Deref, DerefMut, Into, From, :: ruststep_derive :: Holder,
     )]
     # [holder (table = Tables)]
     # [holder (field = week_in_year_number)]
+    #[holder(type_name = "week_in_year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct WeekInYearNumber(pub i64);
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "wireframe_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum WireframeModel {
         #[holder(use_place_holder)]
         ShellBasedWireframeModel(Box<ShellBasedWireframeModel>),
@@ -2179,7 +3293,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "work_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum WorkItem {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(ProductDefinitionFormationAny),
@@ -2189,12 +3306,604 @@ pub mod config_control_design {
     )]
     # [holder (table = Tables)]
     # [holder (field = year_number)]
+    #[holder(type_name = "year_number")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct YearNumber(pub i64);
+    impl AheadOrBehind {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                AheadOrBehind::Ahead => ".AHEAD.",
+                AheadOrBehind::Behind => ".BEHIND.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for AheadOrBehind {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                AheadOrBehind::Ahead => "AHEAD",
+                AheadOrBehind::Behind => "BEHIND",
+            })
+        }
+    }
+    impl ::std::str::FromStr for AheadOrBehind {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "AHEAD" => Ok(AheadOrBehind::Ahead),
+                "BEHIND" => Ok(AheadOrBehind::Behind),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for AheadOrBehind {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl BSplineCurveForm {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                BSplineCurveForm::PolylineForm => ".POLYLINE_FORM.",
+                BSplineCurveForm::CircularArc => ".CIRCULAR_ARC.",
+                BSplineCurveForm::EllipticArc => ".ELLIPTIC_ARC.",
+                BSplineCurveForm::ParabolicArc => ".PARABOLIC_ARC.",
+                BSplineCurveForm::HyperbolicArc => ".HYPERBOLIC_ARC.",
+                BSplineCurveForm::Unspecified => ".UNSPECIFIED.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for BSplineCurveForm {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                BSplineCurveForm::PolylineForm => "POLYLINE_FORM",
+                BSplineCurveForm::CircularArc => "CIRCULAR_ARC",
+                BSplineCurveForm::EllipticArc => "ELLIPTIC_ARC",
+                BSplineCurveForm::ParabolicArc => "PARABOLIC_ARC",
+                BSplineCurveForm::HyperbolicArc => "HYPERBOLIC_ARC",
+                BSplineCurveForm::Unspecified => "UNSPECIFIED",
+            })
+        }
+    }
+    impl ::std::str::FromStr for BSplineCurveForm {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "POLYLINE_FORM" => Ok(BSplineCurveForm::PolylineForm),
+                "CIRCULAR_ARC" => Ok(BSplineCurveForm::CircularArc),
+                "ELLIPTIC_ARC" => Ok(BSplineCurveForm::EllipticArc),
+                "PARABOLIC_ARC" => Ok(BSplineCurveForm::ParabolicArc),
+                "HYPERBOLIC_ARC" => Ok(BSplineCurveForm::HyperbolicArc),
+                "UNSPECIFIED" => Ok(BSplineCurveForm::Unspecified),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for BSplineCurveForm {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl BSplineSurfaceForm {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                BSplineSurfaceForm::PlaneSurf => ".PLANE_SURF.",
+                BSplineSurfaceForm::CylindricalSurf => ".CYLINDRICAL_SURF.",
+                BSplineSurfaceForm::ConicalSurf => ".CONICAL_SURF.",
+                BSplineSurfaceForm::SphericalSurf => ".SPHERICAL_SURF.",
+                BSplineSurfaceForm::ToroidalSurf => ".TOROIDAL_SURF.",
+                BSplineSurfaceForm::SurfOfRevolution => ".SURF_OF_REVOLUTION.",
+                BSplineSurfaceForm::RuledSurf => ".RULED_SURF.",
+                BSplineSurfaceForm::GeneralisedCone => ".GENERALISED_CONE.",
+                BSplineSurfaceForm::QuadricSurf => ".QUADRIC_SURF.",
+                BSplineSurfaceForm::SurfOfLinearExtrusion => ".SURF_OF_LINEAR_EXTRUSION.",
+                BSplineSurfaceForm::Unspecified => ".UNSPECIFIED.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for BSplineSurfaceForm {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                BSplineSurfaceForm::PlaneSurf => "PLANE_SURF",
+                BSplineSurfaceForm::CylindricalSurf => "CYLINDRICAL_SURF",
+                BSplineSurfaceForm::ConicalSurf => "CONICAL_SURF",
+                BSplineSurfaceForm::SphericalSurf => "SPHERICAL_SURF",
+                BSplineSurfaceForm::ToroidalSurf => "TOROIDAL_SURF",
+                BSplineSurfaceForm::SurfOfRevolution => "SURF_OF_REVOLUTION",
+                BSplineSurfaceForm::RuledSurf => "RULED_SURF",
+                BSplineSurfaceForm::GeneralisedCone => "GENERALISED_CONE",
+                BSplineSurfaceForm::QuadricSurf => "QUADRIC_SURF",
+                BSplineSurfaceForm::SurfOfLinearExtrusion => "SURF_OF_LINEAR_EXTRUSION",
+                BSplineSurfaceForm::Unspecified => "UNSPECIFIED",
+            })
+        }
+    }
+    impl ::std::str::FromStr for BSplineSurfaceForm {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "PLANE_SURF" => Ok(BSplineSurfaceForm::PlaneSurf),
+                "CYLINDRICAL_SURF" => Ok(BSplineSurfaceForm::CylindricalSurf),
+                "CONICAL_SURF" => Ok(BSplineSurfaceForm::ConicalSurf),
+                "SPHERICAL_SURF" => Ok(BSplineSurfaceForm::SphericalSurf),
+                "TOROIDAL_SURF" => Ok(BSplineSurfaceForm::ToroidalSurf),
+                "SURF_OF_REVOLUTION" => Ok(BSplineSurfaceForm::SurfOfRevolution),
+                "RULED_SURF" => Ok(BSplineSurfaceForm::RuledSurf),
+                "GENERALISED_CONE" => Ok(BSplineSurfaceForm::GeneralisedCone),
+                "QUADRIC_SURF" => Ok(BSplineSurfaceForm::QuadricSurf),
+                "SURF_OF_LINEAR_EXTRUSION" => Ok(BSplineSurfaceForm::SurfOfLinearExtrusion),
+                "UNSPECIFIED" => Ok(BSplineSurfaceForm::Unspecified),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for BSplineSurfaceForm {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl KnotType {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                KnotType::UniformKnots => ".UNIFORM_KNOTS.",
+                KnotType::Unspecified => ".UNSPECIFIED.",
+                KnotType::QuasiUniformKnots => ".QUASI_UNIFORM_KNOTS.",
+                KnotType::PiecewiseBezierKnots => ".PIECEWISE_BEZIER_KNOTS.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for KnotType {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                KnotType::UniformKnots => "UNIFORM_KNOTS",
+                KnotType::Unspecified => "UNSPECIFIED",
+                KnotType::QuasiUniformKnots => "QUASI_UNIFORM_KNOTS",
+                KnotType::PiecewiseBezierKnots => "PIECEWISE_BEZIER_KNOTS",
+            })
+        }
+    }
+    impl ::std::str::FromStr for KnotType {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "UNIFORM_KNOTS" => Ok(KnotType::UniformKnots),
+                "UNSPECIFIED" => Ok(KnotType::Unspecified),
+                "QUASI_UNIFORM_KNOTS" => Ok(KnotType::QuasiUniformKnots),
+                "PIECEWISE_BEZIER_KNOTS" => Ok(KnotType::PiecewiseBezierKnots),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for KnotType {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl PreferredSurfaceCurveRepresentation {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                PreferredSurfaceCurveRepresentation::Curve3D => ".CURVE_3D.",
+                PreferredSurfaceCurveRepresentation::PcurveS1 => ".PCURVE_S1.",
+                PreferredSurfaceCurveRepresentation::PcurveS2 => ".PCURVE_S2.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for PreferredSurfaceCurveRepresentation {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                PreferredSurfaceCurveRepresentation::Curve3D => "CURVE_3D",
+                PreferredSurfaceCurveRepresentation::PcurveS1 => "PCURVE_S1",
+                PreferredSurfaceCurveRepresentation::PcurveS2 => "PCURVE_S2",
+            })
+        }
+    }
+    impl ::std::str::FromStr for PreferredSurfaceCurveRepresentation {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "CURVE_3D" => Ok(PreferredSurfaceCurveRepresentation::Curve3D),
+                "PCURVE_S1" => Ok(PreferredSurfaceCurveRepresentation::PcurveS1),
+                "PCURVE_S2" => Ok(PreferredSurfaceCurveRepresentation::PcurveS2),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for PreferredSurfaceCurveRepresentation {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl SiPrefix {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                SiPrefix::Exa => ".EXA.",
+                SiPrefix::Peta => ".PETA.",
+                SiPrefix::Tera => ".TERA.",
+                SiPrefix::Giga => ".GIGA.",
+                SiPrefix::Mega => ".MEGA.",
+                SiPrefix::Kilo => ".KILO.",
+                SiPrefix::Hecto => ".HECTO.",
+                SiPrefix::Deca => ".DECA.",
+                SiPrefix::Deci => ".DECI.",
+                SiPrefix::Centi => ".CENTI.",
+                SiPrefix::Milli => ".MILLI.",
+                SiPrefix::Micro => ".MICRO.",
+                SiPrefix::Nano => ".NANO.",
+                SiPrefix::Pico => ".PICO.",
+                SiPrefix::Femto => ".FEMTO.",
+                SiPrefix::Atto => ".ATTO.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for SiPrefix {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                SiPrefix::Exa => "EXA",
+                SiPrefix::Peta => "PETA",
+                SiPrefix::Tera => "TERA",
+                SiPrefix::Giga => "GIGA",
+                SiPrefix::Mega => "MEGA",
+                SiPrefix::Kilo => "KILO",
+                SiPrefix::Hecto => "HECTO",
+                SiPrefix::Deca => "DECA",
+                SiPrefix::Deci => "DECI",
+                SiPrefix::Centi => "CENTI",
+                SiPrefix::Milli => "MILLI",
+                SiPrefix::Micro => "MICRO",
+                SiPrefix::Nano => "NANO",
+                SiPrefix::Pico => "PICO",
+                SiPrefix::Femto => "FEMTO",
+                SiPrefix::Atto => "ATTO",
+            })
+        }
+    }
+    impl ::std::str::FromStr for SiPrefix {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "EXA" => Ok(SiPrefix::Exa),
+                "PETA" => Ok(SiPrefix::Peta),
+                "TERA" => Ok(SiPrefix::Tera),
+                "GIGA" => Ok(SiPrefix::Giga),
+                "MEGA" => Ok(SiPrefix::Mega),
+                "KILO" => Ok(SiPrefix::Kilo),
+                "HECTO" => Ok(SiPrefix::Hecto),
+                "DECA" => Ok(SiPrefix::Deca),
+                "DECI" => Ok(SiPrefix::Deci),
+                "CENTI" => Ok(SiPrefix::Centi),
+                "MILLI" => Ok(SiPrefix::Milli),
+                "MICRO" => Ok(SiPrefix::Micro),
+                "NANO" => Ok(SiPrefix::Nano),
+                "PICO" => Ok(SiPrefix::Pico),
+                "FEMTO" => Ok(SiPrefix::Femto),
+                "ATTO" => Ok(SiPrefix::Atto),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for SiPrefix {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl SiUnitName {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                SiUnitName::Metre => ".METRE.",
+                SiUnitName::Gram => ".GRAM.",
+                SiUnitName::Second => ".SECOND.",
+                SiUnitName::Ampere => ".AMPERE.",
+                SiUnitName::Kelvin => ".KELVIN.",
+                SiUnitName::Mole => ".MOLE.",
+                SiUnitName::Candela => ".CANDELA.",
+                SiUnitName::Radian => ".RADIAN.",
+                SiUnitName::Steradian => ".STERADIAN.",
+                SiUnitName::Hertz => ".HERTZ.",
+                SiUnitName::Newton => ".NEWTON.",
+                SiUnitName::Pascal => ".PASCAL.",
+                SiUnitName::Joule => ".JOULE.",
+                SiUnitName::Watt => ".WATT.",
+                SiUnitName::Coulomb => ".COULOMB.",
+                SiUnitName::Volt => ".VOLT.",
+                SiUnitName::Farad => ".FARAD.",
+                SiUnitName::Ohm => ".OHM.",
+                SiUnitName::Siemens => ".SIEMENS.",
+                SiUnitName::Weber => ".WEBER.",
+                SiUnitName::Tesla => ".TESLA.",
+                SiUnitName::Henry => ".HENRY.",
+                SiUnitName::DegreeCelsius => ".DEGREE_CELSIUS.",
+                SiUnitName::Lumen => ".LUMEN.",
+                SiUnitName::Lux => ".LUX.",
+                SiUnitName::Becquerel => ".BECQUEREL.",
+                SiUnitName::Gray => ".GRAY.",
+                SiUnitName::Sievert => ".SIEVERT.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for SiUnitName {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                SiUnitName::Metre => "METRE",
+                SiUnitName::Gram => "GRAM",
+                SiUnitName::Second => "SECOND",
+                SiUnitName::Ampere => "AMPERE",
+                SiUnitName::Kelvin => "KELVIN",
+                SiUnitName::Mole => "MOLE",
+                SiUnitName::Candela => "CANDELA",
+                SiUnitName::Radian => "RADIAN",
+                SiUnitName::Steradian => "STERADIAN",
+                SiUnitName::Hertz => "HERTZ",
+                SiUnitName::Newton => "NEWTON",
+                SiUnitName::Pascal => "PASCAL",
+                SiUnitName::Joule => "JOULE",
+                SiUnitName::Watt => "WATT",
+                SiUnitName::Coulomb => "COULOMB",
+                SiUnitName::Volt => "VOLT",
+                SiUnitName::Farad => "FARAD",
+                SiUnitName::Ohm => "OHM",
+                SiUnitName::Siemens => "SIEMENS",
+                SiUnitName::Weber => "WEBER",
+                SiUnitName::Tesla => "TESLA",
+                SiUnitName::Henry => "HENRY",
+                SiUnitName::DegreeCelsius => "DEGREE_CELSIUS",
+                SiUnitName::Lumen => "LUMEN",
+                SiUnitName::Lux => "LUX",
+                SiUnitName::Becquerel => "BECQUEREL",
+                SiUnitName::Gray => "GRAY",
+                SiUnitName::Sievert => "SIEVERT",
+            })
+        }
+    }
+    impl ::std::str::FromStr for SiUnitName {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "METRE" => Ok(SiUnitName::Metre),
+                "GRAM" => Ok(SiUnitName::Gram),
+                "SECOND" => Ok(SiUnitName::Second),
+                "AMPERE" => Ok(SiUnitName::Ampere),
+                "KELVIN" => Ok(SiUnitName::Kelvin),
+                "MOLE" => Ok(SiUnitName::Mole),
+                "CANDELA" => Ok(SiUnitName::Candela),
+                "RADIAN" => Ok(SiUnitName::Radian),
+                "STERADIAN" => Ok(SiUnitName::Steradian),
+                "HERTZ" => Ok(SiUnitName::Hertz),
+                "NEWTON" => Ok(SiUnitName::Newton),
+                "PASCAL" => Ok(SiUnitName::Pascal),
+                "JOULE" => Ok(SiUnitName::Joule),
+                "WATT" => Ok(SiUnitName::Watt),
+                "COULOMB" => Ok(SiUnitName::Coulomb),
+                "VOLT" => Ok(SiUnitName::Volt),
+                "FARAD" => Ok(SiUnitName::Farad),
+                "OHM" => Ok(SiUnitName::Ohm),
+                "SIEMENS" => Ok(SiUnitName::Siemens),
+                "WEBER" => Ok(SiUnitName::Weber),
+                "TESLA" => Ok(SiUnitName::Tesla),
+                "HENRY" => Ok(SiUnitName::Henry),
+                "DEGREE_CELSIUS" => Ok(SiUnitName::DegreeCelsius),
+                "LUMEN" => Ok(SiUnitName::Lumen),
+                "LUX" => Ok(SiUnitName::Lux),
+                "BECQUEREL" => Ok(SiUnitName::Becquerel),
+                "GRAY" => Ok(SiUnitName::Gray),
+                "SIEVERT" => Ok(SiUnitName::Sievert),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for SiUnitName {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl Source {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                Source::Made => ".MADE.",
+                Source::Bought => ".BOUGHT.",
+                Source::NotKnown => ".NOT_KNOWN.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for Source {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                Source::Made => "MADE",
+                Source::Bought => "BOUGHT",
+                Source::NotKnown => "NOT_KNOWN",
+            })
+        }
+    }
+    impl ::std::str::FromStr for Source {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "MADE" => Ok(Source::Made),
+                "BOUGHT" => Ok(Source::Bought),
+                "NOT_KNOWN" => Ok(Source::NotKnown),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for Source {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl TransitionCode {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                TransitionCode::Discontinuous => ".DISCONTINUOUS.",
+                TransitionCode::Continuous => ".CONTINUOUS.",
+                TransitionCode::ContSameGradient => ".CONT_SAME_GRADIENT.",
+                TransitionCode::ContSameGradientSameCurvature => {
+                    ".CONT_SAME_GRADIENT_SAME_CURVATURE."
+                }
+            }
+        }
+    }
+    impl ::std::fmt::Display for TransitionCode {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                TransitionCode::Discontinuous => "DISCONTINUOUS",
+                TransitionCode::Continuous => "CONTINUOUS",
+                TransitionCode::ContSameGradient => "CONT_SAME_GRADIENT",
+                TransitionCode::ContSameGradientSameCurvature => {
+                    "CONT_SAME_GRADIENT_SAME_CURVATURE"
+                }
+            })
+        }
+    }
+    impl ::std::str::FromStr for TransitionCode {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "DISCONTINUOUS" => Ok(TransitionCode::Discontinuous),
+                "CONTINUOUS" => Ok(TransitionCode::Continuous),
+                "CONT_SAME_GRADIENT" => Ok(TransitionCode::ContSameGradient),
+                "CONT_SAME_GRADIENT_SAME_CURVATURE" => {
+                    Ok(TransitionCode::ContSameGradientSameCurvature)
+                }
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for TransitionCode {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
+    impl TrimmingPreference {
+        #[doc = r" Item as written in exchange structure, e.g. `.RED.`"]
+        pub fn to_part21(&self) -> &'static str {
+            match self {
+                TrimmingPreference::Cartesian => ".CARTESIAN.",
+                TrimmingPreference::Parameter => ".PARAMETER.",
+                TrimmingPreference::Unspecified => ".UNSPECIFIED.",
+            }
+        }
+    }
+    impl ::std::fmt::Display for TrimmingPreference {
+        #[doc = r" Uppercase name of the item without dots, e.g. `RED`"]
+        fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            f.write_str(match self {
+                TrimmingPreference::Cartesian => "CARTESIAN",
+                TrimmingPreference::Parameter => "PARAMETER",
+                TrimmingPreference::Unspecified => "UNSPECIFIED",
+            })
+        }
+    }
+    impl ::std::str::FromStr for TrimmingPreference {
+        type Err = crate::error::Error;
+        #[doc = r" Accepts both `RED` and `.RED.`"]
+        fn from_str(s: &str) -> crate::error::Result<Self> {
+            let item = s
+                .strip_prefix('.')
+                .and_then(|s| s.strip_suffix('.'))
+                .unwrap_or(s);
+            match item {
+                "CARTESIAN" => Ok(TrimmingPreference::Cartesian),
+                "PARAMETER" => Ok(TrimmingPreference::Parameter),
+                "UNSPECIFIED" => Ok(TrimmingPreference::Unspecified),
+                _ => Err(crate::tables::unexpected_parameter::<Self>(
+                    &crate::ast::Parameter::Enumeration(item.to_string()),
+                )),
+            }
+        }
+    }
+    impl crate::tables::IntoParameter for TrimmingPreference {
+        fn into_parameter(self) -> crate::ast::Parameter {
+            crate::ast::Parameter::Enumeration(self.to_string())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action)]
+    #[holder(type_name = "action")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Action {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2205,7 +3914,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "action")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ActionAny {
         #[holder(use_place_holder)]
         Action(Box<Action>),
@@ -2230,17 +3942,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Action {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_assignment)]
+    #[holder(type_name = "action_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionAssignment {
         #[holder(use_place_holder)]
         pub assigned_action: ActionAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "action_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ActionAssignmentAny {
         #[holder(use_place_holder)]
         ActionAssignment(Box<ActionAssignment>),
@@ -2273,10 +3996,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ActionAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_directive)]
+    #[holder(type_name = "action_directive")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionDirective {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2289,10 +4020,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub requests: Vec<VersionedActionRequest>,
     }
+    impl crate::tables::Validate for ActionDirective {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_method)]
+    #[holder(type_name = "action_method")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionMethod {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2303,17 +4042,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub purpose: Text,
     }
+    impl crate::tables::Validate for ActionMethod {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_assignment)]
+    #[holder(type_name = "action_request_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionRequestAssignment {
         #[holder(use_place_holder)]
         pub assigned_action_request: VersionedActionRequest,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "action_request_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ActionRequestAssignmentAny {
         #[holder(use_place_holder)]
         ActionRequestAssignment(Box<ActionRequestAssignment>),
@@ -2346,40 +4096,72 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ActionRequestAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_solution)]
+    #[holder(type_name = "action_request_solution")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionRequestSolution {
         #[holder(use_place_holder)]
         pub method: ActionMethod,
         #[holder(use_place_holder)]
         pub request: VersionedActionRequest,
     }
+    impl crate::tables::Validate for ActionRequestSolution {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_request_status)]
+    #[holder(type_name = "action_request_status")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionRequestStatus {
         #[holder(use_place_holder)]
         pub status: Label,
         #[holder(use_place_holder)]
         pub assigned_request: VersionedActionRequest,
     }
+    impl crate::tables::Validate for ActionRequestStatus {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = action_status)]
+    #[holder(type_name = "action_status")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ActionStatus {
         #[holder(use_place_holder)]
         pub status: Label,
         #[holder(use_place_holder)]
         pub assigned_action: ExecutedActionAny,
     }
+    impl crate::tables::Validate for ActionStatus {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = address)]
+    #[holder(type_name = "address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Address {
         #[holder(use_place_holder)]
         pub internal_location: Option<Label>,
@@ -2408,7 +4190,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AddressAny {
         #[holder(use_place_holder)]
         Address(Box<Address>),
@@ -2441,38 +4226,81 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Address {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            if !(((((((((((self.internal_location.is_some()
+                || self.street_number.is_some())
+                || self.street.is_some())
+                || self.postal_box.is_some())
+                || self.town.is_some())
+                || self.region.is_some())
+                || self.postal_code.is_some())
+                || self.country.is_some())
+                || self.facsimile_number.is_some())
+                || self.telephone_number.is_some())
+                || self.electronic_mail_address.is_some())
+                || self.telex_number.is_some())
+            {
+                return Err("address.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = advanced_brep_shape_representation)]
+    #[holder(type_name = "advanced_brep_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AdvancedBrepShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for AdvancedBrepShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = advanced_face)]
+    #[holder(type_name = "advanced_face")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AdvancedFace {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face_surface: FaceSurface,
     }
+    impl crate::tables::Validate for AdvancedFace {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.face_surface)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = alternate_product_relationship)]
+    #[holder(type_name = "alternate_product_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AlternateProductRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2485,18 +4313,34 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub basis: Text,
     }
+    impl crate::tables::Validate for AlternateProductRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context)]
+    #[holder(type_name = "application_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationContext {
         #[holder(use_place_holder)]
         pub application: Text,
     }
+    impl crate::tables::Validate for ApplicationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_context_element)]
+    #[holder(type_name = "application_context_element")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationContextElement {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2505,7 +4349,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "application_context_element")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApplicationContextElementAny {
         #[holder(use_place_holder)]
         ApplicationContextElement(Box<ApplicationContextElement>),
@@ -2546,10 +4393,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ApplicationContextElement {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = application_protocol_definition)]
+    #[holder(type_name = "application_protocol_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApplicationProtocolDefinition {
         #[holder(use_place_holder)]
         pub status: Label,
@@ -2560,27 +4415,46 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub application: ApplicationContext,
     }
+    impl crate::tables::Validate for ApplicationProtocolDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval)]
+    #[holder(type_name = "approval")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Approval {
         #[holder(use_place_holder)]
         pub status: ApprovalStatus,
         #[holder(use_place_holder)]
         pub level: Label,
     }
+    impl crate::tables::Validate for Approval {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_assignment)]
+    #[holder(type_name = "approval_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalAssignment {
         #[holder(use_place_holder)]
         pub assigned_approval: Approval,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "approval_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ApprovalAssignmentAny {
         #[holder(use_place_holder)]
         ApprovalAssignment(Box<ApprovalAssignment>),
@@ -2605,20 +4479,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ApprovalAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_date_time)]
+    #[holder(type_name = "approval_date_time")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalDateTime {
         #[holder(use_place_holder)]
         pub date_time: DateTimeSelect,
         #[holder(use_place_holder)]
         pub dated_approval: Approval,
     }
+    impl crate::tables::Validate for ApprovalDateTime {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_person_organization)]
+    #[holder(type_name = "approval_person_organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalPersonOrganization {
         #[holder(use_place_holder)]
         pub person_organization: PersonOrganizationSelect,
@@ -2627,10 +4517,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub role: ApprovalRole,
     }
+    impl crate::tables::Validate for ApprovalPersonOrganization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_relationship)]
+    #[holder(type_name = "approval_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2641,69 +4539,117 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_approval: Approval,
     }
+    impl crate::tables::Validate for ApprovalRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_role)]
+    #[holder(type_name = "approval_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalRole {
         #[holder(use_place_holder)]
         pub role: Label,
     }
+    impl crate::tables::Validate for ApprovalRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = approval_status)]
+    #[holder(type_name = "approval_status")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ApprovalStatus {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for ApprovalStatus {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = area_measure_with_unit)]
+    #[holder(type_name = "area_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AreaMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for AreaMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = area_unit)]
+    #[holder(type_name = "area_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AreaUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for AreaUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = assembly_component_usage)]
+    #[holder(type_name = "assembly_component_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AssemblyComponentUsage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_usage: ProductDefinitionUsage,
         #[holder(use_place_holder)]
         pub reference_designator: Option<Identifier>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "assembly_component_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum AssemblyComponentUsageAny {
         #[holder(use_place_holder)]
         AssemblyComponentUsage(Box<AssemblyComponentUsage>),
@@ -2773,10 +4719,19 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for AssemblyComponentUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_usage)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = assembly_component_usage_substitute)]
+    #[holder(type_name = "assembly_component_usage_substitute")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct AssemblyComponentUsageSubstitute {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -2787,68 +4742,107 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub substitute: AssemblyComponentUsageAny,
     }
+    impl crate::tables::Validate for AssemblyComponentUsageSubstitute {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = axis1_placement)]
+    #[holder(type_name = "axis1_placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Axis1Placement {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub axis: Option<Direction>,
     }
+    impl crate::tables::Validate for Axis1Placement {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.placement)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = axis2_placement_2d)]
+    #[holder(type_name = "axis2_placement_2d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Axis2Placement2D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    impl crate::tables::Validate for Axis2Placement2D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.placement)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = axis2_placement_3d)]
+    #[holder(type_name = "axis2_placement_3d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Axis2Placement3D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub placement: Placement,
         #[holder(use_place_holder)]
         pub axis: Option<Direction>,
         #[holder(use_place_holder)]
         pub ref_direction: Option<Direction>,
     }
+    impl crate::tables::Validate for Axis2Placement3D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.placement)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_curve)]
+    #[holder(type_name = "b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         pub degree: i64,
         #[holder(use_place_holder)]
@@ -2859,7 +4853,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BSplineCurveAny {
         #[holder(use_place_holder)]
         BSplineCurve(Box<BSplineCurve>),
@@ -2938,36 +4935,56 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for BSplineCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_curve_with_knots)]
+    #[holder(type_name = "b_spline_curve_with_knots")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineCurveWithKnots {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub knot_multiplicities: Vec<i64>,
         #[holder(use_place_holder)]
         pub knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    impl crate::tables::Validate for BSplineCurveWithKnots {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_surface)]
+    #[holder(type_name = "b_spline_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         pub u_degree: i64,
         pub v_degree: i64,
@@ -2980,7 +4997,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "b_spline_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BSplineSurfaceAny {
         #[holder(use_place_holder)]
         BSplineSurface(Box<BSplineSurface>),
@@ -3059,18 +5079,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for BSplineSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = b_spline_surface_with_knots)]
+    #[holder(type_name = "b_spline_surface_with_knots")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BSplineSurfaceWithKnots {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
         pub u_multiplicities: Vec<i64>,
         pub v_multiplicities: Vec<i64>,
@@ -3080,51 +5110,84 @@ pub mod config_control_design {
         pub v_knots: Vec<ParameterValue>,
         pub knot_spec: KnotType,
     }
+    impl crate::tables::Validate for BSplineSurfaceWithKnots {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bezier_curve)]
+    #[holder(type_name = "bezier_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BezierCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for BezierCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bezier_surface)]
+    #[holder(type_name = "bezier_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BezierSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
+    impl crate::tables::Validate for BezierSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = boundary_curve)]
+    #[holder(type_name = "boundary_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundaryCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve_on_surface: CompositeCurveOnSurface,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "boundary_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BoundaryCurveAny {
         #[holder(use_place_holder)]
         BoundaryCurve(Box<BoundaryCurve>),
@@ -3159,23 +5222,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for BoundaryCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_curve_on_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bounded_curve)]
+    #[holder(type_name = "bounded_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundedCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "bounded_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BoundedCurveAny {
         #[holder(use_place_holder)]
         BoundedCurve(Box<BoundedCurve>),
@@ -3263,37 +5339,62 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for BoundedCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = bounded_pcurve)]
+    #[holder(type_name = "bounded_pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundedPcurve {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub pcurve: Pcurve,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
     }
+    impl crate::tables::Validate for BoundedPcurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.pcurve)?;
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = bounded_surface)]
+    #[holder(type_name = "bounded_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundedSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "bounded_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum BoundedSurfaceAny {
         #[holder(use_place_holder)]
         BoundedSurface(Box<BoundedSurface>),
@@ -3361,82 +5462,135 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for BoundedSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = bounded_surface_curve)]
+    #[holder(type_name = "bounded_surface_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BoundedSurfaceCurve {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
     }
+    impl crate::tables::Validate for BoundedSurfaceCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface_curve)?;
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = brep_with_voids)]
+    #[holder(type_name = "brep_with_voids")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct BrepWithVoids {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub manifold_solid_brep: ManifoldSolidBrep,
         #[holder(use_place_holder)]
         pub voids: Vec<OrientedClosedShell>,
     }
+    impl crate::tables::Validate for BrepWithVoids {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.manifold_solid_brep)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = calendar_date)]
+    #[holder(type_name = "calendar_date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CalendarDate {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInMonthNumber,
         #[holder(use_place_holder)]
         pub month_component: MonthInYearNumber,
     }
+    impl crate::tables::Validate for CalendarDate {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.date)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cartesian_point)]
+    #[holder(type_name = "cartesian_point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CartesianPoint {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub coordinates: Vec<LengthMeasure>,
     }
+    impl crate::tables::Validate for CartesianPoint {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = cartesian_transformation_operator)]
+    #[holder(type_name = "cartesian_transformation_operator")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CartesianTransformationOperator {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub functionally_defined_transformation: FunctionallyDefinedTransformation,
         #[holder(use_place_holder)]
         pub axis1: Option<Direction>,
@@ -3448,7 +5602,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "cartesian_transformation_operator")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CartesianTransformationOperatorAny {
         #[holder(use_place_holder)]
         CartesianTransformationOperator(Box<CartesianTransformationOperator>),
@@ -3503,138 +5660,228 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for CartesianTransformationOperator {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            crate::tables::Validate::validate(&self.functionally_defined_transformation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cartesian_transformation_operator_3d)]
+    #[holder(type_name = "cartesian_transformation_operator_3d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CartesianTransformationOperator3D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub cartesian_transformation_operator: CartesianTransformationOperator,
         #[holder(use_place_holder)]
         pub axis3: Option<Direction>,
     }
+    impl crate::tables::Validate for CartesianTransformationOperator3D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.cartesian_transformation_operator)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_approval)]
+    #[holder(type_name = "cc_design_approval")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignApproval {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub approval_assignment: ApprovalAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ApprovedItem>,
     }
+    impl crate::tables::Validate for CcDesignApproval {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.approval_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_certification)]
+    #[holder(type_name = "cc_design_certification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignCertification {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub certification_assignment: CertificationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<CertifiedItem>,
     }
+    impl crate::tables::Validate for CcDesignCertification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.certification_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_contract)]
+    #[holder(type_name = "cc_design_contract")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignContract {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub contract_assignment: ContractAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ContractedItem>,
     }
+    impl crate::tables::Validate for CcDesignContract {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.contract_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_date_and_time_assignment)]
+    #[holder(type_name = "cc_design_date_and_time_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignDateAndTimeAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date_and_time_assignment: DateAndTimeAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<DateTimeItem>,
     }
+    impl crate::tables::Validate for CcDesignDateAndTimeAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.date_and_time_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_person_and_organization_assignment)]
+    #[holder(type_name = "cc_design_person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignPersonAndOrganizationAssignment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub person_and_organization_assignment: PersonAndOrganizationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<PersonOrganizationItem>,
     }
+    impl crate::tables::Validate for CcDesignPersonAndOrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.person_and_organization_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_security_classification)]
+    #[holder(type_name = "cc_design_security_classification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignSecurityClassification {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub security_classification_assignment: SecurityClassificationAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ClassifiedItem>,
     }
+    impl crate::tables::Validate for CcDesignSecurityClassification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.security_classification_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cc_design_specification_reference)]
+    #[holder(type_name = "cc_design_specification_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CcDesignSpecificationReference {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document_reference: DocumentReference,
         #[holder(use_place_holder)]
         pub items: Vec<SpecifiedItem>,
     }
+    impl crate::tables::Validate for CcDesignSpecificationReference {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.document_reference)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification)]
+    #[holder(type_name = "certification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Certification {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -3643,17 +5890,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub kind: CertificationType,
     }
+    impl crate::tables::Validate for Certification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification_assignment)]
+    #[holder(type_name = "certification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CertificationAssignment {
         #[holder(use_place_holder)]
         pub assigned_certification: Certification,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "certification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CertificationAssignmentAny {
         #[holder(use_place_holder)]
         CertificationAssignment(Box<CertificationAssignment>),
@@ -3678,79 +5936,129 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for CertificationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = certification_type)]
+    #[holder(type_name = "certification_type")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CertificationType {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    impl crate::tables::Validate for CertificationType {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = change)]
+    #[holder(type_name = "change")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Change {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<WorkItem>,
     }
+    impl crate::tables::Validate for Change {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.action_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = change_request)]
+    #[holder(type_name = "change_request")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ChangeRequest {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<ChangeRequestItem>,
     }
+    impl crate::tables::Validate for ChangeRequest {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.action_request_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = circle)]
+    #[holder(type_name = "circle")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Circle {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Circle {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = closed_shell)]
+    #[holder(type_name = "closed_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ClosedShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub connected_face_set: ConnectedFaceSet,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "closed_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ClosedShellAny {
         #[holder(use_place_holder)]
         ClosedShell(Box<ClosedShell>),
@@ -3785,18 +6093,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ClosedShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.connected_face_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_curve)]
+    #[holder(type_name = "composite_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub segments: Vec<CompositeCurveSegmentAny>,
@@ -3804,7 +6122,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "composite_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CompositeCurveAny {
         #[holder(use_place_holder)]
         CompositeCurve(Box<CompositeCurve>),
@@ -3839,23 +6160,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for CompositeCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_curve_on_surface)]
+    #[holder(type_name = "composite_curve_on_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeCurveOnSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve: CompositeCurve,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "composite_curve_on_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CompositeCurveOnSurfaceAny {
         #[holder(use_place_holder)]
         CompositeCurveOnSurface(Box<CompositeCurveOnSurface>),
@@ -3892,18 +6226,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for CompositeCurveOnSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = composite_curve_segment)]
+    #[holder(type_name = "composite_curve_segment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CompositeCurveSegment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub founded_item: FoundedItem,
         pub transition: TransitionCode,
         pub same_sense: bool,
@@ -3912,7 +6256,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "composite_curve_segment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CompositeCurveSegmentAny {
         #[holder(use_place_holder)]
         CompositeCurveSegment(Box<CompositeCurveSegment>),
@@ -3949,36 +6296,63 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for CompositeCurveSegment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.founded_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = configuration_design)]
+    #[holder(type_name = "configuration_design")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConfigurationDesign {
         #[holder(use_place_holder)]
         pub configuration: ConfigurationItem,
         #[holder(use_place_holder)]
         pub design: ProductDefinitionFormationAny,
     }
+    impl crate::tables::Validate for ConfigurationDesign {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = configuration_effectivity)]
+    #[holder(type_name = "configuration_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConfigurationEffectivity {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_effectivity: ProductDefinitionEffectivity,
         #[holder(use_place_holder)]
         pub configuration: ConfigurationDesign,
     }
+    impl crate::tables::Validate for ConfigurationEffectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_effectivity)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = configuration_item)]
+    #[holder(type_name = "configuration_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConfigurationItem {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -3991,25 +6365,37 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub purpose: Option<Label>,
     }
+    impl crate::tables::Validate for ConfigurationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = conic)]
+    #[holder(type_name = "conic")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Conic {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub position: Axis2Placement,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "conic")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ConicAny {
         #[holder(use_place_holder)]
         Conic(Box<Conic>),
@@ -4069,59 +6455,92 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Conic {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = conical_surface)]
+    #[holder(type_name = "conical_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConicalSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: LengthMeasure,
         #[holder(use_place_holder)]
         pub semi_angle: PlaneAngleMeasure,
     }
+    impl crate::tables::Validate for ConicalSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.elementary_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = connected_edge_set)]
+    #[holder(type_name = "connected_edge_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConnectedEdgeSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub ces_edges: Vec<EdgeAny>,
     }
+    impl crate::tables::Validate for ConnectedEdgeSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = connected_face_set)]
+    #[holder(type_name = "connected_face_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConnectedFaceSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub cfs_faces: Vec<FaceAny>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "connected_face_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ConnectedFaceSetAny {
         #[holder(use_place_holder)]
         ConnectedFaceSet(Box<ConnectedFaceSet>),
@@ -4169,36 +6588,63 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ConnectedFaceSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = context_dependent_shape_representation)]
+    #[holder(type_name = "context_dependent_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContextDependentShapeRepresentation {
         #[holder(use_place_holder)]
         pub representation_relation: ShapeRepresentationRelationship,
         #[holder(use_place_holder)]
         pub represented_product_relation: ProductDefinitionShape,
     }
+    impl crate::tables::Validate for ContextDependentShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = context_dependent_unit)]
+    #[holder(type_name = "context_dependent_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContextDependentUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for ContextDependentUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract)]
+    #[holder(type_name = "contract")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Contract {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -4207,17 +6653,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub kind: ContractType,
     }
+    impl crate::tables::Validate for Contract {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_assignment)]
+    #[holder(type_name = "contract_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContractAssignment {
         #[holder(use_place_holder)]
         pub assigned_contract: Contract,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "contract_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ContractAssignmentAny {
         #[holder(use_place_holder)]
         ContractAssignment(Box<ContractAssignment>),
@@ -4242,36 +6699,62 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ContractAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = contract_type)]
+    #[holder(type_name = "contract_type")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ContractType {
         #[holder(use_place_holder)]
         pub description: Label,
     }
+    impl crate::tables::Validate for ContractType {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = conversion_based_unit)]
+    #[holder(type_name = "conversion_based_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ConversionBasedUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub conversion_factor: MeasureWithUnitAny,
     }
+    impl crate::tables::Validate for ConversionBasedUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = coordinated_universal_time_offset)]
+    #[holder(type_name = "coordinated_universal_time_offset")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CoordinatedUniversalTimeOffset {
         #[holder(use_place_holder)]
         pub hour_offset: HourInDay,
@@ -4279,23 +6762,35 @@ pub mod config_control_design {
         pub minute_offset: Option<MinuteInHour>,
         pub sense: AheadOrBehind,
     }
+    impl crate::tables::Validate for CoordinatedUniversalTimeOffset {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = curve)]
+    #[holder(type_name = "curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Curve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum CurveAny {
         #[holder(use_place_holder)]
         Curve(Box<Curve>),
@@ -4382,18 +6877,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Curve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = curve_bounded_surface)]
+    #[holder(type_name = "curve_bounded_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveBoundedSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -4401,51 +6906,83 @@ pub mod config_control_design {
         pub boundaries: Vec<BoundaryCurveAny>,
         pub implicit_outer: bool,
     }
+    impl crate::tables::Validate for CurveBoundedSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = curve_replica)]
+    #[holder(type_name = "curve_replica")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CurveReplica {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub parent_curve: CurveAny,
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperatorAny,
     }
+    impl crate::tables::Validate for CurveReplica {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = cylindrical_surface)]
+    #[holder(type_name = "cylindrical_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct CylindricalSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for CylindricalSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.elementary_surface)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date)]
+    #[holder(type_name = "date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Date {
         #[holder(use_place_holder)]
         pub year_component: YearNumber,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateAny {
         #[holder(use_place_holder)]
         Date(Box<Date>),
@@ -4486,20 +7023,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Date {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_and_time)]
+    #[holder(type_name = "date_and_time")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DateAndTime {
         #[holder(use_place_holder)]
         pub date_component: DateAny,
         #[holder(use_place_holder)]
         pub time_component: LocalTime,
     }
+    impl crate::tables::Validate for DateAndTime {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_and_time_assignment)]
+    #[holder(type_name = "date_and_time_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DateAndTimeAssignment {
         #[holder(use_place_holder)]
         pub assigned_date_and_time: DateAndTime,
@@ -4508,7 +7061,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "date_and_time_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DateAndTimeAssignmentAny {
         #[holder(use_place_holder)]
         DateAndTimeAssignment(Box<DateAndTimeAssignment>),
@@ -4533,58 +7089,95 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for DateAndTimeAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = date_time_role)]
+    #[holder(type_name = "date_time_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DateTimeRole {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for DateTimeRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = dated_effectivity)]
+    #[holder(type_name = "dated_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DatedEffectivity {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_start_date: DateAndTime,
         #[holder(use_place_holder)]
         pub effectivity_end_date: Option<DateAndTime>,
     }
+    impl crate::tables::Validate for DatedEffectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.effectivity)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = definitional_representation)]
+    #[holder(type_name = "definitional_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DefinitionalRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
+    impl crate::tables::Validate for DefinitionalRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = degenerate_pcurve)]
+    #[holder(type_name = "degenerate_pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DegeneratePcurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -4593,7 +7186,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "degenerate_pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DegeneratePcurveAny {
         #[holder(use_place_holder)]
         DegeneratePcurve(Box<DegeneratePcurve>),
@@ -4630,53 +7226,92 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for DegeneratePcurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = degenerate_toroidal_surface)]
+    #[holder(type_name = "degenerate_toroidal_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DegenerateToroidalSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub toroidal_surface: ToroidalSurface,
         pub select_outer: bool,
     }
+    impl crate::tables::Validate for DegenerateToroidalSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.toroidal_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = design_context)]
+    #[holder(type_name = "design_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DesignContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_context: ProductDefinitionContext,
     }
+    impl crate::tables::Validate for DesignContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = design_make_from_relationship)]
+    #[holder(type_name = "design_make_from_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DesignMakeFromRelationship {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
+    impl crate::tables::Validate for DesignMakeFromRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = dimensional_exponents)]
+    #[holder(type_name = "dimensional_exponents")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DimensionalExponents {
         pub length_exponent: f64,
         pub mass_exponent: f64,
@@ -4686,41 +7321,78 @@ pub mod config_control_design {
         pub amount_of_substance_exponent: f64,
         pub luminous_intensity_exponent: f64,
     }
+    impl crate::tables::Validate for DimensionalExponents {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = directed_action)]
+    #[holder(type_name = "directed_action")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DirectedAction {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub executed_action: ExecutedAction,
         #[holder(use_place_holder)]
         pub directive: ActionDirective,
     }
+    impl crate::tables::Validate for DirectedAction {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.executed_action)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = direction)]
+    #[holder(type_name = "direction")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Direction {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         pub direction_ratios: Vec<f64>,
     }
+    impl crate::tables::Validate for Direction {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            if !((self
+                .direction_ratios
+                .iter()
+                .filter(|&tmp| ((*tmp) != 0.0))
+                .count() as i64)
+                > 0)
+            {
+                return Err("direction.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document)]
+    #[holder(type_name = "document")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Document {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -4733,7 +7405,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "document")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DocumentAny {
         #[holder(use_place_holder)]
         Document(Box<Document>),
@@ -4758,10 +7433,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Document {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_reference)]
+    #[holder(type_name = "document_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentReference {
         #[holder(use_place_holder)]
         pub assigned_document: DocumentAny,
@@ -4770,7 +7453,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "document_reference")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum DocumentReferenceAny {
         #[holder(use_place_holder)]
         DocumentReference(Box<DocumentReference>),
@@ -4795,10 +7481,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for DocumentReference {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_relationship)]
+    #[holder(type_name = "document_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -4809,18 +7503,34 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_document: DocumentAny,
     }
+    impl crate::tables::Validate for DocumentRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_type)]
+    #[holder(type_name = "document_type")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentType {
         #[holder(use_place_holder)]
         pub product_data_type: Label,
     }
+    impl crate::tables::Validate for DocumentType {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = document_usage_constraint)]
+    #[holder(type_name = "document_usage_constraint")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentUsageConstraint {
         #[holder(use_place_holder)]
         pub source: DocumentAny,
@@ -4829,34 +7539,53 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub subject_element_value: Text,
     }
+    impl crate::tables::Validate for DocumentUsageConstraint {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = document_with_class)]
+    #[holder(type_name = "document_with_class")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct DocumentWithClass {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub document: Document,
         #[holder(use_place_holder)]
         pub class: Identifier,
     }
+    impl crate::tables::Validate for DocumentWithClass {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.document)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = edge)]
+    #[holder(type_name = "edge")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Edge {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_start: VertexAny,
@@ -4865,7 +7594,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "edge")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum EdgeAny {
         #[holder(use_place_holder)]
         Edge(Box<Edge>),
@@ -4907,78 +7639,134 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Edge {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = edge_based_wireframe_model)]
+    #[holder(type_name = "edge_based_wireframe_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct EdgeBasedWireframeModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub ebwm_boundary: Vec<ConnectedEdgeSet>,
     }
+    impl crate::tables::Validate for EdgeBasedWireframeModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = edge_based_wireframe_shape_representation)]
+    #[holder(type_name = "edge_based_wireframe_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct EdgeBasedWireframeShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for EdgeBasedWireframeShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = edge_curve)]
+    #[holder(type_name = "edge_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct EdgeCurve {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub edge: Edge,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_geometry: CurveAny,
         pub same_sense: bool,
     }
+    impl crate::tables::Validate for EdgeCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.edge)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = edge_loop)]
+    #[holder(type_name = "edge_loop")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct EdgeLoop {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub path: Path,
     }
+    impl crate::tables::Validate for EdgeLoop {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.r#loop)?;
+            crate::tables::Validate::validate(&self.path)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = effectivity)]
+    #[holder(type_name = "effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Effectivity {
         #[holder(use_place_holder)]
         pub id: Identifier,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum EffectivityAny {
         #[holder(use_place_holder)]
         Effectivity(Box<Effectivity>),
@@ -5027,25 +7815,37 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Effectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = elementary_surface)]
+    #[holder(type_name = "elementary_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ElementarySurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub position: Axis2Placement3D,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "elementary_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ElementarySurfaceAny {
         #[holder(use_place_holder)]
         ElementarySurface(Box<ElementarySurface>),
@@ -5126,57 +7926,90 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ElementarySurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = ellipse)]
+    #[holder(type_name = "ellipse")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Ellipse {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis_1: PositiveLengthMeasure,
         #[holder(use_place_holder)]
         pub semi_axis_2: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Ellipse {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = evaluated_degenerate_pcurve)]
+    #[holder(type_name = "evaluated_degenerate_pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct EvaluatedDegeneratePcurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub degenerate_pcurve: DegeneratePcurve,
         #[holder(use_place_holder)]
         pub equivalent_point: CartesianPoint,
     }
+    impl crate::tables::Validate for EvaluatedDegeneratePcurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.degenerate_pcurve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = executed_action)]
+    #[holder(type_name = "executed_action")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ExecutedAction {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action: Action,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "executed_action")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ExecutedActionAny {
         #[holder(use_place_holder)]
         ExecutedAction(Box<ExecutedAction>),
@@ -5211,25 +8044,38 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ExecutedAction {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.action)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = face)]
+    #[holder(type_name = "face")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Face {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub bounds: Vec<FaceBoundAny>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "face")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FaceAny {
         #[holder(use_place_holder)]
         Face(Box<Face>),
@@ -5271,18 +8117,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Face {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = face_bound)]
+    #[holder(type_name = "face_bound")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FaceBound {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub bound: LoopAny,
@@ -5290,7 +8146,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "face_bound")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FaceBoundAny {
         #[holder(use_place_holder)]
         FaceBound(Box<FaceBound>),
@@ -5323,32 +8182,53 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for FaceBound {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = face_outer_bound)]
+    #[holder(type_name = "face_outer_bound")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FaceOuterBound {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face_bound: FaceBound,
     }
+    impl crate::tables::Validate for FaceOuterBound {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.face_bound)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = face_surface)]
+    #[holder(type_name = "face_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FaceSurface {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face: Face,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub face_geometry: SurfaceAny,
@@ -5356,7 +8236,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "face_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FaceSurfaceAny {
         #[holder(use_place_holder)]
         FaceSurface(Box<FaceSurface>),
@@ -5401,42 +8284,75 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for FaceSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.face)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = faceted_brep)]
+    #[holder(type_name = "faceted_brep")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FacetedBrep {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub manifold_solid_brep: ManifoldSolidBrep,
     }
+    impl crate::tables::Validate for FacetedBrep {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.manifold_solid_brep)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = faceted_brep_shape_representation)]
+    #[holder(type_name = "faceted_brep_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FacetedBrepShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for FacetedBrepShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = founded_item)]
+    #[holder(type_name = "founded_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FoundedItem {}
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "founded_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FoundedItemAny {
         #[holder(use_place_holder)]
         FoundedItem(Box<FoundedItem>),
@@ -5469,10 +8385,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for FoundedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = functionally_defined_transformation)]
+    #[holder(type_name = "functionally_defined_transformation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct FunctionallyDefinedTransformation {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -5481,7 +8405,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "functionally_defined_transformation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum FunctionallyDefinedTransformationAny {
         #[holder(use_place_holder)]
         FunctionallyDefinedTransformation(Box<FunctionallyDefinedTransformation>),
@@ -5512,53 +8439,85 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for FunctionallyDefinedTransformation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_curve_set)]
+    #[holder(type_name = "geometric_curve_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricCurveSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_set: GeometricSet,
     }
+    impl crate::tables::Validate for GeometricCurveSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_representation_context)]
+    #[holder(type_name = "geometric_representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricRepresentationContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub coordinate_space_dimension: DimensionCount,
     }
+    impl crate::tables::Validate for GeometricRepresentationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_representation_item)]
+    #[holder(type_name = "geometric_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricRepresentationItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricRepresentationItemAny {
         #[holder(use_place_holder)]
         GeometricRepresentationItem(Box<GeometricRepresentationItem>),
@@ -5762,25 +8721,38 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for GeometricRepresentationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometric_set)]
+    #[holder(type_name = "geometric_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricSet {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub elements: Vec<GeometricSetSelect>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "geometric_set")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum GeometricSetAny {
         #[holder(use_place_holder)]
         GeometricSet(Box<GeometricSet>),
@@ -5815,102 +8787,171 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for GeometricSet {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometrically_bounded_surface_shape_representation)]
+    #[holder(type_name = "geometrically_bounded_surface_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricallyBoundedSurfaceShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for GeometricallyBoundedSurfaceShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = geometrically_bounded_wireframe_shape_representation)]
+    #[holder(type_name = "geometrically_bounded_wireframe_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GeometricallyBoundedWireframeShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for GeometricallyBoundedWireframeShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = global_uncertainty_assigned_context)]
+    #[holder(type_name = "global_uncertainty_assigned_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GlobalUncertaintyAssignedContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub uncertainty: Vec<UncertaintyMeasureWithUnit>,
     }
+    impl crate::tables::Validate for GlobalUncertaintyAssignedContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = global_unit_assigned_context)]
+    #[holder(type_name = "global_unit_assigned_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct GlobalUnitAssignedContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
         #[holder(use_place_holder)]
         pub units: Vec<Unit>,
     }
+    impl crate::tables::Validate for GlobalUnitAssignedContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = hyperbola)]
+    #[holder(type_name = "hyperbola")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Hyperbola {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub semi_axis: PositiveLengthMeasure,
         #[holder(use_place_holder)]
         pub semi_imag_axis: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for Hyperbola {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = intersection_curve)]
+    #[holder(type_name = "intersection_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct IntersectionCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
     }
+    impl crate::tables::Validate for IntersectionCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = item_defined_transformation)]
+    #[holder(type_name = "item_defined_transformation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ItemDefinedTransformation {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -5921,56 +8962,94 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub transform_item_2: RepresentationItemAny,
     }
+    impl crate::tables::Validate for ItemDefinedTransformation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = length_measure_with_unit)]
+    #[holder(type_name = "length_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for LengthMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = length_unit)]
+    #[holder(type_name = "length_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LengthUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for LengthUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = line)]
+    #[holder(type_name = "line")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Line {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub pnt: CartesianPoint,
         #[holder(use_place_holder)]
         pub dir: Vector,
     }
+    impl crate::tables::Validate for Line {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = local_time)]
+    #[holder(type_name = "local_time")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LocalTime {
         #[holder(use_place_holder)]
         pub hour_component: HourInDay,
@@ -5981,23 +9060,35 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub zone: CoordinatedUniversalTimeOffset,
     }
+    impl crate::tables::Validate for LocalTime {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = r#loop)]
+    #[holder(type_name = "loop")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Loop {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "loop")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum LoopAny {
         #[holder(use_place_holder)]
         Loop(Box<Loop>),
@@ -6048,43 +9139,66 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Loop {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = lot_effectivity)]
+    #[holder(type_name = "lot_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct LotEffectivity {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_lot_id: Identifier,
         #[holder(use_place_holder)]
         pub effectivity_lot_size: MeasureWithUnitAny,
     }
+    impl crate::tables::Validate for LotEffectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.effectivity)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = manifold_solid_brep)]
+    #[holder(type_name = "manifold_solid_brep")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ManifoldSolidBrep {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub solid_model: SolidModel,
         #[holder(use_place_holder)]
         pub outer: ClosedShellAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "manifold_solid_brep")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ManifoldSolidBrepAny {
         #[holder(use_place_holder)]
         ManifoldSolidBrep(Box<ManifoldSolidBrep>),
@@ -6132,70 +9246,119 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ManifoldSolidBrep {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.solid_model)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = manifold_surface_shape_representation)]
+    #[holder(type_name = "manifold_surface_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ManifoldSurfaceShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for ManifoldSurfaceShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = mapped_item)]
+    #[holder(type_name = "mapped_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MappedItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
         #[holder(use_place_holder)]
         pub mapping_source: RepresentationMap,
         #[holder(use_place_holder)]
         pub mapping_target: RepresentationItemAny,
     }
+    impl crate::tables::Validate for MappedItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = mass_measure_with_unit)]
+    #[holder(type_name = "mass_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MassMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for MassMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = mass_unit)]
+    #[holder(type_name = "mass_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MassUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for MassUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = measure_with_unit)]
+    #[holder(type_name = "measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MeasureWithUnit {
         #[holder(use_place_holder)]
         pub value_component: MeasureValue,
@@ -6204,7 +9367,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum MeasureWithUnitAny {
         #[holder(use_place_holder)]
         MeasureWithUnit(Box<MeasureWithUnit>),
@@ -6277,31 +9443,52 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for MeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = mechanical_context)]
+    #[holder(type_name = "mechanical_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct MechanicalContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_context: ProductContext,
     }
+    impl crate::tables::Validate for MechanicalContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_context)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = named_unit)]
+    #[holder(type_name = "named_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct NamedUnit {
         #[holder(use_place_holder)]
         pub dimensions: DimensionalExponents,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "named_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum NamedUnitAny {
         #[holder(use_place_holder)]
         NamedUnit(Box<NamedUnit>),
@@ -6390,32 +9577,51 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for NamedUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = next_assembly_usage_occurrence)]
+    #[holder(type_name = "next_assembly_usage_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct NextAssemblyUsageOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
+    impl crate::tables::Validate for NextAssemblyUsageOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.assembly_component_usage)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = offset_curve_3d)]
+    #[holder(type_name = "offset_curve_3d")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OffsetCurve3D {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -6425,18 +9631,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub ref_direction: Direction,
     }
+    impl crate::tables::Validate for OffsetCurve3D {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = offset_surface)]
+    #[holder(type_name = "offset_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OffsetSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -6444,23 +9660,36 @@ pub mod config_control_design {
         pub distance: LengthMeasure,
         pub self_intersect: Logical,
     }
+    impl crate::tables::Validate for OffsetSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = open_shell)]
+    #[holder(type_name = "open_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OpenShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub connected_face_set: ConnectedFaceSet,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "open_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum OpenShellAny {
         #[holder(use_place_holder)]
         OpenShell(Box<OpenShell>),
@@ -6495,26 +9724,45 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for OpenShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.connected_face_set)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = ordinal_date)]
+    #[holder(type_name = "ordinal_date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrdinalDate {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub day_component: DayInYearNumber,
     }
+    impl crate::tables::Validate for OrdinalDate {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.date)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization)]
+    #[holder(type_name = "organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Organization {
         #[holder(use_place_holder)]
         pub id: Option<Identifier>,
@@ -6523,10 +9771,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for Organization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organization_relationship)]
+    #[holder(type_name = "organization_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -6537,28 +9793,46 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_organization: Organization,
     }
+    impl crate::tables::Validate for OrganizationRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = organizational_address)]
+    #[holder(type_name = "organizational_address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationalAddress {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub organizations: Vec<Organization>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for OrganizationalAddress {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.address)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = organizational_project)]
+    #[holder(type_name = "organizational_project")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrganizationalProject {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -6567,154 +9841,246 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub responsible_organizations: Vec<Organization>,
     }
+    impl crate::tables::Validate for OrganizationalProject {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = oriented_closed_shell)]
+    #[holder(type_name = "oriented_closed_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrientedClosedShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub closed_shell: ClosedShell,
         #[holder(use_place_holder)]
         pub closed_shell_element: ClosedShellAny,
         pub orientation: bool,
     }
+    impl crate::tables::Validate for OrientedClosedShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.closed_shell)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = oriented_edge)]
+    #[holder(type_name = "oriented_edge")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrientedEdge {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub edge: Edge,
         #[holder(use_place_holder)]
         pub edge_element: EdgeAny,
         pub orientation: bool,
     }
+    impl crate::tables::Validate for OrientedEdge {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.edge)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = oriented_face)]
+    #[holder(type_name = "oriented_face")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrientedFace {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub face: Face,
         #[holder(use_place_holder)]
         pub face_element: FaceAny,
         pub orientation: bool,
     }
+    impl crate::tables::Validate for OrientedFace {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.face)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = oriented_open_shell)]
+    #[holder(type_name = "oriented_open_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrientedOpenShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub open_shell: OpenShell,
         #[holder(use_place_holder)]
         pub open_shell_element: OpenShellAny,
         pub orientation: bool,
     }
+    impl crate::tables::Validate for OrientedOpenShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.open_shell)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = oriented_path)]
+    #[holder(type_name = "oriented_path")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OrientedPath {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub path: Path,
         #[holder(use_place_holder)]
         pub path_element: PathAny,
         pub orientation: bool,
     }
+    impl crate::tables::Validate for OrientedPath {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.path)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = outer_boundary_curve)]
+    #[holder(type_name = "outer_boundary_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct OuterBoundaryCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub boundary_curve: BoundaryCurve,
     }
+    impl crate::tables::Validate for OuterBoundaryCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.boundary_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = parabola)]
+    #[holder(type_name = "parabola")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Parabola {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub conic: Conic,
         #[holder(use_place_holder)]
         pub focal_dist: LengthMeasure,
     }
+    impl crate::tables::Validate for Parabola {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.conic)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = parametric_representation_context)]
+    #[holder(type_name = "parametric_representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ParametricRepresentationContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_context: RepresentationContext,
     }
+    impl crate::tables::Validate for ParametricRepresentationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_context)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = path)]
+    #[holder(type_name = "path")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Path {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub edge_list: Vec<OrientedEdge>,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "path")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PathAny {
         #[holder(use_place_holder)]
         Path(Box<Path>),
@@ -6756,18 +10122,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Path {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = pcurve)]
+    #[holder(type_name = "pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Pcurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -6776,7 +10152,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "pcurve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PcurveAny {
         #[holder(use_place_holder)]
         Pcurve(Box<Pcurve>),
@@ -6809,10 +10188,19 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Pcurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person)]
+    #[holder(type_name = "person")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Person {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -6827,20 +10215,39 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub suffix_titles: Option<Vec<Label>>,
     }
+    impl crate::tables::Validate for Person {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            if !(self.last_name.is_some() || self.first_name.is_some()) {
+                return Err("person.wr1");
+            }
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization)]
+    #[holder(type_name = "person_and_organization")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganization {
         #[holder(use_place_holder)]
         pub the_person: Person,
         #[holder(use_place_holder)]
         pub the_organization: Organization,
     }
+    impl crate::tables::Validate for PersonAndOrganization {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_assignment)]
+    #[holder(type_name = "person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganizationAssignment {
         #[holder(use_place_holder)]
         pub assigned_person_and_organization: PersonAndOrganization,
@@ -6849,7 +10256,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "person_and_organization_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PersonAndOrganizationAssignmentAny {
         #[holder(use_place_holder)]
         PersonAndOrganizationAssignment(Box<PersonAndOrganizationAssignment>),
@@ -6880,51 +10290,81 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for PersonAndOrganizationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = person_and_organization_role)]
+    #[holder(type_name = "person_and_organization_role")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonAndOrganizationRole {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for PersonAndOrganizationRole {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = personal_address)]
+    #[holder(type_name = "personal_address")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PersonalAddress {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub address: Address,
         #[holder(use_place_holder)]
         pub people: Vec<Person>,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for PersonalAddress {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.address)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = placement)]
+    #[holder(type_name = "placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Placement {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub location: CartesianPoint,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "placement")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PlacementAny {
         #[holder(use_place_holder)]
         Placement(Box<Placement>),
@@ -6979,65 +10419,108 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Placement {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = plane)]
+    #[holder(type_name = "plane")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Plane {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
     }
+    impl crate::tables::Validate for Plane {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.elementary_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_measure_with_unit)]
+    #[holder(type_name = "plane_angle_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for PlaneAngleMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = plane_angle_unit)]
+    #[holder(type_name = "plane_angle_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PlaneAngleUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for PlaneAngleUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point)]
+    #[holder(type_name = "point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Point {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PointAny {
         #[holder(use_place_holder)]
         Point(Box<Point>),
@@ -7106,36 +10589,56 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Point {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point_on_curve)]
+    #[holder(type_name = "point_on_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PointOnCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
         #[holder(use_place_holder)]
         pub point_parameter: ParameterValue,
     }
+    impl crate::tables::Validate for PointOnCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point_on_surface)]
+    #[holder(type_name = "point_on_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PointOnSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -7144,60 +10647,101 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub point_parameter_v: ParameterValue,
     }
+    impl crate::tables::Validate for PointOnSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = point_replica)]
+    #[holder(type_name = "point_replica")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PointReplica {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub point: Point,
         #[holder(use_place_holder)]
         pub parent_pt: PointAny,
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperatorAny,
     }
+    impl crate::tables::Validate for PointReplica {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.point)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = poly_loop)]
+    #[holder(type_name = "poly_loop")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PolyLoop {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub polygon: Vec<CartesianPoint>,
     }
+    impl crate::tables::Validate for PolyLoop {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.r#loop)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = polyline)]
+    #[holder(type_name = "polyline")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Polyline {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub points: Vec<CartesianPoint>,
     }
+    impl crate::tables::Validate for Polyline {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product)]
+    #[holder(type_name = "product")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Product {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7208,10 +10752,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub frame_of_reference: Vec<ProductContextAny>,
     }
+    impl crate::tables::Validate for Product {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_category)]
+    #[holder(type_name = "product_category")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductCategory {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7220,7 +10772,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_category")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductCategoryAny {
         #[holder(use_place_holder)]
         ProductCategory(Box<ProductCategory>),
@@ -7245,10 +10800,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductCategory {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_category_relationship)]
+    #[holder(type_name = "product_category_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductCategoryRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7259,10 +10822,18 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub sub_category: ProductCategoryAny,
     }
+    impl crate::tables::Validate for ProductCategoryRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_concept)]
+    #[holder(type_name = "product_concept")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductConcept {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7273,41 +10844,63 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub market_context: ProductConceptContext,
     }
+    impl crate::tables::Validate for ProductConcept {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_concept_context)]
+    #[holder(type_name = "product_concept_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductConceptContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub market_segment_type: Label,
     }
+    impl crate::tables::Validate for ProductConceptContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.application_context_element)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_context)]
+    #[holder(type_name = "product_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub discipline_type: Label,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductContextAny {
         #[holder(use_place_holder)]
         ProductContext(Box<ProductContext>),
@@ -7342,10 +10935,19 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.application_context_element)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition)]
+    #[holder(type_name = "product_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinition {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7358,7 +10960,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionAny {
         #[holder(use_place_holder)]
         ProductDefinition(Box<ProductDefinition>),
@@ -7383,25 +10988,37 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_context)]
+    #[holder(type_name = "product_definition_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionContext {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub application_context_element: ApplicationContextElement,
         #[holder(use_place_holder)]
         pub life_cycle_stage: Label,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionContextAny {
         #[holder(use_place_holder)]
         ProductDefinitionContext(Box<ProductDefinitionContext>),
@@ -7438,25 +11055,38 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinitionContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.application_context_element)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_effectivity)]
+    #[holder(type_name = "product_definition_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionEffectivity {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub usage: ProductDefinitionRelationshipAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionEffectivityAny {
         #[holder(use_place_holder)]
         ProductDefinitionEffectivity(Box<ProductDefinitionEffectivity>),
@@ -7493,10 +11123,19 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinitionEffectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.effectivity)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_formation)]
+    #[holder(type_name = "product_definition_formation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionFormation {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7507,7 +11146,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition_formation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionFormationAny {
         #[holder(use_place_holder)]
         ProductDefinitionFormation(Box<ProductDefinitionFormation>),
@@ -7538,25 +11180,43 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinitionFormation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_formation_with_specified_source)]
+    #[holder(type_name = "product_definition_formation_with_specified_source")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionFormationWithSpecifiedSource {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_formation: ProductDefinitionFormation,
         pub make_or_buy: Source,
     }
+    impl crate::tables::Validate for ProductDefinitionFormationWithSpecifiedSource {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_formation)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = product_definition_relationship)]
+    #[holder(type_name = "product_definition_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionRelationship {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -7571,7 +11231,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionRelationshipAny {
         #[holder(use_place_holder)]
         ProductDefinitionRelationship(Box<ProductDefinitionRelationship>),
@@ -7612,37 +11275,59 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinitionRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_shape)]
+    #[holder(type_name = "product_definition_shape")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionShape {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition: PropertyDefinition,
     }
+    impl crate::tables::Validate for ProductDefinitionShape {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.property_definition)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_usage)]
+    #[holder(type_name = "product_definition_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionUsage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "product_definition_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ProductDefinitionUsageAny {
         #[holder(use_place_holder)]
         ProductDefinitionUsage(Box<ProductDefinitionUsage>),
@@ -7679,56 +11364,95 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ProductDefinitionUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_definition_with_associated_documents)]
+    #[holder(type_name = "product_definition_with_associated_documents")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductDefinitionWithAssociatedDocuments {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition: ProductDefinition,
         #[holder(use_place_holder)]
         pub documentation_ids: Vec<DocumentAny>,
     }
+    impl crate::tables::Validate for ProductDefinitionWithAssociatedDocuments {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = product_related_product_category)]
+    #[holder(type_name = "product_related_product_category")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ProductRelatedProductCategory {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_category: ProductCategory,
         #[holder(use_place_holder)]
         pub products: Vec<Product>,
     }
+    impl crate::tables::Validate for ProductRelatedProductCategory {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_category)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = promissory_usage_occurrence)]
+    #[holder(type_name = "promissory_usage_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PromissoryUsageOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
     }
+    impl crate::tables::Validate for PromissoryUsageOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.assembly_component_usage)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition)]
+    #[holder(type_name = "property_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PropertyDefinition {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7739,7 +11463,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "property_definition")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PropertyDefinitionAny {
         #[holder(use_place_holder)]
         PropertyDefinition(Box<PropertyDefinition>),
@@ -7764,10 +11491,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for PropertyDefinition {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = property_definition_representation)]
+    #[holder(type_name = "property_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct PropertyDefinitionRepresentation {
         #[holder(use_place_holder)]
         pub definition: PropertyDefinitionAny,
@@ -7776,7 +11511,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "property_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum PropertyDefinitionRepresentationAny {
         #[holder(use_place_holder)]
         PropertyDefinitionRepresentation(Box<PropertyDefinitionRepresentation>),
@@ -7807,108 +11545,177 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for PropertyDefinitionRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = quantified_assembly_component_usage)]
+    #[holder(type_name = "quantified_assembly_component_usage")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct QuantifiedAssemblyComponentUsage {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
         #[holder(use_place_holder)]
         pub quantity: MeasureWithUnitAny,
     }
+    impl crate::tables::Validate for QuantifiedAssemblyComponentUsage {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.assembly_component_usage)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = quasi_uniform_curve)]
+    #[holder(type_name = "quasi_uniform_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct QuasiUniformCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for QuasiUniformCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = quasi_uniform_surface)]
+    #[holder(type_name = "quasi_uniform_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct QuasiUniformSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
+    impl crate::tables::Validate for QuasiUniformSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = rational_b_spline_curve)]
+    #[holder(type_name = "rational_b_spline_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RationalBSplineCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
         pub weights_data: Vec<f64>,
     }
+    impl crate::tables::Validate for RationalBSplineCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = rational_b_spline_surface)]
+    #[holder(type_name = "rational_b_spline_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RationalBSplineSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
         pub weights_data: Vec<Vec<f64>>,
     }
+    impl crate::tables::Validate for RationalBSplineSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = rectangular_composite_surface)]
+    #[holder(type_name = "rectangular_composite_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RectangularCompositeSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub segments: Vec<Vec<SurfacePatch>>,
     }
+    impl crate::tables::Validate for RectangularCompositeSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = rectangular_trimmed_surface)]
+    #[holder(type_name = "rectangular_trimmed_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RectangularTrimmedSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_surface: BoundedSurface,
         #[holder(use_place_holder)]
         pub basis_surface: SurfaceAny,
@@ -7923,26 +11730,45 @@ pub mod config_control_design {
         pub usense: bool,
         pub vsense: bool,
     }
+    impl crate::tables::Validate for RectangularTrimmedSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = reparametrised_composite_curve_segment)]
+    #[holder(type_name = "reparametrised_composite_curve_segment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ReparametrisedCompositeCurveSegment {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub composite_curve_segment: CompositeCurveSegment,
         #[holder(use_place_holder)]
         pub param_length: ParameterValue,
     }
+    impl crate::tables::Validate for ReparametrisedCompositeCurveSegment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.composite_curve_segment)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation)]
+    #[holder(type_name = "representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Representation {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -7953,7 +11779,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationAny {
         #[holder(use_place_holder)]
         Representation(Box<Representation>),
@@ -7986,10 +11815,18 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Representation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_context)]
+    #[holder(type_name = "representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationContext {
         #[holder(use_place_holder)]
         pub context_identifier: Identifier,
@@ -7998,7 +11835,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_context")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationContextAny {
         #[holder(use_place_holder)]
         RepresentationContext(Box<RepresentationContext>),
@@ -8047,17 +11887,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationContext {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_item)]
+    #[holder(type_name = "representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationItem {
         #[holder(use_place_holder)]
         pub name: Label,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationItemAny {
         #[holder(use_place_holder)]
         RepresentationItem(Box<RepresentationItem>),
@@ -8098,20 +11949,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_map)]
+    #[holder(type_name = "representation_map")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationMap {
         #[holder(use_place_holder)]
         pub mapping_origin: RepresentationItemAny,
         #[holder(use_place_holder)]
         pub mapped_representation: RepresentationAny,
     }
+    impl crate::tables::Validate for RepresentationMap {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = representation_relationship)]
+    #[holder(type_name = "representation_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -8124,7 +11991,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "representation_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum RepresentationRelationshipAny {
         #[holder(use_place_holder)]
         RepresentationRelationship(Box<RepresentationRelationship>),
@@ -8163,40 +12033,68 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for RepresentationRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = representation_relationship_with_transformation)]
+    #[holder(type_name = "representation_relationship_with_transformation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct RepresentationRelationshipWithTransformation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_relationship: RepresentationRelationship,
         #[holder(use_place_holder)]
         pub transformation_operator: Transformation,
     }
+    impl crate::tables::Validate for RepresentationRelationshipWithTransformation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = seam_curve)]
+    #[holder(type_name = "seam_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SeamCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface_curve: SurfaceCurve,
     }
+    impl crate::tables::Validate for SeamCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface_curve)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification)]
+    #[holder(type_name = "security_classification")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassification {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -8205,17 +12103,28 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub security_level: SecurityClassificationLevel,
     }
+    impl crate::tables::Validate for SecurityClassification {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_assignment)]
+    #[holder(type_name = "security_classification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassificationAssignment {
         #[holder(use_place_holder)]
         pub assigned_security_classification: SecurityClassification,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "security_classification_assignment")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SecurityClassificationAssignmentAny {
         #[holder(use_place_holder)]
         SecurityClassificationAssignment(Box<SecurityClassificationAssignment>),
@@ -8246,36 +12155,62 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for SecurityClassificationAssignment {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = security_classification_level)]
+    #[holder(type_name = "security_classification_level")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SecurityClassificationLevel {
         #[holder(use_place_holder)]
         pub name: Label,
     }
+    impl crate::tables::Validate for SecurityClassificationLevel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = serial_numbered_effectivity)]
+    #[holder(type_name = "serial_numbered_effectivity")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SerialNumberedEffectivity {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub effectivity: Effectivity,
         #[holder(use_place_holder)]
         pub effectivity_start_id: Identifier,
         #[holder(use_place_holder)]
         pub effectivity_end_id: Option<Identifier>,
     }
+    impl crate::tables::Validate for SerialNumberedEffectivity {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.effectivity)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = shape_aspect)]
+    #[holder(type_name = "shape_aspect")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeAspect {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -8285,10 +12220,18 @@ pub mod config_control_design {
         pub of_shape: ProductDefinitionShape,
         pub product_definitional: Logical,
     }
+    impl crate::tables::Validate for ShapeAspect {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = shape_aspect_relationship)]
+    #[holder(type_name = "shape_aspect_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeAspectRelationship {
         #[holder(use_place_holder)]
         pub name: Label,
@@ -8299,37 +12242,59 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub related_shape_aspect: ShapeAspect,
     }
+    impl crate::tables::Validate for ShapeAspectRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shape_definition_representation)]
+    #[holder(type_name = "shape_definition_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeDefinitionRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub property_definition_representation: PropertyDefinitionRepresentation,
     }
+    impl crate::tables::Validate for ShapeDefinitionRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.property_definition_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shape_representation)]
+    #[holder(type_name = "shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation: Representation,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ShapeRepresentationAny {
         #[holder(use_place_holder)]
         ShapeRepresentation(Box<ShapeRepresentation>),
@@ -8444,127 +12409,210 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shape_representation_relationship)]
+    #[holder(type_name = "shape_representation_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShapeRepresentationRelationship {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_relationship: RepresentationRelationship,
     }
+    impl crate::tables::Validate for ShapeRepresentationRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shell_based_surface_model)]
+    #[holder(type_name = "shell_based_surface_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShellBasedSurfaceModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub sbsm_boundary: Vec<Shell>,
     }
+    impl crate::tables::Validate for ShellBasedSurfaceModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shell_based_wireframe_model)]
+    #[holder(type_name = "shell_based_wireframe_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShellBasedWireframeModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub sbwm_boundary: Vec<Shell>,
     }
+    impl crate::tables::Validate for ShellBasedWireframeModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = shell_based_wireframe_shape_representation)]
+    #[holder(type_name = "shell_based_wireframe_shape_representation")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ShellBasedWireframeShapeRepresentation {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub shape_representation: ShapeRepresentation,
     }
+    impl crate::tables::Validate for ShellBasedWireframeShapeRepresentation {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.shape_representation)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = si_unit)]
+    #[holder(type_name = "si_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SiUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
         pub prefix: Option<SiPrefix>,
         pub name: SiUnitName,
     }
+    impl crate::tables::Validate for SiUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = solid_angle_measure_with_unit)]
+    #[holder(type_name = "solid_angle_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SolidAngleMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for SolidAngleMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = solid_angle_unit)]
+    #[holder(type_name = "solid_angle_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SolidAngleUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for SolidAngleUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = solid_model)]
+    #[holder(type_name = "solid_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SolidModel {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "solid_model")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SolidModelAny {
         #[holder(use_place_holder)]
         SolidModel(Box<SolidModel>),
@@ -8599,103 +12647,166 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for SolidModel {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = specified_higher_usage_occurrence)]
+    #[holder(type_name = "specified_higher_usage_occurrence")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SpecifiedHigherUsageOccurrence {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub assembly_component_usage: AssemblyComponentUsage,
         #[holder(use_place_holder)]
         pub upper_usage: AssemblyComponentUsageAny,
         #[holder(use_place_holder)]
         pub next_usage: NextAssemblyUsageOccurrence,
     }
+    impl crate::tables::Validate for SpecifiedHigherUsageOccurrence {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.assembly_component_usage)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = spherical_surface)]
+    #[holder(type_name = "spherical_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SphericalSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub radius: PositiveLengthMeasure,
     }
+    impl crate::tables::Validate for SphericalSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.elementary_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = start_request)]
+    #[holder(type_name = "start_request")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct StartRequest {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_request_assignment: ActionRequestAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<StartRequestItem>,
     }
+    impl crate::tables::Validate for StartRequest {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.action_request_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = start_work)]
+    #[holder(type_name = "start_work")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct StartWork {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub action_assignment: ActionAssignment,
         #[holder(use_place_holder)]
         pub items: Vec<WorkItem>,
     }
+    impl crate::tables::Validate for StartWork {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.action_assignment)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = supplied_part_relationship)]
+    #[holder(type_name = "supplied_part_relationship")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SuppliedPartRelationship {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub product_definition_relationship: ProductDefinitionRelationship,
     }
+    impl crate::tables::Validate for SuppliedPartRelationship {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.product_definition_relationship)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface)]
+    #[holder(type_name = "surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Surface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SurfaceAny {
         #[holder(use_place_holder)]
         Surface(Box<Surface>),
@@ -8764,18 +12875,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Surface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface_curve)]
+    #[holder(type_name = "surface_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SurfaceCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub curve: Curve,
         #[holder(use_place_holder)]
         pub curve_3d: CurveAny,
@@ -8785,7 +12906,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "surface_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SurfaceCurveAny {
         #[holder(use_place_holder)]
         SurfaceCurve(Box<SurfaceCurve>),
@@ -8840,50 +12964,80 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for SurfaceCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface_of_linear_extrusion)]
+    #[holder(type_name = "surface_of_linear_extrusion")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SurfaceOfLinearExtrusion {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub swept_surface: SweptSurface,
         #[holder(use_place_holder)]
         pub extrusion_axis: Vector,
     }
+    impl crate::tables::Validate for SurfaceOfLinearExtrusion {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.swept_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface_of_revolution)]
+    #[holder(type_name = "surface_of_revolution")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SurfaceOfRevolution {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub swept_surface: SweptSurface,
         #[holder(use_place_holder)]
         pub axis_position: Axis1Placement,
     }
+    impl crate::tables::Validate for SurfaceOfRevolution {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.swept_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface_patch)]
+    #[holder(type_name = "surface_patch")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SurfacePatch {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub founded_item: FoundedItem,
         #[holder(use_place_holder)]
         pub parent_surface: BoundedSurfaceAny,
@@ -8892,43 +13046,66 @@ pub mod config_control_design {
         pub u_sense: bool,
         pub v_sense: bool,
     }
+    impl crate::tables::Validate for SurfacePatch {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.founded_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = surface_replica)]
+    #[holder(type_name = "surface_replica")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SurfaceReplica {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub parent_surface: SurfaceAny,
         #[holder(use_place_holder)]
         pub transformation: CartesianTransformationOperator3D,
     }
+    impl crate::tables::Validate for SurfaceReplica {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = swept_surface)]
+    #[holder(type_name = "swept_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct SweptSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub surface: Surface,
         #[holder(use_place_holder)]
         pub swept_curve: CurveAny,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "swept_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum SweptSurfaceAny {
         #[holder(use_place_holder)]
         SweptSurface(Box<SweptSurface>),
@@ -8974,23 +13151,36 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for SweptSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = topological_representation_item)]
+    #[holder(type_name = "topological_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TopologicalRepresentationItem {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub representation_item: RepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "topological_representation_item")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum TopologicalRepresentationItemAny {
         #[holder(use_place_holder)]
         TopologicalRepresentationItem(Box<TopologicalRepresentationItem>),
@@ -9126,18 +13316,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for TopologicalRepresentationItem {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = toroidal_surface)]
+    #[holder(type_name = "toroidal_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct ToroidalSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub elementary_surface: ElementarySurface,
         #[holder(use_place_holder)]
         pub major_radius: PositiveLengthMeasure,
@@ -9146,7 +13346,10 @@ pub mod config_control_design {
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "toroidal_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum ToroidalSurfaceAny {
         #[holder(use_place_holder)]
         ToroidalSurface(Box<ToroidalSurface>),
@@ -9183,18 +13386,28 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for ToroidalSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.elementary_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = trimmed_curve)]
+    #[holder(type_name = "trimmed_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct TrimmedCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub bounded_curve: BoundedCurve,
         #[holder(use_place_holder)]
         pub basis_curve: CurveAny,
@@ -9205,74 +13418,123 @@ pub mod config_control_design {
         pub sense_agreement: bool,
         pub master_representation: TrimmingPreference,
     }
+    impl crate::tables::Validate for TrimmedCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.bounded_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = uncertainty_measure_with_unit)]
+    #[holder(type_name = "uncertainty_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct UncertaintyMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
         #[holder(use_place_holder)]
         pub name: Label,
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for UncertaintyMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = uniform_curve)]
+    #[holder(type_name = "uniform_curve")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct UniformCurve {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_curve: BSplineCurve,
     }
+    impl crate::tables::Validate for UniformCurve {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_curve)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = uniform_surface)]
+    #[holder(type_name = "uniform_surface")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct UniformSurface {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub b_spline_surface: BSplineSurface,
     }
+    impl crate::tables::Validate for UniformSurface {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.b_spline_surface)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = vector)]
+    #[holder(type_name = "vector")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Vector {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub orientation: Direction,
         #[holder(use_place_holder)]
         pub magnitude: LengthMeasure,
     }
+    impl crate::tables::Validate for Vector {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder)]
     # [holder (table = Tables)]
     # [holder (field = versioned_action_request)]
+    #[holder(type_name = "versioned_action_request")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VersionedActionRequest {
         #[holder(use_place_holder)]
         pub id: Identifier,
@@ -9283,23 +13545,35 @@ pub mod config_control_design {
         #[holder(use_place_holder)]
         pub description: Text,
     }
+    impl crate::tables::Validate for VersionedActionRequest {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = vertex)]
+    #[holder(type_name = "vertex")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct Vertex {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
     }
     #[derive(Debug, Clone, PartialEq, Holder)]
     # [holder (table = Tables)]
+    #[holder(type_name = "vertex")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub enum VertexAny {
         #[holder(use_place_holder)]
         Vertex(Box<Vertex>),
@@ -9332,114 +13606,192 @@ pub mod config_control_design {
             }
         }
     }
+    impl crate::tables::Validate for Vertex {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = vertex_loop)]
+    #[holder(type_name = "vertex_loop")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VertexLoop {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub r#loop: Loop,
         #[holder(use_place_holder)]
         pub loop_vertex: VertexAny,
     }
+    impl crate::tables::Validate for VertexLoop {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.r#loop)?;
+            Ok(())
+        }
+    }
     #[derive(Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut)]
     # [holder (table = Tables)]
     # [holder (field = vertex_point)]
+    #[holder(type_name = "vertex_point")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VertexPoint {
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub vertex: Vertex,
         #[as_ref]
         #[as_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub geometric_representation_item: GeometricRepresentationItem,
         #[holder(use_place_holder)]
         pub vertex_geometry: PointAny,
     }
+    impl crate::tables::Validate for VertexPoint {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.vertex)?;
+            crate::tables::Validate::validate(&self.geometric_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = vertex_shell)]
+    #[holder(type_name = "vertex_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VertexShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub vertex_shell_extent: VertexLoop,
     }
+    impl crate::tables::Validate for VertexShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = volume_measure_with_unit)]
+    #[holder(type_name = "volume_measure_with_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VolumeMeasureWithUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub measure_with_unit: MeasureWithUnit,
     }
+    impl crate::tables::Validate for VolumeMeasureWithUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.measure_with_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = volume_unit)]
+    #[holder(type_name = "volume_unit")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct VolumeUnit {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub named_unit: NamedUnit,
     }
+    impl crate::tables::Validate for VolumeUnit {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.named_unit)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = week_of_year_and_day_date)]
+    #[holder(type_name = "week_of_year_and_day_date")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct WeekOfYearAndDayDate {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub date: Date,
         #[holder(use_place_holder)]
         pub week_component: WeekInYearNumber,
         #[holder(use_place_holder)]
         pub day_component: Option<DayInWeekNumber>,
     }
+    impl crate::tables::Validate for WeekOfYearAndDayDate {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.date)?;
+            Ok(())
+        }
+    }
     #[derive(
         Debug, Clone, PartialEq, :: derive_new :: new, Holder, AsRef, AsMut, Deref, DerefMut,
     )]
     # [holder (table = Tables)]
     # [holder (field = wire_shell)]
+    #[holder(type_name = "wire_shell")]
     #[holder(generate_deserialize)]
+    #[holder(generate_into_parameter)]
+    #[holder(generate_from_owned)]
     pub struct WireShell {
         #[as_ref]
         #[as_mut]
         #[deref]
         #[deref_mut]
         #[holder(use_place_holder)]
+        #[holder(supertype)]
         pub topological_representation_item: TopologicalRepresentationItem,
         #[holder(use_place_holder)]
         pub wire_shell_extent: Vec<LoopAny>,
     }
+    impl crate::tables::Validate for WireShell {
+        fn validate(&self) -> ::std::result::Result<(), &'static str> {
+            crate::tables::Validate::validate(&self.topological_representation_item)?;
+            Ok(())
+        }
+    }
 }
@@ -2,9 +2,11 @@ mod name;
 mod parameter;
 mod record;
 mod subsuper;
+mod table;
 
 pub use parameter::*;
 pub use record::*;
+pub use table::*;
 
 #[cfg(doc)]
 use crate::ast::*;
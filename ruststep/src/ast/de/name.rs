@@ -17,7 +17,7 @@ use serde::de::{self, IntoDeserializer};
 // and `VariantAccess` determines its component e.g. `1u8`.
 // These are composed into `E::N(1u8)` in `Visitor::visit_enum`.
 //
-impl<'de, 'name> de::EnumAccess<'de> for &'name Name {
+impl<'de, 'name: 'de> de::EnumAccess<'de> for &'name Name {
     type Error = crate::error::Error;
     type Variant = Self;
     fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant)>
@@ -36,7 +36,7 @@ impl<'de, 'name> de::EnumAccess<'de> for &'name Name {
     }
 }
 
-impl<'de, 'name> de::VariantAccess<'de> for &'name Name {
+impl<'de, 'name: 'de> de::VariantAccess<'de> for &'name Name {
     type Error = crate::error::Error;
 
     fn unit_variant(self) -> Result<()> {
@@ -49,7 +49,9 @@ impl<'de, 'name> de::VariantAccess<'de> for &'name Name {
         D: de::DeserializeSeed<'de>,
     {
         match self {
-            Name::Entity(id) | Name::Value(id) => seed.deserialize(id.into_deserializer()),
+            Name::Entity(InstanceId(id)) | Name::Value(id) => {
+                seed.deserialize(id.into_deserializer())
+            }
             Name::ConstantEntity(name) | Name::ConstantValue(name) => {
                 seed.deserialize(name.as_str().into_deserializer())
             }
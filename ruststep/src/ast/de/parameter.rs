@@ -1,12 +1,38 @@
 use super::*;
 use crate::ast::*;
-use inflector::Inflector;
+use alloc::string::String;
 use serde::{
     de::{self, IntoDeserializer},
     forward_to_deserialize_any,
 };
 
-impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
+/// Variant name for enumeration item, e.g. `.SOME_MULTI_WORD_VALUE.` into `SomeMultiWordValue`
+///
+/// espr renames generated variants by `#[serde(rename)]` according to
+/// `espr::codegen::rust::deserialized_variant_name`, which must agree with this.
+#[cfg(feature = "std")]
+fn to_pascal_case(variant: &str) -> String {
+    inflector::Inflector::to_pascal_case(variant)
+}
+
+/// Fallback of `Inflector::to_pascal_case` for enumeration items without `std`,
+/// e.g. `LEVEL_1` into `Level1`
+#[cfg(not(feature = "std"))]
+fn to_pascal_case(variant: &str) -> String {
+    variant
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars.map(|c| c.to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+impl<'de, 'param: 'de> de::Deserializer<'de> for &'param Parameter {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -18,13 +44,14 @@ impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
                 visitor.visit_map(RecordDeserializer::new(keyword, parameter))
             }
             Parameter::Integer(val) => visitor.visit_i64(*val),
-            Parameter::Real(val) => visitor.visit_f64(*val),
-            Parameter::String(val) => visitor.visit_str(val),
+            Parameter::Real(val) | Parameter::RawReal { value: val, .. } => visitor.visit_f64(*val),
+            Parameter::String(val) => visitor.visit_borrowed_str(val),
+            Parameter::Binary { bytes, .. } => visitor.visit_borrowed_bytes(bytes),
             Parameter::List(params) => visitor.visit_seq(SeqDeserializer::new(params)),
             Parameter::Ref(name) => visitor.visit_enum(name),
             Parameter::NotProvided | Parameter::Omitted => visitor.visit_none(),
             Parameter::Enumeration(variant) => {
-                visitor.visit_enum(variant.to_pascal_case().into_deserializer())
+                visitor.visit_enum(to_pascal_case(variant).into_deserializer())
             }
         }
     }
@@ -39,13 +66,34 @@ impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
                 "TRUE" => visitor.visit_bool(true),
                 "F" => visitor.visit_bool(false),
                 "FALSE" => visitor.visit_bool(false),
-                _ => visitor.visit_enum(variant.to_pascal_case().into_deserializer()),
+                _ => visitor.visit_enum(to_pascal_case(variant).into_deserializer()),
+            }
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if let Parameter::String(s) = self {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => visitor.visit_char(c),
+                _ => Err(de::Error::invalid_value(
+                    de::Unexpected::Str(s),
+                    &"a string of single character",
+                )),
             }
         } else {
             self.deserialize_any(visitor)
         }
     }
 
+    /// `$` and `*` become `None`, and others are `Some`
+    ///
+    /// An empty aggregate `()` is not absent, i.e. it is `Some(vec![])` for `Option<Vec<T>>`.
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
         V: de::Visitor<'de>,
@@ -57,9 +105,41 @@ impl<'de, 'param> de::Deserializer<'de> for &'param Parameter {
         }
     }
 
+    /// `*` becomes a unit struct, e.g. [crate::primitive::Derived], while `$` is rejected
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if matches!(self, Parameter::Omitted) {
+            visitor.visit_unit()
+        } else {
+            self.deserialize_any(visitor)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self {
+            // Literal is visited only by `primitive::Real`
+            Parameter::RawReal { raw, .. } if name == crate::primitive::REAL_LITERAL => visitor
+                .visit_newtype_struct(de::value::BorrowedStrDeserializer::<Self::Error>::new(raw)),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
     forward_to_deserialize_any! {
-        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
-        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 str string
+        bytes byte_buf unit seq tuple
         struct tuple_struct map enum identifier ignored_any
     }
 }
@@ -79,7 +159,7 @@ impl<'p> SeqDeserializer<'p> {
     }
 }
 
-impl<'de, 'p> de::Deserializer<'de> for SeqDeserializer<'p> {
+impl<'de, 'p: 'de> de::Deserializer<'de> for SeqDeserializer<'p> {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -96,7 +176,7 @@ impl<'de, 'p> de::Deserializer<'de> for SeqDeserializer<'p> {
     }
 }
 
-impl<'de, 'p> de::SeqAccess<'de> for SeqDeserializer<'p> {
+impl<'de, 'p: 'de> de::SeqAccess<'de> for SeqDeserializer<'p> {
     type Error = crate::error::Error;
 
     fn size_hint(&self) -> Option<usize> {
@@ -108,7 +188,9 @@ impl<'de, 'p> de::SeqAccess<'de> for SeqDeserializer<'p> {
         T: de::DeserializeSeed<'de>,
     {
         if self.cursor < self.parameters.len() {
-            let value = seed.deserialize(&self.parameters[self.cursor])?;
+            let value = seed
+                .deserialize(&self.parameters[self.cursor])
+                .map_err(|e| e.at_index(self.cursor))?;
             self.cursor += 1;
             Ok(Some(value))
         } else {
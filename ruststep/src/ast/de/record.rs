@@ -4,7 +4,7 @@ use serde::{
     forward_to_deserialize_any,
 };
 
-impl<'de, 'record> de::Deserializer<'de> for &'record Record {
+impl<'de, 'record: 'de> de::Deserializer<'de> for &'record Record {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -45,7 +45,7 @@ pub struct RecordDeserializer<'record> {
     value: &'record Parameter,
 }
 
-impl<'de, 'record> de::Deserializer<'de> for RecordDeserializer<'record> {
+impl<'de, 'record: 'de> de::Deserializer<'de> for RecordDeserializer<'record> {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -72,7 +72,7 @@ impl<'record> RecordDeserializer<'record> {
 }
 
 // Entry point of `visit_map`
-impl<'de, 'record> de::MapAccess<'de> for RecordDeserializer<'record> {
+impl<'de, 'record: 'de> de::MapAccess<'de> for RecordDeserializer<'record> {
     type Error = crate::error::Error;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
@@ -115,7 +115,7 @@ impl<'a> RecordStructDeserializer<'a> {
     }
 }
 
-impl<'de, 'a> de::Deserializer<'de> for RecordStructDeserializer<'a> {
+impl<'de, 'a: 'de> de::Deserializer<'de> for RecordStructDeserializer<'a> {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -132,14 +132,15 @@ impl<'de, 'a> de::Deserializer<'de> for RecordStructDeserializer<'a> {
     }
 }
 
-impl<'de, 'a> de::MapAccess<'de> for RecordStructDeserializer<'a> {
+impl<'de, 'a: 'de> de::MapAccess<'de> for RecordStructDeserializer<'a> {
     type Error = crate::error::Error;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
     where
         K: de::DeserializeSeed<'de>,
     {
-        if self.cursor < self.fields.len() {
+        // Trailing fields without parameter are left for `#[serde(default)]`
+        if self.cursor < self.fields.len() && self.cursor < self.parameters.len() {
             let key: de::value::StrDeserializer<Self::Error> =
                 self.fields[self.cursor].into_deserializer();
             let key: K::Value = seed.deserialize(key)?;
@@ -154,7 +155,9 @@ impl<'de, 'a> de::MapAccess<'de> for RecordStructDeserializer<'a> {
         V: de::DeserializeSeed<'de>,
     {
         assert!(self.cursor < self.parameters.len());
-        let value: V::Value = seed.deserialize(&self.parameters[self.cursor])?;
+        let value: V::Value = seed
+            .deserialize(&self.parameters[self.cursor])
+            .map_err(|e| e.at_index(self.cursor))?;
         self.cursor += 1;
         Ok(value)
     }
@@ -4,7 +4,7 @@ use serde::{
     forward_to_deserialize_any,
 };
 
-impl<'de, 'record> de::Deserializer<'de> for &'record SubSuperRecord {
+impl<'de, 'record: 'de> de::Deserializer<'de> for &'record SubSuperRecord {
     type Error = crate::error::Error;
 
     fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
@@ -37,7 +37,7 @@ impl<'record> SubSuperRecordDeserializer<'record> {
 }
 
 // Entry point of `visit_map`
-impl<'de, 'record> de::MapAccess<'de> for SubSuperRecordDeserializer<'record> {
+impl<'de, 'record: 'de> de::MapAccess<'de> for SubSuperRecordDeserializer<'record> {
     type Error = crate::error::Error;
 
     fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
@@ -0,0 +1,193 @@
+use crate::{ast::*, error::*};
+use alloc::{boxed::Box, collections::BTreeMap, format, rc::Rc, vec::Vec};
+use core::cell::RefCell;
+use serde::{de, forward_to_deserialize_any};
+
+/// Deserializer resolving references `#N` by records in a [DataSection]
+///
+/// If `resolve` is true, each entity reference in the parameter is replaced by
+/// the record of the referenced instance as a typed parameter, recursively,
+/// and fields with `#[holder(use_place_holder)]` become [crate::tables::PlaceHolder::Owned].
+/// Otherwise the parameter is deserialized as is, and references are kept as
+/// [crate::tables::PlaceHolder::Ref].
+///
+/// ```
+/// use ruststep::ast::{de::DeserializerWithTable, *};
+/// use std::str::FromStr;
+///
+/// let section = DataSection::from_str("DATA; #1 = A(1.0); #2 = B(#1); ENDSEC;").unwrap();
+/// let de = DeserializerWithTable::new(&Parameter::Ref(Name::Entity(InstanceId(2))), &section, true).unwrap();
+/// assert_eq!(de.parameter().to_string(), "B((A((1.))))");
+/// ```
+///
+/// Use [DeserializerWithTable::with_resolver] with a [TableResolver] shared among parameters
+/// to deserialize many parameters of the same section.
+///
+/// Error
+/// ------
+/// - [Error::UnknownEntity] if a referenced instance does not exist in the section
+/// - [Error::DeserializeFailed] for a cyclic reference, which cannot be resolved in place
+///
+/// Complex entity instances and references to values and constants are kept as they are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeserializerWithTable {
+    parameter: Parameter,
+}
+
+impl DeserializerWithTable {
+    pub fn new(parameter: &Parameter, section: &DataSection, resolve: bool) -> Result<Self> {
+        if !resolve {
+            return Ok(DeserializerWithTable {
+                parameter: parameter.clone(),
+            });
+        }
+        Self::with_resolver(parameter, &TableResolver::new(section))
+    }
+
+    /// Resolve references in `parameter` by `resolver`
+    pub fn with_resolver(parameter: &Parameter, resolver: &TableResolver) -> Result<Self> {
+        Ok(DeserializerWithTable {
+            parameter: resolver.resolve(parameter)?,
+        })
+    }
+
+    /// Parameter to be deserialized, whose references are resolved if requested
+    pub fn parameter(&self) -> &Parameter {
+        &self.parameter
+    }
+}
+
+/// Index of records in a [DataSection] for [DeserializerWithTable]
+///
+/// The resolved parameter of each instance is cached,
+/// i.e. an instance referred many times, e.g. a point shared by edges, is resolved only once.
+///
+/// ```
+/// use ruststep::ast::{de::*, *};
+/// use std::str::FromStr;
+///
+/// let section = DataSection::from_str("DATA; #1 = A(1.0); #2 = B(#1, #1); ENDSEC;").unwrap();
+/// let resolver = TableResolver::new(&section);
+/// for id in [InstanceId(1), InstanceId(2)] {
+///     let de = DeserializerWithTable::with_resolver(&Parameter::Ref(Name::Entity(id)), &resolver).unwrap();
+///     println!("{}", de.parameter());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TableResolver<'section> {
+    /// `None` for complex instances
+    records: BTreeMap<InstanceId, Option<&'section Record>>,
+    resolved: RefCell<BTreeMap<InstanceId, Rc<Parameter>>>,
+}
+
+impl<'section> TableResolver<'section> {
+    pub fn new(section: &'section DataSection) -> Self {
+        let records = section
+            .entities
+            .iter()
+            .map(|instance| match instance {
+                EntityInstance::Simple { id, record } => (*id, Some(record)),
+                EntityInstance::Complex { id, .. } => (*id, None),
+            })
+            .collect();
+        TableResolver {
+            records,
+            resolved: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Replace references in `parameter` by typed parameters of the referenced records, recursively
+    pub fn resolve(&self, parameter: &Parameter) -> Result<Parameter> {
+        self.resolve_with(parameter, &mut Vec::new())
+    }
+
+    /// `visiting` is the stack of instances being resolved for detecting cyclic references
+    fn resolve_with(
+        &self,
+        parameter: &Parameter,
+        visiting: &mut Vec<InstanceId>,
+    ) -> Result<Parameter> {
+        Ok(match parameter {
+            Parameter::Ref(Name::Entity(id)) => {
+                if let Some(resolved) = self.resolved.borrow().get(id) {
+                    return Ok(Parameter::clone(resolved));
+                }
+                let record = match self.records.get(id) {
+                    Some(Some(record)) => record,
+                    Some(None) => return Ok(parameter.clone()),
+                    None => return Err(Error::UnknownEntity(*id)),
+                };
+                if visiting.contains(id) {
+                    return Err(Error::DeserializeFailed(format!(
+                        "Cyclic reference to {} cannot be resolved",
+                        id
+                    )));
+                }
+                visiting.push(*id);
+                let resolved = self.resolve_with(&record.parameter, visiting)?;
+                visiting.pop();
+                let resolved = Rc::new(Parameter::Typed {
+                    keyword: record.name.clone(),
+                    parameter: Box::new(resolved),
+                });
+                self.resolved.borrow_mut().insert(*id, resolved.clone());
+                Parameter::clone(&resolved)
+            }
+            Parameter::Typed { keyword, parameter } => Parameter::Typed {
+                keyword: keyword.clone(),
+                parameter: Box::new(self.resolve_with(parameter, visiting)?),
+            },
+            Parameter::List(list) => Parameter::List(
+                list.iter()
+                    .map(|p| self.resolve_with(p, visiting))
+                    .collect::<Result<_>>()?,
+            ),
+            _ => parameter.clone(),
+        })
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &'de DeserializerWithTable {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.parameter.deserialize_any(visitor)
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.parameter.deserialize_bool(visitor)
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.parameter.deserialize_char(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.parameter.deserialize_option(visitor)
+    }
+
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: de::Visitor<'de>,
+    {
+        self.parameter.deserialize_unit_struct(name, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 str string
+        bytes byte_buf unit newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
@@ -34,24 +34,44 @@
 //! └───────────────────┘
 //! ```
 
+#[cfg(feature = "serde")]
 pub mod de;
+#[cfg(feature = "serde")]
 pub mod ser;
+mod value;
 
+pub use value::StepValue;
+
+#[cfg(feature = "alloc")]
 use crate::parser;
-use std::str::FromStr;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
 
 /// AST portion
+#[cfg(feature = "alloc")]
 pub trait AST: FromStr<Err = crate::error::Error> {
     fn parse(input: &str) -> parser::combinator::ParseResult<Self>;
 }
 
+#[cfg(not(feature = "alloc"))]
+macro_rules! derive_ast_from_str {
+    ($ast:ty, $parse:path) => {};
+}
+
+#[cfg(feature = "alloc")]
 macro_rules! derive_ast_from_str {
     ($ast:ty, $parse:path) => {
-        impl std::str::FromStr for $ast {
+        impl core::str::FromStr for $ast {
             type Err = $crate::error::Error;
             fn from_str(input: &str) -> $crate::error::Result<Self> {
                 use nom::Finish;
                 let input = input.trim();
+                parser::ParseLimits::default().check(input)?;
                 let (residual, record) = AST::parse(input)
                     .finish()
                     .map_err(|err| $crate::error::TokenizeFailed::new(input, err))?;
@@ -76,7 +96,7 @@ macro_rules! derive_ast_from_str {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Name {
     /// Like `#11`, corresponds to [parser::token::entity_instance_name]
-    Entity(u64),
+    Entity(InstanceId),
     /// Like `@11`, corresponds to [parser::token::value_instance_name]
     Value(u64),
     /// Like `#CONST_ENTITY`, corresponds to [parser::token::constant_entity_name]
@@ -86,6 +106,62 @@ pub enum Name {
 }
 derive_ast_from_str!(Name, parser::token::rhs_occurrence_name);
 
+impl core::fmt::Display for Name {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Name::Entity(id) => write!(f, "{}", id),
+            Name::Value(id) => write!(f, "@{}", id),
+            Name::ConstantEntity(name) => write!(f, "#{}", name),
+            Name::ConstantValue(name) => write!(f, "@{}", name),
+        }
+    }
+}
+
+/// Identifier of an entity instance, e.g. `#11`
+///
+/// ```
+/// use ruststep::ast::InstanceId;
+/// use std::str::FromStr;
+///
+/// let id = InstanceId::from_str("#11").unwrap();
+/// assert_eq!(id, InstanceId::from(11));
+/// assert_eq!(id.to_string(), "#11");
+/// assert_eq!(u64::from(id), 11);
+///
+/// assert!(InstanceId::from_str("11").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct InstanceId(pub u64);
+
+impl From<u64> for InstanceId {
+    fn from(id: u64) -> Self {
+        InstanceId(id)
+    }
+}
+
+impl From<InstanceId> for u64 {
+    fn from(id: InstanceId) -> Self {
+        id.0
+    }
+}
+
+impl core::fmt::Display for InstanceId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+impl core::str::FromStr for InstanceId {
+    type Err = crate::error::Error;
+    fn from_str(input: &str) -> crate::error::Result<Self> {
+        input
+            .strip_prefix('#')
+            .and_then(|id| id.parse().ok())
+            .map(InstanceId)
+            .ok_or_else(|| crate::error::Error::InvalidInstanceId(input.to_string()))
+    }
+}
+
 /// A struct typed in EXPRESS schema, e.g. `A(1.0, 2.0)`
 ///
 /// FromStr
@@ -356,7 +432,7 @@ derive_ast_from_str!(SubSuperRecord, parser::exchange::subsuper_record);
 
 impl IntoIterator for SubSuperRecord {
     type Item = Record;
-    type IntoIter = std::vec::IntoIter<Self::Item>;
+    type IntoIter = alloc::vec::IntoIter<Self::Item>;
     fn into_iter(self) -> Self::IntoIter {
         self.0.into_iter()
     }
@@ -364,7 +440,7 @@ impl IntoIterator for SubSuperRecord {
 
 impl<'a> IntoIterator for &'a SubSuperRecord {
     type Item = &'a Record;
-    type IntoIter = std::slice::Iter<'a, Record>;
+    type IntoIter = core::slice::Iter<'a, Record>;
     fn into_iter(self) -> Self::IntoIter {
         self.0.iter()
     }
@@ -407,6 +483,40 @@ pub struct DataSection {
 }
 derive_ast_from_str!(DataSection, parser::exchange::data_section);
 
+impl DataSection {
+    /// Name of the section, e.g. `'S1'` in `DATA('S1', ('SCHEMA_A'));`
+    ///
+    /// ```
+    /// use ruststep::ast::DataSection;
+    /// use std::str::FromStr;
+    ///
+    /// let section = DataSection::from_str("DATA('S1', ('SCHEMA_A')); #1 = A(); ENDSEC;").unwrap();
+    /// assert_eq!(section.name(), Some("S1"));
+    /// assert_eq!(section.schema(), Some("SCHEMA_A"));
+    ///
+    /// let section = DataSection::from_str("DATA; #1 = A(); ENDSEC;").unwrap();
+    /// assert_eq!(section.name(), None);
+    /// assert_eq!(section.schema(), None);
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        match self.meta.first()? {
+            Parameter::String(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Schema governing the instances in this section, e.g. `'SCHEMA_A'` in `DATA('S1', ('SCHEMA_A'));`
+    pub fn schema(&self) -> Option<&str> {
+        match self.meta.get(1)? {
+            Parameter::List(schemas) => match schemas.first()? {
+                Parameter::String(schema) => Some(schema),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
 /// Primitive value type in STEP data
 ///
 /// Inline struct or list can be nested, i.e. `Parameter` can be a tree.
@@ -458,7 +568,7 @@ derive_ast_from_str!(DataSection, parser::exchange::data_section);
 /// | String      | string           |
 /// | List        | seq              |
 /// | NotProvided | option (always none)|
-/// | Omitted     | option (always none)|
+/// | Omitted     | option (always none), or unit_struct, e.g. [crate::primitive::Derived]|
 /// | Enumeration | unit_variant (through [serde::de::value::StringDeserializer])|
 /// | Typed       | map (through [de::RecordDeserializer])|
 /// | Ref         | newtype_variant  |
@@ -589,6 +699,13 @@ pub enum Parameter {
     #[from]
     Real(f64),
 
+    /// Real number with its literal in the source, e.g. `1.50E+01`
+    ///
+    /// Parsed instead of [Parameter::Real] with [parser::ParseOptions::preserve_reals],
+    /// such that writers can reproduce the literal by [Parameter::real_literal].
+    /// It is deserialized in the same way as [Parameter::Real].
+    RawReal { value: f64, raw: String },
+
     /// string literal
     ///
     /// FromStr
@@ -600,6 +717,21 @@ pub enum Parameter {
     /// let p = Parameter::from_str("'EXAMPLE STRING'").unwrap();
     /// assert_eq!(p, Parameter::String("EXAMPLE STRING".to_string()));
     /// ```
+    ///
+    /// Deserialize
+    /// -----------
+    /// A string of single character can be deserialized into `char`:
+    ///
+    /// ```
+    /// use ruststep::ast::*;
+    /// use serde::Deserialize;
+    ///
+    /// let p = Parameter::string("A");
+    /// assert_eq!(char::deserialize(&p).unwrap(), 'A');
+    ///
+    /// let p = Parameter::string("AB");
+    /// assert!(char::deserialize(&p).is_err());
+    /// ```
     #[from]
     String(String),
 
@@ -633,6 +765,29 @@ pub enum Parameter {
     ///
     Enumeration(String),
 
+    /// Binary value, like `"0FF"` for bits `11111111`
+    ///
+    /// The bits are stored right-aligned in `bytes`,
+    /// and `unused_bits` leading bits of the first byte are not a part of the value.
+    ///
+    /// FromStr
+    /// --------
+    /// ```
+    /// # use std::str::FromStr;
+    /// # use ruststep::ast::Parameter;
+    /// let p = Parameter::from_str("\"0FF\"").unwrap();
+    /// assert_eq!(p, Parameter::Binary { bytes: vec![0xFF], unused_bits: 0 });
+    ///
+    /// // 5 bits `10101`
+    /// let p = Parameter::from_str("\"315\"").unwrap();
+    /// assert_eq!(p, Parameter::Binary { bytes: vec![0b10101], unused_bits: 3 });
+    /// ```
+    ///
+    /// Deserialize
+    /// ------------
+    /// `bytes` are passed to [serde::de::Visitor::visit_bytes], and `unused_bits` are dropped.
+    Binary { bytes: Vec<u8>, unused_bits: u8 },
+
     /// List of parameters. This can be non-uniform.
     ///
     /// FromStr
@@ -738,15 +893,322 @@ impl Parameter {
     pub fn string(s: &str) -> Self {
         Parameter::String(s.to_string())
     }
+
+    /// Elements if this is a list parameter
+    ///
+    /// ```
+    /// use ruststep::ast::*;
+    /// use std::str::FromStr;
+    ///
+    /// let p = Parameter::from_str("(1.0, 2, 'a', #3)").unwrap();
+    /// assert_eq!(p.len(), 4);
+    /// assert_eq!(p.get(0).and_then(Parameter::as_f64), Some(1.0));
+    /// assert_eq!(p.get(1).and_then(Parameter::as_f64), Some(2.0));
+    /// assert_eq!(p.get(2).and_then(Parameter::as_str), Some("a"));
+    /// assert_eq!(p.get(3).and_then(Parameter::as_ref_id), Some(InstanceId(3)));
+    /// assert_eq!(p.get(4), None);
+    ///
+    /// let p = Parameter::from_str("1.0").unwrap();
+    /// assert_eq!(p.as_list(), None);
+    /// assert_eq!(p.len(), 0);
+    /// assert!(p.is_empty());
+    /// ```
+    pub fn as_list(&self) -> Option<&[Parameter]> {
+        match self {
+            Parameter::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Number of elements of a list parameter
+    ///
+    /// Other parameters have no elements, i.e. `0`. Use [Parameter::as_list] to distinguish them from `()`.
+    pub fn len(&self) -> usize {
+        self.as_list().map_or(0, |list| list.len())
+    }
+
+    /// True if [Parameter::len] is `0`, i.e. an empty list parameter or not a list
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Element at `index` if this is a list parameter
+    pub fn get(&self, index: usize) -> Option<&Parameter> {
+        self.as_list()?.get(index)
+    }
+
+    /// Value of real or integer parameter
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Parameter::Real(x) | Parameter::RawReal { value: x, .. } => Some(*x),
+            Parameter::Integer(i) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    /// Literal of real parameter in ISO 10303-21 syntax
+    ///
+    /// The literal in the source is kept for [Parameter::RawReal],
+    /// and [Parameter::Real] is formatted as the shortest one.
+    ///
+    /// ```
+    /// use ruststep::ast::Parameter;
+    ///
+    /// let raw = Parameter::RawReal { value: 15.0, raw: "1.50E+01".to_string() };
+    /// assert_eq!(raw.real_literal().as_deref(), Some("1.50E+01"));
+    /// assert_eq!(Parameter::Real(15.0).real_literal().as_deref(), Some("15."));
+    /// assert_eq!(Parameter::Real(-2.5e-10).real_literal().as_deref(), Some("-2.5E-10"));
+    /// assert_eq!(Parameter::Integer(1).real_literal(), None);
+    /// ```
+    pub fn real_literal(&self) -> Option<String> {
+        match self {
+            Parameter::Real(x) => Some(format_real(*x)),
+            Parameter::RawReal { raw, .. } => Some(raw.clone()),
+            _ => None,
+        }
+    }
+
+    /// Value of string parameter
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Parameter::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Instance id if this is an entity instance reference, e.g. `#3`
+    pub fn as_ref_id(&self) -> Option<InstanceId> {
+        match self {
+            Parameter::Ref(Name::Entity(id)) => Some(*id),
+            _ => None,
+        }
+    }
+}
+
+/// Parameter in ISO 10303-21 syntax, e.g. `A((1.,#2))` or `'it''s'`
+///
+/// ```
+/// use ruststep::ast::*;
+///
+/// let p = Parameter::List(vec![
+///     Parameter::Real(1.0),
+///     Parameter::Ref(Name::Entity(InstanceId(2))),
+///     Parameter::String("it's".to_string()),
+///     Parameter::Enumeration("T".to_string()),
+///     Parameter::NotProvided,
+/// ]);
+/// assert_eq!(p.to_string(), "(1.,#2,'it''s',.T.,$)");
+/// assert_eq!(Parameter::String("café".to_string()).to_string(), r"'caf\X2\00E9\X0\'");
+/// ```
+impl core::fmt::Display for Parameter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Parameter::Typed { keyword, parameter } => write!(f, "{}({})", keyword, parameter),
+            Parameter::Integer(i) => write!(f, "{}", i),
+            Parameter::Real(x) => write!(f, "{}", format_real(*x)),
+            Parameter::RawReal { raw, .. } => write!(f, "{}", raw),
+            Parameter::String(s) => write!(f, "'{}'", encode_string(s)),
+            Parameter::Enumeration(e) => write!(f, ".{}.", e),
+            Parameter::Binary { bytes, unused_bits } => {
+                write!(f, "\"{}\"", encode_binary(bytes, *unused_bits))
+            }
+            Parameter::List(list) => write!(f, "({})", DisplayList(list)),
+            Parameter::Ref(name) => write!(f, "{}", name),
+            Parameter::NotProvided => write!(f, "$"),
+            Parameter::Omitted => write!(f, "*"),
+        }
+    }
+}
+
+/// Record in ISO 10303-21 syntax, e.g. `A(1.,#2)`
+impl core::fmt::Display for Record {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.parameter {
+            Parameter::List(list) => write!(f, "{}({})", self.name, DisplayList(list)),
+            parameter => write!(f, "{}({})", self.name, parameter),
+        }
+    }
+}
+
+/// Entity instance in ISO 10303-21 syntax, e.g. `#1 = A(1.,#2);` or `#3 = (A(1.)B(#2));`
+impl core::fmt::Display for EntityInstance {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EntityInstance::Simple { id, record } => write!(f, "{} = {};", id, record),
+            EntityInstance::Complex { id, subsuper } => {
+                write!(f, "{} = (", id)?;
+                for record in &subsuper.0 {
+                    write!(f, "{}", record)?;
+                }
+                write!(f, ");")
+            }
+        }
+    }
 }
 
-impl std::iter::FromIterator<Parameter> for Parameter {
+/// Data section in ISO 10303-21 syntax with an instance per line
+///
+/// ```
+/// use ruststep::ast::*;
+/// use std::str::FromStr;
+///
+/// let input = "DATA('S1',('SCHEMA_A'));\n#1 = A(1.,#2);\n#2 = B('b');\nENDSEC;\n";
+/// let section = DataSection::from_str(input).unwrap();
+/// assert_eq!(section.to_string(), input);
+/// ```
+impl core::fmt::Display for DataSection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.meta.is_empty() {
+            writeln!(f, "DATA;")?;
+        } else {
+            writeln!(f, "DATA({});", DisplayList(&self.meta))?;
+        }
+        for instance in &self.entities {
+            writeln!(f, "{}", instance)?;
+        }
+        writeln!(f, "ENDSEC;")
+    }
+}
+
+/// Encode a string literal without apostrophes, the reverse of [parser::token::string]
+///
+/// `'` and `\` are escaped as `''` and `\\`, and characters out of printable ASCII
+/// are encoded by `\X2\ ... \X0\`, or `\X4\ ... \X0\` if out of the basic multilingual plane.
+fn encode_string(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => encoded.push_str("''"),
+            '\\' => encoded.push_str("\\\\"),
+            ' '..='~' => encoded.push(c),
+            _ => {
+                let wide = c as u32 > 0xFFFF;
+                encoded.push_str(if wide { "\\X4\\" } else { "\\X2\\" });
+                let digits = if wide { 8 } else { 4 };
+                let mut c = Some(c);
+                while let Some(ch) = c {
+                    encoded.push_str(&alloc::format!("{:01$X}", ch as u32, digits));
+                    c = chars.next_if(|next| {
+                        !matches!(next, ' '..='~') && (*next as u32 > 0xFFFF) == wide
+                    });
+                }
+                encoded.push_str("\\X0\\");
+            }
+        }
+    }
+    encoded
+}
+
+/// Encode a binary literal without quotes, the reverse of [parser::token::binary]
+///
+/// The leading digit is the number of unused bits in the first hex digit, e.g. `315` for `10101`.
+fn encode_binary(bytes: &[u8], unused_bits: u8) -> String {
+    let bits = (bytes.len() * 8).saturating_sub(unused_bits as usize);
+    let digits = bits.div_ceil(4);
+    let hex: String = bytes.iter().map(|b| alloc::format!("{:02X}", b)).collect();
+    alloc::format!("{}{}", digits * 4 - bits, &hex[hex.len() - digits..])
+}
+
+/// Comma-separated parameters without parentheses
+struct DisplayList<'a>(&'a [Parameter]);
+
+impl core::fmt::Display for DisplayList<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, parameter) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}", parameter)?;
+        }
+        Ok(())
+    }
+}
+
+/// Shortest ISO 10303-21 literal of `x`, which always has `.` and uses `E` for exponent
+fn format_real(x: f64) -> String {
+    // `{:?}` gives the shortest representation round-tripping, e.g. `15.0` or `-2.5e-10`
+    let repr = alloc::format!("{:?}", x);
+    let (mantissa, exponent) = match repr.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (repr.as_str(), None),
+    };
+    let mut literal = match mantissa.strip_suffix(".0") {
+        Some(integral) => alloc::format!("{}.", integral),
+        None if mantissa.contains('.') => mantissa.to_string(),
+        None => alloc::format!("{}.", mantissa),
+    };
+    if let Some(exponent) = exponent {
+        literal.push('E');
+        literal.push_str(exponent);
+    }
+    literal
+}
+
+#[cfg(feature = "serde")]
+macro_rules! impl_try_from_parameter {
+    ($($ty:ty),*) => {
+        $(
+        /// Same conversion as [serde::Deserialize], e.g. integer parameter into `f64`
+        impl TryFrom<&Parameter> for $ty {
+            type Error = crate::error::Error;
+            fn try_from(p: &Parameter) -> crate::error::Result<Self> {
+                serde::Deserialize::deserialize(p)
+            }
+        }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+impl_try_from_parameter!(
+    f64,
+    f32,
+    i64,
+    i32,
+    u64,
+    u32,
+    bool,
+    char,
+    String,
+    crate::primitive::Logical,
+    crate::primitive::Number
+);
+
+/// Elements of list parameter, e.g. `(1, 2.0, 'a')`
+///
+/// ```
+/// use ruststep::ast::Parameter;
+///
+/// let p = Parameter::List(vec![Parameter::Integer(1), Parameter::Real(2.0)]);
+/// let list = Vec::<Parameter>::try_from(&p).unwrap();
+/// assert_eq!(i64::try_from(&list[0]).unwrap(), 1);
+/// // integer is also accepted as real
+/// assert_eq!(f64::try_from(&list[0]).unwrap(), 1.0);
+/// assert!(i64::try_from(&list[1]).is_err());
+/// assert!(String::try_from(&list[1]).is_err());
+/// assert!(Vec::<Parameter>::try_from(&list[1]).is_err());
+/// ```
+impl TryFrom<&Parameter> for Vec<Parameter> {
+    type Error = crate::error::Error;
+    fn try_from(p: &Parameter) -> crate::error::Result<Self> {
+        match p {
+            Parameter::List(list) => Ok(list.clone()),
+            _ => Err(crate::error::Error::DeserializeFailed(alloc::format!(
+                "expected list parameter, found {:?}",
+                p
+            ))),
+        }
+    }
+}
+
+impl core::iter::FromIterator<Parameter> for Parameter {
     fn from_iter<Iter: IntoIterator<Item = Parameter>>(iter: Iter) -> Self {
         Parameter::List(iter.into_iter().collect())
     }
 }
 
-impl<'a> std::iter::FromIterator<&'a Parameter> for Parameter {
+impl<'a> core::iter::FromIterator<&'a Parameter> for Parameter {
     fn from_iter<Iter: IntoIterator<Item = &'a Parameter>>(iter: Iter) -> Self {
         iter.into_iter().cloned().collect()
     }
@@ -770,11 +1232,72 @@ pub struct Exchange {
 }
 derive_ast_from_str!(Exchange, parser::exchange::exchange_file);
 
+impl Exchange {
+    /// Data sections for the schema
+    ///
+    /// Sections without schema annotation, e.g. `DATA;`, are governed by all schemas in `FILE_SCHEMA`,
+    /// and are always included. Schema names are compared case-insensitively.
+    pub fn data_sections_for_schema<'a>(
+        &'a self,
+        schema: &'a str,
+    ) -> impl Iterator<Item = &'a DataSection> + 'a {
+        self.data.iter().filter(move |section| {
+            section
+                .schema()
+                .map_or(true, |s| s.eq_ignore_ascii_case(schema))
+        })
+    }
+
+    /// Each instance in data sections with the schema of its section
+    pub fn entity_instances(&self) -> impl Iterator<Item = (Option<&str>, &EntityInstance)> {
+        self.data.iter().flat_map(|section| {
+            let schema = section.schema();
+            section
+                .entities
+                .iter()
+                .map(move |instance| (schema, instance))
+        })
+    }
+
+    /// Interpret `HEADER` section records, see [crate::header::Header::from_records]
+    ///
+    /// ```
+    /// use ruststep::ast::Exchange;
+    /// use std::str::FromStr;
+    ///
+    /// let exchange = Exchange::from_str(r#"
+    /// ISO-10303-21;
+    /// HEADER;
+    ///   FILE_DESCRIPTION(('A part'), '2;1');
+    ///   FILE_NAME('part.step', '2021-01-01T00:00:00', ('alice'), ('org'), 'pre', 'sys', '');
+    ///   FILE_SCHEMA(('CONFIG_CONTROL_DESIGN'));
+    /// ENDSEC;
+    /// DATA;
+    /// ENDSEC;
+    /// END-ISO-10303-21;
+    /// "#.trim()).unwrap();
+    ///
+    /// let header = exchange.typed_header().unwrap();
+    /// assert_eq!(header.file_schema.schema, ["CONFIG_CONTROL_DESIGN"]);
+    /// assert_eq!(header.file_name.author, ["alice"]);
+    /// ```
+    #[cfg(all(feature = "std", feature = "serde"))]
+    pub fn typed_header(&self) -> crate::error::Result<crate::header::Header> {
+        crate::header::Header::from_records(&self.header)
+    }
+}
+
 /// Each line of data section
 #[derive(Debug, Clone, PartialEq)]
 pub enum EntityInstance {
-    Simple { id: u64, record: Record },
-    Complex { id: u64, subsuper: SubSuperRecord },
+    Simple {
+        id: InstanceId,
+        record: Record,
+    },
+    Complex {
+        id: InstanceId,
+        subsuper: SubSuperRecord,
+    },
 }
 derive_ast_from_str!(EntityInstance, parser::exchange::entity_instance);
 
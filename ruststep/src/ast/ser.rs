@@ -1,24 +1,135 @@
 use crate::{ast::*, error::*};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::convert::TryFrom;
 use serde::ser;
-use std::convert::TryFrom;
 
 /// Serialize struct into STEP [Record]
 pub fn to_record(obj: &impl ser::Serialize) -> Result<Record> {
     let mut ser = RecordSerializer::default();
     obj.serialize(&mut ser)?;
-    assert!(ser.stack.is_empty()); // should panic because this must be bug, not a valid input
+    assert!(ser.stack.is_empty() && ser.lists.is_empty()); // should panic because this must be bug, not a valid input
     Ok(Record {
         name: ser.name,
         parameter: ser.parameters.iter().collect(),
     })
 }
 
+/// Round `value` into `digits` significant digits, e.g. `1.23456` into `1.23` for 3 digits
+///
+/// `digits` less than 1 is regarded as 1. Non-finite values are returned as is.
+pub fn round_significant(value: f64, digits: usize) -> f64 {
+    if !value.is_finite() {
+        return value;
+    }
+    format!("{:.*e}", digits.max(1) - 1, value)
+        .parse()
+        .unwrap_or(value)
+}
+
+/// Serialize `f64` field with `DIGITS` significant digits, used with `#[serde(serialize_with)]`
+///
+/// Real values are serialized with full precision by default.
+/// This can be used to trade precision for output size and reproducibility:
+///
+/// ```
+/// use ruststep::ast::{ser::to_record, Parameter};
+///
+/// #[derive(serde::Serialize)]
+/// struct CartesianPoint {
+///     #[serde(serialize_with = "ruststep::ast::ser::with_precision::<3, _>")]
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let record = to_record(&CartesianPoint { x: 1.23456, y: 1.23456 }).unwrap();
+/// assert_eq!(
+///     record.parameter,
+///     vec![Parameter::Real(1.23), Parameter::Real(1.23456)].into_iter().collect()
+/// );
+/// ```
+pub fn with_precision<const DIGITS: usize, S: ser::Serializer>(
+    value: &f64,
+    serializer: S,
+) -> core::result::Result<S::Ok, S::Error> {
+    serializer.serialize_f64(round_significant(*value, DIGITS))
+}
+
+/// `f64` serialized with `DIGITS` significant digits
+///
+/// Per-type counterpart of [with_precision], e.g. `Option<Precision<9>>` or `Vec<Precision<9>>`
+/// for attributes of a measure type.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Precision<const DIGITS: usize>(pub f64);
+
+impl<const DIGITS: usize> ser::Serialize for Precision<DIGITS> {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        with_precision::<DIGITS, S>(&self.0, serializer)
+    }
+}
+
+/// Serialized as a newtype variant `Entity`, `Value`, `ConstantEntity` or `ConstantValue`
+/// of an enum named `Name`, the reverse of the deserialization of [Parameter::Ref]
+///
+/// ```
+/// use ruststep::ast::{ser::to_record, *};
+///
+/// #[derive(serde::Serialize)]
+/// struct A {
+///     x: f64,
+///     r: Name,
+/// }
+///
+/// let record = to_record(&A { x: 1.0, r: Name::Entity(InstanceId(2)) }).unwrap();
+/// assert_eq!(record.to_string(), "A(1.,#2)");
+/// ```
+impl ser::Serialize for Name {
+    fn serialize<S: ser::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        match self {
+            Name::Entity(id) => serializer.serialize_newtype_variant(NAME, 0, "Entity", &id.0),
+            Name::Value(id) => serializer.serialize_newtype_variant(NAME, 1, "Value", id),
+            Name::ConstantEntity(name) => {
+                serializer.serialize_newtype_variant(NAME, 2, "ConstantEntity", name)
+            }
+            Name::ConstantValue(name) => {
+                serializer.serialize_newtype_variant(NAME, 3, "ConstantValue", name)
+            }
+        }
+    }
+}
+
+const NAME: &str = "Name";
+
 #[derive(Default, Debug)]
 struct RecordSerializer {
     name: String,
     parameters: Vec<Parameter>,
     // For supporting nested record e.g. `B(3.0, A((1.0, 2.0)))`
     stack: Vec<(String, Vec<Parameter>)>,
+    // Parameters of outer lists while serializing elements of a list e.g. `(1.0, 2.0)`
+    lists: Vec<Vec<Parameter>>,
+}
+
+impl RecordSerializer {
+    fn begin_list(&mut self) {
+        let outer = core::mem::take(&mut self.parameters);
+        self.lists.push(outer);
+    }
+
+    fn end_list(&mut self) {
+        let outer = self.lists.pop().expect("list must be started");
+        let elements = core::mem::replace(&mut self.parameters, outer);
+        self.parameters.push(Parameter::List(elements));
+    }
 }
 
 impl<'se> ser::Serializer for &'se mut RecordSerializer {
@@ -85,8 +196,12 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
         self.parameters.push(Parameter::String(v.to_string()));
         Ok(())
     }
-    fn serialize_bytes(self, _v: &[u8]) -> Result<()> {
-        unimplemented!("Bytes is not supported yet")
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.parameters.push(Parameter::Binary {
+            bytes: v.to_vec(),
+            unused_bits: 0,
+        });
+        Ok(())
     }
 
     fn serialize_none(self) -> Result<()> {
@@ -118,27 +233,54 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
         self.serialize_str(variant)
     }
 
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<()>
     where
         T: ?Sized + ser::Serialize,
     {
-        value.serialize(self)
+        if name != crate::primitive::REAL_LITERAL {
+            return value.serialize(self);
+        }
+        // Literal of `primitive::Real` is written as is
+        value.serialize(&mut *self)?;
+        let raw = match self.parameters.pop() {
+            Some(Parameter::String(raw)) => raw,
+            _ => return Err(ser::Error::custom("Real literal must be a string")),
+        };
+        let value = raw
+            .parse()
+            .map_err(|_| <Error as ser::Error>::custom(format!("Invalid real literal: {}", raw)))?;
+        self.parameters.push(Parameter::RawReal { value, raw });
+        Ok(())
     }
 
     fn serialize_newtype_variant<T>(
         self,
-        _name: &'static str,
+        name: &'static str,
         _variant_index: u32,
-        _variant: &'static str,
-        _value: &T,
+        variant: &'static str,
+        value: &T,
     ) -> Result<()>
     where
         T: ?Sized + ser::Serialize,
     {
-        unimplemented!("Newtype variant is not suuported yet.")
+        if name != NAME {
+            unimplemented!("Newtype variant is not suuported yet.")
+        }
+        // Reference serialized by `impl Serialize for Name`
+        value.serialize(&mut *self)?;
+        let name = match (variant, self.parameters.pop()) {
+            ("Entity", Some(Parameter::Integer(id))) => Name::Entity(InstanceId(id as u64)),
+            ("Value", Some(Parameter::Integer(id))) => Name::Value(id as u64),
+            ("ConstantEntity", Some(Parameter::String(name))) => Name::ConstantEntity(name),
+            ("ConstantValue", Some(Parameter::String(name))) => Name::ConstantValue(name),
+            _ => unreachable!("Invalid variant {} of Name", variant),
+        };
+        self.parameters.push(Parameter::Ref(name));
+        Ok(())
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+        self.begin_list();
         Ok(self)
     }
 
@@ -183,8 +325,8 @@ impl<'se> ser::Serializer for &'se mut RecordSerializer {
             // and start serializing `A((1.0, 2.0))`.
             // This stack will be popped in SerializeStruct::end()
             //
-            let current_name = std::mem::replace(&mut self.name, name.to_string());
-            let current_params = std::mem::take(&mut self.parameters);
+            let current_name = core::mem::replace(&mut self.name, name.to_string());
+            let current_params = core::mem::take(&mut self.parameters);
             self.stack.push((current_name, current_params));
         }
         Ok(self)
@@ -211,6 +353,7 @@ impl<'se> ser::SerializeSeq for &'se mut RecordSerializer {
         value.serialize(&mut **self)
     }
     fn end(self) -> Result<()> {
+        self.end_list();
         Ok(())
     }
 }
@@ -226,6 +369,7 @@ impl<'se> ser::SerializeTuple for &'se mut RecordSerializer {
     }
 
     fn end(self) -> Result<()> {
+        self.end_list();
         Ok(())
     }
 }
@@ -242,6 +386,7 @@ impl<'se> ser::SerializeTupleStruct for &'se mut RecordSerializer {
     }
 
     fn end(self) -> Result<()> {
+        self.end_list();
         Ok(())
     }
 }
@@ -299,8 +444,8 @@ impl<'se> ser::SerializeStruct for &'se mut RecordSerializer {
     fn end(self) -> Result<()> {
         if let Some((name, params)) = self.stack.pop() {
             // restore stacked state
-            let name = std::mem::replace(&mut self.name, name);
-            let params = std::mem::replace(&mut self.parameters, params);
+            let name = core::mem::replace(&mut self.name, name);
+            let params = core::mem::replace(&mut self.parameters, params);
             self.parameters.push(Parameter::Typed {
                 keyword: name,
                 parameter: Box::new(params.into_iter().collect()),
@@ -325,3 +470,61 @@ impl<'se> ser::SerializeStructVariant for &'se mut RecordSerializer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round() {
+        assert_eq!(round_significant(1.23456, 3), 1.23);
+        assert_eq!(round_significant(-0.000123456, 2), -0.00012);
+        assert_eq!(round_significant(123456.0, 2), 120000.0);
+        assert_eq!(round_significant(1.5, 0), 2.0);
+        assert!(round_significant(f64::NAN, 3).is_nan());
+        assert_eq!(round_significant(f64::INFINITY, 3), f64::INFINITY);
+    }
+
+    #[derive(serde::Serialize)]
+    struct A {
+        x: Precision<2>,
+        y: Option<Precision<2>>,
+    }
+
+    #[test]
+    fn precision() {
+        let record = to_record(&A {
+            x: Precision(0.123),
+            y: Some(Precision(9.87)),
+        })
+        .unwrap();
+        assert_eq!(record.name, "A");
+        assert_eq!(
+            record.parameter,
+            vec![Parameter::Real(0.12), Parameter::Real(9.9),]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[derive(serde::Serialize)]
+    struct B {
+        xs: Vec<Vec<i64>>,
+        a: A,
+        r: Option<Name>,
+    }
+
+    #[test]
+    fn nested() {
+        let record = to_record(&B {
+            xs: vec![vec![1, 2], vec![]],
+            a: A {
+                x: Precision(1.0),
+                y: None,
+            },
+            r: Some(Name::Entity(InstanceId(3))),
+        })
+        .unwrap();
+        assert_eq!(record.to_string(), "B(((1,2),()),A((1.,$)),#3)");
+    }
+}
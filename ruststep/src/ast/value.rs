@@ -0,0 +1,98 @@
+use super::*;
+
+/// Dynamic value tree of parameters, traversable without schema
+///
+/// Different from deserializing into the types generated from EXPRESS schema,
+/// any parameter is converted, e.g. for viewers or converters handling arbitrary STEP data.
+///
+/// ```
+/// use ruststep::ast::*;
+/// use std::str::FromStr;
+///
+/// let record = Record::from_str("A(1, 2.0, 'a', .B., #3, (4, $), C(5))").unwrap();
+/// assert_eq!(
+///     StepValue::from(&record),
+///     StepValue::Typed {
+///         keyword: "A".to_string(),
+///         value: Box::new(StepValue::List(vec![
+///             StepValue::Int(1),
+///             StepValue::Real(2.0),
+///             StepValue::Str("a".to_string()),
+///             StepValue::Enum("B".to_string()),
+///             StepValue::Ref(Name::Entity(InstanceId(3))),
+///             StepValue::List(vec![StepValue::Int(4), StepValue::Null]),
+///             StepValue::Typed {
+///                 keyword: "C".to_string(),
+///                 value: Box::new(StepValue::Int(5)),
+///             },
+///         ])),
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepValue {
+    Int(i64),
+    Real(f64),
+    Str(String),
+    /// Enumeration item without dots, e.g. `RED` for `.RED.`
+    Enum(String),
+    Ref(Name),
+    /// Binary, see [Parameter::Binary]
+    Binary {
+        bytes: Vec<u8>,
+        unused_bits: u8,
+    },
+    List(Vec<StepValue>),
+    /// Typed parameter, e.g. `LENGTH_MEASURE(1.0)`, or record with its parameters as [StepValue::List]
+    Typed {
+        keyword: String,
+        value: Box<StepValue>,
+    },
+    /// Not provided `$` or omitted `*` parameter
+    Null,
+}
+
+impl From<&Parameter> for StepValue {
+    fn from(parameter: &Parameter) -> Self {
+        match parameter {
+            Parameter::Typed { keyword, parameter } => StepValue::Typed {
+                keyword: keyword.clone(),
+                value: Box::new(parameter.as_ref().into()),
+            },
+            Parameter::Integer(i) => StepValue::Int(*i),
+            Parameter::Real(x) | Parameter::RawReal { value: x, .. } => StepValue::Real(*x),
+            Parameter::String(s) => StepValue::Str(s.clone()),
+            Parameter::Enumeration(e) => StepValue::Enum(e.clone()),
+            Parameter::Binary { bytes, unused_bits } => StepValue::Binary {
+                bytes: bytes.clone(),
+                unused_bits: *unused_bits,
+            },
+            Parameter::List(list) => StepValue::List(list.iter().map(StepValue::from).collect()),
+            Parameter::Ref(name) => StepValue::Ref(name.clone()),
+            Parameter::NotProvided | Parameter::Omitted => StepValue::Null,
+        }
+    }
+}
+
+impl From<&Record> for StepValue {
+    fn from(record: &Record) -> Self {
+        StepValue::Typed {
+            keyword: record.name.clone(),
+            value: Box::new((&record.parameter).into()),
+        }
+    }
+}
+
+impl StepValue {
+    /// Elements if this is a list
+    pub fn as_list(&self) -> Option<&[StepValue]> {
+        match self {
+            StepValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, StepValue::Null)
+    }
+}
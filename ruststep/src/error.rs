@@ -1,29 +1,206 @@
+use crate::ast::InstanceId;
+use alloc::{boxed::Box, string::String, vec::Vec};
+#[cfg(feature = "serde")]
+use alloc::{string::ToString, vec};
+use core::fmt;
+#[cfg(feature = "serde")]
 use serde::{de, ser};
-use std::fmt;
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[cfg(feature = "alloc")]
     #[error(transparent)]
     TokenizeFailed(#[from] TokenizeFailed),
 
+    #[cfg(feature = "std")]
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
     #[error("Extra input string remains behind: {0}")]
     ExtraInputRemaining(String),
 
     #[error("Error while deserialize STEP struct: {0}")]
     DeserializeFailed(String),
 
-    #[error("Lookup failed for #{0}")]
-    UnknownEntity(u64),
+    #[error("Lookup failed for {0}")]
+    UnknownEntity(InstanceId),
+
+    #[error("Entity ID {0} is duplicated")]
+    DuplicatedEntity(InstanceId),
+
+    #[error("No entity ID remains after {0}")]
+    InstanceIdExhausted(InstanceId),
+
+    #[error("Entity {id} refers {target} which does not exist")]
+    DanglingReference { id: InstanceId, target: InstanceId },
 
-    #[error("Entity ID #{0} is duplicated")]
-    DuplicatedEntity(u64),
+    #[error("Inserting holders of {0} is not supported by the table")]
+    InsertUnsupported(&'static str),
+
+    #[error("Invalid entity instance ID: {0}")]
+    InvalidInstanceId(String),
+
+    #[error("Header record {0} is missing")]
+    MissingHeaderRecord(&'static str),
+
+    #[error("Entity {id} of type {keyword} is not a member of {expected}")]
+    UnexpectedEntityType {
+        id: InstanceId,
+        keyword: String,
+        expected: &'static str,
+    },
+
+    #[error("Entity {id} violates domain rule {rule}")]
+    RuleViolation { id: InstanceId, rule: &'static str },
 
     #[error("Entity '{entity_name}' is not a member of the schema '{schema}'")]
     UnknownEntityName { entity_name: String, schema: String },
+
+    /// Partial records of a complex entity instance, sorted by their names,
+    /// which are not those of an entity and its supertypes nor their combination
+    #[error(
+        "Complex entity instance {id} of ({}) is not a combination of entities in the schema",
+        .keywords.join(" ")
+    )]
+    UnknownComplexEntity {
+        id: InstanceId,
+        keywords: Vec<String>,
+    },
+
+    #[error("Nesting depth of parameters exceeds the limit {0}")]
+    DepthExceeded(usize),
+
+    #[error("Record exceeds the size limit {limit} bytes")]
+    RecordTooLarge { size: usize, limit: usize },
+
+    #[error("{source} at {location}")]
+    Located {
+        location: Location,
+        source: Box<Error>,
+    },
+}
+
+/// Where a parameter failed to be deserialized, see [Error::Located]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Location {
+    /// Entity instance of the record containing the parameter
+    pub id: Option<InstanceId>,
+    /// Indices of the parameter in nested lists, e.g. `[1, 0]` for `3` in `A(1, (3, 4))`
+    pub path: Vec<usize>,
+    /// Position in the input, filled by [Error::locate]
+    pub span: Option<Span>,
+}
+
+/// Position in the input, with 1-based line and column counted in characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(id) = self.id {
+            parts.push(alloc::format!("{}", id));
+        }
+        if !self.path.is_empty() {
+            parts.push(alloc::format!("parameter {:?}", self.path));
+        }
+        if let Some(Span { line, column, .. }) = self.span {
+            parts.push(alloc::format!("line {}, column {}", line, column));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+impl Error {
+    /// Location of this error, if it comes from deserializing a parameter
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Error::Located { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// Record that this error occurred in the `index`-th element of a list
+    #[cfg(feature = "serde")]
+    pub(crate) fn at_index(self, index: usize) -> Self {
+        match self {
+            Error::Located {
+                mut location,
+                source,
+            } => {
+                location.path.insert(0, index);
+                Error::Located { location, source }
+            }
+            e => Error::Located {
+                location: Location {
+                    path: vec![index],
+                    ..Default::default()
+                },
+                source: Box::new(e),
+            },
+        }
+    }
+
+    /// Record that this error occurred in the record of the entity instance `id`
+    #[cfg(feature = "std")]
+    pub(crate) fn at_instance(self, id: InstanceId) -> Self {
+        match self {
+            Error::Located {
+                mut location,
+                source,
+            } => {
+                location.id.get_or_insert(id);
+                Error::Located { location, source }
+            }
+            e => Error::Located {
+                location: Location {
+                    id: Some(id),
+                    ..Default::default()
+                },
+                source: Box::new(e),
+            },
+        }
+    }
+
+    /// Fill [Location::span] by finding the parameter in `input` the data was parsed from
+    ///
+    /// Errors without [Location::id] are returned as they are.
+    #[cfg(feature = "alloc")]
+    pub fn locate(self, input: &str) -> Self {
+        match &self {
+            Error::Located { location, .. } if location.id.is_some() && location.span.is_none() => {
+                self.locate_by(&crate::parser::Locator::new(input))
+            }
+            _ => self,
+        }
+    }
+
+    /// [Error::locate] with the positions of records scanned in advance, for locating many errors
+    #[cfg(feature = "alloc")]
+    pub(crate) fn locate_by(self, locator: &crate::parser::Locator) -> Self {
+        match self {
+            Error::Located {
+                mut location,
+                source,
+            } => {
+                if let (Some(id), None) = (location.id, location.span) {
+                    location.span = locator.locate(id, &location.path);
+                }
+                Error::Located { location, source }
+            }
+            e => e,
+        }
+    }
 }
 
+#[cfg(feature = "serde")]
 impl de::Error for Error {
     fn custom<T>(msg: T) -> Self
     where
@@ -33,6 +210,7 @@ impl de::Error for Error {
     }
 }
 
+#[cfg(feature = "serde")]
 impl ser::Error for Error {
     fn custom<T>(msg: T) -> Self
     where
@@ -43,12 +221,14 @@ impl ser::Error for Error {
 }
 
 /// Error while tokenizing STEP input
+#[cfg(feature = "alloc")]
 pub struct TokenizeFailed {
     rendered_error: String,
 }
 
+#[cfg(feature = "alloc")]
 impl fmt::Debug for TokenizeFailed {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::result::Result<(), fmt::Error> {
         write!(
             f,
             "Error while tokenizing STEP input\n{}",
@@ -58,15 +238,21 @@ impl fmt::Debug for TokenizeFailed {
     }
 }
 
+#[cfg(feature = "alloc")]
 // Use same output as Debug
 impl fmt::Display for TokenizeFailed {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::result::Result<(), fmt::Error> {
         fmt::Debug::fmt(self, f)
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for TokenizeFailed {}
 
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+impl core::error::Error for TokenizeFailed {}
+
+#[cfg(feature = "alloc")]
 impl TokenizeFailed {
     pub fn new(input: &str, err: nom::error::VerboseError<&str>) -> Self {
         TokenizeFailed {
@@ -20,7 +20,10 @@
 //! we write these definitions manually to keep development process simple.
 //!
 
-use crate::{ast::*, error::Result};
+use crate::{
+    ast::*,
+    error::{Error, Result},
+};
 use serde::Deserialize;
 
 /// File description
@@ -59,7 +62,10 @@ pub struct FileDescription {
 /// TYPE time_stamp_text = STRING(256);
 /// END_TYPE;
 /// ```
-#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize)]
+///
+/// Some exporters omit trailing fields, which are filled by empty values.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(rename = "FILE_NAME", default)]
 pub struct FileName {
     pub name: String,
     /// ISO-8601 formatted date and time specifying when the exchange structure was created.
@@ -99,25 +105,104 @@ pub struct Header {
     pub file_description: FileDescription,
     pub file_name: FileName,
     pub file_schema: FileSchema,
+
+    /// Records other than the above three, e.g. `FILE_POPULATION` or vendor specific ones,
+    /// with their names and parameters in the order of appearance
+    pub extra_records: Vec<(String, Vec<Parameter>)>,
 }
 
 impl Header {
+    /// Interpret header records parsed in the same way as data section
+    ///
+    /// Records are looked up by their names, and other records are kept in [Header::extra_records].
     pub fn from_records(records: &[Record]) -> Result<Self> {
-        assert!(records.len() >= 3);
-        let file_description = FileDescription::deserialize(&records[0])?;
-        let file_name = FileName::deserialize(&records[1])?;
-        let file_schema = FileSchema::deserialize(&records[2])?;
+        let find = |name: &'static str| {
+            records
+                .iter()
+                .find(|record| record.name == name)
+                .ok_or(Error::MissingHeaderRecord(name))
+        };
+        let file_description = FileDescription::deserialize(find("FILE_DESCRIPTION")?)?;
+        let file_name = FileName::deserialize(find("FILE_NAME")?)?;
+        let file_schema = FileSchema::deserialize(find("FILE_SCHEMA")?)?;
+        let extra_records = records
+            .iter()
+            .filter(|record| {
+                !matches!(
+                    record.name.as_str(),
+                    "FILE_DESCRIPTION" | "FILE_NAME" | "FILE_SCHEMA"
+                )
+            })
+            .map(|record| {
+                let parameters = match &record.parameter {
+                    Parameter::List(parameters) => parameters.clone(),
+                    parameter => vec![parameter.clone()],
+                };
+                (record.name.clone(), parameters)
+            })
+            .collect();
         Ok(Header {
             file_description,
             file_name,
             file_schema,
+            extra_records,
         })
     }
+
+    /// Records to be written into `HEADER` section, the reverse of [Header::from_records]
+    pub fn to_records(&self) -> Vec<Record> {
+        let string = |s: &String| Parameter::String(s.clone());
+        let strings = |list: &[String]| Parameter::List(list.iter().map(string).collect());
+        let record = |name: &str, parameters: Vec<Parameter>| Record {
+            name: name.to_string(),
+            parameter: Parameter::List(parameters),
+        };
+        let FileDescription {
+            description,
+            implementation_level,
+        } = &self.file_description;
+        let FileName {
+            name,
+            time_stamp,
+            author,
+            organization,
+            preprocessor_version,
+            originating_system,
+            authorization,
+        } = &self.file_name;
+        let mut records = vec![
+            record(
+                "FILE_DESCRIPTION",
+                vec![strings(description), string(implementation_level)],
+            ),
+            record(
+                "FILE_NAME",
+                vec![
+                    string(name),
+                    string(time_stamp),
+                    strings(author),
+                    strings(organization),
+                    string(preprocessor_version),
+                    string(originating_system),
+                    string(authorization),
+                ],
+            ),
+            record("FILE_SCHEMA", vec![strings(&self.file_schema.schema)]),
+        ];
+        records.extend(
+            self.extra_records
+                .iter()
+                .map(|(name, parameters)| record(name, parameters.clone())),
+        );
+        records
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::ast::*;
     use nom::Finish;
+    use std::str::FromStr;
 
     #[test]
     fn header() {
@@ -135,4 +220,124 @@ mod tests {
         let header = super::Header::from_records(&records).unwrap();
         dbg!(header);
     }
+
+    #[test]
+    fn exchange_header() {
+        let exchange = Exchange::from_str(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_DESCRIPTION(('CONFIG CONTROL DESIGN', 'part of a bracket'), '2;1');
+              FILE_NAME('bracket.stp', '2021-06-01T12:00:00', ('alice', 'bob'), ('ACME'),
+                'ST-DEVELOPER v18', 'CAD system', 'approved');
+              FILE_SCHEMA(('CONFIG_CONTROL_DESIGN'));
+            ENDSEC;
+            DATA;
+            ENDSEC;
+            END-ISO-10303-21;
+            "#
+            .trim(),
+        )
+        .unwrap();
+        let header = exchange.typed_header().unwrap();
+        assert_eq!(
+            header.file_description,
+            super::FileDescription {
+                description: vec![
+                    "CONFIG CONTROL DESIGN".to_string(),
+                    "part of a bracket".to_string()
+                ],
+                implementation_level: "2;1".to_string(),
+            }
+        );
+        assert_eq!(
+            header.file_name,
+            super::FileName {
+                name: "bracket.stp".to_string(),
+                time_stamp: "2021-06-01T12:00:00".to_string(),
+                author: vec!["alice".to_string(), "bob".to_string()],
+                organization: vec!["ACME".to_string()],
+                preprocessor_version: "ST-DEVELOPER v18".to_string(),
+                originating_system: "CAD system".to_string(),
+                authorization: "approved".to_string(),
+            }
+        );
+        assert_eq!(header.file_schema.schema, ["CONFIG_CONTROL_DESIGN"]);
+        assert!(header.extra_records.is_empty());
+    }
+
+    #[test]
+    fn file_name_trailing_fields() {
+        let header = r#"
+        HEADER;
+            FILE_DESCRIPTION( ( '' ), '2;1' );
+            FILE_NAME( 'part.step', '2021-01-01T00:00:00', ( 'alice', 'bob' ), ( 'org' ) );
+            FILE_SCHEMA( ( 'CONFIG_CONTROL_DESIGN' ) );
+        ENDSEC;
+        "#
+        .trim();
+        let (_residual, records) = crate::parser::exchange::header_section(header)
+            .finish()
+            .unwrap();
+        let header = super::Header::from_records(&records).unwrap();
+        assert_eq!(
+            header.file_name,
+            super::FileName {
+                name: "part.step".to_string(),
+                time_stamp: "2021-01-01T00:00:00".to_string(),
+                author: vec!["alice".to_string(), "bob".to_string()],
+                organization: vec!["org".to_string()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn header_parameters() {
+        // Header records are parsed into `Parameter` as data section,
+        // and unknown records are kept as they are.
+        let header = r#"
+        HEADER;
+            FILE_DESCRIPTION( ( '' ), '2;1' );
+            FILE_NAME( 'part.step', '', ( '' ), ( '' ), '', '', '' );
+            FILE_SCHEMA( ( 'CONFIG_CONTROL_DESIGN' ) );
+            FILE_POPULATION( 'CONFIG_CONTROL_DESIGN', 'FIRST', ( #1, #2 ) );
+            !VENDOR_INFO( 'exporter' );
+        ENDSEC;
+        "#
+        .trim();
+        let (_residual, records) = crate::parser::exchange::header_section(header)
+            .finish()
+            .unwrap();
+        assert_eq!(
+            records[3].parameter,
+            Parameter::List(vec![
+                Parameter::String("CONFIG_CONTROL_DESIGN".to_string()),
+                Parameter::String("FIRST".to_string()),
+                Parameter::List(vec![
+                    Parameter::Ref(Name::Entity(InstanceId(1))),
+                    Parameter::Ref(Name::Entity(InstanceId(2))),
+                ]),
+            ])
+        );
+        let header = super::Header::from_records(&records).unwrap();
+        assert_eq!(header.file_schema.schema, ["CONFIG_CONTROL_DESIGN"]);
+        assert_eq!(header.extra_records.len(), 2);
+        let (name, parameters) = &header.extra_records[0];
+        assert_eq!(name, "FILE_POPULATION");
+        assert_eq!(parameters.len(), 3);
+        assert_eq!(parameters[1], Parameter::String("FIRST".to_string()));
+        assert_eq!(
+            header.extra_records[1],
+            (
+                "VENDOR_INFO".to_string(),
+                vec![Parameter::String("exporter".to_string())]
+            )
+        );
+
+        assert!(matches!(
+            super::Header::from_records(&records[..2]),
+            Err(crate::error::Error::MissingHeaderRecord("FILE_SCHEMA"))
+        ));
+    }
 }
@@ -105,19 +105,51 @@
 //!
 //! Not supported yet. See [tracking issue](https://github.com/ricosjp/ruststep/issues/215).
 //!
+//! `no_std` support
+//! -----------------
+//! The data model [ast::Parameter], its deserializer, and the [parser] only depend on `alloc` and `serde`,
+//! and can be used in `no_std` environments by disabling the default `std` feature:
+//!
+//! ```toml
+//! ruststep = { version = "*", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! Other components, e.g. tables, header interpretation, and `parser::EntityInstanceReader` reading `std::io::BufRead`,
+//! require `std` feature.
+//! Since the error type implements `core::error::Error` in `no_std` environments,
+//! Rust 1.81 or later is required for this case.
+//!
+//! Deserializing without serde
+//! ----------------------------
+//! The serializer and deserializer of [ast], header interpretation, and holders deserialized by serde
+//! require the default `serde` feature.
+//! Without it, holders generated by `esprc --deserialize-backend from-parameter` are deserialized
+//! by [tables::FromParameter]:
+//!
+//! ```toml
+//! ruststep = { version = "*", default-features = false, features = ["std"] }
+//! ```
+//!
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
 
+extern crate alloc;
+
 pub mod ast;
 pub mod error;
+#[cfg(all(feature = "std", feature = "serde"))]
 pub mod header;
+#[cfg(feature = "alloc")]
 pub mod parser;
 pub mod primitive;
+#[cfg(feature = "std")]
 pub mod tables;
 
 // To work generated code by ruststep-derive only with ruststep
 pub use derive_more;
 pub use itertools;
+#[cfg(feature = "serde")]
 pub use serde;
 
 pub use ruststep_derive::*;
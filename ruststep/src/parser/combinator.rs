@@ -10,6 +10,7 @@
 //!
 //! and combinators in this submodule responsible for handling them.
 
+use alloc::{string::String, vec, vec::Vec};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -2,6 +2,7 @@ use crate::{
     ast::*,
     parser::{combinator::*, token::*},
 };
+use alloc::{string::String, vec::Vec};
 use nom::{branch::alt, Parser};
 
 /// anchor_section = `ANCHOR;` [anchor_list] `ENDSEC;` .
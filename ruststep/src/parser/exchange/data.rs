@@ -1,16 +1,29 @@
 use crate::{
     ast::*,
-    parser::{combinator::*, exchange::*, token::*},
+    parser::{combinator::*, exchange::*, token::*, ParseOptions},
 };
+use alloc::vec::Vec;
 use nom::{branch::alt, Parser};
 
 /// data_section = `DATA` \[ `(` [parameter_list] `)` \] `;` [entity_instance_list] `ENDSEC;` .
 pub fn data_section(input: &str) -> ParseResult<DataSection> {
+    data_section_with_options(input, &ParseOptions::default())
+}
+
+/// [data_section] with [ParseOptions]
+pub fn data_section_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, DataSection> {
     tuple_((
         tag_("DATA"),
-        opt_(tuple_((char_('('), parameter_list, char_(')')))),
+        opt_(tuple_((
+            char_('('),
+            |i| parameter_list_with_options(i, options),
+            char_(')'),
+        ))),
         char_(';'),
-        entity_instance_list,
+        |i| entity_instance_list_with_options(i, options),
         tag_("ENDSEC;"),
     ))
     .map(|(_start, meta, _semicolon, entities, _end)| DataSection {
@@ -24,53 +37,133 @@ pub fn data_section(input: &str) -> ParseResult<DataSection> {
 
 /// entity_instance_list = { [entity_instance] } .
 pub fn entity_instance_list(input: &str) -> ParseResult<Vec<EntityInstance>> {
-    many0_(entity_instance).parse(input)
+    entity_instance_list_with_options(input, &ParseOptions::default())
+}
+
+/// [entity_instance_list] with [ParseOptions]
+pub fn entity_instance_list_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Vec<EntityInstance>> {
+    many0_(|i| entity_instance_with_options(i, options)).parse(input)
 }
 
 /// entity_instance = [simple_entity_instance] | [complex_entity_instance] .
 pub fn entity_instance(input: &str) -> ParseResult<EntityInstance> {
-    alt((simple_entity_instance, complex_entity_instance)).parse(input)
+    entity_instance_with_options(input, &ParseOptions::default())
+}
+
+/// [entity_instance] with [ParseOptions]
+pub fn entity_instance_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, EntityInstance> {
+    alt((
+        |i| simple_entity_instance_with_options(i, options),
+        |i| complex_entity_instance_with_options(i, options),
+    ))
+    .parse(input)
 }
 
 /// simple_entity_instance = [entity_instance_name] `=` [simple_record] `;` .
 pub fn simple_entity_instance(input: &str) -> ParseResult<EntityInstance> {
-    tuple_((entity_instance_name, char_('='), simple_record, char_(';')))
-        .map(|(id, _eq, record, _semicolon)| EntityInstance::Simple { id, record })
-        .parse(input)
+    simple_entity_instance_with_options(input, &ParseOptions::default())
+}
+
+/// [simple_entity_instance] with [ParseOptions]
+pub fn simple_entity_instance_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, EntityInstance> {
+    tuple_((
+        entity_instance_name,
+        char_('='),
+        |i| simple_record_with_options(i, options),
+        char_(';'),
+    ))
+    .map(|(id, _eq, record, _semicolon)| EntityInstance::Simple {
+        id: InstanceId(id),
+        record,
+    })
+    .parse(input)
 }
 
 /// complex_entity_instance = [entity_instance_name] `=` [subsuper_record] `;` .
 pub fn complex_entity_instance(input: &str) -> ParseResult<EntityInstance> {
+    complex_entity_instance_with_options(input, &ParseOptions::default())
+}
+
+/// [complex_entity_instance] with [ParseOptions]
+pub fn complex_entity_instance_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, EntityInstance> {
     tuple_((
         entity_instance_name,
         char_('='),
-        subsuper_record,
+        |i| subsuper_record_with_options(i, options),
         char_(';'),
     ))
-    .map(|(id, _eq, subsuper, _semicolon)| EntityInstance::Complex { id, subsuper })
+    .map(|(id, _eq, subsuper, _semicolon)| EntityInstance::Complex {
+        id: InstanceId(id),
+        subsuper,
+    })
     .parse(input)
 }
 
 /// simple_record = [keyword] `(` \[ [parameter_list] \] `)` .
 pub fn simple_record(input: &str) -> ParseResult<Record> {
-    tuple_((keyword, char_('('), opt_(parameter_list), char_(')')))
-        .map(|(name, _open, parameter, _close)| Record {
-            name,
-            parameter: parameter.unwrap_or_default().into_iter().collect(),
-        })
-        .parse(input)
+    simple_record_with_options(input, &ParseOptions::default())
+}
+
+/// [simple_record] with [ParseOptions]
+pub fn simple_record_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Record> {
+    tuple_((
+        keyword,
+        char_('('),
+        opt_(|i| parameter_list_with_options(i, options)),
+        char_(')'),
+    ))
+    .map(|(name, _open, parameter, _close)| Record {
+        name,
+        parameter: parameter.unwrap_or_default().into_iter().collect(),
+    })
+    .parse(input)
 }
 
 /// simple_record_list = [simple_record] { [simple_record] } .
 pub fn simple_record_list(input: &str) -> ParseResult<Vec<Record>> {
-    many0_(simple_record).parse(input)
+    simple_record_list_with_options(input, &ParseOptions::default())
+}
+
+/// [simple_record_list] with [ParseOptions]
+pub fn simple_record_list_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Vec<Record>> {
+    many0_(|i| simple_record_with_options(i, options)).parse(input)
 }
 
 /// subsuper_record = `(` [simple_record_list] `)` .
 pub fn subsuper_record(input: &str) -> ParseResult<SubSuperRecord> {
-    tuple_((char_('('), simple_record_list, char_(')')))
-        .map(|(_open, records, _close)| SubSuperRecord(records))
-        .parse(input)
+    subsuper_record_with_options(input, &ParseOptions::default())
+}
+
+/// [subsuper_record] with [ParseOptions]
+pub fn subsuper_record_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, SubSuperRecord> {
+    tuple_((
+        char_('('),
+        |i| simple_record_list_with_options(i, options),
+        char_(')'),
+    ))
+    .map(|(_open, records, _close)| SubSuperRecord(records))
+    .parse(input)
 }
 
 #[cfg(test)]
@@ -1,19 +1,40 @@
 use crate::{
     ast::*,
-    parser::{combinator::*, exchange::*},
+    parser::{combinator::*, exchange::*, ParseOptions},
 };
+use alloc::vec::Vec;
 use nom::Parser;
 
 /// header_section = `HEADER;` [header_entity] [header_entity] [header_entity] \[ [header_entity_list] \] `ENDSEC;` .
 pub fn header_section(input: &str) -> ParseResult<Vec<Record>> {
-    tuple_((tag_("HEADER;"), header_entity_list, tag_("ENDSEC;")))
-        .map(|(_start, entities, _close)| entities)
-        .parse(input)
+    header_section_with_options(input, &ParseOptions::default())
+}
+
+/// [header_section] with [ParseOptions]
+pub fn header_section_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Vec<Record>> {
+    tuple_((
+        tag_("HEADER;"),
+        |i| header_entity_list_with_options(i, options),
+        tag_("ENDSEC;"),
+    ))
+    .map(|(_start, entities, _close)| entities)
+    .parse(input)
 }
 
 /// header_entity_list = [header_entity] { [header_entity] } .
 pub fn header_entity_list(input: &str) -> ParseResult<Vec<Record>> {
-    many1_(header_entity).parse(input)
+    header_entity_list_with_options(input, &ParseOptions::default())
+}
+
+/// [header_entity_list] with [ParseOptions]
+pub fn header_entity_list_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Vec<Record>> {
+    many1_(|i| header_entity_with_options(i, options)).parse(input)
 }
 
 /// header_entity = [simple_record] `;` .
@@ -24,7 +45,15 @@ pub fn header_entity_list(input: &str) -> ParseResult<Vec<Record>> {
 /// header_entity = keyword ( [ parameter_list ] ) ; .
 /// ```
 pub fn header_entity(input: &str) -> ParseResult<Record> {
-    tuple_((simple_record, char_(';')))
+    header_entity_with_options(input, &ParseOptions::default())
+}
+
+/// [header_entity] with [ParseOptions]
+pub fn header_entity_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Record> {
+    tuple_((|i| simple_record_with_options(i, options), char_(';')))
         .map(|(record, _semicolon)| record)
         .parse(input)
 }
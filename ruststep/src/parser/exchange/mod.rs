@@ -14,8 +14,9 @@ pub use reference::*;
 
 use crate::{
     ast::*,
-    parser::{combinator::*, token::*},
+    parser::{combinator::*, token::*, ParseOptions},
 };
+use alloc::string::String;
 use nom::Parser;
 
 /// exchange_file = `ISO-10303-21;`
@@ -26,12 +27,22 @@ use nom::Parser;
 ///                 `END-ISO-10303-21;`
 ///               { signature_section } .
 pub fn exchange_file(input: &str) -> ParseResult<Exchange> {
+    exchange_file_with_options(input, &ParseOptions::default())
+}
+
+/// [exchange_file] with [ParseOptions]
+///
+/// The options are passed to the parsers of HEADER and DATA sections.
+pub fn exchange_file_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Exchange> {
     tuple_((
         tag_("ISO-10303-21;"),
-        header_section,
+        |i| header_section_with_options(i, options),
         opt_(anchor_section),
         opt_(reference_section),
-        many0_(data_section),
+        many0_(|i| data_section_with_options(i, options)),
         tag_("END-ISO-10303-21;"),
         many0_(signature_section),
     ))
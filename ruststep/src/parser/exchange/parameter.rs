@@ -1,42 +1,99 @@
 use crate::{
     ast::*,
-    parser::{combinator::*, token::*},
+    parser::{combinator::*, token::*, ParseOptions},
+};
+use alloc::{boxed::Box, string::ToString, vec::Vec};
+use nom::{
+    branch::alt,
+    combinator::{consumed, value},
+    Parser,
 };
-use nom::{branch::alt, combinator::value, Parser};
 
 /// list = `(` \[ [parameter] { `,` [parameter] } \] `)` .
 pub fn list(input: &str) -> ParseResult<Parameter> {
-    tuple_((char_('('), opt_(comma_separated(parameter)), char_(')')))
-        .map(|(_open, params, _close)| Parameter::List(params.unwrap_or_default()))
-        .parse(input)
+    list_with_options(input, &ParseOptions::default())
+}
+
+/// [list] with [ParseOptions]
+pub fn list_with_options<'a>(input: &'a str, options: &ParseOptions) -> ParseResult<'a, Parameter> {
+    tuple_((
+        char_('('),
+        opt_(comma_separated(|i| parameter_with_options(i, options))),
+        char_(')'),
+    ))
+    .map(|(_open, params, _close)| Parameter::List(params.unwrap_or_default()))
+    .parse(input)
 }
 
 /// parameter = [typed_parameter] | [untyped_parameter] | [omitted_parameter] .
 pub fn parameter(input: &str) -> ParseResult<Parameter> {
-    alt((typed_parameter, untyped_parameter, omitted_parameter)).parse(input)
+    parameter_with_options(input, &ParseOptions::default())
+}
+
+/// [parameter] with [ParseOptions]
+pub fn parameter_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Parameter> {
+    alt((
+        |i| typed_parameter_with_options(i, options),
+        |i| untyped_parameter_with_options(i, options),
+        omitted_parameter,
+    ))
+    .parse(input)
 }
 
 /// typed_parameter = [keyword] `(` [parameter] `)` .
 pub fn typed_parameter(input: &str) -> ParseResult<Parameter> {
-    tuple_((keyword, char_('('), parameter, char_(')')))
-        .map(|(name, _open, ty, _close)| Parameter::Typed {
-            keyword: name,
-            parameter: Box::new(ty),
-        })
-        .parse(input)
+    typed_parameter_with_options(input, &ParseOptions::default())
+}
+
+/// [typed_parameter] with [ParseOptions]
+pub fn typed_parameter_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Parameter> {
+    tuple_((
+        keyword,
+        char_('('),
+        |i| parameter_with_options(i, options),
+        char_(')'),
+    ))
+    .map(|(name, _open, ty, _close)| Parameter::Typed {
+        keyword: name,
+        parameter: Box::new(ty),
+    })
+    .parse(input)
 }
 
 /// untyped_parameter = `$` | [integer] | [real] | [string] | [rhs_occurrence_name] | [enumeration] | binary | [list] .
 pub fn untyped_parameter(input: &str) -> ParseResult<Parameter> {
+    untyped_parameter_with_options(input, &ParseOptions::default())
+}
+
+/// [untyped_parameter] with [ParseOptions], where [ParseOptions::preserve_reals] is used for reals
+pub fn untyped_parameter_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Parameter> {
     alt((
         char_('$').map(|_| Parameter::NotProvided),
-        real.map(Parameter::Real),
+        consumed(real).map(|(raw, value)| {
+            if options.preserve_reals {
+                Parameter::RawReal {
+                    value,
+                    raw: raw.to_string(),
+                }
+            } else {
+                Parameter::Real(value)
+            }
+        }),
         integer.map(Parameter::Integer),
         string.map(Parameter::String),
         rhs_occurrence_name.map(Parameter::Ref),
         enumeration.map(Parameter::Enumeration),
-        // FIXME binary
-        list,
+        binary.map(|(bytes, unused_bits)| Parameter::Binary { bytes, unused_bits }),
+        |i| list_with_options(i, options),
     ))
     .parse(input)
 }
@@ -48,7 +105,15 @@ pub fn omitted_parameter(input: &str) -> ParseResult<Parameter> {
 
 /// parameter_list = [parameter] { `,` [parameter] } .
 pub fn parameter_list(input: &str) -> ParseResult<Vec<Parameter>> {
-    comma_separated(parameter).parse(input)
+    parameter_list_with_options(input, &ParseOptions::default())
+}
+
+/// [parameter_list] with [ParseOptions]
+pub fn parameter_list_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> ParseResult<'a, Vec<Parameter>> {
+    comma_separated(|i| parameter_with_options(i, options)).parse(input)
 }
 
 #[cfg(test)]
@@ -81,6 +146,48 @@ mod tests {
         );
     }
 
+    /// Accepts only bytes
+    struct Bytes(Vec<u8>);
+
+    impl<'de> serde::Deserialize<'de> for Bytes {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct Visitor;
+            impl serde::de::Visitor<'_> for Visitor {
+                type Value = Bytes;
+                fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    write!(f, "bytes")
+                }
+                fn visit_bytes<E>(self, v: &[u8]) -> Result<Bytes, E> {
+                    Ok(Bytes(v.to_vec()))
+                }
+            }
+            deserializer.deserialize_any(Visitor)
+        }
+    }
+
+    #[test]
+    fn binary() {
+        use serde::Deserialize;
+
+        // byte-aligned and not byte-aligned
+        for (input, bytes, unused_bits) in [
+            ("\"00FA0\"", vec![0x0F, 0xA0], 0),
+            ("\"1723\"", vec![0x07, 0x23], 5),
+        ] {
+            let (res, p) = super::untyped_parameter(input).finish().unwrap();
+            assert_eq!(res, "");
+            assert_eq!(
+                p,
+                Parameter::Binary {
+                    bytes: bytes.clone(),
+                    unused_bits
+                }
+            );
+            assert_eq!(Bytes::deserialize(&p).unwrap().0, bytes);
+        }
+        assert!(Bytes::deserialize(&Parameter::Integer(1)).is_err());
+    }
+
     #[test]
     fn empty_parameter_list() {
         let (res, record) = super::untyped_parameter("()").finish().unwrap();
@@ -2,6 +2,7 @@ use crate::{
     ast::*,
     parser::{combinator::*, token::*},
 };
+use alloc::vec::Vec;
 use nom::Parser;
 
 /// reference_section = `REFERENCE;` [reference_list] `ENDSEC;` .
@@ -0,0 +1,114 @@
+use crate::error::{Error, Result};
+
+/// Limits on nesting depth and size of records for parsing untrusted input
+///
+/// Parsers of this crate are recursive, and deeply nested parameters such as `((((...))))`
+/// will overflow the stack. The input is scanned by [ParseLimits::check] without recursion
+/// before parsing to reject such input.
+///
+/// ```
+/// use ruststep::{error::Error, parser::ParseLimits};
+///
+/// let limits = ParseLimits { max_depth: 3, max_record_size: 32 };
+/// assert!(limits.check("#1 = A(((1)));").is_ok());
+/// assert!(matches!(limits.check("#1 = A((((1))));"), Err(Error::DepthExceeded(3))));
+///
+/// // Parenthesis in strings and comments are not counted
+/// assert!(limits.check("#1 = A('((((', /* (((( */ 1);").is_ok());
+///
+/// assert!(matches!(
+///     limits.check("#1 = A('a very long string exceeding the limit');"),
+///     Err(Error::RecordTooLarge { limit: 32, .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum nesting depth of parentheses, i.e. records, typed parameters and lists
+    pub max_depth: usize,
+    /// Maximum size of a statement terminated by `;` in bytes
+    pub max_record_size: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        ParseLimits {
+            max_depth: 128,
+            max_record_size: 16 * 1024 * 1024,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Infinite limits, i.e. [ParseLimits::check] always succeeds
+    pub fn unlimited() -> Self {
+        ParseLimits {
+            max_depth: usize::MAX,
+            max_record_size: usize::MAX,
+        }
+    }
+
+    /// Check the input does not exceed the limits
+    pub fn check(&self, input: &str) -> Result<()> {
+        let bytes = input.as_bytes();
+        let mut depth = 0_usize;
+        let mut record_start = 0;
+        let mut in_string = false;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                // `''` in a string is an escaped quote, which closes and reopens the string
+                b'\'' => in_string = !in_string,
+                b'/' if !in_string && bytes.get(i + 1) == Some(&b'*') => {
+                    i = match input[i + 2..].find("*/") {
+                        Some(end) => i + 2 + end + 1,
+                        None => bytes.len(),
+                    };
+                }
+                b'(' if !in_string => {
+                    depth += 1;
+                    if depth > self.max_depth {
+                        return Err(Error::DepthExceeded(self.max_depth));
+                    }
+                }
+                b')' if !in_string => depth = depth.saturating_sub(1),
+                b';' if !in_string => record_start = i + 1,
+                _ => {}
+            }
+            i += 1;
+            if i - record_start > self.max_record_size {
+                return Err(Error::RecordTooLarge {
+                    size: i - record_start,
+                    limit: self.max_record_size,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaped_quote() {
+        let limits = ParseLimits {
+            max_depth: 1,
+            max_record_size: 64,
+        };
+        assert!(limits.check("#1 = A('it''s (((');").is_ok());
+        assert!(limits.check("#1 = A('it''s', ((1)));").is_err());
+    }
+
+    #[test]
+    fn record_size_reset() {
+        let limits = ParseLimits {
+            max_depth: 8,
+            max_record_size: 16,
+        };
+        assert!(limits
+            .check("#1 = A(1.0);\n#2 = A(2.0);\n#3 = A(3.0);")
+            .is_ok());
+        assert!(limits.check("#1 = A(1.0, 2.0, 3.0);").is_err());
+    }
+}
@@ -34,10 +34,24 @@ pub mod combinator;
 pub mod exchange;
 pub mod token;
 
+mod limits;
+mod options;
+mod recovery;
+mod span;
+#[cfg(feature = "std")]
+mod stream;
+pub use limits::*;
+pub use options::*;
+pub use recovery::*;
+pub(crate) use span::Locator;
+#[cfg(feature = "std")]
+pub use stream::*;
+
 use crate::{
     ast,
-    error::{Result, TokenizeFailed},
+    error::{Error, Result, TokenizeFailed},
 };
+use alloc::{string::ToString, vec::Vec};
 use nom::Finish;
 
 /// Parse HEADER section
@@ -66,16 +80,83 @@ use nom::Finish;
 /// assert_eq!(residual, ""); // consume HEADER section of `step_str`
 /// ```
 pub fn parse_header(input: &str) -> Result<(&str, Vec<ast::Record>)> {
+    ParseLimits::default().check(input)?;
     match exchange::header_section(input).finish() {
         Ok((input, records)) => Ok((input, records)),
         Err(e) => Err(TokenizeFailed::new(input, e).into()),
     }
 }
 
-/// Parse entire STEP file
+/// Parse entire STEP file with [ParseLimits::default]
 pub fn parse(input: &str) -> Result<ast::Exchange> {
-    match exchange::exchange_file(input).finish() {
+    parse_with_limits(input, &ParseLimits::default())
+}
+
+/// Parse entire STEP file with limits of nesting depth and record size
+pub fn parse_with_limits(input: &str, limits: &ParseLimits) -> Result<ast::Exchange> {
+    parse_with_options(
+        input,
+        &ParseOptions {
+            limits: *limits,
+            lenient: false,
+            preserve_reals: false,
+        },
+    )
+}
+
+/// Parse entire STEP file with [ParseOptions]
+pub fn parse_with_options(input: &str, options: &ParseOptions) -> Result<ast::Exchange> {
+    options.limits.check(input)?;
+    let normalized;
+    let input = if options.lenient {
+        normalized = normalize_lists(input);
+        normalized.as_str()
+    } else {
+        input
+    };
+    match exchange::exchange_file_with_options(input, options).finish() {
         Ok((_residual, ex)) => Ok(ex),
         Err(e) => Err(TokenizeFailed::new(input, e).into()),
     }
 }
+
+/// Parse a parameter, e.g. `(1, 2.0, A(#3))`, suitable for fuzzing
+///
+/// ```
+/// use ruststep::{ast::Parameter, error::Error, parser::*};
+///
+/// let limits = ParseLimits::default();
+/// assert_eq!(parse_parameter("(1)", &limits).unwrap(), Parameter::List(vec![Parameter::Integer(1)]));
+///
+/// let deep = format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000));
+/// assert!(matches!(parse_parameter(&deep, &limits), Err(Error::DepthExceeded(_))));
+/// assert!(matches!(parse_parameter("(1", &limits), Err(Error::TokenizeFailed(_))));
+/// ```
+pub fn parse_parameter(input: &str, limits: &ParseLimits) -> Result<ast::Parameter> {
+    parse_parameter_with_options(
+        input,
+        &ParseOptions {
+            limits: *limits,
+            lenient: false,
+            preserve_reals: false,
+        },
+    )
+}
+
+/// Parse a parameter with [ParseOptions]
+pub fn parse_parameter_with_options(input: &str, options: &ParseOptions) -> Result<ast::Parameter> {
+    let input = input.trim();
+    options.limits.check(input)?;
+    let normalized;
+    let input = if options.lenient {
+        normalized = normalize_lists(input);
+        normalized.as_str()
+    } else {
+        input
+    };
+    match exchange::parameter_with_options(input, options).finish() {
+        Ok(("", p)) => Ok(p),
+        Ok((residual, _p)) => Err(Error::ExtraInputRemaining(residual.to_string())),
+        Err(e) => Err(TokenizeFailed::new(input, e).into()),
+    }
+}
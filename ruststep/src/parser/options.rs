@@ -0,0 +1,188 @@
+use super::ParseLimits;
+use alloc::string::String;
+
+/// Options for parsing STEP files
+///
+/// The default is strict, i.e. only the syntax of ISO 10303-21 is accepted.
+/// [ParseOptions::lenient] tolerates some malformed output of broken exporters
+/// in lists of parameters, including ones of records:
+///
+/// - a trailing comma is ignored, e.g. `(1., 2.,)` is parsed as `(1., 2.)`
+/// - an empty slot is regarded as `$`, e.g. `(1., , 2.)` is parsed as `(1., $, 2.)`,
+///   and `(,)` as `($)` since its trailing comma is ignored
+///
+/// ```
+/// use ruststep::{ast::Parameter, parser::*};
+///
+/// assert!(parse_parameter_with_options("(1., 2.,)", &ParseOptions::default()).is_err());
+/// assert_eq!(
+///     parse_parameter_with_options("(1., 2.,)", &ParseOptions::lenient()).unwrap(),
+///     Parameter::List(vec![Parameter::Real(1.0), Parameter::Real(2.0)])
+/// );
+/// ```
+///
+/// [ParseOptions::preserve_reals] keeps the literals of reals for writers reproducing them:
+///
+/// ```
+/// use ruststep::{ast::Parameter, parser::*};
+///
+/// let options = ParseOptions { preserve_reals: true, ..Default::default() };
+/// assert_eq!(
+///     parse_parameter_with_options("1.50E+01", &options).unwrap(),
+///     Parameter::RawReal { value: 15.0, raw: "1.50E+01".to_string() }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Limits checked before parsing
+    pub limits: ParseLimits,
+    /// Tolerate trailing commas and empty slots in lists
+    pub lenient: bool,
+    /// Parse reals into [Parameter::RawReal](crate::ast::Parameter::RawReal) with their literals
+    pub preserve_reals: bool,
+}
+
+impl ParseOptions {
+    /// Lenient options with [ParseLimits::default]
+    pub fn lenient() -> Self {
+        ParseOptions {
+            limits: ParseLimits::default(),
+            lenient: true,
+            preserve_reals: false,
+        }
+    }
+}
+
+/// Rewrite trailing commas and empty slots in lists into the strict syntax
+///
+/// Strings and comments are kept as is.
+pub(super) fn normalize_lists(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    // Last character which is not a whitespace nor in comments, with its position in `output`
+    let mut last: Option<(char, usize)> = None;
+    let mut in_string = false;
+    let mut chars = input.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => in_string = !in_string,
+            _ if in_string => {}
+            '/' if input[i + 1..].starts_with('*') => {
+                let end = match input[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 2,
+                    None => input.len(),
+                };
+                output.push_str(&input[i..end]);
+                while chars.peek().is_some_and(|(j, _)| *j < end) {
+                    chars.next();
+                }
+                continue;
+            }
+            ',' if matches!(last, Some(('(' | ',', _))) => output.push('$'),
+            ')' => {
+                if let Some((',', pos)) = last {
+                    output.remove(pos);
+                }
+            }
+            _ => {}
+        }
+        if !c.is_whitespace() || in_string {
+            last = Some((c, output.len()));
+        }
+        output.push(c);
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_lists("(1.,2.,)"), "(1.,2.)");
+        assert_eq!(normalize_lists("(,)"), "($)");
+        assert_eq!(normalize_lists("( , 1, , 2 , )"), "( $, 1, $, 2  )");
+        assert_eq!(normalize_lists("A(1, (2,),);"), "A(1, (2));");
+        // strings and comments are not changed
+        assert_eq!(normalize_lists("('(,)', 'it''s,)')"), "('(,)', 'it''s,)')");
+        assert_eq!(normalize_lists("(1, /* ,) */ )"), "(1 /* ,) */ )");
+    }
+
+    #[test]
+    fn exchange() {
+        let input = r#"
+        ISO-10303-21;
+        HEADER;
+          FILE_DESCRIPTION((''), '2;1');
+          FILE_NAME('', '', (''), (''), '', '', '');
+          FILE_SCHEMA(('TEST'));
+        ENDSEC;
+        DATA;
+          #1 = A((1., 2.,), (,), 3);
+        ENDSEC;
+        END-ISO-10303-21;
+        "#
+        .trim();
+        assert!(crate::parser::parse(input).is_err());
+
+        let ex = crate::parser::parse_with_options(input, &ParseOptions::lenient()).unwrap();
+        assert_eq!(
+            ex.data[0].entities,
+            [EntityInstance::Simple {
+                id: InstanceId(1),
+                record: Record {
+                    name: "A".to_string(),
+                    parameter: Parameter::List(vec![
+                        Parameter::List(vec![Parameter::Real(1.0), Parameter::Real(2.0)]),
+                        Parameter::List(vec![Parameter::NotProvided]),
+                        Parameter::Integer(3),
+                    ]),
+                },
+            }]
+        );
+    }
+
+    #[test]
+    fn preserve_reals() {
+        let input = r#"
+        ISO-10303-21;
+        HEADER;
+          FILE_DESCRIPTION((''), '2;1');
+          FILE_NAME('', '', (''), (''), '', '', '');
+          FILE_SCHEMA(('TEST'));
+        ENDSEC;
+        DATA;
+          #1 = A((1.50E+01, 2., -0.5), 3);
+        ENDSEC;
+        END-ISO-10303-21;
+        "#
+        .trim();
+        let options = ParseOptions {
+            preserve_reals: true,
+            ..Default::default()
+        };
+        let ex = crate::parser::parse_with_options(input, &options).unwrap();
+        let EntityInstance::Simple { record, .. } = &ex.data[0].entities[0] else {
+            panic!("Simple instance is expected");
+        };
+        let reals = record
+            .parameter
+            .get(0)
+            .and_then(Parameter::as_list)
+            .unwrap();
+        let literals: Vec<_> = reals.iter().filter_map(Parameter::real_literal).collect();
+        assert_eq!(literals, ["1.50E+01", "2.", "-0.5"]);
+        assert_eq!(reals[0].as_f64(), Some(15.0));
+        assert_eq!(record.parameter.get(1), Some(&Parameter::Integer(3)));
+
+        let ex = crate::parser::parse_with_options(input, &ParseOptions::default()).unwrap();
+        let EntityInstance::Simple { record, .. } = &ex.data[0].entities[0] else {
+            panic!("Simple instance is expected");
+        };
+        assert_eq!(
+            record.parameter.get(0).and_then(|p| p.get(0)),
+            Some(&Parameter::Real(15.0))
+        );
+    }
+}
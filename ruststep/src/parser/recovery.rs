@@ -0,0 +1,183 @@
+use super::ParseLimits;
+use crate::{
+    ast::{EntityInstance, InstanceId},
+    error::{Error, Result},
+};
+use alloc::{format, string::String, vec::Vec};
+use core::str::FromStr;
+
+/// Parse entity instances in DATA sections one by one with [ParseLimits::default], skipping broken records
+///
+/// Each statement terminated by `;` in DATA sections is parsed separately,
+/// and a failure is reported with the instance id read from its `#N` prefix.
+/// [InstanceId] `#0`, which is not a valid id, is used when the prefix is also broken.
+/// Statements out of the DATA sections, e.g. HEADER section, are ignored.
+///
+/// ```
+/// use ruststep::{ast::InstanceId, parser::parse_entity_instances_lossy};
+///
+/// let (instances, errors) = parse_entity_instances_lossy(r#"
+/// DATA;
+///   #1 = A(1.0);
+///   #2 = A(1.0, ;
+///   #3 = A(3.0);
+/// ENDSEC;
+/// "#);
+/// assert_eq!(instances.len(), 2);
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].0, InstanceId(2));
+/// ```
+pub fn parse_entity_instances_lossy(
+    input: &str,
+) -> (Vec<EntityInstance>, Vec<(InstanceId, Error)>) {
+    parse_entity_instances_lossy_with_limits(input, &ParseLimits::default())
+}
+
+/// [parse_entity_instances_lossy] with limits of nesting depth and record size
+///
+/// Records exceeding the limits are skipped and reported as errors like broken ones.
+///
+/// ```
+/// use ruststep::{ast::InstanceId, error::Error, parser::*};
+///
+/// let limits = ParseLimits { max_depth: 2, ..Default::default() };
+/// let (instances, errors) = parse_entity_instances_lossy_with_limits(
+///     "DATA; #1 = A((1.0)); #2 = A(((1.0))); ENDSEC;",
+///     &limits,
+/// );
+/// assert_eq!(instances.len(), 1);
+/// assert!(matches!(errors[..], [(InstanceId(2), Error::DepthExceeded(2))]));
+/// ```
+pub fn parse_entity_instances_lossy_with_limits(
+    input: &str,
+    limits: &ParseLimits,
+) -> (Vec<EntityInstance>, Vec<(InstanceId, Error)>) {
+    let mut instances = Vec::new();
+    let mut errors = Vec::new();
+    let mut in_data = false;
+    for (statement, terminated) in statements(input) {
+        let statement = statement.trim();
+        if !in_data {
+            in_data = is_data_begin(statement);
+            continue;
+        }
+        if statement == "ENDSEC" {
+            in_data = false;
+            continue;
+        }
+        if statement.is_empty() {
+            continue;
+        }
+        match limits
+            .check(statement)
+            .and_then(|_| parse_instance(statement, terminated))
+        {
+            Ok(instance) => instances.push(instance),
+            Err(e) => errors.push((leading_id(statement), e)),
+        }
+    }
+    (instances, errors)
+}
+
+/// An unterminated statement at the end of truncated input fails as it lacks `;`
+pub(super) fn parse_instance(statement: &str, terminated: bool) -> Result<EntityInstance> {
+    if terminated {
+        EntityInstance::from_str(&format!("{};", statement))
+    } else {
+        EntityInstance::from_str(statement)
+    }
+}
+
+pub(super) fn is_data_begin(statement: &str) -> bool {
+    statement
+        .strip_prefix("DATA")
+        .is_some_and(|rest| rest.is_empty() || rest.trim_start().starts_with('('))
+}
+
+/// Instance id of `#N` prefix, or `#0` if not found
+fn leading_id(statement: &str) -> InstanceId {
+    let digits = statement.strip_prefix('#').map(|rest| {
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        &rest[..end]
+    });
+    InstanceId(digits.and_then(|d| d.parse().ok()).unwrap_or(0))
+}
+
+/// Split into statements by `;` out of strings, with a flag if terminated by `;`
+///
+/// Comments are replaced by a space.
+fn statements(input: &str) -> Vec<(String, bool)> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+    let mut chars = input.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => in_string = !in_string,
+            _ if in_string => {}
+            '/' if input[i + 1..].starts_with('*') => {
+                let end = match input[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 2,
+                    None => input.len(),
+                };
+                while chars.peek().is_some_and(|(j, _)| *j < end) {
+                    chars.next();
+                }
+                current.push(' ');
+                continue;
+            }
+            ';' => {
+                statements.push((core::mem::take(&mut current), true));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() {
+        statements.push((current, false));
+    }
+    statements
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        assert_eq!(
+            statements("A('a;b'); /* ; */ B;C"),
+            [
+                ("A('a;b')".to_string(), true),
+                ("   B".to_string(), true),
+                ("C".to_string(), false)
+            ]
+        );
+        assert_eq!(leading_id("#12 = A()"), InstanceId(12));
+        assert_eq!(leading_id("#x = A()"), InstanceId(0));
+    }
+
+    #[test]
+    fn lossy() {
+        let (instances, errors) = parse_entity_instances_lossy(
+            r#"
+            ISO-10303-21;
+            HEADER;
+              FILE_SCHEMA(('TEST'));
+            ENDSEC;
+            DATA;
+              #1 = A(1.0);
+              #2 = A(1.0, ;
+              #3 = = A();
+              #4 = A(4.0);
+              /* truncated */ #5 = A(
+            "#,
+        );
+        assert_eq!(instances.len(), 2);
+        let ids: Vec<_> = errors.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [InstanceId(2), InstanceId(3), InstanceId(5)]);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{ast::InstanceId, error::Span};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Positions of records and lines in `input` for locating parameters
+///
+/// The input is scanned again instead of keeping positions in [crate::ast::Parameter],
+/// and thus this is used only for reporting errors by [crate::error::Error::locate].
+/// The input is scanned once while creating, and each lookup only scans the record,
+/// i.e. many errors of a large input can be located.
+pub(crate) struct Locator<'input> {
+    input: &'input str,
+    /// Position just after `#N =` of each instance
+    records: BTreeMap<u64, usize>,
+    /// Byte offsets where lines begin
+    line_starts: Vec<usize>,
+}
+
+impl<'input> Locator<'input> {
+    pub(crate) fn new(input: &'input str) -> Self {
+        let bytes = input.as_bytes();
+        let mut records = BTreeMap::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\'' => i = skip_string(bytes, i),
+                b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+                b'#' => {
+                    let digits = i + 1;
+                    i = digits;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    let rhs = skip_space(bytes, i);
+                    if i > digits && bytes.get(rhs) == Some(&b'=') {
+                        if let Ok(id) = input[digits..i].parse() {
+                            records.entry(id).or_insert(rhs + 1);
+                        }
+                    }
+                }
+                _ => i += 1,
+            }
+        }
+        let line_starts = core::iter::once(0)
+            .chain(input.match_indices('\n').map(|(k, _)| k + 1))
+            .collect();
+        Locator {
+            input,
+            records,
+            line_starts,
+        }
+    }
+
+    /// Find the parameter at `path` in the record of the entity instance `id`
+    ///
+    /// The record itself is located for an empty `path`.
+    pub(crate) fn locate(&self, id: InstanceId, path: &[usize]) -> Option<Span> {
+        let bytes = self.input.as_bytes();
+        let mut pos = skip_space(bytes, *self.records.get(&id.0)?);
+        for (depth, index) in path.iter().enumerate() {
+            pos = element(bytes, pos, *index, depth == 0)?;
+        }
+        Some(self.span(pos))
+    }
+
+    fn span(&self, offset: usize) -> Span {
+        let line = self.line_starts.partition_point(|start| *start <= offset);
+        let line_start = self.line_starts[line - 1];
+        Span {
+            offset,
+            line,
+            column: self.input[line_start..offset].chars().count() + 1,
+        }
+    }
+}
+
+/// Position of the `index`-th element of the list at `pos`
+///
+/// The list of a record, e.g. `A(1, 2)`, follows its keyword.
+/// Keywords of typed parameters are also skipped, e.g. `(1, 2)` in `B((1, 2))`.
+fn element(bytes: &[u8], pos: usize, index: usize, record: bool) -> Option<usize> {
+    let mut i = skip_space(bytes, pos);
+    if record {
+        i = skip_space(bytes, skip_keyword(bytes, i));
+    } else {
+        while bytes
+            .get(i)
+            .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, b'_' | b'!'))
+        {
+            i = skip_space(bytes, skip_keyword(bytes, i));
+            if bytes.get(i) != Some(&b'(') {
+                return None;
+            }
+            i = skip_space(bytes, i + 1);
+        }
+    }
+    if bytes.get(i) != Some(&b'(') {
+        return None;
+    }
+
+    let mut i = skip_space(bytes, i + 1);
+    let mut count = 0;
+    let mut depth = 0;
+    if bytes.get(i) == Some(&b')') {
+        return None;
+    }
+    while i < bytes.len() {
+        if count == index && depth == 0 {
+            return Some(i);
+        }
+        match bytes[i] {
+            b'\'' => i = skip_string(bytes, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+            b'(' => {
+                depth += 1;
+                i += 1;
+            }
+            b')' if depth == 0 => return None,
+            b')' => {
+                depth -= 1;
+                i += 1;
+            }
+            b',' if depth == 0 => {
+                count += 1;
+                i = skip_space(bytes, i + 1);
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn skip_keyword(bytes: &[u8], mut i: usize) -> usize {
+    while bytes
+        .get(i)
+        .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, b'_' | b'!'))
+    {
+        i += 1;
+    }
+    i
+}
+
+/// Skip whitespaces and comments
+fn skip_space(bytes: &[u8], mut i: usize) -> usize {
+    loop {
+        match bytes.get(i) {
+            Some(c) if c.is_ascii_whitespace() => i += 1,
+            Some(b'/') if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+            _ => return i,
+        }
+    }
+}
+
+/// Skip a string starting at `i`, where `''` is read as two strings
+fn skip_string(bytes: &[u8], i: usize) -> usize {
+    match bytes[i + 1..].iter().position(|c| *c == b'\'') {
+        Some(end) => i + 1 + end + 1,
+        None => bytes.len(),
+    }
+}
+
+fn skip_comment(bytes: &[u8], i: usize) -> usize {
+    match bytes[i + 2..].windows(2).position(|w| w == b"*/") {
+        Some(end) => i + 2 + end + 2,
+        None => bytes.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_parameter() {
+        let input =
+            "DATA;\n#1 = A('a,(', 2);\n#2 = B(#1, /* ( */ (3, C((4, 'x''y', 5))));\nENDSEC;";
+        let locator = Locator::new(input);
+        let locate = |id, path: &[usize]| locator.locate(InstanceId(id), path);
+        let at = |id, path: &[usize]| {
+            let span = locate(id, path).unwrap();
+            (&input[span.offset..span.offset + 3], span.line, span.column)
+        };
+        assert_eq!(at(1, &[]), ("A('", 2, 6));
+        assert_eq!(at(1, &[1]), ("2);", 2, 15));
+        assert_eq!(at(2, &[0]), ("#1,", 3, 8));
+        assert_eq!(at(2, &[1, 0]), ("3, ", 3, 21));
+        assert_eq!(at(2, &[1, 1, 1]), ("'x'", 3, 30));
+        assert_eq!(at(2, &[1, 1, 2]), ("5))", 3, 38));
+
+        assert!(locate(3, &[]).is_none());
+        assert!(locate(1, &[2]).is_none());
+        assert!(locate(1, &[0, 0]).is_none());
+    }
+}
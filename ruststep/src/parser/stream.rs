@@ -0,0 +1,304 @@
+use super::{recovery::*, ParseLimits};
+use crate::{
+    ast::EntityInstance,
+    error::{Error, Result},
+};
+use std::io::{self, BufRead};
+
+/// Iterator reading entity instances in DATA sections from [BufRead] one by one
+///
+/// The input is split into statements terminated by `;` out of strings and comments,
+/// and each statement in DATA sections is parsed separately,
+/// i.e. only a statement is held in memory instead of the entire exchange structure.
+/// Statements out of the DATA sections, e.g. HEADER section, are skipped.
+///
+/// A statement failed to parse is reported as `Err` and the iteration continues,
+/// while it stops after an I/O error. Each statement is checked by [ParseLimits].
+///
+/// ```
+/// use ruststep::{ast::EntityInstance, parser::EntityInstanceReader};
+///
+/// let input = r#"
+/// ISO-10303-21;
+/// HEADER;
+///   FILE_SCHEMA(('TEST'));
+/// ENDSEC;
+/// DATA;
+///   #1 = A(1.0);
+///   #2 = B(#1,
+///          'spanning; lines');
+/// ENDSEC;
+/// END-ISO-10303-21;
+/// "#;
+/// for instance in EntityInstanceReader::new(input.as_bytes()) {
+///     match instance.unwrap() {
+///         EntityInstance::Simple { id, record } => println!("{} = {}", id, record.name),
+///         EntityInstance::Complex { id, .. } => println!("{} is complex", id),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct EntityInstanceReader<R> {
+    reader: R,
+    limits: ParseLimits,
+    in_data: bool,
+    /// Stopped by EOF or an I/O error
+    done: bool,
+}
+
+impl<R: BufRead> EntityInstanceReader<R> {
+    /// Read with [ParseLimits::default]
+    pub fn new(reader: R) -> Self {
+        Self::with_limits(reader, &ParseLimits::default())
+    }
+
+    pub fn with_limits(reader: R, limits: &ParseLimits) -> Self {
+        EntityInstanceReader {
+            reader,
+            limits: *limits,
+            in_data: false,
+            done: false,
+        }
+    }
+
+    /// Read the next statement with a flag if terminated by `;`, or `None` at EOF
+    ///
+    /// Comments are replaced by a space as [parse_entity_instances_lossy] does.
+    /// Bytes exceeding [ParseLimits::max_record_size] are counted but not kept.
+    fn next_statement(&mut self) -> io::Result<Option<(Statement, bool)>> {
+        let mut statement = Statement::default();
+        let mut state = State::Normal;
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                self.done = true;
+                return Ok((!statement.is_blank()).then_some((statement, false)));
+            }
+            let mut consumed = 0;
+            let mut terminated = false;
+            for &b in buf {
+                consumed += 1;
+                state = match state {
+                    State::Normal | State::Slash if b == b';' => {
+                        terminated = true;
+                        break;
+                    }
+                    State::Slash if b == b'*' => {
+                        statement.pop();
+                        statement.push(b' ', &self.limits);
+                        State::Comment
+                    }
+                    State::Normal | State::Slash => {
+                        statement.push(b, &self.limits);
+                        match b {
+                            b'\'' => State::String,
+                            b'/' => State::Slash,
+                            _ => State::Normal,
+                        }
+                    }
+                    State::String => {
+                        statement.push(b, &self.limits);
+                        if b == b'\'' {
+                            State::Normal
+                        } else {
+                            State::String
+                        }
+                    }
+                    State::Comment | State::CommentStar if b == b'*' => State::CommentStar,
+                    State::CommentStar if b == b'/' => State::Normal,
+                    State::Comment | State::CommentStar => State::Comment,
+                };
+            }
+            self.reader.consume(consumed);
+            if terminated {
+                return Ok(Some((statement, true)));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for EntityInstanceReader<R> {
+    type Item = Result<EntityInstance>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let (statement, terminated) = match self.next_statement() {
+                Ok(Some(statement)) => statement,
+                Ok(None) => return None,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            };
+            if statement.size > self.limits.max_record_size {
+                if self.in_data {
+                    return Some(Err(Error::RecordTooLarge {
+                        size: statement.size,
+                        limit: self.limits.max_record_size,
+                    }));
+                }
+                continue;
+            }
+            let statement = match String::from_utf8(statement.bytes) {
+                Ok(statement) => statement,
+                Err(e) => {
+                    return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e).into()));
+                }
+            };
+            let statement = statement.trim();
+            if !self.in_data {
+                self.in_data = is_data_begin(statement);
+                continue;
+            }
+            if statement == "ENDSEC" {
+                self.in_data = false;
+                continue;
+            }
+            if statement.is_empty() {
+                continue;
+            }
+            return Some(
+                self.limits
+                    .check(statement)
+                    .and_then(|_| parse_instance(statement, terminated)),
+            );
+        }
+        None
+    }
+}
+
+/// Scanner state for splitting statements
+#[derive(Clone, Copy)]
+enum State {
+    Normal,
+    /// Just after `/` which may begin a comment
+    Slash,
+    String,
+    Comment,
+    /// Just after `*` in a comment which may end it
+    CommentStar,
+}
+
+#[derive(Default)]
+struct Statement {
+    bytes: Vec<u8>,
+    /// Size including bytes dropped for exceeding the limit
+    size: usize,
+}
+
+impl Statement {
+    fn push(&mut self, b: u8, limits: &ParseLimits) {
+        self.size += 1;
+        if self.size <= limits.max_record_size {
+            self.bytes.push(b);
+        }
+    }
+
+    fn pop(&mut self) {
+        self.size -= 1;
+        self.bytes.pop();
+    }
+
+    fn is_blank(&self) -> bool {
+        self.bytes.iter().all(|b| b.is_ascii_whitespace())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION(('a; b'), '2;1');
+  FILE_SCHEMA(('TEST'));
+ENDSEC;
+DATA;
+  #1 = A(1.0);
+  #2 = B(#1, /* comment; with semicolon */
+         'string; with semicolon');
+  #3 = (A(2.0) C());
+  #4 = B(#1, ;
+  #5 = C(
+  );
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+    fn keywords(instance: &EntityInstance) -> (u64, Vec<&str>) {
+        match instance {
+            EntityInstance::Simple { id, record } => (id.0, vec![record.name.as_str()]),
+            EntityInstance::Complex { id, subsuper } => (
+                id.0,
+                subsuper
+                    .0
+                    .iter()
+                    .map(|record| record.name.as_str())
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn read_instances() {
+        // Small buffer to split statements and comments across reads
+        for capacity in [1, 2, 3, 7, 1024] {
+            let reader = BufReader::with_capacity(capacity, EXAMPLE.as_bytes());
+            let instances: Vec<_> = EntityInstanceReader::new(reader).collect();
+            assert_eq!(instances.len(), 5);
+            assert!(instances[3].is_err());
+            let instances: Vec<_> = instances.into_iter().filter_map(|i| i.ok()).collect();
+            let ids: Vec<_> = instances.iter().map(keywords).collect();
+            assert_eq!(
+                ids,
+                [
+                    (1, vec!["A"]),
+                    (2, vec!["B"]),
+                    (3, vec!["A", "C"]),
+                    (5, vec!["C"])
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn same_as_parse() {
+        let step_file = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/steps/00000050_80d90bfdd2e74e709956122a_step_000.step");
+        let step_str = std::fs::read_to_string(&step_file).unwrap();
+        let exchange = crate::parser::parse(&step_str).unwrap();
+        let file = std::fs::File::open(&step_file).unwrap();
+        let instances: Vec<_> = EntityInstanceReader::new(BufReader::new(file))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(instances, exchange.data[0].entities);
+    }
+
+    #[test]
+    fn limits() {
+        let limits = ParseLimits {
+            max_depth: 2,
+            max_record_size: 16,
+        };
+        let input = "DATA; #1 = A('a very long string'); #2 = A(((1))); #3 = A(1); ENDSEC;";
+        let instances: Vec<_> =
+            EntityInstanceReader::with_limits(input.as_bytes(), &limits).collect();
+        assert!(matches!(
+            instances[0],
+            Err(Error::RecordTooLarge { limit: 16, .. })
+        ));
+        assert!(matches!(instances[1], Err(Error::DepthExceeded(2))));
+        assert_eq!(keywords(instances[2].as_ref().unwrap()), (3, vec!["A"]));
+    }
+
+    #[test]
+    fn unterminated() {
+        let instances: Vec<_> =
+            EntityInstanceReader::new("DATA; #1 = A(1.0); #2 = A(".as_bytes()).collect();
+        assert_eq!(instances.len(), 2);
+        assert!(instances[0].is_ok());
+        assert!(instances[1].is_err());
+    }
+}
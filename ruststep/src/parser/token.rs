@@ -4,12 +4,13 @@ use crate::{
     ast::*,
     parser::{basic::*, combinator::*},
 };
+use alloc::{format, string::String, vec, vec::Vec};
 use nom::bytes::complete::tag;
-use nom::combinator::map;
+use nom::combinator::{map, map_res};
 use nom::{
     branch::alt,
     character::complete::{char, digit0, digit1, multispace0, none_of, satisfy},
-    combinator::opt,
+    combinator::{map_opt, opt},
     multi::{many0, many1},
     sequence::tuple,
     Parser,
@@ -21,63 +22,179 @@ pub fn sign(input: &str) -> ParseResult<char> {
 }
 
 /// integer = \[ [sign] \] [digit] { [digit] } .
+///
+/// Fails if the absolute value does not fit into `i64`.
 pub fn integer(input: &str) -> ParseResult<i64> {
-    tuple((opt(sign), multispace0, digit1))
-        .map(|(sign, _space, numbers)| {
-            let num: i64 = numbers.parse().expect("Failed to parse into integer");
-            match sign {
+    map_res(
+        tuple((opt(sign), multispace0, digit1)),
+        |(sign, _space, numbers): (_, _, &str)| {
+            let num: i64 = numbers.parse()?;
+            Ok::<_, core::num::ParseIntError>(match sign {
                 Some('-') => -num,
                 _ => num,
-            }
-        })
-        .parse(input)
+            })
+        },
+    )
+    .parse(input)
 }
 
 /// `E` \[ [sign] \] [digit] { [digit] } .
+///
+/// Fails if the absolute value does not fit into `i64`.
 fn exponent(input: &str) -> ParseResult<i64> {
-    tuple((char('E'), multispace0, opt(sign), multispace0, digit1))
-        .map(|(_e, _sp1, sign, _sp2, digit)| {
-            let num: i64 = digit.parse().expect("Failed to parse integer in exponent");
-            match sign {
+    map_res(
+        tuple((char('E'), multispace0, opt(sign), multispace0, digit1)),
+        |(_e, _sp1, sign, _sp2, digit): (_, _, _, _, &str)| {
+            let num: i64 = digit.parse()?;
+            Ok::<_, core::num::ParseIntError>(match sign {
                 Some('-') => -num,
                 _ => num,
-            }
-        })
-        .parse(input)
+            })
+        },
+    )
+    .parse(input)
 }
 
 /// real = \[ [sign] \] [digit] { [digit] } `.` { [digit] } \[ `E` \[ [sign] \] [digit] { [digit] } \] .
 pub fn real(input: &str) -> ParseResult<f64> {
-    tuple((
-        opt(sign),
-        multispace0,
-        digit1,
-        char('.'),
-        digit0,
-        opt(exponent),
-    ))
-    .map(|(sign, _space, integral, _point, fractional, exp)| {
-        let num: f64 = format!("{}.{}e{}", integral, fractional, exp.unwrap_or(0))
-            .parse()
-            .expect("Failed to parse Float");
-        match sign {
-            Some('-') => -num,
-            _ => num,
-        }
-    })
+    map_res(
+        tuple((
+            opt(sign),
+            multispace0,
+            digit1,
+            char('.'),
+            digit0,
+            opt(exponent),
+        )),
+        |(sign, _space, integral, _point, fractional, exp)| {
+            let num: f64 = format!("{}.{}e{}", integral, fractional, exp.unwrap_or(0)).parse()?;
+            Ok::<_, core::num::ParseFloatError>(match sign {
+                Some('-') => -num,
+                _ => num,
+            })
+        },
+    )
     .parse(input)
 }
 
 /// string = `'` { [special] | [digit] | [space] | [lower] | [upper] | high_codepoint | [apostrophe] [apostrophe] | [reverse_solidus] [reverse_solidus] | control_directive } `'` .
+///
+/// Control directives, e.g. `\X2\00E9\X0\` for `é`, are decoded,
+/// and line breaks in the literal are ignored.
 pub fn string(input: &str) -> ParseResult<String> {
     let escaped_char = map(tag("''"), |_| '\''); // Parse '' as a single '
     let normal_char = none_of("'"); // Parse any character except '
 
     let string_content = many0(escaped_char.or(normal_char.map(|c| c)));
 
-    tuple((char('\''), string_content, char('\'')))
-        .map(|(_start, s, _end)| s.iter().collect())
-        .parse(input)
+    map_opt(
+        tuple((char('\''), string_content, char('\''))),
+        |(_start, s, _end)| {
+            let raw: String = s.iter().filter(|c| !matches!(c, '\r' | '\n')).collect();
+            decode_string(&raw)
+        },
+    )
+    .parse(input)
+}
+
+/// Decode control directives in a string literal
+///
+/// - `\\` is a reverse solidus `\`
+/// - `\S\c` is a character of ISO 8859-1 whose code is that of `c` plus 128
+/// - `\X\hh` is a character of ISO 8859-1 by two hex digits
+/// - `\X2\ hhhh ... \X0\` is a sequence of UTF-16 code units by four hex digits
+/// - `\X4\ hhhhhhhh ... \X0\` is a sequence of code points by eight hex digits
+/// - `\Pc\` selecting a part of ISO 8859 is ignored, and ISO 8859-1 is always used
+///
+/// Other reverse solidus are kept as is. `None` if a directive is broken.
+fn decode_string(raw: &str) -> Option<String> {
+    let mut decoded = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(pos) = rest.find('\\') {
+        decoded.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if let Some(r) = rest.strip_prefix("\\\\") {
+            decoded.push('\\');
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("\\X2\\") {
+            let end = r.find("\\X0\\")?;
+            let units = hex_chunks(&r[..end], 4)?
+                .into_iter()
+                .map(|unit| unit as u16);
+            for c in char::decode_utf16(units) {
+                decoded.push(c.ok()?);
+            }
+            rest = &r[end + 4..];
+        } else if let Some(r) = rest.strip_prefix("\\X4\\") {
+            let end = r.find("\\X0\\")?;
+            for code in hex_chunks(&r[..end], 8)? {
+                decoded.push(char::from_u32(code)?);
+            }
+            rest = &r[end + 4..];
+        } else if let Some(r) = rest.strip_prefix("\\X\\") {
+            let code = hex_chunks(r.get(..2)?, 2)?[0];
+            decoded.push(char::from_u32(code)?);
+            rest = &r[2..];
+        } else if let Some(r) = rest.strip_prefix("\\S\\") {
+            let c = r.chars().next().filter(char::is_ascii)?;
+            decoded.push(char::from_u32(c as u32 + 128)?);
+            rest = &r[1..];
+        } else if let Some(r) = rest
+            .strip_prefix("\\P")
+            .and_then(|r| r.get(1..)?.strip_prefix('\\'))
+        {
+            rest = r;
+        } else {
+            decoded.push('\\');
+            rest = &rest[1..];
+        }
+    }
+    decoded.push_str(rest);
+    Some(decoded)
+}
+
+/// Split into numbers of `digits` hex digits, e.g. `00E9` into `[0xE9]` for 4 digits
+fn hex_chunks(hex: &str, digits: usize) -> Option<Vec<u32>> {
+    if hex.is_empty() || hex.len() % digits != 0 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(digits)
+        .map(|i| u32::from_str_radix(&hex[i..i + digits], 16).ok())
+        .collect()
+}
+
+/// binary = `"` ( `0` | `1` | `2` | `3` ) { hex } `"` .
+///
+/// Returns the bytes right-aligned and the number of unused leading bits in the first byte.
+/// The unused bits of the first hex digit must be zero, e.g. `"3F"` is invalid.
+pub fn binary(input: &str) -> ParseResult<'_, (Vec<u8>, u8)> {
+    map_opt(
+        tuple((
+            char('"'),
+            satisfy(|c| matches!(c, '0'..='3')),
+            many0(satisfy(|c| matches!(c, '0'..='9' | 'A'..='F'))),
+            char('"'),
+        )),
+        |(_open, pad, hex, _close)| decode_binary(pad as u8 - b'0', &hex),
+    )
+    .parse(input)
+}
+
+fn decode_binary(pad: u8, hex: &[char]) -> Option<(Vec<u8>, u8)> {
+    let first = hex.first().map_or(0, |c| c.to_digit(16).unwrap());
+    if (hex.is_empty() && pad > 0) || first >> (4 - pad) != 0 {
+        return None;
+    }
+    // Prepend a zero digit to fill bytes for odd number of digits
+    let odd = hex.len() % 2 == 1;
+    let digits: Vec<u8> = odd
+        .then_some(0)
+        .into_iter()
+        .chain(hex.iter().map(|c| c.to_digit(16).unwrap() as u8))
+        .collect();
+    let bytes = digits.chunks(2).map(|d| d[0] << 4 | d[1]).collect();
+    Some((bytes, pad + if odd { 4 } else { 0 }))
 }
 
 /// resource = `<` UNIVERSAL_RESOURCE_IDENTIFIER `>` .
@@ -164,7 +281,7 @@ pub fn constant_value_name(input: &str) -> ParseResult<String> {
 /// lhs_occurrence_name = ( [entity_instance_name] | [value_instance_name] ) .
 pub fn lhs_occurrence_name(input: &str) -> ParseResult<Name> {
     alt((
-        entity_instance_name.map(Name::Entity),
+        entity_instance_name.map(|id| Name::Entity(InstanceId(id))),
         value_instance_name.map(Name::Value),
     ))
     .parse(input)
@@ -173,7 +290,7 @@ pub fn lhs_occurrence_name(input: &str) -> ParseResult<Name> {
 /// rhs_occurrence_name = ( [entity_instance_name] | [value_instance_name] | [constant_entity_name] | [constant_value_name]) .
 pub fn rhs_occurrence_name(input: &str) -> ParseResult<Name> {
     alt((
-        entity_instance_name.map(Name::Entity),
+        entity_instance_name.map(|id| Name::Entity(InstanceId(id))),
         value_instance_name.map(Name::Value),
         constant_entity_name.map(Name::ConstantEntity),
         constant_value_name.map(Name::ConstantValue),
@@ -259,6 +376,26 @@ mod tests {
         assert!(super::real("123").finish().is_err());
     }
 
+    #[test]
+    fn overflow() {
+        use crate::parser::{parse_parameter, ParseLimits};
+
+        assert!(super::integer("99999999999999999999").finish().is_err());
+        assert!(super::exponent("E99999999999999999999").finish().is_err());
+        let limits = ParseLimits::default();
+        for input in [
+            "99999999999999999999",
+            "1.E99999999999999999999",
+            "-9223372036854775808",
+        ] {
+            assert!(parse_parameter(input, &limits).is_err(), "{}", input);
+        }
+        assert_eq!(
+            parse_parameter("-9223372036854775807", &limits).unwrap(),
+            crate::ast::Parameter::Integer(-i64::MAX)
+        );
+    }
+
     #[test]
     fn string() {
         let (res, s) = super::string("'vim'").finish().unwrap();
@@ -273,6 +410,76 @@ mod tests {
         assert_eq!(s, "vim's");
     }
 
+    #[test]
+    fn encoded_string() {
+        let decode = |input: &str| super::string(input).finish().ok().map(|(_res, s)| s);
+        assert_eq!(decode(r"'It''s'").unwrap(), "It's");
+        assert_eq!(decode(r"'caf\X\E9'").unwrap(), "café");
+        assert_eq!(decode(r"'caf\X2\00E9\X0\'").unwrap(), "café");
+        assert_eq!(decode(r"'\X2\65E5672C\X0\'").unwrap(), "日本");
+        assert_eq!(decode(r"'\X2\D83DDE00\X0\'").unwrap(), "😀");
+        assert_eq!(decode(r"'\X4\0001F600\X0\'").unwrap(), "😀");
+        assert_eq!(decode(r"'\PA\caf\S\i'").unwrap(), "café");
+        assert_eq!(decode(r"'C:\\dir\file'").unwrap(), r"C:\dir\file");
+        // Line breaks are not a part of strings
+        assert_eq!(decode("'long\n string'").unwrap(), "long string");
+
+        assert!(decode(r"'\X2\00E\X0\'").is_none());
+        assert!(decode(r"'\X2\00E9'").is_none());
+        assert!(decode(r"'\X2\D83D\X0\'").is_none());
+    }
+
+    #[test]
+    fn string_round_trip() {
+        for s in ["It's", r"C:\dir", "café 日本 😀", "a\nb", ""] {
+            let literal = crate::ast::Parameter::String(s.to_string()).to_string();
+            assert!(literal.is_ascii());
+            let (res, decoded) = super::string(&literal).finish().unwrap();
+            assert_eq!(res, "");
+            assert_eq!(decoded, s);
+        }
+    }
+
+    #[test]
+    fn binary() {
+        for (input, bytes, unused) in [
+            // byte-aligned
+            (r#""0FF""#, vec![0xFF], 0),
+            (r#""00F0A""#, vec![0x0F, 0x0A], 0),
+            // not byte-aligned
+            (r#""315""#, vec![0b10101], 3),
+            (r#""23""#, vec![0b11], 6),
+            (r#""0ABC""#, vec![0x0A, 0xBC], 4),
+            (r#""0""#, vec![], 0),
+        ] {
+            let (res, (b, u)) = super::binary(input).finish().unwrap();
+            assert_eq!(res, "");
+            assert_eq!((b, u), (bytes.clone(), unused));
+
+            let p = crate::ast::Parameter::Binary {
+                bytes,
+                unused_bits: unused,
+            };
+            assert_eq!(p.to_string(), input);
+        }
+        // unused bits must be zero
+        assert!(super::binary(r#""3F""#).finish().is_err());
+        assert!(super::binary(r#""1""#).finish().is_err());
+        assert!(super::binary(r#""4F""#).finish().is_err());
+        assert!(super::binary(r#""0ff""#).finish().is_err());
+    }
+
+    #[test]
+    fn enumeration() {
+        for (input, expected) in [(".LEVEL_1.", "LEVEL_1"), (".MODE3.", "MODE3"), (".T.", "T")] {
+            let (res, s) = super::enumeration(input).finish().unwrap();
+            assert_eq!(res, "");
+            assert_eq!(s, expected);
+        }
+        // must start with an upper case letter or underscore
+        assert!(super::enumeration(".3D.").finish().is_err());
+    }
+
     #[test]
     fn instance_name() {
         let (res, s) = super::entity_instance_name("#18446744073709551615" /* u64::MAX */)
@@ -0,0 +1,22 @@
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+/// Derived value `*`, which is redeclared as a derived attribute in a subtype
+///
+/// Both `$` and `*` are deserialized into `None` for `Option<T>`,
+/// while this accepts only `*` to distinguish a derived value from an unset optional value.
+///
+/// ```
+/// use ruststep::{ast::Parameter, primitive::Derived};
+/// use serde::Deserialize;
+///
+/// assert_eq!(Derived::deserialize(&Parameter::Omitted).unwrap(), Derived);
+/// assert!(Derived::deserialize(&Parameter::NotProvided).is_err());
+///
+/// // `$` and `*` are not distinguished by `Option<T>`
+/// assert_eq!(Option::<f64>::deserialize(&Parameter::Omitted).unwrap(), None);
+/// assert_eq!(Option::<f64>::deserialize(&Parameter::NotProvided).unwrap(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub struct Derived;
@@ -1,5 +1,8 @@
+#[cfg(feature = "serde")]
+use alloc::string::String;
+use core::ops::*;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::ops::*;
 
 /// `LOGICAL` type
 ///
@@ -56,7 +59,8 @@ use std::ops::*;
 /// assert_eq!(Logical::Unknown ^ Logical::Unknown, Logical::Unknown);
 /// assert_eq!(Logical::Unknown ^ Logical::False, Logical::Unknown);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub enum Logical {
     False,
     Unknown,
@@ -69,8 +73,8 @@ impl Default for Logical {
     }
 }
 
-impl std::fmt::Display for Logical {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Display for Logical {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         match self {
             Logical::True => f.pad("true"),
             Logical::Unknown => f.pad("unknown"),
@@ -154,6 +158,7 @@ impl Not for Logical {
     }
 }
 
+#[cfg(feature = "serde")]
 enum SubLogical {
     T,
     True,
@@ -163,13 +168,16 @@ enum SubLogical {
     Unknown,
 }
 
+#[cfg(feature = "serde")]
 struct SubVisitor;
 
+#[cfg(feature = "serde")]
 const VARIANTS: &[&str] = &["T", "True", "F", "False", "U", "Unknown"];
 
+#[cfg(feature = "serde")]
 impl<'de> serde::de::Visitor<'de> for SubVisitor {
     type Value = SubLogical;
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(formatter, "Logical")
     }
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -202,6 +210,7 @@ impl<'de> serde::de::Visitor<'de> for SubVisitor {
     }
 }
 
+#[cfg(feature = "serde")]
 impl From<SubLogical> for Logical {
     fn from(value: SubLogical) -> Self {
         match value {
@@ -215,6 +224,7 @@ impl From<SubLogical> for Logical {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for SubLogical {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -224,12 +234,14 @@ impl<'de> Deserialize<'de> for SubLogical {
     }
 }
 
+#[cfg(feature = "serde")]
 #[derive(Clone, Debug)]
 struct Visitor;
 
+#[cfg(feature = "serde")]
 impl<'de> serde::de::Visitor<'de> for Visitor {
     type Value = Logical;
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(formatter, "enum Logical")
     }
     fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
@@ -240,6 +252,7 @@ impl<'de> serde::de::Visitor<'de> for Visitor {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Logical {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
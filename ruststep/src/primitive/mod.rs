@@ -1,4 +1,10 @@
 //! Primitive types appears in STEP and not defined in Rust
 
+mod derived;
 mod logical;
+mod number;
+mod real;
+pub use derived::*;
 pub use logical::*;
+pub use number::*;
+pub use real::*;
@@ -0,0 +1,115 @@
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Serialize};
+
+/// `NUMBER` type, which holds either an `INTEGER` or a `REAL`
+///
+/// ```
+/// use ruststep::{ast::Parameter, primitive::Number};
+/// use serde::Deserialize;
+///
+/// let p = Parameter::Integer(2);
+/// assert_eq!(Number::deserialize(&p).unwrap(), Number::Int(2));
+///
+/// let p = Parameter::Real(2.5);
+/// assert_eq!(Number::deserialize(&p).unwrap(), Number::Real(2.5));
+///
+/// // Integer-ness is kept, and can be converted into f64 when it does not matter
+/// assert_eq!(Number::Int(2).to_f64(), 2.0);
+/// assert_eq!(Number::from(3), Number::Int(3));
+/// assert_eq!(Number::from(3.0), Number::Real(3.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Number {
+    Int(i64),
+    Real(f64),
+}
+
+impl Number {
+    /// Convert into `f64`, which may lose precision for large integers
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Number::Int(i) => i as f64,
+            Number::Real(x) => x,
+        }
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Number::Int(0)
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Number::Int(i) => fmt::Display::fmt(i, f),
+            Number::Real(x) => fmt::Display::fmt(x, f),
+        }
+    }
+}
+
+impl From<i64> for Number {
+    fn from(i: i64) -> Self {
+        Number::Int(i)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(x: f64) -> Self {
+        Number::Real(x)
+    }
+}
+
+impl From<Number> for f64 {
+    fn from(n: Number) -> f64 {
+        n.to_f64()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Number {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Number::Int(i) => serializer.serialize_i64(*i),
+            Number::Real(x) => serializer.serialize_f64(*x),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct Visitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for Visitor {
+    type Value = Number;
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "integer or real")
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Number, E> {
+        Ok(Number::Int(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Number, E> {
+        match i64::try_from(v) {
+            Ok(i) => Ok(Number::Int(i)),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Number, E> {
+        Ok(Number::Real(v))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Number {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Visitor)
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Serialize};
+
+/// Name of the newtype struct passing the literal of [Real] through serde
+///
+/// The deserializer of [Parameter](crate::ast::Parameter) visits the literal of
+/// [Parameter::RawReal](crate::ast::Parameter::RawReal) as a newtype struct of this name,
+/// and the serializer of [to_record](crate::ast::ser::to_record) writes it back.
+#[cfg(feature = "serde")]
+pub(crate) const REAL_LITERAL: &str = "$ruststep::primitive::Real";
+
+/// `REAL` value keeping its literal in the exchange structure, e.g. `1.50E+00`
+///
+/// Use this instead of `f64` in holders to write reals parsed with
+/// [ParseOptions::preserve_reals](crate::parser::ParseOptions::preserve_reals) back as they are.
+/// Values without literals, e.g. parsed without the option or created in Rust, are written as `f64`.
+///
+/// ```
+/// use ruststep::{ast::Parameter, primitive::Real};
+/// use serde::Deserialize;
+///
+/// let p = Parameter::RawReal { value: 1.5, raw: "1.50E+00".to_string() };
+/// let x = Real::deserialize(&p).unwrap();
+/// assert_eq!(x.value, 1.5);
+/// assert_eq!(x.to_string(), "1.50E+00");
+///
+/// let x = Real::deserialize(&Parameter::Real(1.5)).unwrap();
+/// assert_eq!(x, Real::from(1.5));
+/// assert_eq!(x.raw, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, PartialOrd, Default)]
+pub struct Real {
+    pub value: f64,
+    /// Literal in the exchange structure, if parsed with it
+    pub raw: Option<String>,
+}
+
+impl From<f64> for Real {
+    fn from(value: f64) -> Self {
+        Real { value, raw: None }
+    }
+}
+
+impl From<Real> for f64 {
+    fn from(x: Real) -> f64 {
+        x.value
+    }
+}
+
+impl fmt::Display for Real {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.raw {
+            Some(raw) => f.write_str(raw),
+            None => fmt::Display::fmt(&self.value, f),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Real {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.raw {
+            Some(raw) => serializer.serialize_newtype_struct(REAL_LITERAL, raw.as_str()),
+            None => serializer.serialize_f64(self.value),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Real {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(REAL_LITERAL, RealVisitor { literal: false })
+    }
+}
+
+/// Visitor of reals, which also accepts their literals in the newtype struct of [REAL_LITERAL]
+#[cfg(feature = "serde")]
+struct RealVisitor {
+    literal: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for RealVisitor {
+    type Value = Real;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a real or an integer")
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Real, E> {
+        Ok(Real::from(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Real, E> {
+        Ok(Real::from(value as f64))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Real, E> {
+        Ok(Real::from(value as f64))
+    }
+
+    fn visit_str<E: de::Error>(self, raw: &str) -> Result<Real, E> {
+        if !self.literal {
+            return Err(de::Error::invalid_type(de::Unexpected::Str(raw), &self));
+        }
+        let value = raw
+            .parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(raw), &self))?;
+        Ok(Real {
+            value,
+            raw: Some(raw.into()),
+        })
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Real, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(RealVisitor { literal: true })
+    }
+}
@@ -102,17 +102,37 @@
 //!
 
 use crate::{ast::*, error::*};
+#[cfg(feature = "serde")]
 use serde::{
     de::{self, IntoDeserializer, VariantAccess},
     Deserialize,
 };
-use std::{collections::HashMap, fmt, marker::PhantomData};
+#[cfg(feature = "serde")]
+use std::marker::PhantomData;
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    rc::Rc,
+};
+
+pub use crate::ast::InstanceId;
 
 /// Trait for resolving a reference through entity id
 pub trait IntoOwned: Clone + 'static {
     type Owned;
     type Table;
     fn into_owned(self, table: &Self::Table) -> Result<Self::Owned>;
+
+    /// Compare by values after resolving references,
+    /// while derived `PartialEq` of holders compares references by instance id
+    fn structural_eq(&self, other: &Self, table: &Self::Table) -> Result<bool>
+    where
+        Self::Owned: PartialEq,
+    {
+        Ok(self.clone().into_owned(table)? == other.clone().into_owned(table)?)
+    }
 }
 
 impl<T: IntoOwned> IntoOwned for Vec<T> {
@@ -125,28 +145,355 @@ impl<T: IntoOwned> IntoOwned for Vec<T> {
 
 /// Trait for a field of tables
 pub trait Holder: IntoOwned {
+    /// Keyword in exchange structure, e.g. `CARTESIAN_POINT`
     fn name() -> &'static str;
+    /// Name of the EXPRESS type declaring this, e.g. `cartesian_point`
+    fn type_name() -> &'static str;
     fn attr_len() -> usize;
+
+    /// True if an entity instance of `keyword`, e.g. `POINT`, can be held,
+    /// overridden by `SELECT` types and `XxxAny` enums to accept their members
+    fn accepts(keyword: &str) -> bool {
+        keyword == Self::name()
+    }
+
+    /// Push ids of entities referred by this holder, including those in inline owned values,
+    /// generated by `#[derive(Holder)]` for holders with references
+    fn collect_refs(&self, _refs: &mut Vec<InstanceId>) {}
+
+    /// Push keywords of partial entities composing a complex entity instance of this holder,
+    /// i.e. this and all its supertypes, overridden by `#[derive(Holder)]` for subtypes
+    fn complex_keywords(keywords: &mut Vec<&'static str>) {
+        keywords.push(Self::name());
+    }
+
+    /// Parameters of fields collected from partial records of a complex entity instance,
+    /// where supertype fields are typed parameters, e.g. `BASE((1.0))` for `(BASE(1.0) SUB(2.0))`
+    fn complex_parameters(records: &[Record]) -> Result<Vec<Parameter>> {
+        partial_parameters(records, Self::name())
+    }
+}
+
+/// Parameters of the partial record named `name` in a complex entity instance,
+/// see [Holder::complex_parameters]
+pub fn partial_parameters(records: &[Record], name: &str) -> Result<Vec<Parameter>> {
+    let record = records
+        .iter()
+        .find(|record| record.name == name)
+        .ok_or_else(|| Error::DeserializeFailed(format!("Partial entity {} is not found", name)))?;
+    match &record.parameter {
+        Parameter::List(parameters) => Ok(parameters.clone()),
+        parameter => Ok(vec![parameter.clone()]),
+    }
+}
+
+/// Helper function to implement TableInit trait,
+/// select tables holding a complex entity instance `#id = subsuper`
+///
+/// `complex_keywords[i]` is [Holder::complex_keywords] of the holder of the `i`-th table.
+/// The instance is held in the tables of the most specific entities
+/// whose keywords with their supertypes are all in the instance.
+/// These are usually a subtype, e.g. `SUB` for `(BASE(1.0) SUB(2.0))`,
+/// or sibling subtypes combined by `AND`, e.g. `CONVERSION_BASED_UNIT` and `LENGTH_UNIT`
+/// for `(CONVERSION_BASED_UNIT(...) LENGTH_UNIT() NAMED_UNIT(*))`.
+///
+/// [Error::UnknownComplexEntity] is returned if they do not cover all partial records,
+/// or do not share supertypes.
+pub fn select_complex_tables(
+    id: InstanceId,
+    subsuper: &SubSuperRecord,
+    complex_keywords: &[fn(&mut Vec<&'static str>)],
+) -> Result<Vec<bool>> {
+    let mut instance: Vec<&str> = subsuper
+        .0
+        .iter()
+        .map(|record| record.name.as_str())
+        .collect();
+    instance.sort_unstable();
+    let candidates: Vec<Option<Vec<&'static str>>> = complex_keywords
+        .iter()
+        .map(|push| {
+            let mut keywords = Vec::new();
+            push(&mut keywords);
+            keywords.sort_unstable();
+            keywords.dedup();
+            keywords
+                .iter()
+                .all(|keyword| instance.binary_search(keyword).is_ok())
+                .then_some(keywords)
+        })
+        .collect();
+    let is_subset = |a: &[&str], b: &[&str]| a.iter().all(|keyword| b.contains(keyword));
+    let selected: Vec<bool> = candidates
+        .iter()
+        .map(|candidate| match candidate {
+            Some(keywords) => !candidates
+                .iter()
+                .flatten()
+                .any(|other| other.len() > keywords.len() && is_subset(keywords, other)),
+            None => false,
+        })
+        .collect();
+    let selected_keywords: Vec<&[&str]> = candidates
+        .iter()
+        .zip(&selected)
+        .filter_map(|(candidate, selected)| candidate.as_deref().filter(|_| *selected))
+        .collect();
+
+    // Selected entities connected by sharing supertypes
+    let mut connected: Vec<&[&str]> = selected_keywords.iter().take(1).copied().collect();
+    let mut rest: Vec<&[&str]> = selected_keywords.iter().skip(1).copied().collect();
+    while let Some(index) = rest.iter().position(|keywords| {
+        connected
+            .iter()
+            .any(|c| keywords.iter().any(|keyword| c.contains(keyword)))
+    }) {
+        connected.push(rest.swap_remove(index));
+    }
+    let covered = instance
+        .iter()
+        .all(|keyword| connected.iter().any(|keywords| keywords.contains(keyword)));
+    if !rest.is_empty() || !covered {
+        return Err(Error::UnknownComplexEntity {
+            id,
+            keywords: instance.iter().map(|keyword| keyword.to_string()).collect(),
+        });
+    }
+    Ok(selected)
+}
+
+/// Helper function to implement TableInit trait,
+/// combine partial records into a record of `T` selected by [select_complex_tables]
+pub fn complex_to_record<T: Holder>(subsuper: &SubSuperRecord) -> Result<Record> {
+    T::complex_parameters(&subsuper.0).map(|parameters| Record {
+        name: T::name().to_string(),
+        parameter: Parameter::List(parameters),
+    })
+}
+
+/// Index of EXPRESS keywords of entity instances in tables,
+/// used to dispatch a reference `#N` into the variant of `SELECT` types and `XxxAny` enums
+pub trait TypeIndex {
+    /// Keyword of the instance, e.g. `POINT` for `#1 = POINT(...);`
+    ///
+    /// The first one of [TypeIndex::entity_types] for an instance held in several tables.
+    fn entity_type(&self, id: InstanceId) -> Option<&str>;
+
+    /// Keywords of all tables holding the instance
+    ///
+    /// A complex entity instance of sibling subtypes, e.g. `(CONVERSION_BASED_UNIT(...) LENGTH_UNIT() NAMED_UNIT(*))`,
+    /// is held in the table of each subtype, see [select_complex_tables].
+    /// The default implementation returns [TypeIndex::entity_type].
+    fn entity_types(&self, id: InstanceId) -> Vec<&str> {
+        self.entity_type(id).into_iter().collect()
+    }
+
+    /// Number of instances for each keyword, e.g. `{"POINT": 3}`
+    ///
+    /// Keywords without instances are not included.
+    fn statistics(&self) -> BTreeMap<String, usize>;
+
+    /// Pairs of an instance and the instance it refers which does not exist in tables,
+    /// e.g. `(#2, #999)` for `#2 = B(#999);`, sorted by the referring instance
+    ///
+    /// Forward references are allowed since all sections are already loaded,
+    /// and thus this should be checked before resolving references by [IntoOwned::into_owned].
+    ///
+    /// The default implementation returns an empty `Vec`, i.e. does not check references,
+    /// for tables implemented by hand. `#[derive(TableInit)]` generates the check.
+    fn validate_references(&self) -> Vec<(InstanceId, InstanceId)> {
+        Vec::new()
+    }
+
+    /// Returns [Error::DanglingReference] for the first pair of [TypeIndex::validate_references]
+    fn check_references(&self) -> Result<()> {
+        match self.validate_references().first() {
+            Some(&(id, target)) => Err(Error::DanglingReference { id, target }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Conversion of owned values into holders, the reverse of [IntoOwned]
+///
+/// Entities in the owned value are inserted into `table` with ids allocated by `ids`,
+/// and referred by `#N` from the holder as in exchange structure.
+/// This is implemented by `#[derive(Holder)]`.
+pub trait FromOwned: IntoOwned {
+    /// Holder of the owned value itself, which is not inserted into `table`
+    fn from_owned(
+        owned: Self::Owned,
+        table: &mut Self::Table,
+        ids: &mut IdAllocator,
+    ) -> Result<Self>;
+
+    /// Place holder in a field of other holders,
+    /// overridden by entities to insert it into `table` and refer its id
+    fn place_holder(
+        owned: Self::Owned,
+        table: &mut Self::Table,
+        ids: &mut IdAllocator,
+    ) -> Result<PlaceHolder<Self>> {
+        Ok(PlaceHolder::Owned(Self::from_owned(owned, table, ids)?))
+    }
+}
+
+/// Allocator of instance ids not used in tables, see [FromOwned]
+///
+/// Ids are allocated in ascending order from the first one, skipping those used in the table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdAllocator {
+    next: u64,
+}
+
+impl Default for IdAllocator {
+    fn default() -> Self {
+        IdAllocator { next: 1 }
+    }
+}
+
+impl IdAllocator {
+    /// Allocate ids from `first`
+    pub fn new(first: InstanceId) -> Self {
+        IdAllocator { next: first.0 }
+    }
+
+    /// The smallest id not used in `table` after the previously allocated one
+    ///
+    /// Fails by [Error::InstanceIdExhausted] if no id remains in `u64`.
+    pub fn allocate(&mut self, table: &impl TypeIndex) -> Result<InstanceId> {
+        while table.entity_type(InstanceId(self.next)).is_some() {
+            self.next = next_id(self.next)?;
+        }
+        let id = InstanceId(self.next);
+        self.next = next_id(self.next)?;
+        Ok(id)
+    }
+}
+
+/// Id after `id` for [IdAllocator] and [InstanceWriter]
+fn next_id(id: u64) -> Result<u64> {
+    id.checked_add(1)
+        .ok_or(Error::InstanceIdExhausted(InstanceId(id)))
 }
 
+#[cfg(feature = "serde")]
 pub trait WithVisitor {
     type Visitor: for<'de> de::Visitor<'de, Value = Self>;
     fn visitor_new() -> Self::Visitor;
 }
 
-/// Trait for tables which pulls an entity (`T`) from an entity id (`u64`)
+/// Trait for tables which pulls an entity (`T`) from an entity id ([InstanceId])
 pub trait EntityTable<T: Holder<Table = Self>> {
     /// Get owned entity from table
-    fn get_owned(&self, entity_id: u64) -> Result<T::Owned>;
+    fn get_owned(&self, entity_id: InstanceId) -> Result<T::Owned>;
 
     /// Get owned entities as an iterator
     fn owned_iter<'table>(&'table self) -> Box<dyn Iterator<Item = Result<T::Owned>> + 'table>;
+
+    /// Insert a holder after checking `entity_id` is not used yet, see [insert_checked]
+    ///
+    /// The default implementation returns [Error::InsertUnsupported],
+    /// and `#[derive(Holder)]` implements it for the field of the table.
+    fn insert_checked(&mut self, _entity_id: InstanceId, _holder: T) -> Result<()> {
+        Err(Error::InsertUnsupported(T::name()))
+    }
+
+    /// Insert an owned value with an id allocated by `ids`, and returns the id
+    ///
+    /// Entities in the value are also inserted as separate instances, see [FromOwned].
+    /// Reuse `ids` for successive insertions since it skips ids used in the table.
+    fn insert_owned(&mut self, owned: T::Owned, ids: &mut IdAllocator) -> Result<InstanceId>
+    where
+        Self: TypeIndex + Sized,
+        T: FromOwned,
+    {
+        let holder = T::from_owned(owned, self, ids)?;
+        let id = ids.allocate(self)?;
+        self.insert_checked(id, holder)?;
+        Ok(id)
+    }
+}
+
+/// Write tables back into exchange structure, the reverse of [TableInit]
+///
+/// This is implemented by `#[derive(TableInit)]` with `#[table(into_parameter)]`,
+/// which requires [IntoParameter] for all holders.
+/// Instances keep their ids, and references are written as `#N`.
+/// Entities in inline owned values are written as separate instances with ids after the existing ones,
+/// see [InstanceWriter].
+pub trait WriteTables {
+    /// Data section of all instances sorted by their ids
+    ///
+    /// Fails by [Error::InstanceIdExhausted] if no id remains for inline owned values.
+    fn to_data_section(&self) -> Result<DataSection>;
+
+    /// Exchange structure in ISO 10303-21 syntax with `HEADER` and `DATA` sections
+    ///
+    /// Reals are written with decimal points, e.g. `1.`,
+    /// and strings are encoded by control directives, e.g. `'caf\X2\00E9\X0\'`.
+    #[cfg(feature = "serde")]
+    fn to_part21_string(&self, header: &crate::header::Header) -> Result<String> {
+        let mut output = String::from("ISO-10303-21;\nHEADER;\n");
+        for record in header.to_records() {
+            output.push_str(&format!("{};\n", record));
+        }
+        output.push_str("ENDSEC;\n");
+        output.push_str(&self.to_data_section()?.to_string());
+        output.push_str("END-ISO-10303-21;\n");
+        Ok(output)
+    }
+}
+
+/// Options for creating tables from data sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DeserializeOptions {
+    /// Check domain rules in `WHERE` clauses of each instance by [Validate]
+    ///
+    /// This is off by default since every instance is converted into owned value for checking.
+    pub validate_rules: bool,
+}
+
+/// Domain rules in `WHERE` clauses of an entity, including those of its supertypes
+pub trait Validate {
+    /// Label of the first violated rule, e.g. `point.wr1`
+    fn validate(&self) -> std::result::Result<(), &'static str>;
 }
 
 /// Create Table from [DataSection]
 pub trait TableInit: Default {
     fn append_data_section(&mut self, section: &DataSection) -> Result<()>;
 
+    /// Check domain rules of all instances, and returns [Error::RuleViolation] for the first violation
+    ///
+    /// Only the tables marked by `#[table(validate)]` in `#[derive(TableInit)]` are checked.
+    fn validate_rules(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn from_data_section_with_options(
+        section: &DataSection,
+        options: &DeserializeOptions,
+    ) -> Result<Self> {
+        let table = Self::from_data_section(section)?;
+        if options.validate_rules {
+            table.validate_rules()?;
+        }
+        Ok(table)
+    }
+
+    fn from_exchange_with_options(
+        exchange: &Exchange,
+        schema: &str,
+        options: &DeserializeOptions,
+    ) -> Result<Self> {
+        let table = Self::from_exchange(exchange, schema)?;
+        if options.validate_rules {
+            table.validate_rules()?;
+        }
+        Ok(table)
+    }
+
     fn from_data_section(section: &DataSection) -> Result<Self> {
         let mut table = Self::default();
         table.append_data_section(section)?;
@@ -160,9 +507,59 @@ pub trait TableInit: Default {
         }
         Ok(table)
     }
+
+    /// Create from data sections of exchange structure governed by the schema,
+    /// see [Exchange::data_sections_for_schema]
+    fn from_exchange(exchange: &Exchange, schema: &str) -> Result<Self> {
+        let mut table = Self::default();
+        for section in exchange.data_sections_for_schema(schema) {
+            table.append_data_section(section)?;
+        }
+        Ok(table)
+    }
+
+    /// Create from DATA sections in a STEP string, skipping instances which cannot be read
+    ///
+    /// Each record terminated by `;` is parsed and inserted separately
+    /// by [crate::parser::parse_entity_instances_lossy],
+    /// and failures of both steps are returned with the instance ids.
+    fn from_step_str_lossy(input: &str) -> (Self, Vec<(InstanceId, Error)>) {
+        Self::from_step_str_lossy_with_limits(input, &crate::parser::ParseLimits::default())
+    }
+
+    /// [TableInit::from_step_str_lossy] with limits of nesting depth and record size,
+    /// see [crate::parser::parse_entity_instances_lossy_with_limits]
+    fn from_step_str_lossy_with_limits(
+        input: &str,
+        limits: &crate::parser::ParseLimits,
+    ) -> (Self, Vec<(InstanceId, Error)>) {
+        let (instances, mut errors) =
+            crate::parser::parse_entity_instances_lossy_with_limits(input, limits);
+        let mut table = Self::default();
+        // Scanned only if some instance fails to be inserted
+        let mut locator = None;
+        for instance in instances {
+            let id = match &instance {
+                EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => *id,
+            };
+            let section = DataSection {
+                meta: Vec::new(),
+                entities: vec![instance],
+            };
+            if let Err(e) = table.append_data_section(&section) {
+                let locator = locator.get_or_insert_with(|| crate::parser::Locator::new(input));
+                errors.push((id, e.locate_by(locator)));
+            }
+        }
+        (table, errors)
+    }
 }
 
-pub fn get_owned<T, Table>(table: &Table, map: &HashMap<u64, T>, entity_id: u64) -> Result<T::Owned>
+pub fn get_owned<T, Table>(
+    table: &Table,
+    map: &HashMap<InstanceId, T>,
+    entity_id: InstanceId,
+) -> Result<T::Owned>
 where
     T: Holder<Table = Table>,
     Table: EntityTable<T>,
@@ -175,7 +572,7 @@ where
 
 pub fn owned_iter<'table, T, Table>(
     table: &'table Table,
-    map: &'table HashMap<u64, T>,
+    map: &'table HashMap<InstanceId, T>,
 ) -> Box<dyn Iterator<Item = Result<T::Owned>> + 'table>
 where
     T: Holder<Table = Table>,
@@ -188,14 +585,119 @@ where
     )
 }
 
+/// Cache of resolved entities shared by `Rc`, keyed by instance id and owned type
+///
+/// This is a field of tables marked by `#[table(shared_cache)]` in `#[derive(TableInit)]`,
+/// and fields of type `Rc<T>` in owned structs are resolved through it by [PlaceHolder::into_shared].
+/// An entity referred by many others, e.g. a `cartesian_point` of a mesh,
+/// is then converted into owned value only once.
+///
+/// The cache does not take part in the values of tables:
+/// a cloned cache is empty, and every caches are equal.
+#[derive(Default)]
+pub struct SharedCache {
+    entries: RefCell<HashMap<(TypeId, InstanceId), Rc<dyn Any>>>,
+}
+
+impl SharedCache {
+    /// Get cached value, or insert the value created by `f`
+    pub fn get_or_try_insert<T: 'static>(
+        &self,
+        id: InstanceId,
+        f: impl FnOnce() -> Result<T>,
+    ) -> Result<Rc<T>> {
+        let key = (TypeId::of::<T>(), id);
+        if let Some(cached) = self.entries.borrow().get(&key) {
+            return Ok(Rc::clone(cached).downcast().expect("keyed by TypeId"));
+        }
+        // `f` may resolve other references through this cache, and should be called without borrowing
+        let value = Rc::new(f()?);
+        self.entries.borrow_mut().insert(key, value.clone());
+        Ok(value)
+    }
+
+    /// Number of cached values
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear()
+    }
+}
+
+impl fmt::Debug for SharedCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedCache")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl Clone for SharedCache {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl PartialEq for SharedCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Tables holding a [SharedCache]
+pub trait SharedTable {
+    fn shared_cache(&self) -> &SharedCache;
+}
+
+/// Get entity shared with other references to the same instance, see [SharedCache]
+pub fn get_shared<T, Table>(table: &Table, entity_id: InstanceId) -> Result<Rc<T::Owned>>
+where
+    T: Holder<Table = Table>,
+    T::Owned: 'static,
+    Table: EntityTable<T> + SharedTable,
+{
+    table
+        .shared_cache()
+        .get_or_try_insert(entity_id, || table.get_owned(entity_id))
+}
+
 /// Helper function to implement TableInit trait
+#[cfg(feature = "serde")]
 pub fn insert_record<'de, T: de::Deserialize<'de>>(
-    table: &mut HashMap<u64, T>,
-    id: u64,
+    table: &mut HashMap<InstanceId, T>,
+    id: InstanceId,
+    record: &'de Record,
+) -> crate::error::Result<()> {
+    if table
+        .insert(
+            id,
+            de::Deserialize::deserialize(record).map_err(|e: Error| e.at_instance(id))?,
+        )
+        .is_some()
+    {
+        Err(Error::DuplicatedEntity(id))
+    } else {
+        Ok(())
+    }
+}
+
+/// Helper function to implement TableInit trait without serde, see [FromParameter]
+pub fn insert_record_from_parameter<T: FromParameter>(
+    table: &mut HashMap<InstanceId, T>,
+    id: InstanceId,
     record: &Record,
 ) -> crate::error::Result<()> {
     if table
-        .insert(id, de::Deserialize::deserialize(record)?)
+        .insert(
+            id,
+            T::from_typed(&record.name, &record.parameter).map_err(|e| e.at_instance(id))?,
+        )
         .is_some()
     {
         Err(Error::DuplicatedEntity(id))
@@ -204,6 +706,519 @@ pub fn insert_record<'de, T: de::Deserialize<'de>>(
     }
 }
 
+/// Helper function to implement [WriteTables] trait, push instances of holders in `table`
+///
+/// Holders are written in order of ids, and entities in their inline owned values are pushed
+/// into `writer`, see [IntoParameter::write_records].
+pub fn push_records<T: Holder + IntoParameter>(
+    table: &HashMap<InstanceId, T>,
+    instances: &mut Vec<EntityInstance>,
+    writer: &mut InstanceWriter,
+) -> Result<()> {
+    let mut ids: Vec<_> = table.keys().collect();
+    ids.sort();
+    for id in ids {
+        let records = table[id].clone().write_records(T::name(), writer)?;
+        instances.push(records_to_instance(*id, records));
+    }
+    Ok(())
+}
+
+/// Hand-rolled alternative of [serde::Deserialize] from [Parameter]
+///
+/// This is implemented for holders by `#[holder(generate_from_parameter)]` in `#[derive(Holder)]`,
+/// and follows the same mapping as the [serde::Deserializer] implementation for [Parameter],
+/// e.g. `$` and `*` into `None`, and `.T.` into `true`.
+pub trait FromParameter: Sized {
+    fn from_parameter(parameter: &Parameter) -> Result<Self>;
+
+    /// Create from a record or a typed parameter `KEYWORD(...)`,
+    /// where `parameter` is the content enclosed by `()`
+    fn from_typed(keyword: &str, _parameter: &Parameter) -> Result<Self> {
+        Err(unexpected_keyword::<Self>(keyword))
+    }
+}
+
+/// Error for a keyword which cannot be deserialized into `T`, used in [FromParameter] implementations
+pub fn unexpected_keyword<T>(keyword: &str) -> Error {
+    Error::DeserializeFailed(format!(
+        "Keyword {} cannot be deserialized into {}",
+        keyword,
+        std::any::type_name::<T>()
+    ))
+}
+
+/// Error for a parameter which cannot be deserialized into `T`, used in [FromParameter] implementations
+pub fn unexpected_parameter<T>(parameter: &Parameter) -> Error {
+    Error::DeserializeFailed(format!(
+        "{:?} cannot be deserialized into {}",
+        parameter,
+        std::any::type_name::<T>()
+    ))
+}
+
+/// Parameters of an entity `name` with `len` attributes, used in [FromParameter] implementations
+pub fn attribute_parameters<'p>(
+    name: &str,
+    parameter: &'p Parameter,
+    len: usize,
+) -> Result<&'p [Parameter]> {
+    match parameter {
+        Parameter::List(parameters) if parameters.len() == len => Ok(parameters),
+        Parameter::List(parameters) => Err(Error::DeserializeFailed(format!(
+            "{} has {} attributes, but {} parameters are given",
+            name,
+            len,
+            parameters.len()
+        ))),
+        Parameter::Typed { keyword, parameter } if keyword == name => {
+            attribute_parameters(name, parameter, len)
+        }
+        _ => Err(Error::DeserializeFailed(format!(
+            "{:?} cannot be deserialized into {}",
+            parameter, name
+        ))),
+    }
+}
+
+impl FromParameter for i64 {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Integer(value) => Ok(*value),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for f64 {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Real(value) | Parameter::RawReal { value, .. } => Ok(*value),
+            Parameter::Integer(value) => Ok(*value as f64),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for crate::primitive::Real {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::RawReal { value, raw } => Ok(crate::primitive::Real {
+                value: *value,
+                raw: Some(raw.clone()),
+            }),
+            _ => f64::from_parameter(parameter).map(Into::into),
+        }
+    }
+}
+
+impl FromParameter for String {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::String(value) => Ok(value.clone()),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for bool {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Enumeration(value) => match value.as_str() {
+                "T" | "TRUE" => Ok(true),
+                "F" | "FALSE" => Ok(false),
+                _ => Err(unexpected_parameter::<Self>(parameter)),
+            },
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for crate::primitive::Logical {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        use crate::primitive::Logical;
+        match parameter {
+            Parameter::Enumeration(value) => match value.as_str() {
+                "T" | "TRUE" => Ok(Logical::True),
+                "F" | "FALSE" => Ok(Logical::False),
+                "U" | "UNKNOWN" => Ok(Logical::Unknown),
+                _ => Err(unexpected_parameter::<Self>(parameter)),
+            },
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for crate::primitive::Number {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        use crate::primitive::Number;
+        match parameter {
+            Parameter::Integer(value) => Ok(Number::Int(*value)),
+            Parameter::Real(value) | Parameter::RawReal { value, .. } => Ok(Number::Real(*value)),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl FromParameter for crate::primitive::Derived {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Omitted => Ok(crate::primitive::Derived),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl<T: FromParameter> FromParameter for Option<T> {
+    /// `$` and `*` become `None`, and others are `Some`
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::NotProvided | Parameter::Omitted => Ok(None),
+            _ => Ok(Some(T::from_parameter(parameter)?)),
+        }
+    }
+
+    fn from_typed(keyword: &str, parameter: &Parameter) -> Result<Self> {
+        Ok(Some(T::from_typed(keyword, parameter)?))
+    }
+}
+
+impl<T: FromParameter> FromParameter for Vec<T> {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::List(parameters) => parameters.iter().map(T::from_parameter).collect(),
+            _ => Err(unexpected_parameter::<Self>(parameter)),
+        }
+    }
+}
+
+impl<T: FromParameter> FromParameter for Box<T> {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        Ok(Box::new(T::from_parameter(parameter)?))
+    }
+
+    fn from_typed(keyword: &str, parameter: &Parameter) -> Result<Self> {
+        Ok(Box::new(T::from_typed(keyword, parameter)?))
+    }
+}
+
+/// Conversion into [Parameter], the reverse of [FromParameter]
+///
+/// This is implemented for holders by `#[holder(generate_into_parameter)]` in `#[derive(Holder)]`,
+/// where entities and defined types become typed parameters, e.g. `A((1.0, #2))` and `LABEL('a')`.
+/// `None` becomes `$`, `true` becomes `.T.`, references are kept as [Parameter::Ref],
+/// and aggregates become [Parameter::List].
+pub trait IntoParameter: Sized {
+    fn into_parameter(self) -> Parameter;
+
+    /// Parameters without the keyword, e.g. `[1.0, #2]` for an entity `A(1.0, #2)`
+    ///
+    /// This is a building block for custom writers composing records by themselves.
+    /// Holders of `SELECT` types and `XxxAny` enums return those of the member they hold,
+    /// and other values return themselves as a single parameter.
+    fn into_parameter_list(self) -> Vec<Parameter> {
+        vec![self.into_parameter()]
+    }
+
+    /// Parameter in a record of exchange structure, where entities in inline owned values
+    /// are pushed into `writer` as separate instances and referred by `#N`
+    ///
+    /// This is [IntoParameter::into_parameter] by default for values without entities.
+    fn write_parameter(self, _writer: &mut InstanceWriter) -> Result<Parameter> {
+        Ok(self.into_parameter())
+    }
+
+    /// Records of an instance of this holder, see [IntoParameter::write_parameter]
+    ///
+    /// This is a record named `name` of [IntoParameter::into_parameter_list] by default,
+    /// while subtypes holding their supertypes return partial records of a complex instance.
+    fn write_records(self, name: &str, _writer: &mut InstanceWriter) -> Result<Vec<Record>> {
+        Ok(vec![Record {
+            name: name.to_string(),
+            parameter: Parameter::List(self.into_parameter_list()),
+        }])
+    }
+}
+
+/// Instances of entities in inline owned values written by [IntoParameter::write_parameter]
+///
+/// Exchange structure does not allow entities as parameters, e.g. `#1 = B(A((1.0)));`,
+/// and they are written as separate instances with new ids, e.g. `#1 = B(#2); #2 = A(1.0);`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstanceWriter {
+    next: u64,
+    instances: Vec<EntityInstance>,
+}
+
+impl InstanceWriter {
+    /// Instances get ids from `first`, which should be larger than those of existing instances
+    pub fn new(first: InstanceId) -> Self {
+        InstanceWriter {
+            next: first.0,
+            instances: Vec::new(),
+        }
+    }
+
+    /// Push an instance of `records` with a new id, and returns the reference to it
+    ///
+    /// Multiple records become a complex instance sorted by their names.
+    /// Fails by [Error::InstanceIdExhausted] if no id remains in `u64`.
+    pub fn push(&mut self, records: Vec<Record>) -> Result<Parameter> {
+        let id = InstanceId(self.next);
+        self.next = next_id(self.next)?;
+        self.instances.push(records_to_instance(id, records));
+        Ok(Parameter::Ref(Name::Entity(id)))
+    }
+
+    /// Instances pushed so far in order of ids
+    pub fn into_instances(self) -> Vec<EntityInstance> {
+        self.instances
+    }
+}
+
+/// Helper function to implement [WriteTables] trait, sort instances by ids
+/// and merge instances of the same id written from several tables into a complex instance,
+/// see [TypeIndex::entity_types]
+pub fn merge_instances(mut instances: Vec<EntityInstance>) -> Vec<EntityInstance> {
+    let records = |instance: EntityInstance| match instance {
+        EntityInstance::Simple { record, .. } => vec![record],
+        EntityInstance::Complex { subsuper, .. } => subsuper.0,
+    };
+    let id = |instance: &EntityInstance| match instance {
+        EntityInstance::Simple { id, .. } | EntityInstance::Complex { id, .. } => *id,
+    };
+    instances.sort_by_key(id);
+    let mut merged: Vec<EntityInstance> = Vec::with_capacity(instances.len());
+    for instance in instances {
+        let current = id(&instance);
+        match merged.pop() {
+            Some(last) if id(&last) == current => {
+                let mut union = records(last);
+                union.extend(records(instance));
+                union.sort_by(|a, b| a.name.cmp(&b.name));
+                union.dedup_by(|a, b| a.name == b.name);
+                merged.push(records_to_instance(current, union));
+            }
+            last => {
+                merged.extend(last);
+                merged.push(instance);
+            }
+        }
+    }
+    merged
+}
+
+fn records_to_instance(id: InstanceId, mut records: Vec<Record>) -> EntityInstance {
+    if records.len() == 1 {
+        EntityInstance::Simple {
+            id,
+            record: records.pop().unwrap(),
+        }
+    } else {
+        records.sort_by(|a, b| a.name.cmp(&b.name));
+        EntityInstance::Complex {
+            id,
+            subsuper: SubSuperRecord(records),
+        }
+    }
+}
+
+impl IntoParameter for i64 {
+    fn into_parameter(self) -> Parameter {
+        Parameter::Integer(self)
+    }
+}
+
+impl IntoParameter for f64 {
+    fn into_parameter(self) -> Parameter {
+        Parameter::Real(self)
+    }
+}
+
+impl IntoParameter for crate::primitive::Real {
+    fn into_parameter(self) -> Parameter {
+        match self.raw {
+            Some(raw) => Parameter::RawReal {
+                value: self.value,
+                raw,
+            },
+            None => Parameter::Real(self.value),
+        }
+    }
+}
+
+impl IntoParameter for String {
+    fn into_parameter(self) -> Parameter {
+        Parameter::String(self)
+    }
+}
+
+impl IntoParameter for bool {
+    fn into_parameter(self) -> Parameter {
+        Parameter::Enumeration(if self { "T" } else { "F" }.to_string())
+    }
+}
+
+impl IntoParameter for crate::primitive::Logical {
+    fn into_parameter(self) -> Parameter {
+        use crate::primitive::Logical;
+        let value = match self {
+            Logical::True => "T",
+            Logical::False => "F",
+            Logical::Unknown => "U",
+        };
+        Parameter::Enumeration(value.to_string())
+    }
+}
+
+impl IntoParameter for crate::primitive::Number {
+    fn into_parameter(self) -> Parameter {
+        use crate::primitive::Number;
+        match self {
+            Number::Int(value) => Parameter::Integer(value),
+            Number::Real(value) => Parameter::Real(value),
+        }
+    }
+}
+
+impl IntoParameter for crate::primitive::Derived {
+    fn into_parameter(self) -> Parameter {
+        Parameter::Omitted
+    }
+}
+
+impl<T: IntoParameter> IntoParameter for Option<T> {
+    /// `None` becomes `$`
+    fn into_parameter(self) -> Parameter {
+        match self {
+            Some(value) => value.into_parameter(),
+            None => Parameter::NotProvided,
+        }
+    }
+
+    fn write_parameter(self, writer: &mut InstanceWriter) -> Result<Parameter> {
+        match self {
+            Some(value) => value.write_parameter(writer),
+            None => Ok(Parameter::NotProvided),
+        }
+    }
+}
+
+impl<T: IntoParameter> IntoParameter for Vec<T> {
+    fn into_parameter(self) -> Parameter {
+        Parameter::List(self.into_iter().map(T::into_parameter).collect())
+    }
+
+    fn write_parameter(self, writer: &mut InstanceWriter) -> Result<Parameter> {
+        Ok(Parameter::List(
+            self.into_iter()
+                .map(|value| value.write_parameter(writer))
+                .collect::<Result<_>>()?,
+        ))
+    }
+}
+
+impl<T: IntoParameter> IntoParameter for Box<T> {
+    fn into_parameter(self) -> Parameter {
+        (*self).into_parameter()
+    }
+
+    fn into_parameter_list(self) -> Vec<Parameter> {
+        (*self).into_parameter_list()
+    }
+
+    fn write_parameter(self, writer: &mut InstanceWriter) -> Result<Parameter> {
+        (*self).write_parameter(writer)
+    }
+
+    fn write_records(self, name: &str, writer: &mut InstanceWriter) -> Result<Vec<Record>> {
+        (*self).write_records(name, writer)
+    }
+}
+
+/// Trait for finding entity references in a field of holders,
+/// used for inverse attributes and [TypeIndex::validate_references]
+pub trait ContainsRef {
+    /// True if this field refers the entity of `id`
+    fn contains_ref(&self, id: InstanceId) -> bool;
+
+    /// Push ids of all entities referred by this field, see [Holder::collect_refs]
+    fn collect_refs(&self, refs: &mut Vec<InstanceId>);
+}
+
+impl<T: Holder> ContainsRef for PlaceHolder<T> {
+    fn contains_ref(&self, id: InstanceId) -> bool {
+        matches!(self, PlaceHolder::Ref(Name::Entity(n)) if *n == id)
+    }
+
+    fn collect_refs(&self, refs: &mut Vec<InstanceId>) {
+        match self {
+            PlaceHolder::Ref(Name::Entity(n)) => refs.push(*n),
+            PlaceHolder::Ref(_) => {}
+            PlaceHolder::Owned(owned) => Holder::collect_refs(owned, refs),
+        }
+    }
+}
+
+impl<T: ContainsRef> ContainsRef for Option<T> {
+    fn contains_ref(&self, id: InstanceId) -> bool {
+        matches!(self, Some(x) if x.contains_ref(id))
+    }
+
+    fn collect_refs(&self, refs: &mut Vec<InstanceId>) {
+        if let Some(x) = self {
+            x.collect_refs(refs)
+        }
+    }
+}
+
+impl<T: ContainsRef> ContainsRef for Vec<T> {
+    fn contains_ref(&self, id: InstanceId) -> bool {
+        self.iter().any(|x| x.contains_ref(id))
+    }
+
+    fn collect_refs(&self, refs: &mut Vec<InstanceId>) {
+        for x in self {
+            x.collect_refs(refs)
+        }
+    }
+}
+
+impl<T: ContainsRef> ContainsRef for Box<T> {
+    fn contains_ref(&self, id: InstanceId) -> bool {
+        (**self).contains_ref(id)
+    }
+
+    fn collect_refs(&self, refs: &mut Vec<InstanceId>) {
+        (**self).collect_refs(refs)
+    }
+}
+
+/// Helper function to implement [EntityTable::insert_checked],
+/// insert a holder into the field `map` of the `table`
+///
+/// The entities it refers are not checked since they may be inserted later,
+/// e.g. a forward reference or a cycle of references.
+/// Check them by [TypeIndex::check_references] after all insertions.
+///
+/// Error
+/// ------
+/// - [Error::DuplicatedEntity] if `id` is already used in `map`
+///
+pub fn insert_checked<T, Table>(
+    table: &mut Table,
+    map: impl FnOnce(&mut Table) -> &mut HashMap<InstanceId, T>,
+    id: InstanceId,
+    holder: T,
+) -> Result<()> {
+    let map = map(table);
+    if map.contains_key(&id) {
+        return Err(Error::DuplicatedEntity(id));
+    }
+    map.insert(id, holder);
+    Ok(())
+}
+
 /// Owned value or reference through entity/value id
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlaceHolder<T> {
@@ -234,6 +1249,36 @@ where
     }
 }
 
+impl<T: Holder> PlaceHolder<T>
+where
+    T::Owned: 'static,
+    T::Table: EntityTable<T> + SharedTable,
+{
+    /// Get owned value shared by `Rc`, see [SharedCache]
+    ///
+    /// References to the same entity id are resolved into the same `Rc`,
+    /// while an owned value is not shared.
+    pub fn into_shared(self, table: &T::Table) -> Result<Rc<T::Owned>> {
+        match self {
+            PlaceHolder::Ref(id) => match id {
+                Name::Entity(id) => get_shared(table, id),
+                _ => unimplemented!("ENTITY is only supported now"),
+            },
+            PlaceHolder::Owned(a) => Ok(Rc::new(a.into_owned(table)?)),
+        }
+    }
+}
+
+impl<T> PlaceHolder<T> {
+    /// Convert the owned value, while a reference is kept
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> PlaceHolder<U> {
+        match self {
+            PlaceHolder::Ref(name) => PlaceHolder::Ref(name),
+            PlaceHolder::Owned(owned) => PlaceHolder::Owned(f(owned)),
+        }
+    }
+}
+
 impl<T: Holder> From<T> for PlaceHolder<T> {
     fn from(owned: T) -> Self {
         PlaceHolder::Owned(owned)
@@ -246,6 +1291,57 @@ impl<T> From<Name> for PlaceHolder<T> {
     }
 }
 
+impl<T: FromParameter> FromParameter for PlaceHolder<T> {
+    fn from_parameter(parameter: &Parameter) -> Result<Self> {
+        match parameter {
+            Parameter::Ref(name) => Ok(PlaceHolder::Ref(name.clone())),
+            _ => Ok(PlaceHolder::Owned(T::from_parameter(parameter)?)),
+        }
+    }
+
+    fn from_typed(keyword: &str, parameter: &Parameter) -> Result<Self> {
+        Ok(PlaceHolder::Owned(T::from_typed(keyword, parameter)?))
+    }
+}
+
+impl<T: IntoParameter> IntoParameter for PlaceHolder<T> {
+    fn into_parameter(self) -> Parameter {
+        match self {
+            PlaceHolder::Ref(name) => Parameter::Ref(name),
+            PlaceHolder::Owned(owned) => owned.into_parameter(),
+        }
+    }
+
+    fn into_parameter_list(self) -> Vec<Parameter> {
+        match self {
+            PlaceHolder::Ref(name) => vec![Parameter::Ref(name)],
+            PlaceHolder::Owned(owned) => owned.into_parameter_list(),
+        }
+    }
+
+    fn write_parameter(self, writer: &mut InstanceWriter) -> Result<Parameter> {
+        match self {
+            PlaceHolder::Ref(name) => Ok(Parameter::Ref(name)),
+            PlaceHolder::Owned(owned) => owned.write_parameter(writer),
+        }
+    }
+}
+
+/// References are serialized as [Name], i.e. `#12` in [crate::ast::ser::to_record]
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for PlaceHolder<T> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            PlaceHolder::Ref(name) => name.serialize(serializer),
+            PlaceHolder::Owned(owned) => owned.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
 impl<'de, T: Holder + WithVisitor + Deserialize<'de>> Deserialize<'de> for PlaceHolder<T> {
     fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
     where
@@ -259,10 +1355,12 @@ impl<'de, T: Holder + WithVisitor + Deserialize<'de>> Deserialize<'de> for Place
     }
 }
 
+#[cfg(feature = "serde")]
 struct PlaceHolderVisitor<T> {
     phantom: PhantomData<T>,
 }
 
+#[cfg(feature = "serde")]
 impl<T> Default for PlaceHolderVisitor<T> {
     fn default() -> Self {
         PlaceHolderVisitor {
@@ -271,6 +1369,7 @@ impl<T> Default for PlaceHolderVisitor<T> {
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, T: Deserialize<'de> + Holder + WithVisitor> de::Visitor<'de> for PlaceHolderVisitor<T> {
     type Value = PlaceHolder<T>;
 
@@ -316,7 +1415,7 @@ impl<'de, T: Deserialize<'de> + Holder + WithVisitor> de::Visitor<'de> for Place
         match key.as_str() {
             "Entity" => {
                 let value: u64 = variant.newtype_variant()?;
-                Ok(PlaceHolder::Ref(Name::Entity(value)))
+                Ok(PlaceHolder::Ref(Name::Entity(InstanceId(value))))
             }
             "Value" => {
                 let value: u64 = variant.newtype_variant()?;
@@ -343,3 +1442,89 @@ impl<'de, T: Deserialize<'de> + Holder + WithVisitor> de::Visitor<'de> for Place
         Ok(PlaceHolder::Owned(visitor.visit_map(map)?))
     }
 }
+
+/// Seed deserializing `T` from the value of a typed parameter whose keyword is already consumed
+///
+/// A SELECT type uses this to pass `DISTANCE(1.0)` to its member SELECT type accepting `DISTANCE`,
+/// and the member reads the keyword again as the key of a single-entry map.
+#[cfg(feature = "serde")]
+pub struct KeywordSeed<T> {
+    keyword: String,
+    phantom: PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> KeywordSeed<T> {
+    pub fn new(keyword: String) -> Self {
+        KeywordSeed {
+            keyword,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> de::DeserializeSeed<'de> for KeywordSeed<T> {
+    type Value = T;
+
+    fn deserialize<D>(self, deserializer: D) -> ::std::result::Result<T, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        T::deserialize(KeywordDeserializer {
+            keyword: Some(self.keyword),
+            value: Some(deserializer),
+        })
+    }
+}
+
+/// Single-entry map of a keyword and the deserializer of its value
+#[cfg(feature = "serde")]
+struct KeywordDeserializer<D> {
+    keyword: Option<String>,
+    value: Option<D>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D: de::Deserializer<'de>> de::Deserializer<'de> for KeywordDeserializer<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> ::std::result::Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        struct tuple_struct map enum identifier ignored_any
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D: de::Deserializer<'de>> de::MapAccess<'de> for KeywordDeserializer<D> {
+    type Error = D::Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> ::std::result::Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        match self.keyword.take() {
+            Some(keyword) => seed.deserialize(keyword.into_deserializer()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> ::std::result::Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let value = self
+            .value
+            .take()
+            .expect("Value of a keyword is deserialized only once"); // this must be a bug
+        seed.deserialize(value)
+    }
+}
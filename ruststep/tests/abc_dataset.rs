@@ -41,3 +41,25 @@ fn abc_dataset() -> anyhow::Result<()> {
     dbg!(exchange);
     Ok(())
 }
+
+#[cfg(feature = "ap203")]
+#[test]
+fn abc_dataset_ap203() -> anyhow::Result<()> {
+    use ruststep::{
+        ap203::config_control_design::Tables,
+        tables::{InstanceId, TableInit, TypeIndex},
+    };
+    // The file is written in AP214, and some entities are not members of AP203
+    let (table, errors) = Tables::from_step_str_lossy(&format_example()?);
+    // Complex instances of sibling subtypes, e.g. `(CONVERSION_BASED_UNIT(...) LENGTH_UNIT() NAMED_UNIT(*))`
+    for id in [10, 72, 73, 74, 359] {
+        if let Some((_, e)) = errors.iter().find(|(e, _)| e.0 == id) {
+            panic!("#{} failed: {}", id, e);
+        }
+    }
+    assert_eq!(
+        table.entity_types(InstanceId(72)),
+        ["CONVERSION_BASED_UNIT", "LENGTH_UNIT"]
+    );
+    Ok(())
+}
@@ -54,7 +54,7 @@ fn deserialize_sub1() {
     test(
         "SUB_1(#3, 2.0)",
         Sub1Holder {
-            base: Name::Entity(3).into(),
+            base: Name::Entity(InstanceId(3)).into(),
             y1: 2.0,
         },
     );
@@ -82,7 +82,7 @@ fn deserialize_base_any() {
     test(
         "SUB_1(#3, 2.0)",
         BaseAnyHolder::Sub1(Box::new(Sub1Holder {
-            base: Name::Entity(3).into(),
+            base: Name::Entity(InstanceId(3)).into(),
             y1: 2.0,
         })),
     );
@@ -110,7 +110,7 @@ fn deserialize_base_any_placeholder() {
     test(
         "SUB_1(#3, 2.0)",
         PlaceHolder::Owned(BaseAnyHolder::Sub1(Box::new(Sub1Holder {
-            base: Name::Entity(3).into(),
+            base: Name::Entity(InstanceId(3)).into(),
             y1: 2.0,
         }))),
     );
@@ -162,18 +162,18 @@ fn into_base_any() {
 #[test]
 fn lookup_base_any() {
     test(
-        Parameter::Ref(Name::Entity(1)),
+        Parameter::Ref(Name::Entity(InstanceId(1))),
         BaseAny::Base(Box::new(Base { x: 1.0 })),
     );
     test(
-        Parameter::Ref(Name::Entity(2)),
+        Parameter::Ref(Name::Entity(InstanceId(2))),
         BaseAny::Sub1(Box::new(Sub1 {
             base: Base { x: 1.0 },
             y1: 2.0,
         })),
     );
     test(
-        Parameter::Ref(Name::Entity(3)),
+        Parameter::Ref(Name::Entity(InstanceId(3))),
         BaseAny::Sub2(Box::new(Sub2 {
             base: Base { x: 1.0 },
             y2: 4.0,
@@ -195,14 +195,14 @@ fn lookup_base_any() {
 #[test]
 fn get_owned_base() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let base = EntityTable::<BaseHolder>::get_owned(&table, 1).unwrap();
+    let base = EntityTable::<BaseHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(base, Base { x: 1.0 });
 }
 
 #[test]
 fn get_owned_sub1() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let sub1 = EntityTable::<Sub1Holder>::get_owned(&table, 2).unwrap();
+    let sub1 = EntityTable::<Sub1Holder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         sub1,
         Sub1 {
@@ -217,11 +217,11 @@ fn get_owned_any() {
     let table = Tables::from_str(EXAMPLE).unwrap();
 
     // #1 = BASE(1.0);
-    let any1 = EntityTable::<BaseAnyHolder>::get_owned(&table, 1).unwrap();
+    let any1 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(any1, BaseAny::Base(Box::new(Base { x: 1.0 })));
 
     // #2 = SUB_1(BASE((1.0)), 2.0);
-    let any2 = EntityTable::<BaseAnyHolder>::get_owned(&table, 2).unwrap();
+    let any2 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         any2,
         BaseAny::Sub1(Box::new(Sub1 {
@@ -231,7 +231,7 @@ fn get_owned_any() {
     );
 
     // #3 = SUB_2(#1, 4.0);
-    let any3 = EntityTable::<BaseAnyHolder>::get_owned(&table, 3).unwrap();
+    let any3 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(
         any3,
         BaseAny::Sub2(Box::new(Sub2 {
@@ -246,18 +246,18 @@ fn as_ref_base_any() {
     let table = Tables::from_str(EXAMPLE).unwrap();
 
     // #1 = BASE(1.0);
-    let any1 = EntityTable::<BaseAnyHolder>::get_owned(&table, 1).unwrap();
+    let any1 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(any1.as_ref(), &Base { x: 1.0 });
 
     // #2 = SUB_1(BASE((1.0)), 2.0);
-    let any2 = EntityTable::<BaseAnyHolder>::get_owned(&table, 2).unwrap();
+    let any2 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(any2.as_ref(), &Base { x: 1.0 });
-    let sub2 = EntityTable::<Sub1Holder>::get_owned(&table, 2).unwrap();
+    let sub2 = EntityTable::<Sub1Holder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(sub2.as_ref(), &Base { x: 1.0 });
 
     // #3 = SUB_2(#1, 4.0);
-    let any3 = EntityTable::<BaseAnyHolder>::get_owned(&table, 3).unwrap();
+    let any3 = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(any3.as_ref(), &Base { x: 1.0 });
-    let sub3 = EntityTable::<Sub2Holder>::get_owned(&table, 3).unwrap();
+    let sub3 = EntityTable::<Sub2Holder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(sub3.as_ref(), &Base { x: 1.0 });
 }
@@ -0,0 +1,42 @@
+// Test for accessors of ARRAY attributes by EXPRESS index
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        coords: ARRAY [1:3] OF REAL;
+        offsets: OPTIONAL ARRAY [0:1] OF OPTIONAL INTEGER;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT((1.0, 2.0, 3.0), ($, 5));
+  #2 = POINT((1.0, 2.0, 3.0), $);
+ENDSEC;
+"#;
+
+#[test]
+fn array_at() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let mut point = EntityTable::<PointHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(point.coords_at(0), None);
+    assert_eq!(point.coords_at(1), Some(&1.0));
+    assert_eq!(point.coords_at(3), Some(&3.0));
+    assert_eq!(point.coords_at(4), None);
+    assert_eq!(point.offsets_at(0), Some(&None));
+    assert_eq!(point.offsets_at(1), Some(&Some(5)));
+
+    *point.coords_at_mut(1).unwrap() = 10.0;
+    assert_eq!(point.coords, vec![10.0, 2.0, 3.0]);
+
+    let point = EntityTable::<PointHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert_eq!(point.offsets_at(0), None);
+}
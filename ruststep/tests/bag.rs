@@ -0,0 +1,41 @@
+// Test for BAG aggregates, which are stored in `Vec` keeping duplicated elements
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY cloud;
+        points: BAG [0:?] OF point;
+        weights: BAG OF REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(1.0);
+  #2 = CLOUD((#1, #1, POINT((2.0))), (0.5, 0.5));
+ENDSEC;
+"#;
+
+#[test]
+fn bag() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let cloud = EntityTable::<CloudHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert_eq!(
+        cloud,
+        Cloud {
+            points: vec![Point { x: 1.0 }, Point { x: 1.0 }, Point { x: 2.0 }],
+            weights: vec![0.5, 0.5],
+        }
+    );
+}
@@ -3,7 +3,7 @@ use serde::Deserialize;
 
 fn sub_deserialize<T>(param: &str, ans: T)
 where
-    T: std::fmt::Debug + PartialEq + Deserialize<'static>,
+    T: std::fmt::Debug + PartialEq + serde::de::DeserializeOwned,
 {
     let p = Parameter::Enumeration(param.to_string());
     let x: T = Deserialize::deserialize(&p).unwrap();
@@ -28,8 +28,32 @@ fn deserialize_logical() {
     sub_deserialize("F", Logical::False);
     sub_deserialize("FALSE", Logical::False);
     sub_deserialize("U", Logical::Unknown);
+    sub_deserialize("UNKNOWN", Logical::Unknown);
     sub_deserialize("Unknown", Logical::Unknown);
 
     let p = Parameter::Enumeration("Q".to_string());
     assert!(Logical::deserialize(&p).is_err());
 }
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct A {
+    flag: bool,
+    logicals: Vec<Logical>,
+}
+
+#[test]
+fn deserialize_record() {
+    use std::str::FromStr;
+    let record = Record::from_str("A(.T., (.T., .F., .U.))").unwrap();
+    assert_eq!(
+        A::deserialize(&record).unwrap(),
+        A {
+            flag: true,
+            logicals: vec![Logical::True, Logical::False, Logical::Unknown],
+        }
+    );
+
+    // `.U.` is not a BOOLEAN
+    let record = Record::from_str("A(.U., ())").unwrap();
+    assert!(A::deserialize(&record).is_err());
+}
@@ -0,0 +1,48 @@
+// Test for structs borrowing strings from parameters
+
+use ruststep::ast::*;
+use serde::Deserialize;
+use std::{borrow::Cow, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, ruststep_derive::Deserialize)]
+pub struct Person<'a> {
+    pub name: &'a str,
+    pub age: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(rename = "LABEL")]
+struct Label<'a> {
+    #[serde(borrow)]
+    text: Cow<'a, str>,
+}
+
+#[test]
+fn borrowed_str() {
+    let record = Record::from_str("PERSON('Homura', 14)").unwrap();
+    let person = Person::deserialize(&record).unwrap();
+    assert_eq!(
+        person,
+        Person {
+            name: "Homura",
+            age: 14
+        }
+    );
+    // borrowed from the record, not copied
+    let Parameter::List(parameters) = &record.parameter else {
+        unreachable!()
+    };
+    let Parameter::String(name) = &parameters[0] else {
+        unreachable!()
+    };
+    assert!(std::ptr::eq(person.name, name.as_str()));
+}
+
+#[test]
+fn escaped_str() {
+    // Escapes are resolved while parsing, and the resolved string is borrowed
+    let record = Record::from_str("LABEL('it''s')").unwrap();
+    let label = Label::deserialize(&record).unwrap();
+    assert_eq!(label.text, "it's");
+    assert!(matches!(label.text, Cow::Borrowed(_)));
+}
@@ -0,0 +1,46 @@
+// Identifiers referred with a different casing than their declarations
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY cartesian_point;
+        x: REAL;
+      END_ENTITY;
+
+      TYPE label = STRING;
+      END_TYPE;
+
+      ENTITY line;
+        p: Cartesian_Point;
+        l: Label;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn declared_casing() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = CARTESIAN_POINT(1.0);
+          #2 = LABEL('a');
+          #3 = LINE(#1, #2);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    // Generated names follow the declarations, `cartesian_point` and `label`
+    let line: Line = EntityTable::<LineHolder>::get_owned(&table, InstanceId(3)).unwrap();
+    assert_eq!(
+        line,
+        Line {
+            p: CartesianPoint { x: 1.0 },
+            l: Label("a".to_string()),
+        }
+    );
+}
@@ -0,0 +1,151 @@
+// Test for complex entity instances, e.g. `#1 = (BASE(1.0) SUB(2.0));`
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY base SUPERTYPE OF (sub ANDOR sibling);
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY sub
+        SUPERTYPE OF (subsub)
+        SUBTYPE OF (base);
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY subsub SUBTYPE OF (sub);
+        z: REAL;
+      END_ENTITY;
+
+      ENTITY sibling SUBTYPE OF (base);
+        v: REAL;
+      END_ENTITY;
+
+      ENTITY other;
+        w: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn two_partial_entities() {
+    let table = Tables::from_str("DATA; #1 = (BASE(1.0) SUB(2.0)); ENDSEC;").unwrap();
+    assert_eq!(table.entity_type(InstanceId(1)), Some("SUB"));
+    assert_eq!(table.statistics().len(), 1);
+    assert_eq!(
+        EntityTable::<SubHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        Sub {
+            base: Base { x: 1.0 },
+            y: 2.0
+        }
+    );
+}
+
+#[test]
+fn three_partial_entities() {
+    // Partial entities are sorted alphabetically in exchange structure, but the order does not matter
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = OTHER(0.0);
+          #2 = (SUBSUB(3.0) BASE(1.0) SUB(2.0));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        EntityTable::<SubsubHolder>::get_owned(&table, InstanceId(2)).unwrap(),
+        Subsub {
+            sub: Sub {
+                base: Base { x: 1.0 },
+                y: 2.0
+            },
+            z: 3.0
+        }
+    );
+}
+
+#[test]
+fn unknown_combination() {
+    for input in [
+        "DATA; #1 = (BASE(1.0) OTHER(2.0)); ENDSEC;",
+        "DATA; #1 = (BASE(1.0) SUBSUB(3.0)); ENDSEC;",
+    ] {
+        assert!(matches!(
+            Tables::from_str(input),
+            Err(ruststep::error::Error::UnknownComplexEntity {
+                id: InstanceId(1),
+                ..
+            })
+        ));
+    }
+}
+
+#[test]
+fn sibling_subtypes() {
+    // `sub AND sibling` is held in both tables
+    let input = "DATA; #1 = (BASE(1.0) SIBLING(4.0) SUB(2.0)); ENDSEC;";
+    let table = Tables::from_str(input).unwrap();
+    assert_eq!(table.entity_types(InstanceId(1)), ["SUB", "SIBLING"]);
+    assert_eq!(
+        EntityTable::<SubHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        Sub {
+            base: Base { x: 1.0 },
+            y: 2.0
+        }
+    );
+    assert_eq!(
+        EntityTable::<SiblingHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        Sibling {
+            base: Base { x: 1.0 },
+            v: 4.0
+        }
+    );
+    // Written back as a complex instance
+    let output = table.to_data_section().unwrap().to_string();
+    assert_eq!(
+        output,
+        "DATA;\n#1 = (BASE(1.)SIBLING(4.)SUB(2.));\nENDSEC;\n"
+    );
+}
+
+#[test]
+fn insert_owned() {
+    // Supertypes are kept in the holder instead of separate instances
+    let mut table = Tables::default();
+    let sub = Sub {
+        base: Base { x: 1.0 },
+        y: 2.0,
+    };
+    let id = EntityTable::<SubHolder>::insert_owned(
+        &mut table,
+        sub.clone(),
+        &mut IdAllocator::default(),
+    )
+    .unwrap();
+    assert_eq!(table.statistics().len(), 1);
+    assert_eq!(table.entity_type(id), Some("SUB"));
+    assert_eq!(
+        EntityTable::<SubHolder>::get_owned(&table, id).unwrap(),
+        sub
+    );
+}
+
+#[test]
+fn write_complex_instance() {
+    let table =
+        Tables::from_str("DATA; #1 = OTHER(0.0); #2 = (BASE(1.0) SUB(2.0) SUBSUB(3.0)); ENDSEC;")
+            .unwrap();
+    let output = table.to_data_section().unwrap().to_string();
+    assert_eq!(
+        output,
+        "DATA;\n#1 = OTHER(0.);\n#2 = (BASE(1.)SUB(2.)SUBSUB(3.));\nENDSEC;\n"
+    );
+    assert_eq!(Tables::from_str(&output).unwrap(), table);
+}
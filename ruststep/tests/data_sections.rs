@@ -0,0 +1,72 @@
+// Test for multiple `DATA` sections annotated with schemas
+
+use ruststep::{ast::*, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA schema_a;
+      ENTITY a;
+        x: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+
+    SCHEMA schema_b;
+      ENTITY b;
+        y: INTEGER;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('SCHEMA_A', 'SCHEMA_B'));
+ENDSEC;
+DATA('S1', ('SCHEMA_A'));
+  #1 = A(1.0);
+ENDSEC;
+DATA('S2', ('SCHEMA_B'));
+  #2 = B(2);
+  #3 = B(3);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+#[test]
+fn sections() {
+    let exchange = Exchange::from_str(EXAMPLE).unwrap();
+    assert_eq!(exchange.data.len(), 2);
+    assert_eq!(exchange.data[0].name(), Some("S1"));
+    assert_eq!(exchange.data[1].schema(), Some("SCHEMA_B"));
+
+    let schemas: Vec<_> = exchange
+        .entity_instances()
+        .map(|(schema, instance)| match instance {
+            EntityInstance::Simple { id, .. } => (schema, id.0),
+            EntityInstance::Complex { .. } => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        schemas,
+        [
+            (Some("SCHEMA_A"), 1),
+            (Some("SCHEMA_B"), 2),
+            (Some("SCHEMA_B"), 3)
+        ]
+    );
+}
+
+#[test]
+fn dispatch() {
+    let exchange = Exchange::from_str(EXAMPLE).unwrap();
+    let a =
+        schema_a::Tables::from_exchange(&exchange, schema_a::Tables::SCHEMA_IDENTIFIER).unwrap();
+    assert_eq!(a.a_holders().len(), 1);
+    let b =
+        schema_b::Tables::from_exchange(&exchange, schema_b::Tables::SCHEMA_IDENTIFIER).unwrap();
+    assert_eq!(b.b_holders().len(), 2);
+}
@@ -0,0 +1,47 @@
+// Test for distinguishing a derived value `*` from an unset optional value `$`
+
+use ruststep::{ast::*, primitive::Derived, tables::*};
+use serde::Deserialize;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Deserialize)]
+struct A {
+    x: Option<f64>,
+    y: Derived,
+    z: f64,
+}
+
+#[test]
+fn deserialize_record() {
+    let record = Record::from_str("A($, *, 1.0)").unwrap();
+    let a: A = Deserialize::deserialize(&record).unwrap();
+    assert_eq!(
+        a,
+        A {
+            x: None,
+            y: Derived,
+            z: 1.0
+        }
+    );
+
+    // `$` is not a derived value
+    let record = Record::from_str("A($, $, 1.0)").unwrap();
+    assert!(A::deserialize(&record).is_err());
+}
+
+#[test]
+fn deserialize_list() {
+    let p = Parameter::from_str("($, *)").unwrap();
+    let (x, y): (Option<Derived>, Derived) = Deserialize::deserialize(&p).unwrap();
+    assert_eq!((x, y), (None, Derived));
+}
+
+#[test]
+fn from_parameter() {
+    assert_eq!(
+        Derived::from_parameter(&Parameter::Omitted).unwrap(),
+        Derived
+    );
+    assert!(Derived::from_parameter(&Parameter::NotProvided).is_err());
+    assert_eq!(Derived.into_parameter(), Parameter::Omitted);
+}
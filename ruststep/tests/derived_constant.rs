@@ -0,0 +1,39 @@
+// Test for DERIVE attributes folded into constants
+
+use ruststep::{primitive::*, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY circle;
+        radius: REAL;
+        coords: ARRAY [1:1+1] OF REAL;
+      DERIVE
+        tau: REAL := 2 * PI;
+        angle: REAL := -PI / 2;
+        sides: INTEGER := (3 + 1) DIV 2;
+        count: NUMBER := 2 * 3;
+        closed: BOOLEAN := NOT FALSE;
+        label: STRING := 'cir' + 'cle';
+        area: REAL := PI * radius ** 2;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn derived_constant() {
+    let table = Tables::from_str("DATA; #1 = CIRCLE(1.0, (2.0, 3.0)); ENDSEC;").unwrap();
+    let circle = EntityTable::<CircleHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(circle.tau(), 2.0 * std::f64::consts::PI);
+    assert_eq!(circle.angle(), -std::f64::consts::FRAC_PI_2);
+    assert_eq!(circle.sides(), 2);
+    assert_eq!(circle.count(), Number::Int(6));
+    assert!(circle.closed());
+    assert_eq!(circle.label(), "circle");
+    // bound folded from `1+1`
+    assert_eq!(circle.coords_at(2), Some(&3.0));
+}
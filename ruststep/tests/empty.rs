@@ -0,0 +1,49 @@
+// Test for empty schema and empty exchange structure
+
+use ruststep::{ast::*, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA empty_schema;
+    END_SCHEMA;
+
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+#[test]
+fn empty_data_section() {
+    let section = DataSection::from_str("DATA;\nENDSEC;").unwrap();
+    assert!(section.entities.is_empty());
+
+    let table = empty_schema::Tables::from_data_section(&section).unwrap();
+    assert_eq!(table, empty_schema::Tables::default());
+
+    let table = test_schema::Tables::from_str("DATA;\nENDSEC;").unwrap();
+    assert!(table.a_holders().is_empty());
+}
+
+#[test]
+fn no_data_section() {
+    let exchange = Exchange::from_str(
+        r#"
+        ISO-10303-21;
+        HEADER;
+          FILE_DESCRIPTION((''), '2;1');
+          FILE_NAME('', '', (''), (''), '', '', '');
+          FILE_SCHEMA(('TEST_SCHEMA'));
+        ENDSEC;
+        END-ISO-10303-21;
+        "#
+        .trim(),
+    )
+    .unwrap();
+    assert!(exchange.data.is_empty());
+    let table = test_schema::Tables::from_data_sections(&exchange.data).unwrap();
+    assert!(table.a_holders().is_empty());
+}
@@ -85,7 +85,7 @@ fn deserialize_b_holder_record_ref() {
         b,
         BHolder {
             z: 1.0,
-            a: PlaceHolder::Ref(Name::Entity(2))
+            a: PlaceHolder::Ref(Name::Entity(InstanceId(2)))
         }
     );
 }
@@ -119,7 +119,7 @@ fn deserialize_b_holder_parameter_ref() {
         b,
         BHolder {
             z: 1.0,
-            a: PlaceHolder::Ref(Name::Entity(2))
+            a: PlaceHolder::Ref(Name::Entity(InstanceId(2)))
         }
     );
 }
@@ -127,14 +127,14 @@ fn deserialize_b_holder_parameter_ref() {
 #[test]
 fn get_owned_a() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(a, A { x: 1.0, y: 2.0 });
 }
 
 #[test]
 fn get_owned_b2() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let b = EntityTable::<BHolder>::get_owned(&table, 2).unwrap();
+    let b = EntityTable::<BHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         b,
         B {
@@ -147,7 +147,7 @@ fn get_owned_b2() {
 #[test]
 fn get_owned_b3() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let b = EntityTable::<BHolder>::get_owned(&table, 3).unwrap();
+    let b = EntityTable::<BHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(
         b,
         B {
@@ -156,3 +156,61 @@ fn get_owned_b3() {
         }
     );
 }
+
+#[test]
+fn holder_from_owned() {
+    let a = A { x: 1.0, y: 2.0 };
+    assert_eq!(AHolder::from(a.clone()), AHolder { x: 1.0, y: 2.0 });
+    assert_eq!(A::from(AHolder::from(a.clone())), a);
+
+    // `a` is inserted into the table with an id, and referred from `b`
+    let mut table = Tables::default();
+    let mut ids = IdAllocator::default();
+    EntityTable::<AHolder>::insert_checked(&mut table, InstanceId(1), AHolder { x: 0.0, y: 0.0 })
+        .unwrap();
+    let b = B { z: 3.0, a };
+    let id = EntityTable::<BHolder>::insert_owned(&mut table, b.clone(), &mut ids).unwrap();
+    assert_eq!(id, InstanceId(3));
+    assert_eq!(
+        table.b_holders()[&id],
+        BHolder {
+            z: 3.0,
+            a: PlaceHolder::Ref(Name::Entity(InstanceId(2)))
+        }
+    );
+    assert_eq!(table.entity_type(InstanceId(2)), Some("A"));
+    assert_eq!(EntityTable::<BHolder>::get_owned(&table, id).unwrap(), b);
+
+    // holders without table
+    let holder = BHolder::from_owned(b.clone(), &mut table, &mut ids).unwrap();
+    assert_eq!(holder.a, PlaceHolder::Ref(Name::Entity(InstanceId(4))));
+    assert_eq!(holder.into_owned(&table).unwrap(), b);
+}
+
+#[test]
+fn schema_identifier() {
+    assert_eq!(Tables::SCHEMA_IDENTIFIER, "TEST_SCHEMA");
+    assert_eq!(SCHEMA_IDENTIFIERS, ["TEST_SCHEMA"]);
+}
+
+#[test]
+fn structural_eq() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = A(1.0, 2.0);
+          #2 = A(1.0, 2.0);
+          #3 = B(6.0, #1);
+          #4 = B(6.0, #2);
+          #5 = B(6.0, A((1.0, 2.0)));
+          #6 = B(6.0, A((1.0, 3.0)));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let b = |id| table.b_holders()[&InstanceId(id)].clone();
+    assert_ne!(b(3), b(4));
+    assert!(b(3).structural_eq(&b(4), &table).unwrap());
+    assert!(b(3).structural_eq(&b(5), &table).unwrap());
+    assert!(!b(3).structural_eq(&b(6), &table).unwrap());
+}
@@ -21,3 +21,43 @@ fn enum_deserialize() {
     let a: MagicaQuartet = Deserialize::deserialize(&p).unwrap();
     assert_eq!(a, MagicaQuartet::HomuraAkemi);
 }
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE mode = ENUMERATION OF (some_multi_word_value, level_1, mode3);
+      END_TYPE;
+    END_SCHEMA;
+    "#
+);
+
+#[test]
+fn generated_enum_deserialize() {
+    for (input, expected) in [
+        (
+            ".SOME_MULTI_WORD_VALUE.",
+            test_schema::Mode::SomeMultiWordValue,
+        ),
+        (".LEVEL_1.", test_schema::Mode::Level1),
+        (".MODE3.", test_schema::Mode::Mode3),
+    ] {
+        let (_, p): (_, Parameter) = exchange::parameter(input).finish().unwrap();
+        let mode: test_schema::Mode = Deserialize::deserialize(&p).unwrap();
+        assert_eq!(mode, expected);
+    }
+}
+
+#[test]
+fn generated_enum_text() {
+    use std::str::FromStr;
+    let mode = test_schema::Mode::SomeMultiWordValue;
+    assert_eq!(mode.to_part21(), ".SOME_MULTI_WORD_VALUE.");
+    assert_eq!(mode.to_string(), "SOME_MULTI_WORD_VALUE");
+    assert_eq!(test_schema::Mode::from_str(mode.to_part21()).unwrap(), mode);
+    assert_eq!(
+        test_schema::Mode::from_str("LEVEL_1").unwrap(),
+        test_schema::Mode::Level1
+    );
+    assert!(test_schema::Mode::from_str("level_1").is_err());
+    assert!(test_schema::Mode::from_str(".UNKNOWN.").is_err());
+}
@@ -0,0 +1,58 @@
+// Test for locations of deserialization errors
+
+use ruststep::{
+    error::{Error, Span},
+    tables::*,
+};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY shape;
+        origin: point;
+        grid: LIST [0:?] OF LIST [0:?] OF INTEGER;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn nested_list() {
+    let input = r#"
+DATA;
+  #1 = POINT(0.0, 0.0);
+  #2 = SHAPE(#1,
+    ((1, 2), (3, 'x')));
+ENDSEC;
+"#;
+    let err = Tables::from_str(input).unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!(location.id, Some(InstanceId(2)));
+    assert_eq!(location.path, [1, 1, 1]);
+    let span = location.span.unwrap();
+    assert_eq!((span.line, span.column), (5, 18));
+    assert!(input[span.offset..].starts_with("'x'"));
+    assert!(err
+        .to_string()
+        .ends_with("at #2, parameter [1, 1, 1], line 5, column 18"));
+}
+
+#[test]
+fn record() {
+    // Errors of the record itself are located at the record
+    let input = "DATA;\n#1 = POINT(0.0);\nENDSEC;";
+    let err = Tables::from_str(input).unwrap_err();
+    assert!(matches!(
+        err,
+        Error::Located { ref location, .. } if location.path.is_empty()
+            && location.span == Some(Span { offset: 11, line: 2, column: 6 })
+    ));
+}
@@ -0,0 +1,55 @@
+// Test for attributes typed as GENERIC_ENTITY, which refer any entity of the schema
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY label;
+        text: STRING;
+      END_ENTITY;
+
+      ENTITY group;
+        items: LIST [1:?] OF GENERIC_ENTITY;
+        owner: OPTIONAL GENERIC_ENTITY;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(1.0);
+  #2 = LABEL('a');
+  #3 = GROUP((#1, #2), $);
+  #4 = GROUP((#3), #2);
+ENDSEC;
+"#;
+
+#[test]
+fn generic_entity() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let group = EntityTable::<GroupHolder>::get_owned(&table, InstanceId(4)).unwrap();
+    let label = || {
+        GenericEntity::Label(Box::new(Label {
+            text: "a".to_string(),
+        }))
+    };
+    assert_eq!(
+        group,
+        Group {
+            items: vec![GenericEntity::Group(Box::new(Group {
+                items: vec![GenericEntity::Point(Box::new(Point { x: 1.0 })), label()],
+                owner: None,
+            }))],
+            owner: Some(label()),
+        }
+    );
+}
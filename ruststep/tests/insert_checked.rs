@@ -0,0 +1,187 @@
+// Test for inserting holders into tables
+
+use ruststep::{ast::Name, error::Error, tables::*};
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x, y: REAL;
+      END_ENTITY;
+
+      ENTITY line;
+        start, stop: point;
+      END_ENTITY;
+
+      ENTITY polyline;
+        points: LIST [2:?] OF point;
+      END_ENTITY;
+
+      TYPE curve = SELECT (line, polyline);
+      END_TYPE;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+fn point(x: f64, y: f64) -> PointHolder {
+    PointHolder { x, y }
+}
+
+fn reference<T>(id: u64) -> PlaceHolder<T> {
+    PlaceHolder::Ref(Name::Entity(InstanceId(id)))
+}
+
+#[test]
+fn forward_reference() {
+    let mut table = Tables::default();
+    // `#3` refers `#1` and `#2` inserted later
+    table
+        .insert_checked(
+            InstanceId(3),
+            LineHolder {
+                start: reference(1),
+                stop: reference(2),
+            },
+        )
+        .unwrap();
+    table
+        .insert_checked(InstanceId(1), point(0.0, 0.0))
+        .unwrap();
+    table
+        .insert_checked(InstanceId(2), point(1.0, 0.0))
+        .unwrap();
+    table.check_references().unwrap();
+    assert_eq!(
+        EntityTable::<LineHolder>::get_owned(&table, InstanceId(3)).unwrap(),
+        Line {
+            start: Point { x: 0.0, y: 0.0 },
+            stop: Point { x: 1.0, y: 0.0 },
+        }
+    );
+}
+
+#[test]
+fn dangling_reference() {
+    let mut table = Tables::default();
+    table
+        .insert_checked(InstanceId(1), point(0.0, 0.0))
+        .unwrap();
+    table
+        .insert_checked(InstanceId(2), point(1.0, 0.0))
+        .unwrap();
+
+    // typo of `#2` is found after insertion
+    table
+        .insert_checked(
+            InstanceId(4),
+            LineHolder {
+                start: reference(1),
+                stop: reference(22),
+            },
+        )
+        .unwrap();
+    // references in inline owned values are also checked
+    table
+        .insert_checked(
+            InstanceId(5),
+            PolylineHolder {
+                points: vec![
+                    reference(1),
+                    PlaceHolder::Owned(point(2.0, 2.0)),
+                    reference(6),
+                ],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        table.validate_references(),
+        [
+            (InstanceId(4), InstanceId(22)),
+            (InstanceId(5), InstanceId(6))
+        ]
+    );
+    assert!(matches!(
+        table.check_references(),
+        Err(Error::DanglingReference {
+            id: InstanceId(4),
+            target: InstanceId(22)
+        })
+    ));
+}
+
+#[test]
+fn duplicated() {
+    let mut table = Tables::default();
+    table
+        .insert_checked(InstanceId(1), point(0.0, 0.0))
+        .unwrap();
+    let err = table
+        .insert_checked(InstanceId(1), point(0.0, 1.0))
+        .unwrap_err();
+    assert!(matches!(err, Error::DuplicatedEntity(InstanceId(1))));
+}
+
+#[test]
+fn select() {
+    let mut table = Tables::default();
+    table
+        .insert_checked(InstanceId(1), point(0.0, 0.0))
+        .unwrap();
+    let polyline = CurveHolder::Polyline(Box::new(PolylineHolder {
+        points: vec![reference(1), reference(1)],
+    }));
+    table
+        .insert_checked(InstanceId(2), polyline.clone())
+        .unwrap();
+    assert_eq!(table.entity_type(InstanceId(2)), Some("POLYLINE"));
+    assert!(matches!(
+        table.insert_checked(InstanceId(2), polyline),
+        Err(Error::DuplicatedEntity(InstanceId(2)))
+    ));
+}
+
+#[test]
+fn insert_owned() {
+    let mut table = Tables::default();
+    let mut ids = IdAllocator::default();
+    let polyline = Polyline {
+        points: vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }],
+    };
+    let id = EntityTable::<CurveHolder>::insert_owned(
+        &mut table,
+        Curve::Polyline(Box::new(polyline.clone())),
+        &mut ids,
+    )
+    .unwrap();
+    // points are inserted first
+    assert_eq!(id, InstanceId(3));
+    assert_eq!(table.entity_type(InstanceId(1)), Some("POINT"));
+    assert_eq!(table.entity_type(InstanceId(3)), Some("POLYLINE"));
+    assert_eq!(
+        table.polyline_holders()[&id].points,
+        [reference(1), reference(2)]
+    );
+    assert_eq!(
+        EntityTable::<PolylineHolder>::get_owned(&table, id).unwrap(),
+        polyline
+    );
+}
+
+#[test]
+fn insert_owned_exhausted() {
+    let mut table = Tables::default();
+    let mut ids = IdAllocator::new(InstanceId(u64::MAX - 1));
+    let polyline = Polyline {
+        points: vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.0, y: 1.0 }],
+    };
+    assert!(matches!(
+        EntityTable::<CurveHolder>::insert_owned(
+            &mut table,
+            Curve::Polyline(Box::new(polyline)),
+            &mut ids,
+        ),
+        Err(Error::InstanceIdExhausted(InstanceId(u64::MAX)))
+    ));
+}
@@ -0,0 +1,103 @@
+// Test for converting holders back into parameters
+
+use ruststep::{
+    ast::{Name, Parameter},
+    tables::*,
+};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE color = ENUMERATION OF (red, green);
+      END_TYPE;
+
+      TYPE label = STRING;
+      END_TYPE;
+
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY shape;
+        name: OPTIONAL label;
+        color: color;
+        origin: point;
+        points: LIST [1:?] OF point;
+        grid: LIST [0:?] OF LIST [0:?] OF INTEGER;
+        visible: BOOLEAN;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = POINT(0.0, 0.0);
+  #2 = POINT(1.0, 2.0);
+  #3 = SHAPE($, .RED., #1, (#1, #2), ((1, 2), (3)), .T.);
+ENDSEC;
+"#;
+
+#[test]
+fn into_parameter_list() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let shape = table.shape_holders()[&InstanceId(3)].clone();
+    assert_eq!(
+        shape.into_parameter_list(),
+        [
+            Parameter::NotProvided,
+            Parameter::Enumeration("RED".to_string()),
+            Parameter::Ref(Name::Entity(InstanceId(1))),
+            Parameter::List(vec![
+                Parameter::Ref(Name::Entity(InstanceId(1))),
+                Parameter::Ref(Name::Entity(InstanceId(2)))
+            ]),
+            Parameter::List(vec![
+                Parameter::List(vec![Parameter::Integer(1), Parameter::Integer(2)]),
+                Parameter::List(vec![Parameter::Integer(3)]),
+            ]),
+            Parameter::Enumeration("T".to_string()),
+        ]
+    );
+
+    let point = table.point_holders()[&InstanceId(2)].clone();
+    assert_eq!(
+        point.into_parameter_list(),
+        [Parameter::Real(1.0), Parameter::Real(2.0)]
+    );
+}
+
+#[test]
+fn into_parameter_owned() {
+    // Inline owned values become typed parameters
+    let shape = ShapeHolder {
+        name: Some(PlaceHolder::Owned(Label("a".to_string()).into())),
+        color: Color::Green,
+        origin: PlaceHolder::Owned(Point { x: 0.0, y: 1.0 }.into()),
+        points: Vec::new(),
+        grid: Vec::new(),
+        visible: false,
+    };
+    let parameters = shape.into_parameter_list();
+    assert_eq!(
+        parameters[..3],
+        [
+            Parameter::Typed {
+                keyword: "LABEL".to_string(),
+                parameter: Box::new(Parameter::String("a".to_string())),
+            },
+            Parameter::Enumeration("GREEN".to_string()),
+            Parameter::Typed {
+                keyword: "POINT".to_string(),
+                parameter: Box::new(Parameter::List(vec![
+                    Parameter::Real(0.0),
+                    Parameter::Real(1.0)
+                ])),
+            },
+        ]
+    );
+}
@@ -0,0 +1,55 @@
+// Test for accessors of inverse attributes
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY door;
+        handle: knob;
+      END_ENTITY;
+
+      ENTITY sliding_door SUBTYPE OF (door);
+      END_ENTITY;
+
+      ENTITY knob;
+      INVERSE
+        opens: SET [0:?] OF door FOR handle;
+        mounted_on: BAG [0:?] OF panel FOR knobs;
+      END_ENTITY;
+
+      ENTITY panel;
+        knobs: LIST [0:?] OF knob;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = KNOB();
+  #2 = KNOB();
+  #3 = DOOR(#1);
+  #4 = DOOR(#1);
+  #5 = PANEL((#1, #2));
+  #6 = PANEL(());
+  #7 = (DOOR(#2) SLIDING_DOOR());
+  #8 = SLIDING_DOOR(#3);
+ENDSEC;
+"#;
+
+#[test]
+fn inverse_accessors() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    assert_eq!(
+        table.inverse_opens(InstanceId(1)),
+        [InstanceId(3), InstanceId(4)]
+    );
+    // Subtype instance is found by its inline supertype part, while #8 refers #3 for it
+    assert_eq!(table.inverse_opens(InstanceId(2)), [InstanceId(7)]);
+    assert_eq!(table.inverse_mounted_on(InstanceId(2)), [InstanceId(5)]);
+    assert!(table.inverse_mounted_on(InstanceId(3)).is_empty());
+}
@@ -0,0 +1,39 @@
+// Attributes named by Rust keywords
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        ref: REAL;
+        crate: REAL;
+        super: OPTIONAL REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn keyword_attribute() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = A(1.0, 2.0, $);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    // `ref` is a raw identifier, while `crate` and `super` cannot be and are suffixed
+    assert_eq!(
+        a,
+        A {
+            r#ref: 1.0,
+            crate_: 2.0,
+            super_: None,
+        }
+    );
+}
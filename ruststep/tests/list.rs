@@ -20,6 +20,11 @@ espr_derive::inline_express!(
       ENTITY d;
         a: LIST [0:?] OF LIST [0:?] OF LIST [0:?] OF a;
       END_ENTITY;
+
+      ENTITY e;
+        x: OPTIONAL LIST [0:?] OF REAL;
+        a: OPTIONAL LIST [0:?] OF a;
+      END_ENTITY;
     END_SCHEMA;
     "#
 );
@@ -71,3 +76,22 @@ fn deserialize_list_c() {
         }
     );
 }
+
+#[test]
+fn deserialize_optional_list_e() {
+    let (residual, p): (_, Record) = exchange::simple_record("E($, $)").finish().unwrap();
+    assert_eq!(residual, "");
+    let e: EHolder = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(e, EHolder { x: None, a: None });
+
+    let (residual, p): (_, Record) = exchange::simple_record("E((), ())").finish().unwrap();
+    assert_eq!(residual, "");
+    let e: EHolder = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(
+        e,
+        EHolder {
+            x: Some(Vec::new()),
+            a: Some(Vec::new())
+        }
+    );
+}
@@ -0,0 +1,81 @@
+// Test for skipping broken instances while creating tables
+
+use ruststep::tables::*;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        a: a;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+DATA;
+  #1 = A(1.0);
+  #2 = A(2.0,;
+  #3 = A('not a real');
+  #4 = C(1.0);
+  #5 = B(#1);
+  #6 = (A(6.0) B(#1));
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+#[test]
+fn from_step_str_lossy() {
+    let (table, errors) = Tables::from_step_str_lossy(EXAMPLE);
+    let ids: Vec<_> = errors.iter().map(|(id, _)| *id).collect();
+    assert_eq!(
+        ids,
+        [InstanceId(2), InstanceId(3), InstanceId(4), InstanceId(6)]
+    );
+    assert!(matches!(
+        errors[2].1,
+        ruststep::error::Error::UnknownEntityName { .. }
+    ));
+
+    assert_eq!(
+        EntityTable::<AHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        A { x: 1.0 }
+    );
+    assert_eq!(
+        EntityTable::<BHolder>::get_owned(&table, InstanceId(5)).unwrap(),
+        B { a: A { x: 1.0 } }
+    );
+    assert!(EntityTable::<AHolder>::get_owned(&table, InstanceId(3)).is_err());
+}
+
+#[test]
+fn from_step_str_lossy_with_limits() {
+    let limits = ruststep::parser::ParseLimits {
+        max_record_size: 16,
+        ..Default::default()
+    };
+    let (table, errors) = Tables::from_step_str_lossy_with_limits(
+        "DATA; #1 = A(1.0); #2 = A(2.000000000000); ENDSEC;",
+        &limits,
+    );
+    assert!(matches!(
+        errors[..],
+        [(
+            InstanceId(2),
+            ruststep::error::Error::RecordTooLarge { limit: 16, .. }
+        )]
+    ));
+    assert_eq!(table.a_holders().len(), 1);
+}
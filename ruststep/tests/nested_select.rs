@@ -0,0 +1,88 @@
+// Test for SELECT types whose members are SELECT types or refer the SELECT itself
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        y: INTEGER;
+      END_ENTITY;
+
+      ENTITY c;
+        z: STRING;
+      END_ENTITY;
+
+      TYPE sup = SELECT (a, b);
+      END_TYPE;
+
+      TYPE sup_sup = SELECT (sup, c);
+      END_TYPE;
+
+      ENTITY container;
+        s: sup_sup;
+        ss: LIST [0:?] OF sup_sup;
+      END_ENTITY;
+
+      TYPE node_item = SELECT (node, c);
+      END_TYPE;
+
+      ENTITY node;
+        children: LIST [0:?] OF node_item;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A(1.0);
+  #2 = CONTAINER(A((2.0)), (B((3)), C(('c')), #1));
+  #3 = NODE((C(('leaf'))));
+  #4 = NODE((#3, NODE(((C(('inline')))))));
+ENDSEC;
+"#;
+
+#[test]
+fn nested_typed() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let container = EntityTable::<ContainerHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert_eq!(
+        container,
+        Container {
+            s: SupSup::Sup(Box::new(Sup::A(Box::new(A { x: 2.0 })))),
+            ss: vec![
+                SupSup::Sup(Box::new(Sup::B(Box::new(B { y: 3 })))),
+                SupSup::C(Box::new(C { z: "c".to_string() })),
+                SupSup::Sup(Box::new(Sup::A(Box::new(A { x: 1.0 })))),
+            ],
+        }
+    );
+}
+
+#[test]
+fn self_referring_select() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let leaf = |z: &str| NodeItem::C(Box::new(C { z: z.to_string() }));
+    let node = EntityTable::<NodeHolder>::get_owned(&table, InstanceId(4)).unwrap();
+    assert_eq!(
+        node,
+        Node {
+            children: vec![
+                NodeItem::Node(Box::new(Node {
+                    children: vec![leaf("leaf")]
+                })),
+                NodeItem::Node(Box::new(Node {
+                    children: vec![leaf("inline")]
+                })),
+            ],
+        }
+    );
+}
@@ -0,0 +1,36 @@
+// Test for `NUMBER` attributes keeping integer-ness
+
+use ruststep::{primitive::Number, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY measure;
+        amount: NUMBER;
+        amounts: LIST [0:?] OF NUMBER;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn deserialize_number() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = MEASURE(2, (1.5, 3));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        EntityTable::<MeasureHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        Measure {
+            amount: Number::Int(2),
+            amounts: vec![Number::Real(1.5), Number::Int(3)],
+        }
+    );
+}
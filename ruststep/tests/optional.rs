@@ -1,5 +1,17 @@
-use ruststep::ast::*;
+use ruststep::{ast::*, tables::*};
 use serde::Deserialize;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: OPTIONAL REAL;
+        y: REAL;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
 
 #[test]
 fn deserialize_optional() {
@@ -11,3 +23,41 @@ fn deserialize_optional() {
     let x: Option<i32> = Deserialize::deserialize(&p).unwrap();
     assert_eq!(x, Some(3));
 }
+
+#[test]
+fn deserialize_optional_list() {
+    // `$` is an absent aggregate
+    let p = Parameter::NotProvided;
+    let x: Option<Vec<i64>> = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(x, None);
+
+    // `()` is an empty aggregate
+    let p = Parameter::List(Vec::new());
+    let x: Option<Vec<i64>> = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(x, Some(Vec::new()));
+
+    let p = Parameter::List(vec![Parameter::Integer(1), Parameter::Integer(2)]);
+    let x: Option<Vec<i64>> = Deserialize::deserialize(&p).unwrap();
+    assert_eq!(x, Some(vec![1, 2]));
+}
+
+#[test]
+fn optional_attribute() {
+    let table = test_schema::Tables::from_str(
+        r#"
+        DATA;
+          #1 = A($, 1.0);
+          #2 = A(2.0, 3.0);
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    let a: Vec<test_schema::A> = [1, 2]
+        .map(|id| EntityTable::<test_schema::AHolder>::get_owned(&table, InstanceId(id)).unwrap())
+        .into();
+    // `OPTIONAL REAL` is `Option<f64>` while `REAL` is `f64`
+    let x: Vec<Option<f64>> = a.iter().map(|a| a.x).collect();
+    let y: Vec<f64> = a.iter().map(|a| a.y).collect();
+    assert_eq!(x, [None, Some(2.0)]);
+    assert_eq!(y, [1.0, 3.0]);
+}
@@ -0,0 +1,93 @@
+// Test for writing real literals back as parsed with `ParseOptions::preserve_reals`
+
+use ruststep::{
+    ast::{ser::to_record, Parameter},
+    parser::{parse_with_options, ParseOptions},
+    primitive::Real,
+    tables::*,
+    Holder, TableInit,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Default, TableInit)]
+#[table(into_parameter)]
+pub struct Tables {
+    point: HashMap<InstanceId, PointHolder>,
+}
+
+#[derive(Debug, Clone, PartialEq, Holder, serde::Serialize)]
+#[holder(table = Tables)]
+#[holder(field = point)]
+#[holder(type_name = "point")]
+#[holder(generate_deserialize)]
+#[holder(generate_into_parameter)]
+#[serde(rename = "POINT")]
+pub struct Point {
+    pub x: Real,
+    pub y: Real,
+}
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION((''), '2;1');
+  FILE_NAME('', '', (''), (''), '', '', '');
+  FILE_SCHEMA(('S'));
+ENDSEC;
+DATA;
+  #1 = POINT(1.50E+00, 2);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+fn parse(preserve_reals: bool) -> Tables {
+    let options = ParseOptions {
+        preserve_reals,
+        ..Default::default()
+    };
+    let exchange = parse_with_options(EXAMPLE.trim(), &options).unwrap();
+    Tables::from_data_sections(&exchange.data).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let table = parse(true);
+    assert_eq!(
+        table.to_data_section().unwrap().to_string(),
+        "DATA;\n#1 = POINT(1.50E+00,2.);\nENDSEC;\n"
+    );
+}
+
+#[test]
+fn without_literals() {
+    let table = parse(false);
+    assert_eq!(
+        table.to_data_section().unwrap().to_string(),
+        "DATA;\n#1 = POINT(1.5,2.);\nENDSEC;\n"
+    );
+}
+
+#[test]
+fn serialize() {
+    let point = Point {
+        x: Real {
+            value: 1.5,
+            raw: Some("1.50E+00".to_string()),
+        },
+        y: Real::from(2.0),
+    };
+    let record = to_record(&point).unwrap();
+    assert_eq!(
+        record.parameter,
+        vec![
+            Parameter::RawReal {
+                value: 1.5,
+                raw: "1.50E+00".to_string()
+            },
+            Parameter::Real(2.0)
+        ]
+        .into_iter()
+        .collect()
+    );
+    assert_eq!(record.to_string(), "POINT(1.50E+00,2.)");
+}
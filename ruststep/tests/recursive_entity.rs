@@ -0,0 +1,113 @@
+// Test for entities referring themselves
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY tree_node;
+        name: STRING;
+        parent: OPTIONAL tree_node;
+      END_ENTITY;
+
+      ENTITY a;
+        b: b;
+      END_ENTITY;
+
+      ENTITY b;
+        a: OPTIONAL a;
+      END_ENTITY;
+
+      TYPE wrapped = c;
+      END_TYPE;
+
+      ENTITY c;
+        next: OPTIONAL wrapped;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = TREE_NODE('root', $);
+  #2 = TREE_NODE('child', #1);
+  #3 = TREE_NODE('grandchild', #2);
+  #4 = B($);
+  #5 = A(#4);
+  #6 = B(#5);
+  #7 = C($);
+ENDSEC;
+"#;
+
+#[test]
+fn get_owned() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let root = TreeNode {
+        name: "root".to_string(),
+        parent: None,
+    };
+    let child = TreeNode {
+        name: "child".to_string(),
+        parent: Some(Box::new(root.clone())),
+    };
+    assert_eq!(
+        EntityTable::<TreeNodeHolder>::get_owned(&table, InstanceId(1)).unwrap(),
+        root
+    );
+    assert_eq!(
+        EntityTable::<TreeNodeHolder>::get_owned(&table, InstanceId(3)).unwrap(),
+        TreeNode {
+            name: "grandchild".to_string(),
+            parent: Some(Box::new(child)),
+        }
+    );
+}
+
+#[test]
+fn get_owned_mutual() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let a = A {
+        b: Box::new(B { a: None }),
+    };
+    assert_eq!(
+        EntityTable::<AHolder>::get_owned(&table, InstanceId(5)).unwrap(),
+        a
+    );
+    assert_eq!(
+        EntityTable::<BHolder>::get_owned(&table, InstanceId(6)).unwrap(),
+        B {
+            a: Some(Box::new(a))
+        }
+    );
+}
+
+#[test]
+fn get_owned_defined_type() {
+    // Boxed through the defined type `wrapped` of `c`
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let c = EntityTable::<CHolder>::get_owned(&table, InstanceId(7)).unwrap();
+    assert_eq!(c, C { next: None });
+    let next = C {
+        next: Some(Box::new(Wrapped(c))),
+    };
+    assert!(next.next.is_some());
+}
+
+#[test]
+fn statistics() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let statistics = table.statistics();
+    assert_eq!(
+        statistics.into_iter().collect::<Vec<_>>(),
+        [
+            ("A".to_string(), 1),
+            ("B".to_string(), 2),
+            ("C".to_string(), 1),
+            ("TREE_NODE".to_string(), 3)
+        ]
+    );
+}
@@ -38,21 +38,36 @@ ENDSEC;
 #[test]
 fn get_owned_sup() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let sup1 = EntityTable::<SupHolder>::get_owned(&table, 1).unwrap();
+    let sup1 = EntityTable::<SupHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(sup1, Sup::A(Box::new(A { x: 3.34 })));
-    let sup2 = EntityTable::<SupHolder>::get_owned(&table, 2).unwrap();
+    let sup2 = EntityTable::<SupHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(sup2, Sup::B(Box::new(B { y: 57 })));
 }
 
 #[test]
 fn get_owned_supsup() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let sup1 = EntityTable::<SupHolder>::get_owned(&table, 1).unwrap();
-    let supsup1 = EntityTable::<SupSupHolder>::get_owned(&table, 1).unwrap();
+    let sup1 = EntityTable::<SupHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    let supsup1 = EntityTable::<SupSupHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(supsup1, SupSup::Sup(Box::new(sup1)));
-    let sup2 = EntityTable::<SupHolder>::get_owned(&table, 2).unwrap();
-    let supsup2 = EntityTable::<SupSupHolder>::get_owned(&table, 2).unwrap();
+    let sup2 = EntityTable::<SupHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    let supsup2 = EntityTable::<SupSupHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(supsup2, SupSup::Sup(Box::new(sup2)));
-    let supsup3 = EntityTable::<SupSupHolder>::get_owned(&table, 3).unwrap();
+    let supsup3 = EntityTable::<SupSupHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(supsup3, SupSup::C(Box::new(C { z: "hoge".into() })));
 }
+
+#[test]
+fn get_owned_non_member() {
+    let table = Tables::from_str(EXAMPLE).unwrap();
+    let err = EntityTable::<SupHolder>::get_owned(&table, InstanceId(3)).unwrap_err();
+    assert!(matches!(
+        err,
+        ruststep::error::Error::UnexpectedEntityType { id: InstanceId(3), ref keyword, expected: "SUP" }
+            if keyword == "C"
+    ));
+    assert!(matches!(
+        EntityTable::<SupHolder>::get_owned(&table, InstanceId(4)),
+        Err(ruststep::error::Error::UnknownEntity(InstanceId(4)))
+    ));
+}
@@ -0,0 +1,96 @@
+// Test for resolving references while deserializing
+
+use ruststep::{
+    ast::{de::*, *},
+    tables::*,
+};
+use serde::Deserialize;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY a;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY b;
+        a: a;
+        z: INTEGER;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+DATA;
+  #1 = A(1.0, 2.0);
+  #2 = B(#1, 3);
+  #3 = B(#4, 3);
+  #4 = B(#3, 3);
+ENDSEC;
+"#;
+
+#[test]
+fn resolve() {
+    let section = DataSection::from_str(EXAMPLE).unwrap();
+    let table = Tables::from_data_section(&section).unwrap();
+    let b = Parameter::Ref(Name::Entity(InstanceId(2)));
+
+    let de = DeserializerWithTable::new(&b, &section, true).unwrap();
+    let holder = BHolder::deserialize(&de).unwrap();
+    assert!(matches!(holder.a, PlaceHolder::Owned(_)));
+
+    // Resolved without looking up the table
+    let resolved = holder.into_owned(&Tables::default()).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(resolved.a, a);
+    let b = EntityTable::<BHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert_eq!(resolved, b);
+}
+
+#[test]
+fn keep_ref() {
+    let section = DataSection::from_str(EXAMPLE).unwrap();
+    let EntityInstance::Simple { record, .. } = &section.entities[1] else {
+        panic!("Simple instance is expected");
+    };
+    let b = Parameter::Typed {
+        keyword: record.name.clone(),
+        parameter: Box::new(record.parameter.clone()),
+    };
+    let de = DeserializerWithTable::new(&b, &section, false).unwrap();
+    let holder = BHolder::deserialize(&de).unwrap();
+    assert_eq!(holder.a, PlaceHolder::Ref(Name::Entity(InstanceId(1))));
+}
+
+#[test]
+fn resolve_error() {
+    let section = DataSection::from_str(EXAMPLE).unwrap();
+    assert!(matches!(
+        DeserializerWithTable::new(&Parameter::Ref(Name::Entity(InstanceId(5))), &section, true),
+        Err(ruststep::error::Error::UnknownEntity(InstanceId(5)))
+    ));
+    assert!(matches!(
+        DeserializerWithTable::new(&Parameter::Ref(Name::Entity(InstanceId(3))), &section, true),
+        Err(ruststep::error::Error::DeserializeFailed(_))
+    ));
+}
+
+#[test]
+fn shared_resolver() {
+    // #5 is referred from both #6 and #7, and every holder shares the resolver
+    let input = "DATA; #5 = A(1.0, 2.0); #6 = B(#5, 1); #7 = B(#5, 2); ENDSEC;";
+    let section = DataSection::from_str(input).unwrap();
+    let resolver = TableResolver::new(&section);
+    for id in [6, 7, 6] {
+        let b = Parameter::Ref(Name::Entity(InstanceId(id)));
+        let de = DeserializerWithTable::with_resolver(&b, &resolver).unwrap();
+        assert_eq!(de, DeserializerWithTable::new(&b, &section, true).unwrap());
+        let holder = BHolder::deserialize(&de).unwrap();
+        assert!(matches!(holder.a, PlaceHolder::Owned(_)));
+    }
+}
@@ -47,7 +47,7 @@ fn deserialize_sub() {
     test(
         "SUB(#3, 2.0)",
         SubHolder {
-            base: Name::Entity(3).into(),
+            base: Name::Entity(InstanceId(3)).into(),
             y: 2.0,
         },
     );
@@ -95,14 +95,14 @@ ENDSEC;
 #[test]
 fn get_owned_base() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let owned = EntityTable::<BaseHolder>::get_owned(&table, 1).unwrap();
+    let owned = EntityTable::<BaseHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(owned, Base { x: 1.0 });
 }
 
 #[test]
 fn get_owned_sub() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let owned = EntityTable::<SubHolder>::get_owned(&table, 2).unwrap();
+    let owned = EntityTable::<SubHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         owned,
         Sub {
@@ -115,7 +115,7 @@ fn get_owned_sub() {
 #[test]
 fn get_owned_subsub() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let owned = EntityTable::<SubsubHolder>::get_owned(&table, 3).unwrap();
+    let owned = EntityTable::<SubsubHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(
         owned,
         Subsub {
@@ -133,9 +133,9 @@ fn get_owned_any() {
     let table = Tables::from_str(EXAMPLE).unwrap();
 
     // BaseAny
-    let any = EntityTable::<BaseAnyHolder>::get_owned(&table, 1).unwrap();
+    let any = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(any, BaseAny::Base(Box::new(Base { x: 1.0 })));
-    let any = EntityTable::<BaseAnyHolder>::get_owned(&table, 2).unwrap();
+    let any = EntityTable::<BaseAnyHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         any,
         BaseAny::Sub(Box::new(
@@ -148,7 +148,7 @@ fn get_owned_any() {
     );
 
     // SubAny
-    let any = EntityTable::<SubAnyHolder>::get_owned(&table, 2).unwrap();
+    let any = EntityTable::<SubAnyHolder>::get_owned(&table, InstanceId(2)).unwrap();
     assert_eq!(
         any,
         SubAny::Sub(Box::new(Sub {
@@ -156,7 +156,7 @@ fn get_owned_any() {
             y: 2.0
         }))
     );
-    let any = EntityTable::<SubAnyHolder>::get_owned(&table, 3).unwrap();
+    let any = EntityTable::<SubAnyHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(
         any,
         SubAny::Subsub(Box::new(Subsub {
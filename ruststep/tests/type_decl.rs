@@ -46,16 +46,16 @@ ENDSEC;
 #[test]
 fn get_owned() {
     let table = Tables::from_str(EXAMPLE).unwrap();
-    let a = EntityTable::<AHolder>::get_owned(&table, 1).unwrap();
+    let a = EntityTable::<AHolder>::get_owned(&table, InstanceId(1)).unwrap();
     assert_eq!(a, A("KORE".to_string()));
 
-    let c = EntityTable::<CHolder>::get_owned(&table, 3).unwrap();
+    let c = EntityTable::<CHolder>::get_owned(&table, InstanceId(3)).unwrap();
     assert_eq!(c, C(A("KORE".to_string())));
 
-    let d = EntityTable::<DHolder>::get_owned(&table, 4).unwrap();
+    let d = EntityTable::<DHolder>::get_owned(&table, InstanceId(4)).unwrap();
     assert_eq!(d, D(B::Dore));
 
-    let e = EntityTable::<EHolder>::get_owned(&table, 5).unwrap();
+    let e = EntityTable::<EHolder>::get_owned(&table, InstanceId(5)).unwrap();
     assert_eq!(
         e,
         E {
@@ -66,3 +66,22 @@ fn get_owned() {
         }
     );
 }
+
+#[test]
+fn type_name() {
+    assert_eq!(AHolder::name(), "A");
+    assert_eq!(AHolder::type_name(), "a");
+    assert_eq!(DHolder::type_name(), "d");
+    assert_eq!(EHolder::type_name(), "e");
+}
+
+#[test]
+fn bare_values() {
+    // Defined types are usually written as their underlying values
+    let table =
+        Tables::from_str("DATA; #1 = D(.DORE.); #2 = E('KORE', .SORE., 'KORE', #1); ENDSEC;")
+            .unwrap();
+    let e = EntityTable::<EHolder>::get_owned(&table, InstanceId(2)).unwrap();
+    assert_eq!(e.a, A("KORE".to_string()));
+    assert_eq!(e.c, C(A("KORE".to_string())));
+}
@@ -0,0 +1,69 @@
+// Test for items imported by `USE FROM` with and without `AS`
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA geometry;
+      ENTITY point;
+        x: REAL;
+      END_ENTITY;
+      TYPE color = ENUMERATION OF (red, green);
+      END_TYPE;
+    END_SCHEMA;
+
+    SCHEMA drawing;
+      USE FROM geometry (point AS pt, color);
+      ENTITY line;
+        color: color;
+      END_ENTITY;
+    END_SCHEMA;
+
+    SCHEMA sketch;
+      USE FROM geometry;
+    END_SCHEMA;
+
+    SCHEMA plan;
+      USE FROM drawing (pt AS p, color);
+    END_SCHEMA;
+    "#
+);
+
+#[test]
+fn alias() {
+    let table = geometry::Tables::from_str("DATA; #1 = POINT(1.0); ENDSEC;").unwrap();
+    let p: drawing::Pt =
+        EntityTable::<drawing::PtHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(p, geometry::Point { x: 1.0 });
+}
+
+#[test]
+fn cross_schema_reference() {
+    let table = drawing::Tables::from_str("DATA; #1 = LINE(.GREEN.); ENDSEC;").unwrap();
+    let line = EntityTable::<drawing::LineHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(
+        line,
+        drawing::Line {
+            color: geometry::Color::Green
+        }
+    );
+    assert_eq!(drawing::Color::Red, geometry::Color::Red);
+}
+
+#[test]
+fn whole_schema() {
+    let table = geometry::Tables::from_str("DATA; #1 = POINT(2.0); ENDSEC;").unwrap();
+    let p: sketch::Point =
+        EntityTable::<sketch::PointHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(p, geometry::Point { x: 2.0 });
+}
+
+#[test]
+fn transitive() {
+    // `geometry.point` imported into `drawing` as `pt`, and then into `plan` as `p`
+    let table = geometry::Tables::from_str("DATA; #1 = POINT(3.0); ENDSEC;").unwrap();
+    let p: plan::P = EntityTable::<plan::PHolder>::get_owned(&table, InstanceId(1)).unwrap();
+    assert_eq!(p, geometry::Point { x: 3.0 });
+    assert_eq!(plan::Color::Green, geometry::Color::Green);
+}
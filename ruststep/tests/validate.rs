@@ -0,0 +1,140 @@
+// Test for checking domain rules in `WHERE` clauses while creating tables
+
+use ruststep::{error::Error, tables::*};
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY base;
+        x: REAL;
+      WHERE
+        wr1: SELF.x > 0;
+      END_ENTITY;
+
+      ENTITY sub SUBTYPE OF (base);
+        items: LIST [0:?] OF INTEGER;
+      WHERE
+        wr1: SIZEOF(items) <= 2;
+      END_ENTITY;
+
+      ENTITY unique_items;
+        items: LIST [0:?] OF INTEGER;
+      WHERE
+        wr1: SIZEOF(QUERY(t <* items | SIZEOF(QUERY(u <* items | u = t)) > 1)) = 0;
+      END_ENTITY;
+
+      ENTITY counter;
+        n: INTEGER;
+        items: LIST [0:?] OF INTEGER;
+      WHERE
+        wr1: n + 1 > 0;
+        wr2: SIZEOF(QUERY(t <* items | t * 2 < n)) = 0;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+fn tables(data: &str, validate_rules: bool) -> ruststep::error::Result<Tables> {
+    let data_sec: ruststep::ast::DataSection = data.parse().unwrap();
+    Tables::from_data_section_with_options(&data_sec, &DeserializeOptions { validate_rules })
+}
+
+#[test]
+fn valid() {
+    let data = r#"
+    DATA;
+      #1 = BASE(1.0);
+      #2 = SUB(BASE((2.0)), (1, 2));
+    ENDSEC;
+    "#;
+    assert!(tables(data, true).is_ok());
+}
+
+#[test]
+fn violation() {
+    let data = r#"
+    DATA;
+      #1 = BASE(1.0);
+      #2 = BASE(-1.0);
+    ENDSEC;
+    "#;
+    // Not checked by default
+    assert!(tables(data, false).is_ok());
+    assert!(matches!(
+        tables(data, true),
+        Err(Error::RuleViolation {
+            id: InstanceId(2),
+            rule: "base.wr1"
+        })
+    ));
+}
+
+#[test]
+fn violation_inherited() {
+    let data = r#"
+    DATA;
+      #1 = SUB(BASE((-1.0)), (1, 2, 3));
+    ENDSEC;
+    "#;
+    assert!(matches!(
+        tables(data, true),
+        Err(Error::RuleViolation {
+            id: InstanceId(1),
+            rule: "base.wr1"
+        })
+    ));
+}
+
+#[test]
+fn query() {
+    let data = r#"
+    DATA;
+      #1 = UNIQUE_ITEMS((1, 2, 3));
+    ENDSEC;
+    "#;
+    assert!(tables(data, true).is_ok());
+
+    let data = r#"
+    DATA;
+      #1 = UNIQUE_ITEMS((1, 2, 1));
+    ENDSEC;
+    "#;
+    assert!(matches!(
+        tables(data, true),
+        Err(Error::RuleViolation {
+            id: InstanceId(1),
+            rule: "unique_items.wr1"
+        })
+    ));
+}
+
+#[test]
+fn overflow() {
+    let data = r#"
+    DATA;
+      #1 = COUNTER(1, (1, 2));
+    ENDSEC;
+    "#;
+    assert!(tables(data, true).is_ok());
+
+    for (data, rule) in [
+        ("DATA; #1 = COUNTER(-5, ()); ENDSEC;", "counter.wr1"),
+        ("DATA; #1 = COUNTER(1, (1, -2)); ENDSEC;", "counter.wr2"),
+    ] {
+        assert!(matches!(
+            tables(data, true),
+            Err(Error::RuleViolation { id: InstanceId(1), rule: r }) if r == rule
+        ));
+    }
+
+    // Indeterminate by overflow, which is not a violation
+    let data = r#"
+    DATA;
+      #1 = COUNTER(9223372036854775807, ());
+      #2 = COUNTER(1, (-9223372036854775807));
+    ENDSEC;
+    "#;
+    assert!(tables(data, true).is_ok());
+}
@@ -0,0 +1,72 @@
+// Test for detecting references to instances which do not exist
+
+use ruststep::tables::*;
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      ENTITY point;
+        x, y: REAL;
+      END_ENTITY;
+
+      ENTITY line;
+        start, stop: point;
+      END_ENTITY;
+
+      ENTITY polyline;
+        points: LIST [2:?] OF point;
+      END_ENTITY;
+
+      TYPE curve = SELECT (line, polyline);
+      END_TYPE;
+
+      ENTITY drawing;
+        curves: LIST [0:?] OF curve;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+#[test]
+fn clean() {
+    // forward references are allowed
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = DRAWING((#2, #5));
+          #2 = LINE(#3, #4);
+          #3 = POINT(0.0, 0.0);
+          #4 = POINT(1.0, 0.0);
+          #5 = POLYLINE((#3, #4, #3));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    assert!(table.validate_references().is_empty());
+}
+
+#[test]
+fn dangling() {
+    let table = Tables::from_str(
+        r#"
+        DATA;
+          #1 = DRAWING((#2, #999));
+          #2 = LINE(#3, #999);
+          #3 = POINT(0.0, 0.0);
+          #4 = POLYLINE((#3, #998, #998));
+        ENDSEC;
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        table.validate_references(),
+        [
+            (InstanceId(1), InstanceId(999)),
+            (InstanceId(2), InstanceId(999)),
+            (InstanceId(4), InstanceId(998)),
+        ]
+    );
+}
@@ -0,0 +1,133 @@
+// Test for writing tables back into ISO 10303-21 files
+
+use ruststep::{ast::Exchange, error::Error, tables::*};
+use std::str::FromStr;
+
+espr_derive::inline_express!(
+    r#"
+    SCHEMA test_schema;
+      TYPE color = ENUMERATION OF (red, green);
+      END_TYPE;
+
+      TYPE label = STRING;
+      END_TYPE;
+
+      ENTITY point;
+        x: REAL;
+        y: REAL;
+      END_ENTITY;
+
+      ENTITY shape;
+        name: OPTIONAL label;
+        color: color;
+        points: LIST [1:?] OF point;
+        visible: BOOLEAN;
+      END_ENTITY;
+    END_SCHEMA;
+    "#
+);
+
+use test_schema::*;
+
+const EXAMPLE: &str = r#"
+ISO-10303-21;
+HEADER;
+  FILE_DESCRIPTION(('round trip'), '2;1');
+  FILE_NAME('shape.stp', '2021-01-01T00:00:00', ('alice'), ('org'), 'pre', 'sys', '');
+  FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+DATA;
+  #4 = SHAPE(#5, .GREEN., (#1, #2), .F.);
+  #1 = POINT(0.0, 1.5E+1);
+  #2 = POINT(-2, 3.25);
+  #5 = LABEL('it''s caf\X2\00E9\X0\');
+  #3 = SHAPE($, .RED., (#2), .T.);
+ENDSEC;
+END-ISO-10303-21;
+"#;
+
+#[test]
+fn round_trip() {
+    let exchange = Exchange::from_str(EXAMPLE.trim()).unwrap();
+    let header = exchange.typed_header().unwrap();
+    let table = Tables::from_data_sections(&exchange.data).unwrap();
+
+    let output = table.to_part21_string(&header).unwrap();
+    assert_eq!(
+        output,
+        r#"ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('round trip'),'2;1');
+FILE_NAME('shape.stp','2021-01-01T00:00:00',('alice'),('org'),'pre','sys','');
+FILE_SCHEMA(('TEST_SCHEMA'));
+ENDSEC;
+DATA;
+#1 = POINT(0.,15.);
+#2 = POINT(-2.,3.25);
+#3 = SHAPE($,.RED.,(#2),.T.);
+#4 = SHAPE(#5,.GREEN.,(#1,#2),.F.);
+#5 = LABEL('it''s caf\X2\00E9\X0\');
+ENDSEC;
+END-ISO-10303-21;
+"#
+    );
+
+    let exchange = Exchange::from_str(&output).unwrap();
+    assert_eq!(exchange.typed_header().unwrap(), header);
+    assert_eq!(Tables::from_data_sections(&exchange.data).unwrap(), table);
+}
+
+#[test]
+fn owned_values() {
+    // Inline owned entities are written as separate instances after the existing ones,
+    // while defined types remain typed parameters
+    let mut table = Tables::default();
+    table
+        .insert_checked(
+            InstanceId(1),
+            ShapeHolder {
+                name: Some(PlaceHolder::Owned(Label("a".to_string()).into())),
+                color: Color::Red,
+                points: vec![
+                    PlaceHolder::Owned(Point { x: 1.0, y: 2.0 }.into()),
+                    PlaceHolder::Owned(Point { x: 3.0, y: 4.0 }.into()),
+                ],
+                visible: true,
+            },
+        )
+        .unwrap();
+    table
+        .insert_checked(InstanceId(3), PointHolder { x: 0.0, y: 0.0 })
+        .unwrap();
+    assert_eq!(
+        table.to_data_section().unwrap().to_string(),
+        r#"DATA;
+#1 = SHAPE(LABEL('a'),.RED.,(#4,#5),.T.);
+#3 = POINT(0.,0.);
+#4 = POINT(1.,2.);
+#5 = POINT(3.,4.);
+ENDSEC;
+"#
+    );
+}
+
+#[test]
+fn owned_values_exhausted() {
+    // No id remains for inline owned values after the largest one
+    let mut table = Tables::default();
+    table
+        .insert_checked(
+            InstanceId(u64::MAX),
+            ShapeHolder {
+                name: None,
+                color: Color::Red,
+                points: vec![PlaceHolder::Owned(Point { x: 1.0, y: 2.0 }.into())],
+                visible: true,
+            },
+        )
+        .unwrap();
+    assert!(matches!(
+        table.to_data_section(),
+        Err(Error::InstanceIdExhausted(InstanceId(u64::MAX)))
+    ));
+}